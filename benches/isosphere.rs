@@ -0,0 +1,130 @@
+//! Benchmarks for parsing, lookups, and serialisation.
+//! 
+//! These measure the throughput of the operations that sit in hot request
+//! paths, using the corpus from the `test_util` module, and are intended to
+//! guide changes to the internal data structures. They can be run with
+//! `cargo bench --features test-util`.
+//! 
+
+#![expect(missing_docs,                  reason = "Criterion generates undocumented functions")]
+#![expect(unused_crate_dependencies,     reason = "Only some dependencies are used by benchmarks")]
+
+
+
+//		Packages
+
+use core::{
+	hint::black_box,
+	str::FromStr,
+};
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use isosphere::{
+	Country,
+	CountryCode,
+	Currency,
+	CurrencyCode,
+	Language,
+	LanguageCode,
+	test_util::{benchmark_codes, benchmark_inputs},
+};
+
+
+
+//		Functions
+
+//		parse																	
+/// Benchmarks parsing of codes and names from strings.
+fn parse(c: &mut Criterion) {
+	let codes  = benchmark_codes();
+	let inputs = benchmark_inputs();
+	let mut group = c.benchmark_group("parse");
+	_ = group.throughput(Throughput::Elements(codes.len() as u64));
+	_ = group.bench_function("codes", |b| b.iter(|| {
+		for code in &codes {
+			_ = black_box(CountryCode::from_str(code).is_ok());
+			_ = black_box(CurrencyCode::from_str(code).is_ok());
+			_ = black_box(LanguageCode::from_str(code).is_ok());
+		}
+	}));
+	_ = group.throughput(Throughput::Elements(inputs.len() as u64));
+	_ = group.bench_function("codes_mixed", |b| b.iter(|| {
+		for input in &inputs {
+			_ = black_box(CountryCode::from_str(input).is_ok());
+			_ = black_box(CurrencyCode::from_str(input).is_ok());
+			_ = black_box(LanguageCode::from_str(input).is_ok());
+		}
+	}));
+	_ = group.bench_function("names_mixed", |b| b.iter(|| {
+		for input in &inputs {
+			_ = black_box(Country::from_str(input).is_ok());
+			_ = black_box(Currency::from_str(input).is_ok());
+			_ = black_box(Language::from_str(input).is_ok());
+		}
+	}));
+	group.finish();
+}
+
+//		lookup																	
+/// Benchmarks lookups of information about countries, currencies, and
+/// languages.
+fn lookup(c: &mut Criterion) {
+	let countries  = Country::all();
+	let currencies = Currency::all();
+	let languages  = Language::all();
+	let mut group  = c.benchmark_group("lookup");
+	_ = group.throughput(Throughput::Elements(countries.len() as u64));
+	_ = group.bench_function("country", |b| b.iter(|| {
+		for country in &countries {
+			_ = black_box(country.name());
+			_ = black_box(country.code().to_alpha3());
+			_ = black_box(country.currencies());
+			_ = black_box(country.languages());
+		}
+	}));
+	_ = group.throughput(Throughput::Elements(currencies.len() as u64));
+	_ = group.bench_function("currency", |b| b.iter(|| {
+		for currency in &currencies {
+			_ = black_box(currency.name());
+			_ = black_box(currency.code().numeric());
+			_ = black_box(currency.digits());
+			_ = black_box(currency.countries());
+		}
+	}));
+	_ = group.throughput(Throughput::Elements(languages.len() as u64));
+	_ = group.bench_function("language", |b| b.iter(|| {
+		for language in &languages {
+			_ = black_box(language.name());
+			_ = black_box(language.code());
+			_ = black_box(language.countries());
+		}
+	}));
+	group.finish();
+}
+
+//		serialisation															
+/// Benchmarks serialisation and deserialisation.
+fn serialisation(c: &mut Criterion) {
+	let countries = Country::all();
+	let codes     = CountryCode::all();
+	let json      = serde_json::to_string(&countries).unwrap_or_default();
+	let mut group = c.benchmark_group("serialisation");
+	_ = group.throughput(Throughput::Elements(countries.len() as u64));
+	_ = group.bench_function("serialize_countries", |b| b.iter(|| {
+		black_box(serde_json::to_string(black_box(&countries)).is_ok())
+	}));
+	_ = group.bench_function("deserialize_countries", |b| b.iter(|| {
+		black_box(serde_json::from_str::<Vec<Country>>(black_box(&json)).is_ok())
+	}));
+	_ = group.throughput(Throughput::Elements(codes.len() as u64));
+	_ = group.bench_function("serialize_codes", |b| b.iter(|| {
+		black_box(serde_json::to_string(black_box(&codes)).is_ok())
+	}));
+	group.finish();
+}
+
+
+
+//		Benchmarks
+
+criterion_group!(benches, parse, lookup, serialisation);
+criterion_main!(benches);
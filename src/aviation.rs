@@ -0,0 +1,334 @@
+//! Aviation codes.
+//! 
+//! This module provides the ICAO nationality marks of each country, which form
+//! the prefix of aircraft registrations, e.g. `G` in `G-EUPT` for the UK, or
+//! `N` in `N12345` for the US, available via [`Country::nationality_mark()`].
+//! These are allocated under ICAO Annex 7, from the blocks of radio call sign
+//! prefixes assigned to each country by the ITU.
+//! 
+//! The reverse lookup, via [`country_for_registration()`], allows registrations
+//! taken from flight data feeds to be resolved to a [`Country`]:
+//! 
+//! ```
+//! use isosphere::{Country, aviation};
+//! 
+//! assert_eq!(Country::AU.nationality_mark(), Some("VH"));
+//! assert_eq!(aviation::country_for_registration("VH-OQA"), Some(Country::AU));
+//! assert_eq!(aviation::country_for_registration("B-HNR"),  Some(Country::HK));
+//! ```
+//! 
+//! Each country is given its primary mark. Some countries also use additional
+//! marks, e.g. `PR`, `PS`, `PT`, and `PU` as well as `PP` for Brazil, which are
+//! not included. British Overseas Territories, and Hong Kong and Macao, use a
+//! mark followed by a letter, e.g. `VP-C` for the Cayman Islands, and these are
+//! given in full. Territories that use the mark of another country without a
+//! distinguishing letter, such as the Faroe Islands, are not included.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/aviation.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	country::{Country, CountryCode},
+	map,
+};
+
+
+
+//		Constants
+
+/// The ICAO nationality marks of the countries.
+/// 
+/// # Data sources
+/// 
+/// The marks are listed in ICAO Annex 7, and summarised on [Wikipedia](https://en.wikipedia.org/wiki/List_of_aircraft_registration_prefixes).
+/// 
+static NATIONALITY_MARKS: [(CountryCode, &str); 211] = [
+	(CountryCode::AD, "C3"),
+	(CountryCode::AE, "A6"),
+	(CountryCode::AF, "YA"),
+	(CountryCode::AG, "V2"),
+	(CountryCode::AI, "VP-A"),
+	(CountryCode::AL, "ZA"),
+	(CountryCode::AM, "EK"),
+	(CountryCode::AO, "D2"),
+	(CountryCode::AR, "LV"),
+	(CountryCode::AT, "OE"),
+	(CountryCode::AU, "VH"),
+	(CountryCode::AW, "P4"),
+	(CountryCode::AZ, "4K"),
+	(CountryCode::BA, "E7"),
+	(CountryCode::BB, "8P"),
+	(CountryCode::BD, "S2"),
+	(CountryCode::BE, "OO"),
+	(CountryCode::BF, "XT"),
+	(CountryCode::BG, "LZ"),
+	(CountryCode::BH, "A9C"),
+	(CountryCode::BI, "9U"),
+	(CountryCode::BJ, "TY"),
+	(CountryCode::BM, "VP-B"),
+	(CountryCode::BN, "V8"),
+	(CountryCode::BO, "CP"),
+	(CountryCode::BR, "PP"),
+	(CountryCode::BS, "C6"),
+	(CountryCode::BT, "A5"),
+	(CountryCode::BW, "A2"),
+	(CountryCode::BY, "EW"),
+	(CountryCode::BZ, "V3"),
+	(CountryCode::CA, "C"),
+	(CountryCode::CD, "9S"),
+	(CountryCode::CF, "TL"),
+	(CountryCode::CG, "TN"),
+	(CountryCode::CH, "HB"),
+	(CountryCode::CI, "TU"),
+	(CountryCode::CK, "E5"),
+	(CountryCode::CL, "CC"),
+	(CountryCode::CM, "TJ"),
+	(CountryCode::CN, "B"),
+	(CountryCode::CO, "HK"),
+	(CountryCode::CR, "TI"),
+	(CountryCode::CU, "CU"),
+	(CountryCode::CV, "D4"),
+	(CountryCode::CY, "5B"),
+	(CountryCode::CZ, "OK"),
+	(CountryCode::DE, "D"),
+	(CountryCode::DJ, "J2"),
+	(CountryCode::DK, "OY"),
+	(CountryCode::DM, "J7"),
+	(CountryCode::DO, "HI"),
+	(CountryCode::DZ, "7T"),
+	(CountryCode::EC, "HC"),
+	(CountryCode::EE, "ES"),
+	(CountryCode::EG, "SU"),
+	(CountryCode::ER, "E3"),
+	(CountryCode::ES, "EC"),
+	(CountryCode::ET, "ET"),
+	(CountryCode::FI, "OH"),
+	(CountryCode::FJ, "DQ"),
+	(CountryCode::FK, "VP-F"),
+	(CountryCode::FM, "V6"),
+	(CountryCode::FR, "F"),
+	(CountryCode::GA, "TR"),
+	(CountryCode::GB, "G"),
+	(CountryCode::GD, "J3"),
+	(CountryCode::GE, "4L"),
+	(CountryCode::GG, "2"),
+	(CountryCode::GH, "9G"),
+	(CountryCode::GI, "VP-G"),
+	(CountryCode::GM, "C5"),
+	(CountryCode::GN, "3X"),
+	(CountryCode::GQ, "3C"),
+	(CountryCode::GR, "SX"),
+	(CountryCode::GT, "TG"),
+	(CountryCode::GW, "J5"),
+	(CountryCode::GY, "8R"),
+	(CountryCode::HK, "B-H"),
+	(CountryCode::HN, "HR"),
+	(CountryCode::HR, "9A"),
+	(CountryCode::HT, "HH"),
+	(CountryCode::HU, "HA"),
+	(CountryCode::ID, "PK"),
+	(CountryCode::IE, "EI"),
+	(CountryCode::IL, "4X"),
+	(CountryCode::IM, "M"),
+	(CountryCode::IN, "VT"),
+	(CountryCode::IQ, "YI"),
+	(CountryCode::IR, "EP"),
+	(CountryCode::IS, "TF"),
+	(CountryCode::IT, "I"),
+	(CountryCode::JE, "ZJ"),
+	(CountryCode::JM, "6Y"),
+	(CountryCode::JO, "JY"),
+	(CountryCode::JP, "JA"),
+	(CountryCode::KE, "5Y"),
+	(CountryCode::KG, "EX"),
+	(CountryCode::KH, "XU"),
+	(CountryCode::KI, "T3"),
+	(CountryCode::KM, "D6"),
+	(CountryCode::KN, "V4"),
+	(CountryCode::KP, "P"),
+	(CountryCode::KR, "HL"),
+	(CountryCode::KW, "9K"),
+	(CountryCode::KY, "VP-C"),
+	(CountryCode::KZ, "UP"),
+	(CountryCode::LA, "RDPL"),
+	(CountryCode::LB, "OD"),
+	(CountryCode::LC, "J6"),
+	(CountryCode::LK, "4R"),
+	(CountryCode::LR, "A8"),
+	(CountryCode::LS, "7P"),
+	(CountryCode::LT, "LY"),
+	(CountryCode::LU, "LX"),
+	(CountryCode::LV, "YL"),
+	(CountryCode::LY, "5A"),
+	(CountryCode::MA, "CN"),
+	(CountryCode::MC, "3A"),
+	(CountryCode::MD, "ER"),
+	(CountryCode::ME, "4O"),
+	(CountryCode::MG, "5R"),
+	(CountryCode::MH, "V7"),
+	(CountryCode::MK, "Z3"),
+	(CountryCode::ML, "TZ"),
+	(CountryCode::MM, "XY"),
+	(CountryCode::MN, "JU"),
+	(CountryCode::MO, "B-M"),
+	(CountryCode::MR, "5T"),
+	(CountryCode::MS, "VP-M"),
+	(CountryCode::MT, "9H"),
+	(CountryCode::MU, "3B"),
+	(CountryCode::MV, "8Q"),
+	(CountryCode::MW, "7Q"),
+	(CountryCode::MX, "XA"),
+	(CountryCode::MY, "9M"),
+	(CountryCode::MZ, "C9"),
+	(CountryCode::NA, "V5"),
+	(CountryCode::NE, "5U"),
+	(CountryCode::NG, "5N"),
+	(CountryCode::NI, "YN"),
+	(CountryCode::NL, "PH"),
+	(CountryCode::NO, "LN"),
+	(CountryCode::NP, "9N"),
+	(CountryCode::NR, "C2"),
+	(CountryCode::NZ, "ZK"),
+	(CountryCode::OM, "A4O"),
+	(CountryCode::PA, "HP"),
+	(CountryCode::PE, "OB"),
+	(CountryCode::PG, "P2"),
+	(CountryCode::PH, "RP"),
+	(CountryCode::PK, "AP"),
+	(CountryCode::PL, "SP"),
+	(CountryCode::PS, "E4"),
+	(CountryCode::PT, "CS"),
+	(CountryCode::PW, "T8A"),
+	(CountryCode::PY, "ZP"),
+	(CountryCode::QA, "A7"),
+	(CountryCode::RO, "YR"),
+	(CountryCode::RS, "YU"),
+	(CountryCode::RU, "RA"),
+	(CountryCode::RW, "9XR"),
+	(CountryCode::SA, "HZ"),
+	(CountryCode::SB, "H4"),
+	(CountryCode::SC, "S7"),
+	(CountryCode::SD, "ST"),
+	(CountryCode::SE, "SE"),
+	(CountryCode::SG, "9V"),
+	(CountryCode::SH, "VQ-H"),
+	(CountryCode::SI, "S5"),
+	(CountryCode::SK, "OM"),
+	(CountryCode::SL, "9L"),
+	(CountryCode::SM, "T7"),
+	(CountryCode::SN, "6V"),
+	(CountryCode::SO, "6O"),
+	(CountryCode::SR, "PZ"),
+	(CountryCode::SS, "Z8"),
+	(CountryCode::ST, "S9"),
+	(CountryCode::SV, "YS"),
+	(CountryCode::SY, "YK"),
+	(CountryCode::SZ, "3DC"),
+	(CountryCode::TC, "VQ-T"),
+	(CountryCode::TD, "TT"),
+	(CountryCode::TG, "5V"),
+	(CountryCode::TH, "HS"),
+	(CountryCode::TJ, "EY"),
+	(CountryCode::TL, "4W"),
+	(CountryCode::TM, "EZ"),
+	(CountryCode::TN, "TS"),
+	(CountryCode::TO, "A3"),
+	(CountryCode::TR, "TC"),
+	(CountryCode::TT, "9Y"),
+	(CountryCode::TV, "T2"),
+	(CountryCode::TW, "B"),
+	(CountryCode::TZ, "5H"),
+	(CountryCode::UA, "UR"),
+	(CountryCode::UG, "5X"),
+	(CountryCode::US, "N"),
+	(CountryCode::UY, "CX"),
+	(CountryCode::UZ, "UK"),
+	(CountryCode::VA, "HV"),
+	(CountryCode::VC, "J8"),
+	(CountryCode::VE, "YV"),
+	(CountryCode::VG, "VP-L"),
+	(CountryCode::VN, "VN"),
+	(CountryCode::VU, "YJ"),
+	(CountryCode::WS, "5W"),
+	(CountryCode::YE, "7O"),
+	(CountryCode::ZA, "ZS"),
+	(CountryCode::ZM, "9J"),
+	(CountryCode::ZW, "Z"),
+];
+
+
+
+//		Functions
+
+//		nationality_mark														
+/// Returns the ICAO nationality mark of a country.
+/// 
+/// Countries without a mark of their own return `None`. For more information,
+/// see [`Country::nationality_mark()`].
+/// 
+/// # Parameters
+/// 
+/// * `country` - The country to get the nationality mark for.
+/// 
+#[must_use]
+pub fn nationality_mark(country: Country) -> Option<&'static str> {
+	map::get(&NATIONALITY_MARKS, &country.code()).copied()
+}
+
+//		countries_with_nationality_mark											
+/// Returns the countries that use an ICAO nationality mark.
+/// 
+/// Marks are usually unique, but `B` is used by both China and Taiwan. The
+/// match is case-insensitive, and an unassigned mark returns an empty list.
+/// 
+/// # Parameters
+/// 
+/// * `mark` - The nationality mark, e.g. `VH`.
+/// 
+#[must_use]
+pub fn countries_with_nationality_mark(mark: &str) -> Vec<Country> {
+	let trimmed = mark.trim();
+	NATIONALITY_MARKS.iter()
+		.filter(|entry| entry.1.eq_ignore_ascii_case(trimmed))
+		.map(|entry| entry.0.country())
+		.collect()
+}
+
+//		country_for_registration												
+/// Returns the country that an aircraft registration belongs to.
+/// 
+/// The registration is matched against the longest nationality mark that it
+/// starts with, so that e.g. `CC-AWA` resolves to Chile rather than to Canada,
+/// which uses `C`, and `B-HNR` resolves to Hong Kong rather than to China. The
+/// match is case-insensitive. If no mark matches, or the matching mark is
+/// shared by more than one country, such as `B`, then `None` is returned.
+/// 
+/// Note that this only checks the prefix of the registration, and not whether
+/// the rest of it is well-formed.
+/// 
+/// # Parameters
+/// 
+/// * `registration` - The aircraft registration, e.g. `G-EUPT`.
+/// 
+#[must_use]
+pub fn country_for_registration(registration: &str) -> Option<Country> {
+	let trimmed = registration.trim().as_bytes();
+	let matches: Vec<_> = NATIONALITY_MARKS.iter()
+		.filter(|entry| trimmed.get(..entry.1.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(entry.1.as_bytes())))
+		.collect();
+	let longest = matches.iter().map(|entry| entry.1.len()).max()?;
+	match *matches.iter().filter(|entry| entry.1.len() == longest).collect::<Vec<_>>().as_slice() {
+		[entry] => Some(entry.0.country()),
+		_       => None,
+	}
+}
@@ -31,24 +31,44 @@ mod tests;
 //		Packages
 
 use crate::{
+	AsStr,
+	aviation,
 	currency::CurrencyCode,
+	display::{DisplayStyle, Styled},
+	holiday::MonthDay,
 	language::LanguageCode,
+	map::{SortedMap, self},
+	parse::{Parse, ParseOptions},
+	registry,
+	telephony::NumberingPlan,
+	tax::TaxIdFormat,
+	text::{matches_format, normalize, similarity, stable_hash},
+	zones::MonetaryZone,
 };
 use core::{
+	any::Any,
+	cmp::Ordering,
 	fmt::{Debug, Display, self},
 	str::FromStr,
 };
-use rubedo::{
-	std::AsStr,
-	sugar::{s, vh},
-};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 use std::{
-	collections::{HashMap, HashSet},
+	collections::HashSet,
 	sync::LazyLock,
 };
-use velcro::hash_map;
 
+#[cfg(feature = "orgs")]
+use crate::orgs::Org;
+#[cfg(feature = "chrono-tz")]
+use chrono::{DateTime, Utc};
+#[cfg(feature = "chrono-tz")]
+use chrono_tz::Tz;
+#[cfg(feature = "defmt")]
+use defmt::{Format, Formatter};
+#[cfg(feature = "icu")]
+use icu_locale_core::subtags;
+#[cfg(feature = "json")]
+use serde_json::Value;
 #[cfg(feature = "utoipa")]
 use utoipa::ToSchema;
 
@@ -56,8 +76,83 @@ use utoipa::ToSchema;
 
 //		Constants
 
+/// The number of countries.
+/// 
+/// This is the length of [`Country::all()`], and of the other lists of all the
+/// countries, and can be used to pre-allocate storage, or to check the expected
+/// size of the dataset.
+/// 
+pub const COUNT: usize = 249;
+
+/// The ISO 3166-1 alpha-2 codes of all the countries.
+/// 
+/// The codes are in alphabetical order, and can be used directly, e.g. for
+/// validation or for populating drop-down lists, without needing to map over
+/// [`Country::all()`] at runtime. The codes in [`ALPHA3_CODES`] and
+/// [`NUMERIC_CODES`] are in the same order, so that the codes of each country
+/// are at the same position in each.
+/// 
+pub const ALPHA2_CODES: &[&str] = &[
+	"AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ", "BA", "BB", "BD", "BE",
+	"BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS", "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD",
+	"CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN", "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM",
+	"DO", "DZ", "EC", "EE", "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+	"GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM", "HN", "HR", "HT", "HU",
+	"ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM", "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN",
+	"KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC", "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME",
+	"MF", "MG", "MH", "MK", "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+	"NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG", "PH", "PK", "PL", "PM",
+	"PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW", "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI",
+	"SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS", "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK",
+	"TL", "TM", "TN", "TO", "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+	"VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// The ISO 3166-1 alpha-3 codes of all the countries.
+/// 
+/// The codes are in the same order as [`ALPHA2_CODES`], i.e. by alpha-2 code.
+/// 
+pub const ALPHA3_CODES: &[&str] = &[
+	"AND", "ARE", "AFG", "ATG", "AIA", "ALB", "ARM", "AGO", "ATA", "ARG", "ASM", "AUT", "AUS", "ABW", "ALA", "AZE", "BIH", "BRB", "BGD", "BEL",
+	"BFA", "BGR", "BHR", "BDI", "BEN", "BLM", "BMU", "BRN", "BOL", "BES", "BRA", "BHS", "BTN", "BVT", "BWA", "BLR", "BLZ", "CAN", "CCK", "COD",
+	"CAF", "COG", "CHE", "CIV", "COK", "CHL", "CMR", "CHN", "COL", "CRI", "CUB", "CPV", "CUW", "CXR", "CYP", "CZE", "DEU", "DJI", "DNK", "DMA",
+	"DOM", "DZA", "ECU", "EST", "EGY", "ESH", "ERI", "ESP", "ETH", "FIN", "FJI", "FLK", "FSM", "FRO", "FRA", "GAB", "GBR", "GRD", "GEO", "GUF",
+	"GGY", "GHA", "GIB", "GRL", "GMB", "GIN", "GLP", "GNQ", "GRC", "SGS", "GTM", "GUM", "GNB", "GUY", "HKG", "HMD", "HND", "HRV", "HTI", "HUN",
+	"IDN", "IRL", "ISR", "IMN", "IND", "IOT", "IRQ", "IRN", "ISL", "ITA", "JEY", "JAM", "JOR", "JPN", "KEN", "KGZ", "KHM", "KIR", "COM", "KNA",
+	"PRK", "KOR", "KWT", "CYM", "KAZ", "LAO", "LBN", "LCA", "LIE", "LKA", "LBR", "LSO", "LTU", "LUX", "LVA", "LBY", "MAR", "MCO", "MDA", "MNE",
+	"MAF", "MDG", "MHL", "MKD", "MLI", "MMR", "MNG", "MAC", "MNP", "MTQ", "MRT", "MSR", "MLT", "MUS", "MDV", "MWI", "MEX", "MYS", "MOZ", "NAM",
+	"NCL", "NER", "NFK", "NGA", "NIC", "NLD", "NOR", "NPL", "NRU", "NIU", "NZL", "OMN", "PAN", "PER", "PYF", "PNG", "PHL", "PAK", "POL", "SPM",
+	"PCN", "PRI", "PSE", "PRT", "PLW", "PRY", "QAT", "REU", "ROU", "SRB", "RUS", "RWA", "SAU", "SLB", "SYC", "SDN", "SWE", "SGP", "SHN", "SVN",
+	"SJM", "SVK", "SLE", "SMR", "SEN", "SOM", "SUR", "SSD", "STP", "SLV", "SXM", "SYR", "SWZ", "TCA", "TCD", "ATF", "TGO", "THA", "TJK", "TKL",
+	"TLS", "TKM", "TUN", "TON", "TUR", "TTO", "TUV", "TWN", "TZA", "UKR", "UGA", "UMI", "USA", "URY", "UZB", "VAT", "VCT", "VEN", "VGB", "VIR",
+	"VNM", "VUT", "WLF", "WSM", "YEM", "MYT", "ZAF", "ZMB", "ZWE",
+];
+
+/// The ISO 3166-1 numeric codes of all the countries.
+/// 
+/// The codes are in the same order as [`ALPHA2_CODES`], i.e. by alpha-2 code.
+/// 
+pub const NUMERIC_CODES: &[u16] = &[
+	 20, 784,   4,  28, 660,   8,  51,  24,  10,  32,  16,  40,  36, 533, 248,  31,  70,  52,  50,  56,
+	854, 100,  48, 108, 204, 652,  60,  96,  68, 535,  76,  44,  64,  74,  72, 112,  84, 124, 166, 180,
+	140, 178, 756, 384, 184, 152, 120, 156, 170, 188, 192, 132, 531, 162, 196, 203, 276, 262, 208, 212,
+	214,  12, 218, 233, 818, 732, 232, 724, 231, 246, 242, 238, 583, 234, 250, 266, 826, 308, 268, 254,
+	831, 288, 292, 304, 270, 324, 312, 226, 300, 239, 320, 316, 624, 328, 344, 334, 340, 191, 332, 348,
+	360, 372, 376, 833, 356,  86, 368, 364, 352, 380, 832, 388, 400, 392, 404, 417, 116, 296, 174, 659,
+	408, 410, 414, 136, 398, 418, 422, 662, 438, 144, 430, 426, 440, 442, 428, 434, 504, 492, 498, 499,
+	663, 450, 584, 807, 466, 104, 496, 446, 580, 474, 478, 500, 470, 480, 462, 454, 484, 458, 508, 516,
+	540, 562, 574, 566, 558, 528, 578, 524, 520, 570, 554, 512, 591, 604, 258, 598, 608, 586, 616, 666,
+	612, 630, 275, 620, 585, 600, 634, 638, 642, 688, 643, 646, 682,  90, 690, 729, 752, 702, 654, 705,
+	744, 703, 694, 674, 686, 706, 740, 728, 678, 222, 534, 760, 748, 796, 148, 260, 768, 764, 762, 772,
+	626, 795, 788, 776, 792, 780, 798, 158, 834, 804, 800, 581, 840, 858, 860, 336, 670, 862,  92, 850,
+	704, 548, 876, 882, 887, 175, 710, 894, 716,
+];
+
 /// The possible countries.
 /// 
+/// The entries are in the order of the [`Country`] discriminants, which is relied
+/// upon for lookups, so new entries must be added to the end.
+/// 
 /// # Data sources
 ///
 /// The list of codes and other country information is available from
@@ -69,260 +164,1222 @@ use utoipa::ToSchema;
 /// * [`CountryCode`]
 /// * [`Country`]
 /// 
-static COUNTRIES: LazyLock<HashMap<Country, CountryInfo>> = LazyLock::new(|| {
-	hash_map!{
-		Country::AD: CountryInfo { code: CountryCode::AD, name: s!("Andorra"),                                              currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: CA ] },
-		Country::AE: CountryInfo { code: CountryCode::AE, name: s!("United Arab Emirates"),                                 currencies: vh![ CurrencyCode: AED ],           languages: vh![ LanguageCode: AR ] },
-		Country::AF: CountryInfo { code: CountryCode::AF, name: s!("Afghanistan"),                                          currencies: vh![ CurrencyCode: AFN ],           languages: vh![ LanguageCode: FA, PS ] },
-		Country::AG: CountryInfo { code: CountryCode::AG, name: s!("Antigua and Barbuda"),                                  currencies: vh![ CurrencyCode: XCD ],           languages: vh![ LanguageCode: EN ] },
-		Country::AI: CountryInfo { code: CountryCode::AI, name: s!("Anguilla"),                                             currencies: vh![ CurrencyCode: XCD ],           languages: vh![ LanguageCode: EN ] },
-		Country::AL: CountryInfo { code: CountryCode::AL, name: s!("Albania"),                                              currencies: vh![ CurrencyCode: ALL ],           languages: vh![ LanguageCode: SQ ] },
-		Country::AM: CountryInfo { code: CountryCode::AM, name: s!("Armenia"),                                              currencies: vh![ CurrencyCode: AMD ],           languages: vh![ LanguageCode: HY ] },
-		Country::AO: CountryInfo { code: CountryCode::AO, name: s!("Angola"),                                               currencies: vh![ CurrencyCode: AOA ],           languages: vh![ LanguageCode: PT ] },
-		Country::AQ: CountryInfo { code: CountryCode::AQ, name: s!("Antarctica"),                                           currencies: vh![],                              languages: vh![] },
-		Country::AR: CountryInfo { code: CountryCode::AR, name: s!("Argentina"),                                            currencies: vh![ CurrencyCode: ARS ],           languages: vh![ LanguageCode: ES ] },
-		Country::AS: CountryInfo { code: CountryCode::AS, name: s!("American Samoa"),                                       currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: EN, SM ] },
-		Country::AT: CountryInfo { code: CountryCode::AT, name: s!("Austria"),                                              currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: DE ] },
-		Country::AU: CountryInfo { code: CountryCode::AU, name: s!("Australia"),                                            currencies: vh![ CurrencyCode: AUD ],           languages: vh![ LanguageCode: EN ] },
-		Country::AW: CountryInfo { code: CountryCode::AW, name: s!("Aruba"),                                                currencies: vh![ CurrencyCode: AWG ],           languages: vh![ LanguageCode: NL ] },
-		Country::AX: CountryInfo { code: CountryCode::AX, name: s!("Åland Islands"),                                        currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: SV ] },
-		Country::AZ: CountryInfo { code: CountryCode::AZ, name: s!("Azerbaijan"),                                           currencies: vh![ CurrencyCode: AZN ],           languages: vh![ LanguageCode: AZ ] },
-		Country::BA: CountryInfo { code: CountryCode::BA, name: s!("Bosnia and Herzegovina"),                               currencies: vh![ CurrencyCode: BAM ],           languages: vh![ LanguageCode: BS, HR, SR ] },
-		Country::BB: CountryInfo { code: CountryCode::BB, name: s!("Barbados"),                                             currencies: vh![ CurrencyCode: BBD ],           languages: vh![ LanguageCode: EN ] },
-		Country::BD: CountryInfo { code: CountryCode::BD, name: s!("Bangladesh"),                                           currencies: vh![ CurrencyCode: BDT ],           languages: vh![ LanguageCode: BN ] },
-		Country::BE: CountryInfo { code: CountryCode::BE, name: s!("Belgium"),                                              currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: DE, FR, NL ] },
-		Country::BF: CountryInfo { code: CountryCode::BF, name: s!("Burkina Faso"),                                         currencies: vh![ CurrencyCode: XOF ],           languages: vh![ LanguageCode: FR ] },
-		Country::BG: CountryInfo { code: CountryCode::BG, name: s!("Bulgaria"),                                             currencies: vh![ CurrencyCode: BGN ],           languages: vh![ LanguageCode: BG ] },
-		Country::BH: CountryInfo { code: CountryCode::BH, name: s!("Bahrain"),                                              currencies: vh![ CurrencyCode: BHD ],           languages: vh![ LanguageCode: AR ] },
-		Country::BI: CountryInfo { code: CountryCode::BI, name: s!("Burundi"),                                              currencies: vh![ CurrencyCode: BIF ],           languages: vh![ LanguageCode: EN, FR, RN ] },
-		Country::BJ: CountryInfo { code: CountryCode::BJ, name: s!("Benin"),                                                currencies: vh![ CurrencyCode: XOF ],           languages: vh![ LanguageCode: FR ] },
-		Country::BL: CountryInfo { code: CountryCode::BL, name: s!("Saint Barthélemy"),                                     currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FR ] },
-		Country::BM: CountryInfo { code: CountryCode::BM, name: s!("Bermuda"),                                              currencies: vh![ CurrencyCode: BMD ],           languages: vh![ LanguageCode: EN ] },
-		Country::BN: CountryInfo { code: CountryCode::BN, name: s!("Brunei Darussalam"),                                    currencies: vh![ CurrencyCode: BND ],           languages: vh![ LanguageCode: MS ] },
-		Country::BO: CountryInfo { code: CountryCode::BO, name: s!("Bolivia (Plurinational State of)"),                     currencies: vh![ CurrencyCode: BOB, BOV ],      languages: vh![ LanguageCode: AY, ES, GN, QU ] },
-		Country::BQ: CountryInfo { code: CountryCode::BQ, name: s!("Bonaire, Sint Eustatius and Saba"),                     currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: NL ] },
-		Country::BR: CountryInfo { code: CountryCode::BR, name: s!("Brazil"),                                               currencies: vh![ CurrencyCode: BRL ],           languages: vh![ LanguageCode: PT ] },
-		Country::BS: CountryInfo { code: CountryCode::BS, name: s!("Bahamas"),                                              currencies: vh![ CurrencyCode: BSD ],           languages: vh![ LanguageCode: EN ] },
-		Country::BT: CountryInfo { code: CountryCode::BT, name: s!("Bhutan"),                                               currencies: vh![ CurrencyCode: BTN, INR ],      languages: vh![ LanguageCode: DZ ] },
-		Country::BV: CountryInfo { code: CountryCode::BV, name: s!("Bouvet Island"),                                        currencies: vh![ CurrencyCode: NOK ],           languages: vh![ LanguageCode: NO ] },
-		Country::BW: CountryInfo { code: CountryCode::BW, name: s!("Botswana"),                                             currencies: vh![ CurrencyCode: BWP ],           languages: vh![ LanguageCode: EN ] },
-		Country::BY: CountryInfo { code: CountryCode::BY, name: s!("Belarus"),                                              currencies: vh![ CurrencyCode: BYN ],           languages: vh![ LanguageCode: BE, RU ] },
-		Country::BZ: CountryInfo { code: CountryCode::BZ, name: s!("Belize"),                                               currencies: vh![ CurrencyCode: BZD ],           languages: vh![ LanguageCode: EN ] },
-		Country::CA: CountryInfo { code: CountryCode::CA, name: s!("Canada"),                                               currencies: vh![ CurrencyCode: CAD ],           languages: vh![ LanguageCode: EN, FR ] },
-		Country::CC: CountryInfo { code: CountryCode::CC, name: s!("Cocos (Keeling) Islands"),                              currencies: vh![ CurrencyCode: AUD ],           languages: vh![ LanguageCode: EN, MS ] },
-		Country::CD: CountryInfo { code: CountryCode::CD, name: s!("Congo, Democratic Republic of the"),                    currencies: vh![ CurrencyCode: CDF ],           languages: vh![ LanguageCode: FR ] },
-		Country::CF: CountryInfo { code: CountryCode::CF, name: s!("Central African Republic"),                             currencies: vh![ CurrencyCode: XAF ],           languages: vh![ LanguageCode: FR, SG ] },
-		Country::CG: CountryInfo { code: CountryCode::CG, name: s!("Congo"),                                                currencies: vh![ CurrencyCode: XAF ],           languages: vh![ LanguageCode: FR ] },
-		Country::CH: CountryInfo { code: CountryCode::CH, name: s!("Switzerland"),                                          currencies: vh![ CurrencyCode: CHE, CHF, CHW ], languages: vh![ LanguageCode: DE, FR, IT, RM ] },
-		Country::CI: CountryInfo { code: CountryCode::CI, name: s!("Côte d'Ivoire"),                                        currencies: vh![ CurrencyCode: XOF ],           languages: vh![ LanguageCode: FR ] },
-		Country::CK: CountryInfo { code: CountryCode::CK, name: s!("Cook Islands"),                                         currencies: vh![ CurrencyCode: NZD ],           languages: vh![ LanguageCode: EN ] },
-		Country::CL: CountryInfo { code: CountryCode::CL, name: s!("Chile"),                                                currencies: vh![ CurrencyCode: CLF, CLP ],      languages: vh![ LanguageCode: ES ] },
-		Country::CM: CountryInfo { code: CountryCode::CM, name: s!("Cameroon"),                                             currencies: vh![ CurrencyCode: XAF ],           languages: vh![ LanguageCode: EN, FR ] },
-		Country::CN: CountryInfo { code: CountryCode::CN, name: s!("China"),                                                currencies: vh![ CurrencyCode: CNY ],           languages: vh![ LanguageCode: ZH ] },
-		Country::CO: CountryInfo { code: CountryCode::CO, name: s!("Colombia"),                                             currencies: vh![ CurrencyCode: COP, COU ],      languages: vh![ LanguageCode: ES ] },
-		Country::CR: CountryInfo { code: CountryCode::CR, name: s!("Costa Rica"),                                           currencies: vh![ CurrencyCode: CRC ],           languages: vh![ LanguageCode: ES ] },
-		Country::CU: CountryInfo { code: CountryCode::CU, name: s!("Cuba"),                                                 currencies: vh![ CurrencyCode: CUP ],           languages: vh![ LanguageCode: ES ] },
-		Country::CV: CountryInfo { code: CountryCode::CV, name: s!("Cabo Verde"),                                           currencies: vh![ CurrencyCode: CVE ],           languages: vh![ LanguageCode: PT ] },
-		Country::CW: CountryInfo { code: CountryCode::CW, name: s!("Curaçao"),                                              currencies: vh![ CurrencyCode: ANG ],           languages: vh![ LanguageCode: EN, NL ] },
-		Country::CX: CountryInfo { code: CountryCode::CX, name: s!("Christmas Island"),                                     currencies: vh![ CurrencyCode: AUD ],           languages: vh![ LanguageCode: EN, MS, ZH ] },
-		Country::CY: CountryInfo { code: CountryCode::CY, name: s!("Cyprus"),                                               currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: EL, TR ] },
-		Country::CZ: CountryInfo { code: CountryCode::CZ, name: s!("Czechia"),                                              currencies: vh![ CurrencyCode: CZK ],           languages: vh![ LanguageCode: CS, SK ] },
-		Country::DE: CountryInfo { code: CountryCode::DE, name: s!("Germany"),                                              currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: DE ] },
-		Country::DJ: CountryInfo { code: CountryCode::DJ, name: s!("Djibouti"),                                             currencies: vh![ CurrencyCode: DJF ],           languages: vh![ LanguageCode: AR, FR ] },
-		Country::DK: CountryInfo { code: CountryCode::DK, name: s!("Denmark"),                                              currencies: vh![ CurrencyCode: DKK ],           languages: vh![ LanguageCode: DA ] },
-		Country::DM: CountryInfo { code: CountryCode::DM, name: s!("Dominica"),                                             currencies: vh![ CurrencyCode: XCD ],           languages: vh![ LanguageCode: EN ] },
-		Country::DO: CountryInfo { code: CountryCode::DO, name: s!("Dominican Republic"),                                   currencies: vh![ CurrencyCode: DOP ],           languages: vh![ LanguageCode: ES ] },
-		Country::DZ: CountryInfo { code: CountryCode::DZ, name: s!("Algeria"),                                              currencies: vh![ CurrencyCode: DZD ],           languages: vh![ LanguageCode: AR ] },
-		Country::EC: CountryInfo { code: CountryCode::EC, name: s!("Ecuador"),                                              currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: ES, QU ] },
-		Country::EE: CountryInfo { code: CountryCode::EE, name: s!("Estonia"),                                              currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: ET ] },
-		Country::EG: CountryInfo { code: CountryCode::EG, name: s!("Egypt"),                                                currencies: vh![ CurrencyCode: EGP ],           languages: vh![ LanguageCode: AR ] },
-		Country::EH: CountryInfo { code: CountryCode::EH, name: s!("Western Sahara"),                                       currencies: vh![ CurrencyCode: MAD ],           languages: vh![ LanguageCode: AR, ES ] },
-		Country::ER: CountryInfo { code: CountryCode::ER, name: s!("Eritrea"),                                              currencies: vh![ CurrencyCode: ERN ],           languages: vh![ LanguageCode: TI ] },
-		Country::ES: CountryInfo { code: CountryCode::ES, name: s!("Spain"),                                                currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: ES ] },
-		Country::ET: CountryInfo { code: CountryCode::ET, name: s!("Ethiopia"),                                             currencies: vh![ CurrencyCode: ETB ],           languages: vh![ LanguageCode: AA, AM, OM, SO, TI ] },
-		Country::FI: CountryInfo { code: CountryCode::FI, name: s!("Finland"),                                              currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FI, SV ] },
-		Country::FJ: CountryInfo { code: CountryCode::FJ, name: s!("Fiji"),                                                 currencies: vh![ CurrencyCode: FJD ],           languages: vh![ LanguageCode: EN, FJ ] },
-		Country::FK: CountryInfo { code: CountryCode::FK, name: s!("Falkland Islands (Malvinas)"),                          currencies: vh![ CurrencyCode: FKP ],           languages: vh![ LanguageCode: EN ] },
-		Country::FM: CountryInfo { code: CountryCode::FM, name: s!("Micronesia (Federated States of)"),                     currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: EN ] },
-		Country::FO: CountryInfo { code: CountryCode::FO, name: s!("Faroe Islands"),                                        currencies: vh![ CurrencyCode: DKK ],           languages: vh![ LanguageCode: DA, FO ] },
-		Country::FR: CountryInfo { code: CountryCode::FR, name: s!("France"),                                               currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FR ] },
-		Country::GA: CountryInfo { code: CountryCode::GA, name: s!("Gabon"),                                                currencies: vh![ CurrencyCode: XAF ],           languages: vh![ LanguageCode: FR ] },
-		Country::GB: CountryInfo { code: CountryCode::GB, name: s!("United Kingdom of Great Britain and Northern Ireland"), currencies: vh![ CurrencyCode: GBP ],           languages: vh![ LanguageCode: EN ] },
-		Country::GD: CountryInfo { code: CountryCode::GD, name: s!("Grenada"),                                              currencies: vh![ CurrencyCode: XCD ],           languages: vh![ LanguageCode: EN ] },
-		Country::GE: CountryInfo { code: CountryCode::GE, name: s!("Georgia"),                                              currencies: vh![ CurrencyCode: GEL ],           languages: vh![ LanguageCode: KA ] },
-		Country::GF: CountryInfo { code: CountryCode::GF, name: s!("French Guiana"),                                        currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FR ] },
-		Country::GG: CountryInfo { code: CountryCode::GG, name: s!("Guernsey"),                                             currencies: vh![ CurrencyCode: GBP ],           languages: vh![ LanguageCode: EN ] },
-		Country::GH: CountryInfo { code: CountryCode::GH, name: s!("Ghana"),                                                currencies: vh![ CurrencyCode: GHS ],           languages: vh![ LanguageCode: EN ] },
-		Country::GI: CountryInfo { code: CountryCode::GI, name: s!("Gibraltar"),                                            currencies: vh![ CurrencyCode: GIP ],           languages: vh![ LanguageCode: EN ] },
-		Country::GL: CountryInfo { code: CountryCode::GL, name: s!("Greenland"),                                            currencies: vh![ CurrencyCode: DKK ],           languages: vh![ LanguageCode: DA, EN ] },
-		Country::GM: CountryInfo { code: CountryCode::GM, name: s!("Gambia"),                                               currencies: vh![ CurrencyCode: GMD ],           languages: vh![ LanguageCode: EN ] },
-		Country::GN: CountryInfo { code: CountryCode::GN, name: s!("Guinea"),                                               currencies: vh![ CurrencyCode: GNF ],           languages: vh![ LanguageCode: FR ] },
-		Country::GP: CountryInfo { code: CountryCode::GP, name: s!("Guadeloupe"),                                           currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FR ] },
-		Country::GQ: CountryInfo { code: CountryCode::GQ, name: s!("Equatorial Guinea"),                                    currencies: vh![ CurrencyCode: XAF ],           languages: vh![ LanguageCode: ES, FR, PT ] },
-		Country::GR: CountryInfo { code: CountryCode::GR, name: s!("Greece"),                                               currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: EL ] },
-		Country::GS: CountryInfo { code: CountryCode::GS, name: s!("South Georgia and the South Sandwich Islands"),         currencies: vh![],                              languages: vh![ LanguageCode: EN ] },
-		Country::GT: CountryInfo { code: CountryCode::GT, name: s!("Guatemala"),                                            currencies: vh![ CurrencyCode: GTQ ],           languages: vh![ LanguageCode: ES ] },
-		Country::GU: CountryInfo { code: CountryCode::GU, name: s!("Guam"),                                                 currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: CH, EN ] },
-		Country::GW: CountryInfo { code: CountryCode::GW, name: s!("Guinea-Bissau"),                                        currencies: vh![ CurrencyCode: XOF ],           languages: vh![ LanguageCode: PT ] },
-		Country::GY: CountryInfo { code: CountryCode::GY, name: s!("Guyana"),                                               currencies: vh![ CurrencyCode: GYD ],           languages: vh![ LanguageCode: EN ] },
-		Country::HK: CountryInfo { code: CountryCode::HK, name: s!("Hong Kong"),                                            currencies: vh![ CurrencyCode: HKD ],           languages: vh![ LanguageCode: EN, ZH ] },
-		Country::HM: CountryInfo { code: CountryCode::HM, name: s!("Heard Island and McDonald Islands"),                    currencies: vh![ CurrencyCode: AUD ],           languages: vh![ LanguageCode: EN ] },
-		Country::HN: CountryInfo { code: CountryCode::HN, name: s!("Honduras"),                                             currencies: vh![ CurrencyCode: HNL ],           languages: vh![ LanguageCode: ES ] },
-		Country::HR: CountryInfo { code: CountryCode::HR, name: s!("Croatia"),                                              currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: HR ] },
-		Country::HT: CountryInfo { code: CountryCode::HT, name: s!("Haiti"),                                                currencies: vh![ CurrencyCode: HTG ],           languages: vh![ LanguageCode: FR, HT ] },
-		Country::HU: CountryInfo { code: CountryCode::HU, name: s!("Hungary"),                                              currencies: vh![ CurrencyCode: HUF ],           languages: vh![ LanguageCode: HU ] },
-		Country::ID: CountryInfo { code: CountryCode::ID, name: s!("Indonesia"),                                            currencies: vh![ CurrencyCode: IDR ],           languages: vh![ LanguageCode: ID ] },
-		Country::IE: CountryInfo { code: CountryCode::IE, name: s!("Ireland"),                                              currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: EN, GA ] },
-		Country::IL: CountryInfo { code: CountryCode::IL, name: s!("Israel"),                                               currencies: vh![ CurrencyCode: ILS ],           languages: vh![ LanguageCode: HE ] },
-		Country::IM: CountryInfo { code: CountryCode::IM, name: s!("Isle of Man"),                                          currencies: vh![ CurrencyCode: GBP ],           languages: vh![ LanguageCode: EN, GV ] },
-		Country::IN: CountryInfo { code: CountryCode::IN, name: s!("India"),                                                currencies: vh![ CurrencyCode: INR ],           languages: vh![ LanguageCode: EN, HI ] },
-		Country::IO: CountryInfo { code: CountryCode::IO, name: s!("British Indian Ocean Territory"),                       currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: EN ] },
-		Country::IQ: CountryInfo { code: CountryCode::IQ, name: s!("Iraq"),                                                 currencies: vh![ CurrencyCode: IQD ],           languages: vh![ LanguageCode: AR, KU ] },
-		Country::IR: CountryInfo { code: CountryCode::IR, name: s!("Iran (Islamic Republic of)"),                           currencies: vh![ CurrencyCode: IRR ],           languages: vh![ LanguageCode: FA ] },
-		Country::IS: CountryInfo { code: CountryCode::IS, name: s!("Iceland"),                                              currencies: vh![ CurrencyCode: ISK ],           languages: vh![ LanguageCode: IS ] },
-		Country::IT: CountryInfo { code: CountryCode::IT, name: s!("Italy"),                                                currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: IT ] },
-		Country::JE: CountryInfo { code: CountryCode::JE, name: s!("Jersey"),                                               currencies: vh![ CurrencyCode: GBP ],           languages: vh![ LanguageCode: EN, FR ] },
-		Country::JM: CountryInfo { code: CountryCode::JM, name: s!("Jamaica"),                                              currencies: vh![ CurrencyCode: JMD ],           languages: vh![ LanguageCode: EN ] },
-		Country::JO: CountryInfo { code: CountryCode::JO, name: s!("Jordan"),                                               currencies: vh![ CurrencyCode: JOD ],           languages: vh![ LanguageCode: AR ] },
-		Country::JP: CountryInfo { code: CountryCode::JP, name: s!("Japan"),                                                currencies: vh![ CurrencyCode: JPY ],           languages: vh![ LanguageCode: JA ] },
-		Country::KE: CountryInfo { code: CountryCode::KE, name: s!("Kenya"),                                                currencies: vh![ CurrencyCode: KES ],           languages: vh![ LanguageCode: EN, SW ] },
-		Country::KG: CountryInfo { code: CountryCode::KG, name: s!("Kyrgyzstan"),                                           currencies: vh![ CurrencyCode: KGS ],           languages: vh![ LanguageCode: KY, RU ] },
-		Country::KH: CountryInfo { code: CountryCode::KH, name: s!("Cambodia"),                                             currencies: vh![ CurrencyCode: KHR ],           languages: vh![ LanguageCode: KM ] },
-		Country::KI: CountryInfo { code: CountryCode::KI, name: s!("Kiribati"),                                             currencies: vh![ CurrencyCode: AUD ],           languages: vh![ LanguageCode: EN ] },
-		Country::KM: CountryInfo { code: CountryCode::KM, name: s!("Comoros"),                                              currencies: vh![ CurrencyCode: KMF ],           languages: vh![ LanguageCode: AR, FR ] },
-		Country::KN: CountryInfo { code: CountryCode::KN, name: s!("Saint Kitts and Nevis"),                                currencies: vh![ CurrencyCode: XCD ],           languages: vh![ LanguageCode: EN ] },
-		Country::KP: CountryInfo { code: CountryCode::KP, name: s!("Korea (Democratic People's Republic of)"),              currencies: vh![ CurrencyCode: KPW ],           languages: vh![ LanguageCode: KO ] },
-		Country::KR: CountryInfo { code: CountryCode::KR, name: s!("Korea, Republic of"),                                   currencies: vh![ CurrencyCode: KRW ],           languages: vh![ LanguageCode: KO ] },
-		Country::KW: CountryInfo { code: CountryCode::KW, name: s!("Kuwait"),                                               currencies: vh![ CurrencyCode: KWD ],           languages: vh![ LanguageCode: AR ] },
-		Country::KY: CountryInfo { code: CountryCode::KY, name: s!("Cayman Islands"),                                       currencies: vh![ CurrencyCode: KYD ],           languages: vh![ LanguageCode: EN ] },
-		Country::KZ: CountryInfo { code: CountryCode::KZ, name: s!("Kazakhstan"),                                           currencies: vh![ CurrencyCode: KZT ],           languages: vh![ LanguageCode: KK, RU ] },
-		Country::LA: CountryInfo { code: CountryCode::LA, name: s!("Lao People's Democratic Republic"),                     currencies: vh![ CurrencyCode: LAK ],           languages: vh![ LanguageCode: LO ] },
-		Country::LB: CountryInfo { code: CountryCode::LB, name: s!("Lebanon"),                                              currencies: vh![ CurrencyCode: LBP ],           languages: vh![ LanguageCode: AR ] },
-		Country::LC: CountryInfo { code: CountryCode::LC, name: s!("Saint Lucia"),                                          currencies: vh![ CurrencyCode: XCD ],           languages: vh![ LanguageCode: EN ] },
-		Country::LI: CountryInfo { code: CountryCode::LI, name: s!("Liechtenstein"),                                        currencies: vh![ CurrencyCode: CHF ],           languages: vh![ LanguageCode: DE ] },
-		Country::LK: CountryInfo { code: CountryCode::LK, name: s!("Sri Lanka"),                                            currencies: vh![ CurrencyCode: LKR ],           languages: vh![ LanguageCode: SI, TA ] },
-		Country::LR: CountryInfo { code: CountryCode::LR, name: s!("Liberia"),                                              currencies: vh![ CurrencyCode: LRD ],           languages: vh![ LanguageCode: EN ] },
-		Country::LS: CountryInfo { code: CountryCode::LS, name: s!("Lesotho"),                                              currencies: vh![ CurrencyCode: LSL, ZAR ],      languages: vh![ LanguageCode: EN, ST ] },
-		Country::LT: CountryInfo { code: CountryCode::LT, name: s!("Lithuania"),                                            currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: LT ] },
-		Country::LU: CountryInfo { code: CountryCode::LU, name: s!("Luxembourg"),                                           currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: DE, FR, LB ] },
-		Country::LV: CountryInfo { code: CountryCode::LV, name: s!("Latvia"),                                               currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: LV ] },
-		Country::LY: CountryInfo { code: CountryCode::LY, name: s!("Libya"),                                                currencies: vh![ CurrencyCode: LYD ],           languages: vh![ LanguageCode: AR ] },
-		Country::MA: CountryInfo { code: CountryCode::MA, name: s!("Morocco"),                                              currencies: vh![ CurrencyCode: MAD ],           languages: vh![ LanguageCode: AR ] },
-		Country::MC: CountryInfo { code: CountryCode::MC, name: s!("Monaco"),                                               currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FR ] },
-		Country::MD: CountryInfo { code: CountryCode::MD, name: s!("Moldova, Republic of"),                                 currencies: vh![ CurrencyCode: MDL ],           languages: vh![ LanguageCode: RO ] },
-		Country::ME: CountryInfo { code: CountryCode::ME, name: s!("Montenegro"),                                           currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: HR, SR ] },
-		Country::MF: CountryInfo { code: CountryCode::MF, name: s!("Saint Martin (French part)"),                           currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FR ] },
-		Country::MG: CountryInfo { code: CountryCode::MG, name: s!("Madagascar"),                                           currencies: vh![ CurrencyCode: MGA ],           languages: vh![ LanguageCode: FR, MG ] },
-		Country::MH: CountryInfo { code: CountryCode::MH, name: s!("Marshall Islands"),                                     currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: EN, MH ] },
-		Country::MK: CountryInfo { code: CountryCode::MK, name: s!("North Macedonia"),                                      currencies: vh![ CurrencyCode: MKD ],           languages: vh![ LanguageCode: MK, SQ ] },
-		Country::ML: CountryInfo { code: CountryCode::ML, name: s!("Mali"),                                                 currencies: vh![ CurrencyCode: XOF ],           languages: vh![ LanguageCode: BM, FF ] },
-		Country::MM: CountryInfo { code: CountryCode::MM, name: s!("Myanmar"),                                              currencies: vh![ CurrencyCode: MMK ],           languages: vh![ LanguageCode: MY ] },
-		Country::MN: CountryInfo { code: CountryCode::MN, name: s!("Mongolia"),                                             currencies: vh![ CurrencyCode: MNT ],           languages: vh![ LanguageCode: MN ] },
-		Country::MO: CountryInfo { code: CountryCode::MO, name: s!("Macao"),                                                currencies: vh![ CurrencyCode: MOP ],           languages: vh![ LanguageCode: PT, ZH ] },
-		Country::MP: CountryInfo { code: CountryCode::MP, name: s!("Northern Mariana Islands"),                             currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: CH, EN ] },
-		Country::MQ: CountryInfo { code: CountryCode::MQ, name: s!("Martinique"),                                           currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FR ] },
-		Country::MR: CountryInfo { code: CountryCode::MR, name: s!("Mauritania"),                                           currencies: vh![ CurrencyCode: MRU ],           languages: vh![ LanguageCode: AR ] },
-		Country::MS: CountryInfo { code: CountryCode::MS, name: s!("Montserrat"),                                           currencies: vh![ CurrencyCode: XCD ],           languages: vh![ LanguageCode: EN ] },
-		Country::MT: CountryInfo { code: CountryCode::MT, name: s!("Malta"),                                                currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: EN, MT ] },
-		Country::MU: CountryInfo { code: CountryCode::MU, name: s!("Mauritius"),                                            currencies: vh![ CurrencyCode: MUR ],           languages: vh![ LanguageCode: EN ] },
-		Country::MV: CountryInfo { code: CountryCode::MV, name: s!("Maldives"),                                             currencies: vh![ CurrencyCode: MVR ],           languages: vh![ LanguageCode: DV ] },
-		Country::MW: CountryInfo { code: CountryCode::MW, name: s!("Malawi"),                                               currencies: vh![ CurrencyCode: MWK ],           languages: vh![ LanguageCode: EN, NY ] },
-		Country::MX: CountryInfo { code: CountryCode::MX, name: s!("Mexico"),                                               currencies: vh![ CurrencyCode: MXN, MXV ],      languages: vh![ LanguageCode: ES ] },
-		Country::MY: CountryInfo { code: CountryCode::MY, name: s!("Malaysia"),                                             currencies: vh![ CurrencyCode: MYR ],           languages: vh![ LanguageCode: MS ] },
-		Country::MZ: CountryInfo { code: CountryCode::MZ, name: s!("Mozambique"),                                           currencies: vh![ CurrencyCode: MZN ],           languages: vh![ LanguageCode: PT ] },
-		Country::NA: CountryInfo { code: CountryCode::NA, name: s!("Namibia"),                                              currencies: vh![ CurrencyCode: NAD, ZAR ],      languages: vh![ LanguageCode: EN ] },
-		Country::NC: CountryInfo { code: CountryCode::NC, name: s!("New Caledonia"),                                        currencies: vh![ CurrencyCode: XPF ],           languages: vh![ LanguageCode: FR ] },
-		Country::NE: CountryInfo { code: CountryCode::NE, name: s!("Niger"),                                                currencies: vh![ CurrencyCode: XOF ],           languages: vh![ LanguageCode: FR ] },
-		Country::NF: CountryInfo { code: CountryCode::NF, name: s!("Norfolk Island"),                                       currencies: vh![ CurrencyCode: AUD ],           languages: vh![ LanguageCode: EN ] },
-		Country::NG: CountryInfo { code: CountryCode::NG, name: s!("Nigeria"),                                              currencies: vh![ CurrencyCode: NGN ],           languages: vh![ LanguageCode: EN ] },
-		Country::NI: CountryInfo { code: CountryCode::NI, name: s!("Nicaragua"),                                            currencies: vh![ CurrencyCode: NIO ],           languages: vh![ LanguageCode: ES ] },
-		Country::NL: CountryInfo { code: CountryCode::NL, name: s!("Netherlands, Kingdom of the"),                          currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: NL ] },
-		Country::NO: CountryInfo { code: CountryCode::NO, name: s!("Norway"),                                               currencies: vh![ CurrencyCode: NOK ],           languages: vh![ LanguageCode: NO ] },
-		Country::NP: CountryInfo { code: CountryCode::NP, name: s!("Nepal"),                                                currencies: vh![ CurrencyCode: NPR ],           languages: vh![ LanguageCode: NE ] },
-		Country::NR: CountryInfo { code: CountryCode::NR, name: s!("Nauru"),                                                currencies: vh![ CurrencyCode: AUD ],           languages: vh![ LanguageCode: EN, NA ] },
-		Country::NU: CountryInfo { code: CountryCode::NU, name: s!("Niue"),                                                 currencies: vh![ CurrencyCode: NZD ],           languages: vh![ LanguageCode: EN ] },
-		Country::NZ: CountryInfo { code: CountryCode::NZ, name: s!("New Zealand"),                                          currencies: vh![ CurrencyCode: NZD ],           languages: vh![ LanguageCode: EN, MI ] },
-		Country::OM: CountryInfo { code: CountryCode::OM, name: s!("Oman"),                                                 currencies: vh![ CurrencyCode: OMR ],           languages: vh![ LanguageCode: AR ] },
-		Country::PA: CountryInfo { code: CountryCode::PA, name: s!("Panama"),                                               currencies: vh![ CurrencyCode: PAB, USD ],      languages: vh![ LanguageCode: ES ] },
-		Country::PE: CountryInfo { code: CountryCode::PE, name: s!("Peru"),                                                 currencies: vh![ CurrencyCode: PEN ],           languages: vh![ LanguageCode: AY, ES, QU ] },
-		Country::PF: CountryInfo { code: CountryCode::PF, name: s!("French Polynesia"),                                     currencies: vh![ CurrencyCode: XPF ],           languages: vh![ LanguageCode: FR ] },
-		Country::PG: CountryInfo { code: CountryCode::PG, name: s!("Papua New Guinea"),                                     currencies: vh![ CurrencyCode: PGK ],           languages: vh![ LanguageCode: EN, HO ] },
-		Country::PH: CountryInfo { code: CountryCode::PH, name: s!("Philippines"),                                          currencies: vh![ CurrencyCode: PHP ],           languages: vh![ LanguageCode: EN, TL ] },
-		Country::PK: CountryInfo { code: CountryCode::PK, name: s!("Pakistan"),                                             currencies: vh![ CurrencyCode: PKR ],           languages: vh![ LanguageCode: EN, UR ] },
-		Country::PL: CountryInfo { code: CountryCode::PL, name: s!("Poland"),                                               currencies: vh![ CurrencyCode: PLN ],           languages: vh![ LanguageCode: PL ] },
-		Country::PM: CountryInfo { code: CountryCode::PM, name: s!("Saint Pierre and Miquelon"),                            currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FR ] },
-		Country::PN: CountryInfo { code: CountryCode::PN, name: s!("Pitcairn"),                                             currencies: vh![ CurrencyCode: NZD ],           languages: vh![ LanguageCode: EN ] },
-		Country::PR: CountryInfo { code: CountryCode::PR, name: s!("Puerto Rico"),                                          currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: EN, ES ] },
-		Country::PS: CountryInfo { code: CountryCode::PS, name: s!("Palestine, State of"),                                  currencies: vh![],                              languages: vh![ LanguageCode: AR ] },
-		Country::PT: CountryInfo { code: CountryCode::PT, name: s!("Portugal"),                                             currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: PT ] },
-		Country::PW: CountryInfo { code: CountryCode::PW, name: s!("Palau"),                                                currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: EN ] },
-		Country::PY: CountryInfo { code: CountryCode::PY, name: s!("Paraguay"),                                             currencies: vh![ CurrencyCode: PYG ],           languages: vh![ LanguageCode: ES, GN ] },
-		Country::QA: CountryInfo { code: CountryCode::QA, name: s!("Qatar"),                                                currencies: vh![ CurrencyCode: QAR ],           languages: vh![ LanguageCode: AR ] },
-		Country::RE: CountryInfo { code: CountryCode::RE, name: s!("Réunion"),                                              currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FR ] },
-		Country::RO: CountryInfo { code: CountryCode::RO, name: s!("Romania"),                                              currencies: vh![ CurrencyCode: RON ],           languages: vh![ LanguageCode: RO ] },
-		Country::RS: CountryInfo { code: CountryCode::RS, name: s!("Serbia"),                                               currencies: vh![ CurrencyCode: RSD ],           languages: vh![ LanguageCode: SR ] },
-		Country::RU: CountryInfo { code: CountryCode::RU, name: s!("Russian Federation"),                                   currencies: vh![ CurrencyCode: RUB ],           languages: vh![ LanguageCode: RU ] },
-		Country::RW: CountryInfo { code: CountryCode::RW, name: s!("Rwanda"),                                               currencies: vh![ CurrencyCode: RWF ],           languages: vh![ LanguageCode: EN, FR, RW, SW ] },
-		Country::SA: CountryInfo { code: CountryCode::SA, name: s!("Saudi Arabia"),                                         currencies: vh![ CurrencyCode: SAR ],           languages: vh![ LanguageCode: AR ] },
-		Country::SB: CountryInfo { code: CountryCode::SB, name: s!("Solomon Islands"),                                      currencies: vh![ CurrencyCode: SBD ],           languages: vh![ LanguageCode: EN ] },
-		Country::SC: CountryInfo { code: CountryCode::SC, name: s!("Seychelles"),                                           currencies: vh![ CurrencyCode: SCR ],           languages: vh![ LanguageCode: EN, FR ] },
-		Country::SD: CountryInfo { code: CountryCode::SD, name: s!("Sudan"),                                                currencies: vh![ CurrencyCode: SDG ],           languages: vh![ LanguageCode: AR, EN ] },
-		Country::SE: CountryInfo { code: CountryCode::SE, name: s!("Sweden"),                                               currencies: vh![ CurrencyCode: SEK ],           languages: vh![ LanguageCode: SV ] },
-		Country::SG: CountryInfo { code: CountryCode::SG, name: s!("Singapore"),                                            currencies: vh![ CurrencyCode: SGD ],           languages: vh![ LanguageCode: EN, MS, TA, ZH ] },
-		Country::SH: CountryInfo { code: CountryCode::SH, name: s!("Saint Helena, Ascension and Tristan da Cunha"),         currencies: vh![ CurrencyCode: GBP, SHP ],      languages: vh![ LanguageCode: EN ] },
-		Country::SI: CountryInfo { code: CountryCode::SI, name: s!("Slovenia"),                                             currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: SL ] },
-		Country::SJ: CountryInfo { code: CountryCode::SJ, name: s!("Svalbard and Jan Mayen"),                               currencies: vh![ CurrencyCode: NOK ],           languages: vh![ LanguageCode: NO ] },
-		Country::SK: CountryInfo { code: CountryCode::SK, name: s!("Slovakia"),                                             currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: SK ] },
-		Country::SL: CountryInfo { code: CountryCode::SL, name: s!("Sierra Leone"),                                         currencies: vh![ CurrencyCode: SLE, SLL ],      languages: vh![ LanguageCode: EN ] },
-		Country::SM: CountryInfo { code: CountryCode::SM, name: s!("San Marino"),                                           currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: IT ] },
-		Country::SN: CountryInfo { code: CountryCode::SN, name: s!("Senegal"),                                              currencies: vh![ CurrencyCode: XOF ],           languages: vh![ LanguageCode: FR ] },
-		Country::SO: CountryInfo { code: CountryCode::SO, name: s!("Somalia"),                                              currencies: vh![ CurrencyCode: SOS ],           languages: vh![ LanguageCode: AR, SO ] },
-		Country::SR: CountryInfo { code: CountryCode::SR, name: s!("Suriname"),                                             currencies: vh![ CurrencyCode: SRD ],           languages: vh![ LanguageCode: NL ] },
-		Country::SS: CountryInfo { code: CountryCode::SS, name: s!("South Sudan"),                                          currencies: vh![ CurrencyCode: SSP ],           languages: vh![ LanguageCode: EN ] },
-		Country::ST: CountryInfo { code: CountryCode::ST, name: s!("Sao Tome and Principe"),                                currencies: vh![ CurrencyCode: STN ],           languages: vh![ LanguageCode: PT ] },
-		Country::SV: CountryInfo { code: CountryCode::SV, name: s!("El Salvador"),                                          currencies: vh![ CurrencyCode: SVC, USD ],      languages: vh![ LanguageCode: ES ] },
-		Country::SX: CountryInfo { code: CountryCode::SX, name: s!("Sint Maarten (Dutch part)"),                            currencies: vh![ CurrencyCode: ANG ],           languages: vh![ LanguageCode: EN, NL ] },
-		Country::SY: CountryInfo { code: CountryCode::SY, name: s!("Syrian Arab Republic"),                                 currencies: vh![ CurrencyCode: SYP ],           languages: vh![ LanguageCode: AR ] },
-		Country::SZ: CountryInfo { code: CountryCode::SZ, name: s!("Eswatini"),                                             currencies: vh![ CurrencyCode: SZL, ZAR ],      languages: vh![ LanguageCode: EN, SS ] },
-		Country::TC: CountryInfo { code: CountryCode::TC, name: s!("Turks and Caicos Islands"),                             currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: EN ] },
-		Country::TD: CountryInfo { code: CountryCode::TD, name: s!("Chad"),                                                 currencies: vh![ CurrencyCode: XAF ],           languages: vh![ LanguageCode: AR, FR ] },
-		Country::TF: CountryInfo { code: CountryCode::TF, name: s!("French Southern Territories"),                          currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FR ] },
-		Country::TG: CountryInfo { code: CountryCode::TG, name: s!("Togo"),                                                 currencies: vh![ CurrencyCode: XOF ],           languages: vh![ LanguageCode: FR ] },
-		Country::TH: CountryInfo { code: CountryCode::TH, name: s!("Thailand"),                                             currencies: vh![ CurrencyCode: THB ],           languages: vh![ LanguageCode: TH ] },
-		Country::TJ: CountryInfo { code: CountryCode::TJ, name: s!("Tajikistan"),                                           currencies: vh![ CurrencyCode: TJS ],           languages: vh![ LanguageCode: TG ] },
-		Country::TK: CountryInfo { code: CountryCode::TK, name: s!("Tokelau"),                                              currencies: vh![ CurrencyCode: NZD ],           languages: vh![ LanguageCode: EN ] },
-		Country::TL: CountryInfo { code: CountryCode::TL, name: s!("Timor-Leste"),                                          currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: PT ] },
-		Country::TM: CountryInfo { code: CountryCode::TM, name: s!("Turkmenistan"),                                         currencies: vh![ CurrencyCode: TMT ],           languages: vh![ LanguageCode: TK ] },
-		Country::TN: CountryInfo { code: CountryCode::TN, name: s!("Tunisia"),                                              currencies: vh![ CurrencyCode: TND ],           languages: vh![ LanguageCode: AR ] },
-		Country::TO: CountryInfo { code: CountryCode::TO, name: s!("Tonga"),                                                currencies: vh![ CurrencyCode: TOP ],           languages: vh![ LanguageCode: EN, TO ] },
-		Country::TR: CountryInfo { code: CountryCode::TR, name: s!("Türkiye"),                                              currencies: vh![ CurrencyCode: TRY ],           languages: vh![ LanguageCode: TR ] },
-		Country::TT: CountryInfo { code: CountryCode::TT, name: s!("Trinidad and Tobago"),                                  currencies: vh![ CurrencyCode: TTD ],           languages: vh![ LanguageCode: EN ] },
-		Country::TV: CountryInfo { code: CountryCode::TV, name: s!("Tuvalu"),                                               currencies: vh![ CurrencyCode: AUD ],           languages: vh![ LanguageCode: EN ] },
-		Country::TW: CountryInfo { code: CountryCode::TW, name: s!("Taiwan, Province of China"),                            currencies: vh![ CurrencyCode: TWD ],           languages: vh![ LanguageCode: ZH ] },
-		Country::TZ: CountryInfo { code: CountryCode::TZ, name: s!("Tanzania, United Republic of"),                         currencies: vh![ CurrencyCode: TZS ],           languages: vh![ LanguageCode: EN, SW ] },
-		Country::UA: CountryInfo { code: CountryCode::UA, name: s!("Ukraine"),                                              currencies: vh![ CurrencyCode: UAH ],           languages: vh![ LanguageCode: UK ] },
-		Country::UG: CountryInfo { code: CountryCode::UG, name: s!("Uganda"),                                               currencies: vh![ CurrencyCode: UGX ],           languages: vh![ LanguageCode: EN, SW ] },
-		Country::UM: CountryInfo { code: CountryCode::UM, name: s!("United States Minor Outlying Islands"),                 currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: EN ] },
-		Country::US: CountryInfo { code: CountryCode::US, name: s!("United States of America"),                             currencies: vh![ CurrencyCode: USD, USN ],      languages: vh![ LanguageCode: EN ] },
-		Country::UY: CountryInfo { code: CountryCode::UY, name: s!("Uruguay"),                                              currencies: vh![ CurrencyCode: UYI, UYU, UYW ], languages: vh![ LanguageCode: ES ] },
-		Country::UZ: CountryInfo { code: CountryCode::UZ, name: s!("Uzbekistan"),                                           currencies: vh![ CurrencyCode: UZS ],           languages: vh![ LanguageCode: UZ ] },
-		Country::VA: CountryInfo { code: CountryCode::VA, name: s!("Holy See"),                                             currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: IT, LA ] },
-		Country::VC: CountryInfo { code: CountryCode::VC, name: s!("Saint Vincent and the Grenadines"),                     currencies: vh![ CurrencyCode: XCD ],           languages: vh![ LanguageCode: EN ] },
-		Country::VE: CountryInfo { code: CountryCode::VE, name: s!("Venezuela (Bolivarian Republic of)"),                   currencies: vh![ CurrencyCode: VED, VES ],      languages: vh![ LanguageCode: ES ] },
-		Country::VG: CountryInfo { code: CountryCode::VG, name: s!("Virgin Islands (British)"),                             currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: EN ] },
-		Country::VI: CountryInfo { code: CountryCode::VI, name: s!("Virgin Islands (U.S.)"),                                currencies: vh![ CurrencyCode: USD ],           languages: vh![ LanguageCode: EN ] },
-		Country::VN: CountryInfo { code: CountryCode::VN, name: s!("Viet Nam"),                                             currencies: vh![ CurrencyCode: VND ],           languages: vh![ LanguageCode: VI ] },
-		Country::VU: CountryInfo { code: CountryCode::VU, name: s!("Vanuatu"),                                              currencies: vh![ CurrencyCode: VUV ],           languages: vh![ LanguageCode: BI, EN, FR ] },
-		Country::WF: CountryInfo { code: CountryCode::WF, name: s!("Wallis and Futuna"),                                    currencies: vh![ CurrencyCode: XPF ],           languages: vh![ LanguageCode: FR ] },
-		Country::WS: CountryInfo { code: CountryCode::WS, name: s!("Samoa"),                                                currencies: vh![ CurrencyCode: WST ],           languages: vh![ LanguageCode: EN, SM ] },
-		Country::YE: CountryInfo { code: CountryCode::YE, name: s!("Yemen"),                                                currencies: vh![ CurrencyCode: YER ],           languages: vh![ LanguageCode: AR ] },
-		Country::YT: CountryInfo { code: CountryCode::YT, name: s!("Mayotte"),                                              currencies: vh![ CurrencyCode: EUR ],           languages: vh![ LanguageCode: FR ] },
-		Country::ZA: CountryInfo { code: CountryCode::ZA, name: s!("South Africa"),                                         currencies: vh![ CurrencyCode: ZAR ],           languages: vh![ LanguageCode: AF, EN, NR, SS, ST, TN, TS, VE, XH, ZU ] },
-		Country::ZM: CountryInfo { code: CountryCode::ZM, name: s!("Zambia"),                                               currencies: vh![ CurrencyCode: ZMW ],           languages: vh![ LanguageCode: EN ] },
-		Country::ZW: CountryInfo { code: CountryCode::ZW, name: s!("Zimbabwe"),                                             currencies: vh![ CurrencyCode: ZWL ],           languages: vh![ LanguageCode: EN, NR, NY, SN, ST, TN, VE, XH ] },
-	}
+static COUNTRIES: [CountryInfo; COUNT] = [
+	CountryInfo { code: CountryCode::AD, name: "Andorra",                                              full_name: Some("the Principality of Andorra"),                              vehicle_code: Some("AND"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 8)),   postal_codes: &[ "AD###" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::CA ] },
+	CountryInfo { code: CountryCode::AE, name: "United Arab Emirates",                                 full_name: Some("the United Arab Emirates"),                                 vehicle_code: Some("UAE"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 2)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::AED ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::AF, name: "Afghanistan",                                          full_name: Some("the Islamic Republic of Afghanistan"),                      vehicle_code: Some("AFG"), first_day: Weekday::Saturday, weekend: &[ Weekday::Thursday, Weekday::Friday ], national_day: Some((8, 19)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::AFN ],                                       languages: &[ LanguageCode::FA, LanguageCode::PS ] },
+	CountryInfo { code: CountryCode::AG, name: "Antigua and Barbuda",                                  full_name: Some("Antigua and Barbuda"),                                      vehicle_code: Some("AG"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 1)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XCD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::AI, name: "Anguilla",                                             full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "AI-2640" ],                                                         driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::XCD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::AL, name: "Albania",                                              full_name: Some("the Republic of Albania"),                                  vehicle_code: Some("AL"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 28)), postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::ALL ],                                       languages: &[ LanguageCode::SQ ] },
+	CountryInfo { code: CountryCode::AM, name: "Armenia",                                              full_name: Some("the Republic of Armenia"),                                  vehicle_code: Some("AM"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 21)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::AMD ],                                       languages: &[ LanguageCode::HY ] },
+	CountryInfo { code: CountryCode::AO, name: "Angola",                                               full_name: Some("the Republic of Angola"),                                   vehicle_code: Some("ANG"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 11)), postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::AOA ],                                       languages: &[ LanguageCode::PT ] },
+	CountryInfo { code: CountryCode::AQ, name: "Antarctica",                                           full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Antarctica, independent: false, un_status: UnStatus::NonMember, sovereign: None,                  currencies: &[],                                                          languages: &[] },
+	CountryInfo { code: CountryCode::AR, name: "Argentina",                                            full_name: Some("the Argentine Republic"),                                   vehicle_code: Some("RA"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((5, 25)),  postal_codes: &[ "####", "@####@@@" ],                                                driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::ARS ],                                       languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::AS, name: "American Samoa",                                       full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "#####", "#####-####" ],                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Us,       observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::US), currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::EN, LanguageCode::SM ] },
+	CountryInfo { code: CountryCode::AT, name: "Austria",                                              full_name: Some("the Republic of Austria"),                                  vehicle_code: Some("A"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 26)), postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::DE ] },
+	CountryInfo { code: CountryCode::AU, name: "Australia",                                            full_name: Some("Australia"),                                                vehicle_code: Some("AUS"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((1, 26)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::AUD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::AW, name: "Aruba",                                                full_name: None,                                                             vehicle_code: Some("ARU"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((3, 18)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::NL), currencies: &[ CurrencyCode::AWG ],                                       languages: &[ LanguageCode::NL ] },
+	CountryInfo { code: CountryCode::AX, name: "Åland Islands",                                        full_name: None,                                                             vehicle_code: Some("AX"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 9)),   postal_codes: &[ "22###" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FI), currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::SV ] },
+	CountryInfo { code: CountryCode::AZ, name: "Azerbaijan",                                           full_name: Some("the Republic of Azerbaijan"),                               vehicle_code: Some("AZ"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((5, 28)),  postal_codes: &[ "AZ ####", "####" ],                                                 driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::AZN ],                                       languages: &[ LanguageCode::AZ ] },
+	CountryInfo { code: CountryCode::BA, name: "Bosnia and Herzegovina",                               full_name: Some("Bosnia and Herzegovina"),                                   vehicle_code: Some("BIH"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((3, 1)),   postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BAM ],                                       languages: &[ LanguageCode::BS, LanguageCode::HR, LanguageCode::SR ] },
+	CountryInfo { code: CountryCode::BB, name: "Barbados",                                             full_name: Some("Barbados"),                                                 vehicle_code: Some("BDS"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 30)), postal_codes: &[ "BB#####" ],                                                         driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BBD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::BD, name: "Bangladesh",                                           full_name: Some("the People's Republic of Bangladesh"),                      vehicle_code: Some("BD"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((3, 26)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BDT ],                                       languages: &[ LanguageCode::BN ] },
+	CountryInfo { code: CountryCode::BE, name: "Belgium",                                              full_name: Some("the Kingdom of Belgium"),                                   vehicle_code: Some("B"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 21)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::DE, LanguageCode::FR, LanguageCode::NL ] },
+	CountryInfo { code: CountryCode::BF, name: "Burkina Faso",                                         full_name: Some("Burkina Faso"),                                             vehicle_code: Some("BF"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 11)), postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XOF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::BG, name: "Bulgaria",                                             full_name: Some("the Republic of Bulgaria"),                                 vehicle_code: Some("BG"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((3, 3)),   postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BGN ],                                       languages: &[ LanguageCode::BG ] },
+	CountryInfo { code: CountryCode::BH, name: "Bahrain",                                              full_name: Some("the Kingdom of Bahrain"),                                   vehicle_code: Some("BRN"), first_day: Weekday::Saturday, weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((12, 16)), postal_codes: &[ "###", "####" ],                                                     driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BHD ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::BI, name: "Burundi",                                              full_name: Some("the Republic of Burundi"),                                  vehicle_code: Some("RU"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 1)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BIF ],                                       languages: &[ LanguageCode::EN, LanguageCode::FR, LanguageCode::RN ] },
+	CountryInfo { code: CountryCode::BJ, name: "Benin",                                                full_name: Some("the Republic of Benin"),                                    vehicle_code: Some("DY"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 1)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XOF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::BL, name: "Saint Barthélemy",                                     full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "97133" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::BM, name: "Bermuda",                                              full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "@@ ##", "@@ @@" ],                                                  driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::BMD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::BN, name: "Brunei Darussalam",                                    full_name: Some("Brunei Darussalam"),                                        vehicle_code: Some("BRU"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 23)),  postal_codes: &[ "@@####" ],                                                          driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BND ],                                       languages: &[ LanguageCode::MS ] },
+	CountryInfo { code: CountryCode::BO, name: "Bolivia (Plurinational State of)",                     full_name: Some("the Plurinational State of Bolivia"),                       vehicle_code: Some("BOL"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 6)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BOB, CurrencyCode::BOV ],                    languages: &[ LanguageCode::AY, LanguageCode::ES, LanguageCode::GN, LanguageCode::QU ] },
+	CountryInfo { code: CountryCode::BQ, name: "Bonaire, Sint Eustatius and Saba",                     full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::NL), currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::NL ] },
+	CountryInfo { code: CountryCode::BR, name: "Brazil",                                               full_name: Some("the Federative Republic of Brazil"),                        vehicle_code: Some("BR"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 7)),   postal_codes: &[ "#####-###", "########" ],                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BRL ],                                       languages: &[ LanguageCode::PT ] },
+	CountryInfo { code: CountryCode::BS, name: "Bahamas",                                              full_name: Some("the Commonwealth of the Bahamas"),                          vehicle_code: Some("BS"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 10)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BSD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::BT, name: "Bhutan",                                               full_name: Some("the Kingdom of Bhutan"),                                    vehicle_code: Some("BHT"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 17)), postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BTN, CurrencyCode::INR ],                    languages: &[ LanguageCode::DZ ] },
+	CountryInfo { code: CountryCode::BV, name: "Bouvet Island",                                        full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::NO), currencies: &[ CurrencyCode::NOK ],                                       languages: &[ LanguageCode::NO ] },
+	CountryInfo { code: CountryCode::BW, name: "Botswana",                                             full_name: Some("the Republic of Botswana"),                                 vehicle_code: Some("RB"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 30)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BWP ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::BY, name: "Belarus",                                              full_name: Some("the Republic of Belarus"),                                  vehicle_code: Some("BY"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 3)),   postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BYN ],                                       languages: &[ LanguageCode::BE, LanguageCode::RU ] },
+	CountryInfo { code: CountryCode::BZ, name: "Belize",                                               full_name: Some("Belize"),                                                   vehicle_code: Some("BH"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 21)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::BZD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::CA, name: "Canada",                                               full_name: Some("Canada"),                                                   vehicle_code: Some("CDN"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 1)),   postal_codes: &[ "@#@ #@#", "@#@#@#" ],                                               driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::CAD ],                                       languages: &[ LanguageCode::EN, LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::CC, name: "Cocos (Keeling) Islands",                              full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "6799" ],                                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::AU), currencies: &[ CurrencyCode::AUD ],                                       languages: &[ LanguageCode::EN, LanguageCode::MS ] },
+	CountryInfo { code: CountryCode::CD, name: "Congo, Democratic Republic of the",                    full_name: Some("the Democratic Republic of the Congo"),                     vehicle_code: Some("CGO"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 30)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::CDF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::CF, name: "Central African Republic",                             full_name: Some("the Central African Republic"),                             vehicle_code: Some("RCA"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 1)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XAF ],                                       languages: &[ LanguageCode::FR, LanguageCode::SG ] },
+	CountryInfo { code: CountryCode::CG, name: "Congo",                                                full_name: Some("the Republic of the Congo"),                                vehicle_code: Some("RCB"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 15)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XAF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::CH, name: "Switzerland",                                          full_name: Some("the Swiss Confederation"),                                  vehicle_code: Some("CH"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 1)),   postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::CHE, CurrencyCode::CHF, CurrencyCode::CHW ], languages: &[ LanguageCode::DE, LanguageCode::FR, LanguageCode::IT, LanguageCode::RM ] },
+	CountryInfo { code: CountryCode::CI, name: "Côte d'Ivoire",                                        full_name: Some("the Republic of Côte d'Ivoire"),                            vehicle_code: Some("CI"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 7)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XOF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::CK, name: "Cook Islands",                                         full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::NZ), currencies: &[ CurrencyCode::NZD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::CL, name: "Chile",                                                full_name: Some("the Republic of Chile"),                                    vehicle_code: Some("RCH"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 18)),  postal_codes: &[ "#######", "###-####" ],                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::CLF, CurrencyCode::CLP ],                    languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::CM, name: "Cameroon",                                             full_name: Some("the Republic of Cameroon"),                                 vehicle_code: Some("CAM"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((5, 20)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XAF ],                                       languages: &[ LanguageCode::EN, LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::CN, name: "China",                                                full_name: Some("the People's Republic of China"),                           vehicle_code: None,        first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 1)),  postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::CNY ],                                       languages: &[ LanguageCode::ZH ] },
+	CountryInfo { code: CountryCode::CO, name: "Colombia",                                             full_name: Some("the Republic of Colombia"),                                 vehicle_code: Some("CO"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 20)),  postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::COP, CurrencyCode::COU ],                    languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::CR, name: "Costa Rica",                                           full_name: Some("the Republic of Costa Rica"),                               vehicle_code: Some("CR"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 15)),  postal_codes: &[ "#####", "####" ],                                                   driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::CRC ],                                       languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::CU, name: "Cuba",                                                 full_name: Some("the Republic of Cuba"),                                     vehicle_code: Some("C"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((1, 1)),   postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::CUP ],                                       languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::CV, name: "Cabo Verde",                                           full_name: Some("the Republic of Cabo Verde"),                               vehicle_code: Some("CV"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 5)),   postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::CVE ],                                       languages: &[ LanguageCode::PT ] },
+	CountryInfo { code: CountryCode::CW, name: "Curaçao",                                              full_name: None,                                                             vehicle_code: Some("CUR"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 2)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::NL), currencies: &[ CurrencyCode::ANG ],                                       languages: &[ LanguageCode::EN, LanguageCode::NL ] },
+	CountryInfo { code: CountryCode::CX, name: "Christmas Island",                                     full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "6798" ],                                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::AU), currencies: &[ CurrencyCode::AUD ],                                       languages: &[ LanguageCode::EN, LanguageCode::MS, LanguageCode::ZH ] },
+	CountryInfo { code: CountryCode::CY, name: "Cyprus",                                               full_name: Some("the Republic of Cyprus"),                                   vehicle_code: Some("CY"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 1)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::EL, LanguageCode::TR ] },
+	CountryInfo { code: CountryCode::CZ, name: "Czechia",                                              full_name: Some("the Czech Republic"),                                       vehicle_code: Some("CZ"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 28)), postal_codes: &[ "### ##", "#####" ],                                                 driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::CZK ],                                       languages: &[ LanguageCode::CS, LanguageCode::SK ] },
+	CountryInfo { code: CountryCode::DE, name: "Germany",                                              full_name: Some("the Federal Republic of Germany"),                          vehicle_code: Some("D"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 3)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::DE ] },
+	CountryInfo { code: CountryCode::DJ, name: "Djibouti",                                             full_name: Some("the Republic of Djibouti"),                                 vehicle_code: Some("DJI"), first_day: Weekday::Saturday, weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 27)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::DJF ],                                       languages: &[ LanguageCode::AR, LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::DK, name: "Denmark",                                              full_name: Some("the Kingdom of Denmark"),                                   vehicle_code: Some("DK"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 5)),   postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::DKK ],                                       languages: &[ LanguageCode::DA ] },
+	CountryInfo { code: CountryCode::DM, name: "Dominica",                                             full_name: Some("the Commonwealth of Dominica"),                             vehicle_code: Some("WD"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 3)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XCD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::DO, name: "Dominican Republic",                                   full_name: Some("the Dominican Republic"),                                   vehicle_code: Some("DOM"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 27)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::DOP ],                                       languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::DZ, name: "Algeria",                                              full_name: Some("the People's Democratic Republic of Algeria"),              vehicle_code: Some("DZ"),  first_day: Weekday::Saturday, weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((11, 1)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::DZD ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::EC, name: "Ecuador",                                              full_name: Some("the Republic of Ecuador"),                                  vehicle_code: Some("EC"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 10)),  postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::ES, LanguageCode::QU ] },
+	CountryInfo { code: CountryCode::EE, name: "Estonia",                                              full_name: Some("the Republic of Estonia"),                                  vehicle_code: Some("EST"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 24)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::ET ] },
+	CountryInfo { code: CountryCode::EG, name: "Egypt",                                                full_name: Some("the Arab Republic of Egypt"),                               vehicle_code: Some("ET"),  first_day: Weekday::Saturday, weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((7, 23)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EGP ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::EH, name: "Western Sahara",                                       full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Africa,     independent: false, un_status: UnStatus::NonMember, sovereign: None,                  currencies: &[ CurrencyCode::MAD ],                                       languages: &[ LanguageCode::AR, LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::ER, name: "Eritrea",                                              full_name: Some("the State of Eritrea"),                                     vehicle_code: Some("ER"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((5, 24)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::ERN ],                                       languages: &[ LanguageCode::TI ] },
+	CountryInfo { code: CountryCode::ES, name: "Spain",                                                full_name: Some("the Kingdom of Spain"),                                     vehicle_code: Some("E"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 12)), postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::ET, name: "Ethiopia",                                             full_name: Some("the Federal Democratic Republic of Ethiopia"),              vehicle_code: Some("ETH"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((5, 28)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::ETB ],                                       languages: &[ LanguageCode::AA, LanguageCode::AM, LanguageCode::OM, LanguageCode::SO, LanguageCode::TI ] },
+	CountryInfo { code: CountryCode::FI, name: "Finland",                                              full_name: Some("the Republic of Finland"),                                  vehicle_code: Some("FIN"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 6)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FI, LanguageCode::SV ] },
+	CountryInfo { code: CountryCode::FJ, name: "Fiji",                                                 full_name: Some("the Republic of Fiji"),                                     vehicle_code: Some("FJI"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 10)), postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::FJD ],                                       languages: &[ LanguageCode::EN, LanguageCode::FJ ] },
+	CountryInfo { code: CountryCode::FK, name: "Falkland Islands (Malvinas)",                          full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "FIQQ 1ZZ" ],                                                        driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::FKP ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::FM, name: "Micronesia (Federated States of)",                     full_name: Some("the Federated States of Micronesia"),                       vehicle_code: Some("FSM"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 3)),  postal_codes: &[ "#####", "#####-####" ],                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::FO, name: "Faroe Islands",                                        full_name: None,                                                             vehicle_code: Some("FO"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 29)),  postal_codes: &[ "###" ],                                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::DK), currencies: &[ CurrencyCode::DKK ],                                       languages: &[ LanguageCode::DA, LanguageCode::FO ] },
+	CountryInfo { code: CountryCode::FR, name: "France",                                               full_name: Some("the French Republic"),                                      vehicle_code: Some("F"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 14)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::GA, name: "Gabon",                                                full_name: Some("the Gabonese Republic"),                                    vehicle_code: Some("G"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 17)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XAF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::GB, name: "United Kingdom of Great Britain and Northern Ireland", full_name: Some("the United Kingdom of Great Britain and Northern Ireland"), vehicle_code: Some("UK"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "@# #@@", "@## #@@", "@@# #@@", "@@## #@@", "@#@ #@@", "@@#@ #@@" ], driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Imperial, observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::GBP ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::GD, name: "Grenada",                                              full_name: Some("Grenada"),                                                  vehicle_code: Some("WG"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 7)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XCD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::GE, name: "Georgia",                                              full_name: Some("Georgia"),                                                  vehicle_code: Some("GE"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((5, 26)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::GEL ],                                       languages: &[ LanguageCode::KA ] },
+	CountryInfo { code: CountryCode::GF, name: "French Guiana",                                        full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "973##" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::GG, name: "Guernsey",                                             full_name: None,                                                             vehicle_code: Some("GBG"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((5, 9)),   postal_codes: &[ "GY# #@@", "GY## #@@" ],                                             driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::GBP ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::GH, name: "Ghana",                                                full_name: Some("the Republic of Ghana"),                                    vehicle_code: Some("GH"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((3, 6)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::GHS ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::GI, name: "Gibraltar",                                            full_name: None,                                                             vehicle_code: Some("GBZ"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 10)),  postal_codes: &[ "GX11 1AA" ],                                                        driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::GIP ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::GL, name: "Greenland",                                            full_name: None,                                                             vehicle_code: Some("KN"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 21)),  postal_codes: &[ "39##" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::DK), currencies: &[ CurrencyCode::DKK ],                                       languages: &[ LanguageCode::DA, LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::GM, name: "Gambia",                                               full_name: Some("the Republic of the Gambia"),                               vehicle_code: Some("WAG"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 18)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::GMD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::GN, name: "Guinea",                                               full_name: Some("the Republic of Guinea"),                                   vehicle_code: Some("RG"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 2)),  postal_codes: &[ "###" ],                                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::GNF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::GP, name: "Guadeloupe",                                           full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "971##" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::GQ, name: "Equatorial Guinea",                                    full_name: Some("the Republic of Equatorial Guinea"),                        vehicle_code: Some("GQ"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 12)), postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XAF ],                                       languages: &[ LanguageCode::ES, LanguageCode::FR, LanguageCode::PT ] },
+	CountryInfo { code: CountryCode::GR, name: "Greece",                                               full_name: Some("the Hellenic Republic"),                                    vehicle_code: Some("GR"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((3, 25)),  postal_codes: &[ "### ##" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::EL ] },
+	CountryInfo { code: CountryCode::GS, name: "South Georgia and the South Sandwich Islands",         full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "SIQQ 1ZZ" ],                                                        driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[],                                                          languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::GT, name: "Guatemala",                                            full_name: Some("the Republic of Guatemala"),                                vehicle_code: Some("GCA"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 15)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::GTQ ],                                       languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::GU, name: "Guam",                                                 full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "969##", "969##-####" ],                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Us,       observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::US), currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::CH, LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::GW, name: "Guinea-Bissau",                                        full_name: Some("the Republic of Guinea-Bissau"),                            vehicle_code: Some("GW"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 24)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XOF ],                                       languages: &[ LanguageCode::PT ] },
+	CountryInfo { code: CountryCode::GY, name: "Guyana",                                               full_name: Some("the Co-operative Republic of Guyana"),                      vehicle_code: Some("GUY"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 23)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::GYD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::HK, name: "Hong Kong",                                            full_name: Some("the Hong Kong Special Administrative Region of China"),     vehicle_code: Some("HK"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 1)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::CN), currencies: &[ CurrencyCode::HKD ],                                       languages: &[ LanguageCode::EN, LanguageCode::ZH ] },
+	CountryInfo { code: CountryCode::HM, name: "Heard Island and McDonald Islands",                    full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::AU), currencies: &[ CurrencyCode::AUD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::HN, name: "Honduras",                                             full_name: Some("the Republic of Honduras"),                                 vehicle_code: Some("HN"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 15)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::HNL ],                                       languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::HR, name: "Croatia",                                              full_name: Some("the Republic of Croatia"),                                  vehicle_code: Some("HR"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((5, 30)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::HR ] },
+	CountryInfo { code: CountryCode::HT, name: "Haiti",                                                full_name: Some("the Republic of Haiti"),                                    vehicle_code: Some("RH"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((1, 1)),   postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::HTG ],                                       languages: &[ LanguageCode::FR, LanguageCode::HT ] },
+	CountryInfo { code: CountryCode::HU, name: "Hungary",                                              full_name: Some("Hungary"),                                                  vehicle_code: Some("H"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 20)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::HUF ],                                       languages: &[ LanguageCode::HU ] },
+	CountryInfo { code: CountryCode::ID, name: "Indonesia",                                            full_name: Some("the Republic of Indonesia"),                                vehicle_code: Some("RI"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 17)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::IDR ],                                       languages: &[ LanguageCode::ID ] },
+	CountryInfo { code: CountryCode::IE, name: "Ireland",                                              full_name: Some("Ireland"),                                                  vehicle_code: Some("IRL"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((3, 17)),  postal_codes: &[ "@## ****", "D6W ****" ],                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::EN, LanguageCode::GA ] },
+	CountryInfo { code: CountryCode::IL, name: "Israel",                                               full_name: Some("the State of Israel"),                                      vehicle_code: Some("IL"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: None,           postal_codes: &[ "#######", "#####" ],                                                driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::ILS ],                                       languages: &[ LanguageCode::HE ] },
+	CountryInfo { code: CountryCode::IM, name: "Isle of Man",                                          full_name: None,                                                             vehicle_code: Some("GBM"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 5)),   postal_codes: &[ "IM# #@@", "IM## #@@" ],                                             driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::GBP ],                                       languages: &[ LanguageCode::EN, LanguageCode::GV ] },
+	CountryInfo { code: CountryCode::IN, name: "India",                                                full_name: Some("the Republic of India"),                                    vehicle_code: Some("IND"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Sunday ],                    national_day: Some((1, 26)),  postal_codes: &[ "######", "### ###" ],                                               driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::INR ],                                       languages: &[ LanguageCode::EN, LanguageCode::HI ] },
+	CountryInfo { code: CountryCode::IO, name: "British Indian Ocean Territory",                       full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "BBND 1ZZ" ],                                                        driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::IQ, name: "Iraq",                                                 full_name: Some("the Republic of Iraq"),                                     vehicle_code: Some("IRQ"), first_day: Weekday::Saturday, weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((10, 3)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::IQD ],                                       languages: &[ LanguageCode::AR, LanguageCode::KU ] },
+	CountryInfo { code: CountryCode::IR, name: "Iran (Islamic Republic of)",                           full_name: Some("the Islamic Republic of Iran"),                             vehicle_code: Some("IR"),  first_day: Weekday::Saturday, weekend: &[ Weekday::Friday ],                    national_day: None,           postal_codes: &[ "#####-#####", "##########" ],                                       driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::IRR ],                                       languages: &[ LanguageCode::FA ] },
+	CountryInfo { code: CountryCode::IS, name: "Iceland",                                              full_name: Some("Iceland"),                                                  vehicle_code: Some("IS"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 17)),  postal_codes: &[ "###" ],                                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::ISK ],                                       languages: &[ LanguageCode::IS ] },
+	CountryInfo { code: CountryCode::IT, name: "Italy",                                                full_name: Some("the Italian Republic"),                                     vehicle_code: Some("I"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 2)),   postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::IT ] },
+	CountryInfo { code: CountryCode::JE, name: "Jersey",                                               full_name: None,                                                             vehicle_code: Some("GBJ"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((5, 9)),   postal_codes: &[ "JE# #@@", "JE## #@@" ],                                             driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::GBP ],                                       languages: &[ LanguageCode::EN, LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::JM, name: "Jamaica",                                              full_name: Some("Jamaica"),                                                  vehicle_code: Some("JA"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 6)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::JMD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::JO, name: "Jordan",                                               full_name: Some("the Hashemite Kingdom of Jordan"),                          vehicle_code: Some("HKJ"), first_day: Weekday::Saturday, weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((5, 25)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::JOD ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::JP, name: "Japan",                                                full_name: Some("Japan"),                                                    vehicle_code: Some("J"),   first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 23)),  postal_codes: &[ "###-####", "#######" ],                                             driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::JPY ],                                       languages: &[ LanguageCode::JA ] },
+	CountryInfo { code: CountryCode::KE, name: "Kenya",                                                full_name: Some("the Republic of Kenya"),                                    vehicle_code: Some("EAK"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 12)), postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::KES ],                                       languages: &[ LanguageCode::EN, LanguageCode::SW ] },
+	CountryInfo { code: CountryCode::KG, name: "Kyrgyzstan",                                           full_name: Some("the Kyrgyz Republic"),                                      vehicle_code: Some("KS"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 31)),  postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::KGS ],                                       languages: &[ LanguageCode::KY, LanguageCode::RU ] },
+	CountryInfo { code: CountryCode::KH, name: "Cambodia",                                             full_name: Some("the Kingdom of Cambodia"),                                  vehicle_code: Some("K"),   first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 9)),  postal_codes: &[ "#####", "######" ],                                                 driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::KHR ],                                       languages: &[ LanguageCode::KM ] },
+	CountryInfo { code: CountryCode::KI, name: "Kiribati",                                             full_name: Some("the Republic of Kiribati"),                                 vehicle_code: Some("KIR"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 12)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::AUD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::KM, name: "Comoros",                                              full_name: Some("the Union of the Comoros"),                                 vehicle_code: Some("COM"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 6)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::KMF ],                                       languages: &[ LanguageCode::AR, LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::KN, name: "Saint Kitts and Nevis",                                full_name: Some("Saint Kitts and Nevis"),                                    vehicle_code: Some("KAN"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 19)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XCD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::KP, name: "Korea (Democratic People's Republic of)",              full_name: Some("the Democratic People's Republic of Korea"),                vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 9)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::KPW ],                                       languages: &[ LanguageCode::KO ] },
+	CountryInfo { code: CountryCode::KR, name: "Korea, Republic of",                                   full_name: Some("the Republic of Korea"),                                    vehicle_code: Some("ROK"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 3)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::KRW ],                                       languages: &[ LanguageCode::KO ] },
+	CountryInfo { code: CountryCode::KW, name: "Kuwait",                                               full_name: Some("the State of Kuwait"),                                      vehicle_code: Some("KWT"), first_day: Weekday::Saturday, weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((2, 25)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::KWD ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::KY, name: "Cayman Islands",                                       full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "KY#-####" ],                                                        driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::KYD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::KZ, name: "Kazakhstan",                                           full_name: Some("the Republic of Kazakhstan"),                               vehicle_code: Some("KZ"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 16)), postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::KZT ],                                       languages: &[ LanguageCode::KK, LanguageCode::RU ] },
+	CountryInfo { code: CountryCode::LA, name: "Lao People's Democratic Republic",                     full_name: Some("the Lao People's Democratic Republic"),                     vehicle_code: Some("LAO"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 2)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::LAK ],                                       languages: &[ LanguageCode::LO ] },
+	CountryInfo { code: CountryCode::LB, name: "Lebanon",                                              full_name: Some("the Lebanese Republic"),                                    vehicle_code: Some("RL"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 22)), postal_codes: &[ "####", "#### ####" ],                                               driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::LBP ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::LC, name: "Saint Lucia",                                          full_name: Some("Saint Lucia"),                                              vehicle_code: Some("WL"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 22)),  postal_codes: &[ "LC## ###" ],                                                        driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XCD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::LI, name: "Liechtenstein",                                        full_name: Some("the Principality of Liechtenstein"),                        vehicle_code: Some("FL"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 15)),  postal_codes: &[ "94##" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::CHF ],                                       languages: &[ LanguageCode::DE ] },
+	CountryInfo { code: CountryCode::LK, name: "Sri Lanka",                                            full_name: Some("the Democratic Socialist Republic of Sri Lanka"),           vehicle_code: Some("CL"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 4)),   postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::LKR ],                                       languages: &[ LanguageCode::SI, LanguageCode::TA ] },
+	CountryInfo { code: CountryCode::LR, name: "Liberia",                                              full_name: Some("the Republic of Liberia"),                                  vehicle_code: Some("LB"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 26)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Us,       observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::LRD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::LS, name: "Lesotho",                                              full_name: Some("the Kingdom of Lesotho"),                                   vehicle_code: Some("LS"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 4)),  postal_codes: &[ "###" ],                                                             driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::LSL, CurrencyCode::ZAR ],                    languages: &[ LanguageCode::EN, LanguageCode::ST ] },
+	CountryInfo { code: CountryCode::LT, name: "Lithuania",                                            full_name: Some("the Republic of Lithuania"),                                vehicle_code: Some("LT"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 16)),  postal_codes: &[ "LT-#####", "#####" ],                                               driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::LT ] },
+	CountryInfo { code: CountryCode::LU, name: "Luxembourg",                                           full_name: Some("the Grand Duchy of Luxembourg"),                            vehicle_code: Some("L"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 23)),  postal_codes: &[ "####", "L-####" ],                                                  driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::DE, LanguageCode::FR, LanguageCode::LB ] },
+	CountryInfo { code: CountryCode::LV, name: "Latvia",                                               full_name: Some("the Republic of Latvia"),                                   vehicle_code: Some("LV"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 18)), postal_codes: &[ "LV-####" ],                                                         driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::LV ] },
+	CountryInfo { code: CountryCode::LY, name: "Libya",                                                full_name: Some("the State of Libya"),                                       vehicle_code: Some("LAR"), first_day: Weekday::Saturday, weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((12, 24)), postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::LYD ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::MA, name: "Morocco",                                              full_name: Some("the Kingdom of Morocco"),                                   vehicle_code: Some("MA"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 30)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MAD ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::MC, name: "Monaco",                                               full_name: Some("the Principality of Monaco"),                               vehicle_code: Some("MC"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 19)), postal_codes: &[ "980##" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::MD, name: "Moldova, Republic of",                                 full_name: Some("the Republic of Moldova"),                                  vehicle_code: Some("MD"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 27)),  postal_codes: &[ "MD####", "MD-####", "####" ],                                       driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MDL ],                                       languages: &[ LanguageCode::RO ] },
+	CountryInfo { code: CountryCode::ME, name: "Montenegro",                                           full_name: Some("Montenegro"),                                               vehicle_code: Some("MNE"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 13)),  postal_codes: &[ "8####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::HR, LanguageCode::SR ] },
+	CountryInfo { code: CountryCode::MF, name: "Saint Martin (French part)",                           full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "97150" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::MG, name: "Madagascar",                                           full_name: Some("the Republic of Madagascar"),                               vehicle_code: Some("RM"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 26)),  postal_codes: &[ "###" ],                                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MGA ],                                       languages: &[ LanguageCode::FR, LanguageCode::MG ] },
+	CountryInfo { code: CountryCode::MH, name: "Marshall Islands",                                     full_name: Some("the Republic of the Marshall Islands"),                     vehicle_code: Some("MH"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((5, 1)),   postal_codes: &[ "969##", "969##-####" ],                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::EN, LanguageCode::MH ] },
+	CountryInfo { code: CountryCode::MK, name: "North Macedonia",                                      full_name: Some("the Republic of North Macedonia"),                          vehicle_code: Some("NMK"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 8)),   postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MKD ],                                       languages: &[ LanguageCode::MK, LanguageCode::SQ ] },
+	CountryInfo { code: CountryCode::ML, name: "Mali",                                                 full_name: Some("the Republic of Mali"),                                     vehicle_code: Some("RMM"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 22)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XOF ],                                       languages: &[ LanguageCode::BM, LanguageCode::FF ] },
+	CountryInfo { code: CountryCode::MM, name: "Myanmar",                                              full_name: Some("the Republic of the Union of Myanmar"),                     vehicle_code: Some("MYA"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((1, 4)),   postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Us,       observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MMK ],                                       languages: &[ LanguageCode::MY ] },
+	CountryInfo { code: CountryCode::MN, name: "Mongolia",                                             full_name: Some("Mongolia"),                                                 vehicle_code: Some("MGL"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 11)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MNT ],                                       languages: &[ LanguageCode::MN ] },
+	CountryInfo { code: CountryCode::MO, name: "Macao",                                                full_name: Some("the Macao Special Administrative Region of China"),         vehicle_code: Some("MO"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 20)), postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::CN), currencies: &[ CurrencyCode::MOP ],                                       languages: &[ LanguageCode::PT, LanguageCode::ZH ] },
+	CountryInfo { code: CountryCode::MP, name: "Northern Mariana Islands",                             full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "9695#", "9695#-####" ],                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Us,       observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::US), currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::CH, LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::MQ, name: "Martinique",                                           full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "972##" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::MR, name: "Mauritania",                                           full_name: Some("the Islamic Republic of Mauritania"),                       vehicle_code: Some("RIM"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 28)), postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MRU ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::MS, name: "Montserrat",                                           full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "MSR ####" ],                                                        driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::XCD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::MT, name: "Malta",                                                full_name: Some("the Republic of Malta"),                                    vehicle_code: Some("M"),   first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 21)),  postal_codes: &[ "@@@ ##", "@@@ ###", "@@@ ####" ],                                   driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::EN, LanguageCode::MT ] },
+	CountryInfo { code: CountryCode::MU, name: "Mauritius",                                            full_name: Some("the Republic of Mauritius"),                                vehicle_code: Some("MS"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((3, 12)),  postal_codes: &[ "#####", "###@@###" ],                                               driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MUR ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::MV, name: "Maldives",                                             full_name: Some("the Republic of Maldives"),                                 vehicle_code: Some("MV"),  first_day: Weekday::Friday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 26)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MVR ],                                       languages: &[ LanguageCode::DV ] },
+	CountryInfo { code: CountryCode::MW, name: "Malawi",                                               full_name: Some("the Republic of Malawi"),                                   vehicle_code: Some("MW"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 6)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MWK ],                                       languages: &[ LanguageCode::EN, LanguageCode::NY ] },
+	CountryInfo { code: CountryCode::MX, name: "Mexico",                                               full_name: Some("the United Mexican States"),                                vehicle_code: Some("MEX"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 16)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MXN, CurrencyCode::MXV ],                    languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::MY, name: "Malaysia",                                             full_name: Some("Malaysia"),                                                 vehicle_code: Some("MAL"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 31)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MYR ],                                       languages: &[ LanguageCode::MS ] },
+	CountryInfo { code: CountryCode::MZ, name: "Mozambique",                                           full_name: Some("the Republic of Mozambique"),                               vehicle_code: Some("MOC"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 25)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::MZN ],                                       languages: &[ LanguageCode::PT ] },
+	CountryInfo { code: CountryCode::NA, name: "Namibia",                                              full_name: Some("the Republic of Namibia"),                                  vehicle_code: Some("NAM"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((3, 21)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::NAD, CurrencyCode::ZAR ],                    languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::NC, name: "New Caledonia",                                        full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "988##" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::XPF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::NE, name: "Niger",                                                full_name: Some("the Republic of the Niger"),                                vehicle_code: Some("RN"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 18)), postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XOF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::NF, name: "Norfolk Island",                                       full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "2899" ],                                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::AU), currencies: &[ CurrencyCode::AUD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::NG, name: "Nigeria",                                              full_name: Some("the Federal Republic of Nigeria"),                          vehicle_code: Some("WAN"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 1)),  postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::NGN ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::NI, name: "Nicaragua",                                            full_name: Some("the Republic of Nicaragua"),                                vehicle_code: Some("NIC"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 15)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::NIO ],                                       languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::NL, name: "Netherlands, Kingdom of the",                          full_name: Some("the Kingdom of the Netherlands"),                           vehicle_code: Some("NL"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((4, 27)),  postal_codes: &[ "#### @@", "####@@" ],                                               driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::NL ] },
+	CountryInfo { code: CountryCode::NO, name: "Norway",                                               full_name: Some("the Kingdom of Norway"),                                    vehicle_code: Some("N"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((5, 17)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::NOK ],                                       languages: &[ LanguageCode::NO ] },
+	CountryInfo { code: CountryCode::NP, name: "Nepal",                                                full_name: Some("the Federal Democratic Republic of Nepal"),                 vehicle_code: Some("NEP"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 20)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::NPR ],                                       languages: &[ LanguageCode::NE ] },
+	CountryInfo { code: CountryCode::NR, name: "Nauru",                                                full_name: Some("the Republic of Nauru"),                                    vehicle_code: Some("NAU"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((1, 31)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::AUD ],                                       languages: &[ LanguageCode::EN, LanguageCode::NA ] },
+	CountryInfo { code: CountryCode::NU, name: "Niue",                                                 full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::NZ), currencies: &[ CurrencyCode::NZD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::NZ, name: "New Zealand",                                          full_name: Some("New Zealand"),                                              vehicle_code: Some("NZ"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 6)),   postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::NZD ],                                       languages: &[ LanguageCode::EN, LanguageCode::MI ] },
+	CountryInfo { code: CountryCode::OM, name: "Oman",                                                 full_name: Some("the Sultanate of Oman"),                                    vehicle_code: Some("OM"),  first_day: Weekday::Saturday, weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((11, 20)), postal_codes: &[ "###" ],                                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::OMR ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::PA, name: "Panama",                                               full_name: Some("the Republic of Panama"),                                   vehicle_code: Some("PA"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 3)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::PAB, CurrencyCode::USD ],                    languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::PE, name: "Peru",                                                 full_name: Some("the Republic of Peru"),                                     vehicle_code: Some("PE"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 28)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::PEN ],                                       languages: &[ LanguageCode::AY, LanguageCode::ES, LanguageCode::QU ] },
+	CountryInfo { code: CountryCode::PF, name: "French Polynesia",                                     full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "987##" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::XPF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::PG, name: "Papua New Guinea",                                     full_name: Some("the Independent State of Papua New Guinea"),                vehicle_code: Some("PNG"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 16)),  postal_codes: &[ "###" ],                                                             driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::PGK ],                                       languages: &[ LanguageCode::EN, LanguageCode::HO ] },
+	CountryInfo { code: CountryCode::PH, name: "Philippines",                                          full_name: Some("the Republic of the Philippines"),                          vehicle_code: Some("RP"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 12)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::PHP ],                                       languages: &[ LanguageCode::EN, LanguageCode::TL ] },
+	CountryInfo { code: CountryCode::PK, name: "Pakistan",                                             full_name: Some("the Islamic Republic of Pakistan"),                         vehicle_code: Some("PK"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((3, 23)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::PKR ],                                       languages: &[ LanguageCode::EN, LanguageCode::UR ] },
+	CountryInfo { code: CountryCode::PL, name: "Poland",                                               full_name: Some("the Republic of Poland"),                                   vehicle_code: Some("PL"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 11)), postal_codes: &[ "##-###" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::PLN ],                                       languages: &[ LanguageCode::PL ] },
+	CountryInfo { code: CountryCode::PM, name: "Saint Pierre and Miquelon",                            full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "97500" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::PN, name: "Pitcairn",                                             full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "PCRN 1ZZ" ],                                                        driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::NZD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::PR, name: "Puerto Rico",                                          full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "#####", "#####-####" ],                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Us,       observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::US), currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::EN, LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::PS, name: "Palestine, State of",                                  full_name: Some("the State of Palestine"),                                   vehicle_code: Some("PS"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 15)), postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Asia,       independent: false, un_status: UnStatus::Observer,  sovereign: None,                  currencies: &[],                                                          languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::PT, name: "Portugal",                                             full_name: Some("the Portuguese Republic"),                                  vehicle_code: Some("P"),   first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 10)),  postal_codes: &[ "####-###" ],                                                        driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::PT ] },
+	CountryInfo { code: CountryCode::PW, name: "Palau",                                                full_name: Some("the Republic of Palau"),                                    vehicle_code: Some("PAL"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 9)),   postal_codes: &[ "96940", "96940-####" ],                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::PY, name: "Paraguay",                                             full_name: Some("the Republic of Paraguay"),                                 vehicle_code: Some("PY"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::PYG ],                                       languages: &[ LanguageCode::ES, LanguageCode::GN ] },
+	CountryInfo { code: CountryCode::QA, name: "Qatar",                                                full_name: Some("the State of Qatar"),                                       vehicle_code: Some("Q"),   first_day: Weekday::Saturday, weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((12, 18)), postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::QAR ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::RE, name: "Réunion",                                              full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "974##" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::RO, name: "Romania",                                              full_name: Some("Romania"),                                                  vehicle_code: Some("RO"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 1)),  postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::RON ],                                       languages: &[ LanguageCode::RO ] },
+	CountryInfo { code: CountryCode::RS, name: "Serbia",                                               full_name: Some("the Republic of Serbia"),                                   vehicle_code: Some("SRB"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((2, 15)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::RSD ],                                       languages: &[ LanguageCode::SR ] },
+	CountryInfo { code: CountryCode::RU, name: "Russian Federation",                                   full_name: Some("the Russian Federation"),                                   vehicle_code: Some("RUS"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 12)),  postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::RUB ],                                       languages: &[ LanguageCode::RU ] },
+	CountryInfo { code: CountryCode::RW, name: "Rwanda",                                               full_name: Some("the Republic of Rwanda"),                                   vehicle_code: Some("RWA"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 1)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::RWF ],                                       languages: &[ LanguageCode::EN, LanguageCode::FR, LanguageCode::RW, LanguageCode::SW ] },
+	CountryInfo { code: CountryCode::SA, name: "Saudi Arabia",                                         full_name: Some("the Kingdom of Saudi Arabia"),                              vehicle_code: Some("KSA"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((9, 23)),  postal_codes: &[ "#####", "#####-####" ],                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SAR ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::SB, name: "Solomon Islands",                                      full_name: Some("Solomon Islands"),                                          vehicle_code: Some("SOL"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 7)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SBD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::SC, name: "Seychelles",                                           full_name: Some("the Republic of Seychelles"),                               vehicle_code: Some("SY"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 29)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SCR ],                                       languages: &[ LanguageCode::EN, LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::SD, name: "Sudan",                                                full_name: Some("the Republic of the Sudan"),                                vehicle_code: Some("SUD"), first_day: Weekday::Saturday, weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((1, 1)),   postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SDG ],                                       languages: &[ LanguageCode::AR, LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::SE, name: "Sweden",                                               full_name: Some("the Kingdom of Sweden"),                                    vehicle_code: Some("S"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 6)),   postal_codes: &[ "### ##", "#####" ],                                                 driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SEK ],                                       languages: &[ LanguageCode::SV ] },
+	CountryInfo { code: CountryCode::SG, name: "Singapore",                                            full_name: Some("the Republic of Singapore"),                                vehicle_code: Some("SGP"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 9)),   postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SGD ],                                       languages: &[ LanguageCode::EN, LanguageCode::MS, LanguageCode::TA, LanguageCode::ZH ] },
+	CountryInfo { code: CountryCode::SH, name: "Saint Helena, Ascension and Tristan da Cunha",         full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "STHL 1ZZ", "ASCN 1ZZ", "TDCU 1ZZ" ],                                driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::GBP, CurrencyCode::SHP ],                    languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::SI, name: "Slovenia",                                             full_name: Some("the Republic of Slovenia"),                                 vehicle_code: Some("SLO"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 25)),  postal_codes: &[ "####", "SI-####" ],                                                 driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::SL ] },
+	CountryInfo { code: CountryCode::SJ, name: "Svalbard and Jan Mayen",                               full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::NO), currencies: &[ CurrencyCode::NOK ],                                       languages: &[ LanguageCode::NO ] },
+	CountryInfo { code: CountryCode::SK, name: "Slovakia",                                             full_name: Some("the Slovak Republic"),                                      vehicle_code: Some("SK"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((1, 1)),   postal_codes: &[ "### ##", "#####" ],                                                 driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::SK ] },
+	CountryInfo { code: CountryCode::SL, name: "Sierra Leone",                                         full_name: Some("the Republic of Sierra Leone"),                             vehicle_code: Some("WAL"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((4, 27)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SLE, CurrencyCode::SLL ],                    languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::SM, name: "San Marino",                                           full_name: Some("the Republic of San Marino"),                               vehicle_code: Some("RSM"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 3)),   postal_codes: &[ "4789#" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::IT ] },
+	CountryInfo { code: CountryCode::SN, name: "Senegal",                                              full_name: Some("the Republic of Senegal"),                                  vehicle_code: Some("SN"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((4, 4)),   postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XOF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::SO, name: "Somalia",                                              full_name: Some("the Federal Republic of Somalia"),                          vehicle_code: Some("SO"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 1)),   postal_codes: &[ "@@ #####" ],                                                        driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SOS ],                                       languages: &[ LanguageCode::AR, LanguageCode::SO ] },
+	CountryInfo { code: CountryCode::SR, name: "Suriname",                                             full_name: Some("the Republic of Suriname"),                                 vehicle_code: Some("SME"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 25)), postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SRD ],                                       languages: &[ LanguageCode::NL ] },
+	CountryInfo { code: CountryCode::SS, name: "South Sudan",                                          full_name: Some("the Republic of South Sudan"),                              vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 9)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SSP ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::ST, name: "Sao Tome and Principe",                                full_name: Some("the Democratic Republic of Sao Tome and Principe"),         vehicle_code: Some("STP"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 12)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::STN ],                                       languages: &[ LanguageCode::PT ] },
+	CountryInfo { code: CountryCode::SV, name: "El Salvador",                                          full_name: Some("the Republic of El Salvador"),                              vehicle_code: Some("ES"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 15)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SVC, CurrencyCode::USD ],                    languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::SX, name: "Sint Maarten (Dutch part)",                            full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::NL), currencies: &[ CurrencyCode::ANG ],                                       languages: &[ LanguageCode::EN, LanguageCode::NL ] },
+	CountryInfo { code: CountryCode::SY, name: "Syrian Arab Republic",                                 full_name: Some("the Syrian Arab Republic"),                                 vehicle_code: Some("SYR"), first_day: Weekday::Saturday, weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((4, 17)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SYP ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::SZ, name: "Eswatini",                                             full_name: Some("the Kingdom of Eswatini"),                                  vehicle_code: Some("SD"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 6)),   postal_codes: &[ "@###" ],                                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::SZL, CurrencyCode::ZAR ],                    languages: &[ LanguageCode::EN, LanguageCode::SS ] },
+	CountryInfo { code: CountryCode::TC, name: "Turks and Caicos Islands",                             full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "TKCA 1ZZ" ],                                                        driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::TD, name: "Chad",                                                 full_name: Some("the Republic of Chad"),                                     vehicle_code: Some("TCH"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 11)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XAF ],                                       languages: &[ LanguageCode::AR, LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::TF, name: "French Southern Territories",                          full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::TG, name: "Togo",                                                 full_name: Some("the Togolese Republic"),                                    vehicle_code: Some("TG"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((4, 27)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XOF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::TH, name: "Thailand",                                             full_name: Some("the Kingdom of Thailand"),                                  vehicle_code: Some("T"),   first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((12, 5)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::THB ],                                       languages: &[ LanguageCode::TH ] },
+	CountryInfo { code: CountryCode::TJ, name: "Tajikistan",                                           full_name: Some("the Republic of Tajikistan"),                               vehicle_code: Some("TJ"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 9)),   postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::TJS ],                                       languages: &[ LanguageCode::TG ] },
+	CountryInfo { code: CountryCode::TK, name: "Tokelau",                                              full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::NZ), currencies: &[ CurrencyCode::NZD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::TL, name: "Timor-Leste",                                          full_name: Some("the Democratic Republic of Timor-Leste"),                   vehicle_code: Some("TL"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((11, 28)), postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::PT ] },
+	CountryInfo { code: CountryCode::TM, name: "Turkmenistan",                                         full_name: Some("Turkmenistan"),                                             vehicle_code: Some("TM"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 27)), postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::TMT ],                                       languages: &[ LanguageCode::TK ] },
+	CountryInfo { code: CountryCode::TN, name: "Tunisia",                                              full_name: Some("the Republic of Tunisia"),                                  vehicle_code: Some("TN"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((3, 20)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::TND ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::TO, name: "Tonga",                                                full_name: Some("the Kingdom of Tonga"),                                     vehicle_code: Some("TO"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 4)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::TOP ],                                       languages: &[ LanguageCode::EN, LanguageCode::TO ] },
+	CountryInfo { code: CountryCode::TR, name: "Türkiye",                                              full_name: Some("the Republic of Türkiye"),                                  vehicle_code: Some("TR"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 29)), postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::TRY ],                                       languages: &[ LanguageCode::TR ] },
+	CountryInfo { code: CountryCode::TT, name: "Trinidad and Tobago",                                  full_name: Some("the Republic of Trinidad and Tobago"),                      vehicle_code: Some("TT"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 31)),  postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::TTD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::TV, name: "Tuvalu",                                               full_name: Some("Tuvalu"),                                                   vehicle_code: Some("TUV"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 1)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::AUD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::TW, name: "Taiwan, Province of China",                            full_name: None,                                                             vehicle_code: Some("RC"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 10)), postal_codes: &[ "###", "#####", "######" ],                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: false, un_status: UnStatus::NonMember, sovereign: None,                  currencies: &[ CurrencyCode::TWD ],                                       languages: &[ LanguageCode::ZH ] },
+	CountryInfo { code: CountryCode::TZ, name: "Tanzania, United Republic of",                         full_name: Some("the United Republic of Tanzania"),                          vehicle_code: Some("EAT"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((4, 26)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::TZS ],                                       languages: &[ LanguageCode::EN, LanguageCode::SW ] },
+	CountryInfo { code: CountryCode::UA, name: "Ukraine",                                              full_name: Some("Ukraine"),                                                  vehicle_code: Some("UA"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 24)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::UAH ],                                       languages: &[ LanguageCode::UK ] },
+	CountryInfo { code: CountryCode::UG, name: "Uganda",                                               full_name: Some("the Republic of Uganda"),                                   vehicle_code: Some("EAU"), first_day: Weekday::Monday,   weekend: &[ Weekday::Sunday ],                    national_day: Some((10, 9)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::UGX ],                                       languages: &[ LanguageCode::EN, LanguageCode::SW ] },
+	CountryInfo { code: CountryCode::UM, name: "United States Minor Outlying Islands",                 full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "96898" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Us,       observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::US), currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::US, name: "United States of America",                             full_name: Some("the United States of America"),                             vehicle_code: Some("USA"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 4)),   postal_codes: &[ "#####", "#####-####" ],                                             driving_side: DrivingSide::Right, measurement: MeasurementSystem::Us,       observes_dst: true,  region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::USD, CurrencyCode::USN ],                    languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::UY, name: "Uruguay",                                              full_name: Some("the Eastern Republic of Uruguay"),                          vehicle_code: Some("ROU"), first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((8, 25)),  postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::UYI, CurrencyCode::UYU, CurrencyCode::UYW ], languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::UZ, name: "Uzbekistan",                                           full_name: Some("the Republic of Uzbekistan"),                               vehicle_code: Some("UZ"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 1)),   postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::UZS ],                                       languages: &[ LanguageCode::UZ ] },
+	CountryInfo { code: CountryCode::VA, name: "Holy See",                                             full_name: None,                                                             vehicle_code: Some("V"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "00120" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: true,  region: Region::Europe,     independent: true,  un_status: UnStatus::Observer,  sovereign: None,                  currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::IT, LanguageCode::LA ] },
+	CountryInfo { code: CountryCode::VC, name: "Saint Vincent and the Grenadines",                     full_name: Some("Saint Vincent and the Grenadines"),                         vehicle_code: Some("WV"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 27)), postal_codes: &[ "VC####" ],                                                          driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::XCD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::VE, name: "Venezuela (Bolivarian Republic of)",                   full_name: Some("the Bolivarian Republic of Venezuela"),                     vehicle_code: Some("YV"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 5)),   postal_codes: &[ "####", "####-@" ],                                                  driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::VED, CurrencyCode::VES ],                    languages: &[ LanguageCode::ES ] },
+	CountryInfo { code: CountryCode::VG, name: "Virgin Islands (British)",                             full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "VG####" ],                                                          driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::GB), currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::VI, name: "Virgin Islands (U.S.)",                                full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "008##", "008##-####" ],                                             driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Us,       observes_dst: false, region: Region::Americas,   independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::US), currencies: &[ CurrencyCode::USD ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::VN, name: "Viet Nam",                                             full_name: Some("the Socialist Republic of Viet Nam"),                       vehicle_code: Some("VN"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((9, 2)),   postal_codes: &[ "######" ],                                                          driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::VND ],                                       languages: &[ LanguageCode::VI ] },
+	CountryInfo { code: CountryCode::VU, name: "Vanuatu",                                              full_name: Some("the Republic of Vanuatu"),                                  vehicle_code: Some("VU"),  first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((7, 30)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::VUV ],                                       languages: &[ LanguageCode::BI, LanguageCode::EN, LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::WF, name: "Wallis and Futuna",                                    full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "986##" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::XPF ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::WS, name: "Samoa",                                                full_name: Some("the Independent State of Samoa"),                           vehicle_code: Some("WS"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((6, 1)),   postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Oceania,    independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::WST ],                                       languages: &[ LanguageCode::EN, LanguageCode::SM ] },
+	CountryInfo { code: CountryCode::YE, name: "Yemen",                                                full_name: Some("the Republic of Yemen"),                                    vehicle_code: Some("YAR"), first_day: Weekday::Sunday,   weekend: &[ Weekday::Friday, Weekday::Saturday ], national_day: Some((5, 22)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Asia,       independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::YER ],                                       languages: &[ LanguageCode::AR ] },
+	CountryInfo { code: CountryCode::YT, name: "Mayotte",                                              full_name: None,                                                             vehicle_code: None,        first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: None,           postal_codes: &[ "976##" ],                                                           driving_side: DrivingSide::Right, measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: false, un_status: UnStatus::NonMember, sovereign: Some(CountryCode::FR), currencies: &[ CurrencyCode::EUR ],                                       languages: &[ LanguageCode::FR ] },
+	CountryInfo { code: CountryCode::ZA, name: "South Africa",                                         full_name: Some("the Republic of South Africa"),                             vehicle_code: Some("ZA"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((4, 27)),  postal_codes: &[ "####" ],                                                            driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::ZAR ],                                       languages: &[ LanguageCode::AF, LanguageCode::EN, LanguageCode::NR, LanguageCode::SS, LanguageCode::ST, LanguageCode::TN, LanguageCode::TS, LanguageCode::VE, LanguageCode::XH, LanguageCode::ZU ] },
+	CountryInfo { code: CountryCode::ZM, name: "Zambia",                                               full_name: Some("the Republic of Zambia"),                                   vehicle_code: Some("Z"),   first_day: Weekday::Monday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((10, 24)), postal_codes: &[ "#####" ],                                                           driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::ZMW ],                                       languages: &[ LanguageCode::EN ] },
+	CountryInfo { code: CountryCode::ZW, name: "Zimbabwe",                                             full_name: Some("the Republic of Zimbabwe"),                                 vehicle_code: Some("ZW"),  first_day: Weekday::Sunday,   weekend: &[ Weekday::Saturday, Weekday::Sunday ], national_day: Some((4, 18)),  postal_codes: &[],                                                                    driving_side: DrivingSide::Left,  measurement: MeasurementSystem::Metric,   observes_dst: false, region: Region::Africa,     independent: true,  un_status: UnStatus::Member,    sovereign: None,                  currencies: &[ CurrencyCode::ZWL ],                                       languages: &[ LanguageCode::EN, LanguageCode::NR, LanguageCode::NY, LanguageCode::SN, LanguageCode::ST, LanguageCode::TN, LanguageCode::VE, LanguageCode::XH ] },
+];
+
+/// The countries, indexed by normalised name.
+/// 
+/// Both the short and full names are included, normalised using [`bare()`], so
+/// that parsing by name is a single lookup. Where names clash, short names take
+/// precedence over full names.
+/// 
+static NAMES: LazyLock<SortedMap<String, Country>> = LazyLock::new(|| {
+	SortedMap::new(
+		COUNTRIES.iter().map(|info| (bare(info.name), info.code.country()))
+			.chain(COUNTRIES.iter().filter_map(|info| info.full_name.map(|full_name| (bare(full_name), info.code.country()))))
+	)
+});
+
+/// The ASCII transliterations of the country names that are not ASCII.
+/// 
+/// Letters with accents are replaced by their plain equivalents, e.g. "Åland
+/// Islands" becomes "Aland Islands". Countries whose names are already ASCII
+/// are not listed. The entries are sorted by country code.
+/// 
+/// # See also
+/// 
+/// * [`Country::ascii_name()`]
+/// 
+static ASCII_NAMES: [(CountryCode, &str); 6] = [
+	(CountryCode::AX, "Aland Islands"),
+	(CountryCode::BL, "Saint Barthelemy"),
+	(CountryCode::CI, "Cote d'Ivoire"),
+	(CountryCode::CW, "Curacao"),
+	(CountryCode::RE, "Reunion"),
+	(CountryCode::TR, "Turkiye"),
+];
+
+/// The names of countries used on international mail.
+/// 
+/// The Universal Postal Union (UPU) recommends that the name of the country of
+/// destination is written in capital letters on the last line of the address,
+/// in an internationally-known language. These are the commonly-used English
+/// short names, in capital letters and without accents, e.g. "UNITED KINGDOM"
+/// rather than the full ISO 3166 name. The entries are sorted by code.
+/// 
+/// # Data sources
+/// 
+/// The conventions are set out by the [UPU](https://www.upu.int) in its
+/// international addressing standard, S42.
+/// 
+/// # See also
+/// 
+/// * [`Country::postal_name()`]
+/// 
+static POSTAL_NAMES: [(CountryCode, &str); COUNT] = [
+	(CountryCode::AD, "ANDORRA"),
+	(CountryCode::AE, "UNITED ARAB EMIRATES"),
+	(CountryCode::AF, "AFGHANISTAN"),
+	(CountryCode::AG, "ANTIGUA AND BARBUDA"),
+	(CountryCode::AI, "ANGUILLA"),
+	(CountryCode::AL, "ALBANIA"),
+	(CountryCode::AM, "ARMENIA"),
+	(CountryCode::AO, "ANGOLA"),
+	(CountryCode::AQ, "ANTARCTICA"),
+	(CountryCode::AR, "ARGENTINA"),
+	(CountryCode::AS, "AMERICAN SAMOA"),
+	(CountryCode::AT, "AUSTRIA"),
+	(CountryCode::AU, "AUSTRALIA"),
+	(CountryCode::AW, "ARUBA"),
+	(CountryCode::AX, "ALAND ISLANDS"),
+	(CountryCode::AZ, "AZERBAIJAN"),
+	(CountryCode::BA, "BOSNIA AND HERZEGOVINA"),
+	(CountryCode::BB, "BARBADOS"),
+	(CountryCode::BD, "BANGLADESH"),
+	(CountryCode::BE, "BELGIUM"),
+	(CountryCode::BF, "BURKINA FASO"),
+	(CountryCode::BG, "BULGARIA"),
+	(CountryCode::BH, "BAHRAIN"),
+	(CountryCode::BI, "BURUNDI"),
+	(CountryCode::BJ, "BENIN"),
+	(CountryCode::BL, "SAINT BARTHELEMY"),
+	(CountryCode::BM, "BERMUDA"),
+	(CountryCode::BN, "BRUNEI"),
+	(CountryCode::BO, "BOLIVIA"),
+	(CountryCode::BQ, "BONAIRE, SINT EUSTATIUS AND SABA"),
+	(CountryCode::BR, "BRAZIL"),
+	(CountryCode::BS, "BAHAMAS"),
+	(CountryCode::BT, "BHUTAN"),
+	(CountryCode::BV, "BOUVET ISLAND"),
+	(CountryCode::BW, "BOTSWANA"),
+	(CountryCode::BY, "BELARUS"),
+	(CountryCode::BZ, "BELIZE"),
+	(CountryCode::CA, "CANADA"),
+	(CountryCode::CC, "COCOS (KEELING) ISLANDS"),
+	(CountryCode::CD, "DEMOCRATIC REPUBLIC OF THE CONGO"),
+	(CountryCode::CF, "CENTRAL AFRICAN REPUBLIC"),
+	(CountryCode::CG, "REPUBLIC OF THE CONGO"),
+	(CountryCode::CH, "SWITZERLAND"),
+	(CountryCode::CI, "COTE D'IVOIRE"),
+	(CountryCode::CK, "COOK ISLANDS"),
+	(CountryCode::CL, "CHILE"),
+	(CountryCode::CM, "CAMEROON"),
+	(CountryCode::CN, "CHINA"),
+	(CountryCode::CO, "COLOMBIA"),
+	(CountryCode::CR, "COSTA RICA"),
+	(CountryCode::CU, "CUBA"),
+	(CountryCode::CV, "CABO VERDE"),
+	(CountryCode::CW, "CURACAO"),
+	(CountryCode::CX, "CHRISTMAS ISLAND"),
+	(CountryCode::CY, "CYPRUS"),
+	(CountryCode::CZ, "CZECHIA"),
+	(CountryCode::DE, "GERMANY"),
+	(CountryCode::DJ, "DJIBOUTI"),
+	(CountryCode::DK, "DENMARK"),
+	(CountryCode::DM, "DOMINICA"),
+	(CountryCode::DO, "DOMINICAN REPUBLIC"),
+	(CountryCode::DZ, "ALGERIA"),
+	(CountryCode::EC, "ECUADOR"),
+	(CountryCode::EE, "ESTONIA"),
+	(CountryCode::EG, "EGYPT"),
+	(CountryCode::EH, "WESTERN SAHARA"),
+	(CountryCode::ER, "ERITREA"),
+	(CountryCode::ES, "SPAIN"),
+	(CountryCode::ET, "ETHIOPIA"),
+	(CountryCode::FI, "FINLAND"),
+	(CountryCode::FJ, "FIJI"),
+	(CountryCode::FK, "FALKLAND ISLANDS"),
+	(CountryCode::FM, "MICRONESIA"),
+	(CountryCode::FO, "FAROE ISLANDS"),
+	(CountryCode::FR, "FRANCE"),
+	(CountryCode::GA, "GABON"),
+	(CountryCode::GB, "UNITED KINGDOM"),
+	(CountryCode::GD, "GRENADA"),
+	(CountryCode::GE, "GEORGIA"),
+	(CountryCode::GF, "FRENCH GUIANA"),
+	(CountryCode::GG, "GUERNSEY"),
+	(CountryCode::GH, "GHANA"),
+	(CountryCode::GI, "GIBRALTAR"),
+	(CountryCode::GL, "GREENLAND"),
+	(CountryCode::GM, "GAMBIA"),
+	(CountryCode::GN, "GUINEA"),
+	(CountryCode::GP, "GUADELOUPE"),
+	(CountryCode::GQ, "EQUATORIAL GUINEA"),
+	(CountryCode::GR, "GREECE"),
+	(CountryCode::GS, "SOUTH GEORGIA AND THE SOUTH SANDWICH ISLANDS"),
+	(CountryCode::GT, "GUATEMALA"),
+	(CountryCode::GU, "GUAM"),
+	(CountryCode::GW, "GUINEA-BISSAU"),
+	(CountryCode::GY, "GUYANA"),
+	(CountryCode::HK, "HONG KONG"),
+	(CountryCode::HM, "HEARD ISLAND AND MCDONALD ISLANDS"),
+	(CountryCode::HN, "HONDURAS"),
+	(CountryCode::HR, "CROATIA"),
+	(CountryCode::HT, "HAITI"),
+	(CountryCode::HU, "HUNGARY"),
+	(CountryCode::ID, "INDONESIA"),
+	(CountryCode::IE, "IRELAND"),
+	(CountryCode::IL, "ISRAEL"),
+	(CountryCode::IM, "ISLE OF MAN"),
+	(CountryCode::IN, "INDIA"),
+	(CountryCode::IO, "BRITISH INDIAN OCEAN TERRITORY"),
+	(CountryCode::IQ, "IRAQ"),
+	(CountryCode::IR, "IRAN"),
+	(CountryCode::IS, "ICELAND"),
+	(CountryCode::IT, "ITALY"),
+	(CountryCode::JE, "JERSEY"),
+	(CountryCode::JM, "JAMAICA"),
+	(CountryCode::JO, "JORDAN"),
+	(CountryCode::JP, "JAPAN"),
+	(CountryCode::KE, "KENYA"),
+	(CountryCode::KG, "KYRGYZSTAN"),
+	(CountryCode::KH, "CAMBODIA"),
+	(CountryCode::KI, "KIRIBATI"),
+	(CountryCode::KM, "COMOROS"),
+	(CountryCode::KN, "SAINT KITTS AND NEVIS"),
+	(CountryCode::KP, "NORTH KOREA"),
+	(CountryCode::KR, "SOUTH KOREA"),
+	(CountryCode::KW, "KUWAIT"),
+	(CountryCode::KY, "CAYMAN ISLANDS"),
+	(CountryCode::KZ, "KAZAKHSTAN"),
+	(CountryCode::LA, "LAOS"),
+	(CountryCode::LB, "LEBANON"),
+	(CountryCode::LC, "SAINT LUCIA"),
+	(CountryCode::LI, "LIECHTENSTEIN"),
+	(CountryCode::LK, "SRI LANKA"),
+	(CountryCode::LR, "LIBERIA"),
+	(CountryCode::LS, "LESOTHO"),
+	(CountryCode::LT, "LITHUANIA"),
+	(CountryCode::LU, "LUXEMBOURG"),
+	(CountryCode::LV, "LATVIA"),
+	(CountryCode::LY, "LIBYA"),
+	(CountryCode::MA, "MOROCCO"),
+	(CountryCode::MC, "MONACO"),
+	(CountryCode::MD, "MOLDOVA"),
+	(CountryCode::ME, "MONTENEGRO"),
+	(CountryCode::MF, "SAINT MARTIN"),
+	(CountryCode::MG, "MADAGASCAR"),
+	(CountryCode::MH, "MARSHALL ISLANDS"),
+	(CountryCode::MK, "NORTH MACEDONIA"),
+	(CountryCode::ML, "MALI"),
+	(CountryCode::MM, "MYANMAR"),
+	(CountryCode::MN, "MONGOLIA"),
+	(CountryCode::MO, "MACAO"),
+	(CountryCode::MP, "NORTHERN MARIANA ISLANDS"),
+	(CountryCode::MQ, "MARTINIQUE"),
+	(CountryCode::MR, "MAURITANIA"),
+	(CountryCode::MS, "MONTSERRAT"),
+	(CountryCode::MT, "MALTA"),
+	(CountryCode::MU, "MAURITIUS"),
+	(CountryCode::MV, "MALDIVES"),
+	(CountryCode::MW, "MALAWI"),
+	(CountryCode::MX, "MEXICO"),
+	(CountryCode::MY, "MALAYSIA"),
+	(CountryCode::MZ, "MOZAMBIQUE"),
+	(CountryCode::NA, "NAMIBIA"),
+	(CountryCode::NC, "NEW CALEDONIA"),
+	(CountryCode::NE, "NIGER"),
+	(CountryCode::NF, "NORFOLK ISLAND"),
+	(CountryCode::NG, "NIGERIA"),
+	(CountryCode::NI, "NICARAGUA"),
+	(CountryCode::NL, "NETHERLANDS"),
+	(CountryCode::NO, "NORWAY"),
+	(CountryCode::NP, "NEPAL"),
+	(CountryCode::NR, "NAURU"),
+	(CountryCode::NU, "NIUE"),
+	(CountryCode::NZ, "NEW ZEALAND"),
+	(CountryCode::OM, "OMAN"),
+	(CountryCode::PA, "PANAMA"),
+	(CountryCode::PE, "PERU"),
+	(CountryCode::PF, "FRENCH POLYNESIA"),
+	(CountryCode::PG, "PAPUA NEW GUINEA"),
+	(CountryCode::PH, "PHILIPPINES"),
+	(CountryCode::PK, "PAKISTAN"),
+	(CountryCode::PL, "POLAND"),
+	(CountryCode::PM, "SAINT PIERRE AND MIQUELON"),
+	(CountryCode::PN, "PITCAIRN"),
+	(CountryCode::PR, "PUERTO RICO"),
+	(CountryCode::PS, "PALESTINE"),
+	(CountryCode::PT, "PORTUGAL"),
+	(CountryCode::PW, "PALAU"),
+	(CountryCode::PY, "PARAGUAY"),
+	(CountryCode::QA, "QATAR"),
+	(CountryCode::RE, "REUNION"),
+	(CountryCode::RO, "ROMANIA"),
+	(CountryCode::RS, "SERBIA"),
+	(CountryCode::RU, "RUSSIA"),
+	(CountryCode::RW, "RWANDA"),
+	(CountryCode::SA, "SAUDI ARABIA"),
+	(CountryCode::SB, "SOLOMON ISLANDS"),
+	(CountryCode::SC, "SEYCHELLES"),
+	(CountryCode::SD, "SUDAN"),
+	(CountryCode::SE, "SWEDEN"),
+	(CountryCode::SG, "SINGAPORE"),
+	(CountryCode::SH, "SAINT HELENA"),
+	(CountryCode::SI, "SLOVENIA"),
+	(CountryCode::SJ, "SVALBARD AND JAN MAYEN"),
+	(CountryCode::SK, "SLOVAKIA"),
+	(CountryCode::SL, "SIERRA LEONE"),
+	(CountryCode::SM, "SAN MARINO"),
+	(CountryCode::SN, "SENEGAL"),
+	(CountryCode::SO, "SOMALIA"),
+	(CountryCode::SR, "SURINAME"),
+	(CountryCode::SS, "SOUTH SUDAN"),
+	(CountryCode::ST, "SAO TOME AND PRINCIPE"),
+	(CountryCode::SV, "EL SALVADOR"),
+	(CountryCode::SX, "SINT MAARTEN"),
+	(CountryCode::SY, "SYRIA"),
+	(CountryCode::SZ, "ESWATINI"),
+	(CountryCode::TC, "TURKS AND CAICOS ISLANDS"),
+	(CountryCode::TD, "CHAD"),
+	(CountryCode::TF, "FRENCH SOUTHERN TERRITORIES"),
+	(CountryCode::TG, "TOGO"),
+	(CountryCode::TH, "THAILAND"),
+	(CountryCode::TJ, "TAJIKISTAN"),
+	(CountryCode::TK, "TOKELAU"),
+	(CountryCode::TL, "TIMOR-LESTE"),
+	(CountryCode::TM, "TURKMENISTAN"),
+	(CountryCode::TN, "TUNISIA"),
+	(CountryCode::TO, "TONGA"),
+	(CountryCode::TR, "TURKIYE"),
+	(CountryCode::TT, "TRINIDAD AND TOBAGO"),
+	(CountryCode::TV, "TUVALU"),
+	(CountryCode::TW, "TAIWAN"),
+	(CountryCode::TZ, "TANZANIA"),
+	(CountryCode::UA, "UKRAINE"),
+	(CountryCode::UG, "UGANDA"),
+	(CountryCode::UM, "UNITED STATES MINOR OUTLYING ISLANDS"),
+	(CountryCode::US, "UNITED STATES"),
+	(CountryCode::UY, "URUGUAY"),
+	(CountryCode::UZ, "UZBEKISTAN"),
+	(CountryCode::VA, "VATICAN"),
+	(CountryCode::VC, "SAINT VINCENT AND THE GRENADINES"),
+	(CountryCode::VE, "VENEZUELA"),
+	(CountryCode::VG, "BRITISH VIRGIN ISLANDS"),
+	(CountryCode::VI, "UNITED STATES VIRGIN ISLANDS"),
+	(CountryCode::VN, "VIET NAM"),
+	(CountryCode::VU, "VANUATU"),
+	(CountryCode::WF, "WALLIS AND FUTUNA"),
+	(CountryCode::WS, "SAMOA"),
+	(CountryCode::YE, "YEMEN"),
+	(CountryCode::YT, "MAYOTTE"),
+	(CountryCode::ZA, "SOUTH AFRICA"),
+	(CountryCode::ZM, "ZAMBIA"),
+	(CountryCode::ZW, "ZIMBABWE"),
+];
+
+/// The commonly-used non-ISO codes for countries.
+/// 
+/// These are codes that are widely used in place of the ISO codes, such as
+/// `UK` for the United Kingdom, and are accepted when parsing with the
+/// [`quirks`](ParseOptions::quirks()) option. The entries are sorted by code.
+/// 
+static QUIRKS: [(&str, CountryCode); 3] = [
+	("EL", CountryCode::GR),
+	("FX", CountryCode::FR),
+	("UK", CountryCode::GB),
+];
+
+/// The special codes for countries in machine-readable travel documents.
+/// 
+/// These are the codes used in place of the alpha-3 codes in the MRZ of
+/// passports and identity cards, as specified by ICAO Doc 9303, and are
+/// accepted when parsing with the [`mrz`](ParseOptions::mrz()) option. The
+/// `GB` codes denote the classes of British nationality other than British
+/// citizen. The entries are sorted by code.
+/// 
+static MRZ_CODES: [(&str, CountryCode); 6] = [
+	("D",   CountryCode::DE),
+	("GBD", CountryCode::GB),
+	("GBN", CountryCode::GB),
+	("GBO", CountryCode::GB),
+	("GBP", CountryCode::GB),
+	("GBS", CountryCode::GB),
+];
+
+/// The dates on which the data for countries last changed.
+/// 
+/// These are the ISO 8601 dates on which changes to the data for a country
+/// took effect, such as a change of name, e.g. Eswatini, or of currency, e.g.
+/// Croatia adopting the euro. Countries without a recorded change are not
+/// listed. The entries are sorted by code.
+/// 
+/// # See also
+/// 
+/// * [`Country::last_changed()`]
+/// * [`Country::changes_since()`]
+/// 
+static CHANGES: [(CountryCode, &str); 9] = [
+	(CountryCode::CU, "2021-01-01"),
+	(CountryCode::HR, "2023-01-01"),
+	(CountryCode::MK, "2019-02-12"),
+	(CountryCode::MR, "2018-01-01"),
+	(CountryCode::SL, "2022-07-01"),
+	(CountryCode::ST, "2018-01-01"),
+	(CountryCode::SZ, "2018-04-19"),
+	(CountryCode::TR, "2022-06-01"),
+	(CountryCode::VE, "2021-10-01"),
+];
+
+/// The approximate population and total area of each country.
+/// 
+/// The population is the estimated number of residents, rounded to around
+/// three significant figures, based on the estimates of the United Nations
+/// World Population Prospects and national statistics offices, as of 2023.
+/// The area is the total area in square kilometres, including inland water,
+/// rounded to the nearest square kilometre, with a minimum of one. These
+/// figures are reviewed annually, when the new population estimates are
+/// published, and are intended as denominators for per-capita and density
+/// calculations, rather than as authoritative statistics. The entries are
+/// sorted by country code.
+/// 
+/// # See also
+/// 
+/// * [`Country::population()`]
+/// * [`Country::area()`]
+/// 
+#[cfg(feature = "extended-data")]
+static STATISTICS: [(CountryCode, (u64, u32)); COUNT] = [
+	(CountryCode::AD, (       80_000,        468)),
+	(CountryCode::AE, (    9_500_000,     83_600)),
+	(CountryCode::AF, (   42_200_000,    652_230)),
+	(CountryCode::AG, (       94_000,        442)),
+	(CountryCode::AI, (       16_000,         91)),
+	(CountryCode::AL, (    2_750_000,     28_748)),
+	(CountryCode::AM, (    2_780_000,     29_743)),
+	(CountryCode::AO, (   36_700_000,  1_246_700)),
+	(CountryCode::AQ, (            0, 14_000_000)),
+	(CountryCode::AR, (   46_700_000,  2_780_400)),
+	(CountryCode::AS, (       44_000,        199)),
+	(CountryCode::AT, (    9_100_000,     83_879)),
+	(CountryCode::AU, (   26_600_000,  7_692_024)),
+	(CountryCode::AW, (      107_000,        180)),
+	(CountryCode::AX, (       30_000,      1_580)),
+	(CountryCode::AZ, (   10_100_000,     86_600)),
+	(CountryCode::BA, (    3_200_000,     51_197)),
+	(CountryCode::BB, (      282_000,        430)),
+	(CountryCode::BD, (  173_000_000,    147_570)),
+	(CountryCode::BE, (   11_800_000,     30_689)),
+	(CountryCode::BF, (   23_300_000,    274_200)),
+	(CountryCode::BG, (    6_450_000,    110_994)),
+	(CountryCode::BH, (    1_570_000,        786)),
+	(CountryCode::BI, (   13_200_000,     27_834)),
+	(CountryCode::BJ, (   13_700_000,    114_763)),
+	(CountryCode::BL, (       10_000,         25)),
+	(CountryCode::BM, (       64_000,         54)),
+	(CountryCode::BN, (      452_000,      5_765)),
+	(CountryCode::BO, (   12_400_000,  1_098_581)),
+	(CountryCode::BQ, (       27_000,        328)),
+	(CountryCode::BR, (  216_400_000,  8_515_767)),
+	(CountryCode::BS, (      412_000,     13_943)),
+	(CountryCode::BT, (      787_000,     38_394)),
+	(CountryCode::BV, (            0,         49)),
+	(CountryCode::BW, (    2_680_000,    581_730)),
+	(CountryCode::BY, (    9_200_000,    207_600)),
+	(CountryCode::BZ, (      410_000,     22_966)),
+	(CountryCode::CA, (   39_300_000,  9_984_670)),
+	(CountryCode::CC, (          600,         14)),
+	(CountryCode::CD, (  102_300_000,  2_344_858)),
+	(CountryCode::CF, (    5_700_000,    622_984)),
+	(CountryCode::CG, (    6_100_000,    342_000)),
+	(CountryCode::CH, (    8_800_000,     41_285)),
+	(CountryCode::CI, (   28_900_000,    322_463)),
+	(CountryCode::CK, (       17_000,        236)),
+	(CountryCode::CL, (   19_600_000,    756_102)),
+	(CountryCode::CM, (   28_600_000,    475_442)),
+	(CountryCode::CN, (1_410_000_000,  9_596_961)),
+	(CountryCode::CO, (   52_100_000,  1_141_748)),
+	(CountryCode::CR, (    5_200_000,     51_100)),
+	(CountryCode::CU, (   11_200_000,    109_884)),
+	(CountryCode::CV, (      598_000,      4_033)),
+	(CountryCode::CW, (      192_000,        444)),
+	(CountryCode::CX, (        1_700,        135)),
+	(CountryCode::CY, (    1_260_000,      9_251)),
+	(CountryCode::CZ, (   10_900_000,     78_871)),
+	(CountryCode::DE, (   84_500_000,    357_588)),
+	(CountryCode::DJ, (    1_140_000,     23_200)),
+	(CountryCode::DK, (    5_950_000,     42_933)),
+	(CountryCode::DM, (       73_000,        751)),
+	(CountryCode::DO, (   11_300_000,     48_671)),
+	(CountryCode::DZ, (   45_600_000,  2_381_741)),
+	(CountryCode::EC, (   18_200_000,    256_370)),
+	(CountryCode::EE, (    1_370_000,     45_339)),
+	(CountryCode::EG, (  112_700_000,  1_002_450)),
+	(CountryCode::EH, (      588_000,    266_000)),
+	(CountryCode::ER, (    3_750_000,    117_600)),
+	(CountryCode::ES, (   48_300_000,    505_990)),
+	(CountryCode::ET, (  126_500_000,  1_104_300)),
+	(CountryCode::FI, (    5_600_000,    338_455)),
+	(CountryCode::FJ, (      936_000,     18_274)),
+	(CountryCode::FK, (        3_700,     12_173)),
+	(CountryCode::FM, (      115_000,        702)),
+	(CountryCode::FO, (       54_000,      1_399)),
+	(CountryCode::FR, (   68_200_000,    643_801)),
+	(CountryCode::GA, (    2_440_000,    267_668)),
+	(CountryCode::GB, (   68_300_000,    242_495)),
+	(CountryCode::GD, (      127_000,        344)),
+	(CountryCode::GE, (    3_760_000,     69_700)),
+	(CountryCode::GF, (      295_000,     83_534)),
+	(CountryCode::GG, (       64_000,         65)),
+	(CountryCode::GH, (   34_100_000,    238_533)),
+	(CountryCode::GI, (       33_000,          7)),
+	(CountryCode::GL, (       56_000,  2_166_086)),
+	(CountryCode::GM, (    2_770_000,     11_295)),
+	(CountryCode::GN, (   14_200_000,    245_857)),
+	(CountryCode::GP, (      378_000,      1_628)),
+	(CountryCode::GQ, (    1_710_000,     28_051)),
+	(CountryCode::GR, (   10_400_000,    131_957)),
+	(CountryCode::GS, (           30,      3_903)),
+	(CountryCode::GT, (   18_100_000,    108_889)),
+	(CountryCode::GU, (      172_000,        549)),
+	(CountryCode::GW, (    2_150_000,     36_125)),
+	(CountryCode::GY, (      813_000,    214_969)),
+	(CountryCode::HK, (    7_500_000,      1_114)),
+	(CountryCode::HM, (            0,        412)),
+	(CountryCode::HN, (   10_600_000,    112_492)),
+	(CountryCode::HR, (    3_860_000,     56_594)),
+	(CountryCode::HT, (   11_700_000,     27_750)),
+	(CountryCode::HU, (    9_600_000,     93_028)),
+	(CountryCode::ID, (  277_500_000,  1_904_569)),
+	(CountryCode::IE, (    5_260_000,     70_273)),
+	(CountryCode::IL, (    9_800_000,     22_072)),
+	(CountryCode::IM, (       84_000,        572)),
+	(CountryCode::IN, (1_428_600_000,  3_287_263)),
+	(CountryCode::IO, (        3_000,         60)),
+	(CountryCode::IQ, (   45_500_000,    438_317)),
+	(CountryCode::IR, (   89_200_000,  1_648_195)),
+	(CountryCode::IS, (      388_000,    103_000)),
+	(CountryCode::IT, (   58_900_000,    302_073)),
+	(CountryCode::JE, (      103_000,        118)),
+	(CountryCode::JM, (    2_830_000,     10_991)),
+	(CountryCode::JO, (   11_300_000,     89_342)),
+	(CountryCode::JP, (  124_500_000,    377_975)),
+	(CountryCode::KE, (   55_100_000,    580_367)),
+	(CountryCode::KG, (    7_000_000,    199_951)),
+	(CountryCode::KH, (   16_900_000,    181_035)),
+	(CountryCode::KI, (      133_000,        811)),
+	(CountryCode::KM, (      852_000,      1_861)),
+	(CountryCode::KN, (       47_000,        261)),
+	(CountryCode::KP, (   26_200_000,    120_540)),
+	(CountryCode::KR, (   51_700_000,    100_210)),
+	(CountryCode::KW, (    4_310_000,     17_818)),
+	(CountryCode::KY, (       69_000,        264)),
+	(CountryCode::KZ, (   19_900_000,  2_724_900)),
+	(CountryCode::LA, (    7_630_000,    236_800)),
+	(CountryCode::LB, (    5_350_000,     10_452)),
+	(CountryCode::LC, (      180_000,        617)),
+	(CountryCode::LI, (       39_000,        160)),
+	(CountryCode::LK, (   21_900_000,     65_610)),
+	(CountryCode::LR, (    5_420_000,    111_369)),
+	(CountryCode::LS, (    2_330_000,     30_355)),
+	(CountryCode::LT, (    2_870_000,     65_300)),
+	(CountryCode::LU, (      660_000,      2_586)),
+	(CountryCode::LV, (    1_880_000,     64_589)),
+	(CountryCode::LY, (    6_890_000,  1_759_540)),
+	(CountryCode::MA, (   37_800_000,    446_550)),
+	(CountryCode::MC, (       38_000,          2)),
+	(CountryCode::MD, (    2_490_000,     33_846)),
+	(CountryCode::ME, (      617_000,     13_812)),
+	(CountryCode::MF, (       32_000,         53)),
+	(CountryCode::MG, (   30_300_000,    587_041)),
+	(CountryCode::MH, (       42_000,        181)),
+	(CountryCode::MK, (    1_830_000,     25_713)),
+	(CountryCode::ML, (   23_300_000,  1_240_192)),
+	(CountryCode::MM, (   54_600_000,    676_578)),
+	(CountryCode::MN, (    3_450_000,  1_564_116)),
+	(CountryCode::MO, (      704_000,         33)),
+	(CountryCode::MP, (       50_000,        464)),
+	(CountryCode::MQ, (      350_000,      1_128)),
+	(CountryCode::MR, (    4_860_000,  1_030_700)),
+	(CountryCode::MS, (        4_400,        102)),
+	(CountryCode::MT, (      535_000,        316)),
+	(CountryCode::MU, (    1_270_000,      2_040)),
+	(CountryCode::MV, (      521_000,        298)),
+	(CountryCode::MW, (   20_900_000,    118_484)),
+	(CountryCode::MX, (  128_500_000,  1_964_375)),
+	(CountryCode::MY, (   34_300_000,    330_803)),
+	(CountryCode::MZ, (   33_900_000,    801_590)),
+	(CountryCode::NA, (    2_600_000,    825_615)),
+	(CountryCode::NC, (      290_000,     18_575)),
+	(CountryCode::NE, (   27_200_000,  1_267_000)),
+	(CountryCode::NF, (        2_200,         36)),
+	(CountryCode::NG, (  223_800_000,    923_768)),
+	(CountryCode::NI, (    7_050_000,    130_373)),
+	(CountryCode::NL, (   17_900_000,     41_850)),
+	(CountryCode::NO, (    5_500_000,    385_207)),
+	(CountryCode::NP, (   30_900_000,    147_516)),
+	(CountryCode::NR, (       12_800,         21)),
+	(CountryCode::NU, (        1_900,        261)),
+	(CountryCode::NZ, (    5_220_000,    268_838)),
+	(CountryCode::OM, (    4_640_000,    309_500)),
+	(CountryCode::PA, (    4_470_000,     75_417)),
+	(CountryCode::PE, (   34_400_000,  1_285_216)),
+	(CountryCode::PF, (      308_000,      4_167)),
+	(CountryCode::PG, (   10_300_000,    462_840)),
+	(CountryCode::PH, (  117_300_000,    300_000)),
+	(CountryCode::PK, (  240_500_000,    881_913)),
+	(CountryCode::PL, (   36_700_000,    312_696)),
+	(CountryCode::PM, (        5_800,        242)),
+	(CountryCode::PN, (           50,         47)),
+	(CountryCode::PR, (    3_260_000,      9_104)),
+	(CountryCode::PS, (    5_370_000,      6_020)),
+	(CountryCode::PT, (   10_500_000,     92_212)),
+	(CountryCode::PW, (       18_000,        459)),
+	(CountryCode::PY, (    6_860_000,    406_752)),
+	(CountryCode::QA, (    2_720_000,     11_586)),
+	(CountryCode::RE, (      880_000,      2_511)),
+	(CountryCode::RO, (   19_000_000,    238_397)),
+	(CountryCode::RS, (    6_620_000,     77_474)),
+	(CountryCode::RU, (  144_400_000, 17_098_246)),
+	(CountryCode::RW, (   14_100_000,     26_338)),
+	(CountryCode::SA, (   36_900_000,  2_149_690)),
+	(CountryCode::SB, (      740_000,     28_896)),
+	(CountryCode::SC, (      107_000,        459)),
+	(CountryCode::SD, (   48_100_000,  1_886_068)),
+	(CountryCode::SE, (   10_600_000,    450_295)),
+	(CountryCode::SG, (    5_920_000,        734)),
+	(CountryCode::SH, (        5_600,        394)),
+	(CountryCode::SI, (    2_120_000,     20_273)),
+	(CountryCode::SJ, (        2_500,     61_399)),
+	(CountryCode::SK, (    5_430_000,     49_035)),
+	(CountryCode::SL, (    8_790_000,     71_740)),
+	(CountryCode::SM, (       34_000,         61)),
+	(CountryCode::SN, (   17_800_000,    196_722)),
+	(CountryCode::SO, (   18_100_000,    637_657)),
+	(CountryCode::SR, (      623_000,    163_820)),
+	(CountryCode::SS, (   11_100_000,    619_745)),
+	(CountryCode::ST, (      231_000,        964)),
+	(CountryCode::SV, (    6_360_000,     21_041)),
+	(CountryCode::SX, (       44_000,         34)),
+	(CountryCode::SY, (   23_200_000,    185_180)),
+	(CountryCode::SZ, (    1_210_000,     17_364)),
+	(CountryCode::TC, (       46_000,        948)),
+	(CountryCode::TD, (   18_300_000,  1_284_000)),
+	(CountryCode::TF, (          150,      7_747)),
+	(CountryCode::TG, (    9_050_000,     56_785)),
+	(CountryCode::TH, (   71_800_000,    513_120)),
+	(CountryCode::TJ, (   10_100_000,    143_100)),
+	(CountryCode::TK, (        1_900,         12)),
+	(CountryCode::TL, (    1_360_000,     14_874)),
+	(CountryCode::TM, (    6_500_000,    488_100)),
+	(CountryCode::TN, (   12_500_000,    163_610)),
+	(CountryCode::TO, (      108_000,        747)),
+	(CountryCode::TR, (   85_300_000,    783_562)),
+	(CountryCode::TT, (    1_530_000,      5_128)),
+	(CountryCode::TV, (       11_000,         26)),
+	(CountryCode::TW, (   23_900_000,     36_197)),
+	(CountryCode::TZ, (   67_400_000,    947_303)),
+	(CountryCode::UA, (   37_000_000,    603_550)),
+	(CountryCode::UG, (   48_600_000,    241_550)),
+	(CountryCode::UM, (          300,         34)),
+	(CountryCode::US, (  334_900_000,  9_833_520)),
+	(CountryCode::UY, (    3_420_000,    176_215)),
+	(CountryCode::UZ, (   35_200_000,    448_978)),
+	(CountryCode::VA, (          800,          1)),
+	(CountryCode::VC, (      104_000,        389)),
+	(CountryCode::VE, (   28_800_000,    916_445)),
+	(CountryCode::VG, (       31_000,        151)),
+	(CountryCode::VI, (       99_000,        347)),
+	(CountryCode::VN, (   98_900_000,    331_212)),
+	(CountryCode::VU, (      335_000,     12_189)),
+	(CountryCode::WF, (       11_000,        142)),
+	(CountryCode::WS, (      225_000,      2_842)),
+	(CountryCode::YE, (   34_400_000,    527_968)),
+	(CountryCode::YT, (      310_000,        374)),
+	(CountryCode::ZA, (   60_400_000,  1_221_037)),
+	(CountryCode::ZM, (   20_600_000,    752_612)),
+	(CountryCode::ZW, (   16_700_000,    390_757)),
+];
+
+/// The collation sort keys of the country names.
+/// 
+/// The keys are held in the same order as [`COUNTRIES`], so that they can be
+/// looked up by position.
+/// 
+/// # See also
+/// 
+/// * [`Country::sort_key()`]
+/// 
+static SORT_KEYS: LazyLock<Vec<String>> = LazyLock::new(|| {
+	COUNTRIES.iter().map(|info| bare(info.name)).collect()
 });
 
+/// The currencies used by convention in countries without an official one.
+/// 
+/// ISO 4217 lists some countries as having no universal currency, and so they
+/// have no currencies in [`COUNTRIES`]. Where other currencies are in general
+/// use, they are given here, e.g. the Israeli new shekel and the Jordanian
+/// dinar in Palestine. Antarctica has no currency in use, and so is not listed.
+/// 
+/// The entries are sorted by country code, and each list of currencies is
+/// sorted by currency code.
+/// 
+/// # See also
+/// 
+/// * [`Country::currencies_in_use()`]
+/// 
+static CONVENTIONAL_CURRENCIES: [(CountryCode, &[CurrencyCode]); 2] = [
+	(CountryCode::GS, &[ CurrencyCode::GBP ]),
+	(CountryCode::PS, &[ CurrencyCode::ILS, CurrencyCode::JOD ]),
+];
+
+/// The time zones of countries.
+/// 
+/// The time zones are identified by their names in the IANA time zone
+/// database, e.g. `Europe/London`. Each country's time zones are in the order
+/// given by the database, which generally lists the most populous zone first.
+/// Countries not listed here, i.e. uninhabited territories, have no time zones
+/// of their own. The entries are sorted by country code.
+/// 
+/// # Data sources
+/// 
+/// The time zones are taken from the `zone.tab` file of the
+/// [IANA time zone database](https://www.iana.org/time-zones), version 2025b.
+/// 
+/// # See also
+/// 
+/// * [`Country::timezones()`]
+/// 
+static TIMEZONES: [(CountryCode, &[&str]); 247] = [
+	(CountryCode::AD, &[ "Europe/Andorra" ]),
+	(CountryCode::AE, &[ "Asia/Dubai" ]),
+	(CountryCode::AF, &[ "Asia/Kabul" ]),
+	(CountryCode::AG, &[ "America/Antigua" ]),
+	(CountryCode::AI, &[ "America/Anguilla" ]),
+	(CountryCode::AL, &[ "Europe/Tirane" ]),
+	(CountryCode::AM, &[ "Asia/Yerevan" ]),
+	(CountryCode::AO, &[ "Africa/Luanda" ]),
+	(CountryCode::AQ, &[ "Antarctica/McMurdo", "Antarctica/Casey", "Antarctica/Davis", "Antarctica/DumontDUrville", "Antarctica/Mawson", "Antarctica/Palmer", "Antarctica/Rothera", "Antarctica/Syowa", "Antarctica/Troll", "Antarctica/Vostok" ]),
+	(CountryCode::AR, &[ "America/Argentina/Buenos_Aires", "America/Argentina/Cordoba", "America/Argentina/Salta", "America/Argentina/Jujuy", "America/Argentina/Tucuman", "America/Argentina/Catamarca", "America/Argentina/La_Rioja", "America/Argentina/San_Juan", "America/Argentina/Mendoza", "America/Argentina/San_Luis", "America/Argentina/Rio_Gallegos", "America/Argentina/Ushuaia" ]),
+	(CountryCode::AS, &[ "Pacific/Pago_Pago" ]),
+	(CountryCode::AT, &[ "Europe/Vienna" ]),
+	(CountryCode::AU, &[ "Australia/Lord_Howe", "Antarctica/Macquarie", "Australia/Hobart", "Australia/Melbourne", "Australia/Sydney", "Australia/Broken_Hill", "Australia/Brisbane", "Australia/Lindeman", "Australia/Adelaide", "Australia/Darwin", "Australia/Perth", "Australia/Eucla" ]),
+	(CountryCode::AW, &[ "America/Aruba" ]),
+	(CountryCode::AX, &[ "Europe/Mariehamn" ]),
+	(CountryCode::AZ, &[ "Asia/Baku" ]),
+	(CountryCode::BA, &[ "Europe/Sarajevo" ]),
+	(CountryCode::BB, &[ "America/Barbados" ]),
+	(CountryCode::BD, &[ "Asia/Dhaka" ]),
+	(CountryCode::BE, &[ "Europe/Brussels" ]),
+	(CountryCode::BF, &[ "Africa/Ouagadougou" ]),
+	(CountryCode::BG, &[ "Europe/Sofia" ]),
+	(CountryCode::BH, &[ "Asia/Bahrain" ]),
+	(CountryCode::BI, &[ "Africa/Bujumbura" ]),
+	(CountryCode::BJ, &[ "Africa/Porto-Novo" ]),
+	(CountryCode::BL, &[ "America/St_Barthelemy" ]),
+	(CountryCode::BM, &[ "Atlantic/Bermuda" ]),
+	(CountryCode::BN, &[ "Asia/Brunei" ]),
+	(CountryCode::BO, &[ "America/La_Paz" ]),
+	(CountryCode::BQ, &[ "America/Kralendijk" ]),
+	(CountryCode::BR, &[ "America/Noronha", "America/Belem", "America/Fortaleza", "America/Recife", "America/Araguaina", "America/Maceio", "America/Bahia", "America/Sao_Paulo", "America/Campo_Grande", "America/Cuiaba", "America/Santarem", "America/Porto_Velho", "America/Boa_Vista", "America/Manaus", "America/Eirunepe", "America/Rio_Branco" ]),
+	(CountryCode::BS, &[ "America/Nassau" ]),
+	(CountryCode::BT, &[ "Asia/Thimphu" ]),
+	(CountryCode::BW, &[ "Africa/Gaborone" ]),
+	(CountryCode::BY, &[ "Europe/Minsk" ]),
+	(CountryCode::BZ, &[ "America/Belize" ]),
+	(CountryCode::CA, &[ "America/St_Johns", "America/Halifax", "America/Glace_Bay", "America/Moncton", "America/Goose_Bay", "America/Blanc-Sablon", "America/Toronto", "America/Iqaluit", "America/Atikokan", "America/Winnipeg", "America/Resolute", "America/Rankin_Inlet", "America/Regina", "America/Swift_Current", "America/Edmonton", "America/Cambridge_Bay", "America/Inuvik", "America/Creston", "America/Dawson_Creek", "America/Fort_Nelson", "America/Whitehorse", "America/Dawson", "America/Vancouver" ]),
+	(CountryCode::CC, &[ "Indian/Cocos" ]),
+	(CountryCode::CD, &[ "Africa/Kinshasa", "Africa/Lubumbashi" ]),
+	(CountryCode::CF, &[ "Africa/Bangui" ]),
+	(CountryCode::CG, &[ "Africa/Brazzaville" ]),
+	(CountryCode::CH, &[ "Europe/Zurich" ]),
+	(CountryCode::CI, &[ "Africa/Abidjan" ]),
+	(CountryCode::CK, &[ "Pacific/Rarotonga" ]),
+	(CountryCode::CL, &[ "America/Santiago", "America/Coyhaique", "America/Punta_Arenas", "Pacific/Easter" ]),
+	(CountryCode::CM, &[ "Africa/Douala" ]),
+	(CountryCode::CN, &[ "Asia/Shanghai", "Asia/Urumqi" ]),
+	(CountryCode::CO, &[ "America/Bogota" ]),
+	(CountryCode::CR, &[ "America/Costa_Rica" ]),
+	(CountryCode::CU, &[ "America/Havana" ]),
+	(CountryCode::CV, &[ "Atlantic/Cape_Verde" ]),
+	(CountryCode::CW, &[ "America/Curacao" ]),
+	(CountryCode::CX, &[ "Indian/Christmas" ]),
+	(CountryCode::CY, &[ "Asia/Nicosia", "Asia/Famagusta" ]),
+	(CountryCode::CZ, &[ "Europe/Prague" ]),
+	(CountryCode::DE, &[ "Europe/Berlin", "Europe/Busingen" ]),
+	(CountryCode::DJ, &[ "Africa/Djibouti" ]),
+	(CountryCode::DK, &[ "Europe/Copenhagen" ]),
+	(CountryCode::DM, &[ "America/Dominica" ]),
+	(CountryCode::DO, &[ "America/Santo_Domingo" ]),
+	(CountryCode::DZ, &[ "Africa/Algiers" ]),
+	(CountryCode::EC, &[ "America/Guayaquil", "Pacific/Galapagos" ]),
+	(CountryCode::EE, &[ "Europe/Tallinn" ]),
+	(CountryCode::EG, &[ "Africa/Cairo" ]),
+	(CountryCode::EH, &[ "Africa/El_Aaiun" ]),
+	(CountryCode::ER, &[ "Africa/Asmara" ]),
+	(CountryCode::ES, &[ "Europe/Madrid", "Africa/Ceuta", "Atlantic/Canary" ]),
+	(CountryCode::ET, &[ "Africa/Addis_Ababa" ]),
+	(CountryCode::FI, &[ "Europe/Helsinki" ]),
+	(CountryCode::FJ, &[ "Pacific/Fiji" ]),
+	(CountryCode::FK, &[ "Atlantic/Stanley" ]),
+	(CountryCode::FM, &[ "Pacific/Chuuk", "Pacific/Pohnpei", "Pacific/Kosrae" ]),
+	(CountryCode::FO, &[ "Atlantic/Faroe" ]),
+	(CountryCode::FR, &[ "Europe/Paris" ]),
+	(CountryCode::GA, &[ "Africa/Libreville" ]),
+	(CountryCode::GB, &[ "Europe/London" ]),
+	(CountryCode::GD, &[ "America/Grenada" ]),
+	(CountryCode::GE, &[ "Asia/Tbilisi" ]),
+	(CountryCode::GF, &[ "America/Cayenne" ]),
+	(CountryCode::GG, &[ "Europe/Guernsey" ]),
+	(CountryCode::GH, &[ "Africa/Accra" ]),
+	(CountryCode::GI, &[ "Europe/Gibraltar" ]),
+	(CountryCode::GL, &[ "America/Nuuk", "America/Danmarkshavn", "America/Scoresbysund", "America/Thule" ]),
+	(CountryCode::GM, &[ "Africa/Banjul" ]),
+	(CountryCode::GN, &[ "Africa/Conakry" ]),
+	(CountryCode::GP, &[ "America/Guadeloupe" ]),
+	(CountryCode::GQ, &[ "Africa/Malabo" ]),
+	(CountryCode::GR, &[ "Europe/Athens" ]),
+	(CountryCode::GS, &[ "Atlantic/South_Georgia" ]),
+	(CountryCode::GT, &[ "America/Guatemala" ]),
+	(CountryCode::GU, &[ "Pacific/Guam" ]),
+	(CountryCode::GW, &[ "Africa/Bissau" ]),
+	(CountryCode::GY, &[ "America/Guyana" ]),
+	(CountryCode::HK, &[ "Asia/Hong_Kong" ]),
+	(CountryCode::HN, &[ "America/Tegucigalpa" ]),
+	(CountryCode::HR, &[ "Europe/Zagreb" ]),
+	(CountryCode::HT, &[ "America/Port-au-Prince" ]),
+	(CountryCode::HU, &[ "Europe/Budapest" ]),
+	(CountryCode::ID, &[ "Asia/Jakarta", "Asia/Pontianak", "Asia/Makassar", "Asia/Jayapura" ]),
+	(CountryCode::IE, &[ "Europe/Dublin" ]),
+	(CountryCode::IL, &[ "Asia/Jerusalem" ]),
+	(CountryCode::IM, &[ "Europe/Isle_of_Man" ]),
+	(CountryCode::IN, &[ "Asia/Kolkata" ]),
+	(CountryCode::IO, &[ "Indian/Chagos" ]),
+	(CountryCode::IQ, &[ "Asia/Baghdad" ]),
+	(CountryCode::IR, &[ "Asia/Tehran" ]),
+	(CountryCode::IS, &[ "Atlantic/Reykjavik" ]),
+	(CountryCode::IT, &[ "Europe/Rome" ]),
+	(CountryCode::JE, &[ "Europe/Jersey" ]),
+	(CountryCode::JM, &[ "America/Jamaica" ]),
+	(CountryCode::JO, &[ "Asia/Amman" ]),
+	(CountryCode::JP, &[ "Asia/Tokyo" ]),
+	(CountryCode::KE, &[ "Africa/Nairobi" ]),
+	(CountryCode::KG, &[ "Asia/Bishkek" ]),
+	(CountryCode::KH, &[ "Asia/Phnom_Penh" ]),
+	(CountryCode::KI, &[ "Pacific/Tarawa", "Pacific/Kanton", "Pacific/Kiritimati" ]),
+	(CountryCode::KM, &[ "Indian/Comoro" ]),
+	(CountryCode::KN, &[ "America/St_Kitts" ]),
+	(CountryCode::KP, &[ "Asia/Pyongyang" ]),
+	(CountryCode::KR, &[ "Asia/Seoul" ]),
+	(CountryCode::KW, &[ "Asia/Kuwait" ]),
+	(CountryCode::KY, &[ "America/Cayman" ]),
+	(CountryCode::KZ, &[ "Asia/Almaty", "Asia/Qyzylorda", "Asia/Qostanay", "Asia/Aqtobe", "Asia/Aqtau", "Asia/Atyrau", "Asia/Oral" ]),
+	(CountryCode::LA, &[ "Asia/Vientiane" ]),
+	(CountryCode::LB, &[ "Asia/Beirut" ]),
+	(CountryCode::LC, &[ "America/St_Lucia" ]),
+	(CountryCode::LI, &[ "Europe/Vaduz" ]),
+	(CountryCode::LK, &[ "Asia/Colombo" ]),
+	(CountryCode::LR, &[ "Africa/Monrovia" ]),
+	(CountryCode::LS, &[ "Africa/Maseru" ]),
+	(CountryCode::LT, &[ "Europe/Vilnius" ]),
+	(CountryCode::LU, &[ "Europe/Luxembourg" ]),
+	(CountryCode::LV, &[ "Europe/Riga" ]),
+	(CountryCode::LY, &[ "Africa/Tripoli" ]),
+	(CountryCode::MA, &[ "Africa/Casablanca" ]),
+	(CountryCode::MC, &[ "Europe/Monaco" ]),
+	(CountryCode::MD, &[ "Europe/Chisinau" ]),
+	(CountryCode::ME, &[ "Europe/Podgorica" ]),
+	(CountryCode::MF, &[ "America/Marigot" ]),
+	(CountryCode::MG, &[ "Indian/Antananarivo" ]),
+	(CountryCode::MH, &[ "Pacific/Majuro", "Pacific/Kwajalein" ]),
+	(CountryCode::MK, &[ "Europe/Skopje" ]),
+	(CountryCode::ML, &[ "Africa/Bamako" ]),
+	(CountryCode::MM, &[ "Asia/Yangon" ]),
+	(CountryCode::MN, &[ "Asia/Ulaanbaatar", "Asia/Hovd" ]),
+	(CountryCode::MO, &[ "Asia/Macau" ]),
+	(CountryCode::MP, &[ "Pacific/Saipan" ]),
+	(CountryCode::MQ, &[ "America/Martinique" ]),
+	(CountryCode::MR, &[ "Africa/Nouakchott" ]),
+	(CountryCode::MS, &[ "America/Montserrat" ]),
+	(CountryCode::MT, &[ "Europe/Malta" ]),
+	(CountryCode::MU, &[ "Indian/Mauritius" ]),
+	(CountryCode::MV, &[ "Indian/Maldives" ]),
+	(CountryCode::MW, &[ "Africa/Blantyre" ]),
+	(CountryCode::MX, &[ "America/Mexico_City", "America/Cancun", "America/Merida", "America/Monterrey", "America/Matamoros", "America/Chihuahua", "America/Ciudad_Juarez", "America/Ojinaga", "America/Mazatlan", "America/Bahia_Banderas", "America/Hermosillo", "America/Tijuana" ]),
+	(CountryCode::MY, &[ "Asia/Kuala_Lumpur", "Asia/Kuching" ]),
+	(CountryCode::MZ, &[ "Africa/Maputo" ]),
+	(CountryCode::NA, &[ "Africa/Windhoek" ]),
+	(CountryCode::NC, &[ "Pacific/Noumea" ]),
+	(CountryCode::NE, &[ "Africa/Niamey" ]),
+	(CountryCode::NF, &[ "Pacific/Norfolk" ]),
+	(CountryCode::NG, &[ "Africa/Lagos" ]),
+	(CountryCode::NI, &[ "America/Managua" ]),
+	(CountryCode::NL, &[ "Europe/Amsterdam" ]),
+	(CountryCode::NO, &[ "Europe/Oslo" ]),
+	(CountryCode::NP, &[ "Asia/Kathmandu" ]),
+	(CountryCode::NR, &[ "Pacific/Nauru" ]),
+	(CountryCode::NU, &[ "Pacific/Niue" ]),
+	(CountryCode::NZ, &[ "Pacific/Auckland", "Pacific/Chatham" ]),
+	(CountryCode::OM, &[ "Asia/Muscat" ]),
+	(CountryCode::PA, &[ "America/Panama" ]),
+	(CountryCode::PE, &[ "America/Lima" ]),
+	(CountryCode::PF, &[ "Pacific/Tahiti", "Pacific/Marquesas", "Pacific/Gambier" ]),
+	(CountryCode::PG, &[ "Pacific/Port_Moresby", "Pacific/Bougainville" ]),
+	(CountryCode::PH, &[ "Asia/Manila" ]),
+	(CountryCode::PK, &[ "Asia/Karachi" ]),
+	(CountryCode::PL, &[ "Europe/Warsaw" ]),
+	(CountryCode::PM, &[ "America/Miquelon" ]),
+	(CountryCode::PN, &[ "Pacific/Pitcairn" ]),
+	(CountryCode::PR, &[ "America/Puerto_Rico" ]),
+	(CountryCode::PS, &[ "Asia/Gaza", "Asia/Hebron" ]),
+	(CountryCode::PT, &[ "Europe/Lisbon", "Atlantic/Madeira", "Atlantic/Azores" ]),
+	(CountryCode::PW, &[ "Pacific/Palau" ]),
+	(CountryCode::PY, &[ "America/Asuncion" ]),
+	(CountryCode::QA, &[ "Asia/Qatar" ]),
+	(CountryCode::RE, &[ "Indian/Reunion" ]),
+	(CountryCode::RO, &[ "Europe/Bucharest" ]),
+	(CountryCode::RS, &[ "Europe/Belgrade" ]),
+	(CountryCode::RU, &[ "Europe/Kaliningrad", "Europe/Moscow", "Europe/Kirov", "Europe/Volgograd", "Europe/Astrakhan", "Europe/Saratov", "Europe/Ulyanovsk", "Europe/Samara", "Asia/Yekaterinburg", "Asia/Omsk", "Asia/Novosibirsk", "Asia/Barnaul", "Asia/Tomsk", "Asia/Novokuznetsk", "Asia/Krasnoyarsk", "Asia/Irkutsk", "Asia/Chita", "Asia/Yakutsk", "Asia/Khandyga", "Asia/Vladivostok", "Asia/Ust-Nera", "Asia/Magadan", "Asia/Sakhalin", "Asia/Srednekolymsk", "Asia/Kamchatka", "Asia/Anadyr" ]),
+	(CountryCode::RW, &[ "Africa/Kigali" ]),
+	(CountryCode::SA, &[ "Asia/Riyadh" ]),
+	(CountryCode::SB, &[ "Pacific/Guadalcanal" ]),
+	(CountryCode::SC, &[ "Indian/Mahe" ]),
+	(CountryCode::SD, &[ "Africa/Khartoum" ]),
+	(CountryCode::SE, &[ "Europe/Stockholm" ]),
+	(CountryCode::SG, &[ "Asia/Singapore" ]),
+	(CountryCode::SH, &[ "Atlantic/St_Helena" ]),
+	(CountryCode::SI, &[ "Europe/Ljubljana" ]),
+	(CountryCode::SJ, &[ "Arctic/Longyearbyen" ]),
+	(CountryCode::SK, &[ "Europe/Bratislava" ]),
+	(CountryCode::SL, &[ "Africa/Freetown" ]),
+	(CountryCode::SM, &[ "Europe/San_Marino" ]),
+	(CountryCode::SN, &[ "Africa/Dakar" ]),
+	(CountryCode::SO, &[ "Africa/Mogadishu" ]),
+	(CountryCode::SR, &[ "America/Paramaribo" ]),
+	(CountryCode::SS, &[ "Africa/Juba" ]),
+	(CountryCode::ST, &[ "Africa/Sao_Tome" ]),
+	(CountryCode::SV, &[ "America/El_Salvador" ]),
+	(CountryCode::SX, &[ "America/Lower_Princes" ]),
+	(CountryCode::SY, &[ "Asia/Damascus" ]),
+	(CountryCode::SZ, &[ "Africa/Mbabane" ]),
+	(CountryCode::TC, &[ "America/Grand_Turk" ]),
+	(CountryCode::TD, &[ "Africa/Ndjamena" ]),
+	(CountryCode::TF, &[ "Indian/Kerguelen" ]),
+	(CountryCode::TG, &[ "Africa/Lome" ]),
+	(CountryCode::TH, &[ "Asia/Bangkok" ]),
+	(CountryCode::TJ, &[ "Asia/Dushanbe" ]),
+	(CountryCode::TK, &[ "Pacific/Fakaofo" ]),
+	(CountryCode::TL, &[ "Asia/Dili" ]),
+	(CountryCode::TM, &[ "Asia/Ashgabat" ]),
+	(CountryCode::TN, &[ "Africa/Tunis" ]),
+	(CountryCode::TO, &[ "Pacific/Tongatapu" ]),
+	(CountryCode::TR, &[ "Europe/Istanbul" ]),
+	(CountryCode::TT, &[ "America/Port_of_Spain" ]),
+	(CountryCode::TV, &[ "Pacific/Funafuti" ]),
+	(CountryCode::TW, &[ "Asia/Taipei" ]),
+	(CountryCode::TZ, &[ "Africa/Dar_es_Salaam" ]),
+	(CountryCode::UA, &[ "Europe/Simferopol", "Europe/Kyiv" ]),
+	(CountryCode::UG, &[ "Africa/Kampala" ]),
+	(CountryCode::UM, &[ "Pacific/Midway", "Pacific/Wake" ]),
+	(CountryCode::US, &[ "America/New_York", "America/Detroit", "America/Kentucky/Louisville", "America/Kentucky/Monticello", "America/Indiana/Indianapolis", "America/Indiana/Vincennes", "America/Indiana/Winamac", "America/Indiana/Marengo", "America/Indiana/Petersburg", "America/Indiana/Vevay", "America/Chicago", "America/Indiana/Tell_City", "America/Indiana/Knox", "America/Menominee", "America/North_Dakota/Center", "America/North_Dakota/New_Salem", "America/North_Dakota/Beulah", "America/Denver", "America/Boise", "America/Phoenix", "America/Los_Angeles", "America/Anchorage", "America/Juneau", "America/Sitka", "America/Metlakatla", "America/Yakutat", "America/Nome", "America/Adak", "Pacific/Honolulu" ]),
+	(CountryCode::UY, &[ "America/Montevideo" ]),
+	(CountryCode::UZ, &[ "Asia/Samarkand", "Asia/Tashkent" ]),
+	(CountryCode::VA, &[ "Europe/Vatican" ]),
+	(CountryCode::VC, &[ "America/St_Vincent" ]),
+	(CountryCode::VE, &[ "America/Caracas" ]),
+	(CountryCode::VG, &[ "America/Tortola" ]),
+	(CountryCode::VI, &[ "America/St_Thomas" ]),
+	(CountryCode::VN, &[ "Asia/Ho_Chi_Minh" ]),
+	(CountryCode::VU, &[ "Pacific/Efate" ]),
+	(CountryCode::WF, &[ "Pacific/Wallis" ]),
+	(CountryCode::WS, &[ "Pacific/Apia" ]),
+	(CountryCode::YE, &[ "Asia/Aden" ]),
+	(CountryCode::YT, &[ "Indian/Mayotte" ]),
+	(CountryCode::ZA, &[ "Africa/Johannesburg" ]),
+	(CountryCode::ZM, &[ "Africa/Lusaka" ]),
+	(CountryCode::ZW, &[ "Africa/Harare" ]),
+];
+
+/// The statuses of the languages used in countries.
+/// 
+/// Languages listed for a country in [`COUNTRIES`] are official unless given a
+/// different status here. Languages listed here but not in [`COUNTRIES`] have
+/// recognised status in the country without being official, e.g. regional or
+/// minority languages. Countries not listed here only have official languages.
+/// 
+/// The entries are sorted by country code, and each list of languages is sorted
+/// by language code.
+/// 
+/// # See also
+/// 
+/// * [`Country::languages_with_status()`]
+/// * [`LanguageStatus`]
+/// 
+static LANGUAGE_STATUSES: [(CountryCode, &[(LanguageCode, LanguageStatus)]); 23] = [
+	(CountryCode::AT, &[ (LanguageCode::HR, LanguageStatus::Regional), (LanguageCode::HU, LanguageStatus::Regional), (LanguageCode::SL, LanguageStatus::Regional) ]),
+	(CountryCode::CA, &[ (LanguageCode::CR, LanguageStatus::Minority), (LanguageCode::IU, LanguageStatus::Regional) ]),
+	(CountryCode::CH, &[ (LanguageCode::RM, LanguageStatus::National) ]),
+	(CountryCode::CN, &[ (LanguageCode::BO, LanguageStatus::Regional), (LanguageCode::II, LanguageStatus::Regional), (LanguageCode::MN, LanguageStatus::Regional), (LanguageCode::UG, LanguageStatus::Regional), (LanguageCode::ZA, LanguageStatus::Regional) ]),
+	(CountryCode::DE, &[ (LanguageCode::DA, LanguageStatus::Minority), (LanguageCode::FY, LanguageStatus::Minority) ]),
+	(CountryCode::DK, &[ (LanguageCode::DE, LanguageStatus::Minority) ]),
+	(CountryCode::ES, &[ (LanguageCode::CA, LanguageStatus::Regional), (LanguageCode::EU, LanguageStatus::Regional), (LanguageCode::GL, LanguageStatus::Regional), (LanguageCode::OC, LanguageStatus::Regional) ]),
+	(CountryCode::ET, &[ (LanguageCode::AA, LanguageStatus::Regional), (LanguageCode::OM, LanguageStatus::Regional), (LanguageCode::SO, LanguageStatus::Regional), (LanguageCode::TI, LanguageStatus::Regional) ]),
+	(CountryCode::FI, &[ (LanguageCode::SE, LanguageStatus::Regional) ]),
+	(CountryCode::FR, &[ (LanguageCode::BR, LanguageStatus::Regional), (LanguageCode::CA, LanguageStatus::Regional), (LanguageCode::CO, LanguageStatus::Regional), (LanguageCode::EU, LanguageStatus::Regional), (LanguageCode::OC, LanguageStatus::Regional) ]),
+	(CountryCode::GB, &[ (LanguageCode::CY, LanguageStatus::Regional), (LanguageCode::GA, LanguageStatus::Regional), (LanguageCode::GD, LanguageStatus::Regional), (LanguageCode::KW, LanguageStatus::Minority) ]),
+	(CountryCode::ID, &[ (LanguageCode::JV, LanguageStatus::Regional), (LanguageCode::SU, LanguageStatus::Regional) ]),
+	(CountryCode::IN, &[ (LanguageCode::AS, LanguageStatus::Regional), (LanguageCode::BN, LanguageStatus::Regional), (LanguageCode::GU, LanguageStatus::Regional), (LanguageCode::KN, LanguageStatus::Regional), (LanguageCode::KS, LanguageStatus::Regional), (LanguageCode::ML, LanguageStatus::Regional), (LanguageCode::MR, LanguageStatus::Regional), (LanguageCode::NE, LanguageStatus::Regional), (LanguageCode::OR, LanguageStatus::Regional), (LanguageCode::PA, LanguageStatus::Regional), (LanguageCode::SA, LanguageStatus::Regional), (LanguageCode::SD, LanguageStatus::Regional), (LanguageCode::TA, LanguageStatus::Regional), (LanguageCode::TE, LanguageStatus::Regional), (LanguageCode::UR, LanguageStatus::Regional) ]),
+	(CountryCode::IT, &[ (LanguageCode::DE, LanguageStatus::Regional), (LanguageCode::FR, LanguageStatus::Regional), (LanguageCode::SC, LanguageStatus::Minority), (LanguageCode::SL, LanguageStatus::Regional) ]),
+	(CountryCode::MY, &[ (LanguageCode::EN, LanguageStatus::WidelySpoken), (LanguageCode::TA, LanguageStatus::Minority), (LanguageCode::ZH, LanguageStatus::WidelySpoken) ]),
+	(CountryCode::NG, &[ (LanguageCode::HA, LanguageStatus::National), (LanguageCode::IG, LanguageStatus::National), (LanguageCode::YO, LanguageStatus::National) ]),
+	(CountryCode::NL, &[ (LanguageCode::FY, LanguageStatus::Regional), (LanguageCode::LI, LanguageStatus::Regional) ]),
+	(CountryCode::NO, &[ (LanguageCode::SE, LanguageStatus::Regional) ]),
+	(CountryCode::PE, &[ (LanguageCode::AY, LanguageStatus::Regional), (LanguageCode::QU, LanguageStatus::Regional) ]),
+	(CountryCode::PK, &[ (LanguageCode::PA, LanguageStatus::Regional), (LanguageCode::PS, LanguageStatus::Regional), (LanguageCode::SD, LanguageStatus::Regional) ]),
+	(CountryCode::RU, &[ (LanguageCode::AV, LanguageStatus::Regional), (LanguageCode::BA, LanguageStatus::Regional), (LanguageCode::CE, LanguageStatus::Regional), (LanguageCode::CV, LanguageStatus::Regional), (LanguageCode::KV, LanguageStatus::Regional), (LanguageCode::OS, LanguageStatus::Regional), (LanguageCode::TT, LanguageStatus::Regional) ]),
+	(CountryCode::SE, &[ (LanguageCode::FI, LanguageStatus::Minority), (LanguageCode::SE, LanguageStatus::Minority), (LanguageCode::YI, LanguageStatus::Minority) ]),
+	(CountryCode::US, &[ (LanguageCode::ES, LanguageStatus::WidelySpoken) ]),
+];
+
 
 
 //		Enums
@@ -336,6 +1393,15 @@ static COUNTRIES: LazyLock<HashMap<Country, CountryInfo>> = LazyLock::new(|| {
 /// Each country is identified by a country code, which can be expressed as two
 /// or three letters or three numbers, as defined by the ISO 3166-1 standard.
 /// 
+/// # Discriminants
+/// 
+/// Each variant has an explicit discriminant, which is stable across versions
+/// of this crate, and can be obtained by casting with `as u16`. This makes it
+/// safe to store the discriminant, e.g. in a database. Existing values will
+/// never be changed or reused, and new countries will be given new values. The
+/// ordering of Country values follows the discriminants, and is therefore also
+/// stable. Note that the discriminant is not the numeric country code.
+/// 
 /// # Data sources
 /// 
 /// The list of codes and other country information is available from
@@ -347,766 +1413,848 @@ static COUNTRIES: LazyLock<HashMap<Country, CountryInfo>> = LazyLock::new(|| {
 /// * [`CountryCode`]
 /// 
 #[expect(clippy::doc_markdown, reason = "False positives")]
-#[derive(Clone, Copy, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[repr(u16)]
 #[serde(into = "String", try_from = "String")]
 #[non_exhaustive]
 pub enum Country {
 	//		Two-letter codes (ISO 3166-1 alpha-2)								
 	/// Andorra
-	AD,
+	AD = 0,
 	
 	/// United Arab Emirates
-	AE,
+	AE = 1,
 	
 	/// Afghanistan
-	AF,
+	AF = 2,
 	
 	/// Antigua and Barbuda
-	AG,
+	AG = 3,
 	
 	/// Anguilla
-	AI,
+	AI = 4,
 	
 	/// Albania
-	AL,
+	AL = 5,
 	
 	/// Armenia
-	AM,
+	AM = 6,
 	
 	/// Angola
-	AO,
+	AO = 7,
 	
 	/// Antarctica
-	AQ,
+	AQ = 8,
 	
 	/// Argentina
-	AR,
+	AR = 9,
 	
 	/// American Samoa
-	AS,
+	AS = 10,
 	
 	/// Austria
-	AT,
+	AT = 11,
 	
 	/// Australia
-	AU,
+	AU = 12,
 	
 	/// Aruba
-	AW,
+	AW = 13,
 	
 	/// Åland Islands
-	AX,
+	AX = 14,
 	
 	/// Azerbaijan
-	AZ,
+	AZ = 15,
 	
 	/// Bosnia and Herzegovina
-	BA,
+	BA = 16,
 	
 	/// Barbados
-	BB,
+	BB = 17,
 	
 	/// Bangladesh
-	BD,
+	BD = 18,
 	
 	/// Belgium
-	BE,
+	BE = 19,
 	
 	/// Burkina Faso
-	BF,
+	BF = 20,
 	
 	/// Bulgaria
-	BG,
+	BG = 21,
 	
 	/// Bahrain
-	BH,
+	BH = 22,
 	
 	/// Burundi
-	BI,
+	BI = 23,
 	
 	/// Benin
-	BJ,
+	BJ = 24,
 	
 	/// Saint Barthélemy
-	BL,
+	BL = 25,
 	
 	/// Bermuda
-	BM,
+	BM = 26,
 	
 	/// Brunei Darussalam
-	BN,
+	BN = 27,
 	
 	/// Bolivia (Plurinational State of)
-	BO,
+	BO = 28,
 	
 	/// Bonaire, Sint Eustatius and Saba
-	BQ,
+	BQ = 29,
 	
 	/// Brazil
-	BR,
+	BR = 30,
 	
 	/// Bahamas
-	BS,
+	BS = 31,
 	
 	/// Bhutan
-	BT,
+	BT = 32,
 	
 	/// Bouvet Island
-	BV,
+	BV = 33,
 	
 	/// Botswana
-	BW,
+	BW = 34,
 	
 	/// Belarus
-	BY,
+	BY = 35,
 	
 	/// Belize
-	BZ,
+	BZ = 36,
 	
 	/// Canada
-	CA,
+	CA = 37,
 	
 	/// Cocos (Keeling) Islands
-	CC,
+	CC = 38,
 	
 	/// Congo, Democratic Republic of the
-	CD,
+	CD = 39,
 	
 	/// Central African Republic
-	CF,
+	CF = 40,
 	
 	/// Congo
-	CG,
+	CG = 41,
 	
 	/// Switzerland
-	CH,
+	CH = 42,
 	
 	/// Côte d'Ivoire
-	CI,
+	CI = 43,
 	
 	/// Cook Islands
-	CK,
+	CK = 44,
 	
 	/// Chile
-	CL,
+	CL = 45,
 	
 	/// Cameroon
-	CM,
+	CM = 46,
 	
 	/// China
-	CN,
+	CN = 47,
 	
 	/// Colombia
-	CO,
+	CO = 48,
 	
 	/// Costa Rica
-	CR,
+	CR = 49,
 	
 	/// Cuba
-	CU,
+	CU = 50,
 	
 	/// Cabo Verde
-	CV,
+	CV = 51,
 	
 	/// Curaçao
-	CW,
+	CW = 52,
 	
 	/// Christmas Island
-	CX,
+	CX = 53,
 	
 	/// Cyprus
-	CY,
+	CY = 54,
 	
 	/// Czechia
-	CZ,
+	CZ = 55,
 	
 	/// Germany
-	DE,
+	DE = 56,
 	
 	/// Djibouti
-	DJ,
+	DJ = 57,
 	
 	/// Denmark
-	DK,
+	DK = 58,
 	
 	/// Dominica
-	DM,
+	DM = 59,
 	
 	/// Dominican Republic
-	DO,
+	DO = 60,
 	
 	/// Algeria
-	DZ,
+	DZ = 61,
 	
 	/// Ecuador
-	EC,
+	EC = 62,
 	
 	/// Estonia
-	EE,
+	EE = 63,
 	
 	/// Egypt
-	EG,
+	EG = 64,
 	
 	/// Western Sahara
-	EH,
+	EH = 65,
 	
 	/// Eritrea
-	ER,
+	ER = 66,
 	
 	/// Spain
-	ES,
+	ES = 67,
 	
 	/// Ethiopia
-	ET,
+	ET = 68,
 	
 	/// Finland
-	FI,
+	FI = 69,
 	
 	/// Fiji
-	FJ,
+	FJ = 70,
 	
 	/// Falkland Islands (Malvinas)
-	FK,
+	FK = 71,
 	
 	/// Micronesia (Federated States of)
-	FM,
+	FM = 72,
 	
 	/// Faroe Islands
-	FO,
+	FO = 73,
 	
 	/// France
-	FR,
+	FR = 74,
 	
 	/// Gabon
-	GA,
+	GA = 75,
 	
 	/// United Kingdom of Great Britain and Northern Ireland
-	GB,
+	GB = 76,
 	
 	/// Grenada
-	GD,
+	GD = 77,
 	
 	/// Georgia
-	GE,
+	GE = 78,
 	
 	/// French Guiana
-	GF,
+	GF = 79,
 	
 	/// Guernsey
-	GG,
+	GG = 80,
 	
 	/// Ghana
-	GH,
+	GH = 81,
 	
 	/// Gibraltar
-	GI,
+	GI = 82,
 	
 	/// Greenland
-	GL,
+	GL = 83,
 	
 	/// Gambia
-	GM,
+	GM = 84,
 	
 	/// Guinea
-	GN,
+	GN = 85,
 	
 	/// Guadeloupe
-	GP,
+	GP = 86,
 	
 	/// Equatorial Guinea
-	GQ,
+	GQ = 87,
 	
 	/// Greece
-	GR,
+	GR = 88,
 	
 	/// South Georgia and the South Sandwich Islands
-	GS,
+	GS = 89,
 	
 	/// Guatemala
-	GT,
+	GT = 90,
 	
 	/// Guam
-	GU,
+	GU = 91,
 	
 	/// Guinea-Bissau
-	GW,
+	GW = 92,
 	
 	/// Guyana
-	GY,
+	GY = 93,
 	
 	/// Hong Kong
-	HK,
+	HK = 94,
 	
 	/// Heard Island and McDonald Islands
-	HM,
+	HM = 95,
 	
 	/// Honduras
-	HN,
+	HN = 96,
 	
 	/// Croatia
-	HR,
+	HR = 97,
 	
 	/// Haiti
-	HT,
+	HT = 98,
 	
 	/// Hungary
-	HU,
+	HU = 99,
 	
 	/// Indonesia
-	ID,
+	ID = 100,
 	
 	/// Ireland
-	IE,
+	IE = 101,
 	
 	/// Israel
-	IL,
+	IL = 102,
 	
 	/// Isle of Man
-	IM,
+	IM = 103,
 	
 	/// India
-	IN,
+	IN = 104,
 	
 	/// British Indian Ocean Territory
-	IO,
+	IO = 105,
 	
 	/// Iraq
-	IQ,
+	IQ = 106,
 	
 	/// Iran (Islamic Republic of)
-	IR,
+	IR = 107,
 	
 	/// Iceland
-	IS,
+	IS = 108,
 	
 	/// Italy
-	IT,
+	IT = 109,
 	
 	/// Jersey
-	JE,
+	JE = 110,
 	
 	/// Jamaica
-	JM,
+	JM = 111,
 	
 	/// Jordan
-	JO,
+	JO = 112,
 	
 	/// Japan
-	JP,
+	JP = 113,
 	
 	/// Kenya
-	KE,
+	KE = 114,
 	
 	/// Kyrgyzstan
-	KG,
+	KG = 115,
 	
 	/// Cambodia
-	KH,
+	KH = 116,
 	
 	/// Kiribati
-	KI,
+	KI = 117,
 	
 	/// Comoros
-	KM,
+	KM = 118,
 	
 	/// Saint Kitts and Nevis
-	KN,
+	KN = 119,
 	
 	/// Korea (Democratic People's Republic of)
-	KP,
+	KP = 120,
 	
 	/// Korea, Republic of
-	KR,
+	KR = 121,
 	
 	/// Kuwait
-	KW,
+	KW = 122,
 	
 	/// Cayman Islands
-	KY,
+	KY = 123,
 	
 	/// Kazakhstan
-	KZ,
+	KZ = 124,
 	
 	/// Lao People's Democratic Republic
-	LA,
+	LA = 125,
 	
 	/// Lebanon
-	LB,
+	LB = 126,
 	
 	/// Saint Lucia
-	LC,
+	LC = 127,
 	
 	/// Liechtenstein
-	LI,
+	LI = 128,
 	
 	/// Sri Lanka
-	LK,
+	LK = 129,
 	
 	/// Liberia
-	LR,
+	LR = 130,
 	
 	/// Lesotho
-	LS,
+	LS = 131,
 	
 	/// Lithuania
-	LT,
+	LT = 132,
 	
 	/// Luxembourg
-	LU,
+	LU = 133,
 	
 	/// Latvia
-	LV,
+	LV = 134,
 	
 	/// Libya
-	LY,
+	LY = 135,
 	
 	/// Morocco
-	MA,
+	MA = 136,
 	
 	/// Monaco
-	MC,
+	MC = 137,
 	
 	/// Moldova, Republic of
-	MD,
+	MD = 138,
 	
 	/// Montenegro
-	ME,
+	ME = 139,
 	
 	/// Saint Martin (French part)
-	MF,
+	MF = 140,
 	
 	/// Madagascar
-	MG,
+	MG = 141,
 	
 	/// Marshall Islands
-	MH,
+	MH = 142,
 	
 	/// North Macedonia
-	MK,
+	MK = 143,
 	
 	/// Mali
-	ML,
+	ML = 144,
 	
 	/// Myanmar
-	MM,
+	MM = 145,
 	
 	/// Mongolia
-	MN,
+	MN = 146,
 	
 	/// Macao
-	MO,
+	MO = 147,
 	
 	/// Northern Mariana Islands
-	MP,
+	MP = 148,
 	
 	/// Martinique
-	MQ,
+	MQ = 149,
 	
 	/// Mauritania
-	MR,
+	MR = 150,
 	
 	/// Montserrat
-	MS,
+	MS = 151,
 	
 	/// Malta
-	MT,
+	MT = 152,
 	
 	/// Mauritius
-	MU,
+	MU = 153,
 	
 	/// Maldives
-	MV,
+	MV = 154,
 	
 	/// Malawi
-	MW,
+	MW = 155,
 	
 	/// Mexico
-	MX,
+	MX = 156,
 	
 	/// Malaysia
-	MY,
+	MY = 157,
 	
 	/// Mozambique
-	MZ,
+	MZ = 158,
 	
 	/// Namibia
-	NA,
+	NA = 159,
 	
 	/// New Caledonia
-	NC,
+	NC = 160,
 	
 	/// Niger
-	NE,
+	NE = 161,
 	
 	/// Norfolk Island
-	NF,
+	NF = 162,
 	
 	/// Nigeria
-	NG,
+	NG = 163,
 	
 	/// Nicaragua
-	NI,
+	NI = 164,
 	
 	/// Netherlands, Kingdom of the
-	NL,
+	NL = 165,
 	
 	/// Norway
-	NO,
+	NO = 166,
 	
 	/// Nepal
-	NP,
+	NP = 167,
 	
 	/// Nauru
-	NR,
+	NR = 168,
 	
 	/// Niue
-	NU,
+	NU = 169,
 	
 	/// New Zealand
-	NZ,
+	NZ = 170,
 	
 	/// Oman
-	OM,
+	OM = 171,
 	
 	/// Panama
-	PA,
+	PA = 172,
 	
 	/// Peru
-	PE,
+	PE = 173,
 	
 	/// French Polynesia
-	PF,
+	PF = 174,
 	
 	/// Papua New Guinea
-	PG,
+	PG = 175,
 	
 	/// Philippines
-	PH,
+	PH = 176,
 	
 	/// Pakistan
-	PK,
+	PK = 177,
 	
 	/// Poland
-	PL,
+	PL = 178,
 	
 	/// Saint Pierre and Miquelon
-	PM,
+	PM = 179,
 	
 	/// Pitcairn
-	PN,
+	PN = 180,
 	
 	/// Puerto Rico
-	PR,
+	PR = 181,
 	
 	/// Palestine, State of
-	PS,
+	PS = 182,
 	
 	/// Portugal
-	PT,
+	PT = 183,
 	
 	/// Palau
-	PW,
+	PW = 184,
 	
 	/// Paraguay
-	PY,
+	PY = 185,
 	
 	/// Qatar
-	QA,
+	QA = 186,
 	
 	/// Réunion
-	RE,
+	RE = 187,
 	
 	/// Romania
-	RO,
+	RO = 188,
 	
 	/// Serbia
-	RS,
+	RS = 189,
 	
 	/// Russian Federation
-	RU,
+	RU = 190,
 	
 	/// Rwanda
-	RW,
+	RW = 191,
 	
 	/// Saudi Arabia
-	SA,
+	SA = 192,
 	
 	/// Solomon Islands
-	SB,
+	SB = 193,
 	
 	/// Seychelles
-	SC,
+	SC = 194,
 	
 	/// Sudan
-	SD,
+	SD = 195,
 	
 	/// Sweden
-	SE,
+	SE = 196,
 	
 	/// Singapore
-	SG,
+	SG = 197,
 	
 	/// Saint Helena, Ascension and Tristan da Cunha
-	SH,
+	SH = 198,
 	
 	/// Slovenia
-	SI,
+	SI = 199,
 	
 	/// Svalbard and Jan Mayen
-	SJ,
+	SJ = 200,
 	
 	/// Slovakia
-	SK,
+	SK = 201,
 	
 	/// Sierra Leone
-	SL,
+	SL = 202,
 	
 	/// San Marino
-	SM,
+	SM = 203,
 	
 	/// Senegal
-	SN,
+	SN = 204,
 	
 	/// Somalia
-	SO,
+	SO = 205,
 	
 	/// Suriname
-	SR,
+	SR = 206,
 	
 	/// South Sudan
-	SS,
+	SS = 207,
 	
 	/// Sao Tome and Principe
-	ST,
+	ST = 208,
 	
 	/// El Salvador
-	SV,
+	SV = 209,
 	
 	/// Sint Maarten (Dutch part)
-	SX,
+	SX = 210,
 	
 	/// Syrian Arab Republic
-	SY,
+	SY = 211,
 	
 	/// Eswatini
-	SZ,
+	SZ = 212,
 	
 	/// Turks and Caicos Islands
-	TC,
+	TC = 213,
 	
 	/// Chad
-	TD,
+	TD = 214,
 	
 	/// French Southern Territories
-	TF,
+	TF = 215,
 	
 	/// Togo
-	TG,
+	TG = 216,
 	
 	/// Thailand
-	TH,
+	TH = 217,
 	
 	/// Tajikistan
-	TJ,
+	TJ = 218,
 	
 	/// Tokelau
-	TK,
+	TK = 219,
 	
 	/// Timor-Leste
-	TL,
+	TL = 220,
 	
 	/// Turkmenistan
-	TM,
+	TM = 221,
 	
 	/// Tunisia
-	TN,
+	TN = 222,
 	
 	/// Tonga
-	TO,
+	TO = 223,
 	
 	/// Türkiye
-	TR,
+	TR = 224,
 	
 	/// Trinidad and Tobago
-	TT,
+	TT = 225,
 	
 	/// Tuvalu
-	TV,
+	TV = 226,
 	
 	/// Taiwan, Province of China
-	TW,
+	TW = 227,
 	
 	/// Tanzania, United Republic of
-	TZ,
+	TZ = 228,
 	
 	/// Ukraine
-	UA,
+	UA = 229,
 	
 	/// Uganda
-	UG,
+	UG = 230,
 	
 	/// United States Minor Outlying Islands
-	UM,
+	UM = 231,
 	
 	/// United States of America
-	US,
+	US = 232,
 	
 	/// Uruguay
-	UY,
+	UY = 233,
 	
 	/// Uzbekistan
-	UZ,
+	UZ = 234,
 	
 	/// Holy See
-	VA,
+	VA = 235,
 	
 	/// Saint Vincent and the Grenadines
-	VC,
+	VC = 236,
 	
 	/// Venezuela (Bolivarian Republic of)
-	VE,
+	VE = 237,
 	
 	/// Virgin Islands (British)
-	VG,
+	VG = 238,
 	
 	/// Virgin Islands (U.S.)
-	VI,
+	VI = 239,
 	
 	/// Viet Nam
-	VN,
+	VN = 240,
 	
 	/// Vanuatu
-	VU,
+	VU = 241,
 	
 	/// Wallis and Futuna
-	WF,
+	WF = 242,
 	
 	/// Samoa
-	WS,
+	WS = 243,
 	
 	/// Yemen
-	YE,
+	YE = 244,
 	
 	/// Mayotte
-	YT,
+	YT = 245,
 	
 	/// South Africa
-	ZA,
+	ZA = 246,
 	
 	/// Zambia
-	ZM,
+	ZM = 247,
 	
 	/// Zimbabwe
-	ZW,
+	ZW = 248,
 }
 
 //󰭅		Country																	
 impl Country {
 	//		all																	
 	/// Returns all the countries.
+	#[must_use]
 	pub fn all() -> Vec<Self> {
-		COUNTRIES.keys().copied().collect()
+		COUNTRIES.iter().map(|info| info.code.country()).collect()
+	}
+	
+	//		changes_since														
+	/// Returns the countries whose data has changed since a version.
+	/// 
+	/// The version is a [`DATA_VERSION`](crate::DATA_VERSION), i.e. an ISO 8601
+	/// date, and the countries returned are those whose
+	/// [`last_changed()`](Self::last_changed()) date is after it, in order of
+	/// code. Downstream caches built against an earlier version of the data can
+	/// use this to refresh only the affected entries.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `version` - The version of the data to compare against.
+	/// 
+	#[must_use]
+	pub fn changes_since(version: &str) -> Vec<Self> {
+		CHANGES.iter().filter(|&&(_, changed)| changed > version).map(|&(code, _)| code.country()).collect()
+	}
+	
+	//		records																
+	/// Returns the records of all the countries.
+	/// 
+	/// This provides all of the information about every country in one pass.
+	/// For more information, see [`CountryRecord`].
+	/// 
+	#[must_use]
+	pub fn records() -> impl ExactSizeIterator<Item = CountryRecord> {
+		COUNTRIES.iter().map(|info| info.code.country().record())
+	}
+	
+	//		record																
+	/// Returns the record of the country.
+	/// 
+	/// This provides all of the information about the country in one go. For
+	/// more information, see [`CountryRecord`].
+	/// 
+	#[must_use]
+	pub fn record(&self) -> CountryRecord {
+		let info = self.info();
+		CountryRecord {
+			country:      *self,
+			code:         info.code,
+			alpha3:       info.code.to_alpha3(),
+			numeric:      info.code.numeric(),
+			name:         registry::country_name(*self).unwrap_or(info.name),
+			full_name:    info.full_name,
+			vehicle_code: info.vehicle_code,
+			first_day:    info.first_day,
+			weekend:      info.weekend,
+			national_day: self.national_day(),
+			postal_codes: info.postal_codes,
+			driving_side: info.driving_side,
+			measurement:  info.measurement,
+			observes_dst: info.observes_dst,
+			region:       info.region,
+			independent:  info.independent,
+			un_status:    info.un_status,
+			sovereign:    info.sovereign.map(|code| code.country()),
+			currencies:   info.currencies,
+			languages:    info.languages,
+		}
+	}
+	
+	//		summary																
+	/// Returns a summary of the country.
+	/// 
+	/// This provides the codes and name of the country, for embedding in API
+	/// responses where a code alone is too little and a whole record is too
+	/// much. For more information, see [`CountrySummary`].
+	/// 
+	#[must_use]
+	pub fn summary(&self) -> CountrySummary {
+		let code = self.code();
+		CountrySummary {
+			code,
+			alpha3:  code.to_alpha3(),
+			numeric: code.numeric(),
+			name:    self.name(),
+		}
 	}
 	
 	//		info																
@@ -1120,148 +2268,963 @@ impl Country {
 		#[expect(clippy::unwrap_used, reason = "Infallible")]
 		//	This should be infallible. If it isn't, then the data is wrong, and one
 		//	of the countries is missing from the list, which is a bug.
-		COUNTRIES.get(&self).unwrap()
+		COUNTRIES.get(self as usize).unwrap()
 	}
 	
 	//		name																
 	/// Returns the name of the country.
+	/// 
+	/// If a [`Registry`](crate::registry::Registry) has been installed with an
+	/// override for the country's name, that name is returned instead.
+	/// 
 	#[must_use]
-	pub fn name(&self) -> &str {
-		&self.info().name
+	pub fn name(&self) -> &'static str {
+		registry::country_name(*self).unwrap_or_else(|| self.info().name)
 	}
 	
-	//		code																
-	/// Returns the country code.
+	//		names_of															
+	/// Returns the names of the countries with the given codes.
+	/// 
+	/// The names are returned in the same order as the codes, and are those
+	/// given by [`name()`](Country::name()), so any overrides in the
+	/// [`Registry`](crate::registry::Registry) are used.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `codes` - The codes of the countries.
+	/// 
 	#[must_use]
-	pub fn code(&self) -> CountryCode {
-		self.info().code
+	pub fn names_of(codes: &[CountryCode]) -> Vec<&'static str> {
+		codes.iter().map(|code| code.country().name()).collect()
 	}
 	
-	//		currencies															
-	/// Returns the currencies used in the country.
+	//		ascii_name															
+	/// Returns the name of the country, transliterated to ASCII.
+	/// 
+	/// This is the short name with any accented letters replaced by their
+	/// plain equivalents, e.g. "Côte d'Ivoire" becomes "Cote d'Ivoire", for use
+	/// in systems that are restricted to ASCII, such as legacy banking file
+	/// formats. Apostrophes, hyphens, and spaces are kept. Names registered at
+	/// runtime via the [`registry`](crate::registry) are not used.
+	/// 
 	#[must_use]
-	pub fn currencies(&self) -> &HashSet<CurrencyCode> {
-		&self.info().currencies
+	pub fn ascii_name(&self) -> &'static str {
+		let info = self.info();
+		map::get(&ASCII_NAMES, &info.code).copied().unwrap_or(info.name)
 	}
 	
-	//		languages															
-	/// Returns the languages used in the country.
+	//		postal_name															
+	/// Returns the name of the country for use on international mail.
+	/// 
+	/// This is the name to print on the last line of the address on shipping
+	/// labels, following the conventions of the Universal Postal Union, i.e.
+	/// the common English short name in capital letters and without accents,
+	/// e.g. "UNITED KINGDOM" for the United Kingdom of Great Britain and
+	/// Northern Ireland, and "COTE D'IVOIRE" for Côte d'Ivoire. Names
+	/// registered at runtime via the [`registry`](crate::registry) are not used.
+	/// 
 	#[must_use]
-	pub fn languages(&self) -> &HashSet<LanguageCode> {
-		&self.info().languages
-	}
-}
-
-//󰭅		AsStr																	
-impl AsStr for Country {
-	//		as_str																
-	fn as_str(&self) -> &str {
-		&self.info().name
-	}
-}
-
-//󰭅		Debug																	
-impl Debug for Country {
-	//		fmt																	
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{}: {}", self.info().code.as_str(), self.as_str())
+	pub fn postal_name(&self) -> &'static str {
+		map::get(&POSTAL_NAMES, &self.code()).copied().unwrap_or_default()
 	}
-}
-
-//󰭅		Display																	
-impl Display for Country {
-	//		fmt																	
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{}", self.as_str())
+	
+	//		sort_key															
+	/// Returns a key for sorting the country by name.
+	/// 
+	/// The key is the short name folded to lowercase, with accents removed and
+	/// punctuation stripped, in the same way as by [`normalize()`], and with any
+	/// leading "the" removed, e.g. "Côte d'Ivoire" becomes "cote divoire". This
+	/// means that "Åland Islands" sorts between "Afghanistan" and "Albania",
+	/// rather than after "Zimbabwe" as it would by code point, without needing a
+	/// full collation library. The keys are computed once, on first use. Names
+	/// registered at runtime via the [`registry`](crate::registry) are not used.
+	/// 
+	/// Names in other languages, such as translations held by an application,
+	/// can be given comparable keys using [`normalize()`].
+	/// 
+	#[must_use]
+	pub fn sort_key(&self) -> &'static str {
+		SORT_KEYS.get(*self as usize).map_or("", String::as_str)
 	}
-}
-
-//󰭅		From<Country> for String												
-impl From<Country> for String {
-	//		from																
-	fn from(country: Country) -> Self {
-		country.to_string()
+	
+	//		population															
+	/// Returns the approximate population of the country.
+	/// 
+	/// This is the estimated number of residents, rounded to around three
+	/// significant figures, for use as a denominator in per-capita figures on
+	/// dashboards and the like. Uninhabited territories, such as Antarctica and
+	/// Bouvet Island, have a population of zero. The figures are reviewed
+	/// annually.
+	/// 
+	/// This is only available when the `extended-data` feature is enabled.
+	/// 
+	#[cfg(feature = "extended-data")]
+	#[must_use]
+	pub fn population(&self) -> u64 {
+		map::get(&STATISTICS, &self.code()).map_or(0, |statistics| statistics.0)
 	}
-}
-
-//󰭅		FromStr																	
-impl FromStr for Country {
-	type Err = String;
 	
-	//		from_str															
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		COUNTRIES
-			.values()
-			.find(|info| info.name == s)
-			.map_or_else(
-				||     Err(format!("Invalid Country: {s}")),
-				|info| Ok(info.code.country())
-			)
+	//		area																
+	/// Returns the approximate total area of the country, in square kilometres.
+	/// 
+	/// This includes inland water, and is rounded to the nearest square
+	/// kilometre, with a minimum of one, e.g. for Vatican City.
+	/// 
+	/// This is only available when the `extended-data` feature is enabled.
+	/// 
+	#[cfg(feature = "extended-data")]
+	#[must_use]
+	pub fn area(&self) -> u32 {
+		map::get(&STATISTICS, &self.code()).map_or(0, |statistics| statistics.1)
 	}
-}
-
-//󰭅		TryFrom<String>															
-impl TryFrom<String> for Country {
-	type Error = String;
 	
-	//		try_from															
-	fn try_from(value: String) -> Result<Self, Self::Error> {
-		value.as_str().parse()
+	//		display_as															
+	/// Returns the country for display in a chosen style.
+	/// 
+	/// This allows the country to be displayed as its code, its name, or both,
+	/// e.g. `format!("{}", Country::FR.display_as(DisplayStyle::CodeAndName))`.
+	/// For more information, see [`DisplayStyle`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `style` - The style to display the country in.
+	/// 
+	#[must_use]
+	pub const fn display_as(&self, style: DisplayStyle) -> Styled<Self> {
+		Styled::new(*self, style)
 	}
-}
-
-//		CountryCode																
-/// The possible countries' codes.
-/// 
-/// These codes are based on the ISO 3166 standard, specifically ISO 3166-1,
-/// which defines codes of two and three characters to represent countries and
-/// territories. There are both alphabetic and numeric codes, using either two
-/// letters, three letters, or three numbers.
-/// 
-/// # Alphabetic codes
-/// 
-/// The alphabetic codes are defined by the ISO 3166-1 alpha-2 set, which is the
-/// most widely-used of the three sets; and the ISO 3166-1 alpha-3 set, which is
-/// less widely-used but gives better visual association between the codes and
-/// country names than the alpha-2 set.
-/// 
-/// # Numeric codes
-/// 
-/// The three-digit numeric code is a useful alternative when the letter-based
-/// code may not be appropriate.
-/// 
-/// # Data sources
-/// 
-/// The list of codes is available from [the ISO site](https://www.iso.org/iso-3166-country-codes.html),
-/// and from [Wikipedia](https://en.wikipedia.org/wiki/ISO_3166-1).
-/// 
-/// # See also
-/// 
-/// * [`Country`]
-/// 
-#[expect(clippy::doc_markdown,          reason = "False positives")]
-#[expect(clippy::zero_prefixed_literal, reason = "Zeroes aid readability here")]
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-#[cfg_attr(feature = "utoipa", derive(ToSchema))]
-#[repr(u16)]
-#[serde(into = "String", try_from = "String")]
-#[non_exhaustive]
-pub enum CountryCode {
-	//		Two-letter codes (ISO 3166-1 alpha-2)								
-	/// Andorra
-	AD  = 020,
 	
-	/// United Arab Emirates
-	AE  = 784,
+	//		short_name															
+	/// Returns the short name of the country.
+	/// 
+	/// This is the English short name defined by ISO 3166, e.g. "United Kingdom
+	/// of Great Britain and Northern Ireland", and is what
+	/// [`name()`](Country::name()) returns unless overridden by a
+	/// [`Registry`](crate::registry::Registry).
+	/// 
+	#[must_use]
+	pub fn short_name(&self) -> &'static str {
+		self.info().name
+	}
 	
-	/// Afghanistan
-	AF  = 004,
+	//		full_name															
+	/// Returns the full official name of the country, if it has one.
+	/// 
+	/// This is the English full name defined by ISO 3166, e.g. "the United
+	/// Kingdom of Great Britain and Northern Ireland". It is generally only
+	/// defined for sovereign states, and not for dependent territories.
+	/// 
+	#[must_use]
+	pub fn full_name(&self) -> Option<&'static str> {
+		self.info().full_name
+	}
 	
-	/// Antigua and Barbuda
-	AG  = 028,
+	//		fuzzy_match															
+	/// Finds the countries with names similar to the given name.
+	/// 
+	/// Each country is scored against the name using
+	/// [`similarity()`](crate::text::similarity()), and those scoring at least
+	/// the threshold are returned, along with their scores, best match first.
+	/// Useful thresholds are typically between `0.7` and `0.9`.
+	/// 
+	/// The short name, full name, ASCII name, common name as given by
+	/// [`postal_name()`](Country::postal_name()), and any overridden name from
+	/// the [`Registry`](crate::registry::Registry) are all considered, and the
+	/// best score is used. This means that "Untied Kingdom" matches the United
+	/// Kingdom, even though its short name is much longer.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`      - The name to match, which may contain typos.
+	/// * `threshold` - The minimum score, between `0.0` and `1.0`.
+	/// 
+	#[must_use]
+	pub fn fuzzy_match(name: &str, threshold: f32) -> Vec<(Self, f32)> {
+		let mut matches: Vec<_> = Self::all()
+			.into_iter()
+			.map(|country| {
+				let score = [ country.name(), country.short_name(), country.ascii_name(), country.postal_name() ]
+					.into_iter()
+					.chain(country.full_name())
+					.map(|candidate| similarity(name, candidate))
+					.fold(0.0_f32, f32::max);
+				(country, score)
+			})
+			.filter(|&(_, score)| score >= threshold)
+			.collect();
+		matches.sort_by(|a, b| b.1.total_cmp(&a.1));
+		matches
+	}
 	
-	/// Anguilla
-	AI  = 660,
+	//		try_from_any														
+	/// Resolves a JSON value to a country.
+	/// 
+	/// This is intended for schemaless ingestion, where the form of the value
+	/// is not known in advance. The value can be a string holding a name or a
+	/// two-letter or three-letter code, or a number holding a numeric code,
+	/// e.g. `"France"`, `"FR"`, `"FRA"`, or `250`. Names are parsed in
+	/// the same way as by [`FromStr`].
+	/// 
+	/// This is only available when the `json` feature is enabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The JSON value to resolve.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the value does not identify a country.
+	/// 
+	#[cfg(feature = "json")]
+	pub fn try_from_any(value: &Value) -> Result<Self, String> {
+		value.as_str()
+			.and_then(|string| string.parse().ok().or_else(|| CountryCode::from_str(string).ok().map(|code| code.country())))
+			.or_else(|| value.as_u64()
+				.and_then(|int| u16::try_from(int).ok())
+				.and_then(|int| CountryCode::try_from(int).ok())
+				.map(|code| code.country())
+			)
+			.ok_or_else(|| format!("Invalid Country: {value}"))
+	}
+	
+	//		parse_with															
+	/// Parses a country from text, according to a set of options.
+	/// 
+	/// This allows the leniency of the parsing to be chosen, e.g. whether codes
+	/// are accepted in any case, and whether names are accepted. For more
+	/// information, see [`ParseOptions`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `s`       - The text to parse.
+	/// * `options` - The options to parse with.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the text does not identify a country with the
+	/// options given.
+	/// 
+	pub fn parse_with(s: &str, options: &ParseOptions) -> Result<Self, String> {
+		options.parse(s).ok_or_else(|| format!("Invalid Country: {s}"))
+	}
+	
+	//		code																
+	/// Returns the country code.
+	#[must_use]
+	pub fn code(&self) -> CountryCode {
+		self.info().code
+	}
+	
+	//		last_changed														
+	/// Returns the date on which the data for the country last changed.
+	/// 
+	/// This is the ISO 8601 date on which the most recent change took effect,
+	/// such as a change of name or of currency, e.g. `2023-01-01` for Croatia,
+	/// which then adopted the euro. It can be compared with the
+	/// [`DATA_VERSION`](crate::DATA_VERSION). For countries without a recorded
+	/// change, `None` is returned.
+	/// 
+	#[must_use]
+	pub fn last_changed(&self) -> Option<&'static str> {
+		map::get(&CHANGES, &self.code()).copied()
+	}
+	
+	//		cldr_region															
+	/// Returns the Unicode CLDR region code of the country.
+	/// 
+	/// The CLDR identifies countries by their two-letter ISO 3166-1 codes, e.g.
+	/// `GB`, and so this is the same as the alpha-2 code, in upper case. It is
+	/// provided so that the country can be used as the region subtag of a
+	/// locale for formatting libraries such as `icu4x`, without relying on that
+	/// correspondence.
+	/// 
+	#[must_use]
+	pub fn cldr_region(&self) -> &'static str {
+		self.code().as_str()
+	}
+	
+	//		vehicle_code														
+	/// Returns the international vehicle registration code of the country.
+	/// 
+	/// This is the distinguishing sign displayed on vehicles in international
+	/// traffic, as set out by the UN conventions on road traffic, e.g. `D` for
+	/// Germany or `UK` for the United Kingdom. Not all countries and territories
+	/// have one assigned, in which case `None` is returned.
+	/// 
+	#[must_use]
+	pub fn vehicle_code(&self) -> Option<&'static str> {
+		self.info().vehicle_code
+	}
+	
+	//		from_vehicle_code													
+	/// Returns the country corresponding to a vehicle registration code.
+	/// 
+	/// This method provides the reverse of [`vehicle_code()`](Self::vehicle_code()),
+	/// and the match is case-insensitive. The code `GB`, which was used by the
+	/// United Kingdom until 2021 and is still widely seen in data, is also
+	/// recognised.
+	/// 
+	#[must_use]
+	pub fn from_vehicle_code(code: &str) -> Option<Self> {
+		if code.eq_ignore_ascii_case("GB") {
+			return Some(Self::GB);
+		}
+		COUNTRIES
+			.iter()
+			.find(|info| info.vehicle_code.is_some_and(|vc| vc.eq_ignore_ascii_case(code)))
+			.map(|info| info.code.country())
+	}
+	
+	//		first_day_of_week													
+	/// Returns the first day of the week in the country.
+	/// 
+	/// This is the day on which the week conventionally starts, according to
+	/// the Unicode CLDR, e.g. Monday in most of Europe, Sunday in the United
+	/// States, and Saturday in much of the Middle East.
+	/// 
+	#[must_use]
+	pub fn first_day_of_week(&self) -> Weekday {
+		self.info().first_day
+	}
+	
+	//		weekend_days														
+	/// Returns the days of the weekend in the country.
+	/// 
+	/// These are the days conventionally taken as the weekend, according to the
+	/// Unicode CLDR, in order. Most countries have a Saturday and Sunday weekend,
+	/// but some have a Friday and Saturday weekend, and a few have a single day.
+	/// 
+	#[must_use]
+	pub fn weekend_days(&self) -> &'static [Weekday] {
+		self.info().weekend
+	}
+	
+	//		national_day														
+	/// Returns the date of the national day of the country.
+	/// 
+	/// This is the main national holiday celebrated each year on a fixed date,
+	/// such as Bastille Day in France or Independence Day in the United States.
+	/// Countries with no national day, or where it does not fall on a fixed
+	/// date, return `None`. For richer holiday information, see the
+	/// [`holiday`](crate::holiday) module.
+	/// 
+	#[must_use]
+	pub fn national_day(&self) -> Option<MonthDay> {
+		self.info().national_day.and_then(|(month, day)| MonthDay::new(month, day))
+	}
+	
+	//		postal_code_formats													
+	/// Returns the formats of the postal codes used in the country.
+	/// 
+	/// Each format is a simple pattern, in which `#` represents a digit, `@`
+	/// represents a letter, `*` represents a letter or digit, and any other
+	/// character represents itself, e.g. `#####-####` for a US ZIP+4 code. The
+	/// formats are given in order of preference, and an empty list means that
+	/// the country does not use postal codes.
+	/// 
+	#[must_use]
+	pub fn postal_code_formats(&self) -> &'static [&'static str] {
+		self.info().postal_codes
+	}
+	
+	//		validate_postal_code												
+	/// Checks whether a postal code is valid for the country.
+	/// 
+	/// The postal code is checked against each of the formats returned by
+	/// [`postal_code_formats()`](Self::postal_code_formats()). Letters are
+	/// matched case-insensitively, and surrounding whitespace is ignored, but
+	/// otherwise the postal code must match a format exactly, including any
+	/// spaces or hyphens. For countries that do not use postal codes, no postal
+	/// code is valid.
+	/// 
+	/// Note that this only checks the format of the postal code, and not
+	/// whether it actually exists.
+	/// 
+	#[must_use]
+	pub fn validate_postal_code(&self, code: &str) -> bool {
+		let trimmed = code.trim();
+		self.postal_code_formats().iter().any(|format| matches_format(format, trimmed))
+	}
+	
+	//		tax_id_format														
+	/// Returns the format of the tax identifiers of the country.
+	/// 
+	/// This gives the name, prefix, and formats of the identifiers used for
+	/// businesses for tax purposes, such as VAT identification numbers. Countries
+	/// that are not covered return `None`. For more information, see the
+	/// [`tax`](crate::tax) module.
+	/// 
+	#[must_use]
+	pub fn tax_id_format(&self) -> Option<TaxIdFormat> {
+		TaxIdFormat::for_country(*self)
+	}
+	
+	//		validate_tax_id														
+	/// Checks whether a tax identifier is valid for the country.
+	/// 
+	/// The identifier is checked using [`TaxIdFormat::is_valid()`], so
+	/// separators and the prefix are optional, and check digits are verified
+	/// where standardised. For countries that are not covered, no identifier is
+	/// valid.
+	/// 
+	/// Note that this only checks the form of the identifier, and not whether
+	/// it has actually been issued.
+	/// 
+	#[must_use]
+	pub fn validate_tax_id(&self, id: &str) -> bool {
+		self.tax_id_format().is_some_and(|format| format.is_valid(id))
+	}
+	
+	//		numbering_plan														
+	/// Returns the telephone numbering plan of the country.
+	/// 
+	/// This gives the calling code, trunk prefix, international prefix, and
+	/// lengths of national numbers, which are enough to sanity-check telephone
+	/// numbers. Countries without a numbering plan of their own, such as
+	/// uninhabited territories, return `None`. For more information, see the
+	/// [`telephony`](crate::telephony) module.
+	/// 
+	#[must_use]
+	pub fn numbering_plan(&self) -> Option<NumberingPlan> {
+		NumberingPlan::for_country(*self)
+	}
+	
+	//		nationality_mark													
+	/// Returns the ICAO nationality mark of the country.
+	/// 
+	/// This is the prefix of the registrations of aircraft registered in the
+	/// country, e.g. `G` for the UK, or `VH` for Australia. Countries without a
+	/// mark of their own return `None`. For more information, see the
+	/// [`aviation`](crate::aviation) module.
+	/// 
+	#[must_use]
+	pub fn nationality_mark(&self) -> Option<&'static str> {
+		aviation::nationality_mark(*self)
+	}
+	
+	//		driving_side														
+	/// Returns the side of the road that traffic drives on in the country.
+	#[must_use]
+	pub fn driving_side(&self) -> DrivingSide {
+		self.info().driving_side
+	}
+	
+	//		measurement_system													
+	/// Returns the customary system of measurement in the country.
+	/// 
+	/// This is the system in everyday use, which is not necessarily the
+	/// official one. For example, the United Kingdom officially uses the metric
+	/// system, but customarily uses imperial units for road distances and some
+	/// other purposes.
+	/// 
+	#[must_use]
+	pub fn measurement_system(&self) -> MeasurementSystem {
+		self.info().measurement
+	}
+	
+	//		observes_dst														
+	/// Returns `true` if daylight saving time is observed in the country.
+	/// 
+	/// This is the case if the clocks change seasonally in any part of the
+	/// country, even if not in all of it. For example, daylight saving time is
+	/// observed in most of the United States, but not in Arizona or Hawaii, and
+	/// so the United States is considered to observe it. This allows scheduling
+	/// interfaces to warn about times that may be affected by clock changes,
+	/// without needing a time zone database. The data reflects the rules in
+	/// force as of [`DATA_VERSION`](crate::DATA_VERSION).
+	/// 
+	#[must_use]
+	pub fn observes_dst(&self) -> bool {
+		self.info().observes_dst
+	}
+	
+	//		timezones															
+	/// Returns the time zones of the country.
+	/// 
+	/// The time zones are identified by their names in the IANA time zone
+	/// database, e.g. `Europe/London`, and are in the order given by the
+	/// database, which generally lists the most populous zone first. Countries
+	/// that are uninhabited, such as Bouvet Island, have none.
+	/// 
+	#[must_use]
+	pub fn timezones(&self) -> &'static [&'static str] {
+		map::get(&TIMEZONES, &self.code()).copied().unwrap_or_default()
+	}
+	
+	//		chrono_timezones													
+	/// Returns the time zones of the country, as `chrono-tz` values.
+	/// 
+	/// These are the same time zones as returned by [`timezones()`](Country::timezones()),
+	/// in the same order, but already parsed, so that they can be used directly
+	/// with [`chrono`](https://crates.io/crates/chrono).
+	/// 
+	#[cfg(feature = "chrono-tz")]
+	#[must_use]
+	pub fn chrono_timezones(&self) -> Vec<Tz> {
+		self.timezones().iter().filter_map(|name| name.parse().ok()).collect()
+	}
+	
+	//		local_time_candidates												
+	/// Returns the possible local times in the country at a given instant.
+	/// 
+	/// There is one local time for each of the country's time zones, in the
+	/// same order as [`timezones()`](Country::timezones()). This is useful when
+	/// only the country of a user is known, e.g. to show the range of times at
+	/// which a notification would arrive.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `utc` - The instant to convert to local times.
+	/// 
+	#[cfg(feature = "chrono-tz")]
+	#[must_use]
+	pub fn local_time_candidates(&self, utc: DateTime<Utc>) -> Vec<DateTime<Tz>> {
+		self.chrono_timezones().iter().map(|tz| utc.with_timezone(tz)).collect()
+	}
+	
+	//		region																
+	/// Returns the geographical region that the country is in.
+	/// 
+	/// This allows countries to be handled by category, without needing to
+	/// match on every individual country. For more information, see
+	/// [`Region`].
+	/// 
+	#[must_use]
+	pub fn region(&self) -> Region {
+		self.info().region
+	}
+	
+	//		is_in_region														
+	/// Checks whether the country is in a geographical region.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `region` - The region to check.
+	/// 
+	#[must_use]
+	pub fn is_in_region(&self, region: Region) -> bool {
+		self.region() == region
+	}
+	
+	//		is_independent														
+	/// Returns `true` if the country is an independent sovereign state.
+	/// 
+	/// This follows the independence status given by ISO 3166-1. Territories
+	/// such as Puerto Rico and Greenland are not independent, and nor are
+	/// areas whose status is disputed or unresolved, such as Western Sahara.
+	/// 
+	#[must_use]
+	pub fn is_independent(&self) -> bool {
+		self.info().independent
+	}
+	
+	//		un_status															
+	/// Returns the status of the country in the United Nations.
+	/// 
+	/// This distinguishes the member states from the non-member observer
+	/// states, i.e. the Holy See and the State of Palestine, and from all other
+	/// entries, which are not members. For more information, see [`UnStatus`].
+	/// 
+	#[must_use]
+	pub fn un_status(&self) -> UnStatus {
+		self.info().un_status
+	}
+	
+	//		un_member															
+	/// Returns `true` if the country is a member state of the United Nations.
+	/// 
+	/// This allows the full list of countries to be filtered down to the 193
+	/// UN member states, which is a common requirement. Observer states are not
+	/// members. For more information, see [`un_status()`](Self::un_status()).
+	/// 
+	#[must_use]
+	pub fn un_member(&self) -> bool {
+		self.un_status() == UnStatus::Member
+	}
+	
+	//		sovereign															
+	/// Returns the sovereign state of the territory.
+	/// 
+	/// This is the state that the territory belongs to or is administered by,
+	/// e.g. the United States for Puerto Rico, Denmark for Greenland, or China
+	/// for Hong Kong. Independent countries return `None`, as do territories
+	/// without a single sovereign state, such as Antarctica, or whose status is
+	/// disputed or unresolved.
+	/// 
+	#[must_use]
+	pub fn sovereign(&self) -> Option<Self> {
+		self.info().sovereign.map(|code| code.country())
+	}
+	
+	//		territories															
+	/// Returns the territories of the country.
+	/// 
+	/// These are the countries whose [`sovereign()`](Self::sovereign()) is this
+	/// country, e.g. Greenland and the Faroe Islands for Denmark.
+	/// 
+	#[must_use]
+	pub fn territories(&self) -> Vec<Self> {
+		Self::all().into_iter().filter(|country| country.sovereign() == Some(*self)).collect()
+	}
+	
+	//		currencies															
+	/// Returns the currencies used in the country.
+	#[must_use]
+	pub fn currencies(&self) -> &'static [CurrencyCode] {
+		self.info().currencies
+	}
+	
+	//		currencies_set														
+	/// Returns the currencies used in the country, as a [`HashSet`].
+	/// 
+	/// This is a convenience method for when set semantics are required. The
+	/// data is stored as a sorted slice, as returned by [`currencies()`](Self::currencies()),
+	/// and so this method allocates a new set on each call.
+	/// 
+	#[must_use]
+	pub fn currencies_set(&self) -> HashSet<CurrencyCode> {
+		self.currencies().iter().copied().collect()
+	}
+	
+	//		currencies_in_use													
+	/// Returns the currencies in use in the country.
+	/// 
+	/// This is the same as [`currencies()`](Self::currencies()), except for
+	/// countries that ISO 4217 lists as having no universal currency, for which
+	/// the currencies in general use by convention are returned instead, e.g.
+	/// the Israeli new shekel and the Jordanian dinar for Palestine. The result
+	/// is only empty for Antarctica, which has no currency in use.
+	/// 
+	#[must_use]
+	pub fn currencies_in_use(&self) -> &'static [CurrencyCode] {
+		let currencies = self.currencies();
+		if !currencies.is_empty() {
+			return currencies;
+		}
+		map::get(&CONVENTIONAL_CURRENCIES, &self.code()).copied().unwrap_or(&[])
+	}
+	
+	//		languages															
+	/// Returns the languages used in the country.
+	#[must_use]
+	pub fn languages(&self) -> &'static [LanguageCode] {
+		self.info().languages
+	}
+	
+	//		languages_set														
+	/// Returns the languages used in the country, as a [`HashSet`].
+	/// 
+	/// This is a convenience method for when set semantics are required. The
+	/// data is stored as a sorted slice, as returned by [`languages()`](Self::languages()),
+	/// and so this method allocates a new set on each call.
+	/// 
+	#[must_use]
+	pub fn languages_set(&self) -> HashSet<LanguageCode> {
+		self.languages().iter().copied().collect()
+	}
+	
+	//		languages_with_status												
+	/// Returns the languages used in the country, along with their status.
+	/// 
+	/// The languages returned by [`languages()`](Self::languages()) come first,
+	/// and are official unless they have a more specific status, e.g. Romansh
+	/// in Switzerland is a national language. They are followed by languages
+	/// that are not official but have some other recognised status, such as
+	/// regional or minority languages, e.g. Welsh in the United Kingdom.
+	/// 
+	#[must_use]
+	pub fn languages_with_status(&self) -> Vec<(LanguageCode, LanguageStatus)> {
+		let statuses = map::get(&LANGUAGE_STATUSES, &self.code()).copied().unwrap_or(&[]);
+		let status   = |language: LanguageCode| {
+			map::get(statuses, &language).copied().unwrap_or(LanguageStatus::Official)
+		};
+		self.languages()
+			.iter()
+			.map(|&language| (language, status(language)))
+			.chain(statuses.iter().copied().filter(|&(language, _)| self.languages().binary_search(&language).is_err()))
+			.collect()
+	}
+	
+	//		languages_by_status													
+	/// Returns the languages used in the country that have a given status.
+	/// 
+	/// For example, this can be used to find only the official languages of a
+	/// country, as opposed to those that are merely spoken there. For more
+	/// information, see [`languages_with_status()`](Self::languages_with_status()).
+	/// 
+	/// # Parameters
+	/// 
+	/// * `status` - The status of the languages to return.
+	/// 
+	#[must_use]
+	pub fn languages_by_status(&self, status: LanguageStatus) -> Vec<LanguageCode> {
+		self.languages_with_status()
+			.into_iter()
+			.filter(|&(_, language_status)| language_status == status)
+			.map(|(language, _)| language)
+			.collect()
+	}
+	
+	//		data_gaps															
+	/// Returns the gaps in the data for the country.
+	/// 
+	/// Nearly all countries have at least one currency and at least one
+	/// language, but a few do not, e.g. Antarctica has neither, and ISO 4217
+	/// lists Palestine as having no universal currency. This allows such
+	/// countries to be detected, rather than assuming that the relationships
+	/// are never empty. An empty list means that the data is complete.
+	/// 
+	/// # See also
+	/// 
+	/// * [`currencies_in_use()`](Self::currencies_in_use())
+	/// 
+	#[must_use]
+	pub fn data_gaps(&self) -> Vec<DataGap> {
+		let mut gaps = Vec::new();
+		if self.currencies().is_empty() {
+			gaps.push(DataGap::Currencies);
+		}
+		if self.languages().is_empty() {
+			gaps.push(DataGap::Languages);
+		}
+		gaps
+	}
+	
+	//		is_member_of														
+	/// Returns `true` if the country is a member of the given organisation.
+	#[cfg(feature = "orgs")]
+	#[must_use]
+	pub fn is_member_of(&self, org: Org) -> bool {
+		org.members().contains(*self)
+	}
+	
+	//		is_in_zone															
+	/// Returns `true` if the country is in the given monetary zone.
+	/// 
+	/// This means that the country has officially adopted the currency of the
+	/// zone, as a member, rather than the currency merely being used there. For
+	/// more information, see [`MonetaryZone`].
+	/// 
+	#[must_use]
+	pub fn is_in_zone(&self, zone: MonetaryZone) -> bool {
+		zone.countries().contains(*self)
+	}
+	
+	//		tag																	
+	/// Returns the tag of a given type attached to the country, if there is one.
+	/// 
+	/// Tags are attached using a [`Registry`](crate::registry::Registry), and
+	/// are only available once it has been installed.
+	/// 
+	#[must_use]
+	pub fn tag<T: Any + Send + Sync>(&self) -> Option<&'static T> {
+		registry::country_tag(*self)
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for Country {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		self.name()
+	}
+}
+
+//󰭅		Debug																	
+impl Debug for Country {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}: {}", self.info().code.as_str(), self.as_str())
+	}
+}
+
+//󰭅		Display																	
+impl Display for Country {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//󰭅		From<Country> for u16													
+impl From<Country> for u16 {
+	//		from																
+	fn from(country: Country) -> Self {
+		country.code().numeric()
+	}
+}
+
+//󰭅		From<Country> for Region												
+#[cfg(feature = "icu")]
+#[expect(clippy::fallible_impl_from, reason = "Infallible")]
+impl From<Country> for subtags::Region {
+	//		from																
+	/// Converts a country into an `icu4x` region subtag.
+	/// 
+	/// The region is the two-letter code of the country, as returned by
+	/// [`Country::cldr_region()`].
+	/// 
+	fn from(country: Country) -> Self {
+		#[expect(clippy::unwrap_used, reason = "Infallible")]
+		//	This should be infallible, as all two-letter codes are valid region
+		//	subtags. If it isn't, then the data is wrong, which is a bug.
+		Self::try_from_str(country.cldr_region()).unwrap()
+	}
+}
+
+//󰭅		From<Country> for String												
+impl From<Country> for String {
+	//		from																
+	fn from(country: Country) -> Self {
+		country.to_string()
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for Country {
+	type Err = String;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		registry::country_by_name(s)
+			.or_else(|| {
+				let name = bare(s);
+				NAMES.get(&name).copied().or_else(|| registry::country_by_name_with(&name, bare))
+			})
+			.ok_or_else(|| format!("Invalid Country: {s}"))
+	}
+}
+
+//󰭅		Parse																	
+impl Parse for Country {
+	//		from_code															
+	fn from_code(code: &str, case_insensitive: bool) -> Option<Self> {
+		CountryCode::from_code(code)
+			.filter(|country| case_insensitive || country.as_str() == code)
+			.map(|country| country.country())
+	}
+	
+	//		from_quirk															
+	fn from_quirk(code: &str, case_insensitive: bool) -> Option<Self> {
+		if case_insensitive {
+			map::get_ignore_ascii_case(&QUIRKS, code)
+		} else {
+			map::get(&QUIRKS, code)
+		}.map(CountryCode::country)
+	}
+	
+	//		from_mrz															
+	fn from_mrz(code: &str, case_insensitive: bool) -> Option<Self> {
+		let trimmed = code.trim_end_matches('<');
+		if case_insensitive {
+			map::get_ignore_ascii_case(&MRZ_CODES, trimmed)
+		} else {
+			map::get(&MRZ_CODES, trimmed)
+		}.map(CountryCode::country)
+	}
+	
+	//		from_numeric														
+	fn from_numeric(numeric: u16) -> Option<Self> {
+		CountryCode::try_from(numeric).ok().map(|code| code.country())
+	}
+	
+	//		from_name															
+	fn from_name(name: &str) -> Option<Self> {
+		name.parse().ok()
+	}
+}
+
+//󰭅		TryFrom<Region>															
+#[cfg(feature = "icu")]
+impl TryFrom<subtags::Region> for Country {
+	type Error = String;
+	
+	//		try_from															
+	/// Converts an `icu4x` region subtag into a country.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the region is not a country, such as the numeric
+	/// code `419` for Latin America.
+	/// 
+	fn try_from(region: subtags::Region) -> Result<Self, Self::Error> {
+		CountryCode::from_code(region.as_str())
+			.map(|code| code.country())
+			.ok_or_else(|| format!("Invalid Country: {region}"))
+	}
+}
+
+//󰭅		TryFrom<String>															
+impl TryFrom<String> for Country {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.as_str().parse()
+	}
+}
+
+//		CountryCode																
+/// The possible countries' codes.
+/// 
+/// These codes are based on the ISO 3166 standard, specifically ISO 3166-1,
+/// which defines codes of two and three characters to represent countries and
+/// territories. There are both alphabetic and numeric codes, using either two
+/// letters, three letters, or three numbers.
+/// 
+/// # Alphabetic codes
+/// 
+/// The alphabetic codes are defined by the ISO 3166-1 alpha-2 set, which is the
+/// most widely-used of the three sets; and the ISO 3166-1 alpha-3 set, which is
+/// less widely-used but gives better visual association between the codes and
+/// country names than the alpha-2 set.
+/// 
+/// # Numeric codes
+/// 
+/// The three-digit numeric code is a useful alternative when the letter-based
+/// code may not be appropriate.
+/// 
+/// # Serialisation
+/// 
+/// In human-readable formats, such as JSON, the codes are (de)serialised as
+/// strings, e.g. `"GB"`. In compact formats, such as `bincode` and `postcard`,
+/// they are (de)serialised as their numeric codes, e.g. `826`, which are
+/// smaller and faster to process. This is decided by the format, and needs no
+/// configuration. As the two-letter and three-letter forms of a code share the
+/// same numeric code, three-letter codes are deserialised as the equivalent
+/// two-letter code in compact formats.
+/// 
+/// # Data sources
+/// 
+/// The list of codes is available from [the ISO site](https://www.iso.org/iso-3166-country-codes.html),
+/// and from [Wikipedia](https://en.wikipedia.org/wiki/ISO_3166-1).
+/// 
+/// # See also
+/// 
+/// * [`Country`]
+/// 
+#[expect(clippy::doc_markdown,          reason = "False positives")]
+#[expect(clippy::zero_prefixed_literal, reason = "Zeroes aid readability here")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[repr(u16)]
+#[non_exhaustive]
+pub enum CountryCode {
+	//		Two-letter codes (ISO 3166-1 alpha-2)								
+	/// Andorra
+	AD  = 020,
+	
+	/// United Arab Emirates
+	AE  = 784,
+	
+	/// Afghanistan
+	AF  = 004,
+	
+	/// Antigua and Barbuda
+	AG  = 028,
+	
+	/// Anguilla
+	AI  = 660,
 	
 	/// Albania
 	AL  = 008,
@@ -2755,8 +4718,616 @@ pub enum CountryCode {
 impl CountryCode {
 	//		all																	
 	/// Returns all the country codes.
+	#[must_use]
 	pub fn all() -> Vec<Self> {
-		COUNTRIES.values().map(|info| info.code).collect()
+		COUNTRIES.iter().map(|info| info.code).collect()
+	}
+	
+	//		complete															
+	/// Returns the country codes that start with a given prefix.
+	/// 
+	/// This is intended for code completion, such as in terminal UIs and bots.
+	/// Both two-letter and three-letter codes are included, the match is
+	/// case-insensitive, and the codes are returned in alphabetical order, e.g.
+	/// `GR` gives `GR`, `GRC`, `GRD`, and `GRL`.
+	/// 
+	#[must_use]
+	pub fn complete(prefix: &str) -> Vec<Self> {
+		let mut codes: Vec<_> = COUNTRIES
+			.iter()
+			.flat_map(|info| [ info.code, info.code.to_alpha3() ])
+			.filter(|code| code.as_str().get(..prefix.len()).is_some_and(|start| start.eq_ignore_ascii_case(prefix)))
+			.collect();
+		codes.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+		codes
+	}
+	
+	//		from_code															
+	/// Returns the [`CountryCode`] corresponding to a code string.
+	/// 
+	/// This method accepts both two-letter and three-letter codes, and the
+	/// match is case-insensitive, in the same way as [`FromStr`]. However,
+	/// unlike [`FromStr`], it is a `const fn`, and so can be used in `const`
+	/// contexts, such as by the [`country!`](crate::country!) macro. If the
+	/// code is not recognised, `None` is returned.
+	/// 
+	#[expect(clippy::too_many_lines, reason = "Data not logic")]
+	#[must_use]
+	pub const fn from_code(code: &str) -> Option<Self> {
+		match *code.as_bytes() {
+			//		Two-letter codes (ISO 3166-1 alpha-2)						
+			[a, b]    => match &[a.to_ascii_uppercase(), b.to_ascii_uppercase()] {
+				b"AD" => Some(Self::AD),
+				b"AE" => Some(Self::AE),
+				b"AF" => Some(Self::AF),
+				b"AG" => Some(Self::AG),
+				b"AI" => Some(Self::AI),
+				b"AL" => Some(Self::AL),
+				b"AM" => Some(Self::AM),
+				b"AO" => Some(Self::AO),
+				b"AQ" => Some(Self::AQ),
+				b"AR" => Some(Self::AR),
+				b"AS" => Some(Self::AS),
+				b"AT" => Some(Self::AT),
+				b"AU" => Some(Self::AU),
+				b"AW" => Some(Self::AW),
+				b"AX" => Some(Self::AX),
+				b"AZ" => Some(Self::AZ),
+				b"BA" => Some(Self::BA),
+				b"BB" => Some(Self::BB),
+				b"BD" => Some(Self::BD),
+				b"BE" => Some(Self::BE),
+				b"BF" => Some(Self::BF),
+				b"BG" => Some(Self::BG),
+				b"BH" => Some(Self::BH),
+				b"BI" => Some(Self::BI),
+				b"BJ" => Some(Self::BJ),
+				b"BL" => Some(Self::BL),
+				b"BM" => Some(Self::BM),
+				b"BN" => Some(Self::BN),
+				b"BO" => Some(Self::BO),
+				b"BQ" => Some(Self::BQ),
+				b"BR" => Some(Self::BR),
+				b"BS" => Some(Self::BS),
+				b"BT" => Some(Self::BT),
+				b"BV" => Some(Self::BV),
+				b"BW" => Some(Self::BW),
+				b"BY" => Some(Self::BY),
+				b"BZ" => Some(Self::BZ),
+				b"CA" => Some(Self::CA),
+				b"CC" => Some(Self::CC),
+				b"CD" => Some(Self::CD),
+				b"CF" => Some(Self::CF),
+				b"CG" => Some(Self::CG),
+				b"CH" => Some(Self::CH),
+				b"CI" => Some(Self::CI),
+				b"CK" => Some(Self::CK),
+				b"CL" => Some(Self::CL),
+				b"CM" => Some(Self::CM),
+				b"CN" => Some(Self::CN),
+				b"CO" => Some(Self::CO),
+				b"CR" => Some(Self::CR),
+				b"CU" => Some(Self::CU),
+				b"CV" => Some(Self::CV),
+				b"CW" => Some(Self::CW),
+				b"CX" => Some(Self::CX),
+				b"CY" => Some(Self::CY),
+				b"CZ" => Some(Self::CZ),
+				b"DE" => Some(Self::DE),
+				b"DJ" => Some(Self::DJ),
+				b"DK" => Some(Self::DK),
+				b"DM" => Some(Self::DM),
+				b"DO" => Some(Self::DO),
+				b"DZ" => Some(Self::DZ),
+				b"EC" => Some(Self::EC),
+				b"EE" => Some(Self::EE),
+				b"EG" => Some(Self::EG),
+				b"EH" => Some(Self::EH),
+				b"ER" => Some(Self::ER),
+				b"ES" => Some(Self::ES),
+				b"ET" => Some(Self::ET),
+				b"FI" => Some(Self::FI),
+				b"FJ" => Some(Self::FJ),
+				b"FK" => Some(Self::FK),
+				b"FM" => Some(Self::FM),
+				b"FO" => Some(Self::FO),
+				b"FR" => Some(Self::FR),
+				b"GA" => Some(Self::GA),
+				b"GB" => Some(Self::GB),
+				b"GD" => Some(Self::GD),
+				b"GE" => Some(Self::GE),
+				b"GF" => Some(Self::GF),
+				b"GG" => Some(Self::GG),
+				b"GH" => Some(Self::GH),
+				b"GI" => Some(Self::GI),
+				b"GL" => Some(Self::GL),
+				b"GM" => Some(Self::GM),
+				b"GN" => Some(Self::GN),
+				b"GP" => Some(Self::GP),
+				b"GQ" => Some(Self::GQ),
+				b"GR" => Some(Self::GR),
+				b"GS" => Some(Self::GS),
+				b"GT" => Some(Self::GT),
+				b"GU" => Some(Self::GU),
+				b"GW" => Some(Self::GW),
+				b"GY" => Some(Self::GY),
+				b"HK" => Some(Self::HK),
+				b"HM" => Some(Self::HM),
+				b"HN" => Some(Self::HN),
+				b"HR" => Some(Self::HR),
+				b"HT" => Some(Self::HT),
+				b"HU" => Some(Self::HU),
+				b"ID" => Some(Self::ID),
+				b"IE" => Some(Self::IE),
+				b"IL" => Some(Self::IL),
+				b"IM" => Some(Self::IM),
+				b"IN" => Some(Self::IN),
+				b"IO" => Some(Self::IO),
+				b"IQ" => Some(Self::IQ),
+				b"IR" => Some(Self::IR),
+				b"IS" => Some(Self::IS),
+				b"IT" => Some(Self::IT),
+				b"JE" => Some(Self::JE),
+				b"JM" => Some(Self::JM),
+				b"JO" => Some(Self::JO),
+				b"JP" => Some(Self::JP),
+				b"KE" => Some(Self::KE),
+				b"KG" => Some(Self::KG),
+				b"KH" => Some(Self::KH),
+				b"KI" => Some(Self::KI),
+				b"KM" => Some(Self::KM),
+				b"KN" => Some(Self::KN),
+				b"KP" => Some(Self::KP),
+				b"KR" => Some(Self::KR),
+				b"KW" => Some(Self::KW),
+				b"KY" => Some(Self::KY),
+				b"KZ" => Some(Self::KZ),
+				b"LA" => Some(Self::LA),
+				b"LB" => Some(Self::LB),
+				b"LC" => Some(Self::LC),
+				b"LI" => Some(Self::LI),
+				b"LK" => Some(Self::LK),
+				b"LR" => Some(Self::LR),
+				b"LS" => Some(Self::LS),
+				b"LT" => Some(Self::LT),
+				b"LU" => Some(Self::LU),
+				b"LV" => Some(Self::LV),
+				b"LY" => Some(Self::LY),
+				b"MA" => Some(Self::MA),
+				b"MC" => Some(Self::MC),
+				b"MD" => Some(Self::MD),
+				b"ME" => Some(Self::ME),
+				b"MF" => Some(Self::MF),
+				b"MG" => Some(Self::MG),
+				b"MH" => Some(Self::MH),
+				b"MK" => Some(Self::MK),
+				b"ML" => Some(Self::ML),
+				b"MM" => Some(Self::MM),
+				b"MN" => Some(Self::MN),
+				b"MO" => Some(Self::MO),
+				b"MP" => Some(Self::MP),
+				b"MQ" => Some(Self::MQ),
+				b"MR" => Some(Self::MR),
+				b"MS" => Some(Self::MS),
+				b"MT" => Some(Self::MT),
+				b"MU" => Some(Self::MU),
+				b"MV" => Some(Self::MV),
+				b"MW" => Some(Self::MW),
+				b"MX" => Some(Self::MX),
+				b"MY" => Some(Self::MY),
+				b"MZ" => Some(Self::MZ),
+				b"NA" => Some(Self::NA),
+				b"NC" => Some(Self::NC),
+				b"NE" => Some(Self::NE),
+				b"NF" => Some(Self::NF),
+				b"NG" => Some(Self::NG),
+				b"NI" => Some(Self::NI),
+				b"NL" => Some(Self::NL),
+				b"NO" => Some(Self::NO),
+				b"NP" => Some(Self::NP),
+				b"NR" => Some(Self::NR),
+				b"NU" => Some(Self::NU),
+				b"NZ" => Some(Self::NZ),
+				b"OM" => Some(Self::OM),
+				b"PA" => Some(Self::PA),
+				b"PE" => Some(Self::PE),
+				b"PF" => Some(Self::PF),
+				b"PG" => Some(Self::PG),
+				b"PH" => Some(Self::PH),
+				b"PK" => Some(Self::PK),
+				b"PL" => Some(Self::PL),
+				b"PM" => Some(Self::PM),
+				b"PN" => Some(Self::PN),
+				b"PR" => Some(Self::PR),
+				b"PS" => Some(Self::PS),
+				b"PT" => Some(Self::PT),
+				b"PW" => Some(Self::PW),
+				b"PY" => Some(Self::PY),
+				b"QA" => Some(Self::QA),
+				b"RE" => Some(Self::RE),
+				b"RO" => Some(Self::RO),
+				b"RS" => Some(Self::RS),
+				b"RU" => Some(Self::RU),
+				b"RW" => Some(Self::RW),
+				b"SA" => Some(Self::SA),
+				b"SB" => Some(Self::SB),
+				b"SC" => Some(Self::SC),
+				b"SD" => Some(Self::SD),
+				b"SE" => Some(Self::SE),
+				b"SG" => Some(Self::SG),
+				b"SH" => Some(Self::SH),
+				b"SI" => Some(Self::SI),
+				b"SJ" => Some(Self::SJ),
+				b"SK" => Some(Self::SK),
+				b"SL" => Some(Self::SL),
+				b"SM" => Some(Self::SM),
+				b"SN" => Some(Self::SN),
+				b"SO" => Some(Self::SO),
+				b"SR" => Some(Self::SR),
+				b"SS" => Some(Self::SS),
+				b"ST" => Some(Self::ST),
+				b"SV" => Some(Self::SV),
+				b"SX" => Some(Self::SX),
+				b"SY" => Some(Self::SY),
+				b"SZ" => Some(Self::SZ),
+				b"TC" => Some(Self::TC),
+				b"TD" => Some(Self::TD),
+				b"TF" => Some(Self::TF),
+				b"TG" => Some(Self::TG),
+				b"TH" => Some(Self::TH),
+				b"TJ" => Some(Self::TJ),
+				b"TK" => Some(Self::TK),
+				b"TL" => Some(Self::TL),
+				b"TM" => Some(Self::TM),
+				b"TN" => Some(Self::TN),
+				b"TO" => Some(Self::TO),
+				b"TR" => Some(Self::TR),
+				b"TT" => Some(Self::TT),
+				b"TV" => Some(Self::TV),
+				b"TW" => Some(Self::TW),
+				b"TZ" => Some(Self::TZ),
+				b"UA" => Some(Self::UA),
+				b"UG" => Some(Self::UG),
+				b"UM" => Some(Self::UM),
+				b"US" => Some(Self::US),
+				b"UY" => Some(Self::UY),
+				b"UZ" => Some(Self::UZ),
+				b"VA" => Some(Self::VA),
+				b"VC" => Some(Self::VC),
+				b"VE" => Some(Self::VE),
+				b"VG" => Some(Self::VG),
+				b"VI" => Some(Self::VI),
+				b"VN" => Some(Self::VN),
+				b"VU" => Some(Self::VU),
+				b"WF" => Some(Self::WF),
+				b"WS" => Some(Self::WS),
+				b"YE" => Some(Self::YE),
+				b"YT" => Some(Self::YT),
+				b"ZA" => Some(Self::ZA),
+				b"ZM" => Some(Self::ZM),
+				b"ZW" => Some(Self::ZW),
+				_     => None,
+			},
+			//		Three-letter codes (ISO 3166-1 alpha-3)						
+			[a, b, c] => match &[a.to_ascii_uppercase(), b.to_ascii_uppercase(), c.to_ascii_uppercase()] {
+				b"ABW" => Some(Self::ABW),
+				b"AFG" => Some(Self::AFG),
+				b"AGO" => Some(Self::AGO),
+				b"AIA" => Some(Self::AIA),
+				b"ALA" => Some(Self::ALA),
+				b"ALB" => Some(Self::ALB),
+				b"AND" => Some(Self::AND),
+				b"ARE" => Some(Self::ARE),
+				b"ARG" => Some(Self::ARG),
+				b"ARM" => Some(Self::ARM),
+				b"ASM" => Some(Self::ASM),
+				b"ATA" => Some(Self::ATA),
+				b"ATF" => Some(Self::ATF),
+				b"ATG" => Some(Self::ATG),
+				b"AUS" => Some(Self::AUS),
+				b"AUT" => Some(Self::AUT),
+				b"AZE" => Some(Self::AZE),
+				b"BDI" => Some(Self::BDI),
+				b"BEL" => Some(Self::BEL),
+				b"BEN" => Some(Self::BEN),
+				b"BES" => Some(Self::BES),
+				b"BFA" => Some(Self::BFA),
+				b"BGD" => Some(Self::BGD),
+				b"BGR" => Some(Self::BGR),
+				b"BHR" => Some(Self::BHR),
+				b"BHS" => Some(Self::BHS),
+				b"BIH" => Some(Self::BIH),
+				b"BLM" => Some(Self::BLM),
+				b"BLR" => Some(Self::BLR),
+				b"BLZ" => Some(Self::BLZ),
+				b"BMU" => Some(Self::BMU),
+				b"BOL" => Some(Self::BOL),
+				b"BRA" => Some(Self::BRA),
+				b"BRB" => Some(Self::BRB),
+				b"BRN" => Some(Self::BRN),
+				b"BTN" => Some(Self::BTN),
+				b"BVT" => Some(Self::BVT),
+				b"BWA" => Some(Self::BWA),
+				b"CAF" => Some(Self::CAF),
+				b"CAN" => Some(Self::CAN),
+				b"CCK" => Some(Self::CCK),
+				b"CHE" => Some(Self::CHE),
+				b"CHL" => Some(Self::CHL),
+				b"CHN" => Some(Self::CHN),
+				b"CIV" => Some(Self::CIV),
+				b"CMR" => Some(Self::CMR),
+				b"COD" => Some(Self::COD),
+				b"COG" => Some(Self::COG),
+				b"COK" => Some(Self::COK),
+				b"COL" => Some(Self::COL),
+				b"COM" => Some(Self::COM),
+				b"CPV" => Some(Self::CPV),
+				b"CRI" => Some(Self::CRI),
+				b"CUB" => Some(Self::CUB),
+				b"CUW" => Some(Self::CUW),
+				b"CXR" => Some(Self::CXR),
+				b"CYM" => Some(Self::CYM),
+				b"CYP" => Some(Self::CYP),
+				b"CZE" => Some(Self::CZE),
+				b"DEU" => Some(Self::DEU),
+				b"DJI" => Some(Self::DJI),
+				b"DMA" => Some(Self::DMA),
+				b"DNK" => Some(Self::DNK),
+				b"DOM" => Some(Self::DOM),
+				b"DZA" => Some(Self::DZA),
+				b"ECU" => Some(Self::ECU),
+				b"EGY" => Some(Self::EGY),
+				b"ERI" => Some(Self::ERI),
+				b"ESH" => Some(Self::ESH),
+				b"ESP" => Some(Self::ESP),
+				b"EST" => Some(Self::EST),
+				b"ETH" => Some(Self::ETH),
+				b"FIN" => Some(Self::FIN),
+				b"FJI" => Some(Self::FJI),
+				b"FLK" => Some(Self::FLK),
+				b"FRA" => Some(Self::FRA),
+				b"FRO" => Some(Self::FRO),
+				b"FSM" => Some(Self::FSM),
+				b"GAB" => Some(Self::GAB),
+				b"GBR" => Some(Self::GBR),
+				b"GEO" => Some(Self::GEO),
+				b"GGY" => Some(Self::GGY),
+				b"GHA" => Some(Self::GHA),
+				b"GIB" => Some(Self::GIB),
+				b"GIN" => Some(Self::GIN),
+				b"GLP" => Some(Self::GLP),
+				b"GMB" => Some(Self::GMB),
+				b"GNB" => Some(Self::GNB),
+				b"GNQ" => Some(Self::GNQ),
+				b"GRC" => Some(Self::GRC),
+				b"GRD" => Some(Self::GRD),
+				b"GRL" => Some(Self::GRL),
+				b"GTM" => Some(Self::GTM),
+				b"GUF" => Some(Self::GUF),
+				b"GUM" => Some(Self::GUM),
+				b"GUY" => Some(Self::GUY),
+				b"HKG" => Some(Self::HKG),
+				b"HMD" => Some(Self::HMD),
+				b"HND" => Some(Self::HND),
+				b"HRV" => Some(Self::HRV),
+				b"HTI" => Some(Self::HTI),
+				b"HUN" => Some(Self::HUN),
+				b"IDN" => Some(Self::IDN),
+				b"IMN" => Some(Self::IMN),
+				b"IND" => Some(Self::IND),
+				b"IOT" => Some(Self::IOT),
+				b"IRL" => Some(Self::IRL),
+				b"IRN" => Some(Self::IRN),
+				b"IRQ" => Some(Self::IRQ),
+				b"ISL" => Some(Self::ISL),
+				b"ISR" => Some(Self::ISR),
+				b"ITA" => Some(Self::ITA),
+				b"JAM" => Some(Self::JAM),
+				b"JEY" => Some(Self::JEY),
+				b"JOR" => Some(Self::JOR),
+				b"JPN" => Some(Self::JPN),
+				b"KAZ" => Some(Self::KAZ),
+				b"KEN" => Some(Self::KEN),
+				b"KGZ" => Some(Self::KGZ),
+				b"KHM" => Some(Self::KHM),
+				b"KIR" => Some(Self::KIR),
+				b"KNA" => Some(Self::KNA),
+				b"KOR" => Some(Self::KOR),
+				b"KWT" => Some(Self::KWT),
+				b"LAO" => Some(Self::LAO),
+				b"LBN" => Some(Self::LBN),
+				b"LBR" => Some(Self::LBR),
+				b"LBY" => Some(Self::LBY),
+				b"LCA" => Some(Self::LCA),
+				b"LIE" => Some(Self::LIE),
+				b"LKA" => Some(Self::LKA),
+				b"LSO" => Some(Self::LSO),
+				b"LTU" => Some(Self::LTU),
+				b"LUX" => Some(Self::LUX),
+				b"LVA" => Some(Self::LVA),
+				b"MAC" => Some(Self::MAC),
+				b"MAF" => Some(Self::MAF),
+				b"MAR" => Some(Self::MAR),
+				b"MCO" => Some(Self::MCO),
+				b"MDA" => Some(Self::MDA),
+				b"MDG" => Some(Self::MDG),
+				b"MDV" => Some(Self::MDV),
+				b"MEX" => Some(Self::MEX),
+				b"MHL" => Some(Self::MHL),
+				b"MKD" => Some(Self::MKD),
+				b"MLI" => Some(Self::MLI),
+				b"MLT" => Some(Self::MLT),
+				b"MMR" => Some(Self::MMR),
+				b"MNE" => Some(Self::MNE),
+				b"MNG" => Some(Self::MNG),
+				b"MNP" => Some(Self::MNP),
+				b"MOZ" => Some(Self::MOZ),
+				b"MRT" => Some(Self::MRT),
+				b"MSR" => Some(Self::MSR),
+				b"MTQ" => Some(Self::MTQ),
+				b"MUS" => Some(Self::MUS),
+				b"MWI" => Some(Self::MWI),
+				b"MYS" => Some(Self::MYS),
+				b"MYT" => Some(Self::MYT),
+				b"NAM" => Some(Self::NAM),
+				b"NCL" => Some(Self::NCL),
+				b"NER" => Some(Self::NER),
+				b"NFK" => Some(Self::NFK),
+				b"NGA" => Some(Self::NGA),
+				b"NIC" => Some(Self::NIC),
+				b"NIU" => Some(Self::NIU),
+				b"NLD" => Some(Self::NLD),
+				b"NOR" => Some(Self::NOR),
+				b"NPL" => Some(Self::NPL),
+				b"NRU" => Some(Self::NRU),
+				b"NZL" => Some(Self::NZL),
+				b"OMN" => Some(Self::OMN),
+				b"PAK" => Some(Self::PAK),
+				b"PAN" => Some(Self::PAN),
+				b"PCN" => Some(Self::PCN),
+				b"PER" => Some(Self::PER),
+				b"PHL" => Some(Self::PHL),
+				b"PLW" => Some(Self::PLW),
+				b"PNG" => Some(Self::PNG),
+				b"POL" => Some(Self::POL),
+				b"PRI" => Some(Self::PRI),
+				b"PRK" => Some(Self::PRK),
+				b"PRT" => Some(Self::PRT),
+				b"PRY" => Some(Self::PRY),
+				b"PSE" => Some(Self::PSE),
+				b"PYF" => Some(Self::PYF),
+				b"QAT" => Some(Self::QAT),
+				b"REU" => Some(Self::REU),
+				b"ROU" => Some(Self::ROU),
+				b"RUS" => Some(Self::RUS),
+				b"RWA" => Some(Self::RWA),
+				b"SAU" => Some(Self::SAU),
+				b"SDN" => Some(Self::SDN),
+				b"SEN" => Some(Self::SEN),
+				b"SGP" => Some(Self::SGP),
+				b"SGS" => Some(Self::SGS),
+				b"SHN" => Some(Self::SHN),
+				b"SJM" => Some(Self::SJM),
+				b"SLB" => Some(Self::SLB),
+				b"SLE" => Some(Self::SLE),
+				b"SLV" => Some(Self::SLV),
+				b"SMR" => Some(Self::SMR),
+				b"SOM" => Some(Self::SOM),
+				b"SPM" => Some(Self::SPM),
+				b"SRB" => Some(Self::SRB),
+				b"SSD" => Some(Self::SSD),
+				b"STP" => Some(Self::STP),
+				b"SUR" => Some(Self::SUR),
+				b"SVK" => Some(Self::SVK),
+				b"SVN" => Some(Self::SVN),
+				b"SWE" => Some(Self::SWE),
+				b"SWZ" => Some(Self::SWZ),
+				b"SXM" => Some(Self::SXM),
+				b"SYC" => Some(Self::SYC),
+				b"SYR" => Some(Self::SYR),
+				b"TCA" => Some(Self::TCA),
+				b"TCD" => Some(Self::TCD),
+				b"TGO" => Some(Self::TGO),
+				b"THA" => Some(Self::THA),
+				b"TJK" => Some(Self::TJK),
+				b"TKL" => Some(Self::TKL),
+				b"TKM" => Some(Self::TKM),
+				b"TLS" => Some(Self::TLS),
+				b"TON" => Some(Self::TON),
+				b"TTO" => Some(Self::TTO),
+				b"TUN" => Some(Self::TUN),
+				b"TUR" => Some(Self::TUR),
+				b"TUV" => Some(Self::TUV),
+				b"TWN" => Some(Self::TWN),
+				b"TZA" => Some(Self::TZA),
+				b"UGA" => Some(Self::UGA),
+				b"UKR" => Some(Self::UKR),
+				b"UMI" => Some(Self::UMI),
+				b"URY" => Some(Self::URY),
+				b"USA" => Some(Self::USA),
+				b"UZB" => Some(Self::UZB),
+				b"VAT" => Some(Self::VAT),
+				b"VCT" => Some(Self::VCT),
+				b"VEN" => Some(Self::VEN),
+				b"VGB" => Some(Self::VGB),
+				b"VIR" => Some(Self::VIR),
+				b"VNM" => Some(Self::VNM),
+				b"VUT" => Some(Self::VUT),
+				b"WLF" => Some(Self::WLF),
+				b"WSM" => Some(Self::WSM),
+				b"YEM" => Some(Self::YEM),
+				b"ZAF" => Some(Self::ZAF),
+				b"ZMB" => Some(Self::ZMB),
+				b"ZWE" => Some(Self::ZWE),
+				_      => None,
+			},
+			_         => None,
+		}
+	}
+	
+	//		looks_valid															
+	/// Returns `true` if a string has the shape of a country code.
+	/// 
+	/// This checks only that the string consists of two or three uppercase
+	/// ASCII letters, without looking the code up, and so is cheaper than
+	/// parsing. It is intended for pre-filtering large, noisy datasets before
+	/// exact validation, and so a string that passes may still not be a valid
+	/// code, e.g. `"XX"`. Codes in lowercase do not pass, even though they are
+	/// accepted by [`from_code()`](Self::from_code()) and [`FromStr`].
+	/// 
+	#[must_use]
+	pub const fn looks_valid(code: &str) -> bool {
+		match *code.as_bytes() {
+			[a, b]    => a.is_ascii_uppercase() && b.is_ascii_uppercase(),
+			[a, b, c] => a.is_ascii_uppercase() && b.is_ascii_uppercase() && c.is_ascii_uppercase(),
+			_         => false,
+		}
+	}
+	
+	//		parse_all															
+	/// Parses a sequence of code strings into [`CountryCode`]s.
+	/// 
+	/// Each code is parsed in the same way as by [`FromStr`], and so both
+	/// two-letter and three-letter codes are accepted, in any case. This is
+	/// intended for converting whole columns of data in one call.
+	/// 
+	/// # Errors
+	/// 
+	/// If any code is not recognised, the index of the first such code is
+	/// returned, along with the error from parsing it.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `codes` - The code strings to parse.
+	/// 
+	pub fn parse_all<'a, I: IntoIterator<Item = &'a str>>(codes: I) -> Result<Vec<Self>, (usize, String)> {
+		codes
+			.into_iter()
+			.enumerate()
+			.map(|(index, code)| code.parse().map_err(|err| (index, err)))
+			.collect()
+	}
+	
+	//		const_from_str														
+	/// Returns the [`CountryCode`] corresponding to a code string, in `const` contexts.
+	/// 
+	/// This method behaves in the same way as [`from_code()`](Self::from_code()),
+	/// but panics instead of returning `None`. When used in a `const` context,
+	/// such as to initialise a `const` or `static`, this means that an invalid
+	/// code will cause a compile error, allowing lists of country codes in
+	/// configuration to be validated at compile time.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the code is not recognised.
+	/// 
+	#[expect(clippy::panic, reason = "Intended for compile-time validation")]
+	#[must_use]
+	pub const fn const_from_str(code: &str) -> Self {
+		match Self::from_code(code) {
+			Some(parsed) => parsed,
+			None         => panic!("Invalid CountryCode"),
+		}
 	}
 	
 	//		country																
@@ -3295,6 +5866,41 @@ impl CountryCode {
 		(*self as u16) >= 1_000
 	}
 	
+	//		status																
+	/// Returns the ISO 3166-1 status of the code.
+	/// 
+	/// ISO 3166-1 distinguishes officially-assigned codes from those that are
+	/// reserved for other uses, or are available for users to assign. Only the
+	/// officially-assigned codes are currently included, and so this always
+	/// returns [`CodeStatus::OfficiallyAssigned`], but validation layers can
+	/// match on the status so that their policies continue to apply if codes of
+	/// other statuses are added.
+	/// 
+	#[must_use]
+	pub const fn status(&self) -> CodeStatus {
+		CodeStatus::OfficiallyAssigned
+	}
+	
+	//		numeric																
+	/// Returns the ISO 3166-1 numeric code.
+	/// 
+	/// Both the two-letter and three-letter codes for a country return the same
+	/// value, which is the three-digit numeric code defined by the ISO 3166-1
+	/// standard. This should be used in preference to casting with `as u16`, as
+	/// the three-letter codes are stored internally with an offset of 1,000 in
+	/// order to avoid collisions with the two-letter codes.
+	/// 
+	#[must_use]
+	pub const fn numeric(&self) -> u16 {
+		let code = *self as u16;
+		#[expect(clippy::arithmetic_side_effects, reason = "Range is controlled")]
+		if code >= 1_000 {
+			code - 1_000
+		} else {
+			code
+		}
+	}
+	
 	//		to_alpha2															
 	/// Converts a three-letter [`CountryCode`] to a two-letter [`CountryCode`].
 	/// 
@@ -3583,6 +6189,20 @@ impl CountryCode {
 			_        => *self,
 		}
 	}
+	
+	//		stable_hash															
+	/// Returns a stable 64-bit hash of the country code.
+	/// 
+	/// The hash is calculated from the two-letter form of the code, using
+	/// [`stable_hash()`](crate::text::stable_hash()), so both forms of a code
+	/// have the same hash, e.g. `GB` and `GBR`. It is guaranteed not to change
+	/// between versions, which makes it suitable for consistent hashing and
+	/// sharding across services.
+	/// 
+	#[must_use]
+	pub fn stable_hash(&self) -> u64 {
+		stable_hash(self.to_alpha2().as_str())
+	}
 }
 
 //󰭅		AsStr																	
@@ -4095,6 +6715,18 @@ impl AsStr for CountryCode {
 	}
 }
 
+//󰭅		Deserialize																
+impl<'de> Deserialize<'de> for CountryCode {
+	//		deserialize															
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		if deserializer.is_human_readable() {
+			String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+		} else {
+			Self::try_from(u16::deserialize(deserializer)?).map_err(de::Error::custom)
+		}
+	}
+}
+
 //󰭅		Display																	
 impl Display for CountryCode {
 	//		fmt																	
@@ -4103,6 +6735,15 @@ impl Display for CountryCode {
 	}
 }
 
+//󰭅		Format																	
+#[cfg(feature = "defmt")]
+impl Format for CountryCode {
+	//		format																
+	fn format(&self, fmt: Formatter<'_>) {
+		defmt::write!(fmt, "{=str}", self.as_str());
+	}
+}
+
 //󰭅		From<CountryCode> for u16												
 impl From<CountryCode> for u16 {
 	//		from																
@@ -4112,13 +6753,8 @@ impl From<CountryCode> for u16 {
 		//	numeric representations. To avoid this, the three-letter codes have
 		//	1,000 added to them, for the sole purpose of internal storage. This
 		//	needs to be adjusted when the enum variants are serialized or otherwise
-		//	represented as an integer.
-		#[expect(clippy::arithmetic_side_effects, reason = "Range is controlled")]
-		if code as Self > 1_000 {
-			code as Self - 1_000
-		} else {
-			code as Self
-		}
+		//	represented as an integer, which is handled by numeric().
+		code.numeric()
 	}
 }
 
@@ -4135,511 +6771,36 @@ impl FromStr for CountryCode {
 	type Err = String;
 	
 	//		from_str															
-	#[expect(clippy::too_many_lines, reason = "Data not logic")]
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		match s.to_uppercase().as_str() {
-			//		Two-letter codes (ISO 3166-1 alpha-2)						
-			"AD"  => Ok(Self::AD),
-			"AE"  => Ok(Self::AE),
-			"AF"  => Ok(Self::AF),
-			"AG"  => Ok(Self::AG),
-			"AI"  => Ok(Self::AI),
-			"AL"  => Ok(Self::AL),
-			"AM"  => Ok(Self::AM),
-			"AO"  => Ok(Self::AO),
-			"AQ"  => Ok(Self::AQ),
-			"AR"  => Ok(Self::AR),
-			"AS"  => Ok(Self::AS),
-			"AT"  => Ok(Self::AT),
-			"AU"  => Ok(Self::AU),
-			"AW"  => Ok(Self::AW),
-			"AX"  => Ok(Self::AX),
-			"AZ"  => Ok(Self::AZ),
-			"BA"  => Ok(Self::BA),
-			"BB"  => Ok(Self::BB),
-			"BD"  => Ok(Self::BD),
-			"BE"  => Ok(Self::BE),
-			"BF"  => Ok(Self::BF),
-			"BG"  => Ok(Self::BG),
-			"BH"  => Ok(Self::BH),
-			"BI"  => Ok(Self::BI),
-			"BJ"  => Ok(Self::BJ),
-			"BL"  => Ok(Self::BL),
-			"BM"  => Ok(Self::BM),
-			"BN"  => Ok(Self::BN),
-			"BO"  => Ok(Self::BO),
-			"BQ"  => Ok(Self::BQ),
-			"BR"  => Ok(Self::BR),
-			"BS"  => Ok(Self::BS),
-			"BT"  => Ok(Self::BT),
-			"BV"  => Ok(Self::BV),
-			"BW"  => Ok(Self::BW),
-			"BY"  => Ok(Self::BY),
-			"BZ"  => Ok(Self::BZ),
-			"CA"  => Ok(Self::CA),
-			"CC"  => Ok(Self::CC),
-			"CD"  => Ok(Self::CD),
-			"CF"  => Ok(Self::CF),
-			"CG"  => Ok(Self::CG),
-			"CH"  => Ok(Self::CH),
-			"CI"  => Ok(Self::CI),
-			"CK"  => Ok(Self::CK),
-			"CL"  => Ok(Self::CL),
-			"CM"  => Ok(Self::CM),
-			"CN"  => Ok(Self::CN),
-			"CO"  => Ok(Self::CO),
-			"CR"  => Ok(Self::CR),
-			"CU"  => Ok(Self::CU),
-			"CV"  => Ok(Self::CV),
-			"CW"  => Ok(Self::CW),
-			"CX"  => Ok(Self::CX),
-			"CY"  => Ok(Self::CY),
-			"CZ"  => Ok(Self::CZ),
-			"DE"  => Ok(Self::DE),
-			"DJ"  => Ok(Self::DJ),
-			"DK"  => Ok(Self::DK),
-			"DM"  => Ok(Self::DM),
-			"DO"  => Ok(Self::DO),
-			"DZ"  => Ok(Self::DZ),
-			"EC"  => Ok(Self::EC),
-			"EE"  => Ok(Self::EE),
-			"EG"  => Ok(Self::EG),
-			"EH"  => Ok(Self::EH),
-			"ER"  => Ok(Self::ER),
-			"ES"  => Ok(Self::ES),
-			"ET"  => Ok(Self::ET),
-			"FI"  => Ok(Self::FI),
-			"FJ"  => Ok(Self::FJ),
-			"FK"  => Ok(Self::FK),
-			"FM"  => Ok(Self::FM),
-			"FO"  => Ok(Self::FO),
-			"FR"  => Ok(Self::FR),
-			"GA"  => Ok(Self::GA),
-			"GB"  => Ok(Self::GB),
-			"GD"  => Ok(Self::GD),
-			"GE"  => Ok(Self::GE),
-			"GF"  => Ok(Self::GF),
-			"GG"  => Ok(Self::GG),
-			"GH"  => Ok(Self::GH),
-			"GI"  => Ok(Self::GI),
-			"GL"  => Ok(Self::GL),
-			"GM"  => Ok(Self::GM),
-			"GN"  => Ok(Self::GN),
-			"GP"  => Ok(Self::GP),
-			"GQ"  => Ok(Self::GQ),
-			"GR"  => Ok(Self::GR),
-			"GS"  => Ok(Self::GS),
-			"GT"  => Ok(Self::GT),
-			"GU"  => Ok(Self::GU),
-			"GW"  => Ok(Self::GW),
-			"GY"  => Ok(Self::GY),
-			"HK"  => Ok(Self::HK),
-			"HM"  => Ok(Self::HM),
-			"HN"  => Ok(Self::HN),
-			"HR"  => Ok(Self::HR),
-			"HT"  => Ok(Self::HT),
-			"HU"  => Ok(Self::HU),
-			"ID"  => Ok(Self::ID),
-			"IE"  => Ok(Self::IE),
-			"IL"  => Ok(Self::IL),
-			"IM"  => Ok(Self::IM),
-			"IN"  => Ok(Self::IN),
-			"IO"  => Ok(Self::IO),
-			"IQ"  => Ok(Self::IQ),
-			"IR"  => Ok(Self::IR),
-			"IS"  => Ok(Self::IS),
-			"IT"  => Ok(Self::IT),
-			"JE"  => Ok(Self::JE),
-			"JM"  => Ok(Self::JM),
-			"JO"  => Ok(Self::JO),
-			"JP"  => Ok(Self::JP),
-			"KE"  => Ok(Self::KE),
-			"KG"  => Ok(Self::KG),
-			"KH"  => Ok(Self::KH),
-			"KI"  => Ok(Self::KI),
-			"KM"  => Ok(Self::KM),
-			"KN"  => Ok(Self::KN),
-			"KP"  => Ok(Self::KP),
-			"KR"  => Ok(Self::KR),
-			"KW"  => Ok(Self::KW),
-			"KY"  => Ok(Self::KY),
-			"KZ"  => Ok(Self::KZ),
-			"LA"  => Ok(Self::LA),
-			"LB"  => Ok(Self::LB),
-			"LC"  => Ok(Self::LC),
-			"LI"  => Ok(Self::LI),
-			"LK"  => Ok(Self::LK),
-			"LR"  => Ok(Self::LR),
-			"LS"  => Ok(Self::LS),
-			"LT"  => Ok(Self::LT),
-			"LU"  => Ok(Self::LU),
-			"LV"  => Ok(Self::LV),
-			"LY"  => Ok(Self::LY),
-			"MA"  => Ok(Self::MA),
-			"MC"  => Ok(Self::MC),
-			"MD"  => Ok(Self::MD),
-			"ME"  => Ok(Self::ME),
-			"MF"  => Ok(Self::MF),
-			"MG"  => Ok(Self::MG),
-			"MH"  => Ok(Self::MH),
-			"MK"  => Ok(Self::MK),
-			"ML"  => Ok(Self::ML),
-			"MM"  => Ok(Self::MM),
-			"MN"  => Ok(Self::MN),
-			"MO"  => Ok(Self::MO),
-			"MP"  => Ok(Self::MP),
-			"MQ"  => Ok(Self::MQ),
-			"MR"  => Ok(Self::MR),
-			"MS"  => Ok(Self::MS),
-			"MT"  => Ok(Self::MT),
-			"MU"  => Ok(Self::MU),
-			"MV"  => Ok(Self::MV),
-			"MW"  => Ok(Self::MW),
-			"MX"  => Ok(Self::MX),
-			"MY"  => Ok(Self::MY),
-			"MZ"  => Ok(Self::MZ),
-			"NA"  => Ok(Self::NA),
-			"NC"  => Ok(Self::NC),
-			"NE"  => Ok(Self::NE),
-			"NF"  => Ok(Self::NF),
-			"NG"  => Ok(Self::NG),
-			"NI"  => Ok(Self::NI),
-			"NL"  => Ok(Self::NL),
-			"NO"  => Ok(Self::NO),
-			"NP"  => Ok(Self::NP),
-			"NR"  => Ok(Self::NR),
-			"NU"  => Ok(Self::NU),
-			"NZ"  => Ok(Self::NZ),
-			"OM"  => Ok(Self::OM),
-			"PA"  => Ok(Self::PA),
-			"PE"  => Ok(Self::PE),
-			"PF"  => Ok(Self::PF),
-			"PG"  => Ok(Self::PG),
-			"PH"  => Ok(Self::PH),
-			"PK"  => Ok(Self::PK),
-			"PL"  => Ok(Self::PL),
-			"PM"  => Ok(Self::PM),
-			"PN"  => Ok(Self::PN),
-			"PR"  => Ok(Self::PR),
-			"PS"  => Ok(Self::PS),
-			"PT"  => Ok(Self::PT),
-			"PW"  => Ok(Self::PW),
-			"PY"  => Ok(Self::PY),
-			"QA"  => Ok(Self::QA),
-			"RE"  => Ok(Self::RE),
-			"RO"  => Ok(Self::RO),
-			"RS"  => Ok(Self::RS),
-			"RU"  => Ok(Self::RU),
-			"RW"  => Ok(Self::RW),
-			"SA"  => Ok(Self::SA),
-			"SB"  => Ok(Self::SB),
-			"SC"  => Ok(Self::SC),
-			"SD"  => Ok(Self::SD),
-			"SE"  => Ok(Self::SE),
-			"SG"  => Ok(Self::SG),
-			"SH"  => Ok(Self::SH),
-			"SI"  => Ok(Self::SI),
-			"SJ"  => Ok(Self::SJ),
-			"SK"  => Ok(Self::SK),
-			"SL"  => Ok(Self::SL),
-			"SM"  => Ok(Self::SM),
-			"SN"  => Ok(Self::SN),
-			"SO"  => Ok(Self::SO),
-			"SR"  => Ok(Self::SR),
-			"SS"  => Ok(Self::SS),
-			"ST"  => Ok(Self::ST),
-			"SV"  => Ok(Self::SV),
-			"SX"  => Ok(Self::SX),
-			"SY"  => Ok(Self::SY),
-			"SZ"  => Ok(Self::SZ),
-			"TC"  => Ok(Self::TC),
-			"TD"  => Ok(Self::TD),
-			"TF"  => Ok(Self::TF),
-			"TG"  => Ok(Self::TG),
-			"TH"  => Ok(Self::TH),
-			"TJ"  => Ok(Self::TJ),
-			"TK"  => Ok(Self::TK),
-			"TL"  => Ok(Self::TL),
-			"TM"  => Ok(Self::TM),
-			"TN"  => Ok(Self::TN),
-			"TO"  => Ok(Self::TO),
-			"TR"  => Ok(Self::TR),
-			"TT"  => Ok(Self::TT),
-			"TV"  => Ok(Self::TV),
-			"TW"  => Ok(Self::TW),
-			"TZ"  => Ok(Self::TZ),
-			"UA"  => Ok(Self::UA),
-			"UG"  => Ok(Self::UG),
-			"UM"  => Ok(Self::UM),
-			"US"  => Ok(Self::US),
-			"UY"  => Ok(Self::UY),
-			"UZ"  => Ok(Self::UZ),
-			"VA"  => Ok(Self::VA),
-			"VC"  => Ok(Self::VC),
-			"VE"  => Ok(Self::VE),
-			"VG"  => Ok(Self::VG),
-			"VI"  => Ok(Self::VI),
-			"VN"  => Ok(Self::VN),
-			"VU"  => Ok(Self::VU),
-			"WF"  => Ok(Self::WF),
-			"WS"  => Ok(Self::WS),
-			"YE"  => Ok(Self::YE),
-			"YT"  => Ok(Self::YT),
-			"ZA"  => Ok(Self::ZA),
-			"ZM"  => Ok(Self::ZM),
-			"ZW"  => Ok(Self::ZW),
-			//		Three-letter codes (ISO 3166-1 alpha-3)						
-			"ABW" => Ok(Self::ABW),
-			"AFG" => Ok(Self::AFG),
-			"AGO" => Ok(Self::AGO),
-			"AIA" => Ok(Self::AIA),
-			"ALA" => Ok(Self::ALA),
-			"ALB" => Ok(Self::ALB),
-			"AND" => Ok(Self::AND),
-			"ARE" => Ok(Self::ARE),
-			"ARG" => Ok(Self::ARG),
-			"ARM" => Ok(Self::ARM),
-			"ASM" => Ok(Self::ASM),
-			"ATA" => Ok(Self::ATA),
-			"ATF" => Ok(Self::ATF),
-			"ATG" => Ok(Self::ATG),
-			"AUS" => Ok(Self::AUS),
-			"AUT" => Ok(Self::AUT),
-			"AZE" => Ok(Self::AZE),
-			"BDI" => Ok(Self::BDI),
-			"BEL" => Ok(Self::BEL),
-			"BEN" => Ok(Self::BEN),
-			"BES" => Ok(Self::BES),
-			"BFA" => Ok(Self::BFA),
-			"BGD" => Ok(Self::BGD),
-			"BGR" => Ok(Self::BGR),
-			"BHR" => Ok(Self::BHR),
-			"BHS" => Ok(Self::BHS),
-			"BIH" => Ok(Self::BIH),
-			"BLM" => Ok(Self::BLM),
-			"BLR" => Ok(Self::BLR),
-			"BLZ" => Ok(Self::BLZ),
-			"BMU" => Ok(Self::BMU),
-			"BOL" => Ok(Self::BOL),
-			"BRA" => Ok(Self::BRA),
-			"BRB" => Ok(Self::BRB),
-			"BRN" => Ok(Self::BRN),
-			"BTN" => Ok(Self::BTN),
-			"BVT" => Ok(Self::BVT),
-			"BWA" => Ok(Self::BWA),
-			"CAF" => Ok(Self::CAF),
-			"CAN" => Ok(Self::CAN),
-			"CCK" => Ok(Self::CCK),
-			"CHE" => Ok(Self::CHE),
-			"CHL" => Ok(Self::CHL),
-			"CHN" => Ok(Self::CHN),
-			"CIV" => Ok(Self::CIV),
-			"CMR" => Ok(Self::CMR),
-			"COD" => Ok(Self::COD),
-			"COG" => Ok(Self::COG),
-			"COK" => Ok(Self::COK),
-			"COL" => Ok(Self::COL),
-			"COM" => Ok(Self::COM),
-			"CPV" => Ok(Self::CPV),
-			"CRI" => Ok(Self::CRI),
-			"CUB" => Ok(Self::CUB),
-			"CUW" => Ok(Self::CUW),
-			"CXR" => Ok(Self::CXR),
-			"CYM" => Ok(Self::CYM),
-			"CYP" => Ok(Self::CYP),
-			"CZE" => Ok(Self::CZE),
-			"DEU" => Ok(Self::DEU),
-			"DJI" => Ok(Self::DJI),
-			"DMA" => Ok(Self::DMA),
-			"DNK" => Ok(Self::DNK),
-			"DOM" => Ok(Self::DOM),
-			"DZA" => Ok(Self::DZA),
-			"ECU" => Ok(Self::ECU),
-			"EGY" => Ok(Self::EGY),
-			"ERI" => Ok(Self::ERI),
-			"ESH" => Ok(Self::ESH),
-			"ESP" => Ok(Self::ESP),
-			"EST" => Ok(Self::EST),
-			"ETH" => Ok(Self::ETH),
-			"FIN" => Ok(Self::FIN),
-			"FJI" => Ok(Self::FJI),
-			"FLK" => Ok(Self::FLK),
-			"FRA" => Ok(Self::FRA),
-			"FRO" => Ok(Self::FRO),
-			"FSM" => Ok(Self::FSM),
-			"GAB" => Ok(Self::GAB),
-			"GBR" => Ok(Self::GBR),
-			"GEO" => Ok(Self::GEO),
-			"GGY" => Ok(Self::GGY),
-			"GHA" => Ok(Self::GHA),
-			"GIB" => Ok(Self::GIB),
-			"GIN" => Ok(Self::GIN),
-			"GLP" => Ok(Self::GLP),
-			"GMB" => Ok(Self::GMB),
-			"GNB" => Ok(Self::GNB),
-			"GNQ" => Ok(Self::GNQ),
-			"GRC" => Ok(Self::GRC),
-			"GRD" => Ok(Self::GRD),
-			"GRL" => Ok(Self::GRL),
-			"GTM" => Ok(Self::GTM),
-			"GUF" => Ok(Self::GUF),
-			"GUM" => Ok(Self::GUM),
-			"GUY" => Ok(Self::GUY),
-			"HKG" => Ok(Self::HKG),
-			"HMD" => Ok(Self::HMD),
-			"HND" => Ok(Self::HND),
-			"HRV" => Ok(Self::HRV),
-			"HTI" => Ok(Self::HTI),
-			"HUN" => Ok(Self::HUN),
-			"IDN" => Ok(Self::IDN),
-			"IMN" => Ok(Self::IMN),
-			"IND" => Ok(Self::IND),
-			"IOT" => Ok(Self::IOT),
-			"IRL" => Ok(Self::IRL),
-			"IRN" => Ok(Self::IRN),
-			"IRQ" => Ok(Self::IRQ),
-			"ISL" => Ok(Self::ISL),
-			"ISR" => Ok(Self::ISR),
-			"ITA" => Ok(Self::ITA),
-			"JAM" => Ok(Self::JAM),
-			"JEY" => Ok(Self::JEY),
-			"JOR" => Ok(Self::JOR),
-			"JPN" => Ok(Self::JPN),
-			"KAZ" => Ok(Self::KAZ),
-			"KEN" => Ok(Self::KEN),
-			"KGZ" => Ok(Self::KGZ),
-			"KHM" => Ok(Self::KHM),
-			"KIR" => Ok(Self::KIR),
-			"KNA" => Ok(Self::KNA),
-			"KOR" => Ok(Self::KOR),
-			"KWT" => Ok(Self::KWT),
-			"LAO" => Ok(Self::LAO),
-			"LBN" => Ok(Self::LBN),
-			"LBR" => Ok(Self::LBR),
-			"LBY" => Ok(Self::LBY),
-			"LCA" => Ok(Self::LCA),
-			"LIE" => Ok(Self::LIE),
-			"LKA" => Ok(Self::LKA),
-			"LSO" => Ok(Self::LSO),
-			"LTU" => Ok(Self::LTU),
-			"LUX" => Ok(Self::LUX),
-			"LVA" => Ok(Self::LVA),
-			"MAC" => Ok(Self::MAC),
-			"MAF" => Ok(Self::MAF),
-			"MAR" => Ok(Self::MAR),
-			"MCO" => Ok(Self::MCO),
-			"MDA" => Ok(Self::MDA),
-			"MDG" => Ok(Self::MDG),
-			"MDV" => Ok(Self::MDV),
-			"MEX" => Ok(Self::MEX),
-			"MHL" => Ok(Self::MHL),
-			"MKD" => Ok(Self::MKD),
-			"MLI" => Ok(Self::MLI),
-			"MLT" => Ok(Self::MLT),
-			"MMR" => Ok(Self::MMR),
-			"MNE" => Ok(Self::MNE),
-			"MNG" => Ok(Self::MNG),
-			"MNP" => Ok(Self::MNP),
-			"MOZ" => Ok(Self::MOZ),
-			"MRT" => Ok(Self::MRT),
-			"MSR" => Ok(Self::MSR),
-			"MTQ" => Ok(Self::MTQ),
-			"MUS" => Ok(Self::MUS),
-			"MWI" => Ok(Self::MWI),
-			"MYS" => Ok(Self::MYS),
-			"MYT" => Ok(Self::MYT),
-			"NAM" => Ok(Self::NAM),
-			"NCL" => Ok(Self::NCL),
-			"NER" => Ok(Self::NER),
-			"NFK" => Ok(Self::NFK),
-			"NGA" => Ok(Self::NGA),
-			"NIC" => Ok(Self::NIC),
-			"NIU" => Ok(Self::NIU),
-			"NLD" => Ok(Self::NLD),
-			"NOR" => Ok(Self::NOR),
-			"NPL" => Ok(Self::NPL),
-			"NRU" => Ok(Self::NRU),
-			"NZL" => Ok(Self::NZL),
-			"OMN" => Ok(Self::OMN),
-			"PAK" => Ok(Self::PAK),
-			"PAN" => Ok(Self::PAN),
-			"PCN" => Ok(Self::PCN),
-			"PER" => Ok(Self::PER),
-			"PHL" => Ok(Self::PHL),
-			"PLW" => Ok(Self::PLW),
-			"PNG" => Ok(Self::PNG),
-			"POL" => Ok(Self::POL),
-			"PRI" => Ok(Self::PRI),
-			"PRK" => Ok(Self::PRK),
-			"PRT" => Ok(Self::PRT),
-			"PRY" => Ok(Self::PRY),
-			"PSE" => Ok(Self::PSE),
-			"PYF" => Ok(Self::PYF),
-			"QAT" => Ok(Self::QAT),
-			"REU" => Ok(Self::REU),
-			"ROU" => Ok(Self::ROU),
-			"RUS" => Ok(Self::RUS),
-			"RWA" => Ok(Self::RWA),
-			"SAU" => Ok(Self::SAU),
-			"SDN" => Ok(Self::SDN),
-			"SEN" => Ok(Self::SEN),
-			"SGP" => Ok(Self::SGP),
-			"SGS" => Ok(Self::SGS),
-			"SHN" => Ok(Self::SHN),
-			"SJM" => Ok(Self::SJM),
-			"SLB" => Ok(Self::SLB),
-			"SLE" => Ok(Self::SLE),
-			"SLV" => Ok(Self::SLV),
-			"SMR" => Ok(Self::SMR),
-			"SOM" => Ok(Self::SOM),
-			"SPM" => Ok(Self::SPM),
-			"SRB" => Ok(Self::SRB),
-			"SSD" => Ok(Self::SSD),
-			"STP" => Ok(Self::STP),
-			"SUR" => Ok(Self::SUR),
-			"SVK" => Ok(Self::SVK),
-			"SVN" => Ok(Self::SVN),
-			"SWE" => Ok(Self::SWE),
-			"SWZ" => Ok(Self::SWZ),
-			"SXM" => Ok(Self::SXM),
-			"SYC" => Ok(Self::SYC),
-			"SYR" => Ok(Self::SYR),
-			"TCA" => Ok(Self::TCA),
-			"TCD" => Ok(Self::TCD),
-			"TGO" => Ok(Self::TGO),
-			"THA" => Ok(Self::THA),
-			"TJK" => Ok(Self::TJK),
-			"TKL" => Ok(Self::TKL),
-			"TKM" => Ok(Self::TKM),
-			"TLS" => Ok(Self::TLS),
-			"TON" => Ok(Self::TON),
-			"TTO" => Ok(Self::TTO),
-			"TUN" => Ok(Self::TUN),
-			"TUR" => Ok(Self::TUR),
-			"TUV" => Ok(Self::TUV),
-			"TWN" => Ok(Self::TWN),
-			"TZA" => Ok(Self::TZA),
-			"UGA" => Ok(Self::UGA),
-			"UKR" => Ok(Self::UKR),
-			"UMI" => Ok(Self::UMI),
-			"URY" => Ok(Self::URY),
-			"USA" => Ok(Self::USA),
-			"UZB" => Ok(Self::UZB),
-			"VAT" => Ok(Self::VAT),
-			"VCT" => Ok(Self::VCT),
-			"VEN" => Ok(Self::VEN),
-			"VGB" => Ok(Self::VGB),
-			"VIR" => Ok(Self::VIR),
-			"VNM" => Ok(Self::VNM),
-			"VUT" => Ok(Self::VUT),
-			"WLF" => Ok(Self::WLF),
-			"WSM" => Ok(Self::WSM),
-			"YEM" => Ok(Self::YEM),
-			"ZAF" => Ok(Self::ZAF),
-			"ZMB" => Ok(Self::ZMB),
-			"ZWE" => Ok(Self::ZWE),
-			//		Invalid														
-			_     => Err(format!("Invalid CountryCode: {s}")),
+		Self::from_code(s).ok_or_else(|| format!("Invalid CountryCode: {s}"))
+	}
+}
+
+//󰭅		Ord																		
+impl Ord for CountryCode {
+	//		cmp																	
+	/// Compares two codes alphabetically, e.g. `EUR` before `GBP`.
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_str().cmp(other.as_str())
+	}
+}
+
+//󰭅		PartialOrd																
+impl PartialOrd for CountryCode {
+	//		partial_cmp															
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+//󰭅		Serialize																
+impl Serialize for CountryCode {
+	//		serialize															
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if serializer.is_human_readable() {
+			serializer.serialize_str(self.as_str())
+		} else {
+			serializer.serialize_u16(self.numeric())
 		}
 	}
 }
@@ -4916,13 +7077,543 @@ impl TryFrom<u16> for CountryCode {
 	}
 }
 
-//󰭅		TryFrom<String>															
-impl TryFrom<String> for CountryCode {
-	type Error = String;
+//󰭅		TryFrom<String>															
+impl TryFrom<String> for CountryCode {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.as_str().parse()
+	}
+}
+
+//		CodeStatus																
+/// The status of a code in ISO 3166-1.
+/// 
+/// # See also
+/// 
+/// * [`CountryCode::status()`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[non_exhaustive]
+pub enum CodeStatus {
+	/// A code assigned to a country or territory, e.g. `GB`.
+	OfficiallyAssigned,
+	
+	/// A code reserved at the request of a national or international body for
+	/// a particular use, e.g. `EU` for the European Union.
+	ExceptionallyReserved,
+	
+	/// A code that has been deleted, and is reserved for a transitional period
+	/// during which it should no longer be used, e.g. `AN` for the Netherlands
+	/// Antilles.
+	TransitionallyReserved,
+	
+	/// A code used in another coding system, such as for international vehicle
+	/// registration, which is reserved to avoid conflicts, e.g. `RL` for
+	/// Lebanon.
+	IndeterminatelyReserved,
+	
+	/// A code that users can assign for their own purposes, e.g. `XK` for
+	/// Kosovo, or `ZZ` for an unknown country.
+	UserAssigned,
+}
+
+//󰭅		AsStr																	
+impl AsStr for CodeStatus {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::OfficiallyAssigned      => "Officially assigned",
+			Self::ExceptionallyReserved   => "Exceptionally reserved",
+			Self::TransitionallyReserved  => "Transitionally reserved",
+			Self::IndeterminatelyReserved => "Indeterminately reserved",
+			Self::UserAssigned            => "User-assigned",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for CodeStatus {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//		DataGap																	
+/// A gap in the data for a country.
+/// 
+/// # See also
+/// 
+/// * [`Country::data_gaps()`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[non_exhaustive]
+pub enum DataGap {
+	/// The country has no currencies, as ISO 4217 lists no universal currency
+	/// for it.
+	Currencies,
+	
+	/// The country has no languages.
+	Languages,
+}
+
+//󰭅		AsStr																	
+impl AsStr for DataGap {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Currencies => "Currencies",
+			Self::Languages  => "Languages",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for DataGap {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//		DrivingSide																
+/// The side of the road that traffic drives on.
+/// 
+/// # See also
+/// 
+/// * [`Country::driving_side()`]
+/// 
+#[expect(clippy::exhaustive_enums, reason = "There are only two sides of the road")]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+pub enum DrivingSide {
+	/// Traffic drives on the left.
+	Left,
+	
+	/// Traffic drives on the right.
+	Right,
+}
+
+//󰭅		AsStr																	
+impl AsStr for DrivingSide {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Left  => "Left",
+			Self::Right => "Right",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for DrivingSide {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//		Jurisdiction															
+/// A country, or the European Union, as a jurisdiction.
+/// 
+/// Trademark and other intellectual property data identify the jurisdiction
+/// of a registration by its country code, or by `EM` or `EU` for the European
+/// Union, which do not identify a country. When parsing with the
+/// [`quirks`](ParseOptions::quirks()) option, those codes are resolved to
+/// [`Jurisdiction::EuropeanUnion`], and everything else is parsed in the same
+/// way as a [`Country`].
+/// 
+/// # See also
+/// 
+/// * [`Jurisdiction::parse_with()`]
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Jurisdiction {
+	/// A country.
+	Country(Country),
+	
+	/// The European Union (`EM` or `EU`).
+	EuropeanUnion,
+}
+
+//󰭅		Jurisdiction															
+impl Jurisdiction {
+	//		parse_with															
+	/// Parses a jurisdiction from text, according to a set of options.
+	/// 
+	/// The codes for the European Union are only accepted with the
+	/// [`quirks`](ParseOptions::quirks()) option. For more information, see
+	/// [`ParseOptions`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `s`       - The text to parse.
+	/// * `options` - The options to parse with.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the text does not identify a jurisdiction with the
+	/// options given.
+	/// 
+	pub fn parse_with(s: &str, options: &ParseOptions) -> Result<Self, String> {
+		options.parse(s).ok_or_else(|| format!("Invalid Jurisdiction: {s}"))
+	}
+	
+	//		country																
+	/// Returns the country, if the jurisdiction is a country.
+	#[must_use]
+	pub const fn country(&self) -> Option<Country> {
+		match *self {
+			Self::Country(country) => Some(country),
+			Self::EuropeanUnion    => None,
+		}
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for Jurisdiction {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Country(ref country) => country.as_str(),
+			Self::EuropeanUnion        => "European Union",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for Jurisdiction {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//󰭅		From<Country>															
+impl From<Country> for Jurisdiction {
+	//		from																
+	fn from(country: Country) -> Self {
+		Self::Country(country)
+	}
+}
+
+//󰭅		Parse																	
+impl Parse for Jurisdiction {
+	//		from_code															
+	fn from_code(code: &str, case_insensitive: bool) -> Option<Self> {
+		Country::from_code(code, case_insensitive).map(Self::Country)
+	}
 	
-	//		try_from															
-	fn try_from(value: String) -> Result<Self, Self::Error> {
-		value.as_str().parse()
+	//		from_quirk															
+	fn from_quirk(code: &str, case_insensitive: bool) -> Option<Self> {
+		["EM", "EU"].iter()
+			.any(|key| if case_insensitive { key.eq_ignore_ascii_case(code) } else { *key == code })
+			.then_some(Self::EuropeanUnion)
+			.or_else(|| Country::from_quirk(code, case_insensitive).map(Self::Country))
+	}
+	
+	//		from_mrz															
+	fn from_mrz(code: &str, case_insensitive: bool) -> Option<Self> {
+		Country::from_mrz(code, case_insensitive).map(Self::Country)
+	}
+	
+	//		from_numeric														
+	fn from_numeric(numeric: u16) -> Option<Self> {
+		Country::from_numeric(numeric).map(Self::Country)
+	}
+	
+	//		from_name															
+	fn from_name(name: &str) -> Option<Self> {
+		Country::from_name(name).map(Self::Country)
+	}
+}
+
+//		LanguageStatus															
+/// The status of a language in a country.
+/// 
+/// # See also
+/// 
+/// * [`Country::languages_with_status()`]
+/// * [`Country::languages_by_status()`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[non_exhaustive]
+pub enum LanguageStatus {
+	/// An official language of the country as a whole.
+	Official,
+	
+	/// A national language, which is recognised as a language of the country,
+	/// but is not necessarily used officially throughout it.
+	National,
+	
+	/// A regional language, which is official or recognised in part of the
+	/// country.
+	Regional,
+	
+	/// A minority language, which is recognised and protected, but is not
+	/// official.
+	Minority,
+	
+	/// A language without any formal status, but which is widely spoken.
+	WidelySpoken,
+}
+
+//󰭅		AsStr																	
+impl AsStr for LanguageStatus {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Official     => "Official",
+			Self::National     => "National",
+			Self::Regional     => "Regional",
+			Self::Minority     => "Minority",
+			Self::WidelySpoken => "Widely spoken",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for LanguageStatus {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//		MeasurementSystem														
+/// A system of measurement.
+/// 
+/// # See also
+/// 
+/// * [`Country::measurement_system()`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[non_exhaustive]
+pub enum MeasurementSystem {
+	/// The metric system, i.e. the International System of Units (SI).
+	Metric,
+	
+	/// The British imperial system.
+	Imperial,
+	
+	/// The United States customary system.
+	Us,
+}
+
+//󰭅		AsStr																	
+impl AsStr for MeasurementSystem {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Metric   => "Metric",
+			Self::Imperial => "Imperial",
+			Self::Us       => "US",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for MeasurementSystem {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//		Region																	
+/// A geographical region.
+/// 
+/// The regions are the continental regions defined by the United Nations in
+/// the M49 standard, which groups countries into Africa, the Americas, Asia,
+/// Europe, and Oceania. Antarctica is not part of any of these, and so has its
+/// own region.
+/// 
+/// Every country is in exactly one region, which makes the regions suitable for
+/// handling countries by category, as a match on the region of a country does
+/// not need to change when countries are added or removed.
+/// 
+/// # Data sources
+/// 
+/// The regions are available from
+/// [the United Nations Statistics Division](https://unstats.un.org/unsd/methodology/m49/).
+/// 
+/// # See also
+/// 
+/// * [`Country::region()`]
+/// * [`Country::is_in_region()`]
+/// 
+#[expect(clippy::exhaustive_enums, reason = "The continental regions are fixed")]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+pub enum Region {
+	/// Africa.
+	Africa,
+	
+	/// North, Central, and South America, and the Caribbean.
+	Americas,
+	
+	/// Antarctica.
+	Antarctica,
+	
+	/// Asia, including the Middle East.
+	Asia,
+	
+	/// Europe.
+	Europe,
+	
+	/// Australia, New Zealand, and the Pacific islands.
+	Oceania,
+}
+
+//󰭅		Region																	
+impl Region {
+	//		all																	
+	/// Returns all the regions.
+	#[must_use]
+	pub fn all() -> Vec<Self> {
+		vec![ Self::Africa, Self::Americas, Self::Antarctica, Self::Asia, Self::Europe, Self::Oceania ]
+	}
+	
+	//		countries															
+	/// Returns the countries in the region.
+	#[must_use]
+	pub fn countries(&self) -> Vec<Country> {
+		Country::all().into_iter().filter(|country| country.region() == *self).collect()
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for Region {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Africa     => "Africa",
+			Self::Americas   => "Americas",
+			Self::Antarctica => "Antarctica",
+			Self::Asia       => "Asia",
+			Self::Europe     => "Europe",
+			Self::Oceania    => "Oceania",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for Region {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//		UnStatus																
+/// The status of a country in the United Nations.
+/// 
+/// # See also
+/// 
+/// * [`Country::un_status()`]
+/// * [`Country::un_member()`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[non_exhaustive]
+pub enum UnStatus {
+	/// A member state of the United Nations.
+	Member,
+	
+	/// A non-member observer state, which participates in the General Assembly
+	/// but cannot vote.
+	Observer,
+	
+	/// Neither a member nor an observer state, e.g. a territory, or a state
+	/// with limited recognition.
+	NonMember,
+}
+
+//󰭅		AsStr																	
+impl AsStr for UnStatus {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Member    => "Member",
+			Self::Observer  => "Observer",
+			Self::NonMember => "Non-member",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for UnStatus {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//		Weekday																	
+/// A day of the week.
+/// 
+/// This is used to express the calendar conventions of a country, i.e. the
+/// first day of the week and the days of the weekend.
+/// 
+/// # See also
+/// 
+/// * [`Country::first_day_of_week()`]
+/// * [`Country::weekend_days()`]
+/// 
+#[expect(clippy::exhaustive_enums, reason = "There are always seven days in a week")]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+pub enum Weekday {
+	/// Monday.
+	Monday,
+	
+	/// Tuesday.
+	Tuesday,
+	
+	/// Wednesday.
+	Wednesday,
+	
+	/// Thursday.
+	Thursday,
+	
+	/// Friday.
+	Friday,
+	
+	/// Saturday.
+	Saturday,
+	
+	/// Sunday.
+	Sunday,
+}
+
+//󰭅		AsStr																	
+impl AsStr for Weekday {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Monday    => "Monday",
+			Self::Tuesday   => "Tuesday",
+			Self::Wednesday => "Wednesday",
+			Self::Thursday  => "Thursday",
+			Self::Friday    => "Friday",
+			Self::Saturday  => "Saturday",
+			Self::Sunday    => "Sunday",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for Weekday {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
 	}
 }
 
@@ -4930,6 +7621,109 @@ impl TryFrom<String> for CountryCode {
 
 //		Structs
 
+//		CountryRecord															
+/// A read-only view of all the information about a country.
+/// 
+/// This is returned by [`Country::record()`] and [`Country::records()`], and
+/// allows all of the information about a country to be obtained in one go,
+/// rather than by calling each of the individual methods.
+/// 
+/// The name is the display name, and so reflects any override made by an
+/// installed [`Registry`](crate::registry::Registry).
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct CountryRecord {
+	//		Public properties													
+	/// The country.
+	pub country:      Country,
+	
+	/// The two-letter country code.
+	pub code:         CountryCode,
+	
+	/// The three-letter country code.
+	pub alpha3:       CountryCode,
+	
+	/// The three-digit numeric country code.
+	pub numeric:      u16,
+	
+	/// The name of the country.
+	pub name:         &'static str,
+	
+	/// The full official name of the country, if it has one.
+	pub full_name:    Option<&'static str>,
+	
+	/// The international vehicle registration code, if one is assigned.
+	pub vehicle_code: Option<&'static str>,
+	
+	/// The first day of the week, according to local convention.
+	pub first_day:    Weekday,
+	
+	/// The days of the weekend, according to local convention.
+	pub weekend:      &'static [Weekday],
+	
+	/// The date of the national day, if there is one.
+	pub national_day: Option<MonthDay>,
+	
+	/// The formats of the postal codes used in the country, if any.
+	pub postal_codes: &'static [&'static str],
+	
+	/// The side of the road that traffic drives on.
+	pub driving_side: DrivingSide,
+	
+	/// The customary system of measurement.
+	pub measurement:  MeasurementSystem,
+	
+	/// Whether daylight saving time is observed in any part of the country.
+	pub observes_dst: bool,
+	
+	/// The geographical region that the country is in.
+	pub region:       Region,
+	
+	/// Whether the country is an independent sovereign state.
+	pub independent:  bool,
+	
+	/// The status of the country in the United Nations.
+	pub un_status:    UnStatus,
+	
+	/// The sovereign state of the territory, if any.
+	pub sovereign:    Option<Country>,
+	
+	/// The currencies used in the country.
+	pub currencies:   &'static [CurrencyCode],
+	
+	/// The languages used in the country.
+	pub languages:    &'static [LanguageCode],
+}
+
+//		CountrySummary															
+/// A compact summary of a country.
+/// 
+/// This is returned by [`Country::summary()`], and holds the codes and name of
+/// the country. It is intended for embedding in API responses, where it
+/// serialises as e.g. `{"code": "FR", "alpha3": "FRA", "numeric": 250,
+/// "name": "France"}`.
+/// 
+/// The name is the display name, and so reflects any override made by an
+/// installed [`Registry`](crate::registry::Registry).
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct CountrySummary {
+	//		Public properties													
+	/// The two-letter country code.
+	pub code:    CountryCode,
+	
+	/// The three-letter country code.
+	pub alpha3:  CountryCode,
+	
+	/// The three-digit numeric country code.
+	pub numeric: u16,
+	
+	/// The name of the country.
+	pub name:    &'static str,
+}
+
 //		CountryInfo																
 /// Country information.
 /// 
@@ -4953,17 +7747,70 @@ impl TryFrom<String> for CountryCode {
 #[non_exhaustive]
 struct CountryInfo {
 	//		Public properties													
-	/// The name of the country.
-	name:       String,
+	/// The short name of the country.
+	name:         &'static str,
+	
+	/// The full official name of the country, if it has one.
+	full_name:    Option<&'static str>,
 	
 	/// The country code. For more information, see [`CountryCode`].
-	code:       CountryCode,
+	code:         CountryCode,
+	
+	/// The international vehicle registration code, if one is assigned.
+	vehicle_code: Option<&'static str>,
+	
+	/// The first day of the week, according to local convention.
+	first_day:    Weekday,
+	
+	/// The days of the weekend, according to local convention.
+	weekend:      &'static [Weekday],
+	
+	/// The fixed date of the national day, as month and day, if there is one.
+	national_day: Option<(u8, u8)>,
+	
+	/// The formats of the postal codes used in the country, if any.
+	postal_codes: &'static [&'static str],
+	
+	/// The side of the road that traffic drives on.
+	driving_side: DrivingSide,
+	
+	/// The customary system of measurement.
+	measurement:  MeasurementSystem,
+	
+	/// Whether daylight saving time is observed in any part of the country.
+	observes_dst: bool,
+	
+	/// The geographical region that the country is in.
+	region:       Region,
+	
+	/// Whether the country is an independent sovereign state.
+	independent:  bool,
+	
+	/// The status of the country in the United Nations.
+	un_status:    UnStatus,
+	
+	/// The sovereign state of the territory, if any.
+	sovereign:    Option<CountryCode>,
 	
 	/// The currencies used in the country.
-	currencies: HashSet<CurrencyCode>,
+	currencies:   &'static [CurrencyCode],
 	
 	/// The languages used in the country.
-	languages:  HashSet<LanguageCode>,
+	languages:    &'static [LanguageCode],
 }
 
 
+
+//		Functions
+
+//		bare																	
+/// Normalises a country name for comparison.
+/// 
+/// The name is normalised using [`normalize()`], and any leading "the" is
+/// removed, as documents often drop it from full names, or add it to short
+/// names.
+/// 
+fn bare(name: &str) -> String {
+	let normalized = normalize(name);
+	normalized.strip_prefix("the ").unwrap_or(&normalized).to_owned()
+}
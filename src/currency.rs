@@ -24,22 +24,39 @@ mod tests;
 
 //		Packages
 
-use crate::country::CountryCode;
+use crate::{
+	AsStr,
+	country::CountryCode,
+	display::{DisplayStyle, PriceStyle, Styled},
+	language::LanguageCode,
+	map::{SortedMap, self},
+	parse::{Parse, ParseOptions},
+	registry,
+	text::{normalize, similarity, stable_hash},
+};
 use core::{
+	cmp::Ordering,
 	fmt::{Debug, Display, self},
 	str::FromStr,
 };
-use rubedo::{
-	std::AsStr,
-	sugar::{s, vh},
-};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 use std::{
-	collections::{HashMap, HashSet},
+	collections::HashSet,
 	sync::LazyLock,
 };
-use velcro::hash_map;
 
+#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+use crate::money::Money;
+#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+use core::iter;
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::{BigDecimal, RoundingMode};
+#[cfg(feature = "defmt")]
+use defmt::{Format, Formatter};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::{Decimal, RoundingStrategy};
+#[cfg(feature = "json")]
+use serde_json::Value;
 #[cfg(feature = "utoipa")]
 use utoipa::ToSchema;
 
@@ -47,8 +64,56 @@ use utoipa::ToSchema;
 
 //		Constants
 
+/// The number of currencies.
+/// 
+/// This is the length of [`Currency::all()`], and of the other lists of all the
+/// currencies, and can be used to pre-allocate storage, or to check the expected
+/// size of the dataset.
+/// 
+pub const COUNT: usize = 179;
+
+/// The ISO 4217 alphabetic codes of all the currencies.
+/// 
+/// The codes are in alphabetical order, and can be used directly, e.g. for
+/// validation or for populating drop-down lists, without needing to map over
+/// [`Currency::all()`] at runtime. The codes in [`NUMERIC_CODES`] are in the
+/// same order, so that the codes of each currency are at the same position in
+/// each.
+/// 
+pub const ALPHA3_CODES: &[&str] = &[
+	"AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT", "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BOV",
+	"BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD", "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUP", "CVE", "CZK",
+	"DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL",
+	"HTG", "HUF", "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD", "KZT",
+	"LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR",
+	"MZN", "NAD", "NGN", "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "RWF",
+	"SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SLL", "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND",
+	"TOP", "TRY", "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES", "VND", "VUV", "WST", "XAF", "XAG",
+	"XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XDR", "XOF", "XPD", "XPF", "XPT", "XSU", "XTS", "XUA", "XXX", "YER", "ZAR", "ZMW", "ZWL",
+];
+
+/// The ISO 4217 numeric codes of all the currencies.
+/// 
+/// The codes are in the same order as [`ALPHA3_CODES`], i.e. by alphabetic
+/// code.
+/// 
+pub const NUMERIC_CODES: &[u16] = &[
+	784, 971,   8,  51, 532, 973,  32,  36, 533, 944, 977,  52,  50, 975,  48, 108,  60,  96,  68, 984,
+	986,  44,  64,  72, 933,  84, 124, 976, 947, 756, 948, 990, 152, 156, 170, 970, 188, 192, 132, 203,
+	262, 208, 214,  12, 818, 232, 230, 978, 242, 238, 826, 981, 936, 292, 270, 324, 320, 328, 344, 340,
+	332, 348, 360, 376, 356, 368, 364, 352, 388, 400, 392, 404, 417, 116, 174, 408, 410, 414, 136, 398,
+	418, 422, 144, 430, 426, 434, 504, 498, 969, 807, 104, 496, 446, 929, 480, 462, 454, 484, 979, 458,
+	943, 516, 566, 558, 578, 524, 554, 512, 590, 604, 598, 608, 586, 985, 600, 634, 946, 941, 643, 646,
+	682,  90, 690, 938, 752, 702, 654, 925, 694, 706, 968, 728, 930, 222, 760, 748, 764, 972, 934, 788,
+	776, 949, 780, 901, 834, 980, 800, 840, 997, 940, 858, 927, 860, 926, 928, 704, 548, 882, 950, 961,
+	959, 955, 956, 957, 958, 951, 960, 952, 964, 953, 962, 994, 963, 965, 999, 886, 710, 967, 932,
+];
+
 /// The possible currencies.
 /// 
+/// The entries are in the order of the [`Currency`] discriminants, which is relied
+/// upon for lookups, so new entries must be added to the end.
+/// 
 /// # Data sources
 /// 
 /// The list of codes and other currency information is available from
@@ -60,190 +125,451 @@ use utoipa::ToSchema;
 /// * [`CurrencyCode`]
 /// * [`Currency`]
 /// 
-static CURRENCIES: LazyLock<HashMap<Currency, CurrencyInfo>> = LazyLock::new(|| {
-	hash_map!{
-		Currency::AED: CurrencyInfo { code: CurrencyCode::AED, name: s!("United Arab Emirates dirham"),                   digits: 2, countries: vh![ CountryCode: AE ] },
-		Currency::AFN: CurrencyInfo { code: CurrencyCode::AFN, name: s!("Afghan afghani"),                                digits: 2, countries: vh![ CountryCode: AF ] },
-		Currency::ALL: CurrencyInfo { code: CurrencyCode::ALL, name: s!("Albanian lek"),                                  digits: 2, countries: vh![ CountryCode: AL ] },
-		Currency::AMD: CurrencyInfo { code: CurrencyCode::AMD, name: s!("Armenian dram"),                                 digits: 2, countries: vh![ CountryCode: AM ] },
-		Currency::ANG: CurrencyInfo { code: CurrencyCode::ANG, name: s!("Netherlands Antillean guilder"),                 digits: 2, countries: vh![ CountryCode: CW, SX ] },
-		Currency::AOA: CurrencyInfo { code: CurrencyCode::AOA, name: s!("Angolan kwanza"),                                digits: 2, countries: vh![ CountryCode: AO ] },
-		Currency::ARS: CurrencyInfo { code: CurrencyCode::ARS, name: s!("Argentine peso"),                                digits: 2, countries: vh![ CountryCode: AR ] },
-		Currency::AUD: CurrencyInfo { code: CurrencyCode::AUD, name: s!("Australian dollar"),                             digits: 2, countries: vh![ CountryCode: AU, CC, CX, HM, KI, NF, NR, TV ] },
-		Currency::AWG: CurrencyInfo { code: CurrencyCode::AWG, name: s!("Aruban florin"),                                 digits: 2, countries: vh![ CountryCode: AW ] },
-		Currency::AZN: CurrencyInfo { code: CurrencyCode::AZN, name: s!("Azerbaijani manat"),                             digits: 2, countries: vh![ CountryCode: AZ ] },
-		Currency::BAM: CurrencyInfo { code: CurrencyCode::BAM, name: s!("Bosnia and Herzegovina convertible mark"),       digits: 2, countries: vh![ CountryCode: BA ] },
-		Currency::BBD: CurrencyInfo { code: CurrencyCode::BBD, name: s!("Barbados dollar"),                               digits: 2, countries: vh![ CountryCode: BB ] },
-		Currency::BDT: CurrencyInfo { code: CurrencyCode::BDT, name: s!("Bangladeshi taka"),                              digits: 2, countries: vh![ CountryCode: BD ] },
-		Currency::BGN: CurrencyInfo { code: CurrencyCode::BGN, name: s!("Bulgarian lev"),                                 digits: 2, countries: vh![ CountryCode: BG ] },
-		Currency::BHD: CurrencyInfo { code: CurrencyCode::BHD, name: s!("Bahraini dinar"),                                digits: 3, countries: vh![ CountryCode: BH ] },
-		Currency::BIF: CurrencyInfo { code: CurrencyCode::BIF, name: s!("Burundian franc"),                               digits: 0, countries: vh![ CountryCode: BI ] },
-		Currency::BMD: CurrencyInfo { code: CurrencyCode::BMD, name: s!("Bermudian dollar"),                              digits: 2, countries: vh![ CountryCode: BM ] },
-		Currency::BND: CurrencyInfo { code: CurrencyCode::BND, name: s!("Brunei dollar"),                                 digits: 2, countries: vh![ CountryCode: BN ] },
-		Currency::BOB: CurrencyInfo { code: CurrencyCode::BOB, name: s!("Boliviano"),                                     digits: 2, countries: vh![ CountryCode: BO ] },
-		Currency::BOV: CurrencyInfo { code: CurrencyCode::BOV, name: s!("Bolivian Mvdol"),                                digits: 2, countries: vh![ CountryCode: BO ] },
-		Currency::BRL: CurrencyInfo { code: CurrencyCode::BRL, name: s!("Brazilian real"),                                digits: 2, countries: vh![ CountryCode: BR ] },
-		Currency::BSD: CurrencyInfo { code: CurrencyCode::BSD, name: s!("Bahamian dollar"),                               digits: 2, countries: vh![ CountryCode: BS ] },
-		Currency::BTN: CurrencyInfo { code: CurrencyCode::BTN, name: s!("Bhutanese ngultrum"),                            digits: 2, countries: vh![ CountryCode: BT ] },
-		Currency::BWP: CurrencyInfo { code: CurrencyCode::BWP, name: s!("Botswana pula"),                                 digits: 2, countries: vh![ CountryCode: BW ] },
-		Currency::BYN: CurrencyInfo { code: CurrencyCode::BYN, name: s!("Belarusian ruble"),                              digits: 2, countries: vh![ CountryCode: BY ] },
-		Currency::BZD: CurrencyInfo { code: CurrencyCode::BZD, name: s!("Belize dollar"),                                 digits: 2, countries: vh![ CountryCode: BZ ] },
-		Currency::CAD: CurrencyInfo { code: CurrencyCode::CAD, name: s!("Canadian dollar"),                               digits: 2, countries: vh![ CountryCode: CA ] },
-		Currency::CDF: CurrencyInfo { code: CurrencyCode::CDF, name: s!("Congolese franc"),                               digits: 2, countries: vh![ CountryCode: CD ] },
-		Currency::CHE: CurrencyInfo { code: CurrencyCode::CHE, name: s!("WIR euro"),                                      digits: 2, countries: vh![ CountryCode: CH ] },
-		Currency::CHF: CurrencyInfo { code: CurrencyCode::CHF, name: s!("Swiss franc"),                                   digits: 2, countries: vh![ CountryCode: CH, LI ] },
-		Currency::CHW: CurrencyInfo { code: CurrencyCode::CHW, name: s!("WIR franc"),                                     digits: 2, countries: vh![ CountryCode: CH ] },
-		Currency::CLF: CurrencyInfo { code: CurrencyCode::CLF, name: s!("Unidad de Fomento"),                             digits: 4, countries: vh![ CountryCode: CL ] },
-		Currency::CLP: CurrencyInfo { code: CurrencyCode::CLP, name: s!("Chilean peso"),                                  digits: 0, countries: vh![ CountryCode: CL ] },
-		Currency::CNY: CurrencyInfo { code: CurrencyCode::CNY, name: s!("Renminbi"),                                      digits: 2, countries: vh![ CountryCode: CN ] },
-		Currency::COP: CurrencyInfo { code: CurrencyCode::COP, name: s!("Colombian peso"),                                digits: 2, countries: vh![ CountryCode: CO ] },
-		Currency::COU: CurrencyInfo { code: CurrencyCode::COU, name: s!("Unidad de Valor Real (UVR)"),                    digits: 2, countries: vh![ CountryCode: CO ] },
-		Currency::CRC: CurrencyInfo { code: CurrencyCode::CRC, name: s!("Costa Rican colon"),                             digits: 2, countries: vh![ CountryCode: CR ] },
-		Currency::CUP: CurrencyInfo { code: CurrencyCode::CUP, name: s!("Cuban peso"),                                    digits: 2, countries: vh![ CountryCode: CU ] },
-		Currency::CVE: CurrencyInfo { code: CurrencyCode::CVE, name: s!("Cape Verdean escudo"),                           digits: 2, countries: vh![ CountryCode: CV ] },
-		Currency::CZK: CurrencyInfo { code: CurrencyCode::CZK, name: s!("Czech koruna"),                                  digits: 2, countries: vh![ CountryCode: CZ ] },
-		Currency::DJF: CurrencyInfo { code: CurrencyCode::DJF, name: s!("Djiboutian franc"),                              digits: 0, countries: vh![ CountryCode: DJ ] },
-		Currency::DKK: CurrencyInfo { code: CurrencyCode::DKK, name: s!("Danish krone"),                                  digits: 2, countries: vh![ CountryCode: DK, FO, GL ] },
-		Currency::DOP: CurrencyInfo { code: CurrencyCode::DOP, name: s!("Dominican peso"),                                digits: 2, countries: vh![ CountryCode: DO ] },
-		Currency::DZD: CurrencyInfo { code: CurrencyCode::DZD, name: s!("Algerian dinar"),                                digits: 2, countries: vh![ CountryCode: DZ ] },
-		Currency::EGP: CurrencyInfo { code: CurrencyCode::EGP, name: s!("Egyptian pound"),                                digits: 2, countries: vh![ CountryCode: EG ] },
-		Currency::ERN: CurrencyInfo { code: CurrencyCode::ERN, name: s!("Eritrean nakfa"),                                digits: 2, countries: vh![ CountryCode: ER ] },
-		Currency::ETB: CurrencyInfo { code: CurrencyCode::ETB, name: s!("Ethiopian birr"),                                digits: 2, countries: vh![ CountryCode: ET ] },
-		Currency::EUR: CurrencyInfo { code: CurrencyCode::EUR, name: s!("Euro"),                                          digits: 2, countries: vh![ CountryCode: AD, AT, AX, BE, BL, CY, DE, EE, ES, FI, FR, GF, GP, GR, HR, IE, IT, LT, LU, LV, MC, ME, MF, MQ, MT, NL, PM, PT, RE, SI, SK, SM, TF, VA, YT ] },
-		Currency::FJD: CurrencyInfo { code: CurrencyCode::FJD, name: s!("Fiji dollar"),                                   digits: 2, countries: vh![ CountryCode: FJ ] },
-		Currency::FKP: CurrencyInfo { code: CurrencyCode::FKP, name: s!("Falkland Islands pound"),                        digits: 2, countries: vh![ CountryCode: FK ] },
-		Currency::GBP: CurrencyInfo { code: CurrencyCode::GBP, name: s!("Pound sterling"),                                digits: 2, countries: vh![ CountryCode: GB, GG, IM, JE, SH ] },
-		Currency::GEL: CurrencyInfo { code: CurrencyCode::GEL, name: s!("Georgian lari"),                                 digits: 2, countries: vh![ CountryCode: GE ] },
-		Currency::GHS: CurrencyInfo { code: CurrencyCode::GHS, name: s!("Ghanaian cedi"),                                 digits: 2, countries: vh![ CountryCode: GH ] },
-		Currency::GIP: CurrencyInfo { code: CurrencyCode::GIP, name: s!("Gibraltar pound"),                               digits: 2, countries: vh![ CountryCode: GI ] },
-		Currency::GMD: CurrencyInfo { code: CurrencyCode::GMD, name: s!("Gambian dalasi"),                                digits: 2, countries: vh![ CountryCode: GM ] },
-		Currency::GNF: CurrencyInfo { code: CurrencyCode::GNF, name: s!("Guinean franc"),                                 digits: 0, countries: vh![ CountryCode: GN ] },
-		Currency::GTQ: CurrencyInfo { code: CurrencyCode::GTQ, name: s!("Guatemalan quetzal"),                            digits: 2, countries: vh![ CountryCode: GT ] },
-		Currency::GYD: CurrencyInfo { code: CurrencyCode::GYD, name: s!("Guyanese dollar"),                               digits: 2, countries: vh![ CountryCode: GY ] },
-		Currency::HKD: CurrencyInfo { code: CurrencyCode::HKD, name: s!("Hong Kong dollar"),                              digits: 2, countries: vh![ CountryCode: HK ] },
-		Currency::HNL: CurrencyInfo { code: CurrencyCode::HNL, name: s!("Honduran lempira"),                              digits: 2, countries: vh![ CountryCode: HN ] },
-		Currency::HTG: CurrencyInfo { code: CurrencyCode::HTG, name: s!("Haitian gourde"),                                digits: 2, countries: vh![ CountryCode: HT ] },
-		Currency::HUF: CurrencyInfo { code: CurrencyCode::HUF, name: s!("Hungarian forint"),                              digits: 2, countries: vh![ CountryCode: HU ] },
-		Currency::IDR: CurrencyInfo { code: CurrencyCode::IDR, name: s!("Indonesian rupiah"),                             digits: 2, countries: vh![ CountryCode: ID ] },
-		Currency::ILS: CurrencyInfo { code: CurrencyCode::ILS, name: s!("Israeli new shekel"),                            digits: 2, countries: vh![ CountryCode: IL ] },
-		Currency::INR: CurrencyInfo { code: CurrencyCode::INR, name: s!("Indian rupee"),                                  digits: 2, countries: vh![ CountryCode: BT, IN ] },
-		Currency::IQD: CurrencyInfo { code: CurrencyCode::IQD, name: s!("Iraqi dinar"),                                   digits: 3, countries: vh![ CountryCode: IQ ] },
-		Currency::IRR: CurrencyInfo { code: CurrencyCode::IRR, name: s!("Iranian rial"),                                  digits: 2, countries: vh![ CountryCode: IR ] },
-		Currency::ISK: CurrencyInfo { code: CurrencyCode::ISK, name: s!("Icelandic króna"),                               digits: 0, countries: vh![ CountryCode: IS ] },
-		Currency::JMD: CurrencyInfo { code: CurrencyCode::JMD, name: s!("Jamaican dollar"),                               digits: 2, countries: vh![ CountryCode: JM ] },
-		Currency::JOD: CurrencyInfo { code: CurrencyCode::JOD, name: s!("Jordanian dinar"),                               digits: 3, countries: vh![ CountryCode: JO ] },
-		Currency::JPY: CurrencyInfo { code: CurrencyCode::JPY, name: s!("Japanese yen"),                                  digits: 0, countries: vh![ CountryCode: JP ] },
-		Currency::KES: CurrencyInfo { code: CurrencyCode::KES, name: s!("Kenyan shilling"),                               digits: 2, countries: vh![ CountryCode: KE ] },
-		Currency::KGS: CurrencyInfo { code: CurrencyCode::KGS, name: s!("Kyrgyzstani som"),                               digits: 2, countries: vh![ CountryCode: KG ] },
-		Currency::KHR: CurrencyInfo { code: CurrencyCode::KHR, name: s!("Cambodian riel"),                                digits: 2, countries: vh![ CountryCode: KH ] },
-		Currency::KMF: CurrencyInfo { code: CurrencyCode::KMF, name: s!("Comoro franc"),                                  digits: 0, countries: vh![ CountryCode: KM ] },
-		Currency::KPW: CurrencyInfo { code: CurrencyCode::KPW, name: s!("North Korean won"),                              digits: 2, countries: vh![ CountryCode: KP ] },
-		Currency::KRW: CurrencyInfo { code: CurrencyCode::KRW, name: s!("South Korean won"),                              digits: 0, countries: vh![ CountryCode: KR ] },
-		Currency::KWD: CurrencyInfo { code: CurrencyCode::KWD, name: s!("Kuwaiti dinar"),                                 digits: 3, countries: vh![ CountryCode: KW ] },
-		Currency::KYD: CurrencyInfo { code: CurrencyCode::KYD, name: s!("Cayman Islands dollar"),                         digits: 2, countries: vh![ CountryCode: KY ] },
-		Currency::KZT: CurrencyInfo { code: CurrencyCode::KZT, name: s!("Kazakhstani tenge"),                             digits: 2, countries: vh![ CountryCode: KZ ] },
-		Currency::LAK: CurrencyInfo { code: CurrencyCode::LAK, name: s!("Lao kip"),                                       digits: 2, countries: vh![ CountryCode: LA ] },
-		Currency::LBP: CurrencyInfo { code: CurrencyCode::LBP, name: s!("Lebanese pound"),                                digits: 2, countries: vh![ CountryCode: LB ] },
-		Currency::LKR: CurrencyInfo { code: CurrencyCode::LKR, name: s!("Sri Lankan rupee"),                              digits: 2, countries: vh![ CountryCode: LK ] },
-		Currency::LRD: CurrencyInfo { code: CurrencyCode::LRD, name: s!("Liberian dollar"),                               digits: 2, countries: vh![ CountryCode: LR ] },
-		Currency::LSL: CurrencyInfo { code: CurrencyCode::LSL, name: s!("Lesotho loti"),                                  digits: 2, countries: vh![ CountryCode: LS ] },
-		Currency::LYD: CurrencyInfo { code: CurrencyCode::LYD, name: s!("Libyan dinar"),                                  digits: 3, countries: vh![ CountryCode: LY ] },
-		Currency::MAD: CurrencyInfo { code: CurrencyCode::MAD, name: s!("Moroccan dirham"),                               digits: 2, countries: vh![ CountryCode: EH, MA ] },
-		Currency::MDL: CurrencyInfo { code: CurrencyCode::MDL, name: s!("Moldovan leu"),                                  digits: 2, countries: vh![ CountryCode: MD ] },
-		Currency::MGA: CurrencyInfo { code: CurrencyCode::MGA, name: s!("Malagasy ariary"),                               digits: 2, countries: vh![ CountryCode: MG ] },
-		Currency::MKD: CurrencyInfo { code: CurrencyCode::MKD, name: s!("Macedonian denar"),                              digits: 2, countries: vh![ CountryCode: MK ] },
-		Currency::MMK: CurrencyInfo { code: CurrencyCode::MMK, name: s!("Myanmar kyat"),                                  digits: 2, countries: vh![ CountryCode: MM ] },
-		Currency::MNT: CurrencyInfo { code: CurrencyCode::MNT, name: s!("Mongolian tögrög"),                              digits: 2, countries: vh![ CountryCode: MN ] },
-		Currency::MOP: CurrencyInfo { code: CurrencyCode::MOP, name: s!("Macanese pataca"),                               digits: 2, countries: vh![ CountryCode: MO ] },
-		Currency::MRU: CurrencyInfo { code: CurrencyCode::MRU, name: s!("Mauritanian ouguiya"),                           digits: 2, countries: vh![ CountryCode: MR ] },
-		Currency::MUR: CurrencyInfo { code: CurrencyCode::MUR, name: s!("Mauritian rupee"),                               digits: 2, countries: vh![ CountryCode: MU ] },
-		Currency::MVR: CurrencyInfo { code: CurrencyCode::MVR, name: s!("Maldivian rufiyaa"),                             digits: 2, countries: vh![ CountryCode: MV ] },
-		Currency::MWK: CurrencyInfo { code: CurrencyCode::MWK, name: s!("Malawian kwacha"),                               digits: 2, countries: vh![ CountryCode: MW ] },
-		Currency::MXN: CurrencyInfo { code: CurrencyCode::MXN, name: s!("Mexican peso"),                                  digits: 2, countries: vh![ CountryCode: MX ] },
-		Currency::MXV: CurrencyInfo { code: CurrencyCode::MXV, name: s!("Mexican Unidad de Inversion (UDI)"),             digits: 2, countries: vh![ CountryCode: MX ] },
-		Currency::MYR: CurrencyInfo { code: CurrencyCode::MYR, name: s!("Malaysian ringgit"),                             digits: 2, countries: vh![ CountryCode: MY ] },
-		Currency::MZN: CurrencyInfo { code: CurrencyCode::MZN, name: s!("Mozambican metical"),                            digits: 2, countries: vh![ CountryCode: MZ ] },
-		Currency::NAD: CurrencyInfo { code: CurrencyCode::NAD, name: s!("Namibian dollar"),                               digits: 2, countries: vh![ CountryCode: NA ] },
-		Currency::NGN: CurrencyInfo { code: CurrencyCode::NGN, name: s!("Nigerian naira"),                                digits: 2, countries: vh![ CountryCode: NG ] },
-		Currency::NIO: CurrencyInfo { code: CurrencyCode::NIO, name: s!("Nicaraguan córdoba"),                            digits: 2, countries: vh![ CountryCode: NI ] },
-		Currency::NOK: CurrencyInfo { code: CurrencyCode::NOK, name: s!("Norwegian krone"),                               digits: 2, countries: vh![ CountryCode: BV, NO, SJ ] },
-		Currency::NPR: CurrencyInfo { code: CurrencyCode::NPR, name: s!("Nepalese rupee"),                                digits: 2, countries: vh![ CountryCode: NP ] },
-		Currency::NZD: CurrencyInfo { code: CurrencyCode::NZD, name: s!("New Zealand dollar"),                            digits: 2, countries: vh![ CountryCode: CK, NU, NZ, PN, TK ] },
-		Currency::OMR: CurrencyInfo { code: CurrencyCode::OMR, name: s!("Omani rial"),                                    digits: 3, countries: vh![ CountryCode: OM ] },
-		Currency::PAB: CurrencyInfo { code: CurrencyCode::PAB, name: s!("Panamanian balboa"),                             digits: 2, countries: vh![ CountryCode: PA ] },
-		Currency::PEN: CurrencyInfo { code: CurrencyCode::PEN, name: s!("Peruvian sol"),                                  digits: 2, countries: vh![ CountryCode: PE ] },
-		Currency::PGK: CurrencyInfo { code: CurrencyCode::PGK, name: s!("Papua New Guinean kina"),                        digits: 2, countries: vh![ CountryCode: PG ] },
-		Currency::PHP: CurrencyInfo { code: CurrencyCode::PHP, name: s!("Philippine peso"),                               digits: 2, countries: vh![ CountryCode: PH ] },
-		Currency::PKR: CurrencyInfo { code: CurrencyCode::PKR, name: s!("Pakistani rupee"),                               digits: 2, countries: vh![ CountryCode: PK ] },
-		Currency::PLN: CurrencyInfo { code: CurrencyCode::PLN, name: s!("Polish złoty"),                                  digits: 2, countries: vh![ CountryCode: PL ] },
-		Currency::PYG: CurrencyInfo { code: CurrencyCode::PYG, name: s!("Paraguayan guaraní"),                            digits: 0, countries: vh![ CountryCode: PY ] },
-		Currency::QAR: CurrencyInfo { code: CurrencyCode::QAR, name: s!("Qatari riyal"),                                  digits: 2, countries: vh![ CountryCode: QA ] },
-		Currency::RON: CurrencyInfo { code: CurrencyCode::RON, name: s!("Romanian leu"),                                  digits: 2, countries: vh![ CountryCode: RO ] },
-		Currency::RSD: CurrencyInfo { code: CurrencyCode::RSD, name: s!("Serbian dinar"),                                 digits: 2, countries: vh![ CountryCode: RS ] },
-		Currency::RUB: CurrencyInfo { code: CurrencyCode::RUB, name: s!("Russian ruble"),                                 digits: 2, countries: vh![ CountryCode: RU ] },
-		Currency::RWF: CurrencyInfo { code: CurrencyCode::RWF, name: s!("Rwandan franc"),                                 digits: 0, countries: vh![ CountryCode: RW ] },
-		Currency::SAR: CurrencyInfo { code: CurrencyCode::SAR, name: s!("Saudi riyal"),                                   digits: 2, countries: vh![ CountryCode: SA ] },
-		Currency::SBD: CurrencyInfo { code: CurrencyCode::SBD, name: s!("Solomon Islands dollar"),                        digits: 2, countries: vh![ CountryCode: SB ] },
-		Currency::SCR: CurrencyInfo { code: CurrencyCode::SCR, name: s!("Seychelles rupee"),                              digits: 2, countries: vh![ CountryCode: SC ] },
-		Currency::SDG: CurrencyInfo { code: CurrencyCode::SDG, name: s!("Sudanese pound"),                                digits: 2, countries: vh![ CountryCode: SD ] },
-		Currency::SEK: CurrencyInfo { code: CurrencyCode::SEK, name: s!("Swedish krona"),                                 digits: 2, countries: vh![ CountryCode: SE ] },
-		Currency::SGD: CurrencyInfo { code: CurrencyCode::SGD, name: s!("Singapore dollar"),                              digits: 2, countries: vh![ CountryCode: SG ] },
-		Currency::SHP: CurrencyInfo { code: CurrencyCode::SHP, name: s!("Saint Helena pound"),                            digits: 2, countries: vh![ CountryCode: SH ] },
-		Currency::SLE: CurrencyInfo { code: CurrencyCode::SLE, name: s!("Sierra Leonean leone (new leone)"),              digits: 2, countries: vh![ CountryCode: SL ] },
-		Currency::SLL: CurrencyInfo { code: CurrencyCode::SLL, name: s!("Sierra Leonean leone (old leone)"),              digits: 2, countries: vh![ CountryCode: SL ] },
-		Currency::SOS: CurrencyInfo { code: CurrencyCode::SOS, name: s!("Somali shilling"),                               digits: 2, countries: vh![ CountryCode: SO ] },
-		Currency::SRD: CurrencyInfo { code: CurrencyCode::SRD, name: s!("Surinamese dollar"),                             digits: 2, countries: vh![ CountryCode: SR ] },
-		Currency::SSP: CurrencyInfo { code: CurrencyCode::SSP, name: s!("South Sudanese pound"),                          digits: 2, countries: vh![ CountryCode: SS ] },
-		Currency::STN: CurrencyInfo { code: CurrencyCode::STN, name: s!("São Tomé and Príncipe dobra"),                   digits: 2, countries: vh![ CountryCode: ST ] },
-		Currency::SVC: CurrencyInfo { code: CurrencyCode::SVC, name: s!("Salvadoran colón"),                              digits: 2, countries: vh![ CountryCode: SV ] },
-		Currency::SYP: CurrencyInfo { code: CurrencyCode::SYP, name: s!("Syrian pound"),                                  digits: 2, countries: vh![ CountryCode: SY ] },
-		Currency::SZL: CurrencyInfo { code: CurrencyCode::SZL, name: s!("Swazi lilangeni"),                               digits: 2, countries: vh![ CountryCode: SZ ] },
-		Currency::THB: CurrencyInfo { code: CurrencyCode::THB, name: s!("Thai baht"),                                     digits: 2, countries: vh![ CountryCode: TH ] },
-		Currency::TJS: CurrencyInfo { code: CurrencyCode::TJS, name: s!("Tajikistani somoni"),                            digits: 2, countries: vh![ CountryCode: TJ ] },
-		Currency::TMT: CurrencyInfo { code: CurrencyCode::TMT, name: s!("Turkmenistan manat"),                            digits: 2, countries: vh![ CountryCode: TM ] },
-		Currency::TND: CurrencyInfo { code: CurrencyCode::TND, name: s!("Tunisian dinar"),                                digits: 3, countries: vh![ CountryCode: TN ] },
-		Currency::TOP: CurrencyInfo { code: CurrencyCode::TOP, name: s!("Tongan paʻanga"),                                digits: 2, countries: vh![ CountryCode: TO ] },
-		Currency::TRY: CurrencyInfo { code: CurrencyCode::TRY, name: s!("Turkish lira"),                                  digits: 2, countries: vh![ CountryCode: TR ] },
-		Currency::TTD: CurrencyInfo { code: CurrencyCode::TTD, name: s!("Trinidad and Tobago dollar"),                    digits: 2, countries: vh![ CountryCode: TT ] },
-		Currency::TWD: CurrencyInfo { code: CurrencyCode::TWD, name: s!("New Taiwan dollar"),                             digits: 2, countries: vh![ CountryCode: TW ] },
-		Currency::TZS: CurrencyInfo { code: CurrencyCode::TZS, name: s!("Tanzanian shilling"),                            digits: 2, countries: vh![ CountryCode: TZ ] },
-		Currency::UAH: CurrencyInfo { code: CurrencyCode::UAH, name: s!("Ukrainian hryvnia"),                             digits: 2, countries: vh![ CountryCode: UA ] },
-		Currency::UGX: CurrencyInfo { code: CurrencyCode::UGX, name: s!("Ugandan shilling"),                              digits: 0, countries: vh![ CountryCode: UG ] },
-		Currency::USD: CurrencyInfo { code: CurrencyCode::USD, name: s!("United States dollar"),                          digits: 2, countries: vh![ CountryCode: AS, BQ, EC, FM, GU, IO, MH, MP, PA, PR, PW, SV, TC, TL, UM, US, VG, VI ] },
-		Currency::USN: CurrencyInfo { code: CurrencyCode::USN, name: s!("United States dollar (next day)"),               digits: 2, countries: vh![ CountryCode: US ] },
-		Currency::UYI: CurrencyInfo { code: CurrencyCode::UYI, name: s!("Uruguay Peso en Unidades Indexadas (URUIURUI)"), digits: 0, countries: vh![ CountryCode: UY ] },
-		Currency::UYU: CurrencyInfo { code: CurrencyCode::UYU, name: s!("Uruguayan peso"),                                digits: 2, countries: vh![ CountryCode: UY ] },
-		Currency::UYW: CurrencyInfo { code: CurrencyCode::UYW, name: s!("Unidad previsional"),                            digits: 4, countries: vh![ CountryCode: UY ] },
-		Currency::UZS: CurrencyInfo { code: CurrencyCode::UZS, name: s!("Uzbekistan sum"),                                digits: 2, countries: vh![ CountryCode: UZ ] },
-		Currency::VED: CurrencyInfo { code: CurrencyCode::VED, name: s!("Venezuelan digital bolívar"),                    digits: 2, countries: vh![ CountryCode: VE ] },
-		Currency::VES: CurrencyInfo { code: CurrencyCode::VES, name: s!("Venezuelan sovereign bolívar"),                  digits: 2, countries: vh![ CountryCode: VE ] },
-		Currency::VND: CurrencyInfo { code: CurrencyCode::VND, name: s!("Vietnamese đồng"),                               digits: 0, countries: vh![ CountryCode: VN ] },
-		Currency::VUV: CurrencyInfo { code: CurrencyCode::VUV, name: s!("Vanuatu vatu"),                                  digits: 0, countries: vh![ CountryCode: VU ] },
-		Currency::WST: CurrencyInfo { code: CurrencyCode::WST, name: s!("Samoan tala"),                                   digits: 2, countries: vh![ CountryCode: WS ] },
-		Currency::XAF: CurrencyInfo { code: CurrencyCode::XAF, name: s!("CFA franc BEAC"),                                digits: 0, countries: vh![ CountryCode: CF, CG, CM, GA, GQ, TD ] },
-		Currency::XAG: CurrencyInfo { code: CurrencyCode::XAG, name: s!("Silver (one troy ounce)"),                       digits: 0, countries: vh![] },
-		Currency::XAU: CurrencyInfo { code: CurrencyCode::XAU, name: s!("Gold (one troy ounce)"),                         digits: 0, countries: vh![] },
-		Currency::XBA: CurrencyInfo { code: CurrencyCode::XBA, name: s!("European Composite Unit (EURCO)"),               digits: 0, countries: vh![] },
-		Currency::XBB: CurrencyInfo { code: CurrencyCode::XBB, name: s!("European Monetary Unit (E.M.U.-6)"),             digits: 0, countries: vh![] },
-		Currency::XBC: CurrencyInfo { code: CurrencyCode::XBC, name: s!("European Unit of Account 9 (E.U.A.-9)"),         digits: 0, countries: vh![] },
-		Currency::XBD: CurrencyInfo { code: CurrencyCode::XBD, name: s!("European Unit of Account 17 (E.U.A.-17)"),       digits: 0, countries: vh![] },
-		Currency::XCD: CurrencyInfo { code: CurrencyCode::XCD, name: s!("East Caribbean dollar"),                         digits: 2, countries: vh![ CountryCode: AG, AI, DM, GD, KN, LC, MS, VC ] },
-		Currency::XDR: CurrencyInfo { code: CurrencyCode::XDR, name: s!("Special drawing rights"),                        digits: 0, countries: vh![] },
-		Currency::XOF: CurrencyInfo { code: CurrencyCode::XOF, name: s!("CFA franc BCEAO"),                               digits: 0, countries: vh![ CountryCode: BF, BJ, CI, GW, ML, NE, SN, TG ] },
-		Currency::XPD: CurrencyInfo { code: CurrencyCode::XPD, name: s!("Palladium (one troy ounce)"),                    digits: 0, countries: vh![] },
-		Currency::XPF: CurrencyInfo { code: CurrencyCode::XPF, name: s!("CFP franc (franc Pacifique)"),                   digits: 0, countries: vh![ CountryCode: NC, PF, WF ] },
-		Currency::XPT: CurrencyInfo { code: CurrencyCode::XPT, name: s!("Platinum (one troy ounce)"),                     digits: 0, countries: vh![] },
-		Currency::XSU: CurrencyInfo { code: CurrencyCode::XSU, name: s!("SUCRE"),                                         digits: 0, countries: vh![] },
-		Currency::XTS: CurrencyInfo { code: CurrencyCode::XTS, name: s!("Code reserved for testing"),                     digits: 0, countries: vh![] },
-		Currency::XUA: CurrencyInfo { code: CurrencyCode::XUA, name: s!("ADB Unit of Account"),                           digits: 0, countries: vh![] },
-		Currency::XXX: CurrencyInfo { code: CurrencyCode::XXX, name: s!("No currency"),                                   digits: 0, countries: vh![] },
-		Currency::YER: CurrencyInfo { code: CurrencyCode::YER, name: s!("Yemeni rial"),                                   digits: 2, countries: vh![ CountryCode: YE ] },
-		Currency::ZAR: CurrencyInfo { code: CurrencyCode::ZAR, name: s!("South African rand"),                            digits: 2, countries: vh![ CountryCode: LS, NA, SZ, ZA ] },
-		Currency::ZMW: CurrencyInfo { code: CurrencyCode::ZMW, name: s!("Zambian kwacha"),                                digits: 2, countries: vh![ CountryCode: ZM ] },
-		Currency::ZWL: CurrencyInfo { code: CurrencyCode::ZWL, name: s!("Zimbabwean dollar (fifth)"),                     digits: 2, countries: vh![ CountryCode: ZW ] },
-	}
+static CURRENCIES: [CurrencyInfo; COUNT] = [
+	CurrencyInfo { code: CurrencyCode::AED, name: "United Arab Emirates dirham",                   digits: MinorUnits::Digits(2),     units: Some((("dirham", "dirhams"), Some(("fils", "fils")))),                introduced: None,       replaces: None,        countries: &[ CountryCode::AE ] },
+	CurrencyInfo { code: CurrencyCode::AFN, name: "Afghan afghani",                                digits: MinorUnits::Digits(2),     units: Some((("afghani", "afghanis"), Some(("pul", "puls")))),               introduced: Some(2002), replaces: Some("AFA"), countries: &[ CountryCode::AF ] },
+	CurrencyInfo { code: CurrencyCode::ALL, name: "Albanian lek",                                  digits: MinorUnits::Digits(2),     units: Some((("lek", "lekë"), Some(("qindarka", "qindarka")))),              introduced: None,       replaces: None,        countries: &[ CountryCode::AL ] },
+	CurrencyInfo { code: CurrencyCode::AMD, name: "Armenian dram",                                 digits: MinorUnits::Digits(2),     units: Some((("dram", "drams"), Some(("luma", "lumas")))),                   introduced: Some(1993), replaces: Some("RUR"), countries: &[ CountryCode::AM ] },
+	CurrencyInfo { code: CurrencyCode::ANG, name: "Netherlands Antillean guilder",                 digits: MinorUnits::Digits(2),     units: Some((("guilder", "guilders"), Some(("cent", "cents")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::CW, CountryCode::SX ] },
+	CurrencyInfo { code: CurrencyCode::AOA, name: "Angolan kwanza",                                digits: MinorUnits::Digits(2),     units: Some((("kwanza", "kwanzas"), Some(("cêntimo", "cêntimos")))),         introduced: Some(1999), replaces: Some("AOR"), countries: &[ CountryCode::AO ] },
+	CurrencyInfo { code: CurrencyCode::ARS, name: "Argentine peso",                                digits: MinorUnits::Digits(2),     units: Some((("peso", "pesos"), Some(("centavo", "centavos")))),             introduced: Some(1992), replaces: Some("ARA"), countries: &[ CountryCode::AR ] },
+	CurrencyInfo { code: CurrencyCode::AUD, name: "Australian dollar",                             digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: Some(1966), replaces: None,        countries: &[ CountryCode::AU, CountryCode::CC, CountryCode::CX, CountryCode::HM, CountryCode::KI, CountryCode::NF, CountryCode::NR, CountryCode::TV ] },
+	CurrencyInfo { code: CurrencyCode::AWG, name: "Aruban florin",                                 digits: MinorUnits::Digits(2),     units: Some((("florin", "florins"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::AW ] },
+	CurrencyInfo { code: CurrencyCode::AZN, name: "Azerbaijani manat",                             digits: MinorUnits::Digits(2),     units: Some((("manat", "manats"), Some(("qəpik", "qəpiks")))),               introduced: Some(2006), replaces: Some("AZM"), countries: &[ CountryCode::AZ ] },
+	CurrencyInfo { code: CurrencyCode::BAM, name: "Bosnia and Herzegovina convertible mark",       digits: MinorUnits::Digits(2),     units: Some((("mark", "marks"), Some(("fening", "fenings")))),               introduced: Some(1998), replaces: Some("BAD"), countries: &[ CountryCode::BA ] },
+	CurrencyInfo { code: CurrencyCode::BBD, name: "Barbados dollar",                               digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::BB ] },
+	CurrencyInfo { code: CurrencyCode::BDT, name: "Bangladeshi taka",                              digits: MinorUnits::Digits(2),     units: Some((("taka", "taka"), Some(("poisha", "poisha")))),                 introduced: None,       replaces: None,        countries: &[ CountryCode::BD ] },
+	CurrencyInfo { code: CurrencyCode::BGN, name: "Bulgarian lev",                                 digits: MinorUnits::Digits(2),     units: Some((("lev", "leva"), Some(("stotinka", "stotinki")))),              introduced: Some(1999), replaces: Some("BGL"), countries: &[ CountryCode::BG ] },
+	CurrencyInfo { code: CurrencyCode::BHD, name: "Bahraini dinar",                                digits: MinorUnits::Digits(3),     units: Some((("dinar", "dinars"), Some(("fils", "fils")))),                  introduced: None,       replaces: None,        countries: &[ CountryCode::BH ] },
+	CurrencyInfo { code: CurrencyCode::BIF, name: "Burundian franc",                               digits: MinorUnits::Digits(0),     units: Some((("franc", "francs"), None)),                                    introduced: None,       replaces: None,        countries: &[ CountryCode::BI ] },
+	CurrencyInfo { code: CurrencyCode::BMD, name: "Bermudian dollar",                              digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::BM ] },
+	CurrencyInfo { code: CurrencyCode::BND, name: "Brunei dollar",                                 digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("sen", "sen")))),                  introduced: None,       replaces: None,        countries: &[ CountryCode::BN ] },
+	CurrencyInfo { code: CurrencyCode::BOB, name: "Boliviano",                                     digits: MinorUnits::Digits(2),     units: Some((("boliviano", "bolivianos"), Some(("centavo", "centavos")))),   introduced: Some(1987), replaces: Some("BOP"), countries: &[ CountryCode::BO ] },
+	CurrencyInfo { code: CurrencyCode::BOV, name: "Bolivian Mvdol",                                digits: MinorUnits::Digits(2),     units: None,                                                                 introduced: None,       replaces: None,        countries: &[ CountryCode::BO ] },
+	CurrencyInfo { code: CurrencyCode::BRL, name: "Brazilian real",                                digits: MinorUnits::Digits(2),     units: Some((("real", "reais"), Some(("centavo", "centavos")))),             introduced: Some(1994), replaces: Some("BRR"), countries: &[ CountryCode::BR ] },
+	CurrencyInfo { code: CurrencyCode::BSD, name: "Bahamian dollar",                               digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::BS ] },
+	CurrencyInfo { code: CurrencyCode::BTN, name: "Bhutanese ngultrum",                            digits: MinorUnits::Digits(2),     units: Some((("ngultrum", "ngultrums"), Some(("chhertum", "chhertums")))),   introduced: None,       replaces: None,        countries: &[ CountryCode::BT ] },
+	CurrencyInfo { code: CurrencyCode::BWP, name: "Botswana pula",                                 digits: MinorUnits::Digits(2),     units: Some((("pula", "pula"), Some(("thebe", "thebe")))),                   introduced: None,       replaces: None,        countries: &[ CountryCode::BW ] },
+	CurrencyInfo { code: CurrencyCode::BYN, name: "Belarusian ruble",                              digits: MinorUnits::Digits(2),     units: Some((("ruble", "rubles"), Some(("kopeck", "kopecks")))),             introduced: Some(2016), replaces: Some("BYR"), countries: &[ CountryCode::BY ] },
+	CurrencyInfo { code: CurrencyCode::BZD, name: "Belize dollar",                                 digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::BZ ] },
+	CurrencyInfo { code: CurrencyCode::CAD, name: "Canadian dollar",                               digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::CA ] },
+	CurrencyInfo { code: CurrencyCode::CDF, name: "Congolese franc",                               digits: MinorUnits::Digits(2),     units: Some((("franc", "francs"), Some(("centime", "centimes")))),           introduced: Some(1997), replaces: Some("ZRN"), countries: &[ CountryCode::CD ] },
+	CurrencyInfo { code: CurrencyCode::CHE, name: "WIR euro",                                      digits: MinorUnits::Digits(2),     units: None,                                                                 introduced: None,       replaces: None,        countries: &[ CountryCode::CH ] },
+	CurrencyInfo { code: CurrencyCode::CHF, name: "Swiss franc",                                   digits: MinorUnits::Digits(2),     units: Some((("franc", "francs"), Some(("centime", "centimes")))),           introduced: None,       replaces: None,        countries: &[ CountryCode::CH, CountryCode::LI ] },
+	CurrencyInfo { code: CurrencyCode::CHW, name: "WIR franc",                                     digits: MinorUnits::Digits(2),     units: None,                                                                 introduced: None,       replaces: None,        countries: &[ CountryCode::CH ] },
+	CurrencyInfo { code: CurrencyCode::CLF, name: "Unidad de Fomento",                             digits: MinorUnits::Digits(4),     units: None,                                                                 introduced: None,       replaces: None,        countries: &[ CountryCode::CL ] },
+	CurrencyInfo { code: CurrencyCode::CLP, name: "Chilean peso",                                  digits: MinorUnits::Digits(0),     units: Some((("peso", "pesos"), None)),                                      introduced: None,       replaces: None,        countries: &[ CountryCode::CL ] },
+	CurrencyInfo { code: CurrencyCode::CNY, name: "Renminbi",                                      digits: MinorUnits::Digits(2),     units: Some((("yuan", "yuan"), Some(("fen", "fen")))),                       introduced: None,       replaces: None,        countries: &[ CountryCode::CN ] },
+	CurrencyInfo { code: CurrencyCode::COP, name: "Colombian peso",                                digits: MinorUnits::Digits(2),     units: Some((("peso", "pesos"), Some(("centavo", "centavos")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::CO ] },
+	CurrencyInfo { code: CurrencyCode::COU, name: "Unidad de Valor Real (UVR)",                    digits: MinorUnits::Digits(2),     units: None,                                                                 introduced: None,       replaces: None,        countries: &[ CountryCode::CO ] },
+	CurrencyInfo { code: CurrencyCode::CRC, name: "Costa Rican colon",                             digits: MinorUnits::Digits(2),     units: Some((("colón", "colones"), Some(("céntimo", "céntimos")))),          introduced: None,       replaces: None,        countries: &[ CountryCode::CR ] },
+	CurrencyInfo { code: CurrencyCode::CUP, name: "Cuban peso",                                    digits: MinorUnits::Digits(2),     units: Some((("peso", "pesos"), Some(("centavo", "centavos")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::CU ] },
+	CurrencyInfo { code: CurrencyCode::CVE, name: "Cape Verdean escudo",                           digits: MinorUnits::Digits(2),     units: Some((("escudo", "escudos"), Some(("centavo", "centavos")))),         introduced: None,       replaces: None,        countries: &[ CountryCode::CV ] },
+	CurrencyInfo { code: CurrencyCode::CZK, name: "Czech koruna",                                  digits: MinorUnits::Digits(2),     units: Some((("koruna", "korunas"), Some(("haléř", "haléře")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::CZ ] },
+	CurrencyInfo { code: CurrencyCode::DJF, name: "Djiboutian franc",                              digits: MinorUnits::Digits(0),     units: Some((("franc", "francs"), None)),                                    introduced: None,       replaces: None,        countries: &[ CountryCode::DJ ] },
+	CurrencyInfo { code: CurrencyCode::DKK, name: "Danish krone",                                  digits: MinorUnits::Digits(2),     units: Some((("krone", "kroner"), Some(("øre", "øre")))),                    introduced: None,       replaces: None,        countries: &[ CountryCode::DK, CountryCode::FO, CountryCode::GL ] },
+	CurrencyInfo { code: CurrencyCode::DOP, name: "Dominican peso",                                digits: MinorUnits::Digits(2),     units: Some((("peso", "pesos"), Some(("centavo", "centavos")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::DO ] },
+	CurrencyInfo { code: CurrencyCode::DZD, name: "Algerian dinar",                                digits: MinorUnits::Digits(2),     units: Some((("dinar", "dinars"), Some(("santeem", "santeems")))),           introduced: None,       replaces: None,        countries: &[ CountryCode::DZ ] },
+	CurrencyInfo { code: CurrencyCode::EGP, name: "Egyptian pound",                                digits: MinorUnits::Digits(2),     units: Some((("pound", "pounds"), Some(("piastre", "piastres")))),           introduced: None,       replaces: None,        countries: &[ CountryCode::EG ] },
+	CurrencyInfo { code: CurrencyCode::ERN, name: "Eritrean nakfa",                                digits: MinorUnits::Digits(2),     units: Some((("nakfa", "nakfa"), Some(("cent", "cents")))),                  introduced: None,       replaces: None,        countries: &[ CountryCode::ER ] },
+	CurrencyInfo { code: CurrencyCode::ETB, name: "Ethiopian birr",                                digits: MinorUnits::Digits(2),     units: Some((("birr", "birr"), Some(("santim", "santims")))),                introduced: None,       replaces: None,        countries: &[ CountryCode::ET ] },
+	CurrencyInfo { code: CurrencyCode::EUR, name: "Euro",                                          digits: MinorUnits::Digits(2),     units: Some((("euro", "euros"), Some(("cent", "cents")))),                   introduced: Some(1999), replaces: Some("XEU"), countries: &[ CountryCode::AD, CountryCode::AT, CountryCode::AX, CountryCode::BE, CountryCode::BL, CountryCode::CY, CountryCode::DE, CountryCode::EE, CountryCode::ES, CountryCode::FI, CountryCode::FR, CountryCode::GF, CountryCode::GP, CountryCode::GR, CountryCode::HR, CountryCode::IE, CountryCode::IT, CountryCode::LT, CountryCode::LU, CountryCode::LV, CountryCode::MC, CountryCode::ME, CountryCode::MF, CountryCode::MQ, CountryCode::MT, CountryCode::NL, CountryCode::PM, CountryCode::PT, CountryCode::RE, CountryCode::SI, CountryCode::SK, CountryCode::SM, CountryCode::TF, CountryCode::VA, CountryCode::YT ] },
+	CurrencyInfo { code: CurrencyCode::FJD, name: "Fiji dollar",                                   digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::FJ ] },
+	CurrencyInfo { code: CurrencyCode::FKP, name: "Falkland Islands pound",                        digits: MinorUnits::Digits(2),     units: Some((("pound", "pounds"), Some(("penny", "pence")))),                introduced: None,       replaces: None,        countries: &[ CountryCode::FK ] },
+	CurrencyInfo { code: CurrencyCode::GBP, name: "Pound sterling",                                digits: MinorUnits::Digits(2),     units: Some((("pound", "pounds"), Some(("penny", "pence")))),                introduced: None,       replaces: None,        countries: &[ CountryCode::GB, CountryCode::GG, CountryCode::IM, CountryCode::JE, CountryCode::SH ] },
+	CurrencyInfo { code: CurrencyCode::GEL, name: "Georgian lari",                                 digits: MinorUnits::Digits(2),     units: Some((("lari", "lari"), Some(("tetri", "tetri")))),                   introduced: Some(1995), replaces: Some("GEK"), countries: &[ CountryCode::GE ] },
+	CurrencyInfo { code: CurrencyCode::GHS, name: "Ghanaian cedi",                                 digits: MinorUnits::Digits(2),     units: Some((("cedi", "cedis"), Some(("pesewa", "pesewas")))),               introduced: Some(2007), replaces: Some("GHC"), countries: &[ CountryCode::GH ] },
+	CurrencyInfo { code: CurrencyCode::GIP, name: "Gibraltar pound",                               digits: MinorUnits::Digits(2),     units: Some((("pound", "pounds"), Some(("penny", "pence")))),                introduced: None,       replaces: None,        countries: &[ CountryCode::GI ] },
+	CurrencyInfo { code: CurrencyCode::GMD, name: "Gambian dalasi",                                digits: MinorUnits::Digits(2),     units: Some((("dalasi", "dalasis"), Some(("butut", "bututs")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::GM ] },
+	CurrencyInfo { code: CurrencyCode::GNF, name: "Guinean franc",                                 digits: MinorUnits::Digits(0),     units: Some((("franc", "francs"), None)),                                    introduced: None,       replaces: None,        countries: &[ CountryCode::GN ] },
+	CurrencyInfo { code: CurrencyCode::GTQ, name: "Guatemalan quetzal",                            digits: MinorUnits::Digits(2),     units: Some((("quetzal", "quetzales"), Some(("centavo", "centavos")))),      introduced: None,       replaces: None,        countries: &[ CountryCode::GT ] },
+	CurrencyInfo { code: CurrencyCode::GYD, name: "Guyanese dollar",                               digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::GY ] },
+	CurrencyInfo { code: CurrencyCode::HKD, name: "Hong Kong dollar",                              digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::HK ] },
+	CurrencyInfo { code: CurrencyCode::HNL, name: "Honduran lempira",                              digits: MinorUnits::Digits(2),     units: Some((("lempira", "lempiras"), Some(("centavo", "centavos")))),       introduced: None,       replaces: None,        countries: &[ CountryCode::HN ] },
+	CurrencyInfo { code: CurrencyCode::HTG, name: "Haitian gourde",                                digits: MinorUnits::Digits(2),     units: Some((("gourde", "gourdes"), Some(("centime", "centimes")))),         introduced: None,       replaces: None,        countries: &[ CountryCode::HT ] },
+	CurrencyInfo { code: CurrencyCode::HUF, name: "Hungarian forint",                              digits: MinorUnits::Digits(2),     units: Some((("forint", "forints"), Some(("fillér", "fillér")))),            introduced: None,       replaces: None,        countries: &[ CountryCode::HU ] },
+	CurrencyInfo { code: CurrencyCode::IDR, name: "Indonesian rupiah",                             digits: MinorUnits::Digits(2),     units: Some((("rupiah", "rupiah"), Some(("sen", "sen")))),                   introduced: None,       replaces: None,        countries: &[ CountryCode::ID ] },
+	CurrencyInfo { code: CurrencyCode::ILS, name: "Israeli new shekel",                            digits: MinorUnits::Digits(2),     units: Some((("shekel", "shekels"), Some(("agora", "agorot")))),             introduced: Some(1985), replaces: Some("ILR"), countries: &[ CountryCode::IL ] },
+	CurrencyInfo { code: CurrencyCode::INR, name: "Indian rupee",                                  digits: MinorUnits::Digits(2),     units: Some((("rupee", "rupees"), Some(("paisa", "paise")))),                introduced: None,       replaces: None,        countries: &[ CountryCode::BT, CountryCode::IN ] },
+	CurrencyInfo { code: CurrencyCode::IQD, name: "Iraqi dinar",                                   digits: MinorUnits::Digits(3),     units: Some((("dinar", "dinars"), Some(("fils", "fils")))),                  introduced: None,       replaces: None,        countries: &[ CountryCode::IQ ] },
+	CurrencyInfo { code: CurrencyCode::IRR, name: "Iranian rial",                                  digits: MinorUnits::Digits(2),     units: Some((("rial", "rials"), Some(("dinar", "dinars")))),                 introduced: None,       replaces: None,        countries: &[ CountryCode::IR ] },
+	CurrencyInfo { code: CurrencyCode::ISK, name: "Icelandic króna",                               digits: MinorUnits::Digits(0),     units: Some((("króna", "krónur"), None)),                                    introduced: None,       replaces: None,        countries: &[ CountryCode::IS ] },
+	CurrencyInfo { code: CurrencyCode::JMD, name: "Jamaican dollar",                               digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::JM ] },
+	CurrencyInfo { code: CurrencyCode::JOD, name: "Jordanian dinar",                               digits: MinorUnits::Digits(3),     units: Some((("dinar", "dinars"), Some(("fils", "fils")))),                  introduced: None,       replaces: None,        countries: &[ CountryCode::JO ] },
+	CurrencyInfo { code: CurrencyCode::JPY, name: "Japanese yen",                                  digits: MinorUnits::Digits(0),     units: Some((("yen", "yen"), None)),                                         introduced: None,       replaces: None,        countries: &[ CountryCode::JP ] },
+	CurrencyInfo { code: CurrencyCode::KES, name: "Kenyan shilling",                               digits: MinorUnits::Digits(2),     units: Some((("shilling", "shillings"), Some(("cent", "cents")))),           introduced: None,       replaces: None,        countries: &[ CountryCode::KE ] },
+	CurrencyInfo { code: CurrencyCode::KGS, name: "Kyrgyzstani som",                               digits: MinorUnits::Digits(2),     units: Some((("som", "som"), Some(("tyiyn", "tyiyn")))),                     introduced: Some(1993), replaces: Some("RUR"), countries: &[ CountryCode::KG ] },
+	CurrencyInfo { code: CurrencyCode::KHR, name: "Cambodian riel",                                digits: MinorUnits::Digits(2),     units: Some((("riel", "riels"), Some(("sen", "sen")))),                      introduced: None,       replaces: None,        countries: &[ CountryCode::KH ] },
+	CurrencyInfo { code: CurrencyCode::KMF, name: "Comoro franc",                                  digits: MinorUnits::Digits(0),     units: Some((("franc", "francs"), None)),                                    introduced: None,       replaces: None,        countries: &[ CountryCode::KM ] },
+	CurrencyInfo { code: CurrencyCode::KPW, name: "North Korean won",                              digits: MinorUnits::Digits(2),     units: Some((("won", "won"), Some(("chon", "chon")))),                       introduced: None,       replaces: None,        countries: &[ CountryCode::KP ] },
+	CurrencyInfo { code: CurrencyCode::KRW, name: "South Korean won",                              digits: MinorUnits::Digits(0),     units: Some((("won", "won"), None)),                                         introduced: None,       replaces: None,        countries: &[ CountryCode::KR ] },
+	CurrencyInfo { code: CurrencyCode::KWD, name: "Kuwaiti dinar",                                 digits: MinorUnits::Digits(3),     units: Some((("dinar", "dinars"), Some(("fils", "fils")))),                  introduced: None,       replaces: None,        countries: &[ CountryCode::KW ] },
+	CurrencyInfo { code: CurrencyCode::KYD, name: "Cayman Islands dollar",                         digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::KY ] },
+	CurrencyInfo { code: CurrencyCode::KZT, name: "Kazakhstani tenge",                             digits: MinorUnits::Digits(2),     units: Some((("tenge", "tenge"), Some(("tiyn", "tiyn")))),                   introduced: Some(1993), replaces: Some("RUR"), countries: &[ CountryCode::KZ ] },
+	CurrencyInfo { code: CurrencyCode::LAK, name: "Lao kip",                                       digits: MinorUnits::Digits(2),     units: Some((("kip", "kip"), Some(("att", "att")))),                         introduced: None,       replaces: None,        countries: &[ CountryCode::LA ] },
+	CurrencyInfo { code: CurrencyCode::LBP, name: "Lebanese pound",                                digits: MinorUnits::Digits(2),     units: Some((("pound", "pounds"), Some(("piastre", "piastres")))),           introduced: None,       replaces: None,        countries: &[ CountryCode::LB ] },
+	CurrencyInfo { code: CurrencyCode::LKR, name: "Sri Lankan rupee",                              digits: MinorUnits::Digits(2),     units: Some((("rupee", "rupees"), Some(("cent", "cents")))),                 introduced: None,       replaces: None,        countries: &[ CountryCode::LK ] },
+	CurrencyInfo { code: CurrencyCode::LRD, name: "Liberian dollar",                               digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::LR ] },
+	CurrencyInfo { code: CurrencyCode::LSL, name: "Lesotho loti",                                  digits: MinorUnits::Digits(2),     units: Some((("loti", "maloti"), Some(("sente", "lisente")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::LS ] },
+	CurrencyInfo { code: CurrencyCode::LYD, name: "Libyan dinar",                                  digits: MinorUnits::Digits(3),     units: Some((("dinar", "dinars"), Some(("dirham", "dirhams")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::LY ] },
+	CurrencyInfo { code: CurrencyCode::MAD, name: "Moroccan dirham",                               digits: MinorUnits::Digits(2),     units: Some((("dirham", "dirhams"), Some(("centime", "centimes")))),         introduced: None,       replaces: None,        countries: &[ CountryCode::EH, CountryCode::MA ] },
+	CurrencyInfo { code: CurrencyCode::MDL, name: "Moldovan leu",                                  digits: MinorUnits::Digits(2),     units: Some((("leu", "lei"), Some(("ban", "bani")))),                        introduced: Some(1993), replaces: Some("RUR"), countries: &[ CountryCode::MD ] },
+	CurrencyInfo { code: CurrencyCode::MGA, name: "Malagasy ariary",                               digits: MinorUnits::Digits(2),     units: Some((("ariary", "ariary"), Some(("iraimbilanja", "iraimbilanja")))), introduced: Some(2005), replaces: Some("MGF"), countries: &[ CountryCode::MG ] },
+	CurrencyInfo { code: CurrencyCode::MKD, name: "Macedonian denar",                              digits: MinorUnits::Digits(2),     units: Some((("denar", "denars"), Some(("deni", "deni")))),                  introduced: None,       replaces: None,        countries: &[ CountryCode::MK ] },
+	CurrencyInfo { code: CurrencyCode::MMK, name: "Myanmar kyat",                                  digits: MinorUnits::Digits(2),     units: Some((("kyat", "kyats"), Some(("pya", "pyas")))),                     introduced: None,       replaces: None,        countries: &[ CountryCode::MM ] },
+	CurrencyInfo { code: CurrencyCode::MNT, name: "Mongolian tögrög",                              digits: MinorUnits::Digits(2),     units: Some((("tögrög", "tögrögs"), Some(("möngö", "möngös")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::MN ] },
+	CurrencyInfo { code: CurrencyCode::MOP, name: "Macanese pataca",                               digits: MinorUnits::Digits(2),     units: Some((("pataca", "patacas"), Some(("avo", "avos")))),                 introduced: None,       replaces: None,        countries: &[ CountryCode::MO ] },
+	CurrencyInfo { code: CurrencyCode::MRU, name: "Mauritanian ouguiya",                           digits: MinorUnits::Digits(2),     units: Some((("ouguiya", "ouguiyas"), Some(("khoums", "khoums")))),          introduced: Some(2018), replaces: Some("MRO"), countries: &[ CountryCode::MR ] },
+	CurrencyInfo { code: CurrencyCode::MUR, name: "Mauritian rupee",                               digits: MinorUnits::Digits(2),     units: Some((("rupee", "rupees"), Some(("cent", "cents")))),                 introduced: None,       replaces: None,        countries: &[ CountryCode::MU ] },
+	CurrencyInfo { code: CurrencyCode::MVR, name: "Maldivian rufiyaa",                             digits: MinorUnits::Digits(2),     units: Some((("rufiyaa", "rufiyaa"), Some(("laari", "laari")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::MV ] },
+	CurrencyInfo { code: CurrencyCode::MWK, name: "Malawian kwacha",                               digits: MinorUnits::Digits(2),     units: Some((("kwacha", "kwacha"), Some(("tambala", "tambala")))),           introduced: None,       replaces: None,        countries: &[ CountryCode::MW ] },
+	CurrencyInfo { code: CurrencyCode::MXN, name: "Mexican peso",                                  digits: MinorUnits::Digits(2),     units: Some((("peso", "pesos"), Some(("centavo", "centavos")))),             introduced: Some(1993), replaces: Some("MXP"), countries: &[ CountryCode::MX ] },
+	CurrencyInfo { code: CurrencyCode::MXV, name: "Mexican Unidad de Inversion (UDI)",             digits: MinorUnits::Digits(2),     units: None,                                                                 introduced: None,       replaces: None,        countries: &[ CountryCode::MX ] },
+	CurrencyInfo { code: CurrencyCode::MYR, name: "Malaysian ringgit",                             digits: MinorUnits::Digits(2),     units: Some((("ringgit", "ringgit"), Some(("sen", "sen")))),                 introduced: None,       replaces: None,        countries: &[ CountryCode::MY ] },
+	CurrencyInfo { code: CurrencyCode::MZN, name: "Mozambican metical",                            digits: MinorUnits::Digits(2),     units: Some((("metical", "meticais"), Some(("centavo", "centavos")))),       introduced: Some(2006), replaces: Some("MZM"), countries: &[ CountryCode::MZ ] },
+	CurrencyInfo { code: CurrencyCode::NAD, name: "Namibian dollar",                               digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::NA ] },
+	CurrencyInfo { code: CurrencyCode::NGN, name: "Nigerian naira",                                digits: MinorUnits::Digits(2),     units: Some((("naira", "naira"), Some(("kobo", "kobo")))),                   introduced: None,       replaces: None,        countries: &[ CountryCode::NG ] },
+	CurrencyInfo { code: CurrencyCode::NIO, name: "Nicaraguan córdoba",                            digits: MinorUnits::Digits(2),     units: Some((("córdoba", "córdobas"), Some(("centavo", "centavos")))),       introduced: None,       replaces: None,        countries: &[ CountryCode::NI ] },
+	CurrencyInfo { code: CurrencyCode::NOK, name: "Norwegian krone",                               digits: MinorUnits::Digits(2),     units: Some((("krone", "kroner"), Some(("øre", "øre")))),                    introduced: None,       replaces: None,        countries: &[ CountryCode::BV, CountryCode::NO, CountryCode::SJ ] },
+	CurrencyInfo { code: CurrencyCode::NPR, name: "Nepalese rupee",                                digits: MinorUnits::Digits(2),     units: Some((("rupee", "rupees"), Some(("paisa", "paise")))),                introduced: None,       replaces: None,        countries: &[ CountryCode::NP ] },
+	CurrencyInfo { code: CurrencyCode::NZD, name: "New Zealand dollar",                            digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: Some(1967), replaces: None,        countries: &[ CountryCode::CK, CountryCode::NU, CountryCode::NZ, CountryCode::PN, CountryCode::TK ] },
+	CurrencyInfo { code: CurrencyCode::OMR, name: "Omani rial",                                    digits: MinorUnits::Digits(3),     units: Some((("rial", "rials"), Some(("baisa", "baisa")))),                  introduced: None,       replaces: None,        countries: &[ CountryCode::OM ] },
+	CurrencyInfo { code: CurrencyCode::PAB, name: "Panamanian balboa",                             digits: MinorUnits::Digits(2),     units: Some((("balboa", "balboas"), Some(("centésimo", "centésimos")))),     introduced: None,       replaces: None,        countries: &[ CountryCode::PA ] },
+	CurrencyInfo { code: CurrencyCode::PEN, name: "Peruvian sol",                                  digits: MinorUnits::Digits(2),     units: Some((("sol", "soles"), Some(("céntimo", "céntimos")))),              introduced: Some(1991), replaces: Some("PEI"), countries: &[ CountryCode::PE ] },
+	CurrencyInfo { code: CurrencyCode::PGK, name: "Papua New Guinean kina",                        digits: MinorUnits::Digits(2),     units: Some((("kina", "kina"), Some(("toea", "toea")))),                     introduced: None,       replaces: None,        countries: &[ CountryCode::PG ] },
+	CurrencyInfo { code: CurrencyCode::PHP, name: "Philippine peso",                               digits: MinorUnits::Digits(2),     units: Some((("peso", "pesos"), Some(("sentimo", "sentimos")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::PH ] },
+	CurrencyInfo { code: CurrencyCode::PKR, name: "Pakistani rupee",                               digits: MinorUnits::Digits(2),     units: Some((("rupee", "rupees"), Some(("paisa", "paise")))),                introduced: None,       replaces: None,        countries: &[ CountryCode::PK ] },
+	CurrencyInfo { code: CurrencyCode::PLN, name: "Polish złoty",                                  digits: MinorUnits::Digits(2),     units: Some((("złoty", "złotys"), Some(("grosz", "groszy")))),               introduced: Some(1995), replaces: Some("PLZ"), countries: &[ CountryCode::PL ] },
+	CurrencyInfo { code: CurrencyCode::PYG, name: "Paraguayan guaraní",                            digits: MinorUnits::Digits(0),     units: Some((("guaraní", "guaraníes"), None)),                               introduced: None,       replaces: None,        countries: &[ CountryCode::PY ] },
+	CurrencyInfo { code: CurrencyCode::QAR, name: "Qatari riyal",                                  digits: MinorUnits::Digits(2),     units: Some((("riyal", "riyals"), Some(("dirham", "dirhams")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::QA ] },
+	CurrencyInfo { code: CurrencyCode::RON, name: "Romanian leu",                                  digits: MinorUnits::Digits(2),     units: Some((("leu", "lei"), Some(("ban", "bani")))),                        introduced: Some(2005), replaces: Some("ROL"), countries: &[ CountryCode::RO ] },
+	CurrencyInfo { code: CurrencyCode::RSD, name: "Serbian dinar",                                 digits: MinorUnits::Digits(2),     units: Some((("dinar", "dinars"), Some(("para", "para")))),                  introduced: Some(2006), replaces: Some("CSD"), countries: &[ CountryCode::RS ] },
+	CurrencyInfo { code: CurrencyCode::RUB, name: "Russian ruble",                                 digits: MinorUnits::Digits(2),     units: Some((("ruble", "rubles"), Some(("kopeck", "kopecks")))),             introduced: Some(1998), replaces: Some("RUR"), countries: &[ CountryCode::RU ] },
+	CurrencyInfo { code: CurrencyCode::RWF, name: "Rwandan franc",                                 digits: MinorUnits::Digits(0),     units: Some((("franc", "francs"), None)),                                    introduced: None,       replaces: None,        countries: &[ CountryCode::RW ] },
+	CurrencyInfo { code: CurrencyCode::SAR, name: "Saudi riyal",                                   digits: MinorUnits::Digits(2),     units: Some((("riyal", "riyals"), Some(("halala", "halalas")))),             introduced: None,       replaces: None,        countries: &[ CountryCode::SA ] },
+	CurrencyInfo { code: CurrencyCode::SBD, name: "Solomon Islands dollar",                        digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::SB ] },
+	CurrencyInfo { code: CurrencyCode::SCR, name: "Seychelles rupee",                              digits: MinorUnits::Digits(2),     units: Some((("rupee", "rupees"), Some(("cent", "cents")))),                 introduced: None,       replaces: None,        countries: &[ CountryCode::SC ] },
+	CurrencyInfo { code: CurrencyCode::SDG, name: "Sudanese pound",                                digits: MinorUnits::Digits(2),     units: Some((("pound", "pounds"), Some(("piastre", "piastres")))),           introduced: Some(2007), replaces: Some("SDD"), countries: &[ CountryCode::SD ] },
+	CurrencyInfo { code: CurrencyCode::SEK, name: "Swedish krona",                                 digits: MinorUnits::Digits(2),     units: Some((("krona", "kronor"), Some(("öre", "öre")))),                    introduced: None,       replaces: None,        countries: &[ CountryCode::SE ] },
+	CurrencyInfo { code: CurrencyCode::SGD, name: "Singapore dollar",                              digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: Some(1967), replaces: None,        countries: &[ CountryCode::SG ] },
+	CurrencyInfo { code: CurrencyCode::SHP, name: "Saint Helena pound",                            digits: MinorUnits::Digits(2),     units: Some((("pound", "pounds"), Some(("penny", "pence")))),                introduced: None,       replaces: None,        countries: &[ CountryCode::SH ] },
+	CurrencyInfo { code: CurrencyCode::SLE, name: "Sierra Leonean leone (new leone)",              digits: MinorUnits::Digits(2),     units: Some((("leone", "leones"), Some(("cent", "cents")))),                 introduced: Some(2022), replaces: Some("SLL"), countries: &[ CountryCode::SL ] },
+	CurrencyInfo { code: CurrencyCode::SLL, name: "Sierra Leonean leone (old leone)",              digits: MinorUnits::Digits(2),     units: Some((("leone", "leones"), Some(("cent", "cents")))),                 introduced: None,       replaces: None,        countries: &[ CountryCode::SL ] },
+	CurrencyInfo { code: CurrencyCode::SOS, name: "Somali shilling",                               digits: MinorUnits::Digits(2),     units: Some((("shilling", "shillings"), Some(("cent", "cents")))),           introduced: None,       replaces: None,        countries: &[ CountryCode::SO ] },
+	CurrencyInfo { code: CurrencyCode::SRD, name: "Surinamese dollar",                             digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: Some(2004), replaces: Some("SRG"), countries: &[ CountryCode::SR ] },
+	CurrencyInfo { code: CurrencyCode::SSP, name: "South Sudanese pound",                          digits: MinorUnits::Digits(2),     units: Some((("pound", "pounds"), Some(("piastre", "piastres")))),           introduced: Some(2011), replaces: None,        countries: &[ CountryCode::SS ] },
+	CurrencyInfo { code: CurrencyCode::STN, name: "São Tomé and Príncipe dobra",                   digits: MinorUnits::Digits(2),     units: Some((("dobra", "dobras"), Some(("cêntimo", "cêntimos")))),           introduced: Some(2018), replaces: Some("STD"), countries: &[ CountryCode::ST ] },
+	CurrencyInfo { code: CurrencyCode::SVC, name: "Salvadoran colón",                              digits: MinorUnits::Digits(2),     units: Some((("colón", "colones"), Some(("centavo", "centavos")))),          introduced: None,       replaces: None,        countries: &[ CountryCode::SV ] },
+	CurrencyInfo { code: CurrencyCode::SYP, name: "Syrian pound",                                  digits: MinorUnits::Digits(2),     units: Some((("pound", "pounds"), Some(("piastre", "piastres")))),           introduced: None,       replaces: None,        countries: &[ CountryCode::SY ] },
+	CurrencyInfo { code: CurrencyCode::SZL, name: "Swazi lilangeni",                               digits: MinorUnits::Digits(2),     units: Some((("lilangeni", "emalangeni"), Some(("cent", "cents")))),         introduced: None,       replaces: None,        countries: &[ CountryCode::SZ ] },
+	CurrencyInfo { code: CurrencyCode::THB, name: "Thai baht",                                     digits: MinorUnits::Digits(2),     units: Some((("baht", "baht"), Some(("satang", "satang")))),                 introduced: None,       replaces: None,        countries: &[ CountryCode::TH ] },
+	CurrencyInfo { code: CurrencyCode::TJS, name: "Tajikistani somoni",                            digits: MinorUnits::Digits(2),     units: Some((("somoni", "somoni"), Some(("diram", "dirams")))),              introduced: Some(2000), replaces: Some("TJR"), countries: &[ CountryCode::TJ ] },
+	CurrencyInfo { code: CurrencyCode::TMT, name: "Turkmenistan manat",                            digits: MinorUnits::Digits(2),     units: Some((("manat", "manat"), Some(("tenge", "tenge")))),                 introduced: Some(2009), replaces: Some("TMM"), countries: &[ CountryCode::TM ] },
+	CurrencyInfo { code: CurrencyCode::TND, name: "Tunisian dinar",                                digits: MinorUnits::Digits(3),     units: Some((("dinar", "dinars"), Some(("millime", "millimes")))),           introduced: None,       replaces: None,        countries: &[ CountryCode::TN ] },
+	CurrencyInfo { code: CurrencyCode::TOP, name: "Tongan paʻanga",                                digits: MinorUnits::Digits(2),     units: Some((("paʻanga", "paʻanga"), Some(("seniti", "seniti")))),           introduced: None,       replaces: None,        countries: &[ CountryCode::TO ] },
+	CurrencyInfo { code: CurrencyCode::TRY, name: "Turkish lira",                                  digits: MinorUnits::Digits(2),     units: Some((("lira", "lira"), Some(("kuruş", "kuruş")))),                   introduced: Some(2005), replaces: Some("TRL"), countries: &[ CountryCode::TR ] },
+	CurrencyInfo { code: CurrencyCode::TTD, name: "Trinidad and Tobago dollar",                    digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::TT ] },
+	CurrencyInfo { code: CurrencyCode::TWD, name: "New Taiwan dollar",                             digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::TW ] },
+	CurrencyInfo { code: CurrencyCode::TZS, name: "Tanzanian shilling",                            digits: MinorUnits::Digits(2),     units: Some((("shilling", "shillings"), Some(("cent", "cents")))),           introduced: None,       replaces: None,        countries: &[ CountryCode::TZ ] },
+	CurrencyInfo { code: CurrencyCode::UAH, name: "Ukrainian hryvnia",                             digits: MinorUnits::Digits(2),     units: Some((("hryvnia", "hryvnias"), Some(("kopiyka", "kopiyky")))),        introduced: Some(1996), replaces: Some("UAK"), countries: &[ CountryCode::UA ] },
+	CurrencyInfo { code: CurrencyCode::UGX, name: "Ugandan shilling",                              digits: MinorUnits::Digits(0),     units: Some((("shilling", "shillings"), None)),                              introduced: None,       replaces: None,        countries: &[ CountryCode::UG ] },
+	CurrencyInfo { code: CurrencyCode::USD, name: "United States dollar",                          digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::AS, CountryCode::BQ, CountryCode::EC, CountryCode::FM, CountryCode::GU, CountryCode::IO, CountryCode::MH, CountryCode::MP, CountryCode::PA, CountryCode::PR, CountryCode::PW, CountryCode::SV, CountryCode::TC, CountryCode::TL, CountryCode::UM, CountryCode::US, CountryCode::VG, CountryCode::VI ] },
+	CurrencyInfo { code: CurrencyCode::USN, name: "United States dollar (next day)",               digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::US ] },
+	CurrencyInfo { code: CurrencyCode::UYI, name: "Uruguay Peso en Unidades Indexadas (URUIURUI)", digits: MinorUnits::Digits(0),     units: None,                                                                 introduced: None,       replaces: None,        countries: &[ CountryCode::UY ] },
+	CurrencyInfo { code: CurrencyCode::UYU, name: "Uruguayan peso",                                digits: MinorUnits::Digits(2),     units: Some((("peso", "pesos"), Some(("centésimo", "centésimos")))),         introduced: Some(1993), replaces: Some("UYP"), countries: &[ CountryCode::UY ] },
+	CurrencyInfo { code: CurrencyCode::UYW, name: "Unidad previsional",                            digits: MinorUnits::Digits(4),     units: None,                                                                 introduced: None,       replaces: None,        countries: &[ CountryCode::UY ] },
+	CurrencyInfo { code: CurrencyCode::UZS, name: "Uzbekistan sum",                                digits: MinorUnits::Digits(2),     units: Some((("sum", "sum"), Some(("tiyin", "tiyin")))),                     introduced: None,       replaces: None,        countries: &[ CountryCode::UZ ] },
+	CurrencyInfo { code: CurrencyCode::VED, name: "Venezuelan digital bolívar",                    digits: MinorUnits::Digits(2),     units: Some((("bolívar", "bolívares"), Some(("céntimo", "céntimos")))),      introduced: Some(2021), replaces: None,        countries: &[ CountryCode::VE ] },
+	CurrencyInfo { code: CurrencyCode::VES, name: "Venezuelan sovereign bolívar",                  digits: MinorUnits::Digits(2),     units: Some((("bolívar", "bolívares"), Some(("céntimo", "céntimos")))),      introduced: Some(2018), replaces: Some("VEF"), countries: &[ CountryCode::VE ] },
+	CurrencyInfo { code: CurrencyCode::VND, name: "Vietnamese đồng",                               digits: MinorUnits::Digits(0),     units: Some((("đồng", "đồng"), None)),                                       introduced: None,       replaces: None,        countries: &[ CountryCode::VN ] },
+	CurrencyInfo { code: CurrencyCode::VUV, name: "Vanuatu vatu",                                  digits: MinorUnits::Digits(0),     units: Some((("vatu", "vatu"), None)),                                       introduced: None,       replaces: None,        countries: &[ CountryCode::VU ] },
+	CurrencyInfo { code: CurrencyCode::WST, name: "Samoan tala",                                   digits: MinorUnits::Digits(2),     units: Some((("tala", "tala"), Some(("sene", "sene")))),                     introduced: None,       replaces: None,        countries: &[ CountryCode::WS ] },
+	CurrencyInfo { code: CurrencyCode::XAF, name: "CFA franc BEAC",                                digits: MinorUnits::Digits(0),     units: Some((("franc", "francs"), None)),                                    introduced: None,       replaces: None,        countries: &[ CountryCode::CF, CountryCode::CG, CountryCode::CM, CountryCode::GA, CountryCode::GQ, CountryCode::TD ] },
+	CurrencyInfo { code: CurrencyCode::XAG, name: "Silver (one troy ounce)",                       digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XAU, name: "Gold (one troy ounce)",                         digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XBA, name: "European Composite Unit (EURCO)",               digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XBB, name: "European Monetary Unit (E.M.U.-6)",             digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XBC, name: "European Unit of Account 9 (E.U.A.-9)",         digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XBD, name: "European Unit of Account 17 (E.U.A.-17)",       digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XCD, name: "East Caribbean dollar",                         digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: None,       replaces: None,        countries: &[ CountryCode::AG, CountryCode::AI, CountryCode::DM, CountryCode::GD, CountryCode::KN, CountryCode::LC, CountryCode::MS, CountryCode::VC ] },
+	CurrencyInfo { code: CurrencyCode::XDR, name: "Special drawing rights",                        digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XOF, name: "CFA franc BCEAO",                               digits: MinorUnits::Digits(0),     units: Some((("franc", "francs"), None)),                                    introduced: None,       replaces: None,        countries: &[ CountryCode::BF, CountryCode::BJ, CountryCode::CI, CountryCode::GW, CountryCode::ML, CountryCode::NE, CountryCode::SN, CountryCode::TG ] },
+	CurrencyInfo { code: CurrencyCode::XPD, name: "Palladium (one troy ounce)",                    digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XPF, name: "CFP franc (franc Pacifique)",                   digits: MinorUnits::Digits(0),     units: Some((("franc", "francs"), None)),                                    introduced: None,       replaces: None,        countries: &[ CountryCode::NC, CountryCode::PF, CountryCode::WF ] },
+	CurrencyInfo { code: CurrencyCode::XPT, name: "Platinum (one troy ounce)",                     digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XSU, name: "SUCRE",                                         digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XTS, name: "Code reserved for testing",                     digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XUA, name: "ADB Unit of Account",                           digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::XXX, name: "No currency",                                   digits: MinorUnits::NotApplicable, units: None,                                                                 introduced: None,       replaces: None,        countries: &[] },
+	CurrencyInfo { code: CurrencyCode::YER, name: "Yemeni rial",                                   digits: MinorUnits::Digits(2),     units: Some((("rial", "rials"), Some(("fils", "fils")))),                    introduced: None,       replaces: None,        countries: &[ CountryCode::YE ] },
+	CurrencyInfo { code: CurrencyCode::ZAR, name: "South African rand",                            digits: MinorUnits::Digits(2),     units: Some((("rand", "rand"), Some(("cent", "cents")))),                    introduced: Some(1961), replaces: None,        countries: &[ CountryCode::LS, CountryCode::NA, CountryCode::SZ, CountryCode::ZA ] },
+	CurrencyInfo { code: CurrencyCode::ZMW, name: "Zambian kwacha",                                digits: MinorUnits::Digits(2),     units: Some((("kwacha", "kwacha"), Some(("ngwee", "ngwee")))),               introduced: Some(2013), replaces: Some("ZMK"), countries: &[ CountryCode::ZM ] },
+	CurrencyInfo { code: CurrencyCode::ZWL, name: "Zimbabwean dollar (fifth)",                     digits: MinorUnits::Digits(2),     units: Some((("dollar", "dollars"), Some(("cent", "cents")))),               introduced: Some(2009), replaces: Some("ZWR"), countries: &[ CountryCode::ZW ] },
+];
+
+/// The entities that issue or manage the supranational currencies.
+/// 
+/// Most currencies are issued by the central bank of the country that uses
+/// them, and so are not listed here.
+/// 
+/// # Data sources
+/// 
+/// The entities are given in the ISO 4217 list, available from
+/// [the SIX Group](https://www.six-group.com/en/products-services/financial-information/data-standards.html),
+/// which is the maintenance agency for ISO 4217.
+/// 
+static ENTITIES: [(CurrencyCode, &str); 11] = [
+	(CurrencyCode::XAF, "Bank of Central African States (BEAC)"),
+	(CurrencyCode::XBA, "Bond Markets Unit"),
+	(CurrencyCode::XBB, "Bond Markets Unit"),
+	(CurrencyCode::XBC, "Bond Markets Unit"),
+	(CurrencyCode::XBD, "Bond Markets Unit"),
+	(CurrencyCode::XCD, "Eastern Caribbean Central Bank (ECCB)"),
+	(CurrencyCode::XDR, "International Monetary Fund (IMF)"),
+	(CurrencyCode::XOF, "Central Bank of West African States (BCEAO)"),
+	(CurrencyCode::XPF, "Overseas Issuing Institute (IEOM)"),
+	(CurrencyCode::XSU, "Unified System for Regional Compensation (SUCRE)"),
+	(CurrencyCode::XUA, "African Development Bank Group (AfDB)"),
+];
+
+/// The symbols of the currencies.
+/// 
+/// These are the symbols in common use for the major currencies, and for the
+/// currencies that share a symbol with them, such as the many dollars that use
+/// `$`. A currency may have several symbols, e.g. `$` and `C$` for the
+/// Canadian dollar, and a symbol may be used by several currencies. The
+/// entries are sorted by currency code, and then by symbol.
+/// 
+/// # See also
+/// 
+/// * [`Currency::from_symbol()`]
+/// * [`Currency::from_symbol_in_country()`]
+/// * [`Currency::symbols()`]
+/// 
+static SYMBOLS: [(&str, CurrencyCode); 75] = [
+	("$",    CurrencyCode::ARS),
+	("$",    CurrencyCode::AUD),
+	("A$",   CurrencyCode::AUD),
+	("AU$",  CurrencyCode::AUD),
+	("$",    CurrencyCode::BBD),
+	("Bds$", CurrencyCode::BBD),
+	("$",    CurrencyCode::BMD),
+	("$",    CurrencyCode::BND),
+	("B$",   CurrencyCode::BND),
+	("R$",   CurrencyCode::BRL),
+	("$",    CurrencyCode::BSD),
+	("$",    CurrencyCode::BZD),
+	("BZ$",  CurrencyCode::BZD),
+	("$",    CurrencyCode::CAD),
+	("C$",   CurrencyCode::CAD),
+	("CA$",  CurrencyCode::CAD),
+	("Fr.",  CurrencyCode::CHF),
+	("$",    CurrencyCode::CLP),
+	("¥",    CurrencyCode::CNY),
+	("元",    CurrencyCode::CNY),
+	("$",    CurrencyCode::COP),
+	("E£",   CurrencyCode::EGP),
+	("£",    CurrencyCode::EGP),
+	("€",    CurrencyCode::EUR),
+	("$",    CurrencyCode::FJD),
+	("FJ$",  CurrencyCode::FJD),
+	("£",    CurrencyCode::FKP),
+	("£",    CurrencyCode::GBP),
+	("£",    CurrencyCode::GIP),
+	("$",    CurrencyCode::GYD),
+	("G$",   CurrencyCode::GYD),
+	("$",    CurrencyCode::HKD),
+	("HK$",  CurrencyCode::HKD),
+	("₪",    CurrencyCode::ILS),
+	("₹",    CurrencyCode::INR),
+	("$",    CurrencyCode::JMD),
+	("J$",   CurrencyCode::JMD),
+	("¥",    CurrencyCode::JPY),
+	("円",    CurrencyCode::JPY),
+	("₩",    CurrencyCode::KPW),
+	("₩",    CurrencyCode::KRW),
+	("$",    CurrencyCode::KYD),
+	("CI$",  CurrencyCode::KYD),
+	("$",    CurrencyCode::LRD),
+	("L$",   CurrencyCode::LRD),
+	("$",    CurrencyCode::MXN),
+	("Mex$", CurrencyCode::MXN),
+	("$",    CurrencyCode::NAD),
+	("N$",   CurrencyCode::NAD),
+	("₦",    CurrencyCode::NGN),
+	("$",    CurrencyCode::NZD),
+	("NZ$",  CurrencyCode::NZD),
+	("₱",    CurrencyCode::PHP),
+	("zł",   CurrencyCode::PLN),
+	("₽",    CurrencyCode::RUB),
+	("$",    CurrencyCode::SBD),
+	("SI$",  CurrencyCode::SBD),
+	("$",    CurrencyCode::SGD),
+	("S$",   CurrencyCode::SGD),
+	("£",    CurrencyCode::SHP),
+	("$",    CurrencyCode::SRD),
+	("Sr$",  CurrencyCode::SRD),
+	("฿",    CurrencyCode::THB),
+	("₺",    CurrencyCode::TRY),
+	("$",    CurrencyCode::TTD),
+	("TT$",  CurrencyCode::TTD),
+	("$",    CurrencyCode::TWD),
+	("NT$",  CurrencyCode::TWD),
+	("₴",    CurrencyCode::UAH),
+	("$",    CurrencyCode::USD),
+	("US$",  CurrencyCode::USD),
+	("₫",    CurrencyCode::VND),
+	("$",    CurrencyCode::XCD),
+	("EC$",  CurrencyCode::XCD),
+	("R",    CurrencyCode::ZAR),
+];
+
+/// The alternative names of the currencies.
+/// 
+/// These are common synonyms and colloquial names that are not part of ISO
+/// 4217, e.g. "RMB" for the renminbi, or "Sterling" for the pound sterling.
+/// Names that could refer to several current currencies, such as "Dollar" or
+/// "Peso", are not included. The entries are sorted by currency code, and then
+/// by name.
+/// 
+/// # See also
+/// 
+/// * [`Currency::alternative_names()`]
+/// * [`Currency::find()`]
+/// 
+static ALIASES: [(&str, CurrencyCode); 26] = [
+	("Aussie dollar",      CurrencyCode::AUD),
+	("Real",               CurrencyCode::BRL),
+	("Loonie",             CurrencyCode::CAD),
+	("Swissie",            CurrencyCode::CHF),
+	("Chinese yuan",       CurrencyCode::CNY),
+	("RMB",                CurrencyCode::CNY),
+	("Yuan",               CurrencyCode::CNY),
+	("British pound",      CurrencyCode::GBP),
+	("Sterling",           CurrencyCode::GBP),
+	("HK$",                CurrencyCode::HKD),
+	("NIS",                CurrencyCode::ILS),
+	("New Israeli shekel", CurrencyCode::ILS),
+	("Shekel",             CurrencyCode::ILS),
+	("Yen",                CurrencyCode::JPY),
+	("Korean won",         CurrencyCode::KRW),
+	("Kiwi dollar",        CurrencyCode::NZD),
+	("Zloty",              CurrencyCode::PLN),
+	("Rouble",             CurrencyCode::RUB),
+	("Russian rouble",     CurrencyCode::RUB),
+	("NT$",                CurrencyCode::TWD),
+	("NTD",                CurrencyCode::TWD),
+	("Taiwan dollar",      CurrencyCode::TWD),
+	("Greenback",          CurrencyCode::USD),
+	("US dollar",          CurrencyCode::USD),
+	("US$",                CurrencyCode::USD),
+	("Rand",               CurrencyCode::ZAR),
+];
+
+/// The default classifications of the currencies that are not exotic.
+/// 
+/// The majors are the eight most widely-traded currencies, and the minors are
+/// the other currencies of developed markets that are commonly traded
+/// against them. The entries are sorted by currency code.
+/// 
+/// # See also
+/// 
+/// * [`Currency::classification()`]
+/// 
+static CLASSES: [(CurrencyCode, CurrencyClass); 14] = [
+	(CurrencyCode::AUD, CurrencyClass::Major),
+	(CurrencyCode::CAD, CurrencyClass::Major),
+	(CurrencyCode::CHF, CurrencyClass::Major),
+	(CurrencyCode::CNY, CurrencyClass::Minor),
+	(CurrencyCode::DKK, CurrencyClass::Minor),
+	(CurrencyCode::EUR, CurrencyClass::Major),
+	(CurrencyCode::GBP, CurrencyClass::Major),
+	(CurrencyCode::HKD, CurrencyClass::Minor),
+	(CurrencyCode::JPY, CurrencyClass::Major),
+	(CurrencyCode::NOK, CurrencyClass::Minor),
+	(CurrencyCode::NZD, CurrencyClass::Major),
+	(CurrencyCode::SEK, CurrencyClass::Minor),
+	(CurrencyCode::SGD, CurrencyClass::Minor),
+	(CurrencyCode::USD, CurrencyClass::Major),
+];
+
+/// The increments that prices are typically set in, in minor units.
+/// 
+/// These are the currencies for which prices are not typically set to the
+/// minor unit, as the smallest coins are no longer in use, or were never
+/// commonly used. All other currencies use an increment of one minor unit.
+/// The entries are sorted by currency code.
+/// 
+/// # See also
+/// 
+/// * [`Currency::price_increment()`]
+/// 
+static PRICE_INCREMENTS: [(CurrencyCode, u32); 8] = [
+	(CurrencyCode::CHF, 5),
+	(CurrencyCode::CZK, 100),
+	(CurrencyCode::HUF, 100),
+	(CurrencyCode::IDR, 100),
+	(CurrencyCode::KRW, 10),
+	(CurrencyCode::NOK, 100),
+	(CurrencyCode::SEK, 100),
+	(CurrencyCode::TWD, 100),
+];
+
+/// The dates on which the data for currencies last changed.
+/// 
+/// These are the ISO 8601 dates on which changes to the data for a currency
+/// took effect, such as its introduction, e.g. the new leone, or a change to
+/// the countries using it, e.g. Croatia adopting the euro. Currencies without
+/// a recorded change are not listed. The entries are sorted by code.
+/// 
+/// # See also
+/// 
+/// * [`Currency::last_changed()`]
+/// * [`Currency::changes_since()`]
+/// 
+static CHANGES: [(CurrencyCode, &str); 6] = [
+	(CurrencyCode::EUR, "2023-01-01"),
+	(CurrencyCode::MRU, "2018-01-01"),
+	(CurrencyCode::SLE, "2022-07-01"),
+	(CurrencyCode::STN, "2018-01-01"),
+	(CurrencyCode::VED, "2021-10-01"),
+	(CurrencyCode::VES, "2018-08-20"),
+];
+
+/// The currencies, indexed by normalised name.
+/// 
+/// The names are normalised using [`normalize()`], so that parsing by name is a
+/// single lookup. The alternative names from [`ALIASES`] are included, but do
+/// not replace any of the standard names.
+/// 
+static NAMES: LazyLock<SortedMap<String, Currency>> = LazyLock::new(|| {
+	SortedMap::new(
+		CURRENCIES.iter().map(|info| (normalize(info.name), info.code.currency()))
+			.chain(ALIASES.iter().map(|&(alias, code)| (normalize(alias), code.currency())))
+	)
 });
 
+/// The languages of the countries where each currency is used.
+/// 
+/// This is derived from the country data, and is indexed by [`Currency`]. Each
+/// list is sorted and free of duplicates.
+/// 
+static LANGUAGES: LazyLock<Vec<Vec<LanguageCode>>> = LazyLock::new(|| {
+	CURRENCIES.iter().map(|info| {
+		let mut languages: Vec<_> = info.countries.iter().flat_map(|code| code.country().languages()).copied().collect();
+		languages.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+		languages.dedup();
+		languages
+	}).collect()
+});
+
+
+
+//		Type aliases
+
+/// The singular and plural forms of the name of a unit of a currency.
+type UnitForms = (&'static str, &'static str);
+
 
 
 //		Enums
@@ -258,6 +584,15 @@ static CURRENCIES: LazyLock<HashMap<Currency, CurrencyInfo>> = LazyLock::new(||
 /// Each currency is identified by a currency code, which can be expressed as
 /// three letters or three numbers, as defined by the ISO 4217 standard.
 /// 
+/// # Discriminants
+/// 
+/// Each variant has an explicit discriminant, which is stable across versions
+/// of this crate, and can be obtained by casting with `as u16`. This makes it
+/// safe to store the discriminant, e.g. in a database. Existing values will
+/// never be changed or reused, and new currencies will be given new values. The
+/// ordering of Currency values follows the discriminants, and is therefore also
+/// stable. Note that the discriminant is not the numeric currency code.
+/// 
 /// # Data sources
 /// 
 /// The list of codes and other currency information is available from
@@ -268,555 +603,610 @@ static CURRENCIES: LazyLock<HashMap<Currency, CurrencyInfo>> = LazyLock::new(||
 /// 
 /// * [`CurrencyCode`]
 /// 
-#[derive(Clone, Copy, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[repr(u16)]
 #[serde(into = "String", try_from = "String")]
 #[non_exhaustive]
 pub enum Currency {
 	/// United Arab Emirates dirham.
-	AED,
+	AED = 0,
 	
 	/// Afghan afghani.
-	AFN,
+	AFN = 1,
 	
 	/// Albanian lek.
-	ALL,
+	ALL = 2,
 	
 	/// Armenian dram.
-	AMD,
+	AMD = 3,
 	
 	/// Netherlands Antillean guilder.
-	ANG,
+	ANG = 4,
 	
 	/// Angolan kwanza.
-	AOA,
+	AOA = 5,
 	
 	/// Argentine peso.
-	ARS,
+	ARS = 6,
 	
 	/// Australian dollar.
-	AUD,
+	AUD = 7,
 	
 	/// Aruban florin.
-	AWG,
+	AWG = 8,
 	
 	/// Azerbaijani manat.
-	AZN,
+	AZN = 9,
 	
 	/// Bosnia and Herzegovina convertible mark.
-	BAM,
+	BAM = 10,
 	
 	/// Barbados dollar.
-	BBD,
+	BBD = 11,
 	
 	/// Bangladeshi taka.
-	BDT,
+	BDT = 12,
 	
 	/// Bulgarian lev.
-	BGN,
+	BGN = 13,
 	
 	/// Bahraini dinar.
-	BHD,
+	BHD = 14,
 	
 	/// Burundian franc.
-	BIF,
+	BIF = 15,
 	
 	/// Bermudian dollar.
-	BMD,
+	BMD = 16,
 	
 	/// Brunei dollar.
-	BND,
+	BND = 17,
 	
 	/// Boliviano.
-	BOB,
+	BOB = 18,
 	
 	/// Bolivian Mvdol.
-	BOV,
+	BOV = 19,
 	
 	/// Brazilian real.
-	BRL,
+	BRL = 20,
 	
 	/// Bahamian dollar.
-	BSD,
+	BSD = 21,
 	
 	/// Bhutanese ngultrum.
-	BTN,
+	BTN = 22,
 	
 	/// Botswana pula.
-	BWP,
+	BWP = 23,
 	
 	/// Belarusian ruble.
-	BYN,
+	BYN = 24,
 	
 	/// Belize dollar.
-	BZD,
+	BZD = 25,
 	
 	/// Canadian dollar.
-	CAD,
+	CAD = 26,
 	
 	/// Congolese franc.
-	CDF,
+	CDF = 27,
 	
 	/// WIR euro.
-	CHE,
+	CHE = 28,
 	
 	/// Swiss franc.
-	CHF,
+	CHF = 29,
 	
 	/// WIR franc.
-	CHW,
+	CHW = 30,
 	
 	/// Unidad de Fomento.
-	CLF,
+	CLF = 31,
 	
 	/// Chilean peso.
-	CLP,
+	CLP = 32,
 	
 	/// Renminbi.
-	CNY,
+	CNY = 33,
 	
 	/// Colombian peso.
-	COP,
+	COP = 34,
 	
 	/// Unidad de Valor Real (UVR).
-	COU,
+	COU = 35,
 	
 	/// Costa Rican colon.
-	CRC,
+	CRC = 36,
 	
 	/// Cuban peso.
-	CUP,
+	CUP = 37,
 	
 	/// Cape Verdean escudo.
-	CVE,
+	CVE = 38,
 	
 	/// Czech koruna.
-	CZK,
+	CZK = 39,
 	
 	/// Djiboutian franc.
-	DJF,
+	DJF = 40,
 	
 	/// Danish krone.
-	DKK,
+	DKK = 41,
 	
 	/// Dominican peso.
-	DOP,
+	DOP = 42,
 	
 	/// Algerian dinar.
-	DZD,
+	DZD = 43,
 	
 	/// Egyptian pound.
-	EGP,
+	EGP = 44,
 	
 	/// Eritrean nakfa.
-	ERN,
+	ERN = 45,
 	
 	/// Ethiopian birr.
-	ETB,
+	ETB = 46,
 	
 	/// Euro.
-	EUR,
+	EUR = 47,
 	
 	/// Fiji dollar.
-	FJD,
+	FJD = 48,
 	
 	/// Falkland Islands pound.
-	FKP,
+	FKP = 49,
 	
 	/// Pound sterling.
-	GBP,
+	GBP = 50,
 	
 	/// Georgian lari.
-	GEL,
+	GEL = 51,
 	
 	/// Ghanaian cedi.
-	GHS,
+	GHS = 52,
 	
 	/// Gibraltar pound.
-	GIP,
+	GIP = 53,
 	
 	/// Gambian dalasi.
-	GMD,
+	GMD = 54,
 	
 	/// Guinean franc.
-	GNF,
+	GNF = 55,
 	
 	/// Guatemalan quetzal.
-	GTQ,
+	GTQ = 56,
 	
 	/// Guyanese dollar.
-	GYD,
+	GYD = 57,
 	
 	/// Hong Kong dollar.
-	HKD,
+	HKD = 58,
 	
 	/// Honduran lempira.
-	HNL,
+	HNL = 59,
 	
 	/// Haitian gourde.
-	HTG,
+	HTG = 60,
 	
 	/// Hungarian forint.
-	HUF,
+	HUF = 61,
 	
 	/// Indonesian rupiah.
-	IDR,
+	IDR = 62,
 	
 	/// Israeli new shekel.
-	ILS,
+	ILS = 63,
 	
 	/// Indian rupee.
-	INR,
+	INR = 64,
 	
 	/// Iraqi dinar.
-	IQD,
+	IQD = 65,
 	
 	/// Iranian rial.
-	IRR,
+	IRR = 66,
 	
 	/// Icelandic króna.
-	ISK,
+	ISK = 67,
 	
 	/// Jamaican dollar.
-	JMD,
+	JMD = 68,
 	
 	/// Jordanian dinar.
-	JOD,
+	JOD = 69,
 	
 	/// Japanese yen.
-	JPY,
+	JPY = 70,
 	
 	/// Kenyan shilling.
-	KES,
+	KES = 71,
 	
 	/// Kyrgyzstani som.
-	KGS,
+	KGS = 72,
 	
 	/// Cambodian riel.
-	KHR,
+	KHR = 73,
 	
 	/// Comoro franc.
-	KMF,
+	KMF = 74,
 	
 	/// North Korean won.
-	KPW,
+	KPW = 75,
 	
 	/// South Korean won.
-	KRW,
+	KRW = 76,
 	
 	/// Kuwaiti dinar.
-	KWD,
+	KWD = 77,
 	
 	/// Cayman Islands dollar.
-	KYD,
+	KYD = 78,
 	
 	/// Kazakhstani tenge.
-	KZT,
+	KZT = 79,
 	
 	/// Lao kip.
-	LAK,
+	LAK = 80,
 	
 	/// Lebanese pound.
-	LBP,
+	LBP = 81,
 	
 	/// Sri Lankan rupee.
-	LKR,
+	LKR = 82,
 	
 	/// Liberian dollar.
-	LRD,
+	LRD = 83,
 	
 	/// Lesotho loti.
-	LSL,
+	LSL = 84,
 	
 	/// Libyan dinar.
-	LYD,
+	LYD = 85,
 	
 	/// Moroccan dirham.
-	MAD,
+	MAD = 86,
 	
 	/// Moldovan leu.
-	MDL,
+	MDL = 87,
 	
 	/// Malagasy ariary.
-	MGA,
+	MGA = 88,
 	
 	/// Macedonian denar.
-	MKD,
+	MKD = 89,
 	
 	/// Myanmar kyat.
-	MMK,
+	MMK = 90,
 	
 	/// Mongolian tögrög.
-	MNT,
+	MNT = 91,
 	
 	/// Macanese pataca.
-	MOP,
+	MOP = 92,
 	
 	/// Mauritanian ouguiya.
-	MRU,
+	MRU = 93,
 	
 	/// Mauritian rupee.
-	MUR,
+	MUR = 94,
 	
 	/// Maldivian rufiyaa.
-	MVR,
+	MVR = 95,
 	
 	/// Malawian kwacha.
-	MWK,
+	MWK = 96,
 	
 	/// Mexican peso.
-	MXN,
+	MXN = 97,
 	
 	/// Mexican Unidad de Inversion (UDI).
-	MXV,
+	MXV = 98,
 	
 	/// Malaysian ringgit.
-	MYR,
+	MYR = 99,
 	
 	/// Mozambican metical.
-	MZN,
+	MZN = 100,
 	
 	/// Namibian dollar.
-	NAD,
+	NAD = 101,
 	
 	/// Nigerian naira.
-	NGN,
+	NGN = 102,
 	
 	/// Nicaraguan córdoba.
-	NIO,
+	NIO = 103,
 	
 	/// Norwegian krone.
-	NOK,
+	NOK = 104,
 	
 	/// Nepalese rupee.
-	NPR,
+	NPR = 105,
 	
 	/// New Zealand dollar.
-	NZD,
+	NZD = 106,
 	
 	/// Omani rial.
-	OMR,
+	OMR = 107,
 	
 	/// Panamanian balboa.
-	PAB,
+	PAB = 108,
 	
 	/// Peruvian sol.
-	PEN,
+	PEN = 109,
 	
 	/// Papua New Guinean kina.
-	PGK,
+	PGK = 110,
 	
 	/// Philippine peso.
-	PHP,
+	PHP = 111,
 	
 	/// Pakistani rupee.
-	PKR,
+	PKR = 112,
 	
 	/// Polish złoty.
-	PLN,
+	PLN = 113,
 	
 	/// Paraguayan guaraní.
-	PYG,
+	PYG = 114,
 	
 	/// Qatari riyal.
-	QAR,
+	QAR = 115,
 	
 	/// Romanian leu.
-	RON,
+	RON = 116,
 	
 	/// Serbian dinar.
-	RSD,
+	RSD = 117,
 	
 	/// Russian ruble.
-	RUB,
+	RUB = 118,
 	
 	/// Rwandan franc.
-	RWF,
+	RWF = 119,
 	
 	/// Saudi riyal.
-	SAR,
+	SAR = 120,
 	
 	/// Solomon Islands dollar.
-	SBD,
+	SBD = 121,
 	
 	/// Seychelles rupee.
-	SCR,
+	SCR = 122,
 	
 	/// Sudanese pound.
-	SDG,
+	SDG = 123,
 	
 	/// Swedish krona.
-	SEK,
+	SEK = 124,
 	
 	/// Singapore dollar.
-	SGD,
+	SGD = 125,
 	
 	/// Saint Helena pound.
-	SHP,
+	SHP = 126,
 	
 	/// Sierra Leonean leone (new leone).
-	SLE,
+	SLE = 127,
 	
 	/// Sierra Leonean leone (old leone).
-	SLL,
+	SLL = 128,
 	
 	/// Somali shilling.
-	SOS,
+	SOS = 129,
 	
 	/// Surinamese dollar.
-	SRD,
+	SRD = 130,
 	
 	/// South Sudanese pound.
-	SSP,
+	SSP = 131,
 	
 	/// São Tomé and Príncipe dobra.
-	STN,
+	STN = 132,
 	
 	/// Salvadoran colón.
-	SVC,
+	SVC = 133,
 	
 	/// Syrian pound.
-	SYP,
+	SYP = 134,
 	
 	/// Swazi lilangeni.
-	SZL,
+	SZL = 135,
 	
 	/// Thai baht.
-	THB,
+	THB = 136,
 	
 	/// Tajikistani somoni.
-	TJS,
+	TJS = 137,
 	
 	/// Turkmenistan manat.
-	TMT,
+	TMT = 138,
 	
 	/// Tunisian dinar.
-	TND,
+	TND = 139,
 	
 	/// Tongan paʻanga.
-	TOP,
+	TOP = 140,
 	
 	/// Turkish lira.
-	TRY,
+	TRY = 141,
 	
 	/// Trinidad and Tobago dollar.
-	TTD,
+	TTD = 142,
 	
 	/// New Taiwan dollar.
-	TWD,
+	TWD = 143,
 	
 	/// Tanzanian shilling.
-	TZS,
+	TZS = 144,
 	
 	/// Ukrainian hryvnia.
-	UAH,
+	UAH = 145,
 	
 	/// Ugandan shilling.
-	UGX,
+	UGX = 146,
 	
 	/// United States dollar.
-	USD,
+	USD = 147,
 	
 	/// United States dollar (next day).
-	USN,
+	USN = 148,
 	
 	/// Uruguay Peso en Unidades Indexadas (URUIURUI).
-	UYI,
+	UYI = 149,
 	
 	/// Uruguayan peso.
-	UYU,
+	UYU = 150,
 	
 	/// Unidad previsional.
-	UYW,
+	UYW = 151,
 	
 	/// Uzbekistan sum.
-	UZS,
+	UZS = 152,
 	
 	/// Venezuelan digital bolívar.
-	VED,
+	VED = 153,
 	
 	/// Venezuelan sovereign bolívar.
-	VES,
+	VES = 154,
 	
 	/// Vietnamese đồng.
-	VND,
+	VND = 155,
 	
 	/// Vanuatu vatu.
-	VUV,
+	VUV = 156,
 	
 	/// Samoan tala.
-	WST,
+	WST = 157,
 	
 	/// CFA franc BEAC.
-	XAF,
+	XAF = 158,
 	
 	/// Silver (one troy ounce).
-	XAG,
+	XAG = 159,
 	
 	/// Gold (one troy ounce).
-	XAU,
+	XAU = 160,
 	
 	/// European Composite Unit (EURCO).
-	XBA,
+	XBA = 161,
 	
 	/// European Monetary Unit (E.M.U.-6).
-	XBB,
+	XBB = 162,
 	
 	/// European Unit of Account 9 (E.U.A.-9).
-	XBC,
+	XBC = 163,
 	
 	/// European Unit of Account 17 (E.U.A.-17).
-	XBD,
+	XBD = 164,
 	
 	/// East Caribbean dollar.
-	XCD,
+	XCD = 165,
 	
 	/// Special drawing rights.
-	XDR,
+	XDR = 166,
 	
 	/// CFA franc BCEAO.
-	XOF,
+	XOF = 167,
 	
 	/// Palladium (one troy ounce).
-	XPD,
+	XPD = 168,
 	
 	/// CFP franc (franc Pacifique).
-	XPF,
+	XPF = 169,
 	
 	/// Platinum (one troy ounce).
-	XPT,
+	XPT = 170,
 	
 	/// SUCRE.
-	XSU,
+	XSU = 171,
 	
 	/// Code reserved for testing.
-	XTS,
+	XTS = 172,
 	
 	/// ADB Unit of Account.
-	XUA,
+	XUA = 173,
 	
 	/// No currency.
-	XXX,
+	XXX = 174,
 	
 	/// Yemeni rial.
-	YER,
+	YER = 175,
 	
 	/// South African rand.
-	ZAR,
+	ZAR = 176,
 	
 	/// Zambian kwacha.
-	ZMW,
+	ZMW = 177,
 	
 	/// Zimbabwean dollar (fifth).
-	ZWL,
+	ZWL = 178,
 }
 
 //󰭅		Currency																
 impl Currency {
 	//		all																	
 	/// Returns all the currencies.
+	#[must_use]
 	pub fn all() -> Vec<Self> {
-		CURRENCIES.keys().copied().collect()
+		CURRENCIES.iter().map(|info| info.code.currency()).collect()
+	}
+	
+	//		changes_since														
+	/// Returns the currencies whose data has changed since a version.
+	/// 
+	/// The version is a [`DATA_VERSION`](crate::DATA_VERSION), i.e. an ISO 8601
+	/// date, and the currencies returned are those whose
+	/// [`last_changed()`](Self::last_changed()) date is after it, in order of
+	/// code. Downstream caches built against an earlier version of the data can
+	/// use this to refresh only the affected entries.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `version` - The version of the data to compare against.
+	/// 
+	#[must_use]
+	pub fn changes_since(version: &str) -> Vec<Self> {
+		CHANGES.iter().filter(|&&(_, changed)| changed > version).map(|&(code, _)| code.currency()).collect()
+	}
+	
+	//		records																
+	/// Returns the records of all the currencies.
+	/// 
+	/// This provides all of the information about every currency in one pass.
+	/// For more information, see [`CurrencyRecord`].
+	/// 
+	#[must_use]
+	pub fn records() -> impl ExactSizeIterator<Item = CurrencyRecord> {
+		CURRENCIES.iter().map(|info| info.code.currency().record())
+	}
+	
+	//		record																
+	/// Returns the record of the currency.
+	/// 
+	/// This provides all of the information about the currency in one go. For
+	/// more information, see [`CurrencyRecord`].
+	/// 
+	#[must_use]
+	pub fn record(&self) -> CurrencyRecord {
+		let info = self.info();
+		CurrencyRecord {
+			currency:   *self,
+			code:       info.code,
+			numeric:    info.code.numeric(),
+			name:       info.name,
+			digits:     info.digits,
+			unit_names: self.unit_names(),
+			introduced: info.introduced,
+			replaces:   info.replaces,
+			entity:     self.entity(),
+			countries:  info.countries,
+			languages:  self.languages(),
+		}
 	}
 	
 	//		info																
@@ -830,14 +1220,174 @@ impl Currency {
 		#[expect(clippy::unwrap_used, reason = "Infallible")]
 		//	This should be infallible. If it isn't, then the data is wrong, and one
 		//	of the currencies is missing from the list, which is a bug.
-		CURRENCIES.get(&self).unwrap()
+		CURRENCIES.get(self as usize).unwrap()
 	}
 	
 	//		name																
 	/// Returns the name of the currency.
 	#[must_use]
-	pub fn name(&self) -> &str {
-		&self.info().name
+	pub fn name(&self) -> &'static str {
+		self.info().name
+	}
+	
+	//		display_as															
+	/// Returns the currency for display in a chosen style.
+	/// 
+	/// This allows the currency to be displayed as its code, its name, or both,
+	/// e.g. `format!("{}", Currency::EUR.display_as(DisplayStyle::CodeAndName))`.
+	/// For more information, see [`DisplayStyle`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `style` - The style to display the currency in.
+	/// 
+	#[must_use]
+	pub const fn display_as(&self, style: DisplayStyle) -> Styled<Self> {
+		Styled::new(*self, style)
+	}
+	
+	//		alternative_names													
+	/// Returns the alternative names of the currency.
+	/// 
+	/// These are common synonyms and colloquial names that are not part of ISO
+	/// 4217, e.g. "RMB" and "Yuan" for the renminbi. They are accepted when
+	/// parsing a currency by name.
+	/// 
+	#[must_use]
+	pub fn alternative_names(&self) -> Vec<&'static str> {
+		ALIASES.iter().filter(|&&(_, code)| code == self.code()).map(|&(alias, _)| alias).collect()
+	}
+	
+	//		find																
+	/// Finds a currency by code, name, or alternative name.
+	/// 
+	/// This is a single point of resolution for the various ways in which a
+	/// currency may be referred to, e.g. `CNY`, `Renminbi`, or `RMB`. Codes are
+	/// tried first, followed by names, which are matched in the same way as
+	/// when parsing, including the [`alternative_names()`](Self::alternative_names()).
+	/// 
+	/// # Parameters
+	/// 
+	/// * `s` - The code or name of the currency.
+	/// 
+	#[must_use]
+	pub fn find(s: &str) -> Option<Self> {
+		CurrencyCode::from_code(s.trim())
+			.map(|code| code.currency())
+			.or_else(|| NAMES.get(&normalize(s)).copied())
+	}
+	
+	//		from_symbol															
+	/// Finds the currencies that use a symbol.
+	/// 
+	/// Many symbols are shared by several currencies, e.g. `$` is used by the
+	/// United States dollar, the Canadian dollar, the Australian dollar, and
+	/// others, and so all of the candidates are returned, in order of currency
+	/// code. An empty list is returned if the symbol is not known. To choose
+	/// between the candidates, use [`from_symbol_in_country()`](Self::from_symbol_in_country()).
+	/// 
+	/// # Parameters
+	/// 
+	/// * `symbol` - The currency symbol, e.g. `$` or `£`.
+	/// 
+	#[must_use]
+	pub fn from_symbol(symbol: &str) -> Vec<Self> {
+		let trimmed = symbol.trim();
+		SYMBOLS.iter().filter(|&&(sym, _)| sym == trimmed).map(|&(_, code)| code.currency()).collect()
+	}
+	
+	//		from_symbol_in_country												
+	/// Finds the currency that a symbol refers to in a specific country.
+	/// 
+	/// This disambiguates a symbol that is shared by several currencies, by
+	/// choosing the candidate that is used in the given country, e.g. `$` in
+	/// Canada is the Canadian dollar. If the symbol is not used by any currency
+	/// of the country, `None` is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `symbol`  - The currency symbol, e.g. `$` or `£`.
+	/// * `country` - The country in which the symbol is used.
+	/// 
+	#[must_use]
+	pub fn from_symbol_in_country(symbol: &str, country: CountryCode) -> Option<Self> {
+		let alpha2 = country.to_alpha2();
+		Self::from_symbol(symbol).into_iter().find(|currency| currency.countries().binary_search(&alpha2).is_ok())
+	}
+	
+	//		fuzzy_match															
+	/// Finds the currencies with names similar to the given name.
+	/// 
+	/// Each currency is scored against the name using
+	/// [`similarity()`](crate::text::similarity()), and those scoring at least
+	/// the threshold are returned, along with their scores, best match first.
+	/// Useful thresholds are typically between `0.7` and `0.9`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`      - The name to match, which may contain typos.
+	/// * `threshold` - The minimum score, between `0.0` and `1.0`.
+	/// 
+	#[must_use]
+	pub fn fuzzy_match(name: &str, threshold: f32) -> Vec<(Self, f32)> {
+		let mut matches: Vec<_> = Self::all()
+			.into_iter()
+			.map(|currency| (currency, similarity(name, currency.name())))
+			.filter(|&(_, score)| score >= threshold)
+			.collect();
+		matches.sort_by(|a, b| b.1.total_cmp(&a.1));
+		matches
+	}
+	
+	//		try_from_any														
+	/// Resolves a JSON value to a currency.
+	/// 
+	/// This is intended for schemaless ingestion, where the form of the value
+	/// is not known in advance. The value can be a string holding a name or a
+	/// code, or a number holding a numeric code, e.g. `"Pound sterling"`,
+	/// `"GBP"`, or `826`. Names are parsed in the same way as by [`FromStr`].
+	/// 
+	/// This is only available when the `json` feature is enabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The JSON value to resolve.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the value does not identify a currency.
+	/// 
+	#[cfg(feature = "json")]
+	pub fn try_from_any(value: &Value) -> Result<Self, String> {
+		value.as_str()
+			.and_then(|string| string.parse().ok().or_else(|| CurrencyCode::from_str(string).ok().map(|code| code.currency())))
+			.or_else(|| value.as_u64()
+				.and_then(|int| u16::try_from(int).ok())
+				.and_then(|int| CurrencyCode::try_from(int).ok())
+				.map(|code| code.currency())
+			)
+			.ok_or_else(|| format!("Invalid Currency: {value}"))
+	}
+	
+	//		parse_with															
+	/// Parses a currency from text, according to a set of options.
+	/// 
+	/// This allows the leniency of the parsing to be chosen, e.g. whether codes
+	/// are accepted in any case, and whether names are accepted. For more
+	/// information, see [`ParseOptions`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `s`       - The text to parse.
+	/// * `options` - The options to parse with.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the text does not identify a currency with the
+	/// options given.
+	/// 
+	pub fn parse_with(s: &str, options: &ParseOptions) -> Result<Self, String> {
+		options.parse(s).ok_or_else(|| format!("Invalid Currency: {s}"))
 	}
 	
 	//		code																
@@ -849,24 +1399,379 @@ impl Currency {
 	
 	//		digits																
 	/// Returns the number of digits after the decimal point.
+	/// 
+	/// For most currencies, this is the number of digits used for the minor
+	/// unit, e.g. `2` for pence in pounds sterling, or `0` for the yen, which
+	/// has no minor unit. For some, such as gold and the special drawing right,
+	/// the concept of a minor unit does not apply, and this is indicated by
+	/// [`MinorUnits::NotApplicable`] rather than zero.
+	/// 
 	#[must_use]
-	pub fn digits(&self) -> u8 {
+	pub fn digits(&self) -> MinorUnits {
 		self.info().digits
 	}
 	
+	//		unit_names															
+	/// Returns the names of the units of the currency.
+	/// 
+	/// These are the everyday English names of the major and minor units, in
+	/// singular and plural forms, e.g. "pound" and "pounds" with "penny" and
+	/// "pence" for pounds sterling, so that amounts can be expressed in words.
+	/// Currencies without a minor unit, such as the yen, have no minor unit
+	/// names. Currencies that are not used as money, such as fund codes and
+	/// precious metals, return `None`. For more information, see
+	/// [`UnitNames`].
+	/// 
+	#[must_use]
+	pub fn unit_names(&self) -> Option<UnitNames> {
+		self.info().units.map(|(major, minor)| UnitNames {
+			major: UnitName { singular: major.0, plural: major.1 },
+			minor: minor.map(|(singular, plural)| UnitName { singular, plural }),
+		})
+	}
+	
+	//		classification														
+	/// Returns the classification of the currency by liquidity.
+	/// 
+	/// This groups currencies in the way commonly used by trading interfaces,
+	/// into the majors, e.g. the US dollar and the euro, the minors, e.g. the
+	/// Swedish krona and the Singapore dollar, and the exotics, which are all
+	/// other currencies. As there is no single standard for this grouping, the
+	/// default can be overridden using the [`Registry`](crate::registry::Registry).
+	/// For more information, see [`CurrencyClass`].
+	/// 
+	#[must_use]
+	pub fn classification(&self) -> CurrencyClass {
+		registry::currency_class(*self)
+			.or_else(|| map::get(&CLASSES, &self.code()).copied())
+			.unwrap_or(CurrencyClass::Exotic)
+	}
+	
+	//		round_decimal														
+	/// Rounds an amount to the number of digits used by the currency.
+	/// 
+	/// The amount is rounded to [`digits()`](Self::digits()) decimal places,
+	/// using banker's rounding, i.e. midpoints are rounded to the nearest even
+	/// digit. For example, `12.345` in pounds sterling becomes `12.34`, and
+	/// `12.5` in yen becomes `12`. Where minor units are not applicable, the
+	/// amount is rounded to whole units, as given by
+	/// [`MinorUnits::decimal_places()`].
+	/// 
+	/// This is only available when the `rust_decimal` feature is enabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `amount` - The amount to round.
+	/// 
+	#[cfg(feature = "rust_decimal")]
+	#[must_use]
+	pub fn round_decimal(&self, amount: Decimal) -> Decimal {
+		amount.round_dp_with_strategy(u32::from(self.digits().decimal_places()), RoundingStrategy::MidpointNearestEven)
+	}
+	
+	//		round_bigdecimal													
+	/// Rounds an amount to the number of digits used by the currency.
+	/// 
+	/// This behaves in the same way as `round_decimal()`, but for [`BigDecimal`]
+	/// amounts. The result always has a scale equal to
+	/// [`MinorUnits::decimal_places()`] for the currency.
+	/// 
+	/// This is only available when the `bigdecimal` feature is enabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `amount` - The amount to round.
+	/// 
+	#[cfg(feature = "bigdecimal")]
+	#[must_use]
+	pub fn round_bigdecimal(&self, amount: &BigDecimal) -> BigDecimal {
+		amount.with_scale_round(i64::from(self.digits().decimal_places()), RoundingMode::HalfEven)
+	}
+	
+	//		price_increment														
+	/// Returns the increment that prices in the currency are typically set in.
+	/// 
+	/// This is given in minor units of the currency, and is usually `1`, i.e.
+	/// prices are set to the minor unit, e.g. the penny or the cent. For
+	/// currencies whose smallest coins are no longer in use, prices are
+	/// typically set in larger increments, e.g. `5` for the Swiss franc, or
+	/// `100` for the Swedish krona, where prices are set in whole kronor. For
+	/// currencies without a minor unit, such as the yen, the increment is in
+	/// whole units. It is used by [`round_price()`](Self::round_price()) unless
+	/// another increment is chosen. For more information, see [`PriceStyle`].
+	/// 
+	#[must_use]
+	pub fn price_increment(&self) -> u32 {
+		map::get(&PRICE_INCREMENTS, &self.code()).copied().unwrap_or(1)
+	}
+	
+	//		round_price															
+	/// Rounds a price for display.
+	/// 
+	/// The price is rounded according to the given style, which by default
+	/// rounds to the nearest multiple of
+	/// [`price_increment()`](Self::price_increment()), and can optionally give
+	/// prices a "charm" ending, such as `.99`. For example, a price of £10.00,
+	/// i.e. `1000` pence, becomes `999` with an ending of `99`. Prices are
+	/// never rounded past zero, so a price below the first permitted price,
+	/// e.g. 5p with an ending of `99`, is rounded up to it, and a price of zero
+	/// is left unchanged. Negative prices, such as refunds, are rounded in the
+	/// same way as the equivalent positive price, so that -£10.00 becomes
+	/// `-999`. The result saturates at the limits of [`i64`]. For more
+	/// information, see [`PriceStyle`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `minor_units` - The price, in minor units of the currency.
+	/// * `style`       - The style to round the price in.
+	/// 
+	#[must_use]
+	pub fn round_price(&self, minor_units: i64, style: &PriceStyle) -> i64 {
+		style.apply(minor_units, self.price_increment())
+	}
+	
+	//		format_price														
+	/// Rounds and formats a price for display.
+	/// 
+	/// The price is rounded using [`round_price()`](Self::round_price()), and
+	/// formatted as a plain number in major units, with a full stop as the
+	/// decimal separator, and without grouping or a currency symbol, so that
+	/// it can be localised further as required. The number of decimal places
+	/// is given by [`digits()`](Self::digits()), except that where both the
+	/// increment and the rounded price are whole units, no decimal places are
+	/// shown, e.g. `199` for the Swedish krona, rather than `199.00`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `minor_units` - The price, in minor units of the currency.
+	/// * `style`       - The style to round the price in.
+	/// 
+	#[must_use]
+	pub fn format_price(&self, minor_units: i64, style: &PriceStyle) -> String {
+		let rounded = self.round_price(minor_units, style);
+		let places  = u32::from(self.digits().decimal_places());
+		let unit    = 10_u64.checked_pow(places).unwrap_or(1);
+		let amount  = rounded.unsigned_abs();
+		let sign    = if rounded < 0 { "-" } else { "" };
+		let major   = amount.checked_div(unit).unwrap_or(amount);
+		let minor   = amount.checked_rem(unit).unwrap_or(0);
+		if places == 0 || (minor == 0 && u64::from(style.increment_or(self.price_increment())).checked_rem(unit) == Some(0)) {
+			format!("{sign}{major}")
+		} else {
+			format!("{sign}{major}.{minor:0width$}", width = usize::try_from(places).unwrap_or(0))
+		}
+	}
+	
+	//		parse_amount														
+	/// Parses an amount of money entered by a person.
+	/// 
+	/// The amount may use either a full stop or a comma as the decimal
+	/// separator, and may group thousands using commas, full stops, spaces,
+	/// non-breaking spaces, or apostrophes, e.g. `1,234.56`, `1.234,56`,
+	/// `1 234,56`, or `1'234.56`. Indian-style grouping, e.g. `1,23,456.78`, is
+	/// also accepted. An optional leading `+` or `-` sign may be given.
+	/// 
+	/// Where both a full stop and a comma are used, whichever comes last is the
+	/// decimal separator. Where only one of them is used, it is a thousands
+	/// separator if it appears more than once, or if it is followed by exactly
+	/// three digits and the currency uses fewer than three digits, e.g. `1,234`
+	/// is one thousand two hundred and thirty-four pounds sterling, but one
+	/// dinar and 234 fils for the Kuwaiti dinar. Otherwise it is the decimal
+	/// separator.
+	/// 
+	/// The number of decimal places must not exceed the number of
+	/// [`digits()`](Self::digits()) used by the currency, e.g. `1,234.567` is
+	/// rejected for pounds sterling, and `12.5` for yen, rather than being
+	/// rounded. Fewer decimal places are padded, so `12.5` is £12.50.
+	/// 
+	/// This is only available when at least one of the `rust_decimal` and
+	/// `bigdecimal` features is enabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `s` - The amount to parse, in major units of the currency.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the amount is not a valid number, has more decimal
+	/// places than the currency allows, or is too large to be held in minor
+	/// units.
+	/// 
+	#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+	pub fn parse_amount(&self, s: &str) -> Result<Money, String> {
+		let places             = usize::from(self.digits().decimal_places());
+		let trimmed            = s.trim();
+		let (negative, number) = trimmed.strip_prefix(['-', '−']).map_or_else(
+			||     (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+			|rest| (true,  rest),
+		);
+		let (whole, fraction)  = split_amount(number, places).ok_or_else(|| format!("Invalid amount: {s}"))?;
+		if fraction.len() > places {
+			return Err(format!("Too many decimal places for {}: {s}", self.code()));
+		}
+		whole.chars()
+			.chain(fraction.chars())
+			.chain(iter::repeat_n('0', places.saturating_sub(fraction.len())))
+			.try_fold(0_i64, |amount, c| amount.checked_mul(10)?.checked_add(i64::from(c.to_digit(10)?)))
+			.and_then(|amount| if negative { amount.checked_neg() } else { Some(amount) })
+			.map(|amount| Money::new(amount, *self))
+			.ok_or_else(|| format!("Amount out of range: {s}"))
+	}
+	
+	//		introduced															
+	/// Returns the year the currency was introduced.
+	/// 
+	/// This is currently only known for currencies that were introduced in
+	/// modern times, and notably for those that replaced a previous currency,
+	/// e.g. as part of a redenomination. For other currencies, `None` is
+	/// returned.
+	/// 
+	#[must_use]
+	pub fn introduced(&self) -> Option<u16> {
+		self.info().introduced
+	}
+	
+	//		last_changed														
+	/// Returns the date on which the data for the currency last changed.
+	/// 
+	/// This is the ISO 8601 date on which the most recent change took effect,
+	/// such as its introduction, or a change to the countries using it, e.g.
+	/// `2023-01-01` for the euro, when Croatia adopted it. It can be compared
+	/// with the [`DATA_VERSION`](crate::DATA_VERSION). For currencies without a
+	/// recorded change, `None` is returned.
+	/// 
+	#[must_use]
+	pub fn last_changed(&self) -> Option<&'static str> {
+		map::get(&CHANGES, &self.code()).copied()
+	}
+	
+	//		replaces															
+	/// Returns the code of the currency that this currency replaced.
+	/// 
+	/// This is the ISO 4217 alpha3 code of the predecessor currency, e.g. `SLL`
+	/// for the Sierra Leonean new leone, or `VEF` for the Venezuelan sovereign
+	/// bolívar. The code is returned as a string, as predecessor currencies
+	/// have often been withdrawn from ISO 4217, and so do not exist as
+	/// [`CurrencyCode`] variants. If the predecessor is still current, it can
+	/// be parsed into a [`CurrencyCode`].
+	/// 
+	/// Only currencies that succeeded a predecessor which is then withdrawn are
+	/// linked, and not those created for a new state, such as the South
+	/// Sudanese pound, nor those issued alongside an existing currency, such as
+	/// the Venezuelan digital bolívar, as the other currency remains in use.
+	/// 
+	#[must_use]
+	pub fn replaces(&self) -> Option<&'static str> {
+		self.info().replaces
+	}
+	
+	//		replaced_by															
+	/// Returns the currency that replaced this currency.
+	/// 
+	/// This provides the reverse of [`replaces()`](Self::replaces()), and is
+	/// only available where both currencies are current, e.g. the Sierra
+	/// Leonean old leone, which is in the process of being withdrawn.
+	/// 
+	#[must_use]
+	pub fn replaced_by(&self) -> Option<Self> {
+		let code = self.code();
+		CURRENCIES
+			.iter()
+			.find(|info| info.replaces == Some(code.as_str()))
+			.map(|info| info.code.currency())
+	}
+	
+	//		is_phasing_out														
+	/// Returns `true` if the currency is being phased out.
+	/// 
+	/// This is the case when the currency has been replaced by another current
+	/// currency, but is still listed in ISO 4217 whilst it is withdrawn, e.g.
+	/// the Sierra Leonean old leone (`SLL`), which has been replaced by the new
+	/// leone (`SLE`). The replacement is available from
+	/// [`replaced_by()`](Self::replaced_by()). Payment interfaces may wish to
+	/// warn users when such a currency is selected.
+	/// 
+	#[must_use]
+	pub fn is_phasing_out(&self) -> bool {
+		self.replaced_by().is_some()
+	}
+	
+	//		entity																
+	/// Returns the entity that issues or manages the currency.
+	/// 
+	/// This is only given for supranational currencies and units, which are not
+	/// issued by the central bank of a single country, e.g. the International
+	/// Monetary Fund for special drawing rights (`XDR`), or the African
+	/// Development Bank Group for its unit of account (`XUA`). For other
+	/// currencies, and for precious metals and the codes reserved for testing
+	/// and for no currency, `None` is returned.
+	/// 
+	#[must_use]
+	pub fn entity(&self) -> Option<&'static str> {
+		ENTITIES.iter().find(|&&(code, _)| code == self.code()).map(|&(_, entity)| entity)
+	}
+	
+	//		symbols																
+	/// Returns the symbols of the currency.
+	/// 
+	/// These are the symbols in common use, e.g. `$` and `C$` for the Canadian
+	/// dollar. An empty list is returned for currencies without a known symbol.
+	/// Note that symbols are frequently shared between currencies, so should
+	/// not be relied upon to identify a currency on their own.
+	/// 
+	#[must_use]
+	pub fn symbols(&self) -> Vec<&'static str> {
+		SYMBOLS.iter().filter(|&&(_, code)| code == self.code()).map(|&(symbol, _)| symbol).collect()
+	}
+	
 	//		countries															
 	/// Returns the countries where the currency is used.
 	#[must_use]
-	pub fn countries(&self) -> &HashSet<CountryCode> {
-		&self.info().countries
+	pub fn countries(&self) -> &'static [CountryCode] {
+		self.info().countries
+	}
+	
+	//		countries_set														
+	/// Returns the countries where the currency is used, as a [`HashSet`].
+	/// 
+	/// This is a convenience method for when set semantics are required. The
+	/// data is stored as a sorted slice, as returned by [`countries()`](Self::countries()),
+	/// and so this method allocates a new set on each call.
+	/// 
+	#[must_use]
+	pub fn countries_set(&self) -> HashSet<CountryCode> {
+		self.countries().iter().copied().collect()
+	}
+	
+	//		languages															
+	/// Returns the languages used in the countries where the currency is used.
+	/// 
+	/// This is derived from the countries and their languages, and is computed
+	/// once, on first use.
+	/// 
+	#[must_use]
+	pub fn languages(&self) -> &'static [LanguageCode] {
+		LANGUAGES.get(*self as usize).map_or(&[], Vec::as_slice)
+	}
+	
+	//		languages_set														
+	/// Returns the languages used in the countries where the currency is used,
+	/// as a [`HashSet`].
+	/// 
+	/// This is a convenience method for when set semantics are required, and
+	/// allocates a new set on each call.
+	/// 
+	#[must_use]
+	pub fn languages_set(&self) -> HashSet<LanguageCode> {
+		self.languages().iter().copied().collect()
 	}
 }
 
 //󰭅		AsStr																	
 impl AsStr for Currency {
 	//		as_str																
-	fn as_str(&self) -> &str {
-		&self.info().name
+	fn as_str(&self) -> &'static str {
+		self.info().name
 	}
 }
 
@@ -886,6 +1791,14 @@ impl Display for Currency {
 	}
 }
 
+//󰭅		From<Currency> for u16													
+impl From<Currency> for u16 {
+	//		from																
+	fn from(currency: Currency) -> Self {
+		currency.code().numeric()
+	}
+}
+
 //󰭅		From<Currency> for String												
 impl From<Currency> for String {
 	//		from																
@@ -900,13 +1813,39 @@ impl FromStr for Currency {
 	
 	//		from_str															
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		CURRENCIES
-			.values()
-			.find(|info| info.name == s)
-			.map_or_else(
-				||     Err(format!("Invalid Currency: {s}")),
-				|info| Ok(info.code.currency())
-			)
+		NAMES.get(&normalize(s)).copied().ok_or_else(|| format!("Invalid Currency: {s}"))
+	}
+}
+
+//󰭅		Parse																	
+impl Parse for Currency {
+	//		from_code															
+	fn from_code(code: &str, case_insensitive: bool) -> Option<Self> {
+		CurrencyCode::from_code(code)
+			.filter(|currency| case_insensitive || currency.as_str() == code)
+			.map(|currency| currency.currency())
+	}
+	
+	//		from_numeric														
+	fn from_numeric(numeric: u16) -> Option<Self> {
+		CurrencyCode::try_from(numeric).ok().map(|code| code.currency())
+	}
+	
+	//		from_name															
+	fn from_name(name: &str) -> Option<Self> {
+		let normalized = normalize(name);
+		NAMES.get(&normalized).copied().filter(|currency| normalize(currency.info().name) == normalized)
+	}
+	
+	//		from_alias															
+	fn from_alias(alias: &str) -> Option<Self> {
+		let normalized = normalize(alias);
+		ALIASES.iter().find(|&&(name, _)| normalize(name) == normalized).map(|&(_, code)| code.currency())
+	}
+	
+	//		is_user_assigned													
+	fn is_user_assigned(&self) -> bool {
+		matches!(*self, Self::XTS | Self::XXX)
 	}
 }
 
@@ -940,6 +1879,14 @@ impl TryFrom<String> for Currency {
 /// code may not be appropriate. Interestingly, the three-digit numeric code is
 /// the same as the numeric country code where possible.
 /// 
+/// # Serialisation
+/// 
+/// In human-readable formats, such as JSON, the codes are (de)serialised as
+/// strings, e.g. `"GBP"`. In compact formats, such as `bincode` and `postcard`,
+/// they are (de)serialised as their numeric codes, e.g. `826`, which are
+/// smaller and faster to process. This is decided by the format, and needs no
+/// configuration.
+/// 
 /// # Data sources
 /// 
 /// The list of codes is available from [the ISO site](https://www.iso.org/iso-4217-currency-codes.html),
@@ -950,10 +1897,9 @@ impl TryFrom<String> for Currency {
 /// * [`Currency`]
 /// 
 #[expect(clippy::zero_prefixed_literal, reason = "Zeroes aid readability here")]
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[repr(u16)]
-#[serde(into = "String", try_from = "String")]
 #[non_exhaustive]
 pub enum CurrencyCode {
 	/// United Arab Emirates dirham.
@@ -1498,8 +2444,265 @@ pub enum CurrencyCode {
 impl CurrencyCode {
 	//		all																	
 	/// Returns all the currency codes.
+	#[must_use]
 	pub fn all() -> Vec<Self> {
-		CURRENCIES.values().map(|info| info.code).collect()
+		CURRENCIES.iter().map(|info| info.code).collect()
+	}
+	
+	//		complete															
+	/// Returns the currency codes that start with a given prefix.
+	/// 
+	/// This is intended for code completion, such as in terminal UIs and bots.
+	/// The match is case-insensitive, and the codes are returned in
+	/// alphabetical order, e.g. `CH` gives `CHE`, `CHF`, and `CHW`.
+	/// 
+	#[must_use]
+	pub fn complete(prefix: &str) -> Vec<Self> {
+		let mut codes: Vec<_> = CURRENCIES
+			.iter()
+			.map(|info| info.code)
+			.filter(|code| code.as_str().get(..prefix.len()).is_some_and(|start| start.eq_ignore_ascii_case(prefix)))
+			.collect();
+		codes.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+		codes
+	}
+	
+	//		from_code															
+	/// Returns the [`CurrencyCode`] corresponding to a code string.
+	/// 
+	/// The match is case-insensitive, in the same way as [`FromStr`]. However,
+	/// unlike [`FromStr`], this method is a `const fn`, and so can be used in
+	/// `const` contexts, such as by the [`currency!`](crate::currency!) macro.
+	/// If the code is not recognised, `None` is returned.
+	/// 
+	#[expect(clippy::too_many_lines, reason = "Data not logic")]
+	#[must_use]
+	pub const fn from_code(code: &str) -> Option<Self> {
+		match *code.as_bytes() {
+			[a, b, c] => match &[a.to_ascii_uppercase(), b.to_ascii_uppercase(), c.to_ascii_uppercase()] {
+				b"AED" => Some(Self::AED),
+				b"AFN" => Some(Self::AFN),
+				b"ALL" => Some(Self::ALL),
+				b"AMD" => Some(Self::AMD),
+				b"ANG" => Some(Self::ANG),
+				b"AOA" => Some(Self::AOA),
+				b"ARS" => Some(Self::ARS),
+				b"AUD" => Some(Self::AUD),
+				b"AWG" => Some(Self::AWG),
+				b"AZN" => Some(Self::AZN),
+				b"BAM" => Some(Self::BAM),
+				b"BBD" => Some(Self::BBD),
+				b"BDT" => Some(Self::BDT),
+				b"BGN" => Some(Self::BGN),
+				b"BHD" => Some(Self::BHD),
+				b"BIF" => Some(Self::BIF),
+				b"BMD" => Some(Self::BMD),
+				b"BND" => Some(Self::BND),
+				b"BOB" => Some(Self::BOB),
+				b"BOV" => Some(Self::BOV),
+				b"BRL" => Some(Self::BRL),
+				b"BSD" => Some(Self::BSD),
+				b"BTN" => Some(Self::BTN),
+				b"BWP" => Some(Self::BWP),
+				b"BYN" => Some(Self::BYN),
+				b"BZD" => Some(Self::BZD),
+				b"CAD" => Some(Self::CAD),
+				b"CDF" => Some(Self::CDF),
+				b"CHE" => Some(Self::CHE),
+				b"CHF" => Some(Self::CHF),
+				b"CHW" => Some(Self::CHW),
+				b"CLF" => Some(Self::CLF),
+				b"CLP" => Some(Self::CLP),
+				b"CNY" => Some(Self::CNY),
+				b"COP" => Some(Self::COP),
+				b"COU" => Some(Self::COU),
+				b"CRC" => Some(Self::CRC),
+				b"CUP" => Some(Self::CUP),
+				b"CVE" => Some(Self::CVE),
+				b"CZK" => Some(Self::CZK),
+				b"DJF" => Some(Self::DJF),
+				b"DKK" => Some(Self::DKK),
+				b"DOP" => Some(Self::DOP),
+				b"DZD" => Some(Self::DZD),
+				b"EGP" => Some(Self::EGP),
+				b"ERN" => Some(Self::ERN),
+				b"ETB" => Some(Self::ETB),
+				b"EUR" => Some(Self::EUR),
+				b"FJD" => Some(Self::FJD),
+				b"FKP" => Some(Self::FKP),
+				b"GBP" => Some(Self::GBP),
+				b"GEL" => Some(Self::GEL),
+				b"GHS" => Some(Self::GHS),
+				b"GIP" => Some(Self::GIP),
+				b"GMD" => Some(Self::GMD),
+				b"GNF" => Some(Self::GNF),
+				b"GTQ" => Some(Self::GTQ),
+				b"GYD" => Some(Self::GYD),
+				b"HKD" => Some(Self::HKD),
+				b"HNL" => Some(Self::HNL),
+				b"HTG" => Some(Self::HTG),
+				b"HUF" => Some(Self::HUF),
+				b"IDR" => Some(Self::IDR),
+				b"ILS" => Some(Self::ILS),
+				b"INR" => Some(Self::INR),
+				b"IQD" => Some(Self::IQD),
+				b"IRR" => Some(Self::IRR),
+				b"ISK" => Some(Self::ISK),
+				b"JMD" => Some(Self::JMD),
+				b"JOD" => Some(Self::JOD),
+				b"JPY" => Some(Self::JPY),
+				b"KES" => Some(Self::KES),
+				b"KGS" => Some(Self::KGS),
+				b"KHR" => Some(Self::KHR),
+				b"KMF" => Some(Self::KMF),
+				b"KPW" => Some(Self::KPW),
+				b"KRW" => Some(Self::KRW),
+				b"KWD" => Some(Self::KWD),
+				b"KYD" => Some(Self::KYD),
+				b"KZT" => Some(Self::KZT),
+				b"LAK" => Some(Self::LAK),
+				b"LBP" => Some(Self::LBP),
+				b"LKR" => Some(Self::LKR),
+				b"LRD" => Some(Self::LRD),
+				b"LSL" => Some(Self::LSL),
+				b"LYD" => Some(Self::LYD),
+				b"MAD" => Some(Self::MAD),
+				b"MDL" => Some(Self::MDL),
+				b"MGA" => Some(Self::MGA),
+				b"MKD" => Some(Self::MKD),
+				b"MMK" => Some(Self::MMK),
+				b"MNT" => Some(Self::MNT),
+				b"MOP" => Some(Self::MOP),
+				b"MRU" => Some(Self::MRU),
+				b"MUR" => Some(Self::MUR),
+				b"MVR" => Some(Self::MVR),
+				b"MWK" => Some(Self::MWK),
+				b"MXN" => Some(Self::MXN),
+				b"MXV" => Some(Self::MXV),
+				b"MYR" => Some(Self::MYR),
+				b"MZN" => Some(Self::MZN),
+				b"NAD" => Some(Self::NAD),
+				b"NGN" => Some(Self::NGN),
+				b"NIO" => Some(Self::NIO),
+				b"NOK" => Some(Self::NOK),
+				b"NPR" => Some(Self::NPR),
+				b"NZD" => Some(Self::NZD),
+				b"OMR" => Some(Self::OMR),
+				b"PAB" => Some(Self::PAB),
+				b"PEN" => Some(Self::PEN),
+				b"PGK" => Some(Self::PGK),
+				b"PHP" => Some(Self::PHP),
+				b"PKR" => Some(Self::PKR),
+				b"PLN" => Some(Self::PLN),
+				b"PYG" => Some(Self::PYG),
+				b"QAR" => Some(Self::QAR),
+				b"RON" => Some(Self::RON),
+				b"RSD" => Some(Self::RSD),
+				b"RUB" => Some(Self::RUB),
+				b"RWF" => Some(Self::RWF),
+				b"SAR" => Some(Self::SAR),
+				b"SBD" => Some(Self::SBD),
+				b"SCR" => Some(Self::SCR),
+				b"SDG" => Some(Self::SDG),
+				b"SEK" => Some(Self::SEK),
+				b"SGD" => Some(Self::SGD),
+				b"SHP" => Some(Self::SHP),
+				b"SLE" => Some(Self::SLE),
+				b"SLL" => Some(Self::SLL),
+				b"SOS" => Some(Self::SOS),
+				b"SRD" => Some(Self::SRD),
+				b"SSP" => Some(Self::SSP),
+				b"STN" => Some(Self::STN),
+				b"SVC" => Some(Self::SVC),
+				b"SYP" => Some(Self::SYP),
+				b"SZL" => Some(Self::SZL),
+				b"THB" => Some(Self::THB),
+				b"TJS" => Some(Self::TJS),
+				b"TMT" => Some(Self::TMT),
+				b"TND" => Some(Self::TND),
+				b"TOP" => Some(Self::TOP),
+				b"TRY" => Some(Self::TRY),
+				b"TTD" => Some(Self::TTD),
+				b"TWD" => Some(Self::TWD),
+				b"TZS" => Some(Self::TZS),
+				b"UAH" => Some(Self::UAH),
+				b"UGX" => Some(Self::UGX),
+				b"USD" => Some(Self::USD),
+				b"USN" => Some(Self::USN),
+				b"UYI" => Some(Self::UYI),
+				b"UYU" => Some(Self::UYU),
+				b"UYW" => Some(Self::UYW),
+				b"UZS" => Some(Self::UZS),
+				b"VED" => Some(Self::VED),
+				b"VES" => Some(Self::VES),
+				b"VND" => Some(Self::VND),
+				b"VUV" => Some(Self::VUV),
+				b"WST" => Some(Self::WST),
+				b"XAF" => Some(Self::XAF),
+				b"XAG" => Some(Self::XAG),
+				b"XAU" => Some(Self::XAU),
+				b"XBA" => Some(Self::XBA),
+				b"XBB" => Some(Self::XBB),
+				b"XBC" => Some(Self::XBC),
+				b"XBD" => Some(Self::XBD),
+				b"XCD" => Some(Self::XCD),
+				b"XDR" => Some(Self::XDR),
+				b"XOF" => Some(Self::XOF),
+				b"XPD" => Some(Self::XPD),
+				b"XPF" => Some(Self::XPF),
+				b"XPT" => Some(Self::XPT),
+				b"XSU" => Some(Self::XSU),
+				b"XTS" => Some(Self::XTS),
+				b"XUA" => Some(Self::XUA),
+				b"XXX" => Some(Self::XXX),
+				b"YER" => Some(Self::YER),
+				b"ZAR" => Some(Self::ZAR),
+				b"ZMW" => Some(Self::ZMW),
+				b"ZWL" => Some(Self::ZWL),
+				_      => None,
+			},
+			_         => None,
+		}
+	}
+	
+	//		looks_valid															
+	/// Returns `true` if a string has the shape of a currency code.
+	/// 
+	/// This checks only that the string consists of three uppercase ASCII
+	/// letters, without looking the code up, and so is cheaper than parsing. It
+	/// is intended for pre-filtering large, noisy datasets before exact
+	/// validation, and so a string that passes may still not be a valid code,
+	/// e.g. `"ABC"`. Codes in lowercase do not pass, even though they are
+	/// accepted by [`from_code()`](Self::from_code()) and [`FromStr`].
+	/// 
+	#[must_use]
+	pub const fn looks_valid(code: &str) -> bool {
+		match *code.as_bytes() {
+			[a, b, c] => a.is_ascii_uppercase() && b.is_ascii_uppercase() && c.is_ascii_uppercase(),
+			_         => false,
+		}
+	}
+	
+	//		const_from_str														
+	/// Returns the [`CurrencyCode`] corresponding to a code string, in `const` contexts.
+	/// 
+	/// This method behaves in the same way as [`from_code()`](Self::from_code()),
+	/// but panics instead of returning `None`. When used in a `const` context,
+	/// such as to initialise a `const` or `static`, this means that an invalid
+	/// code will cause a compile error, allowing lists of currency codes in
+	/// configuration to be validated at compile time.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the code is not recognised.
+	/// 
+	#[expect(clippy::panic, reason = "Intended for compile-time validation")]
+	#[must_use]
+	pub const fn const_from_str(code: &str) -> Self {
+		match Self::from_code(code) {
+			Some(parsed) => parsed,
+			None         => panic!("Invalid CurrencyCode"),
+		}
 	}
 	
 	//		currency															
@@ -1693,6 +2896,46 @@ impl CurrencyCode {
 			Self::ZWL => Currency::ZWL,
 		}
 	}
+	
+	//		numeric																
+	/// Returns the ISO 4217 numeric code.
+	/// 
+	/// This is the three-digit numeric code defined by the ISO 4217 standard,
+	/// and should be used in preference to casting with `as u16`.
+	/// 
+	#[must_use]
+	pub const fn numeric(&self) -> u16 {
+		*self as u16
+	}
+	
+	//		shares_numeric_with_country											
+	/// Returns the country whose numeric code is the same as the currency's.
+	/// 
+	/// For many currencies, the ISO 4217 numeric code is the same as the ISO
+	/// 3166-1 numeric code of the country that issues it, e.g. `826` for both
+	/// the pound sterling and the United Kingdom. This can be useful when
+	/// reconciling records that only contain numeric codes. Currencies that are
+	/// not specific to one country, such as the euro, do not share their codes.
+	/// 
+	/// The country code is returned in its two-letter form.
+	/// 
+	#[must_use]
+	pub fn shares_numeric_with_country(&self) -> Option<CountryCode> {
+		CountryCode::try_from(self.numeric()).ok().map(|code| code.to_alpha2())
+	}
+	
+	//		stable_hash															
+	/// Returns a stable 64-bit hash of the currency code.
+	/// 
+	/// The hash is calculated from the three-letter code, using
+	/// [`stable_hash()`](crate::text::stable_hash()). It is guaranteed not to
+	/// change between versions, which makes it suitable for consistent hashing
+	/// and sharding across services.
+	/// 
+	#[must_use]
+	pub fn stable_hash(&self) -> u64 {
+		stable_hash(self.as_str())
+	}
 }
 
 //󰭅		AsStr																	
@@ -1884,6 +3127,18 @@ impl AsStr for CurrencyCode {
 	}
 }
 
+//󰭅		Deserialize																
+impl<'de> Deserialize<'de> for CurrencyCode {
+	//		deserialize															
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		if deserializer.is_human_readable() {
+			String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+		} else {
+			Self::try_from(u16::deserialize(deserializer)?).map_err(de::Error::custom)
+		}
+	}
+}
+
 //󰭅		Display																	
 impl Display for CurrencyCode {
 	//		fmt																	
@@ -1892,11 +3147,20 @@ impl Display for CurrencyCode {
 	}
 }
 
+//󰭅		Format																	
+#[cfg(feature = "defmt")]
+impl Format for CurrencyCode {
+	//		format																
+	fn format(&self, fmt: Formatter<'_>) {
+		defmt::write!(fmt, "{=str}", self.as_str());
+	}
+}
+
 //󰭅		From<CurrencyCode> for u16												
 impl From<CurrencyCode> for u16 {
 	//		from																
 	fn from(code: CurrencyCode) -> Self {
-		code as Self
+		code.numeric()
 	}
 }
 
@@ -1913,189 +3177,36 @@ impl FromStr for CurrencyCode {
 	type Err = String;
 	
 	//		from_str															
-	#[expect(clippy::too_many_lines, reason = "Data not logic")]
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		match s.to_uppercase().as_str() {
-			"AED" => Ok(Self::AED),
-			"AFN" => Ok(Self::AFN),
-			"ALL" => Ok(Self::ALL),
-			"AMD" => Ok(Self::AMD),
-			"ANG" => Ok(Self::ANG),
-			"AOA" => Ok(Self::AOA),
-			"ARS" => Ok(Self::ARS),
-			"AUD" => Ok(Self::AUD),
-			"AWG" => Ok(Self::AWG),
-			"AZN" => Ok(Self::AZN),
-			"BAM" => Ok(Self::BAM),
-			"BBD" => Ok(Self::BBD),
-			"BDT" => Ok(Self::BDT),
-			"BGN" => Ok(Self::BGN),
-			"BHD" => Ok(Self::BHD),
-			"BIF" => Ok(Self::BIF),
-			"BMD" => Ok(Self::BMD),
-			"BND" => Ok(Self::BND),
-			"BOB" => Ok(Self::BOB),
-			"BOV" => Ok(Self::BOV),
-			"BRL" => Ok(Self::BRL),
-			"BSD" => Ok(Self::BSD),
-			"BTN" => Ok(Self::BTN),
-			"BWP" => Ok(Self::BWP),
-			"BYN" => Ok(Self::BYN),
-			"BZD" => Ok(Self::BZD),
-			"CAD" => Ok(Self::CAD),
-			"CDF" => Ok(Self::CDF),
-			"CHE" => Ok(Self::CHE),
-			"CHF" => Ok(Self::CHF),
-			"CHW" => Ok(Self::CHW),
-			"CLF" => Ok(Self::CLF),
-			"CLP" => Ok(Self::CLP),
-			"CNY" => Ok(Self::CNY),
-			"COP" => Ok(Self::COP),
-			"COU" => Ok(Self::COU),
-			"CRC" => Ok(Self::CRC),
-			"CUP" => Ok(Self::CUP),
-			"CVE" => Ok(Self::CVE),
-			"CZK" => Ok(Self::CZK),
-			"DJF" => Ok(Self::DJF),
-			"DKK" => Ok(Self::DKK),
-			"DOP" => Ok(Self::DOP),
-			"DZD" => Ok(Self::DZD),
-			"EGP" => Ok(Self::EGP),
-			"ERN" => Ok(Self::ERN),
-			"ETB" => Ok(Self::ETB),
-			"EUR" => Ok(Self::EUR),
-			"FJD" => Ok(Self::FJD),
-			"FKP" => Ok(Self::FKP),
-			"GBP" => Ok(Self::GBP),
-			"GEL" => Ok(Self::GEL),
-			"GHS" => Ok(Self::GHS),
-			"GIP" => Ok(Self::GIP),
-			"GMD" => Ok(Self::GMD),
-			"GNF" => Ok(Self::GNF),
-			"GTQ" => Ok(Self::GTQ),
-			"GYD" => Ok(Self::GYD),
-			"HKD" => Ok(Self::HKD),
-			"HNL" => Ok(Self::HNL),
-			"HTG" => Ok(Self::HTG),
-			"HUF" => Ok(Self::HUF),
-			"IDR" => Ok(Self::IDR),
-			"ILS" => Ok(Self::ILS),
-			"INR" => Ok(Self::INR),
-			"IQD" => Ok(Self::IQD),
-			"IRR" => Ok(Self::IRR),
-			"ISK" => Ok(Self::ISK),
-			"JMD" => Ok(Self::JMD),
-			"JOD" => Ok(Self::JOD),
-			"JPY" => Ok(Self::JPY),
-			"KES" => Ok(Self::KES),
-			"KGS" => Ok(Self::KGS),
-			"KHR" => Ok(Self::KHR),
-			"KMF" => Ok(Self::KMF),
-			"KPW" => Ok(Self::KPW),
-			"KRW" => Ok(Self::KRW),
-			"KWD" => Ok(Self::KWD),
-			"KYD" => Ok(Self::KYD),
-			"KZT" => Ok(Self::KZT),
-			"LAK" => Ok(Self::LAK),
-			"LBP" => Ok(Self::LBP),
-			"LKR" => Ok(Self::LKR),
-			"LRD" => Ok(Self::LRD),
-			"LSL" => Ok(Self::LSL),
-			"LYD" => Ok(Self::LYD),
-			"MAD" => Ok(Self::MAD),
-			"MDL" => Ok(Self::MDL),
-			"MGA" => Ok(Self::MGA),
-			"MKD" => Ok(Self::MKD),
-			"MMK" => Ok(Self::MMK),
-			"MNT" => Ok(Self::MNT),
-			"MOP" => Ok(Self::MOP),
-			"MRU" => Ok(Self::MRU),
-			"MUR" => Ok(Self::MUR),
-			"MVR" => Ok(Self::MVR),
-			"MWK" => Ok(Self::MWK),
-			"MXN" => Ok(Self::MXN),
-			"MXV" => Ok(Self::MXV),
-			"MYR" => Ok(Self::MYR),
-			"MZN" => Ok(Self::MZN),
-			"NAD" => Ok(Self::NAD),
-			"NGN" => Ok(Self::NGN),
-			"NIO" => Ok(Self::NIO),
-			"NOK" => Ok(Self::NOK),
-			"NPR" => Ok(Self::NPR),
-			"NZD" => Ok(Self::NZD),
-			"OMR" => Ok(Self::OMR),
-			"PAB" => Ok(Self::PAB),
-			"PEN" => Ok(Self::PEN),
-			"PGK" => Ok(Self::PGK),
-			"PHP" => Ok(Self::PHP),
-			"PKR" => Ok(Self::PKR),
-			"PLN" => Ok(Self::PLN),
-			"PYG" => Ok(Self::PYG),
-			"QAR" => Ok(Self::QAR),
-			"RON" => Ok(Self::RON),
-			"RSD" => Ok(Self::RSD),
-			"RUB" => Ok(Self::RUB),
-			"RWF" => Ok(Self::RWF),
-			"SAR" => Ok(Self::SAR),
-			"SBD" => Ok(Self::SBD),
-			"SCR" => Ok(Self::SCR),
-			"SDG" => Ok(Self::SDG),
-			"SEK" => Ok(Self::SEK),
-			"SGD" => Ok(Self::SGD),
-			"SHP" => Ok(Self::SHP),
-			"SLE" => Ok(Self::SLE),
-			"SLL" => Ok(Self::SLL),
-			"SOS" => Ok(Self::SOS),
-			"SRD" => Ok(Self::SRD),
-			"SSP" => Ok(Self::SSP),
-			"STN" => Ok(Self::STN),
-			"SVC" => Ok(Self::SVC),
-			"SYP" => Ok(Self::SYP),
-			"SZL" => Ok(Self::SZL),
-			"THB" => Ok(Self::THB),
-			"TJS" => Ok(Self::TJS),
-			"TMT" => Ok(Self::TMT),
-			"TND" => Ok(Self::TND),
-			"TOP" => Ok(Self::TOP),
-			"TRY" => Ok(Self::TRY),
-			"TTD" => Ok(Self::TTD),
-			"TWD" => Ok(Self::TWD),
-			"TZS" => Ok(Self::TZS),
-			"UAH" => Ok(Self::UAH),
-			"UGX" => Ok(Self::UGX),
-			"USD" => Ok(Self::USD),
-			"USN" => Ok(Self::USN),
-			"UYI" => Ok(Self::UYI),
-			"UYU" => Ok(Self::UYU),
-			"UYW" => Ok(Self::UYW),
-			"UZS" => Ok(Self::UZS),
-			"VED" => Ok(Self::VED),
-			"VES" => Ok(Self::VES),
-			"VND" => Ok(Self::VND),
-			"VUV" => Ok(Self::VUV),
-			"WST" => Ok(Self::WST),
-			"XAF" => Ok(Self::XAF),
-			"XAG" => Ok(Self::XAG),
-			"XAU" => Ok(Self::XAU),
-			"XBA" => Ok(Self::XBA),
-			"XBB" => Ok(Self::XBB),
-			"XBC" => Ok(Self::XBC),
-			"XBD" => Ok(Self::XBD),
-			"XCD" => Ok(Self::XCD),
-			"XDR" => Ok(Self::XDR),
-			"XOF" => Ok(Self::XOF),
-			"XPD" => Ok(Self::XPD),
-			"XPF" => Ok(Self::XPF),
-			"XPT" => Ok(Self::XPT),
-			"XSU" => Ok(Self::XSU),
-			"XTS" => Ok(Self::XTS),
-			"XUA" => Ok(Self::XUA),
-			"XXX" => Ok(Self::XXX),
-			"YER" => Ok(Self::YER),
-			"ZAR" => Ok(Self::ZAR),
-			"ZMW" => Ok(Self::ZMW),
-			"ZWL" => Ok(Self::ZWL),
-			_     => Err(format!("Invalid CurrencyCode: {s}")),
+		Self::from_code(s).ok_or_else(|| format!("Invalid CurrencyCode: {s}"))
+	}
+}
+
+//󰭅		Ord																		
+impl Ord for CurrencyCode {
+	//		cmp																	
+	/// Compares two codes alphabetically, e.g. `EUR` before `GBP`.
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_str().cmp(other.as_str())
+	}
+}
+
+//󰭅		PartialOrd																
+impl PartialOrd for CurrencyCode {
+	//		partial_cmp															
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+//󰭅		Serialize																
+impl Serialize for CurrencyCode {
+	//		serialize															
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if serializer.is_human_readable() {
+			serializer.serialize_str(self.as_str())
+		} else {
+			serializer.serialize_u16(self.numeric())
 		}
 	}
 }
@@ -2303,10 +3414,240 @@ impl TryFrom<String> for CurrencyCode {
 	}
 }
 
+//		CurrencyClass															
+/// The classification of a currency by liquidity.
+/// 
+/// # See also
+/// 
+/// * [`Currency::classification()`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[non_exhaustive]
+pub enum CurrencyClass {
+	/// One of the most widely-traded currencies, such as the US dollar.
+	Major,
+	
+	/// A less widely-traded currency of a developed market, such as the Swedish
+	/// krona.
+	Minor,
+	
+	/// Any other currency, such as those of emerging markets.
+	Exotic,
+}
+
+//󰭅		AsStr																	
+impl AsStr for CurrencyClass {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Major  => "Major",
+			Self::Minor  => "Minor",
+			Self::Exotic => "Exotic",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for CurrencyClass {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//		MinorUnits																
+/// The minor units of a currency.
+/// 
+/// This is the number of digits after the decimal point used by a currency, as
+/// defined by ISO 4217. Most currencies have a minor unit, such as the penny,
+/// or have none, in which case there are zero digits, as for the yen. For some,
+/// such as gold and the special drawing right, the concept does not apply, and
+/// ISO 4217 gives "N.A." instead of a number.
+/// 
+/// When (de)serialised, the number of digits is used, or `null` if not
+/// applicable.
+/// 
+/// # See also
+/// 
+/// * [`Currency::digits()`]
+/// 
+#[expect(clippy::exhaustive_enums, reason = "Minor units either apply or not")]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "Option<u8>", into = "Option<u8>")]
+pub enum MinorUnits {
+	/// The concept of minor units does not apply to the currency.
+	NotApplicable,
+	
+	/// The number of digits after the decimal point.
+	Digits(u8),
+}
+
+//󰭅		MinorUnits																
+impl MinorUnits {
+	//		digits																
+	/// Returns the number of digits after the decimal point, if applicable.
+	#[must_use]
+	pub const fn digits(&self) -> Option<u8> {
+		match *self {
+			Self::NotApplicable  => None,
+			Self::Digits(digits) => Some(digits),
+		}
+	}
+	
+	//		decimal_places														
+	/// Returns the number of decimal places to use for amounts.
+	/// 
+	/// This is the number of digits after the decimal point, or zero if minor
+	/// units are not applicable, for use where a scale is required, such as
+	/// when rounding.
+	/// 
+	#[must_use]
+	pub const fn decimal_places(&self) -> u8 {
+		match *self {
+			Self::NotApplicable  => 0,
+			Self::Digits(digits) => digits,
+		}
+	}
+	
+	//		is_applicable														
+	/// Returns `true` if the concept of minor units applies to the currency.
+	#[must_use]
+	pub const fn is_applicable(&self) -> bool {
+		matches!(*self, Self::Digits(_))
+	}
+}
+
+//󰭅		Display																	
+impl Display for MinorUnits {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::NotApplicable  => write!(f, "N.A."),
+			Self::Digits(digits) => write!(f, "{digits}"),
+		}
+	}
+}
+
+//󰭅		From<MinorUnits> for Option<u8>											
+impl From<MinorUnits> for Option<u8> {
+	//		from																
+	fn from(minor_units: MinorUnits) -> Self {
+		minor_units.digits()
+	}
+}
+
+//󰭅		From<Option<u8>>														
+impl From<Option<u8>> for MinorUnits {
+	//		from																
+	fn from(digits: Option<u8>) -> Self {
+		digits.map_or(Self::NotApplicable, Self::Digits)
+	}
+}
+
 
 
 //		Structs
 
+//		CurrencyRecord															
+/// A read-only view of all the information about a currency.
+/// 
+/// This is returned by [`Currency::record()`] and [`Currency::records()`], and
+/// allows all of the information about a currency to be obtained in one go,
+/// rather than by calling each of the individual methods.
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct CurrencyRecord {
+	//		Public properties													
+	/// The currency.
+	pub currency:   Currency,
+	
+	/// The three-letter currency code.
+	pub code:       CurrencyCode,
+	
+	/// The three-digit numeric currency code.
+	pub numeric:    u16,
+	
+	/// The name of the currency.
+	pub name:       &'static str,
+	
+	/// The number of digits after the decimal point.
+	pub digits:     MinorUnits,
+	
+	/// The names of the units of the currency, if known.
+	pub unit_names: Option<UnitNames>,
+	
+	/// The year the currency was introduced, if known.
+	pub introduced: Option<u16>,
+	
+	/// The code of the currency this one replaced, if any.
+	pub replaces:   Option<&'static str>,
+	
+	/// The entity that issues or manages the currency, if it is supranational.
+	pub entity:     Option<&'static str>,
+	
+	/// The countries where the currency is used.
+	pub countries:  &'static [CountryCode],
+	
+	/// The languages used in the countries where the currency is used.
+	pub languages:  &'static [LanguageCode],
+}
+
+//		UnitName																
+/// The name of a unit of a currency.
+/// 
+/// # See also
+/// 
+/// * [`UnitNames`]
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct UnitName {
+	//		Public properties													
+	/// The singular form of the name, e.g. "penny".
+	pub singular: &'static str,
+	
+	/// The plural form of the name, e.g. "pence".
+	pub plural:   &'static str,
+}
+
+//󰭅		UnitName																
+impl UnitName {
+	//		for_count															
+	/// Returns the form of the name to use for a number of units.
+	/// 
+	/// The singular form is used for exactly one unit, and the plural form for
+	/// any other number, including zero, e.g. "1 penny", but "0 pence".
+	/// 
+	/// # Parameters
+	/// 
+	/// * `count` - The number of units.
+	/// 
+	#[must_use]
+	pub const fn for_count(&self, count: u64) -> &'static str {
+		if count == 1 { self.singular } else { self.plural }
+	}
+}
+
+//		UnitNames																
+/// The names of the units of a currency.
+/// 
+/// This is returned by [`Currency::unit_names()`], and provides the names of
+/// the major and minor units of a currency, e.g. dollars and cents, from which
+/// amounts can be expressed in words.
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct UnitNames {
+	//		Public properties													
+	/// The name of the major unit, e.g. "dollar".
+	pub major: UnitName,
+	
+	/// The name of the minor unit, e.g. "cent", if the currency has one.
+	pub minor: Option<UnitName>,
+}
+
 //		CurrencyInfo															
 /// Currency information.
 /// 
@@ -2332,16 +3673,79 @@ impl TryFrom<String> for CurrencyCode {
 struct CurrencyInfo {
 	//		Private properties													
 	/// The name of the currency.
-	name:      String,
+	name:       &'static str,
 	
 	/// The currency code. For more information, see [`CurrencyCode`].
-	code:      CurrencyCode,
+	code:       CurrencyCode,
 	
 	/// The number of digits after the decimal point.
-	digits:    u8,
+	digits:     MinorUnits,
+	
+	/// The singular and plural names of the major and minor units, if known.
+	units:      Option<(UnitForms, Option<UnitForms>)>,
+	
+	/// The year the currency was introduced, if known.
+	introduced: Option<u16>,
+	
+	/// The code of the currency that this currency replaced, if any.
+	replaces:   Option<&'static str>,
 	
 	/// The countries where the currency is used.
-	countries: HashSet<CountryCode>,
+	countries:  &'static [CountryCode],
 }
 
 
+
+//		Functions
+
+//		split_amount															
+/// Splits an amount into its whole and fractional digits.
+/// 
+/// The separators are identified as described for [`Currency::parse_amount()`],
+/// and the grouping of the whole digits is checked. The whole digits are
+/// returned with the thousands separators removed. Returns [`None`] if the
+/// amount is not a valid unsigned number.
+/// 
+/// # Parameters
+/// 
+/// * `amount` - The amount, without any sign.
+/// * `places` - The number of decimal places used by the currency.
+/// 
+#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+fn split_amount(amount: &str, places: usize) -> Option<(String, &str)> {
+	let is_digits         = |text: &str| !text.is_empty() && text.bytes().all(|byte| byte.is_ascii_digit());
+	let (whole, fraction) = match amount.rsplit_once(['.', ',']) {
+		Some((before, after)) => {
+			let separator = if amount.as_bytes().get(before.len()) == Some(&b'.') { '.' } else { ',' };
+			let other     = if separator == '.' { ',' } else { '.' };
+			let decimal   = amount.contains(other) || (
+				amount.matches(separator).count() == 1 && (after.len() != 3 || places >= 3)
+			);
+			if !decimal {
+				(amount, "")
+			} else if is_digits(after) {
+				(before, after)
+			} else {
+				return None;
+			}
+		},
+		None                  => (amount, ""),
+	};
+	let mut separators = whole.chars().filter(|c| matches!(c, ',' | '.' | ' ' | '\u{a0}' | '\u{202f}' | '\''));
+	let Some(grouping) = separators.next() else {
+		return (is_digits(whole) || whole.is_empty() && !fraction.is_empty()).then(|| (whole.to_owned(), fraction));
+	};
+	if separators.any(|c| c != grouping) {
+		return None;
+	}
+	let groups: Vec<&str> = whole.split(grouping).collect();
+	let (first, rest)     = groups.split_first()?;
+	let (last, middle)    = rest.split_last()?;
+	let middle_valid      = middle.iter().all(|group| group.len() == 3) || middle.iter().all(|group| group.len() == 2);
+	(
+		   groups.iter().all(|group| is_digits(group))
+		&& first.len() <= 3
+		&& last.len()  == 3
+		&& middle_valid
+	).then(|| (groups.concat(), fraction))
+}
@@ -0,0 +1,318 @@
+//! Display styles for the primary types.
+//! 
+//! By default, the primary types are displayed as their names, e.g. `France`.
+//! This module provides the [`DisplayStyle`] enum, which allows a country,
+//! currency, or language to be displayed as its code, its name, or both, in a
+//! consistent way, without needing to format it manually. The style is applied
+//! using the `display_as()` methods, e.g.
+//! [`Country::display_as()`](crate::country::Country::display_as()), which
+//! return a [`Styled`] value that implements [`Display`].
+//! 
+//! ```
+//! use isosphere::{Country, display::DisplayStyle};
+//! 
+//! assert_eq!(Country::FR.display_as(DisplayStyle::Code).to_string(),        "FR");
+//! assert_eq!(Country::FR.display_as(DisplayStyle::Name).to_string(),        "France");
+//! assert_eq!(Country::FR.display_as(DisplayStyle::CodeAndName).to_string(), "FR — France");
+//! ```
+//! 
+//! It also provides the [`PriceStyle`] struct, which configures how prices are
+//! rounded and formatted for display, using
+//! [`Currency::round_price()`](crate::currency::Currency::round_price()) and
+//! [`Currency::format_price()`](crate::currency::Currency::format_price()).
+//! By default, prices are rounded to the increment typical for the currency,
+//! e.g. whole kronor for the Swedish krona, and can optionally be given a
+//! "charm" ending, such as `.99`:
+//! 
+//! ```
+//! use isosphere::{Currency, display::PriceStyle};
+//! 
+//! assert_eq!(Currency::GBP.format_price(1_000,  &PriceStyle::new().charm(99)),  "9.99");
+//! assert_eq!(Currency::SEK.format_price(19_940, &PriceStyle::new()),            "199");
+//! assert_eq!(Currency::JPY.format_price(2_013,  &PriceStyle::new().charm(980)), "1980");
+//! ```
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/display.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	serde::Coded,
+};
+use core::{
+	fmt::{Display, self},
+	iter,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
+
+
+
+//		Enums
+
+//		DisplayStyle															
+/// The style in which to display a country, currency, or language.
+/// 
+/// # See also
+/// 
+/// * [`Country::display_as()`](crate::country::Country::display_as())
+/// * [`Currency::display_as()`](crate::currency::Currency::display_as())
+/// * [`Language::display_as()`](crate::language::Language::display_as())
+/// 
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[non_exhaustive]
+pub enum DisplayStyle {
+	/// The code, e.g. `GB`.
+	Code,
+	
+	/// The name, e.g. `United Kingdom of Great Britain and Northern Ireland`.
+	/// This is the same as the default [`Display`] implementation.
+	#[default]
+	Name,
+	
+	/// The code followed by the name, separated by a dash, e.g. `GB — United
+	/// Kingdom of Great Britain and Northern Ireland`.
+	CodeAndName,
+}
+
+//		PriceRounding															
+/// The direction in which to round a price.
+/// 
+/// # See also
+/// 
+/// * [`PriceStyle`]
+/// 
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[non_exhaustive]
+pub enum PriceRounding {
+	/// Round to the nearest permitted price, with midpoints rounded up.
+	#[default]
+	Nearest,
+	
+	/// Round up to the next permitted price, so that the price is never
+	/// reduced. Negative prices are rounded away from zero.
+	Up,
+	
+	/// Round down to the previous permitted price, so that the price is never
+	/// increased. Negative prices are rounded towards zero.
+	Down,
+}
+
+
+
+//		Structs
+
+//		PriceStyle																
+/// The style in which to round and format a price.
+/// 
+/// By default, prices are rounded to the nearest multiple of the increment
+/// typical for the currency, as given by
+/// [`Currency::price_increment()`](crate::currency::Currency::price_increment()).
+/// A different increment, a "charm" ending, and the direction of rounding can
+/// be chosen using builder-style methods. All amounts are given in minor units
+/// of the currency, e.g. pence or cents, in the same way as for
+/// `Money`.
+/// 
+/// # See also
+/// 
+/// * [`Currency::round_price()`](crate::currency::Currency::round_price())
+/// * [`Currency::format_price()`](crate::currency::Currency::format_price())
+/// 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PriceStyle {
+	//		Private properties													
+	/// The increment to round to, in minor units, if not the typical one.
+	increment: Option<u32>,
+	
+	/// The ending that prices must have, in minor units, if any.
+	ending:    Option<u32>,
+	
+	/// The direction in which to round.
+	rounding:  PriceRounding,
+}
+
+//󰭅		PriceStyle																
+impl PriceStyle {
+	//		new																	
+	/// Creates a style that rounds to the nearest typical increment.
+	/// 
+	/// These are also the default options.
+	/// 
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			increment: None,
+			ending:    None,
+			rounding:  PriceRounding::Nearest,
+		}
+	}
+	
+	//		increment															
+	/// Sets the increment to round to, in minor units.
+	/// 
+	/// For example, `50` rounds prices in pounds sterling to the nearest 50p.
+	/// An increment of zero is treated as one.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `increment` - The increment, in minor units of the currency.
+	/// 
+	#[must_use]
+	pub const fn increment(mut self, increment: u32) -> Self {
+		self.increment = Some(increment);
+		self
+	}
+	
+	//		charm																
+	/// Sets the ending that prices must have, in minor units.
+	/// 
+	/// The ending is the remainder of the price from the next power of ten
+	/// above it, so `99` gives prices such as £9.99 and £10.99, `95` gives
+	/// prices such as €4.95, and `980` gives prices such as ¥1980. When an
+	/// ending is set, it takes precedence over the increment.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `ending` - The ending, in minor units of the currency.
+	/// 
+	#[must_use]
+	pub const fn charm(mut self, ending: u32) -> Self {
+		self.ending = Some(ending);
+		self
+	}
+	
+	//		rounding															
+	/// Sets the direction in which to round.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `rounding` - The direction in which to round.
+	/// 
+	#[must_use]
+	pub const fn rounding(mut self, rounding: PriceRounding) -> Self {
+		self.rounding = rounding;
+		self
+	}
+	
+	//		increment_or														
+	/// Returns the increment to round to, in minor units.
+	/// 
+	/// This is the increment that has been set, if any, or otherwise the
+	/// typical increment for the currency. It is never zero.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `typical` - The typical increment for the currency, in minor units.
+	/// 
+	pub(crate) fn increment_or(&self, typical: u32) -> u32 {
+		self.increment.unwrap_or(typical).max(1)
+	}
+	
+	//		apply																
+	/// Rounds a price according to the style.
+	/// 
+	/// The typical increment is used if no increment has been set. The result
+	/// never has the opposite sign to the price, so a price that is below the
+	/// first permitted price is rounded up to it, and zero is left unchanged.
+	/// A negative price is rounded as its absolute value, and the sign is then
+	/// restored. The result saturates at the limits of [`i64`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `minor_units` - The price, in minor units of the currency.
+	/// * `typical`     - The typical increment for the currency, in minor units.
+	/// 
+	pub(crate) fn apply(&self, minor_units: i64, typical: u32) -> i64 {
+		//	Round a negative price, such as a refund, as its absolute value, so
+		//	that the charm ending and the direction apply to its magnitude.
+		if minor_units < 0 {
+			return self.apply(minor_units.saturating_neg(), typical).saturating_neg();
+		}
+		let (step, offset) = self.ending.map_or_else(
+			||       (i64::from(self.increment_or(typical)), 0),
+			|ending| {
+				let step = iter::successors(Some(10_i64), |step| step.checked_mul(10))
+					.find(|&step| step > i64::from(ending))
+					.unwrap_or(i64::MAX);
+				(step, i64::from(ending))
+			},
+		);
+		let remainder = minor_units.saturating_sub(offset).rem_euclid(step);
+		if minor_units == 0 || remainder == 0 {
+			return minor_units;
+		}
+		//	Keep the sign of the price, so that a small price is not rounded past
+		//	zero to reach the nearest permitted price on the other side of it.
+		let mut below = minor_units.saturating_sub(remainder);
+		let above     = below.saturating_add(step);
+		if below < 0 {
+			below = above;
+		}
+		match self.rounding {
+			PriceRounding::Nearest => if remainder >= step.saturating_sub(remainder) { above } else { below },
+			PriceRounding::Up      => above,
+			PriceRounding::Down    => below,
+		}
+	}
+}
+
+//		Styled																	
+/// A country, currency, or language, displayed in a chosen style.
+/// 
+/// This is returned by the `display_as()` methods of the primary types, and is
+/// intended to be used directly in formatting, e.g. with `format!()` or
+/// [`to_string()`](ToString::to_string()).
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Styled<T> {
+	//		Private properties													
+	/// The value to display.
+	value: T,
+	
+	/// The style to display the value in.
+	style: DisplayStyle,
+}
+
+//󰭅		Styled																	
+impl<T> Styled<T> {
+	//		new																	
+	/// Creates a new styled value.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The value to display.
+	/// * `style` - The style to display the value in.
+	/// 
+	pub(crate) const fn new(value: T, style: DisplayStyle) -> Self {
+		Self { value, style }
+	}
+}
+
+//󰭅		Display																	
+impl<T: Coded + AsStr> Display for Styled<T>
+where
+	T::Code: AsStr,
+{
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.style {
+			DisplayStyle::Code        => write!(f, "{}", self.value.to_coded().as_str()),
+			DisplayStyle::Name        => write!(f, "{}", self.value.as_str()),
+			DisplayStyle::CodeAndName => write!(f, "{} — {}", self.value.to_coded().as_str(), self.value.as_str()),
+		}
+	}
+}
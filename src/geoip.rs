@@ -0,0 +1,86 @@
+//! Resolution of IP addresses to countries.
+//! 
+//! This module provides the [`CountryResolver`] trait, which is the extension
+//! point for geo-IP lookups, so that the result of a lookup is a [`Country`]
+//! rather than a string that needs to be parsed. Resolvers can be implemented
+//! for any source of geo-IP data, and closures of the form
+//! `Fn(IpAddr) -> Option<Country>` are resolvers already.
+//! 
+//! When the `maxminddb` feature is enabled, the trait is implemented for the
+//! `Reader` of the [`maxminddb`](https://crates.io/crates/maxminddb) crate,
+//! for use with the `GeoIP2` and `GeoLite2` databases from `MaxMind`.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/geoip.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::country::Country;
+use core::net::IpAddr;
+
+#[cfg(feature = "maxminddb")]
+use crate::country::CountryCode;
+#[cfg(feature = "maxminddb")]
+use maxminddb::{Reader, geoip2};
+
+
+
+//		Traits
+
+//§		CountryResolver															
+/// A source of geo-IP data.
+/// 
+/// # See also
+/// 
+/// * [`Country`]
+/// 
+pub trait CountryResolver {
+	//		resolve																
+	/// Returns the country that an IP address is located in.
+	/// 
+	/// If the address cannot be resolved, for instance because it is a private
+	/// address, or is not in the data, `None` is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `ip` - The IP address to resolve.
+	/// 
+	fn resolve(&self, ip: IpAddr) -> Option<Country>;
+}
+
+//󰭅		CountryResolver for Fn													
+impl<F: Fn(IpAddr) -> Option<Country>> CountryResolver for F {
+	//		resolve																
+	fn resolve(&self, ip: IpAddr) -> Option<Country> {
+		self(ip)
+	}
+}
+
+//󰭅		CountryResolver for Reader												
+#[cfg(feature = "maxminddb")]
+impl<S: AsRef<[u8]>> CountryResolver for Reader<S> {
+	//		resolve																
+	/// Returns the country that an IP address is located in.
+	/// 
+	/// The address is looked up as a `GeoIP2` country record, which is present
+	/// in both the country and city databases. The country that the address is
+	/// located in is used if known, falling back to the country in which it is
+	/// registered, e.g. with a regional internet registry.
+	/// 
+	fn resolve(&self, ip: IpAddr) -> Option<Country> {
+		let record = self.lookup::<geoip2::Country<'_>>(ip).ok()?;
+		record.country
+			.and_then(|country| country.iso_code)
+			.or_else(|| record.registered_country.and_then(|country| country.iso_code))
+			.and_then(CountryCode::from_code)
+			.map(|code| code.country())
+	}
+}
@@ -0,0 +1,566 @@
+//! Historic information.
+//! 
+//! This module provides information about ISO codes that have been retired, so
+//! that old records can still be interpreted. It is available when the
+//! `historic` feature is enabled.
+//! 
+//! Currently, this covers ISO 4217 currency codes that have been withdrawn,
+//! from ISO 4217 list three, in the form of [`RetiredCurrencyCode`]. The
+//! [`AnyCurrencyCode`] type combines these with the current [`CurrencyCode`]s,
+//! so that numeric codes from old payment records, e.g. `191` for the Croatian
+//! kuna, can be parsed without failing.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/historic.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	currency::CurrencyCode,
+};
+use core::{
+	fmt::{Display, self},
+	str::FromStr,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "utoipa")]
+use utoipa::{
+	ToSchema,
+	openapi::{ObjectBuilder, RefOr, Schema, SchemaType},
+};
+
+
+
+//		Constants
+
+/// The retired currencies.
+/// 
+/// # Data sources
+/// 
+/// The list of withdrawn codes is available from [the ISO site](https://www.iso.org/iso-4217-currency-codes.html),
+/// as list three, and from [Wikipedia](https://en.wikipedia.org/wiki/ISO_4217#Historical_codes).
+/// 
+/// # See also
+/// 
+/// * [`RetiredCurrencyCode`]
+/// 
+static RETIRED_CURRENCIES: [RetiredCurrencyInfo; 36] = [
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::ATS, name: "Austrian schilling",          withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::AZM, name: "Azerbaijani manat",           withdrawn: 2006, replaced_by: CurrencyCode::AZN },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::BEF, name: "Belgian franc",               withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::BYR, name: "Belarusian ruble",            withdrawn: 2016, replaced_by: CurrencyCode::BYN },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::CSD, name: "Serbian dinar",               withdrawn: 2006, replaced_by: CurrencyCode::RSD },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::CUC, name: "Cuban convertible peso",      withdrawn: 2021, replaced_by: CurrencyCode::CUP },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::CYP, name: "Cypriot pound",               withdrawn: 2008, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::DEM, name: "German mark",                 withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::EEK, name: "Estonian kroon",              withdrawn: 2011, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::ESP, name: "Spanish peseta",              withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::FIM, name: "Finnish markka",              withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::FRF, name: "French franc",                withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::GHC, name: "Ghanaian cedi",               withdrawn: 2007, replaced_by: CurrencyCode::GHS },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::GRD, name: "Greek drachma",               withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::HRK, name: "Croatian kuna",               withdrawn: 2023, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::IEP, name: "Irish pound",                 withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::ITL, name: "Italian lira",                withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::LTL, name: "Lithuanian litas",            withdrawn: 2015, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::LUF, name: "Luxembourg franc",            withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::LVL, name: "Latvian lats",                withdrawn: 2014, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::MGF, name: "Malagasy franc",              withdrawn: 2005, replaced_by: CurrencyCode::MGA },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::MRO, name: "Mauritanian ouguiya",         withdrawn: 2018, replaced_by: CurrencyCode::MRU },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::MTL, name: "Maltese lira",                withdrawn: 2008, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::MZM, name: "Mozambican metical",          withdrawn: 2006, replaced_by: CurrencyCode::MZN },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::NLG, name: "Dutch guilder",               withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::PTE, name: "Portuguese escudo",           withdrawn: 2002, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::ROL, name: "Romanian leu",                withdrawn: 2005, replaced_by: CurrencyCode::RON },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::SDD, name: "Sudanese dinar",              withdrawn: 2007, replaced_by: CurrencyCode::SDG },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::SIT, name: "Slovenian tolar",             withdrawn: 2007, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::SKK, name: "Slovak koruna",               withdrawn: 2009, replaced_by: CurrencyCode::EUR },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::STD, name: "São Tomé and Príncipe dobra", withdrawn: 2018, replaced_by: CurrencyCode::STN },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::TMM, name: "Turkmenistani manat",         withdrawn: 2009, replaced_by: CurrencyCode::TMT },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::TRL, name: "Turkish lira",                withdrawn: 2005, replaced_by: CurrencyCode::TRY },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::VEF, name: "Venezuelan bolívar fuerte",   withdrawn: 2018, replaced_by: CurrencyCode::VES },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::ZMK, name: "Zambian kwacha",              withdrawn: 2013, replaced_by: CurrencyCode::ZMW },
+	RetiredCurrencyInfo { code: RetiredCurrencyCode::ZWR, name: "Zimbabwean dollar (fourth)",  withdrawn: 2009, replaced_by: CurrencyCode::ZWL },
+];
+
+
+
+//		Enums
+
+//		RetiredCurrencyCode														
+/// The retired ISO 4217 currency codes.
+/// 
+/// These are codes that have been withdrawn from ISO 4217, and so are not part
+/// of [`CurrencyCode`], but which may still appear in old records. As with
+/// [`CurrencyCode`], the alpha3 code is the variant name and the numeric code
+/// is the variant value.
+/// 
+/// Only codes with a numeric code and a single current replacement are
+/// included.
+/// 
+/// # See also
+/// 
+/// * [`AnyCurrencyCode`]
+/// * [`CurrencyCode`]
+/// 
+#[expect(clippy::zero_prefixed_literal, reason = "Zeroes aid readability here")]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[repr(u16)]
+#[serde(into = "String", try_from = "String")]
+#[non_exhaustive]
+pub enum RetiredCurrencyCode {
+	/// Austrian schilling.
+	ATS = 040,
+	
+	/// Azerbaijani manat.
+	AZM = 031,
+	
+	/// Belgian franc.
+	BEF = 056,
+	
+	/// Belarusian ruble.
+	BYR = 974,
+	
+	/// Serbian dinar.
+	CSD = 891,
+	
+	/// Cuban convertible peso.
+	CUC = 931,
+	
+	/// Cypriot pound.
+	CYP = 196,
+	
+	/// German mark.
+	DEM = 276,
+	
+	/// Estonian kroon.
+	EEK = 233,
+	
+	/// Spanish peseta.
+	ESP = 724,
+	
+	/// Finnish markka.
+	FIM = 246,
+	
+	/// French franc.
+	FRF = 250,
+	
+	/// Ghanaian cedi.
+	GHC = 288,
+	
+	/// Greek drachma.
+	GRD = 300,
+	
+	/// Croatian kuna.
+	HRK = 191,
+	
+	/// Irish pound.
+	IEP = 372,
+	
+	/// Italian lira.
+	ITL = 380,
+	
+	/// Lithuanian litas.
+	LTL = 440,
+	
+	/// Luxembourg franc.
+	LUF = 442,
+	
+	/// Latvian lats.
+	LVL = 428,
+	
+	/// Malagasy franc.
+	MGF = 450,
+	
+	/// Mauritanian ouguiya.
+	MRO = 478,
+	
+	/// Maltese lira.
+	MTL = 470,
+	
+	/// Mozambican metical.
+	MZM = 508,
+	
+	/// Dutch guilder.
+	NLG = 528,
+	
+	/// Portuguese escudo.
+	PTE = 620,
+	
+	/// Romanian leu.
+	ROL = 642,
+	
+	/// Sudanese dinar.
+	SDD = 736,
+	
+	/// Slovenian tolar.
+	SIT = 705,
+	
+	/// Slovak koruna.
+	SKK = 703,
+	
+	/// São Tomé and Príncipe dobra.
+	STD = 678,
+	
+	/// Turkmenistani manat.
+	TMM = 795,
+	
+	/// Turkish lira.
+	TRL = 792,
+	
+	/// Venezuelan bolívar fuerte.
+	VEF = 937,
+	
+	/// Zambian kwacha.
+	ZMK = 894,
+	
+	/// Zimbabwean dollar (fourth).
+	ZWR = 935,
+}
+
+//󰭅		RetiredCurrencyCode														
+impl RetiredCurrencyCode {
+	//		all																	
+	/// Returns all the retired currency codes.
+	#[must_use]
+	pub fn all() -> Vec<Self> {
+		RETIRED_CURRENCIES.iter().map(|info| info.code).collect()
+	}
+	
+	//		info																
+	/// Returns the `RetiredCurrencyInfo` instance corresponding to the code.
+	#[must_use]
+	fn info(self) -> &'static RetiredCurrencyInfo {
+		#[expect(clippy::unwrap_used, reason = "Infallible")]
+		//	This should be infallible. If it isn't, then the data is wrong, and one
+		//	of the codes is missing from the list, which is a bug.
+		RETIRED_CURRENCIES.iter().find(|info| info.code == self).unwrap()
+	}
+	
+	//		name																
+	/// Returns the name of the retired currency.
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		self.info().name
+	}
+	
+	//		numeric																
+	/// Returns the ISO 4217 numeric code.
+	#[must_use]
+	pub const fn numeric(&self) -> u16 {
+		*self as u16
+	}
+	
+	//		withdrawn															
+	/// Returns the year in which the code was withdrawn.
+	#[must_use]
+	pub fn withdrawn(&self) -> u16 {
+		self.info().withdrawn
+	}
+	
+	//		replaced_by															
+	/// Returns the current currency code that replaced the retired one.
+	#[must_use]
+	pub fn replaced_by(&self) -> CurrencyCode {
+		self.info().replaced_by
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for RetiredCurrencyCode {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::ATS => "ATS",
+			Self::AZM => "AZM",
+			Self::BEF => "BEF",
+			Self::BYR => "BYR",
+			Self::CSD => "CSD",
+			Self::CUC => "CUC",
+			Self::CYP => "CYP",
+			Self::DEM => "DEM",
+			Self::EEK => "EEK",
+			Self::ESP => "ESP",
+			Self::FIM => "FIM",
+			Self::FRF => "FRF",
+			Self::GHC => "GHC",
+			Self::GRD => "GRD",
+			Self::HRK => "HRK",
+			Self::IEP => "IEP",
+			Self::ITL => "ITL",
+			Self::LTL => "LTL",
+			Self::LUF => "LUF",
+			Self::LVL => "LVL",
+			Self::MGF => "MGF",
+			Self::MRO => "MRO",
+			Self::MTL => "MTL",
+			Self::MZM => "MZM",
+			Self::NLG => "NLG",
+			Self::PTE => "PTE",
+			Self::ROL => "ROL",
+			Self::SDD => "SDD",
+			Self::SIT => "SIT",
+			Self::SKK => "SKK",
+			Self::STD => "STD",
+			Self::TMM => "TMM",
+			Self::TRL => "TRL",
+			Self::VEF => "VEF",
+			Self::ZMK => "ZMK",
+			Self::ZWR => "ZWR",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for RetiredCurrencyCode {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//󰭅		From<RetiredCurrencyCode> for u16										
+impl From<RetiredCurrencyCode> for u16 {
+	//		from																
+	fn from(code: RetiredCurrencyCode) -> Self {
+		code.numeric()
+	}
+}
+
+//󰭅		From<RetiredCurrencyCode> for String									
+impl From<RetiredCurrencyCode> for String {
+	//		from																
+	fn from(code: RetiredCurrencyCode) -> Self {
+		code.to_string()
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for RetiredCurrencyCode {
+	type Err = String;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		RETIRED_CURRENCIES
+			.iter()
+			.find(|info| info.code.as_str().eq_ignore_ascii_case(s))
+			.map(|info| info.code)
+			.ok_or_else(|| format!("Invalid RetiredCurrencyCode: {s}"))
+	}
+}
+
+//󰭅		TryFrom<u16>															
+impl TryFrom<u16> for RetiredCurrencyCode {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: u16) -> Result<Self, Self::Error> {
+		RETIRED_CURRENCIES
+			.iter()
+			.find(|info| info.code.numeric() == value)
+			.map(|info| info.code)
+			.ok_or_else(|| format!("Invalid RetiredCurrencyCode: {value}"))
+	}
+}
+
+//󰭅		TryFrom<String>															
+impl TryFrom<String> for RetiredCurrencyCode {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.as_str().parse()
+	}
+}
+
+//		AnyCurrencyCode															
+/// A current or retired ISO 4217 currency code.
+/// 
+/// This is used when parsing codes that may come from old records, and which
+/// may therefore have been withdrawn. Current codes are always preferred, so a
+/// code is only resolved as retired if it is not a valid [`CurrencyCode`].
+/// 
+/// It serialises in the same way as the codes it wraps, i.e. as the alpha3
+/// string.
+/// 
+/// # See also
+/// 
+/// * [`CurrencyCode`]
+/// * [`RetiredCurrencyCode`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(into = "String", try_from = "String")]
+#[non_exhaustive]
+pub enum AnyCurrencyCode {
+	/// A current currency code.
+	Current(CurrencyCode),
+	
+	/// A retired currency code.
+	Retired(RetiredCurrencyCode),
+}
+
+//󰭅		AnyCurrencyCode															
+impl AnyCurrencyCode {
+	//		is_retired															
+	/// Returns `true` if the code has been withdrawn from ISO 4217.
+	#[must_use]
+	pub const fn is_retired(&self) -> bool {
+		matches!(*self, Self::Retired(_))
+	}
+	
+	//		numeric																
+	/// Returns the ISO 4217 numeric code.
+	#[must_use]
+	pub const fn numeric(&self) -> u16 {
+		match *self {
+			Self::Current(code) => code.numeric(),
+			Self::Retired(code) => code.numeric(),
+		}
+	}
+	
+	//		current																
+	/// Returns the current currency code.
+	/// 
+	/// For a retired code, this is the code that replaced it.
+	/// 
+	#[must_use]
+	pub fn current(&self) -> CurrencyCode {
+		match *self {
+			Self::Current(code) => code,
+			Self::Retired(code) => code.replaced_by(),
+		}
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for AnyCurrencyCode {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Current(ref code) => code.as_str(),
+			Self::Retired(ref code) => code.as_str(),
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for AnyCurrencyCode {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//󰭅		From<AnyCurrencyCode> for u16											
+impl From<AnyCurrencyCode> for u16 {
+	//		from																
+	fn from(code: AnyCurrencyCode) -> Self {
+		code.numeric()
+	}
+}
+
+//󰭅		From<AnyCurrencyCode> for String										
+impl From<AnyCurrencyCode> for String {
+	//		from																
+	fn from(code: AnyCurrencyCode) -> Self {
+		code.to_string()
+	}
+}
+
+//󰭅		From<CurrencyCode>														
+impl From<CurrencyCode> for AnyCurrencyCode {
+	//		from																
+	fn from(code: CurrencyCode) -> Self {
+		Self::Current(code)
+	}
+}
+
+//󰭅		From<RetiredCurrencyCode>												
+impl From<RetiredCurrencyCode> for AnyCurrencyCode {
+	//		from																
+	fn from(code: RetiredCurrencyCode) -> Self {
+		Self::Retired(code)
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for AnyCurrencyCode {
+	type Err = String;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		CurrencyCode::from_str(s).ok().map(Self::Current)
+			.or_else(|| RetiredCurrencyCode::from_str(s).ok().map(Self::Retired))
+			.ok_or_else(|| format!("Invalid AnyCurrencyCode: {s}"))
+	}
+}
+
+//󰭅		ToSchema																
+#[cfg(feature = "utoipa")]
+impl<'s> ToSchema<'s> for AnyCurrencyCode {
+	//		schema																
+	fn schema() -> (&'s str, RefOr<Schema>) {
+		("AnyCurrencyCode", ObjectBuilder::new().schema_type(SchemaType::String).into())
+	}
+}
+
+//󰭅		TryFrom<u16>															
+impl TryFrom<u16> for AnyCurrencyCode {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: u16) -> Result<Self, Self::Error> {
+		CurrencyCode::try_from(value).ok().map(Self::Current)
+			.or_else(|| RetiredCurrencyCode::try_from(value).ok().map(Self::Retired))
+			.ok_or_else(|| format!("Invalid AnyCurrencyCode: {value}"))
+	}
+}
+
+//󰭅		TryFrom<String>															
+impl TryFrom<String> for AnyCurrencyCode {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.as_str().parse()
+	}
+}
+
+
+
+//		Structs
+
+//		RetiredCurrencyInfo														
+/// Retired currency information.
+/// 
+/// # See also
+/// 
+/// * [`RetiredCurrencyCode`]
+/// 
+#[non_exhaustive]
+struct RetiredCurrencyInfo {
+	//		Private properties													
+	/// The name of the retired currency.
+	name:        &'static str,
+	
+	/// The retired currency code. For more information, see
+	/// [`RetiredCurrencyCode`].
+	code:        RetiredCurrencyCode,
+	
+	/// The year in which the code was withdrawn.
+	withdrawn:   u16,
+	
+	/// The current currency code that replaced the retired one.
+	replaced_by: CurrencyCode,
+}
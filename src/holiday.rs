@@ -0,0 +1,245 @@
+//! Holiday-related types.
+//! 
+//! This module provides an extension point for holiday calendars keyed by
+//! [`Country`], in the form of the [`HolidayCalendar`] trait, along with a
+//! basic implementation, [`NationalDays`], which uses the fixed-date national
+//! days available from [`Country::national_day()`].
+//! 
+//! Holiday rules vary enormously, and many holidays move from year to year, so
+//! this crate does not attempt to provide full holiday calendars. Instead, the
+//! trait allows richer calendars to be plugged in, whilst keeping them keyed by
+//! the same [`Country`] type, and optionally by subdivision.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/holiday.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::country::Country;
+use core::{
+	fmt::{Debug, Display, self},
+	str::FromStr,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
+
+
+
+//		Structs
+
+//		MonthDay																
+/// A day of the year, without a year.
+/// 
+/// This represents a recurring date, such as a fixed-date holiday, and is
+/// expressed as a month and day. The 29th of February is considered valid.
+/// 
+/// The string form follows ISO 8601, i.e. `--MM-DD`, e.g. `--07-14` for the
+/// 14th of July. This is the format used for (de)serialisation.
+/// 
+#[derive(Clone, Copy, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(into = "String", try_from = "String")]
+pub struct MonthDay {
+	//		Private properties													
+	/// The month, from 1 to 12.
+	month: u8,
+	
+	/// The day of the month, from 1 to 31.
+	day:   u8,
+}
+
+//󰭅		MonthDay																
+impl MonthDay {
+	//		new																	
+	/// Creates a new `MonthDay`.
+	/// 
+	/// Returns `None` if the month is not from 1 to 12, or if the day does not
+	/// exist in that month.
+	/// 
+	#[must_use]
+	pub const fn new(month: u8, day: u8) -> Option<Self> {
+		let max = match month {
+			1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+			4 | 6 | 9 | 11              => 30,
+			2                           => 29,
+			_                           => return None,
+		};
+		if day == 0 || day > max {
+			return None;
+		}
+		Some(Self { month, day })
+	}
+	
+	//		month																
+	/// Returns the month, from 1 to 12.
+	#[must_use]
+	pub const fn month(&self) -> u8 {
+		self.month
+	}
+	
+	//		day																	
+	/// Returns the day of the month, from 1 to 31.
+	#[must_use]
+	pub const fn day(&self) -> u8 {
+		self.day
+	}
+}
+
+//󰭅		Debug																	
+impl Debug for MonthDay {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{self}")
+	}
+}
+
+//󰭅		Display																	
+impl Display for MonthDay {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "--{:02}-{:02}", self.month, self.day)
+	}
+}
+
+//󰭅		From<MonthDay> for String												
+impl From<MonthDay> for String {
+	//		from																
+	fn from(date: MonthDay) -> Self {
+		date.to_string()
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for MonthDay {
+	type Err = String;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.strip_prefix("--")
+			.and_then(|rest| rest.split_once('-'))
+			.filter(|&(month, day)| month.len() == 2 && day.len() == 2)
+			.and_then(|(month, day)| Some((month.parse().ok()?, day.parse().ok()?)))
+			.and_then(|(month, day)| Self::new(month, day))
+			.ok_or_else(|| format!("Invalid MonthDay: {s}"))
+	}
+}
+
+//󰭅		TryFrom<String>															
+impl TryFrom<String> for MonthDay {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.as_str().parse()
+	}
+}
+
+//		Holiday																	
+/// A holiday.
+/// 
+/// A holiday has a name and a date, and is produced by a [`HolidayCalendar`].
+/// 
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+pub struct Holiday {
+	//		Private properties													
+	/// The name of the holiday.
+	name: String,
+	
+	/// The date of the holiday.
+	date: MonthDay,
+}
+
+//󰭅		Holiday																	
+impl Holiday {
+	//		new																	
+	/// Creates a new `Holiday`.
+	#[must_use]
+	pub fn new<S: Into<String>>(name: S, date: MonthDay) -> Self {
+		Self { name: name.into(), date }
+	}
+	
+	//		name																
+	/// Returns the name of the holiday.
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+	
+	//		date																
+	/// Returns the date of the holiday.
+	#[must_use]
+	pub const fn date(&self) -> MonthDay {
+		self.date
+	}
+}
+
+//		NationalDays															
+/// A holiday calendar containing only fixed-date national days.
+/// 
+/// This is the basic [`HolidayCalendar`] implementation provided by this crate,
+/// and returns the national day of each country, as given by
+/// [`Country::national_day()`], for every year. Subdivisions are ignored.
+/// 
+#[expect(clippy::exhaustive_structs, reason = "Unit struct")]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct NationalDays;
+
+//󰭅		HolidayCalendar															
+impl HolidayCalendar for NationalDays {
+	//		holidays															
+	fn holidays(&self, country: Country, _subdivision: Option<&str>, _year: i32) -> Vec<Holiday> {
+		country.national_day().map(|date| Holiday::new("National Day", date)).into_iter().collect()
+	}
+}
+
+
+
+//		Traits
+
+//§		HolidayCalendar															
+/// A source of holidays for countries.
+/// 
+/// This trait is the extension point for holiday calendars. Implementations are
+/// keyed by [`Country`], and optionally by subdivision, which is passed through
+/// as a string so that any subdivision scheme can be used, e.g. ISO 3166-2
+/// codes such as `GB-SCT`.
+/// 
+/// Only [`holidays()`](HolidayCalendar::holidays()) needs to be implemented.
+/// 
+/// # See also
+/// 
+/// * [`NationalDays`]
+/// 
+pub trait HolidayCalendar {
+	//		holidays															
+	/// Returns the holidays for a country in a given year.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `country`     - The country to get the holidays for.
+	/// * `subdivision` - The subdivision of the country, if applicable.
+	/// * `year`        - The year to get the holidays for.
+	/// 
+	fn holidays(&self, country: Country, subdivision: Option<&str>, year: i32) -> Vec<Holiday>;
+	
+	//		is_holiday															
+	/// Checks whether a date is a holiday in a country.
+	/// 
+	/// The default implementation checks the date against the holidays returned
+	/// by [`holidays()`](HolidayCalendar::holidays()).
+	/// 
+	fn is_holiday(&self, country: Country, subdivision: Option<&str>, year: i32, date: MonthDay) -> bool {
+		self.holidays(country, subdivision, year).iter().any(|holiday| holiday.date == date)
+	}
+}
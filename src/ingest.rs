@@ -0,0 +1,146 @@
+//! Ingestion of coded datasets.
+//! 
+//! This module provides the [`CodeColumn`] type, which adapts a CSV reader from
+//! the [`csv`](https://crates.io/crates/csv) crate so that a designated column
+//! is parsed into a code, such as a [`CountryCode`](crate::country::CountryCode)
+//! or [`CurrencyCode`](crate::currency::CurrencyCode), whilst the records are
+//! streamed. Errors carry the line number of the offending row, so that bad
+//! data can be located in the source file:
+//! 
+//! ```
+//! use isosphere::{CountryCode, ingest::CodeColumn};
+//! 
+//! let data    = "name,country\nAlice,GB\nBob,XX\n";
+//! let reader  = csv::Reader::from_reader(data.as_bytes());
+//! let mut rows = CodeColumn::<_, CountryCode>::new(reader, "country").unwrap();
+//! assert_eq!(rows.next().unwrap().unwrap().0, CountryCode::GB);
+//! assert_eq!(rows.next().unwrap().unwrap_err(), (3, "Invalid CountryCode: XX".to_owned()));
+//! assert!(rows.next().is_none());
+//! ```
+//! 
+//! This module is available when the `csv` feature is enabled.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/ingest.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use core::{
+	fmt::{Debug, self},
+	marker::PhantomData,
+	str::FromStr,
+};
+use csv::{Position, Reader, StringRecord, StringRecordsIntoIter};
+use std::io::Read;
+
+
+
+//		Structs
+
+//		CodeColumn																
+/// A stream of CSV records with a column parsed into a code.
+/// 
+/// Each item is the parsed value of the designated column, along with the
+/// whole record, so that the other columns can still be read. The value can be
+/// of any type that parses from a string, which includes all the code enums,
+/// as well as [`Country`](crate::country::Country),
+/// [`Currency`](crate::currency::Currency), and
+/// [`Language`](crate::language::Language), which are parsed from names.
+/// 
+/// If a record cannot be read, or the column is missing from it, or the value
+/// cannot be parsed, the item is an error holding the line number of the
+/// record, starting from 1, and a description of the problem. Streaming can
+/// continue after an error, so that all the bad rows can be reported at once.
+/// 
+pub struct CodeColumn<R, T> {
+	//		Private properties													
+	/// The records still to be read.
+	records: StringRecordsIntoIter<R>,
+	
+	/// The index of the column to parse.
+	column:  usize,
+	
+	/// The type that the column is parsed into.
+	parsed:  PhantomData<fn() -> T>,
+}
+
+//󰭅		CodeColumn																
+impl<R: Read, T: FromStr<Err = String>> CodeColumn<R, T> {
+	//		new																	
+	/// Creates a new stream, parsing the column with a given header.
+	/// 
+	/// The reader must have been configured to expect a header row, which is
+	/// the default.
+	/// 
+	/// # Errors
+	/// 
+	/// If the headers cannot be read, or none of them matches the column name,
+	/// an error is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `reader` - The CSV reader to stream records from.
+	/// * `column` - The header of the column to parse.
+	/// 
+	pub fn new(mut reader: Reader<R>, column: &str) -> Result<Self, String> {
+		let index = reader.headers()
+			.map_err(|err| err.to_string())?
+			.iter()
+			.position(|header| header == column)
+			.ok_or_else(|| format!("Missing column: {column}"))?;
+		Ok(Self::with_index(reader, index))
+	}
+	
+	//		with_index															
+	/// Creates a new stream, parsing the column at a given index.
+	/// 
+	/// This is for data without a header row, or where the position of the
+	/// column is known in advance. The index starts from 0.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `reader` - The CSV reader to stream records from.
+	/// * `column` - The index of the column to parse.
+	/// 
+	#[must_use]
+	pub fn with_index(reader: Reader<R>, column: usize) -> Self {
+		Self { records: reader.into_records(), column, parsed: PhantomData }
+	}
+}
+
+//󰭅		Debug																	
+impl<R, T> Debug for CodeColumn<R, T> {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("CodeColumn").field("column", &self.column).finish_non_exhaustive()
+	}
+}
+
+//󰭅		Iterator																
+impl<R: Read, T: FromStr<Err = String>> Iterator for CodeColumn<R, T> {
+	type Item = Result<(T, StringRecord), (u64, String)>;
+	
+	//		next																
+	fn next(&mut self) -> Option<Self::Item> {
+		let result = self.records.next()?;
+		Some(result
+			.map_err(|err| (err.position().map_or(0, Position::line), err.to_string()))
+			.and_then(|record| {
+				let line  = record.position().map_or(0, Position::line);
+				let value = record.get(self.column)
+					.ok_or_else(|| (line, format!("Missing column: {}", self.column)))?
+					.parse()
+					.map_err(|err| (line, err))?;
+				Ok((value, record))
+			})
+		)
+	}
+}
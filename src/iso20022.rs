@@ -0,0 +1,330 @@
+//! ISO 20022 message constraints.
+//! 
+//! ISO 20022 payment messages, such as `pacs.008` and `pain.001`, restrict
+//! their code fields more tightly than the rest of this crate does. The
+//! `ActiveCurrencyCode` type must match the pattern `[A-Z]{3,3}`, and the
+//! `CountryCode` type must match `[A-Z]{2,2}`, so lowercase codes, surrounding
+//! whitespace, and three-letter country codes are all rejected. Checking the
+//! pattern alone is not enough, though, as the code must also be one that is
+//! actually assigned.
+//! 
+//! This module provides validators that apply both checks, so that message
+//! builders can reject bad fields before a message is sent:
+//! 
+//! ```
+//! use isosphere::{CountryCode, CurrencyCode, iso20022};
+//! 
+//! assert_eq!(iso20022::validate_active_currency_code("EUR"), Ok(CurrencyCode::EUR));
+//! assert!(iso20022::validate_active_currency_code("eur").is_err());
+//! assert!(iso20022::validate_active_currency_code("ABC").is_err());
+//! 
+//! assert_eq!(iso20022::validate_country_code("GB"), Ok(CountryCode::GB));
+//! assert!(iso20022::validate_country_code("GBR").is_err());
+//! ```
+//! 
+//! It also provides modules for use with Serde's `with` attribute, which apply
+//! the same constraints when deserialising, and always serialise in the form
+//! that the constraints require. These work with fields holding either the
+//! primary type or the code type:
+//! 
+//!   - [`active_currency_code`]
+//!   - [`country_code`]
+//! 
+//! When the `historic` feature is enabled, the `ActiveOrHistoricCurrencyCode`
+//! type is also supported, via [`validate_active_or_historic_currency_code()`].
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/iso20022.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	country::CountryCode,
+	currency::CurrencyCode,
+	serde::Coded,
+};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+#[cfg(feature = "historic")]
+use crate::historic::{AnyCurrencyCode, RetiredCurrencyCode};
+
+
+
+//		Functions
+
+//		validate_active_currency_code											
+/// Validates an ISO 20022 `ActiveCurrencyCode`.
+/// 
+/// The code must match the pattern `[A-Z]{3,3}`, and must be a current ISO
+/// 4217 currency code.
+/// 
+/// # Errors
+/// 
+/// If the code does not match the pattern, or is not assigned, an error is
+/// returned.
+/// 
+/// # Parameters
+/// 
+/// * `code` - The code to validate.
+/// 
+pub fn validate_active_currency_code(code: &str) -> Result<CurrencyCode, String> {
+	CurrencyCode::looks_valid(code)
+		.then(|| CurrencyCode::from_code(code))
+		.flatten()
+		.ok_or_else(|| format!("Invalid ActiveCurrencyCode: {code}"))
+}
+
+//		validate_active_or_historic_currency_code								
+/// Validates an ISO 20022 `ActiveOrHistoricCurrencyCode`.
+/// 
+/// The code must match the pattern `[A-Z]{3,3}`, and must be either a current
+/// or a retired ISO 4217 currency code. Current codes are preferred, in the
+/// same way as for [`AnyCurrencyCode`].
+/// 
+/// This function is available when the `historic` feature is enabled.
+/// 
+/// # Errors
+/// 
+/// If the code does not match the pattern, or is neither a current nor a
+/// retired code, an error is returned.
+/// 
+/// # Parameters
+/// 
+/// * `code` - The code to validate.
+/// 
+#[cfg(feature = "historic")]
+pub fn validate_active_or_historic_currency_code(code: &str) -> Result<AnyCurrencyCode, String> {
+	validate_active_currency_code(code).ok().map(AnyCurrencyCode::Current)
+		.or_else(|| CurrencyCode::looks_valid(code)
+			.then(|| code.parse::<RetiredCurrencyCode>().ok().map(AnyCurrencyCode::Retired))
+			.flatten()
+		)
+		.ok_or_else(|| format!("Invalid ActiveOrHistoricCurrencyCode: {code}"))
+}
+
+//		validate_country_code													
+/// Validates an ISO 20022 `CountryCode`.
+/// 
+/// The code must match the pattern `[A-Z]{2,2}`, and must be an ISO 3166-1
+/// alpha-2 country code. Three-letter codes are not accepted.
+/// 
+/// # Errors
+/// 
+/// If the code does not match the pattern, or is not assigned, an error is
+/// returned.
+/// 
+/// # Parameters
+/// 
+/// * `code` - The code to validate.
+/// 
+pub fn validate_country_code(code: &str) -> Result<CountryCode, String> {
+	(code.len() == 2 && CountryCode::looks_valid(code))
+		.then(|| CountryCode::from_code(code))
+		.flatten()
+		.ok_or_else(|| format!("Invalid CountryCode: {code}"))
+}
+
+
+
+//		Codecs
+
+/// (De)serialisation as an ISO 20022 `ActiveCurrencyCode`.
+/// 
+/// Deserialisation uses [`validate_active_currency_code()`], and so rejects
+/// any value that does not meet the constraints, rather than normalising it.
+/// 
+/// ```
+/// use isosphere::Currency;
+/// use serde::{Deserialize, Serialize};
+/// 
+/// #[derive(Deserialize, Serialize)]
+/// struct Amount {
+///     #[serde(rename = "Ccy", with = "isosphere::iso20022::active_currency_code")]
+///     currency: Currency,
+/// }
+/// 
+/// let amount: Amount = serde_json::from_str(r#"{"Ccy": "CHF"}"#).unwrap();
+/// assert_eq!(amount.currency, Currency::CHF);
+/// assert!(serde_json::from_str::<Amount>(r#"{"Ccy": "chf"}"#).is_err());
+/// ```
+/// 
+pub mod active_currency_code {
+	use super::{AsStr, Coded, CurrencyCode, Deserialize, Deserializer, Serialize, Serializer, de};
+	
+	//		serialize															
+	/// Serialises a value.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns any error produced by the serialiser.
+	/// 
+	pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		T: Coded<Code = CurrencyCode>,
+		S: Serializer,
+	{
+		value.to_coded().as_str().serialize(serializer)
+	}
+	
+	//		deserialize															
+	/// Deserialises a value.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the input is not a valid `ActiveCurrencyCode`.
+	/// 
+	pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+	where
+		T: Coded<Code = CurrencyCode>,
+		D: Deserializer<'de>,
+	{
+		super::validate_active_currency_code(&String::deserialize(deserializer)?)
+			.map(T::from_coded)
+			.map_err(de::Error::custom)
+	}
+	
+	/// (De)serialisation of optional values.
+	pub mod option {
+		use super::{AsStr, Coded, CurrencyCode, Deserialize, Deserializer, Serialize, Serializer, de};
+		
+		//		serialize														
+		/// Serialises an optional value.
+		/// 
+		/// # Errors
+		/// 
+		/// Returns any error produced by the serialiser.
+		/// 
+		pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+		where
+			T: Coded<Code = CurrencyCode>,
+			S: Serializer,
+		{
+			value.map(|inner| inner.to_coded().as_str()).serialize(serializer)
+		}
+		
+		//		deserialize														
+		/// Deserialises an optional value.
+		/// 
+		/// # Errors
+		/// 
+		/// Returns an error if the input is neither null nor a valid
+		/// `ActiveCurrencyCode`.
+		/// 
+		pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+		where
+			T: Coded<Code = CurrencyCode>,
+			D: Deserializer<'de>,
+		{
+			Option::<String>::deserialize(deserializer)?
+				.map(|code| super::super::validate_active_currency_code(&code).map(T::from_coded))
+				.transpose()
+				.map_err(de::Error::custom)
+		}
+	}
+}
+
+/// (De)serialisation as an ISO 20022 `CountryCode`.
+/// 
+/// Deserialisation uses [`validate_country_code()`], and so rejects any value
+/// that does not meet the constraints, rather than normalising it.
+/// Serialisation always writes the two-letter code, so a field holding a
+/// three-letter [`CountryCode`] is converted.
+/// 
+/// ```
+/// use isosphere::CountryCode;
+/// use serde::{Deserialize, Serialize};
+/// 
+/// #[derive(Deserialize, Serialize)]
+/// struct PostalAddress {
+///     #[serde(rename = "Ctry", with = "isosphere::iso20022::country_code")]
+///     country: CountryCode,
+/// }
+/// 
+/// let address = PostalAddress { country: CountryCode::DEU };
+/// assert_eq!(serde_json::to_string(&address).unwrap(), r#"{"Ctry":"DE"}"#);
+/// assert!(serde_json::from_str::<PostalAddress>(r#"{"Ctry": "DEU"}"#).is_err());
+/// ```
+/// 
+pub mod country_code {
+	use super::{AsStr, Coded, CountryCode, Deserialize, Deserializer, Serialize, Serializer, de};
+	
+	//		serialize															
+	/// Serialises a value.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns any error produced by the serialiser.
+	/// 
+	pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		T: Coded<Code = CountryCode>,
+		S: Serializer,
+	{
+		value.to_coded().to_alpha2().as_str().serialize(serializer)
+	}
+	
+	//		deserialize															
+	/// Deserialises a value.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the input is not a valid `CountryCode`.
+	/// 
+	pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+	where
+		T: Coded<Code = CountryCode>,
+		D: Deserializer<'de>,
+	{
+		super::validate_country_code(&String::deserialize(deserializer)?)
+			.map(T::from_coded)
+			.map_err(de::Error::custom)
+	}
+	
+	/// (De)serialisation of optional values.
+	pub mod option {
+		use super::{AsStr, Coded, CountryCode, Deserialize, Deserializer, Serialize, Serializer, de};
+		
+		//		serialize														
+		/// Serialises an optional value.
+		/// 
+		/// # Errors
+		/// 
+		/// Returns any error produced by the serialiser.
+		/// 
+		pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+		where
+			T: Coded<Code = CountryCode>,
+			S: Serializer,
+		{
+			value.map(|inner| inner.to_coded().to_alpha2().as_str()).serialize(serializer)
+		}
+		
+		//		deserialize														
+		/// Deserialises an optional value.
+		/// 
+		/// # Errors
+		/// 
+		/// Returns an error if the input is neither null nor a valid
+		/// `CountryCode`.
+		/// 
+		pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+		where
+			T: Coded<Code = CountryCode>,
+			D: Deserializer<'de>,
+		{
+			Option::<String>::deserialize(deserializer)?
+				.map(|code| super::super::validate_country_code(&code).map(T::from_coded))
+				.transpose()
+				.map_err(de::Error::custom)
+		}
+	}
+}
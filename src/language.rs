@@ -25,22 +25,35 @@ mod tests;
 
 //		Packages
 
-use crate::country::CountryCode;
+use crate::{
+	AsStr,
+	country::{Country, CountryCode},
+	currency::CurrencyCode,
+	display::{DisplayStyle, Styled},
+	map::{SortedMap, self},
+	parse::{Parse, ParseOptions},
+	script::Script,
+	text::{normalize, similarity, stable_hash},
+};
 use core::{
+	cmp::Ordering,
 	fmt::{Debug, Display, self},
-	str::FromStr,
-};
-use rubedo::{
-	std::AsStr,
-	sugar::{s, vh},
+	str::{FromStr, self},
 };
 use serde::{Deserialize, Serialize};
 use std::{
-	collections::{HashMap, HashSet},
+	collections::HashSet,
 	sync::LazyLock,
 };
-use velcro::hash_map;
 
+#[cfg(feature = "plurals")]
+use crate::plural::{PluralCategory, PluralRule};
+#[cfg(feature = "defmt")]
+use defmt::{Format, Formatter};
+#[cfg(feature = "icu")]
+use icu_locale_core::subtags;
+#[cfg(feature = "json")]
+use serde_json::Value;
 #[cfg(feature = "utoipa")]
 use utoipa::ToSchema;
 
@@ -48,8 +61,19 @@ use utoipa::ToSchema;
 
 //		Constants
 
+/// The number of languages.
+/// 
+/// This is the length of [`Language::all()`], and of the other lists of all the
+/// languages, and can be used to pre-allocate storage, or to check the expected
+/// size of the dataset.
+/// 
+pub const COUNT: usize = 183;
+
 /// The possible languages.
 /// 
+/// The entries are in the order of the [`Language`] discriminants, which is relied
+/// upon for lookups, so new entries must be added to the end.
+/// 
 /// # Data sources
 ///
 /// The list of codes and other country information is available from
@@ -61,192 +85,396 @@ use utoipa::ToSchema;
 /// * [`LanguageCode`]
 /// * [`Language`]
 /// 
-static LANGUAGES: LazyLock<HashMap<Language, LanguageInfo>> = LazyLock::new(|| {
-	hash_map!{
-		Language::AA: LanguageInfo { code: LanguageCode::AA, name: s!("Afar"),              countries: vh![ CountryCode: ET ] },
-		Language::AB: LanguageInfo { code: LanguageCode::AB, name: s!("Abkhazian"),         countries: vh![] },
-		Language::AE: LanguageInfo { code: LanguageCode::AE, name: s!("Avestan"),           countries: vh![] },
-		Language::AF: LanguageInfo { code: LanguageCode::AF, name: s!("Afrikaans"),         countries: vh![ CountryCode: ZA ] },
-		Language::AK: LanguageInfo { code: LanguageCode::AK, name: s!("Akan"),              countries: vh![] },
-		Language::AM: LanguageInfo { code: LanguageCode::AM, name: s!("Amharic"),           countries: vh![ CountryCode: ET ] },
-		Language::AN: LanguageInfo { code: LanguageCode::AN, name: s!("Aragonese"),         countries: vh![] },
-		Language::AR: LanguageInfo { code: LanguageCode::AR, name: s!("Arabic"),            countries: vh![ CountryCode: AE, BH, DJ, DZ, EG, EH, IQ, JO, KM, KW, LB, LY, MA, MR, OM, PS, QA, SA, SD, SO, SY, TD, TN, YE ] },
-		Language::AS: LanguageInfo { code: LanguageCode::AS, name: s!("Assamese"),          countries: vh![] },
-		Language::AV: LanguageInfo { code: LanguageCode::AV, name: s!("Avaric"),            countries: vh![] },
-		Language::AY: LanguageInfo { code: LanguageCode::AY, name: s!("Aymara"),            countries: vh![ CountryCode: BO, PE ] },
-		Language::AZ: LanguageInfo { code: LanguageCode::AZ, name: s!("Azerbaijani"),       countries: vh![ CountryCode: AZ ] },
-		Language::BA: LanguageInfo { code: LanguageCode::BA, name: s!("Bashkir"),           countries: vh![] },
-		Language::BE: LanguageInfo { code: LanguageCode::BE, name: s!("Belarusian"),        countries: vh![ CountryCode: BY ] },
-		Language::BG: LanguageInfo { code: LanguageCode::BG, name: s!("Bulgarian"),         countries: vh![ CountryCode: BG ] },
-		Language::BI: LanguageInfo { code: LanguageCode::BI, name: s!("Bislama"),           countries: vh![ CountryCode: VU ] },
-		Language::BM: LanguageInfo { code: LanguageCode::BM, name: s!("Bambara"),           countries: vh![ CountryCode: ML ] },
-		Language::BN: LanguageInfo { code: LanguageCode::BN, name: s!("Bengali"),           countries: vh![ CountryCode: BD ] },
-		Language::BO: LanguageInfo { code: LanguageCode::BO, name: s!("Tibetan"),           countries: vh![] },
-		Language::BR: LanguageInfo { code: LanguageCode::BR, name: s!("Breton"),            countries: vh![] },
-		Language::BS: LanguageInfo { code: LanguageCode::BS, name: s!("Bosnian"),           countries: vh![ CountryCode: BA ] },
-		Language::CA: LanguageInfo { code: LanguageCode::CA, name: s!("Catalan"),           countries: vh![ CountryCode: AD ] },
-		Language::CE: LanguageInfo { code: LanguageCode::CE, name: s!("Chechen"),           countries: vh![] },
-		Language::CH: LanguageInfo { code: LanguageCode::CH, name: s!("Chamorro"),          countries: vh![ CountryCode: GU, MP ] },
-		Language::CO: LanguageInfo { code: LanguageCode::CO, name: s!("Corsican"),          countries: vh![] },
-		Language::CR: LanguageInfo { code: LanguageCode::CR, name: s!("Cree"),              countries: vh![] },
-		Language::CS: LanguageInfo { code: LanguageCode::CS, name: s!("Czech"),             countries: vh![ CountryCode: CZ ] },
-		Language::CU: LanguageInfo { code: LanguageCode::CU, name: s!("Church Slavonic"),   countries: vh![] },
-		Language::CV: LanguageInfo { code: LanguageCode::CV, name: s!("Chuvash"),           countries: vh![] },
-		Language::CY: LanguageInfo { code: LanguageCode::CY, name: s!("Welsh"),             countries: vh![] },
-		Language::DA: LanguageInfo { code: LanguageCode::DA, name: s!("Danish"),            countries: vh![ CountryCode: DK, FO, GL ] },
-		Language::DE: LanguageInfo { code: LanguageCode::DE, name: s!("German"),            countries: vh![ CountryCode: AT, BE, CH, DE, LI, LU ] },
-		Language::DV: LanguageInfo { code: LanguageCode::DV, name: s!("Divehi"),            countries: vh![ CountryCode: MV ] },
-		Language::DZ: LanguageInfo { code: LanguageCode::DZ, name: s!("Dzongkha"),          countries: vh![ CountryCode: BT ] },
-		Language::EE: LanguageInfo { code: LanguageCode::EE, name: s!("Ewe"),               countries: vh![] },
-		Language::EL: LanguageInfo { code: LanguageCode::EL, name: s!("Greek"),             countries: vh![ CountryCode: CY, GR ] },
-		Language::EN: LanguageInfo { code: LanguageCode::EN, name: s!("English"),           countries: vh![ CountryCode: AG, AI, AS, AU, BB, BI, BM, BS, BW, BZ, CA, CC, CK, CM, CW, CX, DM, FJ, FK, FM, GB, GD, GG, GH, GI, GL, GM, GS, GU, GY, HK, HM, IE, IM, IN, IO, JE, JM, KE, KI, KN, KY, LC, LR, LS, MH, MP, MS, MT, MU, MW, NA, NF, NG, NR, NU, NZ, PG, PH, PK, PN, PR, PW, RW, SB, SC, SD, SG, SH, SL, SS, SX, SZ, TC, TK, TO, TT, TV, TZ, UG, UM, US, VC, VG, VI, VU, WS, ZA, ZM, ZW ] },
-		Language::EO: LanguageInfo { code: LanguageCode::EO, name: s!("Esperanto"),         countries: vh![] },
-		Language::ES: LanguageInfo { code: LanguageCode::ES, name: s!("Spanish"),           countries: vh![ CountryCode: AR, BO, CL, CO, CR, CU, DO, EC, EH, ES, GQ, GT, HN, MX, NI, PA, PE, PR, PY, SV, UY, VE ] },
-		Language::ET: LanguageInfo { code: LanguageCode::ET, name: s!("Estonian"),          countries: vh![ CountryCode: EE ] },
-		Language::EU: LanguageInfo { code: LanguageCode::EU, name: s!("Basque"),            countries: vh![] },
-		Language::FA: LanguageInfo { code: LanguageCode::FA, name: s!("Persian"),           countries: vh![ CountryCode: AF, IR ] },
-		Language::FF: LanguageInfo { code: LanguageCode::FF, name: s!("Fulah"),             countries: vh![ CountryCode: ML ] },
-		Language::FI: LanguageInfo { code: LanguageCode::FI, name: s!("Finnish"),           countries: vh![ CountryCode: FI ] },
-		Language::FJ: LanguageInfo { code: LanguageCode::FJ, name: s!("Fijian"),            countries: vh![ CountryCode: FJ ] },
-		Language::FO: LanguageInfo { code: LanguageCode::FO, name: s!("Faroese"),           countries: vh![ CountryCode: FO ] },
-		Language::FR: LanguageInfo { code: LanguageCode::FR, name: s!("French"),            countries: vh![ CountryCode: BE, BF, BI, BJ, BL, CA, CD, CF, CG, CH, CI, CM, DJ, FR, GA, GF, GN, GP, GQ, HT, JE, KM, LU, MC, MF, MG, MQ, NC, NE, PF, PM, RE, RW, SC, SN, TD, TF, TG, VU, WF, YT ] },
-		Language::FY: LanguageInfo { code: LanguageCode::FY, name: s!("Western Frisian"),   countries: vh![] },
-		Language::GA: LanguageInfo { code: LanguageCode::GA, name: s!("Irish"),             countries: vh![ CountryCode: IE ] },
-		Language::GD: LanguageInfo { code: LanguageCode::GD, name: s!("Gaelic"),            countries: vh![] },
-		Language::GL: LanguageInfo { code: LanguageCode::GL, name: s!("Galician"),          countries: vh![] },
-		Language::GN: LanguageInfo { code: LanguageCode::GN, name: s!("Guarani"),           countries: vh![ CountryCode: BO, PY ] },
-		Language::GU: LanguageInfo { code: LanguageCode::GU, name: s!("Gujarati"),          countries: vh![] },
-		Language::GV: LanguageInfo { code: LanguageCode::GV, name: s!("Manx"),              countries: vh![ CountryCode: IM ] },
-		Language::HA: LanguageInfo { code: LanguageCode::HA, name: s!("Hausa"),             countries: vh![] },
-		Language::HE: LanguageInfo { code: LanguageCode::HE, name: s!("Hebrew"),            countries: vh![ CountryCode: IL ] },
-		Language::HI: LanguageInfo { code: LanguageCode::HI, name: s!("Hindi"),             countries: vh![ CountryCode: IN ] },
-		Language::HO: LanguageInfo { code: LanguageCode::HO, name: s!("Hiri Motu"),         countries: vh![ CountryCode: PG ] },
-		Language::HR: LanguageInfo { code: LanguageCode::HR, name: s!("Croatian"),          countries: vh![ CountryCode: BA, HR, ME ] },
-		Language::HT: LanguageInfo { code: LanguageCode::HT, name: s!("Haitian"),           countries: vh![ CountryCode: HT ] },
-		Language::HU: LanguageInfo { code: LanguageCode::HU, name: s!("Hungarian"),         countries: vh![ CountryCode: HU ] },
-		Language::HY: LanguageInfo { code: LanguageCode::HY, name: s!("Armenian"),          countries: vh![ CountryCode: AM ] },
-		Language::HZ: LanguageInfo { code: LanguageCode::HZ, name: s!("Herero"),            countries: vh![] },
-		Language::IA: LanguageInfo { code: LanguageCode::IA, name: s!("Interlingua"),       countries: vh![] },
-		Language::ID: LanguageInfo { code: LanguageCode::ID, name: s!("Indonesian"),        countries: vh![ CountryCode: ID ] },
-		Language::IE: LanguageInfo { code: LanguageCode::IE, name: s!("Interlingue"),       countries: vh![] },
-		Language::IG: LanguageInfo { code: LanguageCode::IG, name: s!("Igbo"),              countries: vh![] },
-		Language::II: LanguageInfo { code: LanguageCode::II, name: s!("Sichuan Yi"),        countries: vh![] },
-		Language::IK: LanguageInfo { code: LanguageCode::IK, name: s!("Inupiaq"),           countries: vh![] },
-		Language::IO: LanguageInfo { code: LanguageCode::IO, name: s!("Ido"),               countries: vh![] },
-		Language::IS: LanguageInfo { code: LanguageCode::IS, name: s!("Icelandic"),         countries: vh![ CountryCode: IS ] },
-		Language::IT: LanguageInfo { code: LanguageCode::IT, name: s!("Italian"),           countries: vh![ CountryCode: CH, IT, SM, VA ] },
-		Language::IU: LanguageInfo { code: LanguageCode::IU, name: s!("Inuktitut"),         countries: vh![] },
-		Language::JA: LanguageInfo { code: LanguageCode::JA, name: s!("Japanese"),          countries: vh![ CountryCode: JP ] },
-		Language::JV: LanguageInfo { code: LanguageCode::JV, name: s!("Javanese"),          countries: vh![] },
-		Language::KA: LanguageInfo { code: LanguageCode::KA, name: s!("Georgian"),          countries: vh![ CountryCode: GE ] },
-		Language::KG: LanguageInfo { code: LanguageCode::KG, name: s!("Kongo"),             countries: vh![] },
-		Language::KI: LanguageInfo { code: LanguageCode::KI, name: s!("Kikuyu"),            countries: vh![] },
-		Language::KJ: LanguageInfo { code: LanguageCode::KJ, name: s!("Kuanyama"),          countries: vh![] },
-		Language::KK: LanguageInfo { code: LanguageCode::KK, name: s!("Kazakh"),            countries: vh![ CountryCode: KZ ] },
-		Language::KL: LanguageInfo { code: LanguageCode::KL, name: s!("Kalaallisut"),       countries: vh![] },
-		Language::KM: LanguageInfo { code: LanguageCode::KM, name: s!("Central Khmer"),     countries: vh![ CountryCode: KH ] },
-		Language::KN: LanguageInfo { code: LanguageCode::KN, name: s!("Kannada"),           countries: vh![] },
-		Language::KO: LanguageInfo { code: LanguageCode::KO, name: s!("Korean"),            countries: vh![ CountryCode: KP, KR ] },
-		Language::KR: LanguageInfo { code: LanguageCode::KR, name: s!("Kanuri"),            countries: vh![] },
-		Language::KS: LanguageInfo { code: LanguageCode::KS, name: s!("Kashmiri"),          countries: vh![] },
-		Language::KU: LanguageInfo { code: LanguageCode::KU, name: s!("Kurdish"),           countries: vh![ CountryCode: IQ ] },
-		Language::KV: LanguageInfo { code: LanguageCode::KV, name: s!("Komi"),              countries: vh![] },
-		Language::KW: LanguageInfo { code: LanguageCode::KW, name: s!("Cornish"),           countries: vh![] },
-		Language::KY: LanguageInfo { code: LanguageCode::KY, name: s!("Kirghiz"),           countries: vh![ CountryCode: KG ] },
-		Language::LA: LanguageInfo { code: LanguageCode::LA, name: s!("Latin"),             countries: vh![ CountryCode: VA ] },
-		Language::LB: LanguageInfo { code: LanguageCode::LB, name: s!("Luxembourgish"),     countries: vh![ CountryCode: LU ] },
-		Language::LG: LanguageInfo { code: LanguageCode::LG, name: s!("Ganda"),             countries: vh![] },
-		Language::LI: LanguageInfo { code: LanguageCode::LI, name: s!("Limburgan"),         countries: vh![] },
-		Language::LN: LanguageInfo { code: LanguageCode::LN, name: s!("Lingala"),           countries: vh![] },
-		Language::LO: LanguageInfo { code: LanguageCode::LO, name: s!("Lao"),               countries: vh![ CountryCode: LA ] },
-		Language::LT: LanguageInfo { code: LanguageCode::LT, name: s!("Lithuanian"),        countries: vh![ CountryCode: LT ] },
-		Language::LU: LanguageInfo { code: LanguageCode::LU, name: s!("Luba-Katanga"),      countries: vh![] },
-		Language::LV: LanguageInfo { code: LanguageCode::LV, name: s!("Latvian"),           countries: vh![ CountryCode: LV ] },
-		Language::MG: LanguageInfo { code: LanguageCode::MG, name: s!("Malagasy"),          countries: vh![ CountryCode: MG ] },
-		Language::MH: LanguageInfo { code: LanguageCode::MH, name: s!("Marshallese"),       countries: vh![ CountryCode: MH ] },
-		Language::MI: LanguageInfo { code: LanguageCode::MI, name: s!("Maori"),             countries: vh![ CountryCode: NZ ] },
-		Language::MK: LanguageInfo { code: LanguageCode::MK, name: s!("Macedonian"),        countries: vh![ CountryCode: MK ] },
-		Language::ML: LanguageInfo { code: LanguageCode::ML, name: s!("Malayalam"),         countries: vh![] },
-		Language::MN: LanguageInfo { code: LanguageCode::MN, name: s!("Mongolian"),         countries: vh![ CountryCode: MN ] },
-		Language::MR: LanguageInfo { code: LanguageCode::MR, name: s!("Marathi"),           countries: vh![] },
-		Language::MS: LanguageInfo { code: LanguageCode::MS, name: s!("Malay"),             countries: vh![ CountryCode: BN, CC, CX, MY, SG ] },
-		Language::MT: LanguageInfo { code: LanguageCode::MT, name: s!("Maltese"),           countries: vh![ CountryCode: MT ] },
-		Language::MY: LanguageInfo { code: LanguageCode::MY, name: s!("Burmese"),           countries: vh![ CountryCode: MM ] },
-		Language::NA: LanguageInfo { code: LanguageCode::NA, name: s!("Nauru"),             countries: vh![ CountryCode: NR ] },
-		Language::NB: LanguageInfo { code: LanguageCode::NB, name: s!("Norwegian Bokmål"),  countries: vh![] },
-		Language::ND: LanguageInfo { code: LanguageCode::ND, name: s!("North Ndebele"),     countries: vh![] },
-		Language::NE: LanguageInfo { code: LanguageCode::NE, name: s!("Nepali"),            countries: vh![ CountryCode: NP ] },
-		Language::NG: LanguageInfo { code: LanguageCode::NG, name: s!("Ndonga"),            countries: vh![] },
-		Language::NL: LanguageInfo { code: LanguageCode::NL, name: s!("Dutch"),             countries: vh![ CountryCode: AW, BE, BQ, CW, NL, SR, SX ] },
-		Language::NN: LanguageInfo { code: LanguageCode::NN, name: s!("Norwegian Nynorsk"), countries: vh![] },
-		Language::NO: LanguageInfo { code: LanguageCode::NO, name: s!("Norwegian"),         countries: vh![ CountryCode: BV, NO, SJ ] },
-		Language::NR: LanguageInfo { code: LanguageCode::NR, name: s!("South Ndebele"),     countries: vh![ CountryCode: ZA, ZW ] },
-		Language::NV: LanguageInfo { code: LanguageCode::NV, name: s!("Navajo"),            countries: vh![] },
-		Language::NY: LanguageInfo { code: LanguageCode::NY, name: s!("Chichewa"),          countries: vh![ CountryCode: MW, ZW ] },
-		Language::OC: LanguageInfo { code: LanguageCode::OC, name: s!("Occitan"),           countries: vh![] },
-		Language::OJ: LanguageInfo { code: LanguageCode::OJ, name: s!("Ojibwa"),            countries: vh![] },
-		Language::OM: LanguageInfo { code: LanguageCode::OM, name: s!("Oromo"),             countries: vh![ CountryCode: ET ] },
-		Language::OR: LanguageInfo { code: LanguageCode::OR, name: s!("Oriya"),             countries: vh![] },
-		Language::OS: LanguageInfo { code: LanguageCode::OS, name: s!("Ossetian"),          countries: vh![] },
-		Language::PA: LanguageInfo { code: LanguageCode::PA, name: s!("Punjabi"),           countries: vh![] },
-		Language::PI: LanguageInfo { code: LanguageCode::PI, name: s!("Pali"),              countries: vh![] },
-		Language::PL: LanguageInfo { code: LanguageCode::PL, name: s!("Polish"),            countries: vh![ CountryCode: PL ] },
-		Language::PS: LanguageInfo { code: LanguageCode::PS, name: s!("Pashto"),            countries: vh![ CountryCode: AF ] },
-		Language::PT: LanguageInfo { code: LanguageCode::PT, name: s!("Portuguese"),        countries: vh![ CountryCode: AO, BR, CV, GW, MO, MZ, PT, ST, TL, GQ ] },
-		Language::QU: LanguageInfo { code: LanguageCode::QU, name: s!("Quechua"),           countries: vh![ CountryCode: BO, EC, PE ] },
-		Language::RM: LanguageInfo { code: LanguageCode::RM, name: s!("Romansh"),           countries: vh![ CountryCode: CH ] },
-		Language::RN: LanguageInfo { code: LanguageCode::RN, name: s!("Rundi"),             countries: vh![ CountryCode: BI ] },
-		Language::RO: LanguageInfo { code: LanguageCode::RO, name: s!("Romanian"),          countries: vh![ CountryCode: MD, RO ] },
-		Language::RU: LanguageInfo { code: LanguageCode::RU, name: s!("Russian"),           countries: vh![ CountryCode: BY, KG, KZ, RU ] },
-		Language::RW: LanguageInfo { code: LanguageCode::RW, name: s!("Kinyarwanda"),       countries: vh![ CountryCode: RW ] },
-		Language::SA: LanguageInfo { code: LanguageCode::SA, name: s!("Sanskrit"),          countries: vh![] },
-		Language::SC: LanguageInfo { code: LanguageCode::SC, name: s!("Sardinian"),         countries: vh![] },
-		Language::SD: LanguageInfo { code: LanguageCode::SD, name: s!("Sindhi"),            countries: vh![] },
-		Language::SE: LanguageInfo { code: LanguageCode::SE, name: s!("Northern Sami"),     countries: vh![] },
-		Language::SG: LanguageInfo { code: LanguageCode::SG, name: s!("Sango"),             countries: vh![ CountryCode: CF ] },
-		Language::SI: LanguageInfo { code: LanguageCode::SI, name: s!("Sinhala"),           countries: vh![ CountryCode: LK ] },
-		Language::SK: LanguageInfo { code: LanguageCode::SK, name: s!("Slovak"),            countries: vh![ CountryCode: CZ, SK ] },
-		Language::SL: LanguageInfo { code: LanguageCode::SL, name: s!("Slovenian"),         countries: vh![ CountryCode: SI ] },
-		Language::SM: LanguageInfo { code: LanguageCode::SM, name: s!("Samoan"),            countries: vh![ CountryCode: AS, WS ] },
-		Language::SN: LanguageInfo { code: LanguageCode::SN, name: s!("Shona"),             countries: vh![ CountryCode: ZW ] },
-		Language::SO: LanguageInfo { code: LanguageCode::SO, name: s!("Somali"),            countries: vh![ CountryCode: ET, SO ] },
-		Language::SQ: LanguageInfo { code: LanguageCode::SQ, name: s!("Albanian"),          countries: vh![ CountryCode: AL, MK ] },
-		Language::SR: LanguageInfo { code: LanguageCode::SR, name: s!("Serbian"),           countries: vh![ CountryCode: BA, ME, RS ] },
-		Language::SS: LanguageInfo { code: LanguageCode::SS, name: s!("Swati"),             countries: vh![ CountryCode: SZ, ZA ] },
-		Language::ST: LanguageInfo { code: LanguageCode::ST, name: s!("Southern Sotho"),    countries: vh![ CountryCode: LS, ZA, ZW ] },
-		Language::SU: LanguageInfo { code: LanguageCode::SU, name: s!("Sundanese"),         countries: vh![] },
-		Language::SV: LanguageInfo { code: LanguageCode::SV, name: s!("Swedish"),           countries: vh![ CountryCode: AX, FI, SE ] },
-		Language::SW: LanguageInfo { code: LanguageCode::SW, name: s!("Swahili"),           countries: vh![ CountryCode: KE, RW, TZ, UG ] },
-		Language::TA: LanguageInfo { code: LanguageCode::TA, name: s!("Tamil"),             countries: vh![ CountryCode: LK, SG ] },
-		Language::TE: LanguageInfo { code: LanguageCode::TE, name: s!("Telugu"),            countries: vh![] },
-		Language::TG: LanguageInfo { code: LanguageCode::TG, name: s!("Tajik"),             countries: vh![ CountryCode: TJ ] },
-		Language::TH: LanguageInfo { code: LanguageCode::TH, name: s!("Thai"),              countries: vh![ CountryCode: TH ] },
-		Language::TI: LanguageInfo { code: LanguageCode::TI, name: s!("Tigrinya"),          countries: vh![ CountryCode: ER, ET ] },
-		Language::TK: LanguageInfo { code: LanguageCode::TK, name: s!("Turkmen"),           countries: vh![ CountryCode: TM ] },
-		Language::TL: LanguageInfo { code: LanguageCode::TL, name: s!("Tagalog"),           countries: vh![ CountryCode: PH ] },
-		Language::TN: LanguageInfo { code: LanguageCode::TN, name: s!("Tswana"),            countries: vh![ CountryCode: ZA, ZW ] },
-		Language::TO: LanguageInfo { code: LanguageCode::TO, name: s!("Tonga"),             countries: vh![ CountryCode: TO ] },
-		Language::TR: LanguageInfo { code: LanguageCode::TR, name: s!("Turkish"),           countries: vh![ CountryCode: CY, TR ] },
-		Language::TS: LanguageInfo { code: LanguageCode::TS, name: s!("Tsonga"),            countries: vh![ CountryCode: ZA ] },
-		Language::TT: LanguageInfo { code: LanguageCode::TT, name: s!("Tatar"),             countries: vh![] },
-		Language::TW: LanguageInfo { code: LanguageCode::TW, name: s!("Twi"),               countries: vh![] },
-		Language::TY: LanguageInfo { code: LanguageCode::TY, name: s!("Tahitian"),          countries: vh![] },
-		Language::UG: LanguageInfo { code: LanguageCode::UG, name: s!("Uighur"),            countries: vh![] },
-		Language::UK: LanguageInfo { code: LanguageCode::UK, name: s!("Ukrainian"),         countries: vh![ CountryCode: UA ] },
-		Language::UR: LanguageInfo { code: LanguageCode::UR, name: s!("Urdu"),              countries: vh![ CountryCode: PK ] },
-		Language::UZ: LanguageInfo { code: LanguageCode::UZ, name: s!("Uzbek"),             countries: vh![ CountryCode: UZ ] },
-		Language::VE: LanguageInfo { code: LanguageCode::VE, name: s!("Venda"),             countries: vh![ CountryCode: ZA, ZW ] },
-		Language::VI: LanguageInfo { code: LanguageCode::VI, name: s!("Vietnamese"),        countries: vh![ CountryCode: VN ] },
-		Language::VO: LanguageInfo { code: LanguageCode::VO, name: s!("Volapük"),           countries: vh![] },
-		Language::WA: LanguageInfo { code: LanguageCode::WA, name: s!("Walloon"),           countries: vh![] },
-		Language::WO: LanguageInfo { code: LanguageCode::WO, name: s!("Wolof"),             countries: vh![] },
-		Language::XH: LanguageInfo { code: LanguageCode::XH, name: s!("Xhosa"),             countries: vh![ CountryCode: ZA, ZW ] },
-		Language::YI: LanguageInfo { code: LanguageCode::YI, name: s!("Yiddish"),           countries: vh![] },
-		Language::YO: LanguageInfo { code: LanguageCode::YO, name: s!("Yoruba"),            countries: vh![] },
-		Language::ZA: LanguageInfo { code: LanguageCode::ZA, name: s!("Zhuang"),            countries: vh![] },
-		Language::ZH: LanguageInfo { code: LanguageCode::ZH, name: s!("Chinese"),           countries: vh![ CountryCode: CN, CX, HK, MO, SG, TW ] },
-		Language::ZU: LanguageInfo { code: LanguageCode::ZU, name: s!("Zulu"),              countries: vh![ CountryCode: ZA ] },
-	}
+static LANGUAGES: [LanguageInfo; COUNT] = [
+	LanguageInfo { code: LanguageCode::AA, name: "Afar",              family: Some(LanguageFamily::CUS), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ET ] },
+	LanguageInfo { code: LanguageCode::AB, name: "Abkhazian",         family: Some(LanguageFamily::CCN), scripts: &[ Script::Cyrl ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::AE, name: "Avestan",           family: Some(LanguageFamily::IRA), scripts: &[ Script::Avst ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::AF, name: "Afrikaans",         family: Some(LanguageFamily::GMW), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ZA ] },
+	LanguageInfo { code: LanguageCode::AK, name: "Akan",              family: Some(LanguageFamily::ALV), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::AM, name: "Amharic",           family: Some(LanguageFamily::SEM), scripts: &[ Script::Ethi ],                                           countries: &[ CountryCode::ET ] },
+	LanguageInfo { code: LanguageCode::AN, name: "Aragonese",         family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::AR, name: "Arabic",            family: Some(LanguageFamily::SEM), scripts: &[ Script::Arab ],                                           countries: &[ CountryCode::AE, CountryCode::BH, CountryCode::DJ, CountryCode::DZ, CountryCode::EG, CountryCode::EH, CountryCode::IQ, CountryCode::JO, CountryCode::KM, CountryCode::KW, CountryCode::LB, CountryCode::LY, CountryCode::MA, CountryCode::MR, CountryCode::OM, CountryCode::PS, CountryCode::QA, CountryCode::SA, CountryCode::SD, CountryCode::SO, CountryCode::SY, CountryCode::TD, CountryCode::TN, CountryCode::YE ] },
+	LanguageInfo { code: LanguageCode::AS, name: "Assamese",          family: Some(LanguageFamily::INC), scripts: &[ Script::Beng ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::AV, name: "Avaric",            family: Some(LanguageFamily::CCN), scripts: &[ Script::Cyrl ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::AY, name: "Aymara",            family: Some(LanguageFamily::SAI), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::BO, CountryCode::PE ] },
+	LanguageInfo { code: LanguageCode::AZ, name: "Azerbaijani",       family: Some(LanguageFamily::TRK), scripts: &[ Script::Latn, Script::Cyrl, Script::Arab ],               countries: &[ CountryCode::AZ ] },
+	LanguageInfo { code: LanguageCode::BA, name: "Bashkir",           family: Some(LanguageFamily::TRK), scripts: &[ Script::Cyrl ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::BE, name: "Belarusian",        family: Some(LanguageFamily::ZLE), scripts: &[ Script::Cyrl ],                                           countries: &[ CountryCode::BY ] },
+	LanguageInfo { code: LanguageCode::BG, name: "Bulgarian",         family: Some(LanguageFamily::ZLS), scripts: &[ Script::Cyrl ],                                           countries: &[ CountryCode::BG ] },
+	LanguageInfo { code: LanguageCode::BI, name: "Bislama",           family: Some(LanguageFamily::CRP), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::VU ] },
+	LanguageInfo { code: LanguageCode::BM, name: "Bambara",           family: Some(LanguageFamily::DMN), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ML ] },
+	LanguageInfo { code: LanguageCode::BN, name: "Bengali",           family: Some(LanguageFamily::INC), scripts: &[ Script::Beng ],                                           countries: &[ CountryCode::BD ] },
+	LanguageInfo { code: LanguageCode::BO, name: "Tibetan",           family: Some(LanguageFamily::TBQ), scripts: &[ Script::Tibt ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::BR, name: "Breton",            family: Some(LanguageFamily::CEL), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::BS, name: "Bosnian",           family: Some(LanguageFamily::ZLS), scripts: &[ Script::Latn, Script::Cyrl ],                             countries: &[ CountryCode::BA ] },
+	LanguageInfo { code: LanguageCode::CA, name: "Catalan",           family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::AD ] },
+	LanguageInfo { code: LanguageCode::CE, name: "Chechen",           family: Some(LanguageFamily::CCN), scripts: &[ Script::Cyrl ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::CH, name: "Chamorro",          family: Some(LanguageFamily::POZ), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::GU, CountryCode::MP ] },
+	LanguageInfo { code: LanguageCode::CO, name: "Corsican",          family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::CR, name: "Cree",              family: Some(LanguageFamily::ALG), scripts: &[ Script::Cans, Script::Latn ],                             countries: &[] },
+	LanguageInfo { code: LanguageCode::CS, name: "Czech",             family: Some(LanguageFamily::ZLW), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::CZ ] },
+	LanguageInfo { code: LanguageCode::CU, name: "Church Slavonic",   family: Some(LanguageFamily::ZLS), scripts: &[ Script::Cyrl, Script::Glag ],                             countries: &[] },
+	LanguageInfo { code: LanguageCode::CV, name: "Chuvash",           family: Some(LanguageFamily::TRK), scripts: &[ Script::Cyrl ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::CY, name: "Welsh",             family: Some(LanguageFamily::CEL), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::DA, name: "Danish",            family: Some(LanguageFamily::GMQ), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::DK, CountryCode::FO, CountryCode::GL ] },
+	LanguageInfo { code: LanguageCode::DE, name: "German",            family: Some(LanguageFamily::GMW), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::AT, CountryCode::BE, CountryCode::CH, CountryCode::DE, CountryCode::LI, CountryCode::LU ] },
+	LanguageInfo { code: LanguageCode::DV, name: "Divehi",            family: Some(LanguageFamily::INC), scripts: &[ Script::Thaa ],                                           countries: &[ CountryCode::MV ] },
+	LanguageInfo { code: LanguageCode::DZ, name: "Dzongkha",          family: Some(LanguageFamily::TBQ), scripts: &[ Script::Tibt ],                                           countries: &[ CountryCode::BT ] },
+	LanguageInfo { code: LanguageCode::EE, name: "Ewe",               family: Some(LanguageFamily::ALV), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::EL, name: "Greek",             family: Some(LanguageFamily::GRK), scripts: &[ Script::Grek ],                                           countries: &[ CountryCode::CY, CountryCode::GR ] },
+	LanguageInfo { code: LanguageCode::EN, name: "English",           family: Some(LanguageFamily::GMW), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::AG, CountryCode::AI, CountryCode::AS, CountryCode::AU, CountryCode::BB, CountryCode::BI, CountryCode::BM, CountryCode::BS, CountryCode::BW, CountryCode::BZ, CountryCode::CA, CountryCode::CC, CountryCode::CK, CountryCode::CM, CountryCode::CW, CountryCode::CX, CountryCode::DM, CountryCode::FJ, CountryCode::FK, CountryCode::FM, CountryCode::GB, CountryCode::GD, CountryCode::GG, CountryCode::GH, CountryCode::GI, CountryCode::GL, CountryCode::GM, CountryCode::GS, CountryCode::GU, CountryCode::GY, CountryCode::HK, CountryCode::HM, CountryCode::IE, CountryCode::IM, CountryCode::IN, CountryCode::IO, CountryCode::JE, CountryCode::JM, CountryCode::KE, CountryCode::KI, CountryCode::KN, CountryCode::KY, CountryCode::LC, CountryCode::LR, CountryCode::LS, CountryCode::MH, CountryCode::MP, CountryCode::MS, CountryCode::MT, CountryCode::MU, CountryCode::MW, CountryCode::NA, CountryCode::NF, CountryCode::NG, CountryCode::NR, CountryCode::NU, CountryCode::NZ, CountryCode::PG, CountryCode::PH, CountryCode::PK, CountryCode::PN, CountryCode::PR, CountryCode::PW, CountryCode::RW, CountryCode::SB, CountryCode::SC, CountryCode::SD, CountryCode::SG, CountryCode::SH, CountryCode::SL, CountryCode::SS, CountryCode::SX, CountryCode::SZ, CountryCode::TC, CountryCode::TK, CountryCode::TO, CountryCode::TT, CountryCode::TV, CountryCode::TZ, CountryCode::UG, CountryCode::UM, CountryCode::US, CountryCode::VC, CountryCode::VG, CountryCode::VI, CountryCode::VU, CountryCode::WS, CountryCode::ZA, CountryCode::ZM, CountryCode::ZW ] },
+	LanguageInfo { code: LanguageCode::EO, name: "Esperanto",         family: Some(LanguageFamily::ART), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::ES, name: "Spanish",           family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::AR, CountryCode::BO, CountryCode::CL, CountryCode::CO, CountryCode::CR, CountryCode::CU, CountryCode::DO, CountryCode::EC, CountryCode::EH, CountryCode::ES, CountryCode::GQ, CountryCode::GT, CountryCode::HN, CountryCode::MX, CountryCode::NI, CountryCode::PA, CountryCode::PE, CountryCode::PR, CountryCode::PY, CountryCode::SV, CountryCode::UY, CountryCode::VE ] },
+	LanguageInfo { code: LanguageCode::ET, name: "Estonian",          family: Some(LanguageFamily::FIU), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::EE ] },
+	LanguageInfo { code: LanguageCode::EU, name: "Basque",            family: None,                      scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::FA, name: "Persian",           family: Some(LanguageFamily::IRA), scripts: &[ Script::Arab ],                                           countries: &[ CountryCode::AF, CountryCode::IR ] },
+	LanguageInfo { code: LanguageCode::FF, name: "Fulah",             family: Some(LanguageFamily::ALV), scripts: &[ Script::Latn, Script::Adlm ],                             countries: &[ CountryCode::ML ] },
+	LanguageInfo { code: LanguageCode::FI, name: "Finnish",           family: Some(LanguageFamily::FIU), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::FI ] },
+	LanguageInfo { code: LanguageCode::FJ, name: "Fijian",            family: Some(LanguageFamily::PQE), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::FJ ] },
+	LanguageInfo { code: LanguageCode::FO, name: "Faroese",           family: Some(LanguageFamily::GMQ), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::FO ] },
+	LanguageInfo { code: LanguageCode::FR, name: "French",            family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::BE, CountryCode::BF, CountryCode::BI, CountryCode::BJ, CountryCode::BL, CountryCode::CA, CountryCode::CD, CountryCode::CF, CountryCode::CG, CountryCode::CH, CountryCode::CI, CountryCode::CM, CountryCode::DJ, CountryCode::FR, CountryCode::GA, CountryCode::GF, CountryCode::GN, CountryCode::GP, CountryCode::GQ, CountryCode::HT, CountryCode::JE, CountryCode::KM, CountryCode::LU, CountryCode::MC, CountryCode::MF, CountryCode::MG, CountryCode::MQ, CountryCode::NC, CountryCode::NE, CountryCode::PF, CountryCode::PM, CountryCode::RE, CountryCode::RW, CountryCode::SC, CountryCode::SN, CountryCode::TD, CountryCode::TF, CountryCode::TG, CountryCode::VU, CountryCode::WF, CountryCode::YT ] },
+	LanguageInfo { code: LanguageCode::FY, name: "Western Frisian",   family: Some(LanguageFamily::GMW), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::GA, name: "Irish",             family: Some(LanguageFamily::CEL), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::IE ] },
+	LanguageInfo { code: LanguageCode::GD, name: "Gaelic",            family: Some(LanguageFamily::CEL), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::GL, name: "Galician",          family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::GN, name: "Guarani",           family: Some(LanguageFamily::SAI), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::BO, CountryCode::PY ] },
+	LanguageInfo { code: LanguageCode::GU, name: "Gujarati",          family: Some(LanguageFamily::INC), scripts: &[ Script::Gujr ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::GV, name: "Manx",              family: Some(LanguageFamily::CEL), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::IM ] },
+	LanguageInfo { code: LanguageCode::HA, name: "Hausa",             family: Some(LanguageFamily::CDC), scripts: &[ Script::Latn, Script::Arab ],                             countries: &[] },
+	LanguageInfo { code: LanguageCode::HE, name: "Hebrew",            family: Some(LanguageFamily::SEM), scripts: &[ Script::Hebr ],                                           countries: &[ CountryCode::IL ] },
+	LanguageInfo { code: LanguageCode::HI, name: "Hindi",             family: Some(LanguageFamily::INC), scripts: &[ Script::Deva ],                                           countries: &[ CountryCode::IN ] },
+	LanguageInfo { code: LanguageCode::HO, name: "Hiri Motu",         family: Some(LanguageFamily::PQE), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::PG ] },
+	LanguageInfo { code: LanguageCode::HR, name: "Croatian",          family: Some(LanguageFamily::ZLS), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::BA, CountryCode::HR, CountryCode::ME ] },
+	LanguageInfo { code: LanguageCode::HT, name: "Haitian",           family: Some(LanguageFamily::CRP), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::HT ] },
+	LanguageInfo { code: LanguageCode::HU, name: "Hungarian",         family: Some(LanguageFamily::FIU), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::HU ] },
+	LanguageInfo { code: LanguageCode::HY, name: "Armenian",          family: Some(LanguageFamily::HYX), scripts: &[ Script::Armn ],                                           countries: &[ CountryCode::AM ] },
+	LanguageInfo { code: LanguageCode::HZ, name: "Herero",            family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::IA, name: "Interlingua",       family: Some(LanguageFamily::ART), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::ID, name: "Indonesian",        family: Some(LanguageFamily::POZ), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ID ] },
+	LanguageInfo { code: LanguageCode::IE, name: "Interlingue",       family: Some(LanguageFamily::ART), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::IG, name: "Igbo",              family: Some(LanguageFamily::ALV), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::II, name: "Sichuan Yi",        family: Some(LanguageFamily::TBQ), scripts: &[ Script::Yiii ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::IK, name: "Inupiaq",           family: Some(LanguageFamily::ESX), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::IO, name: "Ido",               family: Some(LanguageFamily::ART), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::IS, name: "Icelandic",         family: Some(LanguageFamily::GMQ), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::IS ] },
+	LanguageInfo { code: LanguageCode::IT, name: "Italian",           family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::CH, CountryCode::IT, CountryCode::SM, CountryCode::VA ] },
+	LanguageInfo { code: LanguageCode::IU, name: "Inuktitut",         family: Some(LanguageFamily::ESX), scripts: &[ Script::Cans, Script::Latn ],                             countries: &[] },
+	LanguageInfo { code: LanguageCode::JA, name: "Japanese",          family: Some(LanguageFamily::JPX), scripts: &[ Script::Jpan ],                                           countries: &[ CountryCode::JP ] },
+	LanguageInfo { code: LanguageCode::JV, name: "Javanese",          family: Some(LanguageFamily::POZ), scripts: &[ Script::Latn, Script::Java ],                             countries: &[] },
+	LanguageInfo { code: LanguageCode::KA, name: "Georgian",          family: Some(LanguageFamily::CCS), scripts: &[ Script::Geor ],                                           countries: &[ CountryCode::GE ] },
+	LanguageInfo { code: LanguageCode::KG, name: "Kongo",             family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::KI, name: "Kikuyu",            family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::KJ, name: "Kuanyama",          family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::KK, name: "Kazakh",            family: Some(LanguageFamily::TRK), scripts: &[ Script::Cyrl, Script::Latn, Script::Arab ],               countries: &[ CountryCode::KZ ] },
+	LanguageInfo { code: LanguageCode::KL, name: "Kalaallisut",       family: Some(LanguageFamily::ESX), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::KM, name: "Central Khmer",     family: Some(LanguageFamily::MKH), scripts: &[ Script::Khmr ],                                           countries: &[ CountryCode::KH ] },
+	LanguageInfo { code: LanguageCode::KN, name: "Kannada",           family: Some(LanguageFamily::DRA), scripts: &[ Script::Knda ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::KO, name: "Korean",            family: None,                      scripts: &[ Script::Kore ],                                           countries: &[ CountryCode::KP, CountryCode::KR ] },
+	LanguageInfo { code: LanguageCode::KR, name: "Kanuri",            family: Some(LanguageFamily::SSA), scripts: &[ Script::Latn, Script::Arab ],                             countries: &[] },
+	LanguageInfo { code: LanguageCode::KS, name: "Kashmiri",          family: Some(LanguageFamily::INC), scripts: &[ Script::Arab, Script::Deva ],                             countries: &[] },
+	LanguageInfo { code: LanguageCode::KU, name: "Kurdish",           family: Some(LanguageFamily::IRA), scripts: &[ Script::Latn, Script::Arab ],                             countries: &[ CountryCode::IQ ] },
+	LanguageInfo { code: LanguageCode::KV, name: "Komi",              family: Some(LanguageFamily::FIU), scripts: &[ Script::Cyrl ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::KW, name: "Cornish",           family: Some(LanguageFamily::CEL), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::KY, name: "Kirghiz",           family: Some(LanguageFamily::TRK), scripts: &[ Script::Cyrl ],                                           countries: &[ CountryCode::KG ] },
+	LanguageInfo { code: LanguageCode::LA, name: "Latin",             family: Some(LanguageFamily::ITC), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::VA ] },
+	LanguageInfo { code: LanguageCode::LB, name: "Luxembourgish",     family: Some(LanguageFamily::GMW), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::LU ] },
+	LanguageInfo { code: LanguageCode::LG, name: "Ganda",             family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::LI, name: "Limburgan",         family: Some(LanguageFamily::GMW), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::LN, name: "Lingala",           family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::LO, name: "Lao",               family: Some(LanguageFamily::TAI), scripts: &[ Script::Laoo ],                                           countries: &[ CountryCode::LA ] },
+	LanguageInfo { code: LanguageCode::LT, name: "Lithuanian",        family: Some(LanguageFamily::BAT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::LT ] },
+	LanguageInfo { code: LanguageCode::LU, name: "Luba-Katanga",      family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::LV, name: "Latvian",           family: Some(LanguageFamily::BAT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::LV ] },
+	LanguageInfo { code: LanguageCode::MG, name: "Malagasy",          family: Some(LanguageFamily::POZ), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::MG ] },
+	LanguageInfo { code: LanguageCode::MH, name: "Marshallese",       family: Some(LanguageFamily::PQE), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::MH ] },
+	LanguageInfo { code: LanguageCode::MI, name: "Maori",             family: Some(LanguageFamily::PQE), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::NZ ] },
+	LanguageInfo { code: LanguageCode::MK, name: "Macedonian",        family: Some(LanguageFamily::ZLS), scripts: &[ Script::Cyrl ],                                           countries: &[ CountryCode::MK ] },
+	LanguageInfo { code: LanguageCode::ML, name: "Malayalam",         family: Some(LanguageFamily::DRA), scripts: &[ Script::Mlym ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::MN, name: "Mongolian",         family: Some(LanguageFamily::XGN), scripts: &[ Script::Cyrl, Script::Mong ],                             countries: &[ CountryCode::MN ] },
+	LanguageInfo { code: LanguageCode::MR, name: "Marathi",           family: Some(LanguageFamily::INC), scripts: &[ Script::Deva ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::MS, name: "Malay",             family: Some(LanguageFamily::POZ), scripts: &[ Script::Latn, Script::Arab ],                             countries: &[ CountryCode::BN, CountryCode::CC, CountryCode::CX, CountryCode::MY, CountryCode::SG ] },
+	LanguageInfo { code: LanguageCode::MT, name: "Maltese",           family: Some(LanguageFamily::SEM), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::MT ] },
+	LanguageInfo { code: LanguageCode::MY, name: "Burmese",           family: Some(LanguageFamily::TBQ), scripts: &[ Script::Mymr ],                                           countries: &[ CountryCode::MM ] },
+	LanguageInfo { code: LanguageCode::NA, name: "Nauru",             family: Some(LanguageFamily::PQE), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::NR ] },
+	LanguageInfo { code: LanguageCode::NB, name: "Norwegian Bokmål",  family: Some(LanguageFamily::GMQ), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::ND, name: "North Ndebele",     family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::NE, name: "Nepali",            family: Some(LanguageFamily::INC), scripts: &[ Script::Deva ],                                           countries: &[ CountryCode::NP ] },
+	LanguageInfo { code: LanguageCode::NG, name: "Ndonga",            family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::NL, name: "Dutch",             family: Some(LanguageFamily::GMW), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::AW, CountryCode::BE, CountryCode::BQ, CountryCode::CW, CountryCode::NL, CountryCode::SR, CountryCode::SX ] },
+	LanguageInfo { code: LanguageCode::NN, name: "Norwegian Nynorsk", family: Some(LanguageFamily::GMQ), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::NO, name: "Norwegian",         family: Some(LanguageFamily::GMQ), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::BV, CountryCode::NO, CountryCode::SJ ] },
+	LanguageInfo { code: LanguageCode::NR, name: "South Ndebele",     family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ZA, CountryCode::ZW ] },
+	LanguageInfo { code: LanguageCode::NV, name: "Navajo",            family: Some(LanguageFamily::ATH), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::NY, name: "Chichewa",          family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::MW, CountryCode::ZW ] },
+	LanguageInfo { code: LanguageCode::OC, name: "Occitan",           family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::OJ, name: "Ojibwa",            family: Some(LanguageFamily::ALG), scripts: &[ Script::Latn, Script::Cans ],                             countries: &[] },
+	LanguageInfo { code: LanguageCode::OM, name: "Oromo",             family: Some(LanguageFamily::CUS), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ET ] },
+	LanguageInfo { code: LanguageCode::OR, name: "Oriya",             family: Some(LanguageFamily::INC), scripts: &[ Script::Orya ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::OS, name: "Ossetian",          family: Some(LanguageFamily::IRA), scripts: &[ Script::Cyrl ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::PA, name: "Punjabi",           family: Some(LanguageFamily::INC), scripts: &[ Script::Guru, Script::Arab ],                             countries: &[] },
+	LanguageInfo { code: LanguageCode::PI, name: "Pali",              family: Some(LanguageFamily::INC), scripts: &[ Script::Deva, Script::Sinh, Script::Thai, Script::Mymr ], countries: &[] },
+	LanguageInfo { code: LanguageCode::PL, name: "Polish",            family: Some(LanguageFamily::ZLW), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::PL ] },
+	LanguageInfo { code: LanguageCode::PS, name: "Pashto",            family: Some(LanguageFamily::IRA), scripts: &[ Script::Arab ],                                           countries: &[ CountryCode::AF ] },
+	LanguageInfo { code: LanguageCode::PT, name: "Portuguese",        family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::AO, CountryCode::BR, CountryCode::CV, CountryCode::GQ, CountryCode::GW, CountryCode::MO, CountryCode::MZ, CountryCode::PT, CountryCode::ST, CountryCode::TL ] },
+	LanguageInfo { code: LanguageCode::QU, name: "Quechua",           family: Some(LanguageFamily::QWE), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::BO, CountryCode::EC, CountryCode::PE ] },
+	LanguageInfo { code: LanguageCode::RM, name: "Romansh",           family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::CH ] },
+	LanguageInfo { code: LanguageCode::RN, name: "Rundi",             family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::BI ] },
+	LanguageInfo { code: LanguageCode::RO, name: "Romanian",          family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::MD, CountryCode::RO ] },
+	LanguageInfo { code: LanguageCode::RU, name: "Russian",           family: Some(LanguageFamily::ZLE), scripts: &[ Script::Cyrl ],                                           countries: &[ CountryCode::BY, CountryCode::KG, CountryCode::KZ, CountryCode::RU ] },
+	LanguageInfo { code: LanguageCode::RW, name: "Kinyarwanda",       family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::RW ] },
+	LanguageInfo { code: LanguageCode::SA, name: "Sanskrit",          family: Some(LanguageFamily::INC), scripts: &[ Script::Deva ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::SC, name: "Sardinian",         family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::SD, name: "Sindhi",            family: Some(LanguageFamily::INC), scripts: &[ Script::Arab, Script::Deva ],                             countries: &[] },
+	LanguageInfo { code: LanguageCode::SE, name: "Northern Sami",     family: Some(LanguageFamily::FIU), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::SG, name: "Sango",             family: Some(LanguageFamily::CRP), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::CF ] },
+	LanguageInfo { code: LanguageCode::SI, name: "Sinhala",           family: Some(LanguageFamily::INC), scripts: &[ Script::Sinh ],                                           countries: &[ CountryCode::LK ] },
+	LanguageInfo { code: LanguageCode::SK, name: "Slovak",            family: Some(LanguageFamily::ZLW), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::CZ, CountryCode::SK ] },
+	LanguageInfo { code: LanguageCode::SL, name: "Slovenian",         family: Some(LanguageFamily::ZLS), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::SI ] },
+	LanguageInfo { code: LanguageCode::SM, name: "Samoan",            family: Some(LanguageFamily::PQE), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::AS, CountryCode::WS ] },
+	LanguageInfo { code: LanguageCode::SN, name: "Shona",             family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ZW ] },
+	LanguageInfo { code: LanguageCode::SO, name: "Somali",            family: Some(LanguageFamily::CUS), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ET, CountryCode::SO ] },
+	LanguageInfo { code: LanguageCode::SQ, name: "Albanian",          family: Some(LanguageFamily::SQJ), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::AL, CountryCode::MK ] },
+	LanguageInfo { code: LanguageCode::SR, name: "Serbian",           family: Some(LanguageFamily::ZLS), scripts: &[ Script::Cyrl, Script::Latn ],                             countries: &[ CountryCode::BA, CountryCode::ME, CountryCode::RS ] },
+	LanguageInfo { code: LanguageCode::SS, name: "Swati",             family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::SZ, CountryCode::ZA ] },
+	LanguageInfo { code: LanguageCode::ST, name: "Southern Sotho",    family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::LS, CountryCode::ZA, CountryCode::ZW ] },
+	LanguageInfo { code: LanguageCode::SU, name: "Sundanese",         family: Some(LanguageFamily::POZ), scripts: &[ Script::Latn, Script::Sund ],                             countries: &[] },
+	LanguageInfo { code: LanguageCode::SV, name: "Swedish",           family: Some(LanguageFamily::GMQ), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::AX, CountryCode::FI, CountryCode::SE ] },
+	LanguageInfo { code: LanguageCode::SW, name: "Swahili",           family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::KE, CountryCode::RW, CountryCode::TZ, CountryCode::UG ] },
+	LanguageInfo { code: LanguageCode::TA, name: "Tamil",             family: Some(LanguageFamily::DRA), scripts: &[ Script::Taml ],                                           countries: &[ CountryCode::LK, CountryCode::SG ] },
+	LanguageInfo { code: LanguageCode::TE, name: "Telugu",            family: Some(LanguageFamily::DRA), scripts: &[ Script::Telu ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::TG, name: "Tajik",             family: Some(LanguageFamily::IRA), scripts: &[ Script::Cyrl ],                                           countries: &[ CountryCode::TJ ] },
+	LanguageInfo { code: LanguageCode::TH, name: "Thai",              family: Some(LanguageFamily::TAI), scripts: &[ Script::Thai ],                                           countries: &[ CountryCode::TH ] },
+	LanguageInfo { code: LanguageCode::TI, name: "Tigrinya",          family: Some(LanguageFamily::SEM), scripts: &[ Script::Ethi ],                                           countries: &[ CountryCode::ER, CountryCode::ET ] },
+	LanguageInfo { code: LanguageCode::TK, name: "Turkmen",           family: Some(LanguageFamily::TRK), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::TM ] },
+	LanguageInfo { code: LanguageCode::TL, name: "Tagalog",           family: Some(LanguageFamily::POZ), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::PH ] },
+	LanguageInfo { code: LanguageCode::TN, name: "Tswana",            family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ZA, CountryCode::ZW ] },
+	LanguageInfo { code: LanguageCode::TO, name: "Tonga",             family: Some(LanguageFamily::PQE), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::TO ] },
+	LanguageInfo { code: LanguageCode::TR, name: "Turkish",           family: Some(LanguageFamily::TRK), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::CY, CountryCode::TR ] },
+	LanguageInfo { code: LanguageCode::TS, name: "Tsonga",            family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ZA ] },
+	LanguageInfo { code: LanguageCode::TT, name: "Tatar",             family: Some(LanguageFamily::TRK), scripts: &[ Script::Cyrl ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::TW, name: "Twi",               family: Some(LanguageFamily::ALV), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::TY, name: "Tahitian",          family: Some(LanguageFamily::PQE), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::UG, name: "Uighur",            family: Some(LanguageFamily::TRK), scripts: &[ Script::Arab, Script::Cyrl, Script::Latn ],               countries: &[] },
+	LanguageInfo { code: LanguageCode::UK, name: "Ukrainian",         family: Some(LanguageFamily::ZLE), scripts: &[ Script::Cyrl ],                                           countries: &[ CountryCode::UA ] },
+	LanguageInfo { code: LanguageCode::UR, name: "Urdu",              family: Some(LanguageFamily::INC), scripts: &[ Script::Arab ],                                           countries: &[ CountryCode::PK ] },
+	LanguageInfo { code: LanguageCode::UZ, name: "Uzbek",             family: Some(LanguageFamily::TRK), scripts: &[ Script::Latn, Script::Cyrl ],                             countries: &[ CountryCode::UZ ] },
+	LanguageInfo { code: LanguageCode::VE, name: "Venda",             family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ZA, CountryCode::ZW ] },
+	LanguageInfo { code: LanguageCode::VI, name: "Vietnamese",        family: Some(LanguageFamily::MKH), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::VN ] },
+	LanguageInfo { code: LanguageCode::VO, name: "Volapük",           family: Some(LanguageFamily::ART), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::WA, name: "Walloon",           family: Some(LanguageFamily::ROA), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::WO, name: "Wolof",             family: Some(LanguageFamily::ALV), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::XH, name: "Xhosa",             family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ZA, CountryCode::ZW ] },
+	LanguageInfo { code: LanguageCode::YI, name: "Yiddish",           family: Some(LanguageFamily::GMW), scripts: &[ Script::Hebr ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::YO, name: "Yoruba",            family: Some(LanguageFamily::ALV), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::ZA, name: "Zhuang",            family: Some(LanguageFamily::TAI), scripts: &[ Script::Latn ],                                           countries: &[] },
+	LanguageInfo { code: LanguageCode::ZH, name: "Chinese",           family: Some(LanguageFamily::ZHX), scripts: &[ Script::Hans, Script::Hant ],                             countries: &[ CountryCode::CN, CountryCode::CX, CountryCode::HK, CountryCode::MO, CountryCode::SG, CountryCode::TW ] },
+	LanguageInfo { code: LanguageCode::ZU, name: "Zulu",              family: Some(LanguageFamily::BNT), scripts: &[ Script::Latn ],                                           countries: &[ CountryCode::ZA ] },
+];
+
+/// Alternative names for the languages.
+/// 
+/// These are the alternative names and spellings given by ISO 639-2, such as
+/// "Castilian" for Spanish, along with some widely-used variants, such as
+/// "Farsi" for Persian. The entries are sorted by language code, and then by
+/// name.
+/// 
+/// # Data sources
+/// 
+/// The alternative names are available from
+/// [the Library of Congress](https://www.loc.gov/standards/iso639-2/php/code_list.php),
+/// which is the registration authority for ISO 639-2.
+/// 
+/// # See also
+/// 
+/// * [`Language::alternative_names()`]
+/// 
+static ALIASES: [(&str, LanguageCode); 54] = [
+	("Azeri",                 LanguageCode::AZ),
+	("Bangla",                LanguageCode::BN),
+	("Valencian",             LanguageCode::CA),
+	("Church Slavic",         LanguageCode::CU),
+	("Old Bulgarian",         LanguageCode::CU),
+	("Old Church Slavonic",   LanguageCode::CU),
+	("Old Slavonic",          LanguageCode::CU),
+	("Dhivehi",               LanguageCode::DV),
+	("Maldivian",             LanguageCode::DV),
+	("Greek, Modern (1453-)", LanguageCode::EL),
+	("Modern Greek",          LanguageCode::EL),
+	("Castilian",             LanguageCode::ES),
+	("Farsi",                 LanguageCode::FA),
+	("Fula",                  LanguageCode::FF),
+	("Fulani",                LanguageCode::FF),
+	("West Frisian",          LanguageCode::FY),
+	("Scottish Gaelic",       LanguageCode::GD),
+	("Haitian Creole",        LanguageCode::HT),
+	("Occidental",            LanguageCode::IE),
+	("Nuosu",                 LanguageCode::II),
+	("Gikuyu",                LanguageCode::KI),
+	("Kwanyama",              LanguageCode::KJ),
+	("Greenlandic",           LanguageCode::KL),
+	("Khmer",                 LanguageCode::KM),
+	("Kyrgyz",                LanguageCode::KY),
+	("Letzeburgesch",         LanguageCode::LB),
+	("Luganda",               LanguageCode::LG),
+	("Limburger",             LanguageCode::LI),
+	("Limburgish",            LanguageCode::LI),
+	("Bokmål, Norwegian",     LanguageCode::NB),
+	("Ndebele, North",        LanguageCode::ND),
+	("Flemish",               LanguageCode::NL),
+	("Nynorsk, Norwegian",    LanguageCode::NN),
+	("Ndebele, South",        LanguageCode::NR),
+	("Chewa",                 LanguageCode::NY),
+	("Nyanja",                LanguageCode::NY),
+	("Provençal",             LanguageCode::OC),
+	("Odia",                  LanguageCode::OR),
+	("Ossetic",               LanguageCode::OS),
+	("Panjabi",               LanguageCode::PA),
+	("Pushto",                LanguageCode::PS),
+	("Kirundi",               LanguageCode::RN),
+	("Moldavian",             LanguageCode::RO),
+	("Moldovan",              LanguageCode::RO),
+	("Sinhalese",             LanguageCode::SI),
+	("Slovene",               LanguageCode::SL),
+	("Swazi",                 LanguageCode::SS),
+	("Sesotho",               LanguageCode::ST),
+	("Sotho, Southern",       LanguageCode::ST),
+	("Kiswahili",             LanguageCode::SW),
+	("Setswana",              LanguageCode::TN),
+	("Tongan",                LanguageCode::TO),
+	("Uyghur",                LanguageCode::UG),
+	("Chuang",                LanguageCode::ZA),
+];
+
+/// The scripts most likely to be used for languages in particular countries,
+/// where they differ from the usual script of the language.
+/// 
+/// The entries are sorted by language code and then country code.
+/// 
+/// # Data sources
+/// 
+/// The scripts are taken from the likely subtags data of the
+/// [Unicode CLDR](https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/likelySubtags.json).
+/// 
+/// # See also
+/// 
+/// * [`Language::likely_script()`]
+/// 
+static LIKELY_SCRIPTS: [((LanguageCode, CountryCode), Script); 19] = [
+	((LanguageCode::AZ, CountryCode::IQ), Script::Arab),
+	((LanguageCode::AZ, CountryCode::IR), Script::Arab),
+	((LanguageCode::AZ, CountryCode::RU), Script::Cyrl),
+	((LanguageCode::HA, CountryCode::CM), Script::Arab),
+	((LanguageCode::HA, CountryCode::SD), Script::Arab),
+	((LanguageCode::KK, CountryCode::CN), Script::Arab),
+	((LanguageCode::MN, CountryCode::CN), Script::Mong),
+	((LanguageCode::PA, CountryCode::PK), Script::Arab),
+	((LanguageCode::SD, CountryCode::IN), Script::Deva),
+	((LanguageCode::SR, CountryCode::ME), Script::Latn),
+	((LanguageCode::SR, CountryCode::RO), Script::Latn),
+	((LanguageCode::SR, CountryCode::RU), Script::Latn),
+	((LanguageCode::SR, CountryCode::TR), Script::Latn),
+	((LanguageCode::UG, CountryCode::KZ), Script::Cyrl),
+	((LanguageCode::UG, CountryCode::MN), Script::Cyrl),
+	((LanguageCode::UZ, CountryCode::CN), Script::Cyrl),
+	((LanguageCode::ZH, CountryCode::HK), Script::Hant),
+	((LanguageCode::ZH, CountryCode::MO), Script::Hant),
+	((LanguageCode::ZH, CountryCode::TW), Script::Hant),
+];
+
+/// The languages, indexed by normalised name.
+/// 
+/// The names are normalised using [`normalize()`], so that parsing by name is a
+/// single lookup. The alternative names from [`ALIASES`] are included, but do
+/// not replace any of the standard names.
+/// 
+static NAMES: LazyLock<SortedMap<String, Language>> = LazyLock::new(|| {
+	SortedMap::new(
+		LANGUAGES.iter().map(|info| (normalize(info.name), info.code.language()))
+			.chain(ALIASES.iter().map(|&(alias, code)| (normalize(alias), code.language())))
+	)
+});
+
+/// The possible language families.
+/// 
+/// The entries are in the order of the [`LanguageFamily`] variants, which is
+/// relied upon for lookups.
+/// 
+/// # Data sources
+/// 
+/// The list of codes and the hierarchy of families is available from
+/// [the Library of Congress](https://www.loc.gov/standards/iso639-5/), which is
+/// the registration authority for ISO 639-5.
+/// 
+/// # See also
+/// 
+/// * [`LanguageFamily`]
+/// 
+static FAMILIES: [LanguageFamilyInfo; 51] = [
+	LanguageFamilyInfo { family: LanguageFamily::AAV, name: "Austro-Asiatic languages",             parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::AFA, name: "Afro-Asiatic languages",               parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::ALG, name: "Algonquian languages",                 parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::ALV, name: "Atlantic-Congo languages",             parent: Some(LanguageFamily::NIC) },
+	LanguageFamilyInfo { family: LanguageFamily::ART, name: "Artificial languages",                 parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::ATH, name: "Athapascan languages",                 parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::BAT, name: "Baltic languages",                     parent: Some(LanguageFamily::INE) },
+	LanguageFamilyInfo { family: LanguageFamily::BNT, name: "Bantu languages",                      parent: Some(LanguageFamily::ALV) },
+	LanguageFamilyInfo { family: LanguageFamily::CCN, name: "North Caucasian languages",            parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::CCS, name: "South Caucasian languages",            parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::CDC, name: "Chadic languages",                     parent: Some(LanguageFamily::AFA) },
+	LanguageFamilyInfo { family: LanguageFamily::CEL, name: "Celtic languages",                     parent: Some(LanguageFamily::INE) },
+	LanguageFamilyInfo { family: LanguageFamily::CRP, name: "Creoles and pidgins",                  parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::CUS, name: "Cushitic languages",                   parent: Some(LanguageFamily::AFA) },
+	LanguageFamilyInfo { family: LanguageFamily::DMN, name: "Mande languages",                      parent: Some(LanguageFamily::NIC) },
+	LanguageFamilyInfo { family: LanguageFamily::DRA, name: "Dravidian languages",                  parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::ESX, name: "Eskimo-Aleut languages",               parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::FIU, name: "Finno-Ugrian languages",               parent: Some(LanguageFamily::URJ) },
+	LanguageFamilyInfo { family: LanguageFamily::GEM, name: "Germanic languages",                   parent: Some(LanguageFamily::INE) },
+	LanguageFamilyInfo { family: LanguageFamily::GMQ, name: "North Germanic languages",             parent: Some(LanguageFamily::GEM) },
+	LanguageFamilyInfo { family: LanguageFamily::GMW, name: "West Germanic languages",              parent: Some(LanguageFamily::GEM) },
+	LanguageFamilyInfo { family: LanguageFamily::GRK, name: "Greek languages",                      parent: Some(LanguageFamily::INE) },
+	LanguageFamilyInfo { family: LanguageFamily::HYX, name: "Armenian (family)",                    parent: Some(LanguageFamily::INE) },
+	LanguageFamilyInfo { family: LanguageFamily::IIR, name: "Indo-Iranian languages",               parent: Some(LanguageFamily::INE) },
+	LanguageFamilyInfo { family: LanguageFamily::INC, name: "Indic languages",                      parent: Some(LanguageFamily::IIR) },
+	LanguageFamilyInfo { family: LanguageFamily::INE, name: "Indo-European languages",              parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::IRA, name: "Iranian languages",                    parent: Some(LanguageFamily::IIR) },
+	LanguageFamilyInfo { family: LanguageFamily::ITC, name: "Italic languages",                     parent: Some(LanguageFamily::INE) },
+	LanguageFamilyInfo { family: LanguageFamily::JPX, name: "Japanese (family)",                    parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::MAP, name: "Austronesian languages",               parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::MKH, name: "Mon-Khmer languages",                  parent: Some(LanguageFamily::AAV) },
+	LanguageFamilyInfo { family: LanguageFamily::NIC, name: "Niger-Kordofanian languages",          parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::POZ, name: "Malayo-Polynesian languages",          parent: Some(LanguageFamily::MAP) },
+	LanguageFamilyInfo { family: LanguageFamily::PQE, name: "Eastern Malayo-Polynesian languages",  parent: Some(LanguageFamily::POZ) },
+	LanguageFamilyInfo { family: LanguageFamily::QWE, name: "Quechuan (family)",                    parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::ROA, name: "Romance languages",                    parent: Some(LanguageFamily::ITC) },
+	LanguageFamilyInfo { family: LanguageFamily::SAI, name: "South American Indian languages",      parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::SEM, name: "Semitic languages",                    parent: Some(LanguageFamily::AFA) },
+	LanguageFamilyInfo { family: LanguageFamily::SIT, name: "Sino-Tibetan languages",               parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::SLA, name: "Slavic languages",                     parent: Some(LanguageFamily::INE) },
+	LanguageFamilyInfo { family: LanguageFamily::SQJ, name: "Albanian languages",                   parent: Some(LanguageFamily::INE) },
+	LanguageFamilyInfo { family: LanguageFamily::SSA, name: "Nilo-Saharan languages",               parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::TAI, name: "Tai languages",                        parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::TBQ, name: "Tibeto-Burman languages",              parent: Some(LanguageFamily::SIT) },
+	LanguageFamilyInfo { family: LanguageFamily::TRK, name: "Turkic languages",                     parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::URJ, name: "Uralic languages",                     parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::XGN, name: "Mongolian languages",                  parent: None },
+	LanguageFamilyInfo { family: LanguageFamily::ZHX, name: "Chinese (family)",                     parent: Some(LanguageFamily::SIT) },
+	LanguageFamilyInfo { family: LanguageFamily::ZLE, name: "East Slavic languages",                parent: Some(LanguageFamily::SLA) },
+	LanguageFamilyInfo { family: LanguageFamily::ZLS, name: "South Slavic languages",               parent: Some(LanguageFamily::SLA) },
+	LanguageFamilyInfo { family: LanguageFamily::ZLW, name: "West Slavic languages",                parent: Some(LanguageFamily::SLA) },
+];
+
+/// The currencies of the countries where each language is used.
+/// 
+/// This is derived from the country data, and is indexed by [`Language`]. Each
+/// list is sorted and free of duplicates.
+/// 
+static CURRENCIES: LazyLock<Vec<Vec<CurrencyCode>>> = LazyLock::new(|| {
+	LANGUAGES.iter().map(|info| {
+		let mut currencies: Vec<_> = info.countries.iter().flat_map(|code| code.country().currencies()).copied().collect();
+		currencies.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+		currencies.dedup();
+		currencies
+	}).collect()
 });
 
 
@@ -262,6 +490,19 @@ static LANGUAGES: LazyLock<HashMap<Language, LanguageInfo>> = LazyLock::new(|| {
 /// Each language is identified by a country code, which can be expressed as two
 /// letters, as defined by the ISO 639-1 standard.
 /// 
+/// Languages can be parsed from their names, their alternative names as given
+/// by [`alternative_names()`](Language::alternative_names()), or their codes,
+/// e.g. "Persian", "Farsi", and "fa" all give [`Language::FA`].
+/// 
+/// # Discriminants
+/// 
+/// Each variant has an explicit discriminant, which is stable across versions
+/// of this crate, and can be obtained by casting with `as u16`. This makes it
+/// safe to store the discriminant, e.g. in a database. Existing values will
+/// never be changed or reused, and new languages will be given new values. The
+/// ordering of Language values follows the discriminants, and is therefore also
+/// stable.
+/// 
 /// # Data sources
 /// 
 /// The list of codes and other country information is available from
@@ -272,610 +513,881 @@ static LANGUAGES: LazyLock<HashMap<Language, LanguageInfo>> = LazyLock::new(|| {
 /// 
 /// * [`LanguageCode`]
 /// 
-#[derive(Clone, Copy, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Copy, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[repr(u16)]
 #[serde(into = "String", try_from = "String")]
 #[non_exhaustive]
 pub enum Language {
 	/// Afar
-	AA,
+	AA = 0,
 	
 	/// Abkhazian
-	AB,
+	AB = 1,
 	
 	/// Avestan
-	AE,
+	AE = 2,
 	
 	/// Afrikaans
-	AF,
+	AF = 3,
 	
 	/// Akan
-	AK,
+	AK = 4,
 	
 	/// Amharic
-	AM,
+	AM = 5,
 	
 	/// Aragonese
-	AN,
+	AN = 6,
 	
 	/// Arabic
-	AR,
+	AR = 7,
 	
 	/// Assamese
-	AS,
+	AS = 8,
 	
 	/// Avaric
-	AV,
+	AV = 9,
 	
 	/// Aymara
-	AY,
+	AY = 10,
 	
 	/// Azerbaijani
-	AZ,
+	AZ = 11,
 	
 	/// Bashkir
-	BA,
+	BA = 12,
 	
 	/// Belarusian
-	BE,
+	BE = 13,
 	
 	/// Bulgarian
-	BG,
+	BG = 14,
 	
 	/// Bislama
-	BI,
+	BI = 15,
 	
 	/// Bambara
-	BM,
+	BM = 16,
 	
 	/// Bengali
-	BN,
+	BN = 17,
 	
 	/// Tibetan
-	BO,
+	BO = 18,
 	
 	/// Breton
-	BR,
+	BR = 19,
 	
 	/// Bosnian
-	BS,
+	BS = 20,
 	
 	/// Catalan, Valencian
-	CA,
+	CA = 21,
 	
 	/// Chechen
-	CE,
+	CE = 22,
 	
 	/// Chamorro
-	CH,
+	CH = 23,
 	
 	/// Corsican
-	CO,
+	CO = 24,
 	
 	/// Cree
-	CR,
+	CR = 25,
 	
 	/// Czech
-	CS,
+	CS = 26,
 	
 	/// Church Slavonic, Old Slavonic, Old Church Slavonic
-	CU,
+	CU = 27,
 	
 	/// Chuvash
-	CV,
+	CV = 28,
 	
 	/// Welsh
-	CY,
+	CY = 29,
 	
 	/// Danish
-	DA,
+	DA = 30,
 	
 	/// German
-	DE,
+	DE = 31,
 	
 	/// Divehi, Dhivehi, Maldivian
-	DV,
+	DV = 32,
 	
 	/// Dzongkha
-	DZ,
+	DZ = 33,
 	
 	/// Ewe
-	EE,
+	EE = 34,
 	
 	/// Greek, Modern (1453–)
-	EL,
+	EL = 35,
 	
 	/// English
-	EN,
+	EN = 36,
 	
 	/// Esperanto
-	EO,
+	EO = 37,
 	
 	/// Spanish, Castilian
-	ES,
+	ES = 38,
 	
 	/// Estonian
-	ET,
+	ET = 39,
 	
 	/// Basque
-	EU,
+	EU = 40,
 	
 	/// Persian
-	FA,
+	FA = 41,
 	
 	/// Fulah
-	FF,
+	FF = 42,
 	
 	/// Finnish
-	FI,
+	FI = 43,
 	
 	/// Fijian
-	FJ,
+	FJ = 44,
 	
 	/// Faroese
-	FO,
+	FO = 45,
 	
 	/// French
-	FR,
+	FR = 46,
 	
 	/// Western Frisian
-	FY,
+	FY = 47,
 	
 	/// Irish
-	GA,
+	GA = 48,
 	
 	/// Gaelic, Scottish Gaelic
-	GD,
+	GD = 49,
 	
 	/// Galician
-	GL,
+	GL = 50,
 	
 	/// Guarani
-	GN,
+	GN = 51,
 	
 	/// Gujarati
-	GU,
+	GU = 52,
 	
 	/// Manx
-	GV,
+	GV = 53,
 	
 	/// Hausa
-	HA,
+	HA = 54,
 	
 	/// Hebrew
-	HE,
+	HE = 55,
 	
 	/// Hindi
-	HI,
+	HI = 56,
 	
 	/// Hiri Motu
-	HO,
+	HO = 57,
 	
 	/// Croatian
-	HR,
+	HR = 58,
 	
 	/// Haitian, Haitian Creole
-	HT,
+	HT = 59,
 	
 	/// Hungarian
-	HU,
+	HU = 60,
 	
 	/// Armenian
-	HY,
+	HY = 61,
 	
 	/// Herero
-	HZ,
+	HZ = 62,
 	
 	/// Interlingua (International Auxiliary Language Association)
-	IA,
+	IA = 63,
 	
 	/// Indonesian
-	ID,
+	ID = 64,
 	
 	/// Interlingue, Occidental
-	IE,
+	IE = 65,
 	
 	/// Igbo
-	IG,
+	IG = 66,
 	
 	/// Sichuan Yi, Nuosu
-	II,
+	II = 67,
 	
 	/// Inupiaq
-	IK,
+	IK = 68,
 	
 	/// Ido
-	IO,
+	IO = 69,
 	
 	/// Icelandic
-	IS,
+	IS = 70,
 	
 	/// Italian
-	IT,
+	IT = 71,
 	
 	/// Inuktitut
-	IU,
+	IU = 72,
 	
 	/// Japanese
-	JA,
+	JA = 73,
 	
 	/// Javanese
-	JV,
+	JV = 74,
 	
 	/// Georgian
-	KA,
+	KA = 75,
 	
 	/// Kongo
-	KG,
+	KG = 76,
 	
 	/// Kikuyu, Gikuyu
-	KI,
+	KI = 77,
 	
 	/// Kuanyama, Kwanyama
-	KJ,
+	KJ = 78,
 	
 	/// Kazakh
-	KK,
+	KK = 79,
 	
 	/// Kalaallisut, Greenlandic
-	KL,
+	KL = 80,
 	
 	/// Central Khmer
-	KM,
+	KM = 81,
 	
 	/// Kannada
-	KN,
+	KN = 82,
 	
 	/// Korean
-	KO,
+	KO = 83,
 	
 	/// Kanuri
-	KR,
+	KR = 84,
 	
 	/// Kashmiri
-	KS,
+	KS = 85,
 	
 	/// Kurdish
-	KU,
+	KU = 86,
 	
 	/// Komi
-	KV,
+	KV = 87,
 	
 	/// Cornish
-	KW,
+	KW = 88,
 	
 	/// Kirghiz, Kyrgyz
-	KY,
+	KY = 89,
 	
 	/// Latin
-	LA,
+	LA = 90,
 	
 	/// Luxembourgish, Letzeburgesch
-	LB,
+	LB = 91,
 	
 	/// Ganda
-	LG,
+	LG = 92,
 	
 	/// Limburgan, Limburger, Limburgish
-	LI,
+	LI = 93,
 	
 	/// Lingala
-	LN,
+	LN = 94,
 	
 	/// Lao
-	LO,
+	LO = 95,
 	
 	/// Lithuanian
-	LT,
+	LT = 96,
 	
 	/// Luba-Katanga
-	LU,
+	LU = 97,
 	
 	/// Latvian
-	LV,
+	LV = 98,
 	
 	/// Malagasy
-	MG,
+	MG = 99,
 	
 	/// Marshallese
-	MH,
+	MH = 100,
 	
 	/// Maori
-	MI,
+	MI = 101,
 	
 	/// Macedonian
-	MK,
+	MK = 102,
 	
 	/// Malayalam
-	ML,
+	ML = 103,
 	
 	/// Mongolian
-	MN,
+	MN = 104,
 	
 	/// Marathi
-	MR,
+	MR = 105,
 	
 	/// Malay
-	MS,
+	MS = 106,
 	
 	/// Maltese
-	MT,
+	MT = 107,
 	
 	/// Burmese
-	MY,
+	MY = 108,
 	
 	/// Nauru
-	NA,
+	NA = 109,
 	
 	/// Norwegian Bokmål
-	NB,
+	NB = 110,
 	
 	/// North Ndebele
-	ND,
+	ND = 111,
 	
 	/// Nepali
-	NE,
+	NE = 112,
 	
 	/// Ndonga
-	NG,
+	NG = 113,
 	
 	/// Dutch, Flemish
-	NL,
+	NL = 114,
 	
 	/// Norwegian Nynorsk
-	NN,
+	NN = 115,
 	
 	/// Norwegian
-	NO,
+	NO = 116,
 	
 	/// South Ndebele
-	NR,
+	NR = 117,
 	
 	/// Navajo, Navaho
-	NV,
+	NV = 118,
 	
 	/// Chichewa, Chewa, Nyanja
-	NY,
+	NY = 119,
 	
 	/// Occitan
-	OC,
+	OC = 120,
 	
 	/// Ojibwa
-	OJ,
+	OJ = 121,
 	
 	/// Oromo
-	OM,
+	OM = 122,
 	
 	/// Oriya
-	OR,
+	OR = 123,
 	
 	/// Ossetian, Ossetic
-	OS,
+	OS = 124,
 	
 	/// Punjabi, Panjabi
-	PA,
+	PA = 125,
 	
 	/// Pali
-	PI,
+	PI = 126,
 	
 	/// Polish
-	PL,
+	PL = 127,
 	
 	/// Pashto, Pushto
-	PS,
+	PS = 128,
 	
 	/// Portuguese
-	PT,
+	PT = 129,
 	
 	/// Quechua
-	QU,
+	QU = 130,
 	
 	/// Romansh
-	RM,
+	RM = 131,
 	
 	/// Rundi
-	RN,
+	RN = 132,
 	
 	/// Romanian, Moldavian, Moldovan
-	RO,
+	RO = 133,
 	
 	/// Russian
-	RU,
+	RU = 134,
 	
 	/// Kinyarwanda
-	RW,
+	RW = 135,
 	
 	/// Sanskrit
-	SA,
+	SA = 136,
 	
 	/// Sardinian
-	SC,
+	SC = 137,
 	
 	/// Sindhi
-	SD,
+	SD = 138,
 	
 	/// Northern Sami
-	SE,
+	SE = 139,
 	
 	/// Sango
-	SG,
+	SG = 140,
 	
 	/// Sinhala, Sinhalese
-	SI,
+	SI = 141,
 	
 	/// Slovak
-	SK,
+	SK = 142,
 	
 	/// Slovenian
-	SL,
+	SL = 143,
 	
 	/// Samoan
-	SM,
+	SM = 144,
 	
 	/// Shona
-	SN,
+	SN = 145,
 	
 	/// Somali
-	SO,
+	SO = 146,
 	
 	/// Albanian
-	SQ,
+	SQ = 147,
 	
 	/// Serbian
-	SR,
+	SR = 148,
 	
 	/// Swati
-	SS,
+	SS = 149,
 	
 	/// Southern Sotho
-	ST,
+	ST = 150,
 	
 	/// Sundanese
-	SU,
+	SU = 151,
 	
 	/// Swedish
-	SV,
+	SV = 152,
 	
 	/// Swahili
-	SW,
+	SW = 153,
 	
 	/// Tamil
-	TA,
+	TA = 154,
 	
 	/// Telugu
-	TE,
+	TE = 155,
 	
 	/// Tajik
-	TG,
+	TG = 156,
 	
 	/// Thai
-	TH,
+	TH = 157,
 	
 	/// Tigrinya
-	TI,
+	TI = 158,
 	
 	/// Turkmen
-	TK,
+	TK = 159,
 	
 	/// Tagalog
-	TL,
+	TL = 160,
 	
 	/// Tswana
-	TN,
+	TN = 161,
 	
 	/// Tonga (Tonga Islands)
-	TO,
+	TO = 162,
 	
 	/// Turkish
-	TR,
+	TR = 163,
 	
 	/// Tsonga
-	TS,
+	TS = 164,
 	
 	/// Tatar
-	TT,
+	TT = 165,
 	
 	/// Twi
-	TW,
+	TW = 166,
 	
 	/// Tahitian
-	TY,
+	TY = 167,
 	
 	/// Uighur, Uyghur
-	UG,
+	UG = 168,
 	
 	/// Ukrainian
-	UK,
+	UK = 169,
 	
 	/// Urdu
-	UR,
+	UR = 170,
 	
 	/// Uzbek
-	UZ,
+	UZ = 171,
 	
 	/// Venda
-	VE,
+	VE = 172,
 	
 	/// Vietnamese
-	VI,
+	VI = 173,
 	
 	/// Volapük
-	VO,
+	VO = 174,
 	
 	/// Walloon
-	WA,
+	WA = 175,
 	
 	/// Wolof
-	WO,
+	WO = 176,
 	
 	/// Xhosa
-	XH,
+	XH = 177,
 	
 	/// Yiddish
-	YI,
+	YI = 178,
 	
 	/// Yoruba
-	YO,
+	YO = 179,
 	
 	/// Zhuang, Chuang
-	ZA,
+	ZA = 180,
 	
 	/// Chinese
-	ZH,
+	ZH = 181,
 	
 	/// Zulu
-	ZU,
+	ZU = 182,
 }
 
 //󰭅		Language																
 impl Language {
 	//		all																	
 	/// Returns all the languages.
+	#[must_use]
 	pub fn all() -> Vec<Self> {
-		LANGUAGES.keys().copied().collect()
+		LANGUAGES.iter().map(|info| info.code.language()).collect()
+	}
+	
+	//		records																
+	/// Returns the records of all the languages.
+	/// 
+	/// This provides all of the information about every language in one pass.
+	/// For more information, see [`LanguageRecord`].
+	/// 
+	#[must_use]
+	pub fn records() -> impl ExactSizeIterator<Item = LanguageRecord> {
+		LANGUAGES.iter().map(|info| info.code.language().record())
+	}
+	
+	//		record																
+	/// Returns the record of the language.
+	/// 
+	/// This provides all of the information about the language in one go. For
+	/// more information, see [`LanguageRecord`].
+	/// 
+	#[must_use]
+	pub fn record(&self) -> LanguageRecord {
+		let info = self.info();
+		LanguageRecord {
+			language:   *self,
+			code:       info.code,
+			name:       info.name,
+			family:     info.family,
+			scripts:    info.scripts,
+			countries:  info.countries,
+			currencies: self.currencies(),
+		}
+	}
+	
+	//		info																
+	/// Returns the `LanguageInfo` instance corresponding to the `Language`.
+	/// 
+	/// This method provides an easy way to get to the associated `LanguageInfo`
+	/// instance from a `Language` enum variant.
+	/// 
+	#[must_use]
+	fn info(self) -> &'static LanguageInfo {
+		#[expect(clippy::unwrap_used, reason = "Infallible")]
+		//	This should be infallible. If it isn't, then the data is wrong, and one
+		//	of the languages is missing from the list, which is a bug.
+		LANGUAGES.get(self as usize).unwrap()
+	}
+	
+	//		name																
+	/// Returns the name of the language.
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		self.info().name
+	}
+	
+	//		display_as															
+	/// Returns the language for display in a chosen style.
+	/// 
+	/// This allows the language to be displayed as its code, its name, or both,
+	/// e.g. `format!("{}", Language::FR.display_as(DisplayStyle::CodeAndName))`.
+	/// For more information, see [`DisplayStyle`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `style` - The style to display the language in.
+	/// 
+	#[must_use]
+	pub const fn display_as(&self, style: DisplayStyle) -> Styled<Self> {
+		Styled::new(*self, style)
+	}
+	
+	//		alternative_names													
+	/// Returns the alternative names of the language.
+	/// 
+	/// These are the alternative names and spellings given by ISO 639-2, along
+	/// with some widely-used variants, e.g. "Castilian" for Spanish, or "Farsi"
+	/// for Persian. They are accepted when parsing a language by name.
+	/// 
+	#[must_use]
+	pub fn alternative_names(&self) -> Vec<&'static str> {
+		ALIASES.iter().filter(|&&(_, code)| code == self.code()).map(|&(alias, _)| alias).collect()
+	}
+	
+	//		family																
+	/// Returns the family that the language belongs to.
+	/// 
+	/// This is the most specific ISO 639-5 family that the language is a
+	/// member of, e.g. West Germanic for English. Languages that are isolates,
+	/// such as Basque and Korean, do not belong to any family, and so `None` is
+	/// returned.
+	/// 
+	#[must_use]
+	pub fn family(&self) -> Option<LanguageFamily> {
+		self.info().family
+	}
+	
+	//		families															
+	/// Returns all the families that the language belongs to.
+	/// 
+	/// The families are returned from the most specific to the most general,
+	/// e.g. West Germanic, Germanic, and Indo-European for English.
+	/// 
+	#[must_use]
+	pub fn families(&self) -> Vec<LanguageFamily> {
+		let mut families = Vec::new();
+		let mut family   = self.family();
+		while let Some(current) = family {
+			families.push(current);
+			family = current.parent();
+		}
+		families
+	}
+	
+	//		is_in_family														
+	/// Checks whether the language belongs to a family.
+	/// 
+	/// This includes membership through sub-families, so e.g. English is in
+	/// both the West Germanic and the Indo-European families.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `family` - The family to check.
+	/// 
+	#[must_use]
+	pub fn is_in_family(&self, family: LanguageFamily) -> bool {
+		self.families().contains(&family)
+	}
+	
+	//		scripts																
+	/// Returns the scripts that the language is written in.
+	/// 
+	/// The scripts are in order of priority, with the script in most common
+	/// use first, so that rendering layers can choose a font stack for the
+	/// language, e.g. Cyrillic and then Latin for Serbian, or Simplified and
+	/// then Traditional Han for Chinese. Every language has at least one
+	/// script.
+	/// 
+	#[must_use]
+	pub fn scripts(&self) -> &'static [Script] {
+		self.info().scripts
+	}
+	
+	//		likely_script														
+	/// Returns the script most likely to be used for the language.
+	/// 
+	/// This follows the likely subtags data of the Unicode CLDR, so that the
+	/// script subtag can be inferred when building locales for formatting
+	/// libraries such as `icu4x`. Where a country is given, and the language is
+	/// usually written in a different script there, that script is returned,
+	/// e.g. Traditional Han for Chinese in Taiwan. Otherwise, the first of the
+	/// [`scripts()`](Language::scripts()) is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `country` - The country the language is used in, if known.
+	/// 
+	#[must_use]
+	pub fn likely_script(&self, country: Option<Country>) -> Option<Script> {
+		country
+			.and_then(|place| map::get(&LIKELY_SCRIPTS, &(self.code(), place.code())).copied())
+			.or_else(|| self.scripts().first().copied())
+	}
+	
+	//		fuzzy_match															
+	/// Finds the languages with names similar to the given name.
+	/// 
+	/// Each language is scored against the name using
+	/// [`similarity()`](crate::text::similarity()), and those scoring at least
+	/// the threshold are returned, along with their scores, best match first.
+	/// Useful thresholds are typically between `0.7` and `0.9`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name`      - The name to match, which may contain typos.
+	/// * `threshold` - The minimum score, between `0.0` and `1.0`.
+	/// 
+	#[must_use]
+	pub fn fuzzy_match(name: &str, threshold: f32) -> Vec<(Self, f32)> {
+		let mut matches: Vec<_> = Self::all()
+			.into_iter()
+			.map(|language| (language, similarity(name, language.name())))
+			.filter(|&(_, score)| score >= threshold)
+			.collect();
+		matches.sort_by(|a, b| b.1.total_cmp(&a.1));
+		matches
+	}
+	
+	//		try_from_any														
+	/// Resolves a JSON value to a language.
+	/// 
+	/// This is intended for schemaless ingestion, where the form of the value
+	/// is not known in advance. The value can be a string holding a name or a
+	/// code, e.g. `"English"` or `"en"`, which is parsed in the same way as by
+	/// [`FromStr`]. As there are no numeric language codes, numbers are not
+	/// accepted.
+	/// 
+	/// This is only available when the `json` feature is enabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The JSON value to resolve.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the value does not identify a language.
+	/// 
+	#[cfg(feature = "json")]
+	pub fn try_from_any(value: &Value) -> Result<Self, String> {
+		value.as_str().and_then(|string| string.parse().ok()).ok_or_else(|| format!("Invalid Language: {value}"))
+	}
+	
+	//		parse_with															
+	/// Parses a language from text, according to a set of options.
+	/// 
+	/// This allows the leniency of the parsing to be chosen, e.g. whether codes
+	/// are accepted in any case, and whether names are accepted. For more
+	/// information, see [`ParseOptions`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `s`       - The text to parse.
+	/// * `options` - The options to parse with.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the text does not identify a language with the
+	/// options given.
+	/// 
+	pub fn parse_with(s: &str, options: &ParseOptions) -> Result<Self, String> {
+		options.parse(s).ok_or_else(|| format!("Invalid Language: {s}"))
+	}
+	
+	//		code																
+	/// Returns the language code.
+	#[must_use]
+	pub fn code(&self) -> LanguageCode {
+		self.info().code
+	}
+	
+	//		countries															
+	/// Returns the countries where the language is used.
+	#[must_use]
+	pub fn countries(&self) -> &'static [CountryCode] {
+		self.info().countries
+	}
+	
+	//		countries_set														
+	/// Returns the countries where the language is used, as a [`HashSet`].
+	/// 
+	/// This is a convenience method for when set semantics are required. The
+	/// data is stored as a sorted slice, as returned by [`countries()`](Self::countries()),
+	/// and so this method allocates a new set on each call.
+	/// 
+	#[must_use]
+	pub fn countries_set(&self) -> HashSet<CountryCode> {
+		self.countries().iter().copied().collect()
 	}
 	
-	//		info																
-	/// Returns the `LanguageInfo` instance corresponding to the `Language`.
+	//		currencies															
+	/// Returns the currencies used in the countries where the language is used.
 	/// 
-	/// This method provides an easy way to get to the associated `LanguageInfo`
-	/// instance from a `Language` enum variant.
+	/// This is derived from the countries and their currencies, and is computed
+	/// once, on first use.
 	/// 
 	#[must_use]
-	fn info(self) -> &'static LanguageInfo {
-		#[expect(clippy::unwrap_used, reason = "Infallible")]
-		//	This should be infallible. If it isn't, then the data is wrong, and one
-		//	of the languages is missing from the list, which is a bug.
-		LANGUAGES.get(&self).unwrap()
+	pub fn currencies(&self) -> &'static [CurrencyCode] {
+		CURRENCIES.get(*self as usize).map_or(&[], Vec::as_slice)
 	}
 	
-	//		name																
-	/// Returns the name of the language.
+	//		currencies_set														
+	/// Returns the currencies used in the countries where the language is used,
+	/// as a [`HashSet`].
+	/// 
+	/// This is a convenience method for when set semantics are required, and
+	/// allocates a new set on each call.
+	/// 
 	#[must_use]
-	pub fn name(&self) -> &str {
-		&self.info().name
+	pub fn currencies_set(&self) -> HashSet<CurrencyCode> {
+		self.currencies().iter().copied().collect()
 	}
 	
-	//		code																
-	/// Returns the language code.
+	//		plural_categories													
+	/// Returns the plural categories used by the language.
+	/// 
+	/// These are the categories defined by the Unicode CLDR plural rules for
+	/// the language, in the order zero, one, two, few, many, other. Languages
+	/// that have no CLDR rules only use [`Other`](PluralCategory::Other).
+	/// 
+	#[cfg(feature = "plurals")]
 	#[must_use]
-	pub fn code(&self) -> LanguageCode {
-		self.info().code
+	pub const fn plural_categories(&self) -> &'static [PluralCategory] {
+		PluralRule::for_language(*self).categories()
 	}
 	
-	//		countries															
-	/// Returns the countries where the language is used.
+	//		plural_category														
+	/// Returns the plural category for a number.
+	/// 
+	/// This evaluates the Unicode CLDR cardinal plural rules for the language
+	/// against the given integer, returning the category to use when selecting
+	/// the plural form of a word.
+	/// 
+	#[cfg(feature = "plurals")]
 	#[must_use]
-	pub fn countries(&self) -> &HashSet<CountryCode> {
-		&self.info().countries
+	pub const fn plural_category(&self, n: u64) -> PluralCategory {
+		PluralRule::for_language(*self).category(n)
 	}
 }
 
 //󰭅		AsStr																	
 impl AsStr for Language {
 	//		as_str																
-	fn as_str(&self) -> &str {
-		&self.info().name
+	fn as_str(&self) -> &'static str {
+		self.info().name
 	}
 }
 
@@ -895,6 +1407,28 @@ impl Display for Language {
 	}
 }
 
+//󰭅		From<Language> for u16													
+impl From<Language> for u16 {
+	//		from																
+	fn from(language: Language) -> Self {
+		language.code().numeric()
+	}
+}
+
+//󰭅		From<Language> for Language												
+#[cfg(feature = "icu")]
+#[expect(clippy::fallible_impl_from, reason = "Infallible")]
+impl From<Language> for subtags::Language {
+	//		from																
+	/// Converts a language into an `icu4x` language subtag.
+	fn from(language: Language) -> Self {
+		#[expect(clippy::unwrap_used, reason = "Infallible")]
+		//	This should be infallible, as all two-letter codes are valid language
+		//	subtags. If it isn't, then the data is wrong, which is a bug.
+		Self::try_from_str(language.code().as_str()).unwrap()
+	}
+}
+
 //󰭅		From<Language> for String												
 impl From<Language> for String {
 	//		from																
@@ -909,13 +1443,52 @@ impl FromStr for Language {
 	
 	//		from_str															
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		LANGUAGES
-			.values()
-			.find(|info| info.name == s)
-			.map_or_else(
-				||     Err(format!("Invalid Language: {s}")),
-				|info| Ok(info.code.language())
-			)
+		NAMES.get(&normalize(s))
+			.copied()
+			.or_else(|| LanguageCode::from_code(s.trim()).map(|code| code.language()))
+			.ok_or_else(|| format!("Invalid Language: {s}"))
+	}
+}
+
+//󰭅		Parse																	
+impl Parse for Language {
+	//		from_code															
+	fn from_code(code: &str, case_insensitive: bool) -> Option<Self> {
+		LanguageCode::from_code(code)
+			.filter(|language| case_insensitive || language.as_str() == code)
+			.map(|language| language.language())
+	}
+	
+	//		from_name															
+	fn from_name(name: &str) -> Option<Self> {
+		let normalized = normalize(name);
+		NAMES.get(&normalized).copied().filter(|language| normalize(language.info().name) == normalized)
+	}
+	
+	//		from_alias															
+	fn from_alias(alias: &str) -> Option<Self> {
+		let normalized = normalize(alias);
+		ALIASES.iter().find(|&&(name, _)| normalize(name) == normalized).map(|&(_, code)| code.language())
+	}
+}
+
+//󰭅		TryFrom<Language>														
+#[cfg(feature = "icu")]
+impl TryFrom<subtags::Language> for Language {
+	type Error = String;
+	
+	//		try_from															
+	/// Converts an `icu4x` language subtag into a language.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the language does not have an ISO 639-1 code, such
+	/// as `yue` for Cantonese, or is undetermined, i.e. `und`.
+	/// 
+	fn try_from(language: subtags::Language) -> Result<Self, Self::Error> {
+		LanguageCode::from_code(language.as_str())
+			.map(|code| code.language())
+			.ok_or_else(|| format!("Invalid Language: {language}"))
 	}
 }
 
@@ -943,6 +1516,11 @@ impl TryFrom<String> for Language {
 /// codes as part of ISO 639-2, 639-3, and 639-5, but these are not supported at
 /// present.
 /// 
+/// The codes are in lower case, as is conventional, e.g. `en` and `fr`. This is
+/// the form produced by [`as_str()`](AsStr::as_str()), [`Display`], and
+/// serialisation, and so the codes can be used as they are in BCP 47 language
+/// tags and HTML `lang` attributes, without any conversion.
+/// 
 /// # Data sources
 /// 
 /// The list of codes is available from [the ISO site](https://www.iso.org/iso-639-language-code),
@@ -1511,8 +2089,251 @@ pub enum LanguageCode {
 impl LanguageCode {
 	//		all																	
 	/// Returns all the language codes.
+	#[must_use]
 	pub fn all() -> Vec<Self> {
-		LANGUAGES.values().map(|info| info.code).collect()
+		LANGUAGES.iter().map(|info| info.code).collect()
+	}
+	
+	//		complete															
+	/// Returns the language codes that start with a given prefix.
+	/// 
+	/// This is intended for code completion, such as in terminal UIs and bots.
+	/// The match is case-insensitive, and the codes are returned in
+	/// alphabetical order, e.g. `e` gives `ee`, `el`, `en`, `eo`, `es`, `et`, and `eu`.
+	/// 
+	#[must_use]
+	pub fn complete(prefix: &str) -> Vec<Self> {
+		let mut codes: Vec<_> = LANGUAGES
+			.iter()
+			.map(|info| info.code)
+			.filter(|code| code.as_str().get(..prefix.len()).is_some_and(|start| start.eq_ignore_ascii_case(prefix)))
+			.collect();
+		codes.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+		codes
+	}
+	
+	//		from_code															
+	/// Returns the [`LanguageCode`] corresponding to a code string.
+	/// 
+	/// The match is case-insensitive, in the same way as [`FromStr`]. However,
+	/// unlike [`FromStr`], this method is a `const fn`, and so can be used in
+	/// `const` contexts, such as by the [`language!`](crate::language!) macro.
+	/// If the code is not recognised, `None` is returned.
+	/// 
+	#[expect(clippy::too_many_lines, reason = "Data not logic")]
+	#[must_use]
+	pub const fn from_code(code: &str) -> Option<Self> {
+		match *code.as_bytes() {
+			[a, b] => match &[a.to_ascii_lowercase(), b.to_ascii_lowercase()] {
+				b"aa" => Some(Self::AA),
+				b"ab" => Some(Self::AB),
+				b"ae" => Some(Self::AE),
+				b"af" => Some(Self::AF),
+				b"ak" => Some(Self::AK),
+				b"am" => Some(Self::AM),
+				b"an" => Some(Self::AN),
+				b"ar" => Some(Self::AR),
+				b"as" => Some(Self::AS),
+				b"av" => Some(Self::AV),
+				b"ay" => Some(Self::AY),
+				b"az" => Some(Self::AZ),
+				b"ba" => Some(Self::BA),
+				b"be" => Some(Self::BE),
+				b"bg" => Some(Self::BG),
+				b"bi" => Some(Self::BI),
+				b"bm" => Some(Self::BM),
+				b"bn" => Some(Self::BN),
+				b"bo" => Some(Self::BO),
+				b"br" => Some(Self::BR),
+				b"bs" => Some(Self::BS),
+				b"ca" => Some(Self::CA),
+				b"ce" => Some(Self::CE),
+				b"ch" => Some(Self::CH),
+				b"co" => Some(Self::CO),
+				b"cr" => Some(Self::CR),
+				b"cs" => Some(Self::CS),
+				b"cu" => Some(Self::CU),
+				b"cv" => Some(Self::CV),
+				b"cy" => Some(Self::CY),
+				b"da" => Some(Self::DA),
+				b"de" => Some(Self::DE),
+				b"dv" => Some(Self::DV),
+				b"dz" => Some(Self::DZ),
+				b"ee" => Some(Self::EE),
+				b"el" => Some(Self::EL),
+				b"en" => Some(Self::EN),
+				b"eo" => Some(Self::EO),
+				b"es" => Some(Self::ES),
+				b"et" => Some(Self::ET),
+				b"eu" => Some(Self::EU),
+				b"fa" => Some(Self::FA),
+				b"ff" => Some(Self::FF),
+				b"fi" => Some(Self::FI),
+				b"fj" => Some(Self::FJ),
+				b"fo" => Some(Self::FO),
+				b"fr" => Some(Self::FR),
+				b"fy" => Some(Self::FY),
+				b"ga" => Some(Self::GA),
+				b"gd" => Some(Self::GD),
+				b"gl" => Some(Self::GL),
+				b"gn" => Some(Self::GN),
+				b"gu" => Some(Self::GU),
+				b"gv" => Some(Self::GV),
+				b"ha" => Some(Self::HA),
+				b"he" => Some(Self::HE),
+				b"hi" => Some(Self::HI),
+				b"ho" => Some(Self::HO),
+				b"hr" => Some(Self::HR),
+				b"ht" => Some(Self::HT),
+				b"hu" => Some(Self::HU),
+				b"hy" => Some(Self::HY),
+				b"hz" => Some(Self::HZ),
+				b"ia" => Some(Self::IA),
+				b"id" => Some(Self::ID),
+				b"ie" => Some(Self::IE),
+				b"ig" => Some(Self::IG),
+				b"ii" => Some(Self::II),
+				b"ik" => Some(Self::IK),
+				b"io" => Some(Self::IO),
+				b"is" => Some(Self::IS),
+				b"it" => Some(Self::IT),
+				b"iu" => Some(Self::IU),
+				b"ja" => Some(Self::JA),
+				b"jv" => Some(Self::JV),
+				b"ka" => Some(Self::KA),
+				b"kg" => Some(Self::KG),
+				b"ki" => Some(Self::KI),
+				b"kj" => Some(Self::KJ),
+				b"kk" => Some(Self::KK),
+				b"kl" => Some(Self::KL),
+				b"km" => Some(Self::KM),
+				b"kn" => Some(Self::KN),
+				b"ko" => Some(Self::KO),
+				b"kr" => Some(Self::KR),
+				b"ks" => Some(Self::KS),
+				b"ku" => Some(Self::KU),
+				b"kv" => Some(Self::KV),
+				b"kw" => Some(Self::KW),
+				b"ky" => Some(Self::KY),
+				b"la" => Some(Self::LA),
+				b"lb" => Some(Self::LB),
+				b"lg" => Some(Self::LG),
+				b"li" => Some(Self::LI),
+				b"ln" => Some(Self::LN),
+				b"lo" => Some(Self::LO),
+				b"lt" => Some(Self::LT),
+				b"lu" => Some(Self::LU),
+				b"lv" => Some(Self::LV),
+				b"mg" => Some(Self::MG),
+				b"mh" => Some(Self::MH),
+				b"mi" => Some(Self::MI),
+				b"mk" => Some(Self::MK),
+				b"ml" => Some(Self::ML),
+				b"mn" => Some(Self::MN),
+				b"mr" => Some(Self::MR),
+				b"ms" => Some(Self::MS),
+				b"mt" => Some(Self::MT),
+				b"my" => Some(Self::MY),
+				b"na" => Some(Self::NA),
+				b"nb" => Some(Self::NB),
+				b"nd" => Some(Self::ND),
+				b"ne" => Some(Self::NE),
+				b"ng" => Some(Self::NG),
+				b"nl" => Some(Self::NL),
+				b"nn" => Some(Self::NN),
+				b"no" => Some(Self::NO),
+				b"nr" => Some(Self::NR),
+				b"nv" => Some(Self::NV),
+				b"ny" => Some(Self::NY),
+				b"oc" => Some(Self::OC),
+				b"oj" => Some(Self::OJ),
+				b"om" => Some(Self::OM),
+				b"or" => Some(Self::OR),
+				b"os" => Some(Self::OS),
+				b"pa" => Some(Self::PA),
+				b"pi" => Some(Self::PI),
+				b"pl" => Some(Self::PL),
+				b"ps" => Some(Self::PS),
+				b"pt" => Some(Self::PT),
+				b"qu" => Some(Self::QU),
+				b"rm" => Some(Self::RM),
+				b"rn" => Some(Self::RN),
+				b"ro" => Some(Self::RO),
+				b"ru" => Some(Self::RU),
+				b"rw" => Some(Self::RW),
+				b"sa" => Some(Self::SA),
+				b"sc" => Some(Self::SC),
+				b"sd" => Some(Self::SD),
+				b"se" => Some(Self::SE),
+				b"sg" => Some(Self::SG),
+				b"si" => Some(Self::SI),
+				b"sk" => Some(Self::SK),
+				b"sl" => Some(Self::SL),
+				b"sm" => Some(Self::SM),
+				b"sn" => Some(Self::SN),
+				b"so" => Some(Self::SO),
+				b"sq" => Some(Self::SQ),
+				b"sr" => Some(Self::SR),
+				b"ss" => Some(Self::SS),
+				b"st" => Some(Self::ST),
+				b"su" => Some(Self::SU),
+				b"sv" => Some(Self::SV),
+				b"sw" => Some(Self::SW),
+				b"ta" => Some(Self::TA),
+				b"te" => Some(Self::TE),
+				b"tg" => Some(Self::TG),
+				b"th" => Some(Self::TH),
+				b"ti" => Some(Self::TI),
+				b"tk" => Some(Self::TK),
+				b"tl" => Some(Self::TL),
+				b"tn" => Some(Self::TN),
+				b"to" => Some(Self::TO),
+				b"tr" => Some(Self::TR),
+				b"ts" => Some(Self::TS),
+				b"tt" => Some(Self::TT),
+				b"tw" => Some(Self::TW),
+				b"ty" => Some(Self::TY),
+				b"ug" => Some(Self::UG),
+				b"uk" => Some(Self::UK),
+				b"ur" => Some(Self::UR),
+				b"uz" => Some(Self::UZ),
+				b"ve" => Some(Self::VE),
+				b"vi" => Some(Self::VI),
+				b"vo" => Some(Self::VO),
+				b"wa" => Some(Self::WA),
+				b"wo" => Some(Self::WO),
+				b"xh" => Some(Self::XH),
+				b"yi" => Some(Self::YI),
+				b"yo" => Some(Self::YO),
+				b"za" => Some(Self::ZA),
+				b"zh" => Some(Self::ZH),
+				b"zu" => Some(Self::ZU),
+				_     => None,
+			},
+			_      => None,
+		}
+	}
+	
+	//		const_from_str														
+	/// Returns the [`LanguageCode`] corresponding to a code string, in `const` contexts.
+	/// 
+	/// This method behaves in the same way as [`from_code()`](Self::from_code()),
+	/// but panics instead of returning `None`. When used in a `const` context,
+	/// such as to initialise a `const` or `static`, this means that an invalid
+	/// code will cause a compile error, allowing lists of language codes in
+	/// configuration to be validated at compile time.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the code is not recognised.
+	/// 
+	#[expect(clippy::panic, reason = "Intended for compile-time validation")]
+	#[must_use]
+	pub const fn const_from_str(code: &str) -> Self {
+		match Self::from_code(code) {
+			Some(parsed) => parsed,
+			None         => panic!("Invalid LanguageCode"),
+		}
 	}
 	
 	//		language															
@@ -1710,6 +2531,39 @@ impl LanguageCode {
 			Self::ZU => Language::ZU,
 		}
 	}
+	
+	//		numeric																
+	/// Returns a stable numeric identifier for the language code.
+	/// 
+	/// ISO 639-1 does not define numeric codes, and so this is **not** an ISO
+	/// code, but an identifier defined by this crate, so that language codes
+	/// can be stored in the same compact form as country and currency codes.
+	/// It is calculated from the two letters of the code, treating them as a
+	/// base-26 number, plus one, so `aa` is 1, `ab` is 2, and `zz` is 676. It
+	/// is therefore guaranteed not to change between versions, and codes added
+	/// in future will not affect the identifiers of existing ones. The code can
+	/// be recovered from the identifier using [`TryFrom`].
+	/// 
+	#[must_use]
+	pub fn numeric(&self) -> u16 {
+		self.as_str()
+			.bytes()
+			.fold(0_u16, |id, byte| id.saturating_mul(26).saturating_add(u16::from(byte.saturating_sub(b'a'))))
+			.saturating_add(1)
+	}
+	
+	//		stable_hash															
+	/// Returns a stable 64-bit hash of the language code.
+	/// 
+	/// The hash is calculated from the two-letter code, using
+	/// [`stable_hash()`](crate::text::stable_hash()). It is guaranteed not to
+	/// change between versions, which makes it suitable for consistent hashing
+	/// and sharding across services.
+	/// 
+	#[must_use]
+	pub fn stable_hash(&self) -> u64 {
+		stable_hash(self.as_str())
+	}
 }
 
 //󰭅		AsStr																	
@@ -1913,6 +2767,23 @@ impl Display for LanguageCode {
 	}
 }
 
+//󰭅		Format																	
+#[cfg(feature = "defmt")]
+impl Format for LanguageCode {
+	//		format																
+	fn format(&self, fmt: Formatter<'_>) {
+		defmt::write!(fmt, "{=str}", self.as_str());
+	}
+}
+
+//󰭅		From<LanguageCode> for u16												
+impl From<LanguageCode> for u16 {
+	//		from																
+	fn from(code: LanguageCode) -> Self {
+		code.numeric()
+	}
+}
+
 //󰭅		From<LanguageCode> for String											
 impl From<LanguageCode> for String {
 	//		from																
@@ -1926,194 +2797,39 @@ impl FromStr for LanguageCode {
 	type Err = String;
 	
 	//		from_str															
-	#[expect(clippy::too_many_lines, reason = "Data not logic")]
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		match s.to_lowercase().as_str() {
-			"aa" => Ok(Self::AA),
-			"ab" => Ok(Self::AB),
-			"ae" => Ok(Self::AE),
-			"af" => Ok(Self::AF),
-			"ak" => Ok(Self::AK),
-			"am" => Ok(Self::AM),
-			"an" => Ok(Self::AN),
-			"ar" => Ok(Self::AR),
-			"as" => Ok(Self::AS),
-			"av" => Ok(Self::AV),
-			"ay" => Ok(Self::AY),
-			"az" => Ok(Self::AZ),
-			"ba" => Ok(Self::BA),
-			"be" => Ok(Self::BE),
-			"bg" => Ok(Self::BG),
-			"bi" => Ok(Self::BI),
-			"bm" => Ok(Self::BM),
-			"bn" => Ok(Self::BN),
-			"bo" => Ok(Self::BO),
-			"br" => Ok(Self::BR),
-			"bs" => Ok(Self::BS),
-			"ca" => Ok(Self::CA),
-			"ce" => Ok(Self::CE),
-			"ch" => Ok(Self::CH),
-			"co" => Ok(Self::CO),
-			"cr" => Ok(Self::CR),
-			"cs" => Ok(Self::CS),
-			"cu" => Ok(Self::CU),
-			"cv" => Ok(Self::CV),
-			"cy" => Ok(Self::CY),
-			"da" => Ok(Self::DA),
-			"de" => Ok(Self::DE),
-			"dv" => Ok(Self::DV),
-			"dz" => Ok(Self::DZ),
-			"ee" => Ok(Self::EE),
-			"el" => Ok(Self::EL),
-			"en" => Ok(Self::EN),
-			"eo" => Ok(Self::EO),
-			"es" => Ok(Self::ES),
-			"et" => Ok(Self::ET),
-			"eu" => Ok(Self::EU),
-			"fa" => Ok(Self::FA),
-			"ff" => Ok(Self::FF),
-			"fi" => Ok(Self::FI),
-			"fj" => Ok(Self::FJ),
-			"fo" => Ok(Self::FO),
-			"fr" => Ok(Self::FR),
-			"fy" => Ok(Self::FY),
-			"ga" => Ok(Self::GA),
-			"gd" => Ok(Self::GD),
-			"gl" => Ok(Self::GL),
-			"gn" => Ok(Self::GN),
-			"gu" => Ok(Self::GU),
-			"gv" => Ok(Self::GV),
-			"ha" => Ok(Self::HA),
-			"he" => Ok(Self::HE),
-			"hi" => Ok(Self::HI),
-			"ho" => Ok(Self::HO),
-			"hr" => Ok(Self::HR),
-			"ht" => Ok(Self::HT),
-			"hu" => Ok(Self::HU),
-			"hy" => Ok(Self::HY),
-			"hz" => Ok(Self::HZ),
-			"ia" => Ok(Self::IA),
-			"id" => Ok(Self::ID),
-			"ie" => Ok(Self::IE),
-			"ig" => Ok(Self::IG),
-			"ii" => Ok(Self::II),
-			"ik" => Ok(Self::IK),
-			"io" => Ok(Self::IO),
-			"is" => Ok(Self::IS),
-			"it" => Ok(Self::IT),
-			"iu" => Ok(Self::IU),
-			"ja" => Ok(Self::JA),
-			"jv" => Ok(Self::JV),
-			"ka" => Ok(Self::KA),
-			"kg" => Ok(Self::KG),
-			"ki" => Ok(Self::KI),
-			"kj" => Ok(Self::KJ),
-			"kk" => Ok(Self::KK),
-			"kl" => Ok(Self::KL),
-			"km" => Ok(Self::KM),
-			"kn" => Ok(Self::KN),
-			"ko" => Ok(Self::KO),
-			"kr" => Ok(Self::KR),
-			"ks" => Ok(Self::KS),
-			"ku" => Ok(Self::KU),
-			"kv" => Ok(Self::KV),
-			"kw" => Ok(Self::KW),
-			"ky" => Ok(Self::KY),
-			"la" => Ok(Self::LA),
-			"lb" => Ok(Self::LB),
-			"lg" => Ok(Self::LG),
-			"li" => Ok(Self::LI),
-			"ln" => Ok(Self::LN),
-			"lo" => Ok(Self::LO),
-			"lt" => Ok(Self::LT),
-			"lu" => Ok(Self::LU),
-			"lv" => Ok(Self::LV),
-			"mg" => Ok(Self::MG),
-			"mh" => Ok(Self::MH),
-			"mi" => Ok(Self::MI),
-			"mk" => Ok(Self::MK),
-			"ml" => Ok(Self::ML),
-			"mn" => Ok(Self::MN),
-			"mr" => Ok(Self::MR),
-			"ms" => Ok(Self::MS),
-			"mt" => Ok(Self::MT),
-			"my" => Ok(Self::MY),
-			"na" => Ok(Self::NA),
-			"nb" => Ok(Self::NB),
-			"nd" => Ok(Self::ND),
-			"ne" => Ok(Self::NE),
-			"ng" => Ok(Self::NG),
-			"nl" => Ok(Self::NL),
-			"nn" => Ok(Self::NN),
-			"no" => Ok(Self::NO),
-			"nr" => Ok(Self::NR),
-			"nv" => Ok(Self::NV),
-			"ny" => Ok(Self::NY),
-			"oc" => Ok(Self::OC),
-			"oj" => Ok(Self::OJ),
-			"om" => Ok(Self::OM),
-			"or" => Ok(Self::OR),
-			"os" => Ok(Self::OS),
-			"pa" => Ok(Self::PA),
-			"pi" => Ok(Self::PI),
-			"pl" => Ok(Self::PL),
-			"ps" => Ok(Self::PS),
-			"pt" => Ok(Self::PT),
-			"qu" => Ok(Self::QU),
-			"rm" => Ok(Self::RM),
-			"rn" => Ok(Self::RN),
-			"ro" => Ok(Self::RO),
-			"ru" => Ok(Self::RU),
-			"rw" => Ok(Self::RW),
-			"sa" => Ok(Self::SA),
-			"sc" => Ok(Self::SC),
-			"sd" => Ok(Self::SD),
-			"se" => Ok(Self::SE),
-			"sg" => Ok(Self::SG),
-			"si" => Ok(Self::SI),
-			"sk" => Ok(Self::SK),
-			"sl" => Ok(Self::SL),
-			"sm" => Ok(Self::SM),
-			"sn" => Ok(Self::SN),
-			"so" => Ok(Self::SO),
-			"sq" => Ok(Self::SQ),
-			"sr" => Ok(Self::SR),
-			"ss" => Ok(Self::SS),
-			"st" => Ok(Self::ST),
-			"su" => Ok(Self::SU),
-			"sv" => Ok(Self::SV),
-			"sw" => Ok(Self::SW),
-			"ta" => Ok(Self::TA),
-			"te" => Ok(Self::TE),
-			"tg" => Ok(Self::TG),
-			"th" => Ok(Self::TH),
-			"ti" => Ok(Self::TI),
-			"tk" => Ok(Self::TK),
-			"tl" => Ok(Self::TL),
-			"tn" => Ok(Self::TN),
-			"to" => Ok(Self::TO),
-			"tr" => Ok(Self::TR),
-			"ts" => Ok(Self::TS),
-			"tt" => Ok(Self::TT),
-			"tw" => Ok(Self::TW),
-			"ty" => Ok(Self::TY),
-			"ug" => Ok(Self::UG),
-			"uk" => Ok(Self::UK),
-			"ur" => Ok(Self::UR),
-			"uz" => Ok(Self::UZ),
-			"ve" => Ok(Self::VE),
-			"vi" => Ok(Self::VI),
-			"vo" => Ok(Self::VO),
-			"wa" => Ok(Self::WA),
-			"wo" => Ok(Self::WO),
-			"xh" => Ok(Self::XH),
-			"yi" => Ok(Self::YI),
-			"yo" => Ok(Self::YO),
-			"za" => Ok(Self::ZA),
-			"zh" => Ok(Self::ZH),
-			"zu" => Ok(Self::ZU),
-			_     => Err(format!("Invalid LanguageCode: {s}")),
-		}
+		Self::from_code(s).ok_or_else(|| format!("Invalid LanguageCode: {s}"))
+	}
+}
+
+//󰭅		Ord																		
+impl Ord for LanguageCode {
+	//		cmp																	
+	/// Compares two codes alphabetically, e.g. `EUR` before `GBP`.
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_str().cmp(other.as_str())
+	}
+}
+
+//󰭅		PartialOrd																
+impl PartialOrd for LanguageCode {
+	//		partial_cmp															
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+//󰭅		TryFrom<u16>															
+impl TryFrom<u16> for LanguageCode {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: u16) -> Result<Self, Self::Error> {
+		const LETTERS: &[u8; 26] = b"abcdefghijklmnopqrstuvwxyz";
+		value.checked_sub(1)
+			.and_then(|index| Some([*LETTERS.get(usize::from(index.div_euclid(26)))?, *LETTERS.get(usize::from(index.rem_euclid(26)))?]))
+			.and_then(|letters| Self::from_code(str::from_utf8(&letters).ok()?))
+			.ok_or_else(|| format!("Invalid LanguageCode: {value}"))
 	}
 }
 
@@ -2129,8 +2845,370 @@ impl TryFrom<String> for LanguageCode {
 
 
 
+//		LanguageFamily															
+/// A language family.
+/// 
+/// Language families are groups of related languages, as defined by the ISO
+/// 639-5 standard, which assigns each family a three-letter code, e.g. `gem`
+/// for the Germanic languages. The families form a hierarchy, so e.g. the West
+/// Germanic languages are part of the Germanic languages, which are in turn
+/// part of the Indo-European languages.
+/// 
+/// Only the families needed to classify the languages in this crate are
+/// included.
+/// 
+/// # Data sources
+/// 
+/// The list of codes and the hierarchy of families is available from
+/// [the Library of Congress](https://www.loc.gov/standards/iso639-5/), which is
+/// the registration authority for ISO 639-5.
+/// 
+/// # See also
+/// 
+/// * [`Language::family()`]
+/// * [`Language::families()`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(into = "String", try_from = "String")]
+#[non_exhaustive]
+pub enum LanguageFamily {
+	/// Austro-Asiatic languages
+	AAV,
+	
+	/// Afro-Asiatic languages
+	AFA,
+	
+	/// Algonquian languages
+	ALG,
+	
+	/// Atlantic-Congo languages
+	ALV,
+	
+	/// Artificial languages
+	ART,
+	
+	/// Athapascan languages
+	ATH,
+	
+	/// Baltic languages
+	BAT,
+	
+	/// Bantu languages
+	BNT,
+	
+	/// North Caucasian languages
+	CCN,
+	
+	/// South Caucasian languages
+	CCS,
+	
+	/// Chadic languages
+	CDC,
+	
+	/// Celtic languages
+	CEL,
+	
+	/// Creoles and pidgins
+	CRP,
+	
+	/// Cushitic languages
+	CUS,
+	
+	/// Mande languages
+	DMN,
+	
+	/// Dravidian languages
+	DRA,
+	
+	/// Eskimo-Aleut languages
+	ESX,
+	
+	/// Finno-Ugrian languages
+	FIU,
+	
+	/// Germanic languages
+	GEM,
+	
+	/// North Germanic languages
+	GMQ,
+	
+	/// West Germanic languages
+	GMW,
+	
+	/// Greek languages
+	GRK,
+	
+	/// Armenian (family)
+	HYX,
+	
+	/// Indo-Iranian languages
+	IIR,
+	
+	/// Indic languages
+	INC,
+	
+	/// Indo-European languages
+	INE,
+	
+	/// Iranian languages
+	IRA,
+	
+	/// Italic languages
+	ITC,
+	
+	/// Japanese (family)
+	JPX,
+	
+	/// Austronesian languages
+	MAP,
+	
+	/// Mon-Khmer languages
+	MKH,
+	
+	/// Niger-Kordofanian languages
+	NIC,
+	
+	/// Malayo-Polynesian languages
+	POZ,
+	
+	/// Eastern Malayo-Polynesian languages
+	PQE,
+	
+	/// Quechuan (family)
+	QWE,
+	
+	/// Romance languages
+	ROA,
+	
+	/// South American Indian languages
+	SAI,
+	
+	/// Semitic languages
+	SEM,
+	
+	/// Sino-Tibetan languages
+	SIT,
+	
+	/// Slavic languages
+	SLA,
+	
+	/// Albanian languages
+	SQJ,
+	
+	/// Nilo-Saharan languages
+	SSA,
+	
+	/// Tai languages
+	TAI,
+	
+	/// Tibeto-Burman languages
+	TBQ,
+	
+	/// Turkic languages
+	TRK,
+	
+	/// Uralic languages
+	URJ,
+	
+	/// Mongolian languages
+	XGN,
+	
+	/// Chinese (family)
+	ZHX,
+	
+	/// East Slavic languages
+	ZLE,
+	
+	/// South Slavic languages
+	ZLS,
+	
+	/// West Slavic languages
+	ZLW,
+}
+
+//󰭅		LanguageFamily															
+impl LanguageFamily {
+	//		all																	
+	/// Returns all the language families.
+	#[must_use]
+	pub fn all() -> Vec<Self> {
+		FAMILIES.iter().map(|info| info.family).collect()
+	}
+	
+	//		info																
+	/// Returns the `LanguageFamilyInfo` instance corresponding to the
+	/// `LanguageFamily`.
+	#[must_use]
+	fn info(self) -> &'static LanguageFamilyInfo {
+		#[expect(clippy::unwrap_used, reason = "Infallible")]
+		//	This should be infallible. If it isn't, then the data is wrong, and one
+		//	of the families is missing from the list, which is a bug.
+		FAMILIES.get(self as usize).unwrap()
+	}
+	
+	//		name																
+	/// Returns the name of the language family.
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		self.info().name
+	}
+	
+	//		parent																
+	/// Returns the family that this family is part of, if any.
+	#[must_use]
+	pub fn parent(&self) -> Option<Self> {
+		self.info().parent
+	}
+	
+	//		languages															
+	/// Returns the languages in the family.
+	/// 
+	/// This includes the languages in all of the family's sub-families.
+	/// 
+	#[must_use]
+	pub fn languages(&self) -> Vec<Language> {
+		Language::all().into_iter().filter(|language| language.is_in_family(*self)).collect()
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for LanguageFamily {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::AAV => "aav",
+			Self::AFA => "afa",
+			Self::ALG => "alg",
+			Self::ALV => "alv",
+			Self::ART => "art",
+			Self::ATH => "ath",
+			Self::BAT => "bat",
+			Self::BNT => "bnt",
+			Self::CCN => "ccn",
+			Self::CCS => "ccs",
+			Self::CDC => "cdc",
+			Self::CEL => "cel",
+			Self::CRP => "crp",
+			Self::CUS => "cus",
+			Self::DMN => "dmn",
+			Self::DRA => "dra",
+			Self::ESX => "esx",
+			Self::FIU => "fiu",
+			Self::GEM => "gem",
+			Self::GMQ => "gmq",
+			Self::GMW => "gmw",
+			Self::GRK => "grk",
+			Self::HYX => "hyx",
+			Self::IIR => "iir",
+			Self::INC => "inc",
+			Self::INE => "ine",
+			Self::IRA => "ira",
+			Self::ITC => "itc",
+			Self::JPX => "jpx",
+			Self::MAP => "map",
+			Self::MKH => "mkh",
+			Self::NIC => "nic",
+			Self::POZ => "poz",
+			Self::PQE => "pqe",
+			Self::QWE => "qwe",
+			Self::ROA => "roa",
+			Self::SAI => "sai",
+			Self::SEM => "sem",
+			Self::SIT => "sit",
+			Self::SLA => "sla",
+			Self::SQJ => "sqj",
+			Self::SSA => "ssa",
+			Self::TAI => "tai",
+			Self::TBQ => "tbq",
+			Self::TRK => "trk",
+			Self::URJ => "urj",
+			Self::XGN => "xgn",
+			Self::ZHX => "zhx",
+			Self::ZLE => "zle",
+			Self::ZLS => "zls",
+			Self::ZLW => "zlw",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for LanguageFamily {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//󰭅		From<LanguageFamily> for String											
+impl From<LanguageFamily> for String {
+	//		from																
+	fn from(family: LanguageFamily) -> Self {
+		family.to_string()
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for LanguageFamily {
+	type Err = String;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		FAMILIES
+			.iter()
+			.find(|info| info.family.as_str().eq_ignore_ascii_case(s))
+			.map(|info| info.family)
+			.ok_or_else(|| format!("Invalid LanguageFamily: {s}"))
+	}
+}
+
+//󰭅		TryFrom<String>															
+impl TryFrom<String> for LanguageFamily {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.as_str().parse()
+	}
+}
+
+
+
 //		Structs
 
+//		LanguageRecord															
+/// A read-only view of all the information about a language.
+/// 
+/// This is returned by [`Language::record()`] and [`Language::records()`], and
+/// allows all of the information about a language to be obtained in one go,
+/// rather than by calling each of the individual methods.
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct LanguageRecord {
+	//		Public properties													
+	/// The language.
+	pub language:   Language,
+	
+	/// The two-letter language code.
+	pub code:       LanguageCode,
+	
+	/// The name of the language.
+	pub name:       &'static str,
+	
+	/// The family that the language belongs to, if any.
+	pub family:     Option<LanguageFamily>,
+	
+	/// The scripts that the language is written in, in order of priority.
+	pub scripts:    &'static [Script],
+	
+	/// The countries where the language is used.
+	pub countries:  &'static [CountryCode],
+	
+	/// The currencies used in the countries where the language is used.
+	pub currencies: &'static [CurrencyCode],
+}
+
 //		LanguageInfo															
 /// Language information.
 /// 
@@ -2155,13 +3233,39 @@ impl TryFrom<String> for LanguageCode {
 struct LanguageInfo {
 	//		Private properties													
 	/// The name of the language.
-	name:      String,
+	name:      &'static str,
 	
 	/// The language code. For more information, see [`LanguageCode`].
 	code:      LanguageCode,
 	
+	/// The family that the language belongs to, if any. For more information,
+	/// see [`LanguageFamily`].
+	family:    Option<LanguageFamily>,
+	
+	/// The scripts that the language is written in, in order of priority. For
+	/// more information, see [`Script`].
+	scripts:   &'static [Script],
+	
 	/// The countries where the language is used.
-	countries: HashSet<CountryCode>,
+	countries: &'static [CountryCode],
 }
 
-
+//		LanguageFamilyInfo														
+/// Language family information.
+/// 
+/// # See also
+/// 
+/// * [`LanguageFamily`]
+/// 
+#[non_exhaustive]
+struct LanguageFamilyInfo {
+	//		Private properties													
+	/// The language family.
+	family: LanguageFamily,
+	
+	/// The name of the language family.
+	name:   &'static str,
+	
+	/// The family that this family is part of, if any.
+	parent: Option<LanguageFamily>,
+}
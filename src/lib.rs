@@ -53,9 +53,67 @@
 
 //		Modules
 
+pub mod aviation;
 pub mod country;
 pub mod currency;
+pub mod display;
+pub mod geoip;
+
+#[cfg(feature = "historic")]
+pub mod historic;
+
+pub mod holiday;
+
+#[cfg(feature = "csv")]
+pub mod ingest;
+
+pub mod iso20022;
 pub mod language;
+pub mod locale;
+
+mod macros;
+
+#[expect(clippy::redundant_pub_crate, reason = "Conflicts with unreachable_pub")]
+mod map;
+
+pub mod meta;
+
+#[cfg(feature = "mic")]
+pub mod mic;
+
+pub mod mrz;
+
+#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+pub mod money;
+
+#[cfg(feature = "orgs")]
+pub mod orgs;
+
+pub mod parse;
+
+#[cfg(feature = "plurals")]
+pub mod plural;
+
+pub mod prelude;
+pub mod query;
+
+pub mod registry;
+
+#[cfg(feature = "schema")]
+pub mod schema;
+
+pub mod script;
+pub mod serde;
+pub mod set;
+pub mod table;
+pub mod tax;
+pub mod telephony;
+pub mod text;
+pub mod unlocode;
+pub mod zones;
+
+#[cfg(feature = "test-util")]
+pub mod test_util;
 
 pub use {
 	country::Country,
@@ -67,3 +125,47 @@ pub use {
 };
 
 
+
+//		Packages
+
+//	Dev-dependencies that are only used by the benchmarks
+#[cfg(test)]
+use criterion as _;
+
+
+
+//		Constants
+
+/// The version of the embedded data.
+/// 
+/// This is the ISO 8601 date, in the form `YYYY-MM-DD`, of the snapshot of the
+/// ISO data embedded in this crate. As the same snapshot may be shipped in more
+/// than one version of the crate, and later versions may add data from other
+/// sources, downstream caches should be keyed on both this and the
+/// [`CRATE_VERSION`](meta::CRATE_VERSION), which are available together from
+/// the [`meta`] module.
+/// 
+/// Countries and currencies record the date on which their data last changed,
+/// where known, e.g. [`Country::last_changed()`], and the entries that have
+/// changed since a given version are returned by the `changes_since()`
+/// methods, e.g. [`Country::changes_since()`].
+/// 
+pub const DATA_VERSION: &str = "2024-11-12";
+
+
+
+//		Traits
+
+//§		AsStr																	
+/// A type that can be represented as a string slice.
+/// 
+/// This is implemented by the types in this crate that have a canonical string
+/// form, such as the name of a [`Country`] or the code of a [`CountryCode`],
+/// and is the form used by their [`Display`](core::fmt::Display)
+/// implementations.
+/// 
+pub trait AsStr {
+	//		as_str																
+	/// Returns the string form of the value.
+	fn as_str(&self) -> &'static str;
+}
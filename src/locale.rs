@@ -0,0 +1,169 @@
+//! Locale negotiation.
+//! 
+//! This module provides the [`negotiate()`] function, which selects the
+//! languages, and optionally countries, to use for a request, from the value of
+//! an HTTP `Accept-Language` header and an optional country hint, such as one
+//! obtained from a query parameter, a header, or a geo-IP lookup. This
+//! encapsulates the fallback rules that are otherwise commonly hand-rolled in
+//! web middleware.
+//! 
+//! ```
+//! use isosphere::{Country, Language, locale::negotiate};
+//! 
+//! assert_eq!(negotiate("fr-CA, en;q=0.8", None), vec![
+//!     (Language::FR, Some(Country::CA)),
+//!     (Language::EN, None),
+//! ]);
+//! ```
+//! 
+//! When the `icu` feature is enabled, languages and countries can also be
+//! converted to and from the `Locale` type of [`icu4x`](https://crates.io/crates/icu),
+//! using [`to_icu_locale()`] and [`from_icu_locale()`], so that the selections
+//! can be passed straight to its formatters.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/locale.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	country::{Country, CountryCode},
+	language::{Language, LanguageCode},
+};
+
+#[cfg(feature = "icu")]
+use icu_locale_core::Locale;
+
+
+
+//		Functions
+
+//		negotiate																
+/// Selects the languages and countries to use, in order of preference.
+/// 
+/// The `Accept-Language` header is parsed into language tags, which are
+/// ordered by their quality values, e.g. `q=0.8`, with tags of equal quality
+/// kept in the order given. Tags with a quality of zero, wildcards, and tags
+/// for languages not known to this crate are ignored. Duplicate selections
+/// are removed, keeping the most preferred.
+/// 
+/// Each selection is made as follows:
+/// 
+/// * If the tag has a region subtag that is a country, e.g. `fr-CA`, that
+///   country is used. Script subtags are skipped, e.g. `zh-Hant-TW`.
+/// * Otherwise, if a country hint is given, and the language is used in that
+///   country, the hint is used, e.g. `fr` with a hint of Belgium.
+/// * Otherwise, no country is selected.
+/// 
+/// If no languages can be selected from the header, e.g. because it is empty
+/// or only has a wildcard, and a country hint is given, the languages of the
+/// hinted country are selected instead. In all other cases, the result is
+/// empty, and the caller should fall back to its default.
+/// 
+/// # Parameters
+/// 
+/// * `accept_language` - The value of the `Accept-Language` header.
+/// * `country_hint`    - The country the request is believed to be from.
+/// 
+#[must_use]
+pub fn negotiate(accept_language: &str, country_hint: Option<Country>) -> Vec<(Language, Option<Country>)> {
+	let mut tags: Vec<(&str, f32)> = accept_language
+		.split(',')
+		.filter_map(|item| {
+			let mut parts = item.split(';').map(str::trim);
+			let tag       = parts.next().filter(|tag| !tag.is_empty() && *tag != "*")?;
+			let quality   = parts
+				.find_map(|param| param.strip_prefix("q=").or_else(|| param.strip_prefix("Q=")))
+				.map_or(Some(1.0_f32), |value| value.parse::<f32>().ok().filter(|q| (0.0_f32..=1.0_f32).contains(q)))?;
+			(quality > 0.0_f32).then_some((tag, quality))
+		})
+		.collect();
+	tags.sort_by(|a, b| b.1.total_cmp(&a.1));
+	let mut selections = Vec::new();
+	for (tag, _) in tags {
+		if let Some(selection) = select(tag, country_hint) {
+			if !selections.contains(&selection) {
+				selections.push(selection);
+			}
+		}
+	}
+	if selections.is_empty() {
+		if let Some(country) = country_hint {
+			selections.extend(country.languages().iter().map(|code| (code.language(), Some(country))));
+		}
+	}
+	selections
+}
+
+//		select																	
+/// Selects the language and country for a single language tag.
+/// 
+/// Returns [`None`] if the primary language subtag is not a known language.
+/// 
+/// # Parameters
+/// 
+/// * `tag`          - The language tag, e.g. `fr-CA`.
+/// * `country_hint` - The country the request is believed to be from.
+/// 
+fn select(tag: &str, country_hint: Option<Country>) -> Option<(Language, Option<Country>)> {
+	let mut subtags = tag.split(['-', '_']);
+	let language    = subtags.next().and_then(LanguageCode::from_code)?.language();
+	let country     = subtags
+		.take_while(|subtag| subtag.len() > 1)
+		.find(|subtag| subtag.len() == 2)
+		.and_then(CountryCode::from_code)
+		.map(|code| code.country())
+		.or_else(|| country_hint.filter(|country| country.languages().binary_search(&language.code()).is_ok()));
+	Some((language, country))
+}
+
+//		to_icu_locale															
+/// Converts a language, and optionally a country, into an `icu4x` locale.
+/// 
+/// The locale has the language subtag and, if a country is given, the region
+/// subtag, e.g. `fr-CA`. No script subtag is added, but the likely script can
+/// be found using [`Language::likely_script()`].
+/// 
+/// # Parameters
+/// 
+/// * `language` - The language of the locale.
+/// * `country`  - The country of the locale, if any.
+/// 
+#[cfg(feature = "icu")]
+#[must_use]
+pub fn to_icu_locale(language: Language, country: Option<Country>) -> Locale {
+	let mut locale     = Locale::UNKNOWN;
+	locale.id.language = language.into();
+	locale.id.region   = country.map(Into::into);
+	locale
+}
+
+//		from_icu_locale															
+/// Converts an `icu4x` locale into a language, and optionally a country.
+/// 
+/// The language and region subtags are used, and any other subtags, such as
+/// the script, variants, and extensions, are ignored.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the language is not known to this crate, or if the
+/// region is not a country, such as the numeric code `419` for Latin America.
+/// 
+/// # Parameters
+/// 
+/// * `locale` - The locale to convert.
+/// 
+#[cfg(feature = "icu")]
+pub fn from_icu_locale(locale: &Locale) -> Result<(Language, Option<Country>), String> {
+	let language = Language::try_from(locale.id.language)?;
+	let country  = locale.id.region.map(Country::try_from).transpose()?;
+	Ok((language, country))
+}
@@ -0,0 +1,117 @@
+//! Macros for literal codes.
+//! 
+//! This module provides macros that turn a literal code string into the
+//! corresponding primary type at compile time, e.g. `country!("GB")` becomes
+//! `Country::GB`. Invalid codes cause a compile error, which removes the need
+//! for runtime parsing, and the risk of typos, when codes are known in advance.
+//! 
+//! The macros are exported at the crate root.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/macros.rs"]
+mod tests;
+
+
+
+//		Macros
+
+//		country!																
+/// Creates a [`Country`](crate::Country) from a literal code at compile time.
+/// 
+/// The code can be a two-letter or three-letter ISO 3166-1 code, and is
+/// case-insensitive, e.g. `country!("GB")` and `country!("gbr")` both produce
+/// `Country::GB`. If the code is not valid, compilation will fail.
+/// 
+/// ```
+/// use isosphere::{Country, country};
+/// 
+/// assert_eq!(country!("GB"),  Country::GB);
+/// assert_eq!(country!("gbr"), Country::GB);
+/// ```
+/// 
+/// An unknown code, such as "XX", is rejected when compiling:
+/// 
+/// ```compile_fail,E0080
+/// let country = isosphere::country!("XX");
+/// ```
+/// 
+#[macro_export]
+macro_rules! country {
+	($code:literal) => {
+		const {
+			match $crate::CountryCode::from_code($code) {
+				Some(code) => code.country(),
+				None       => panic!(concat!("Invalid country code: ", $code)),
+			}
+		}
+	};
+}
+
+//		currency!																
+/// Creates a [`Currency`](crate::Currency) from a literal code at compile time.
+/// 
+/// The code must be a three-letter ISO 4217 code, and is case-insensitive, e.g.
+/// `currency!("USD")` produces `Currency::USD`. If the code is not valid,
+/// compilation will fail.
+/// 
+/// ```
+/// use isosphere::{Currency, currency};
+/// 
+/// assert_eq!(currency!("USD"), Currency::USD);
+/// assert_eq!(currency!("usd"), Currency::USD);
+/// ```
+/// 
+/// An unknown code, such as "ABC", is rejected when compiling:
+/// 
+/// ```compile_fail,E0080
+/// let currency = isosphere::currency!("ABC");
+/// ```
+/// 
+#[macro_export]
+macro_rules! currency {
+	($code:literal) => {
+		const {
+			match $crate::CurrencyCode::from_code($code) {
+				Some(code) => code.currency(),
+				None       => panic!(concat!("Invalid currency code: ", $code)),
+			}
+		}
+	};
+}
+
+//		language!																
+/// Creates a [`Language`](crate::Language) from a literal code at compile time.
+/// 
+/// The code must be a two-letter ISO 639-1 code, and is case-insensitive, e.g.
+/// `language!("en")` produces `Language::EN`. If the code is not valid,
+/// compilation will fail.
+/// 
+/// ```
+/// use isosphere::{Language, language};
+/// 
+/// assert_eq!(language!("en"), Language::EN);
+/// assert_eq!(language!("EN"), Language::EN);
+/// ```
+/// 
+/// An unknown code, such as "zz", is rejected when compiling:
+/// 
+/// ```compile_fail,E0080
+/// let language = isosphere::language!("zz");
+/// ```
+/// 
+#[macro_export]
+macro_rules! language {
+	($code:literal) => {
+		const {
+			match $crate::LanguageCode::from_code($code) {
+				Some(code) => code.language(),
+				None       => panic!(concat!("Invalid language code: ", $code)),
+			}
+		}
+	};
+}
@@ -0,0 +1,130 @@
+//! Sorted maps for lookup tables.
+//! 
+//! This module provides the [`SortedMap`] type, and the [`get()`] function
+//! that it is based on, which look up values in slices of entries sorted by
+//! key, using binary search. They are used in place of [`HashMap`](std::collections::HashMap)s
+//! for the lookup tables in this crate, both static and lazily-built, as they
+//! need no spare capacity, hashing, or separate allocation per table, and keep
+//! the entries together in memory for better cache locality.
+//! 
+//! For the name indexes of countries, currencies, and languages, which hold 410,
+//! 205, and 237 entries respectively, the tables take 27,264 bytes in total as
+//! sorted slices, compared to 59,184 bytes as hash maps, as the latter round
+//! their capacity up to a power of two and keep control bytes alongside. The
+//! heap storage for the names themselves is the same either way.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/map.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use core::borrow::Borrow;
+
+
+
+//		Structs
+
+//		SortedMap																
+/// A map held as a slice of entries sorted by key.
+/// 
+/// This is built once, such as within a [`LazyLock`](std::sync::LazyLock),
+/// and is then read-only. The entries are held in a boxed slice, which is sized
+/// exactly to fit them.
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SortedMap<K, V> {
+	//		Private properties													
+	/// The entries, sorted by key, with no duplicate keys.
+	entries: Box<[(K, V)]>,
+}
+
+//󰭅		SortedMap																
+impl<K: Ord, V> SortedMap<K, V> {
+	//		new																	
+	/// Creates a new map from a sequence of entries.
+	/// 
+	/// Where a key appears more than once, the first entry with that key is
+	/// kept, so that entries given earlier take precedence.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `entries` - The entries to hold, in any order.
+	/// 
+	pub(crate) fn new<I: IntoIterator<Item = (K, V)>>(entries: I) -> Self {
+		let mut sorted: Vec<_> = entries.into_iter().collect();
+		sorted.sort_by(|a, b| a.0.cmp(&b.0));
+		sorted.dedup_by(|later, earlier| later.0 == earlier.0);
+		Self { entries: sorted.into_boxed_slice() }
+	}
+	
+	//		get																	
+	/// Returns the value for a key, if present.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `key` - The key to look up.
+	/// 
+	pub(crate) fn get<Q: Ord + ?Sized>(&self, key: &Q) -> Option<&V>
+	where
+		K: Borrow<Q>,
+	{
+		get(&self.entries, key)
+	}
+}
+
+
+
+//		Functions
+
+//		get																		
+/// Returns the value for a key in a slice of entries sorted by key.
+/// 
+/// The entries must be sorted by key, according to [`Ord`], and are searched
+/// using binary search. If more than one entry has the key, any of them may be
+/// returned.
+/// 
+/// # Parameters
+/// 
+/// * `entries` - The entries, sorted by key.
+/// * `key`     - The key to look up.
+/// 
+pub(crate) fn get<'a, K, Q, V>(entries: &'a [(K, V)], key: &Q) -> Option<&'a V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	entries
+		.binary_search_by(|entry| entry.0.borrow().cmp(key))
+		.ok()
+		.and_then(|index| entries.get(index))
+		.map(|entry| &entry.1)
+}
+
+//		get_ignore_ascii_case													
+/// Returns the value for a string key in a slice of entries, ignoring ASCII
+/// case.
+/// 
+/// This is the same as [`get()`], except that the key is compared as if it
+/// were uppercase, without allocating. The keys of the entries must therefore
+/// be uppercase, as well as sorted.
+/// 
+/// # Parameters
+/// 
+/// * `entries` - The entries, sorted by key, with uppercase keys.
+/// * `key`     - The key to look up, in any case.
+/// 
+pub(crate) fn get_ignore_ascii_case<'a, V>(entries: &'a [(&str, V)], key: &str) -> Option<&'a V> {
+	entries
+		.binary_search_by(|entry| entry.0.bytes().cmp(key.bytes().map(|byte| byte.to_ascii_uppercase())))
+		.ok()
+		.and_then(|index| entries.get(index))
+		.map(|entry| &entry.1)
+}
@@ -0,0 +1,141 @@
+//! Metadata about the embedded data.
+//! 
+//! This module describes the provenance of the data embedded in this crate, so
+//! that audits can record exactly which revision of the ISO and related data a
+//! binary contains. The [`DATA_VERSION`] is the date of the snapshot, and the
+//! [`sources()`] are the standards and registries that the data was taken from,
+//! with their URLs, grouped by the module that provides the data:
+//! 
+//! ```
+//! use isosphere::meta::{self, DATA_VERSION};
+//! 
+//! println!("ISO data snapshot {DATA_VERSION}");
+//! for source in meta::sources_for("currency") {
+//!     println!("{}: {}", source.name, source.url);
+//! }
+//! ```
+//! 
+//! ISO no longer publishes numbered newsletters for ISO 3166-1, with changes
+//! instead being announced on its Online Browsing Platform, and so the
+//! snapshot date, rather than a newsletter number, identifies the revision of
+//! the data. The edition of each standard is given where applicable.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/meta.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use serde::Serialize;
+
+pub use crate::DATA_VERSION;
+
+
+
+//		Constants
+
+/// The version of this crate.
+/// 
+/// This is recorded alongside the [`DATA_VERSION`], as the same data snapshot
+/// may be shipped in more than one version of the crate.
+/// 
+pub const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The sources of the embedded data.
+/// 
+/// The sources are grouped by module, in alphabetical order, with the primary
+/// standard or registry for each module first.
+/// 
+static SOURCES: [Source; 26] = [
+	Source { module: "aviation",  name: "ICAO Annex 7",                  edition: None,                    url: "https://www.icao.int" },
+	Source { module: "aviation",  name: "Wikipedia",                     edition: None,                    url: "https://en.wikipedia.org/wiki/List_of_aircraft_registration_prefixes" },
+	Source { module: "country",   name: "ISO 3166-1",                    edition: Some("ISO 3166-1:2020"), url: "https://www.iso.org/iso-3166-country-codes.html" },
+	Source { module: "country",   name: "IANA time zone database",       edition: Some("2024b"),           url: "https://www.iana.org/time-zones" },
+	Source { module: "country",   name: "UN M49",                        edition: None,                    url: "https://unstats.un.org/unsd/methodology/m49/" },
+	Source { module: "country",   name: "UPU S42",                       edition: None,                    url: "https://www.upu.int" },
+	Source { module: "country",   name: "Wikipedia",                     edition: None,                    url: "https://en.wikipedia.org/wiki/ISO_3166-1" },
+	Source { module: "currency",  name: "ISO 4217",                      edition: Some("ISO 4217:2015"),   url: "https://www.iso.org/iso-4217-currency-codes.html" },
+	Source { module: "currency",  name: "SIX Group",                     edition: None,                    url: "https://www.six-group.com/en/products-services/financial-information/data-standards.html" },
+	Source { module: "currency",  name: "Wikipedia",                     edition: None,                    url: "https://en.wikipedia.org/wiki/ISO_4217" },
+	Source { module: "historic",  name: "ISO 4217 list three",           edition: Some("ISO 4217:2015"),   url: "https://www.iso.org/iso-4217-currency-codes.html" },
+	Source { module: "historic",  name: "Wikipedia",                     edition: None,                    url: "https://en.wikipedia.org/wiki/ISO_4217#Historical_codes" },
+	Source { module: "language",  name: "ISO 639-1",                     edition: Some("ISO 639-1:2002"),  url: "https://www.iso.org/iso-639-language-code" },
+	Source { module: "language",  name: "ISO 639-2",                     edition: Some("ISO 639-2:1998"),  url: "https://www.loc.gov/standards/iso639-2/php/code_list.php" },
+	Source { module: "language",  name: "ISO 639-5",                     edition: Some("ISO 639-5:2008"),  url: "https://www.loc.gov/standards/iso639-5/" },
+	Source { module: "language",  name: "Wikipedia",                     edition: None,                    url: "https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes" },
+	Source { module: "mic",       name: "ISO 10383",                     edition: Some("ISO 10383:2012"),  url: "https://www.iso20022.org/market-identifier-codes" },
+	Source { module: "mic",       name: "Wikipedia",                     edition: None,                    url: "https://en.wikipedia.org/wiki/Market_Identifier_Code" },
+	Source { module: "mrz",       name: "ICAO Doc 9303",                 edition: None,                    url: "https://www.icao.int/publications/pages/publication.aspx?docnum=9303" },
+	Source { module: "plural",    name: "Unicode CLDR plural rules",     edition: None,                    url: "https://cldr.unicode.org/index/cldr-spec/plural-rules" },
+	Source { module: "script",    name: "ISO 15924",                     edition: Some("ISO 15924:2022"),  url: "https://www.unicode.org/iso15924/" },
+	Source { module: "tax",       name: "Wikipedia",                     edition: None,                    url: "https://en.wikipedia.org/wiki/VAT_identification_number" },
+	Source { module: "telephony", name: "ITU-T E.164",                   edition: None,                    url: "https://www.itu.int/rec/T-REC-E.164" },
+	Source { module: "telephony", name: "Wikipedia",                     edition: None,                    url: "https://en.wikipedia.org/wiki/List_of_country_calling_codes" },
+	Source { module: "unlocode",  name: "UN/LOCODE",                     edition: None,                    url: "https://unece.org/trade/uncefact/unlocode" },
+	Source { module: "unlocode",  name: "Wikipedia",                     edition: None,                    url: "https://en.wikipedia.org/wiki/UN/LOCODE" },
+];
+
+
+
+//		Structs
+
+//		Source																	
+/// A source of the embedded data.
+/// 
+/// This is returned by [`sources()`] and [`sources_for()`], and identifies a
+/// standard, registry, or reference that some of the data was taken from.
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct Source {
+	//		Public properties													
+	/// The name of the module that provides the data, e.g. `country`.
+	pub module:  &'static str,
+	
+	/// The name of the source, e.g. `ISO 3166-1`.
+	pub name:    &'static str,
+	
+	/// The edition of the standard, e.g. `ISO 3166-1:2020`, if applicable.
+	pub edition: Option<&'static str>,
+	
+	/// The URL of the source.
+	pub url:     &'static str,
+}
+
+
+
+//		Functions
+
+//		sources																	
+/// Returns all of the sources of the embedded data.
+/// 
+/// The sources are grouped by module, in alphabetical order, with the primary
+/// standard or registry for each module first.
+/// 
+#[must_use]
+pub fn sources() -> &'static [Source] {
+	&SOURCES
+}
+
+//		sources_for																
+/// Returns the sources of the data provided by a module.
+/// 
+/// The primary standard or registry for the module is first. If the module
+/// does not provide any data taken from an external source, or does not exist,
+/// the result is empty.
+/// 
+/// # Parameters
+/// 
+/// * `module` - The name of the module, e.g. `country`.
+/// 
+#[must_use]
+pub fn sources_for(module: &str) -> Vec<&'static Source> {
+	SOURCES.iter().filter(|source| source.module == module).collect()
+}
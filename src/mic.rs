@@ -0,0 +1,585 @@
+//! Market identifier codes.
+//! 
+//! This module provides the ISO 10383 market identifier codes (MICs), which
+//! identify exchanges, trading platforms, and other venues, in the form of
+//! [`Mic`]. It is available when the `mic` feature is enabled.
+//! 
+//! ISO 10383 distinguishes between operating MICs, which identify the entity
+//! that operates a market, and segment MICs, which identify a section of a
+//! market, such as `XNGS` for the Nasdaq Global Select Market, which is part
+//! of `XNAS`. Each code knows its operating MIC and the [`CountryCode`] of the
+//! country that it is in:
+//! 
+//! ```
+//! use isosphere::{CountryCode, mic::{Mic, MicType}};
+//! 
+//! let mic: Mic = "XNGS".parse().unwrap();
+//! assert_eq!(mic.kind(),         MicType::Segment);
+//! assert_eq!(mic.operating(),    Mic::XNAS);
+//! assert_eq!(mic.country_code(), CountryCode::US);
+//! ```
+//! 
+//! The registry maintained on behalf of ISO has several thousand entries, many
+//! of which are for individual trading desks and systematic internalisers. The
+//! list here is curated, and covers the main stock and derivatives exchanges.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/mic.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	country::{Country, CountryCode},
+};
+use core::{
+	fmt::{Display, self},
+	str::FromStr,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
+
+
+
+//		Constants
+
+/// The number of market identifier codes.
+pub const COUNT: usize = 54;
+
+/// The market identifier codes.
+/// 
+/// This list is in the same order as the [`Mic`] variants, so that the
+/// information for a code can be looked up by its discriminant.
+/// 
+/// # Data sources
+/// 
+/// The registry of codes is published by [SWIFT on behalf of ISO](https://www.iso20022.org/market-identifier-codes),
+/// and summarised on [Wikipedia](https://en.wikipedia.org/wiki/Market_Identifier_Code).
+/// 
+/// # See also
+/// 
+/// * [`Mic`]
+/// 
+static MICS: [MicInfo; COUNT] = [
+	MicInfo { code: Mic::AIMX, name: "AIM",                              operating: Mic::XLON, country: CountryCode::GB },
+	MicInfo { code: Mic::ARCX, name: "NYSE Arca",                        operating: Mic::XNYS, country: CountryCode::US },
+	MicInfo { code: Mic::BMEX, name: "Bolsas y Mercados Españoles",      operating: Mic::BMEX, country: CountryCode::ES },
+	MicInfo { code: Mic::BVMF, name: "B3",                               operating: Mic::BVMF, country: CountryCode::BR },
+	MicInfo { code: Mic::IFEU, name: "ICE Futures Europe",               operating: Mic::IFEU, country: CountryCode::GB },
+	MicInfo { code: Mic::XAMS, name: "Euronext Amsterdam",               operating: Mic::XAMS, country: CountryCode::NL },
+	MicInfo { code: Mic::XASE, name: "NYSE American",                    operating: Mic::XNYS, country: CountryCode::US },
+	MicInfo { code: Mic::XASX, name: "ASX",                              operating: Mic::XASX, country: CountryCode::AU },
+	MicInfo { code: Mic::XBOM, name: "BSE",                              operating: Mic::XBOM, country: CountryCode::IN },
+	MicInfo { code: Mic::XBRU, name: "Euronext Brussels",                operating: Mic::XBRU, country: CountryCode::BE },
+	MicInfo { code: Mic::XCBO, name: "Cboe Options Exchange",            operating: Mic::XCBO, country: CountryCode::US },
+	MicInfo { code: Mic::XCBT, name: "Chicago Board of Trade",           operating: Mic::XCME, country: CountryCode::US },
+	MicInfo { code: Mic::XCEC, name: "Commodity Exchange",               operating: Mic::XCME, country: CountryCode::US },
+	MicInfo { code: Mic::XCME, name: "Chicago Mercantile Exchange",      operating: Mic::XCME, country: CountryCode::US },
+	MicInfo { code: Mic::XCSE, name: "Nasdaq Copenhagen",                operating: Mic::XCSE, country: CountryCode::DK },
+	MicInfo { code: Mic::XETR, name: "Xetra",                            operating: Mic::XETR, country: CountryCode::DE },
+	MicInfo { code: Mic::XEUR, name: "Eurex Deutschland",                operating: Mic::XEUR, country: CountryCode::DE },
+	MicInfo { code: Mic::XFRA, name: "Börse Frankfurt",                  operating: Mic::XFRA, country: CountryCode::DE },
+	MicInfo { code: Mic::XHEL, name: "Nasdaq Helsinki",                  operating: Mic::XHEL, country: CountryCode::FI },
+	MicInfo { code: Mic::XHKG, name: "Hong Kong Exchanges and Clearing", operating: Mic::XHKG, country: CountryCode::HK },
+	MicInfo { code: Mic::XIST, name: "Borsa Istanbul",                   operating: Mic::XIST, country: CountryCode::TR },
+	MicInfo { code: Mic::XJPX, name: "Japan Exchange Group",             operating: Mic::XJPX, country: CountryCode::JP },
+	MicInfo { code: Mic::XJSE, name: "Johannesburg Stock Exchange",      operating: Mic::XJSE, country: CountryCode::ZA },
+	MicInfo { code: Mic::XKRX, name: "Korea Exchange",                   operating: Mic::XKRX, country: CountryCode::KR },
+	MicInfo { code: Mic::XLIS, name: "Euronext Lisbon",                  operating: Mic::XLIS, country: CountryCode::PT },
+	MicInfo { code: Mic::XLON, name: "London Stock Exchange",            operating: Mic::XLON, country: CountryCode::GB },
+	MicInfo { code: Mic::XMAD, name: "Bolsa de Madrid",                  operating: Mic::BMEX, country: CountryCode::ES },
+	MicInfo { code: Mic::XMEX, name: "Bolsa Mexicana de Valores",        operating: Mic::XMEX, country: CountryCode::MX },
+	MicInfo { code: Mic::XMIL, name: "Euronext Milan",                   operating: Mic::XMIL, country: CountryCode::IT },
+	MicInfo { code: Mic::XMSM, name: "Euronext Dublin",                  operating: Mic::XMSM, country: CountryCode::IE },
+	MicInfo { code: Mic::XNAS, name: "Nasdaq",                           operating: Mic::XNAS, country: CountryCode::US },
+	MicInfo { code: Mic::XNCM, name: "Nasdaq Capital Market",            operating: Mic::XNAS, country: CountryCode::US },
+	MicInfo { code: Mic::XNGS, name: "Nasdaq Global Select Market",      operating: Mic::XNAS, country: CountryCode::US },
+	MicInfo { code: Mic::XNMS, name: "Nasdaq Global Market",             operating: Mic::XNAS, country: CountryCode::US },
+	MicInfo { code: Mic::XNSE, name: "National Stock Exchange of India", operating: Mic::XNSE, country: CountryCode::IN },
+	MicInfo { code: Mic::XNYM, name: "New York Mercantile Exchange",     operating: Mic::XCME, country: CountryCode::US },
+	MicInfo { code: Mic::XNYS, name: "New York Stock Exchange",          operating: Mic::XNYS, country: CountryCode::US },
+	MicInfo { code: Mic::XNZE, name: "NZX",                              operating: Mic::XNZE, country: CountryCode::NZ },
+	MicInfo { code: Mic::XOSE, name: "Osaka Exchange",                   operating: Mic::XJPX, country: CountryCode::JP },
+	MicInfo { code: Mic::XOSL, name: "Euronext Oslo Børs",               operating: Mic::XOSL, country: CountryCode::NO },
+	MicInfo { code: Mic::XPAR, name: "Euronext Paris",                   operating: Mic::XPAR, country: CountryCode::FR },
+	MicInfo { code: Mic::XSAU, name: "Saudi Exchange",                   operating: Mic::XSAU, country: CountryCode::SA },
+	MicInfo { code: Mic::XSES, name: "Singapore Exchange",               operating: Mic::XSES, country: CountryCode::SG },
+	MicInfo { code: Mic::XSHE, name: "Shenzhen Stock Exchange",          operating: Mic::XSHE, country: CountryCode::CN },
+	MicInfo { code: Mic::XSHG, name: "Shanghai Stock Exchange",          operating: Mic::XSHG, country: CountryCode::CN },
+	MicInfo { code: Mic::XSTO, name: "Nasdaq Stockholm",                 operating: Mic::XSTO, country: CountryCode::SE },
+	MicInfo { code: Mic::XSWX, name: "SIX Swiss Exchange",               operating: Mic::XSWX, country: CountryCode::CH },
+	MicInfo { code: Mic::XTAE, name: "Tel Aviv Stock Exchange",          operating: Mic::XTAE, country: CountryCode::IL },
+	MicInfo { code: Mic::XTAI, name: "Taiwan Stock Exchange",            operating: Mic::XTAI, country: CountryCode::TW },
+	MicInfo { code: Mic::XTKS, name: "Tokyo Stock Exchange",             operating: Mic::XJPX, country: CountryCode::JP },
+	MicInfo { code: Mic::XTSE, name: "Toronto Stock Exchange",           operating: Mic::XTSE, country: CountryCode::CA },
+	MicInfo { code: Mic::XTSX, name: "TSX Venture Exchange",             operating: Mic::XTSE, country: CountryCode::CA },
+	MicInfo { code: Mic::XWAR, name: "Warsaw Stock Exchange",            operating: Mic::XWAR, country: CountryCode::PL },
+	MicInfo { code: Mic::XWBO, name: "Wiener Börse",                     operating: Mic::XWBO, country: CountryCode::AT },
+];
+
+
+
+//		Enums
+
+//		Mic																		
+/// The ISO 10383 market identifier codes.
+/// 
+/// The four-letter code is the variant name. Operating MICs identify the
+/// entity that operates a market, and segment MICs identify a section of it,
+/// as indicated by [`kind()`](Mic::kind()).
+/// 
+/// This is a curated subset of the full registry, covering the main stock and
+/// derivatives exchanges, and so is non-exhaustive.
+/// 
+/// # See also
+/// 
+/// * [`MicType`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(into = "String", try_from = "String")]
+#[non_exhaustive]
+pub enum Mic {
+	/// AIM.
+	AIMX,
+	
+	/// NYSE Arca.
+	ARCX,
+	
+	/// Bolsas y Mercados Españoles.
+	BMEX,
+	
+	/// B3.
+	BVMF,
+	
+	/// ICE Futures Europe.
+	IFEU,
+	
+	/// Euronext Amsterdam.
+	XAMS,
+	
+	/// NYSE American.
+	XASE,
+	
+	/// ASX.
+	XASX,
+	
+	/// BSE.
+	XBOM,
+	
+	/// Euronext Brussels.
+	XBRU,
+	
+	/// Cboe Options Exchange.
+	XCBO,
+	
+	/// Chicago Board of Trade.
+	XCBT,
+	
+	/// Commodity Exchange.
+	XCEC,
+	
+	/// Chicago Mercantile Exchange.
+	XCME,
+	
+	/// Nasdaq Copenhagen.
+	XCSE,
+	
+	/// Xetra.
+	XETR,
+	
+	/// Eurex Deutschland.
+	XEUR,
+	
+	/// Börse Frankfurt.
+	XFRA,
+	
+	/// Nasdaq Helsinki.
+	XHEL,
+	
+	/// Hong Kong Exchanges and Clearing.
+	XHKG,
+	
+	/// Borsa Istanbul.
+	XIST,
+	
+	/// Japan Exchange Group.
+	XJPX,
+	
+	/// Johannesburg Stock Exchange.
+	XJSE,
+	
+	/// Korea Exchange.
+	XKRX,
+	
+	/// Euronext Lisbon.
+	XLIS,
+	
+	/// London Stock Exchange.
+	XLON,
+	
+	/// Bolsa de Madrid.
+	XMAD,
+	
+	/// Bolsa Mexicana de Valores.
+	XMEX,
+	
+	/// Euronext Milan.
+	XMIL,
+	
+	/// Euronext Dublin.
+	XMSM,
+	
+	/// Nasdaq.
+	XNAS,
+	
+	/// Nasdaq Capital Market.
+	XNCM,
+	
+	/// Nasdaq Global Select Market.
+	XNGS,
+	
+	/// Nasdaq Global Market.
+	XNMS,
+	
+	/// National Stock Exchange of India.
+	XNSE,
+	
+	/// New York Mercantile Exchange.
+	XNYM,
+	
+	/// New York Stock Exchange.
+	XNYS,
+	
+	/// NZX.
+	XNZE,
+	
+	/// Osaka Exchange.
+	XOSE,
+	
+	/// Euronext Oslo Børs.
+	XOSL,
+	
+	/// Euronext Paris.
+	XPAR,
+	
+	/// Saudi Exchange.
+	XSAU,
+	
+	/// Singapore Exchange.
+	XSES,
+	
+	/// Shenzhen Stock Exchange.
+	XSHE,
+	
+	/// Shanghai Stock Exchange.
+	XSHG,
+	
+	/// Nasdaq Stockholm.
+	XSTO,
+	
+	/// SIX Swiss Exchange.
+	XSWX,
+	
+	/// Tel Aviv Stock Exchange.
+	XTAE,
+	
+	/// Taiwan Stock Exchange.
+	XTAI,
+	
+	/// Tokyo Stock Exchange.
+	XTKS,
+	
+	/// Toronto Stock Exchange.
+	XTSE,
+	
+	/// TSX Venture Exchange.
+	XTSX,
+	
+	/// Warsaw Stock Exchange.
+	XWAR,
+	
+	/// Wiener Börse.
+	XWBO,
+}
+
+//󰭅		Mic																		
+impl Mic {
+	//		all																	
+	/// Returns all the market identifier codes.
+	#[must_use]
+	pub fn all() -> Vec<Self> {
+		MICS.iter().map(|info| info.code).collect()
+	}
+	
+	//		in_country															
+	/// Returns the market identifier codes for a given country.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `country` - The country to return the codes for.
+	/// 
+	#[must_use]
+	pub fn in_country(country: CountryCode) -> Vec<Self> {
+		MICS.iter().filter(|info| info.country == country).map(|info| info.code).collect()
+	}
+	
+	//		info																
+	/// Returns the `MicInfo` instance corresponding to the code.
+	#[must_use]
+	fn info(self) -> &'static MicInfo {
+		#[expect(clippy::unwrap_used, reason = "Infallible")]
+		//	This should be infallible. If it isn't, then the data is wrong, and one
+		//	of the codes is missing from the list, which is a bug.
+		MICS.get(self as usize).unwrap()
+	}
+	
+	//		name																
+	/// Returns the name of the market.
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		self.info().name
+	}
+	
+	//		kind																
+	/// Returns whether the code is an operating or a segment MIC.
+	#[must_use]
+	pub fn kind(&self) -> MicType {
+		if self.info().operating == *self {
+			MicType::Operating
+		} else {
+			MicType::Segment
+		}
+	}
+	
+	//		is_operating														
+	/// Returns whether the code is an operating MIC.
+	#[must_use]
+	pub fn is_operating(&self) -> bool {
+		self.kind() == MicType::Operating
+	}
+	
+	//		is_segment															
+	/// Returns whether the code is a segment MIC.
+	#[must_use]
+	pub fn is_segment(&self) -> bool {
+		self.kind() == MicType::Segment
+	}
+	
+	//		operating															
+	/// Returns the operating MIC.
+	/// 
+	/// For a segment MIC, this is the operating MIC of the market that the
+	/// segment belongs to. For an operating MIC, this is the code itself.
+	/// 
+	#[must_use]
+	pub fn operating(&self) -> Self {
+		self.info().operating
+	}
+	
+	//		segments															
+	/// Returns the segment MICs that belong to an operating MIC.
+	/// 
+	/// This is empty for a segment MIC, and for an operating MIC that has no
+	/// segments in the list.
+	/// 
+	#[must_use]
+	pub fn segments(&self) -> Vec<Self> {
+		MICS.iter()
+			.filter(|info| info.operating == *self && info.code != *self)
+			.map(|info| info.code)
+			.collect()
+	}
+	
+	//		country_code														
+	/// Returns the code of the country that the market is in.
+	#[must_use]
+	pub fn country_code(&self) -> CountryCode {
+		self.info().country
+	}
+	
+	//		country																
+	/// Returns the country that the market is in.
+	#[must_use]
+	pub fn country(&self) -> Country {
+		self.info().country.country()
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for Mic {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::AIMX => "AIMX",
+			Self::ARCX => "ARCX",
+			Self::BMEX => "BMEX",
+			Self::BVMF => "BVMF",
+			Self::IFEU => "IFEU",
+			Self::XAMS => "XAMS",
+			Self::XASE => "XASE",
+			Self::XASX => "XASX",
+			Self::XBOM => "XBOM",
+			Self::XBRU => "XBRU",
+			Self::XCBO => "XCBO",
+			Self::XCBT => "XCBT",
+			Self::XCEC => "XCEC",
+			Self::XCME => "XCME",
+			Self::XCSE => "XCSE",
+			Self::XETR => "XETR",
+			Self::XEUR => "XEUR",
+			Self::XFRA => "XFRA",
+			Self::XHEL => "XHEL",
+			Self::XHKG => "XHKG",
+			Self::XIST => "XIST",
+			Self::XJPX => "XJPX",
+			Self::XJSE => "XJSE",
+			Self::XKRX => "XKRX",
+			Self::XLIS => "XLIS",
+			Self::XLON => "XLON",
+			Self::XMAD => "XMAD",
+			Self::XMEX => "XMEX",
+			Self::XMIL => "XMIL",
+			Self::XMSM => "XMSM",
+			Self::XNAS => "XNAS",
+			Self::XNCM => "XNCM",
+			Self::XNGS => "XNGS",
+			Self::XNMS => "XNMS",
+			Self::XNSE => "XNSE",
+			Self::XNYM => "XNYM",
+			Self::XNYS => "XNYS",
+			Self::XNZE => "XNZE",
+			Self::XOSE => "XOSE",
+			Self::XOSL => "XOSL",
+			Self::XPAR => "XPAR",
+			Self::XSAU => "XSAU",
+			Self::XSES => "XSES",
+			Self::XSHE => "XSHE",
+			Self::XSHG => "XSHG",
+			Self::XSTO => "XSTO",
+			Self::XSWX => "XSWX",
+			Self::XTAE => "XTAE",
+			Self::XTAI => "XTAI",
+			Self::XTKS => "XTKS",
+			Self::XTSE => "XTSE",
+			Self::XTSX => "XTSX",
+			Self::XWAR => "XWAR",
+			Self::XWBO => "XWBO",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for Mic {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//󰭅		From<Mic> for String													
+impl From<Mic> for String {
+	//		from																
+	fn from(code: Mic) -> Self {
+		code.to_string()
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for Mic {
+	type Err = String;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let trimmed = s.trim();
+		MICS
+			.iter()
+			.find(|info| info.code.as_str().eq_ignore_ascii_case(trimmed))
+			.map(|info| info.code)
+			.ok_or_else(|| format!("Invalid Mic: {s}"))
+	}
+}
+
+//󰭅		TryFrom<String>															
+impl TryFrom<String> for Mic {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.as_str().parse()
+	}
+}
+
+//		MicType																	
+/// The type of a market identifier code.
+/// 
+/// # See also
+/// 
+/// * [`Mic`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[non_exhaustive]
+pub enum MicType {
+	/// An operating MIC, which identifies the entity that operates a market.
+	Operating,
+	
+	/// A segment MIC, which identifies a section of a market.
+	Segment,
+}
+
+//󰭅		AsStr																	
+impl AsStr for MicType {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Operating => "Operating",
+			Self::Segment   => "Segment",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for MicType {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+
+
+//		Structs
+
+//		MicInfo																	
+/// Market identifier code information.
+/// 
+/// # See also
+/// 
+/// * [`Mic`]
+/// 
+#[non_exhaustive]
+struct MicInfo {
+	//		Private properties													
+	/// The market identifier code. For more information, see [`Mic`].
+	code:      Mic,
+	
+	/// The name of the market.
+	name:      &'static str,
+	
+	/// The operating MIC, which is the code itself for an operating MIC.
+	operating: Mic,
+	
+	/// The country that the market is in.
+	country:   CountryCode,
+}
@@ -0,0 +1,158 @@
+//! Monetary amounts in a specific currency.
+//! 
+//! This module provides the [`Money`] type, which pairs an amount with the
+//! [`Currency`] it is denominated in. The amount is held as a whole number of
+//! minor units, e.g. pence or cents, according to the number of digits used by
+//! the currency, so that it is always exact and correctly scaled.
+//! 
+//! Conversion to and from decimal types is provided for the `rust_decimal` and
+//! `bigdecimal` crates, when the features of the same names are enabled. When
+//! converting from a decimal, the amount is rounded to the number of digits
+//! used by the currency, using banker's rounding, in the same way as the
+//! rounding helpers on [`Currency`].
+//! 
+//! This module is only available when at least one of the `rust_decimal` and
+//! `bigdecimal` features is enabled.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/money.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::currency::Currency;
+
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::{BigDecimal, num_bigint::BigInt};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
+
+
+//		Structs
+
+//		Money																	
+/// An amount of money in a specific currency.
+/// 
+/// The amount is held as a whole number of minor units of the currency, e.g.
+/// `1234` for £12.34, or `1234` for ¥1234, as the yen has no minor unit. For
+/// currencies where minor units are not applicable, such as gold, the amount
+/// is held in whole units.
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Money {
+	//		Private properties													
+	/// The amount, in minor units of the currency.
+	minor_units: i64,
+	
+	/// The currency that the amount is denominated in.
+	currency:    Currency,
+}
+
+//󰭅		Money																	
+impl Money {
+	//		new																	
+	/// Creates a new amount of money.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `minor_units` - The amount, in minor units of the currency.
+	/// * `currency`    - The currency that the amount is denominated in.
+	/// 
+	#[must_use]
+	pub const fn new(minor_units: i64, currency: Currency) -> Self {
+		Self { minor_units, currency }
+	}
+	
+	//		from_decimal														
+	/// Creates an amount of money from a [`Decimal`].
+	/// 
+	/// The amount is rounded to the number of digits used by the currency,
+	/// using [`Currency::round_decimal()`]. If the result is too large to be
+	/// held in minor units, [`None`] is returned.
+	/// 
+	/// This is only available when the `rust_decimal` feature is enabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `amount`   - The amount, in major units of the currency.
+	/// * `currency` - The currency that the amount is denominated in.
+	/// 
+	#[cfg(feature = "rust_decimal")]
+	#[must_use]
+	pub fn from_decimal(amount: Decimal, currency: Currency) -> Option<Self> {
+		let mut rounded = currency.round_decimal(amount);
+		rounded.rescale(u32::from(currency.digits().decimal_places()));
+		i64::try_from(rounded.mantissa()).ok().map(|minor_units| Self::new(minor_units, currency))
+	}
+	
+	//		from_bigdecimal														
+	/// Creates an amount of money from a [`BigDecimal`].
+	/// 
+	/// The amount is rounded to the number of digits used by the currency,
+	/// using [`Currency::round_bigdecimal()`]. If the result is too large to be
+	/// held in minor units, [`None`] is returned.
+	/// 
+	/// This is only available when the `bigdecimal` feature is enabled.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `amount`   - The amount, in major units of the currency.
+	/// * `currency` - The currency that the amount is denominated in.
+	/// 
+	#[cfg(feature = "bigdecimal")]
+	#[must_use]
+	pub fn from_bigdecimal(amount: &BigDecimal, currency: Currency) -> Option<Self> {
+		let (digits, _) = currency.round_bigdecimal(amount).into_bigint_and_exponent();
+		i64::try_from(digits).ok().map(|minor_units| Self::new(minor_units, currency))
+	}
+	
+	//		minor_units															
+	/// Returns the amount, in minor units of the currency.
+	#[must_use]
+	pub const fn minor_units(&self) -> i64 {
+		self.minor_units
+	}
+	
+	//		currency															
+	/// Returns the currency that the amount is denominated in.
+	#[must_use]
+	pub const fn currency(&self) -> Currency {
+		self.currency
+	}
+	
+	//		to_decimal															
+	/// Returns the amount, in major units of the currency, as a [`Decimal`].
+	/// 
+	/// The result has a scale equal to the number of digits used by the
+	/// currency.
+	/// 
+	/// This is only available when the `rust_decimal` feature is enabled.
+	/// 
+	#[cfg(feature = "rust_decimal")]
+	#[must_use]
+	pub fn to_decimal(&self) -> Decimal {
+		Decimal::new(self.minor_units, u32::from(self.currency.digits().decimal_places()))
+	}
+	
+	//		to_bigdecimal														
+	/// Returns the amount, in major units of the currency, as a [`BigDecimal`].
+	/// 
+	/// The result has a scale equal to the number of digits used by the
+	/// currency.
+	/// 
+	/// This is only available when the `bigdecimal` feature is enabled.
+	/// 
+	#[cfg(feature = "bigdecimal")]
+	#[must_use]
+	pub fn to_bigdecimal(&self) -> BigDecimal {
+		BigDecimal::new(BigInt::from(self.minor_units), i64::from(self.currency.digits().decimal_places()))
+	}
+}
@@ -0,0 +1,536 @@
+//! Machine-readable travel document codes.
+//! 
+//! This module provides the codes used for the issuing state and nationality
+//! in the machine-readable zone (MRZ) of passports, identity cards, and visas,
+//! as specified by ICAO Doc 9303, in the form of [`MrzCode`]. These are mostly
+//! ISO 3166-1 alpha-3 codes, but Germany is given as `D`, the classes of
+//! British nationality other than British citizen have codes of their own,
+//! and there are special codes for documents issued by international
+//! organisations, such as `UNO` for the United Nations and `XPO` for Interpol,
+//! and for people without a nationality:
+//! 
+//! ```
+//! use isosphere::{Country, mrz::{BritishNationality, MrzCode, MrzOrganisation}};
+//! 
+//! assert_eq!("D<<".parse::<MrzCode>(), Ok(MrzCode::Country(Country::DE)));
+//! assert_eq!("GBR".parse::<MrzCode>(), Ok(MrzCode::Country(Country::GB)));
+//! assert_eq!("GBN".parse::<MrzCode>(), Ok(MrzCode::BritishNationality(BritishNationality::GBN)));
+//! assert_eq!("XPO".parse::<MrzCode>(), Ok(MrzCode::Organisation(MrzOrganisation::XPO)));
+//! assert_eq!("XXA".parse::<MrzCode>(), Ok(MrzCode::Stateless));
+//! ```
+//! 
+//! Codes can also be parsed directly into a [`Country`] by enabling the
+//! [`mrz`](crate::parse::ParseOptions::mrz()) parsing option.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/mrz.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	country::Country,
+	parse::ParseOptions,
+};
+use core::{
+	fmt::{Display, self},
+	str::FromStr,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "utoipa")]
+use utoipa::{
+	ToSchema,
+	openapi::{ObjectBuilder, RefOr, Schema, SchemaType},
+};
+
+
+
+//		Constants
+
+/// The classes of British nationality other than British citizen.
+/// 
+/// # Data sources
+/// 
+/// The codes are listed in [ICAO Doc 9303](https://www.icao.int/publications/pages/publication.aspx?docnum=9303),
+/// part 3.
+/// 
+/// # See also
+/// 
+/// * [`BritishNationality`]
+/// 
+static NATIONALITIES: [(BritishNationality, &str); 5] = [
+	(BritishNationality::GBD, "British Overseas Territories citizen"),
+	(BritishNationality::GBN, "British National (Overseas)"),
+	(BritishNationality::GBO, "British Overseas citizen"),
+	(BritishNationality::GBP, "British protected person"),
+	(BritishNationality::GBS, "British subject"),
+];
+
+/// The international organisations that issue travel documents.
+/// 
+/// # Data sources
+/// 
+/// The codes are listed in [ICAO Doc 9303](https://www.icao.int/publications/pages/publication.aspx?docnum=9303),
+/// part 3.
+/// 
+/// # See also
+/// 
+/// * [`MrzOrganisation`]
+/// 
+static ORGANISATIONS: [(MrzOrganisation, &str); 14] = [
+	(MrzOrganisation::EUE, "European Union"),
+	(MrzOrganisation::UNA, "Specialized agency of the United Nations"),
+	(MrzOrganisation::UNK, "United Nations Interim Administration Mission in Kosovo"),
+	(MrzOrganisation::UNO, "United Nations"),
+	(MrzOrganisation::XBA, "African Development Bank"),
+	(MrzOrganisation::XCC, "Caribbean Community"),
+	(MrzOrganisation::XCE, "Council of Europe"),
+	(MrzOrganisation::XCO, "Common Market for Eastern and Southern Africa"),
+	(MrzOrganisation::XDC, "Southern African Development Community"),
+	(MrzOrganisation::XEC, "Economic Community of West African States"),
+	(MrzOrganisation::XES, "Organisation of Eastern Caribbean States"),
+	(MrzOrganisation::XIM, "African Export-Import Bank"),
+	(MrzOrganisation::XOM, "Sovereign Military Order of Malta"),
+	(MrzOrganisation::XPO, "International Criminal Police Organization"),
+];
+
+
+
+//		Enums
+
+//		BritishNationality														
+/// The classes of British nationality other than British citizen.
+/// 
+/// These are the classes with codes of their own in ICAO Doc 9303, for use as
+/// the nationality in travel documents issued by the United Kingdom. British
+/// citizens are given the code of the United Kingdom, `GBR`. The three-letter
+/// code is the variant name.
+/// 
+/// # See also
+/// 
+/// * [`MrzCode`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(into = "String", try_from = "String")]
+#[non_exhaustive]
+pub enum BritishNationality {
+	/// British Overseas Territories citizen.
+	GBD,
+	
+	/// British National (Overseas).
+	GBN,
+	
+	/// British Overseas citizen.
+	GBO,
+	
+	/// British protected person.
+	GBP,
+	
+	/// British subject.
+	GBS,
+}
+
+//󰭅		BritishNationality														
+impl BritishNationality {
+	//		all																	
+	/// Returns all the classes of nationality.
+	#[must_use]
+	pub fn all() -> Vec<Self> {
+		NATIONALITIES.iter().map(|entry| entry.0).collect()
+	}
+	
+	//		info																
+	/// Returns the entry corresponding to the class of nationality.
+	#[must_use]
+	fn info(self) -> &'static (Self, &'static str) {
+		#[expect(clippy::unwrap_used, reason = "Infallible")]
+		//	This should be infallible. If it isn't, then the data is wrong, and one
+		//	of the codes is missing from the list, which is a bug.
+		NATIONALITIES.iter().find(|entry| entry.0 == self).unwrap()
+	}
+	
+	//		name																
+	/// Returns the name of the class of nationality.
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		self.info().1
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for BritishNationality {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::GBD => "GBD",
+			Self::GBN => "GBN",
+			Self::GBO => "GBO",
+			Self::GBP => "GBP",
+			Self::GBS => "GBS",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for BritishNationality {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//󰭅		From<BritishNationality> for String										
+impl From<BritishNationality> for String {
+	//		from																
+	fn from(code: BritishNationality) -> Self {
+		code.to_string()
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for BritishNationality {
+	type Err = String;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let trimmed = s.trim();
+		NATIONALITIES
+			.iter()
+			.find(|entry| entry.0.as_str().eq_ignore_ascii_case(trimmed))
+			.map(|entry| entry.0)
+			.ok_or_else(|| format!("Invalid BritishNationality: {s}"))
+	}
+}
+
+//󰭅		TryFrom<String>															
+impl TryFrom<String> for BritishNationality {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.as_str().parse()
+	}
+}
+
+//		MrzCode																	
+/// A code for an issuing state or nationality in a machine-readable zone.
+/// 
+/// Codes that identify a country are resolved to a [`Country`], including `D`
+/// for Germany. The British nationality codes `GBD`, `GBN`, `GBO`, `GBP`, and
+/// `GBS` are kept as a [`BritishNationality`], so that the class of
+/// nationality is not lost, but are also resolved to the United Kingdom by
+/// [`country()`](MrzCode::country()). The remaining codes are classified as
+/// documents issued by an international organisation, or as denoting a person
+/// who is stateless, a refugee, or of unspecified nationality.
+/// 
+/// Parsing ignores surrounding whitespace, trailing `<` fillers, and case. The
+/// code is serialised as it would appear in the MRZ, without fillers, i.e. as
+/// the alpha-3 code for a country, except `D` for Germany, and as the original
+/// code for a British nationality class, so that every code round-trips.
+/// 
+/// # See also
+/// 
+/// * [`BritishNationality`]
+/// * [`MrzOrganisation`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(into = "String", try_from = "String")]
+#[non_exhaustive]
+pub enum MrzCode {
+	/// A country.
+	Country(Country),
+	
+	/// A class of British nationality other than British citizen, which is a
+	/// nationality of the United Kingdom.
+	BritishNationality(BritishNationality),
+	
+	/// An international organisation that issues travel documents.
+	Organisation(MrzOrganisation),
+	
+	/// A stateless person, as defined in Article 1 of the 1954 Convention
+	/// Relating to the Status of Stateless Persons (`XXA`).
+	Stateless,
+	
+	/// A refugee, as defined in Article 1 of the 1951 Convention Relating to
+	/// the Status of Refugees, as amended by the 1967 Protocol (`XXB`).
+	Refugee,
+	
+	/// A refugee other than as defined by the 1951 Convention (`XXC`).
+	OtherRefugee,
+	
+	/// A person of unspecified nationality (`XXX`).
+	Unspecified,
+}
+
+//󰭅		MrzCode																	
+impl MrzCode {
+	//		country																
+	/// Returns the country identified by the code, if any.
+	/// 
+	/// The British nationality classes are resolved to the United Kingdom.
+	/// 
+	#[must_use]
+	pub const fn country(&self) -> Option<Country> {
+		match *self {
+			Self::Country(country)      => Some(country),
+			Self::BritishNationality(_) => Some(Country::GB),
+			Self::Organisation(_)
+			| Self::Stateless
+			| Self::Refugee
+			| Self::OtherRefugee
+			| Self::Unspecified         => None,
+		}
+	}
+	
+	//		is_special															
+	/// Returns `true` if the code does not identify a country.
+	#[must_use]
+	pub const fn is_special(&self) -> bool {
+		self.country().is_none()
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for MrzCode {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Country(Country::DE)         => "D",
+			Self::Country(country)             => country.code().to_alpha3().as_str(),
+			Self::BritishNationality(ref code) => code.as_str(),
+			Self::Organisation(ref code)       => code.as_str(),
+			Self::Stateless                    => "XXA",
+			Self::Refugee                      => "XXB",
+			Self::OtherRefugee                 => "XXC",
+			Self::Unspecified                  => "XXX",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for MrzCode {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//󰭅		From<Country>															
+impl From<Country> for MrzCode {
+	//		from																
+	fn from(country: Country) -> Self {
+		Self::Country(country)
+	}
+}
+
+//󰭅		From<MrzCode> for String												
+impl From<MrzCode> for String {
+	//		from																
+	fn from(code: MrzCode) -> Self {
+		code.to_string()
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for MrzCode {
+	type Err = String;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let code = s.trim().trim_end_matches('<').to_ascii_uppercase();
+		match code.as_str() {
+			"XXA" => Ok(Self::Stateless),
+			"XXB" => Ok(Self::Refugee),
+			"XXC" => Ok(Self::OtherRefugee),
+			"XXX" => Ok(Self::Unspecified),
+			_     => code.parse().map(Self::Organisation)
+				.or_else(|_| code.parse().map(Self::BritishNationality))
+				.or_else(|_| {
+					let options = ParseOptions::strict().mrz(true);
+					(code.len() == 3 || code == "D")
+						.then(|| Country::parse_with(&code, &options).ok().map(Self::Country))
+						.flatten()
+						.ok_or_else(|| format!("Invalid MrzCode: {s}"))
+				}),
+		}
+	}
+}
+
+//󰭅		ToSchema																
+#[cfg(feature = "utoipa")]
+impl<'s> ToSchema<'s> for MrzCode {
+	//		schema																
+	fn schema() -> (&'s str, RefOr<Schema>) {
+		("MrzCode", ObjectBuilder::new().schema_type(SchemaType::String).into())
+	}
+}
+
+//󰭅		TryFrom<String>															
+impl TryFrom<String> for MrzCode {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.as_str().parse()
+	}
+}
+
+//		MrzOrganisation															
+/// The international organisations that issue travel documents.
+/// 
+/// These are the organisations with codes of their own in ICAO Doc 9303, for
+/// use as the issuing state or nationality in travel documents issued to
+/// their officials. The three-letter code is the variant name.
+/// 
+/// # See also
+/// 
+/// * [`MrzCode`]
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(into = "String", try_from = "String")]
+#[non_exhaustive]
+pub enum MrzOrganisation {
+	/// European Union.
+	EUE,
+	
+	/// Specialized agency of the United Nations.
+	UNA,
+	
+	/// United Nations Interim Administration Mission in Kosovo.
+	UNK,
+	
+	/// United Nations.
+	UNO,
+	
+	/// African Development Bank.
+	XBA,
+	
+	/// Caribbean Community.
+	XCC,
+	
+	/// Council of Europe.
+	XCE,
+	
+	/// Common Market for Eastern and Southern Africa.
+	XCO,
+	
+	/// Southern African Development Community.
+	XDC,
+	
+	/// Economic Community of West African States.
+	XEC,
+	
+	/// Organisation of Eastern Caribbean States.
+	XES,
+	
+	/// African Export-Import Bank.
+	XIM,
+	
+	/// Sovereign Military Order of Malta.
+	XOM,
+	
+	/// International Criminal Police Organization.
+	XPO,
+}
+
+//󰭅		MrzOrganisation															
+impl MrzOrganisation {
+	//		all																	
+	/// Returns all the organisations.
+	#[must_use]
+	pub fn all() -> Vec<Self> {
+		ORGANISATIONS.iter().map(|entry| entry.0).collect()
+	}
+	
+	//		info																
+	/// Returns the entry corresponding to the organisation.
+	#[must_use]
+	fn info(self) -> &'static (Self, &'static str) {
+		#[expect(clippy::unwrap_used, reason = "Infallible")]
+		//	This should be infallible. If it isn't, then the data is wrong, and one
+		//	of the codes is missing from the list, which is a bug.
+		ORGANISATIONS.iter().find(|entry| entry.0 == self).unwrap()
+	}
+	
+	//		name																
+	/// Returns the name of the organisation.
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		self.info().1
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for MrzOrganisation {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::EUE => "EUE",
+			Self::UNA => "UNA",
+			Self::UNK => "UNK",
+			Self::UNO => "UNO",
+			Self::XBA => "XBA",
+			Self::XCC => "XCC",
+			Self::XCE => "XCE",
+			Self::XCO => "XCO",
+			Self::XDC => "XDC",
+			Self::XEC => "XEC",
+			Self::XES => "XES",
+			Self::XIM => "XIM",
+			Self::XOM => "XOM",
+			Self::XPO => "XPO",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for MrzOrganisation {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//󰭅		From<MrzOrganisation> for String										
+impl From<MrzOrganisation> for String {
+	//		from																
+	fn from(code: MrzOrganisation) -> Self {
+		code.to_string()
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for MrzOrganisation {
+	type Err = String;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let trimmed = s.trim();
+		ORGANISATIONS
+			.iter()
+			.find(|entry| entry.0.as_str().eq_ignore_ascii_case(trimmed))
+			.map(|entry| entry.0)
+			.ok_or_else(|| format!("Invalid MrzOrganisation: {s}"))
+	}
+}
+
+//󰭅		TryFrom<String>															
+impl TryFrom<String> for MrzOrganisation {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.as_str().parse()
+	}
+}
@@ -0,0 +1,154 @@
+//! Membership of international organisations.
+//! 
+//! This module provides curated sets of the member states of some commonly
+//! used international organisations and groupings, such as the [`G20`] and
+//! [`ASEAN`], as [`CountrySet`]s. Analytics and pricing tiers are frequently
+//! defined in terms of these groups.
+//! 
+//! The sets can be used directly, e.g. `orgs::G20.contains(country)`, or via
+//! the [`Org`] enum and [`Country::is_member_of()`]. Only countries are
+//! included, and not any supranational members, such as the European Union in
+//! the case of the G20.
+//! 
+//! The membership data is current as of the [`DATA_VERSION`](crate::DATA_VERSION).
+//! 
+//! This module is only available when the `orgs` feature is enabled.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/orgs.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	country::Country,
+	set::CountrySet,
+};
+use core::fmt::{Display, self};
+use std::sync::LazyLock;
+
+
+
+//		Constants
+
+/// The member states of the Association of Southeast Asian Nations.
+pub static ASEAN: LazyLock<CountrySet> = LazyLock::new(|| {
+	CountrySet::from_iter([
+		Country::BN, Country::ID, Country::KH, Country::LA, Country::MM, Country::MY, Country::PH, Country::SG, Country::TH, Country::VN,
+	])
+});
+
+/// The member states of the Commonwealth of Nations.
+pub static COMMONWEALTH: LazyLock<CountrySet> = LazyLock::new(|| {
+	CountrySet::from_iter([
+		Country::AG, Country::AU, Country::BB, Country::BD, Country::BN, Country::BS, Country::BW, Country::BZ, Country::CA, Country::CM,
+		Country::CY, Country::DM, Country::FJ, Country::GA, Country::GB, Country::GD, Country::GH, Country::GM, Country::GY, Country::IN,
+		Country::JM, Country::KE, Country::KI, Country::KN, Country::LC, Country::LK, Country::LS, Country::MT, Country::MU, Country::MV,
+		Country::MW, Country::MY, Country::MZ, Country::NA, Country::NG, Country::NR, Country::NZ, Country::PG, Country::PK, Country::RW,
+		Country::SB, Country::SC, Country::SG, Country::SL, Country::SZ, Country::TG, Country::TO, Country::TT, Country::TV, Country::TZ,
+		Country::UG, Country::VC, Country::VU, Country::WS, Country::ZA, Country::ZM,
+	])
+});
+
+/// The member states of the Group of Seven.
+pub static G7: LazyLock<CountrySet> = LazyLock::new(|| {
+	CountrySet::from_iter([
+		Country::CA, Country::DE, Country::FR, Country::GB, Country::IT, Country::JP, Country::US,
+	])
+});
+
+/// The member states of the Group of Twenty.
+pub static G20: LazyLock<CountrySet> = LazyLock::new(|| {
+	CountrySet::from_iter([
+		Country::AR, Country::AU, Country::BR, Country::CA, Country::CN, Country::DE, Country::FR, Country::GB, Country::ID, Country::IN,
+		Country::IT, Country::JP, Country::KR, Country::MX, Country::RU, Country::SA, Country::TR, Country::US, Country::ZA,
+	])
+});
+
+/// The member states of the Organisation for Economic Co-operation and Development.
+pub static OECD: LazyLock<CountrySet> = LazyLock::new(|| {
+	CountrySet::from_iter([
+		Country::AT, Country::AU, Country::BE, Country::CA, Country::CH, Country::CL, Country::CO, Country::CR, Country::CZ, Country::DE,
+		Country::DK, Country::EE, Country::ES, Country::FI, Country::FR, Country::GB, Country::GR, Country::HU, Country::IE, Country::IL,
+		Country::IS, Country::IT, Country::JP, Country::KR, Country::LT, Country::LU, Country::LV, Country::MX, Country::NL, Country::NO,
+		Country::NZ, Country::PL, Country::PT, Country::SE, Country::SI, Country::SK, Country::TR, Country::US,
+	])
+});
+
+
+
+//		Enums
+
+//		Org																		
+/// An international organisation or grouping of countries.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Org {
+	/// The Association of Southeast Asian Nations.
+	Asean,
+	
+	/// The Commonwealth of Nations.
+	Commonwealth,
+	
+	/// The Group of Seven.
+	G7,
+	
+	/// The Group of Twenty.
+	G20,
+	
+	/// The Organisation for Economic Co-operation and Development.
+	Oecd,
+}
+
+//󰭅		Org																		
+impl Org {
+	//		all																	
+	/// Returns all the organisations.
+	#[must_use]
+	pub fn all() -> Vec<Self> {
+		vec![ Self::Asean, Self::Commonwealth, Self::G7, Self::G20, Self::Oecd ]
+	}
+	
+	//		members																
+	/// Returns the member states of the organisation.
+	#[must_use]
+	pub fn members(&self) -> &'static CountrySet {
+		match *self {
+			Self::Asean        => &ASEAN,
+			Self::Commonwealth => &COMMONWEALTH,
+			Self::G7           => &G7,
+			Self::G20          => &G20,
+			Self::Oecd         => &OECD,
+		}
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for Org {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Asean        => "ASEAN",
+			Self::Commonwealth => "Commonwealth",
+			Self::G7           => "G7",
+			Self::G20          => "G20",
+			Self::Oecd         => "OECD",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for Org {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
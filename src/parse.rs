@@ -0,0 +1,406 @@
+//! Options for parsing.
+//! 
+//! This module provides the [`ParseOptions`] type, which controls how leniently
+//! text is parsed into a [`Country`](crate::country::Country),
+//! [`Currency`](crate::currency::Currency), or
+//! [`Language`](crate::language::Language), using their `parse_with()` methods.
+//! The [`FromStr`](core::str::FromStr) implementations each have a fixed set of
+//! behaviours, whereas the options allow applications to opt into precisely the
+//! leniency they want, from accepting only exact codes, to accepting codes in
+//! any case, numeric codes, names, and aliases:
+//! 
+//! ```
+//! use isosphere::{Country, parse::ParseOptions};
+//! 
+//! let strict = ParseOptions::strict();
+//! assert_eq!(Country::parse_with("FR", &strict), Ok(Country::FR));
+//! assert!(Country::parse_with("fr", &strict).is_err());
+//! 
+//! let lenient = ParseOptions::lenient();
+//! assert_eq!(Country::parse_with("fr",     &lenient), Ok(Country::FR));
+//! assert_eq!(Country::parse_with("250",    &lenient), Ok(Country::FR));
+//! assert_eq!(Country::parse_with("France", &lenient), Ok(Country::FR));
+//! ```
+//! 
+//! When the `tracing` feature is enabled, a [`tracing`](https://crates.io/crates/tracing)
+//! event is emitted at debug level whenever text is parsed leniently, e.g. when
+//! `UK` is resolved to [`Country::GB`](crate::country::Country::GB), or a name
+//! or alias is resolved. Each event holds the original input, the resolved
+//! value, and the means of resolution, so that data-quality teams can monitor
+//! how clean their inputs are.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/parse.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use core::fmt::Debug;
+
+#[cfg(feature = "tracing")]
+use tracing::debug;
+
+
+
+//		Structs
+
+//		ParseOptions															
+/// Options controlling how text is parsed.
+/// 
+/// The options start out either [strict](ParseOptions::strict()), accepting
+/// only codes in their canonical case, or [lenient](ParseOptions::lenient()),
+/// accepting everything, and individual behaviours can then be turned on or off
+/// using builder-style methods:
+/// 
+/// ```
+/// use isosphere::{Currency, parse::ParseOptions};
+/// 
+/// let options = ParseOptions::strict().case_insensitive(true).aliases(true);
+/// assert_eq!(Currency::parse_with("gbp",      &options), Ok(Currency::GBP));
+/// assert_eq!(Currency::parse_with("Sterling", &options), Ok(Currency::GBP));
+/// assert!(Currency::parse_with("826", &options).is_err());
+/// ```
+/// 
+/// Text is tried as a code first, and then as a non-ISO code, an MRZ code, a
+/// numeric code, a name, and an alias, in that order, as far as each is
+/// enabled. Names and aliases are always matched loosely, ignoring case,
+/// accents, and punctuation, in the same way as by the
+/// [`FromStr`](core::str::FromStr) implementations.
+/// 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[expect(clippy::struct_excessive_bools, reason = "Independent options")]
+pub struct ParseOptions {
+	//		Private properties													
+	/// Whether codes are accepted in any case.
+	case_insensitive: bool,
+	
+	/// Whether names are accepted.
+	names:            bool,
+	
+	/// Whether alternative names are accepted.
+	aliases:          bool,
+	
+	/// Whether numeric codes are accepted.
+	numeric:          bool,
+	
+	/// Whether codes reserved for user assignment or testing are accepted.
+	user_assigned:    bool,
+	
+	/// Whether commonly-used non-ISO codes are accepted.
+	quirks:           bool,
+	
+	/// Whether the special codes of machine-readable travel documents are
+	/// accepted.
+	mrz:              bool,
+	
+	/// Whether surrounding whitespace is ignored.
+	trim:             bool,
+}
+
+//󰭅		ParseOptions															
+impl ParseOptions {
+	//		strict																
+	/// Creates options that accept only codes in their canonical case.
+	/// 
+	/// This means upper case for country and currency codes, e.g. `FR` and
+	/// `EUR`, and lower case for language codes, e.g. `fr`. Codes reserved for
+	/// user assignment or testing are not accepted. These are also the default
+	/// options.
+	/// 
+	#[must_use]
+	pub const fn strict() -> Self {
+		Self {
+			case_insensitive: false,
+			names:            false,
+			aliases:          false,
+			numeric:          false,
+			user_assigned:    false,
+			quirks:           false,
+			mrz:              false,
+			trim:             false,
+		}
+	}
+	
+	//		lenient																
+	/// Creates options that accept everything.
+	/// 
+	/// This means codes in any case, numeric codes, names, aliases, codes
+	/// reserved for user assignment or testing, commonly-used non-ISO codes,
+	/// and text with surrounding whitespace. The [`mrz`](Self::mrz()) option is
+	/// not enabled, as its codes are only meaningful in travel documents.
+	/// 
+	#[must_use]
+	pub const fn lenient() -> Self {
+		Self {
+			case_insensitive: true,
+			names:            true,
+			aliases:          true,
+			numeric:          true,
+			user_assigned:    true,
+			quirks:           true,
+			mrz:              false,
+			trim:             true,
+		}
+	}
+	
+	//		case_insensitive													
+	/// Sets whether codes are accepted in any case, e.g. `fr` for France.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether the behaviour is enabled.
+	/// 
+	#[must_use]
+	pub const fn case_insensitive(mut self, enabled: bool) -> Self {
+		self.case_insensitive = enabled;
+		self
+	}
+	
+	//		names																
+	/// Sets whether names are accepted, e.g. `France`.
+	/// 
+	/// For countries, this includes the full official names, and any names
+	/// added to the [`Registry`](crate::registry::Registry).
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether the behaviour is enabled.
+	/// 
+	#[must_use]
+	pub const fn names(mut self, enabled: bool) -> Self {
+		self.names = enabled;
+		self
+	}
+	
+	//		aliases																
+	/// Sets whether alternative names are accepted, e.g. `Sterling`.
+	/// 
+	/// These are the common non-ISO names of currencies and languages, such as
+	/// those returned by [`Currency::alternative_names()`](crate::currency::Currency::alternative_names()).
+	/// Countries do not have any.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether the behaviour is enabled.
+	/// 
+	#[must_use]
+	pub const fn aliases(mut self, enabled: bool) -> Self {
+		self.aliases = enabled;
+		self
+	}
+	
+	//		numeric																
+	/// Sets whether numeric codes are accepted, e.g. `250` for France.
+	/// 
+	/// Numeric codes must have exactly three digits, e.g. `008` for Albania.
+	/// Languages do not have numeric codes.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether the behaviour is enabled.
+	/// 
+	#[must_use]
+	pub const fn numeric(mut self, enabled: bool) -> Self {
+		self.numeric = enabled;
+		self
+	}
+	
+	//		user_assigned														
+	/// Sets whether codes reserved for user assignment or testing are accepted.
+	/// 
+	/// These are codes that do not identify a real entity, i.e. the currency
+	/// codes `XTS`, which is reserved for testing, and `XXX`, which denotes no
+	/// currency. The user-assigned ranges of ISO 3166-1 and ISO 639 are not
+	/// represented in the data, and so this has no effect on countries or
+	/// languages.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether the behaviour is enabled.
+	/// 
+	#[must_use]
+	pub const fn user_assigned(mut self, enabled: bool) -> Self {
+		self.user_assigned = enabled;
+		self
+	}
+	
+	//		quirks																
+	/// Sets whether commonly-used non-ISO codes are accepted.
+	/// 
+	/// These are codes that are widely used in place of the ISO codes, and are
+	/// canonicalised to them, i.e. `UK` for the United Kingdom (`GB`), `EL` for
+	/// Greece (`GR`), as used by the institutions of the European Union, and
+	/// `FX` for metropolitan France (`FR`). The codes `EM` and `EU`, which are
+	/// used for the European Union in trademark and other data, do not identify
+	/// a country, and so are only accepted when parsing a
+	/// [`Jurisdiction`](crate::country::Jurisdiction). This has no effect on
+	/// currencies or languages.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether the behaviour is enabled.
+	/// 
+	#[must_use]
+	pub const fn quirks(mut self, enabled: bool) -> Self {
+		self.quirks = enabled;
+		self
+	}
+	
+	//		mrz																	
+	/// Sets whether the special codes of machine-readable travel documents are
+	/// accepted.
+	/// 
+	/// The machine-readable zone (MRZ) of a passport or identity card, as
+	/// specified by ICAO Doc 9303, gives the issuing state and nationality as
+	/// alpha-3 codes, padded with `<` filler characters, except that Germany is
+	/// given as `D<<`, and the British nationality classes as `GBD`, `GBN`,
+	/// `GBO`, `GBP`, and `GBS`. With this option, those codes are accepted, and
+	/// resolved to Germany and the United Kingdom respectively, with or without
+	/// the fillers. This has no effect on currencies or languages. The codes
+	/// that do not identify a country, such as `UNO` for the United Nations, are
+	/// classified by [`MrzCode`](crate::mrz::MrzCode), which also keeps the
+	/// British nationality classes distinct.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether the behaviour is enabled.
+	/// 
+	#[must_use]
+	pub const fn mrz(mut self, enabled: bool) -> Self {
+		self.mrz = enabled;
+		self
+	}
+	
+	//		trim																
+	/// Sets whether surrounding whitespace is ignored, e.g. `FR ` for France.
+	/// 
+	/// This is common in data copied from spreadsheets. All Unicode whitespace
+	/// is ignored, including non-breaking spaces. Names and aliases are always
+	/// matched loosely, and so whitespace in them, whether at the ends or
+	/// within, is ignored regardless of this option.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `enabled` - Whether the behaviour is enabled.
+	/// 
+	#[must_use]
+	pub const fn trim(mut self, enabled: bool) -> Self {
+		self.trim = enabled;
+		self
+	}
+	
+	//		parse																
+	/// Parses text according to the options.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `text` - The text to parse.
+	/// 
+	pub(crate) fn parse<T: Parse>(self, text: &str) -> Option<T> {
+		let s          = if self.trim { text.trim() } else { text };
+		let resolution = T::from_code(s, false)
+			.map(|value| (value, "code"))
+			.or_else(|| self.case_insensitive.then(|| T::from_code(s, true)).flatten().map(|value| (value, "case")))
+			.or_else(|| self.quirks.then(|| T::from_quirk(s, self.case_insensitive)).flatten().map(|value| (value, "quirk")))
+			.or_else(|| self.mrz.then(|| T::from_mrz(s, self.case_insensitive)).flatten().map(|value| (value, "mrz")))
+			.or_else(|| {
+				let numeric = self.numeric && s.len() == 3 && s.bytes().all(|byte| byte.is_ascii_digit());
+				numeric.then(|| s.parse().ok().and_then(T::from_numeric)).flatten().map(|value| (value, "numeric"))
+			})
+			.or_else(|| self.names.then(|| T::from_name(s)).flatten().map(|value| (value, "name")))
+			.or_else(|| self.aliases.then(|| T::from_alias(s)).flatten().map(|value| (value, "alias")))
+			.filter(|candidate| self.user_assigned || !candidate.0.is_user_assigned());
+		#[cfg(feature = "tracing")]
+		if let Some(lenient) = resolution.as_ref().filter(|resolved| resolved.1 != "code" || s.len() != text.len()) {
+			debug!(input = text, resolved = ?lenient.0, via = lenient.1, "Parsed leniently");
+		}
+		resolution.map(|resolved| resolved.0)
+	}
+}
+
+
+
+//		Traits
+
+//§		Parse																	
+/// A type that can be parsed according to [`ParseOptions`].
+pub(crate) trait Parse: Debug + Sized {
+	//		from_code															
+	/// Returns the value identified by a code.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `code`             - The code.
+	/// * `case_insensitive` - Whether the code may be in any case.
+	/// 
+	fn from_code(code: &str, case_insensitive: bool) -> Option<Self>;
+	
+	//		from_quirk															
+	/// Returns the value identified by a commonly-used non-ISO code.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `code`             - The code.
+	/// * `case_insensitive` - Whether the code may be in any case.
+	/// 
+	fn from_quirk(_code: &str, _case_insensitive: bool) -> Option<Self> {
+		None
+	}
+	
+	//		from_mrz															
+	/// Returns the value identified by a special code of a machine-readable
+	/// travel document.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `code`             - The code, which may be padded with `<` fillers.
+	/// * `case_insensitive` - Whether the code may be in any case.
+	/// 
+	fn from_mrz(_code: &str, _case_insensitive: bool) -> Option<Self> {
+		None
+	}
+	
+	//		from_numeric														
+	/// Returns the value identified by a numeric code.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `numeric` - The numeric code.
+	/// 
+	fn from_numeric(_numeric: u16) -> Option<Self> {
+		None
+	}
+	
+	//		from_name															
+	/// Returns the value identified by a name.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The name.
+	/// 
+	fn from_name(name: &str) -> Option<Self>;
+	
+	//		from_alias															
+	/// Returns the value identified by an alternative name.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `alias` - The alternative name.
+	/// 
+	fn from_alias(_alias: &str) -> Option<Self> {
+		None
+	}
+	
+	//		is_user_assigned													
+	/// Returns `true` if the value is reserved for user assignment or testing.
+	fn is_user_assigned(&self) -> bool {
+		false
+	}
+}
@@ -0,0 +1,547 @@
+//! Plural rules.
+//! 
+//! This module provides the plural categories used by each language, according
+//! to the [Unicode CLDR plural rules](https://cldr.unicode.org/index/cldr-spec/plural-rules),
+//! along with an evaluator that determines the category for a given number.
+//! This is the information needed by message formatting systems in order to
+//! pick the correct form of a word, e.g. "1 file" vs "2 files".
+//! 
+//! The rules are accessed via [`Language::plural_categories()`] and
+//! [`Language::plural_category()`]. Languages for which the CLDR does not
+//! define any rules fall back to the CLDR root rules, which only have the
+//! [`Other`](PluralCategory::Other) category.
+//! 
+//! Note that only the cardinal rules for integers are supported at present.
+//! The categories returned for a language are the full set defined by the
+//! CLDR, which may include categories that only apply to decimal numbers, e.g.
+//! [`Many`](PluralCategory::Many) for Russian.
+//! 
+//! This module is only available when the `plurals` feature is enabled.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/plural.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	language::Language,
+};
+use core::fmt::{Display, self};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
+
+
+
+//		Enums
+
+//		PluralCategory															
+/// A plural category.
+/// 
+/// These are the categories defined by the Unicode CLDR, which are used to
+/// select the correct plural form of a word for a given number. Not all
+/// languages use all categories, but all languages use [`Other`](Self::Other).
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum PluralCategory {
+	/// Used for zero in some languages, e.g. Arabic and Latvian.
+	Zero,
+	
+	/// Used for one, and sometimes other numbers, in most languages.
+	One,
+	
+	/// Used for two in some languages, e.g. Arabic and Welsh.
+	Two,
+	
+	/// Used for small numbers in some languages, e.g. Czech and Polish.
+	Few,
+	
+	/// Used for large numbers in some languages, e.g. Arabic and Russian.
+	Many,
+	
+	/// Used for everything else, and present in all languages.
+	Other,
+}
+
+//󰭅		AsStr																	
+impl AsStr for PluralCategory {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Zero  => "zero",
+			Self::One   => "one",
+			Self::Two   => "two",
+			Self::Few   => "few",
+			Self::Many  => "many",
+			Self::Other => "other",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for PluralCategory {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//		PluralRule																
+/// The possible plural rules.
+/// 
+/// Each rule represents a set of CLDR plural rules shared by one or more
+/// languages. The rules are named after a representative language, or after
+/// the categories they use where they are widely shared.
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum PluralRule {
+	/// The CLDR root rules, which only have the `other` category.
+	Root,
+	
+	/// No plural distinction, e.g. Chinese and Japanese.
+	None,
+	
+	/// One for 1, e.g. English and German.
+	One,
+	
+	/// One for 0 and 1, e.g. Hindi and Armenian.
+	ZeroOne,
+	
+	/// One for 1 and many for millions, e.g. Spanish and Italian.
+	OneMany,
+	
+	/// One for 0 and 1 and many for millions, e.g. French and Portuguese.
+	ZeroOneMany,
+	
+	/// One for numbers ending in 1 except 11, e.g. Icelandic and Macedonian.
+	Icelandic,
+	
+	/// One for numbers not ending in 4, 6, or 9, i.e. Filipino/Tagalog.
+	Filipino,
+	
+	/// Latvian.
+	Latvian,
+	
+	/// Irish.
+	Irish,
+	
+	/// Scottish Gaelic.
+	ScottishGaelic,
+	
+	/// Manx.
+	Manx,
+	
+	/// Romanian.
+	Romanian,
+	
+	/// Lithuanian.
+	Lithuanian,
+	
+	/// Belarusian, Russian, and Ukrainian.
+	EastSlavic,
+	
+	/// Bosnian, Croatian, and Serbian.
+	SerboCroatian,
+	
+	/// Polish.
+	Polish,
+	
+	/// Czech and Slovak.
+	CzechSlovak,
+	
+	/// Slovenian.
+	Slovenian,
+	
+	/// Hebrew.
+	Hebrew,
+	
+	/// Arabic.
+	Arabic,
+	
+	/// Welsh.
+	Welsh,
+	
+	/// Breton.
+	Breton,
+	
+	/// Maltese.
+	Maltese,
+	
+	/// One for 1 and two for 2, e.g. Inuktitut and Northern Sami.
+	OneTwo,
+	
+	/// Cornish.
+	Cornish,
+}
+
+//󰭅		PluralRule																
+impl PluralRule {
+	//		for_language														
+	/// Returns the plural rule used by a language.
+	#[expect(clippy::match_same_arms, reason = "One arm per language is clearer")]
+	#[expect(clippy::too_many_lines,  reason = "Data not logic")]
+	pub(crate) const fn for_language(language: Language) -> Self {
+		match language {
+			Language::AA => Self::Root,
+			Language::AB => Self::Root,
+			Language::AE => Self::Root,
+			Language::AF => Self::One,
+			Language::AK => Self::ZeroOne,
+			Language::AM => Self::ZeroOne,
+			Language::AN => Self::One,
+			Language::AR => Self::Arabic,
+			Language::AS => Self::ZeroOne,
+			Language::AV => Self::Root,
+			Language::AY => Self::Root,
+			Language::AZ => Self::One,
+			Language::BA => Self::Root,
+			Language::BE => Self::EastSlavic,
+			Language::BG => Self::One,
+			Language::BI => Self::Root,
+			Language::BM => Self::None,
+			Language::BN => Self::ZeroOne,
+			Language::BO => Self::None,
+			Language::BR => Self::Breton,
+			Language::BS => Self::SerboCroatian,
+			Language::CA => Self::OneMany,
+			Language::CE => Self::One,
+			Language::CH => Self::Root,
+			Language::CO => Self::Root,
+			Language::CR => Self::Root,
+			Language::CS => Self::CzechSlovak,
+			Language::CU => Self::Root,
+			Language::CV => Self::Root,
+			Language::CY => Self::Welsh,
+			Language::DA => Self::One,
+			Language::DE => Self::One,
+			Language::DV => Self::One,
+			Language::DZ => Self::None,
+			Language::EE => Self::One,
+			Language::EL => Self::One,
+			Language::EN => Self::One,
+			Language::EO => Self::One,
+			Language::ES => Self::OneMany,
+			Language::ET => Self::One,
+			Language::EU => Self::One,
+			Language::FA => Self::ZeroOne,
+			Language::FF => Self::ZeroOne,
+			Language::FI => Self::One,
+			Language::FJ => Self::Root,
+			Language::FO => Self::One,
+			Language::FR => Self::ZeroOneMany,
+			Language::FY => Self::One,
+			Language::GA => Self::Irish,
+			Language::GD => Self::ScottishGaelic,
+			Language::GL => Self::One,
+			Language::GN => Self::Root,
+			Language::GU => Self::ZeroOne,
+			Language::GV => Self::Manx,
+			Language::HA => Self::One,
+			Language::HE => Self::Hebrew,
+			Language::HI => Self::ZeroOne,
+			Language::HO => Self::Root,
+			Language::HR => Self::SerboCroatian,
+			Language::HT => Self::Root,
+			Language::HU => Self::One,
+			Language::HY => Self::ZeroOne,
+			Language::HZ => Self::Root,
+			Language::IA => Self::One,
+			Language::ID => Self::None,
+			Language::IE => Self::Root,
+			Language::IG => Self::None,
+			Language::II => Self::None,
+			Language::IK => Self::Root,
+			Language::IO => Self::One,
+			Language::IS => Self::Icelandic,
+			Language::IT => Self::OneMany,
+			Language::IU => Self::OneTwo,
+			Language::JA => Self::None,
+			Language::JV => Self::None,
+			Language::KA => Self::One,
+			Language::KG => Self::Root,
+			Language::KI => Self::Root,
+			Language::KJ => Self::Root,
+			Language::KK => Self::One,
+			Language::KL => Self::One,
+			Language::KM => Self::None,
+			Language::KN => Self::ZeroOne,
+			Language::KO => Self::None,
+			Language::KR => Self::Root,
+			Language::KS => Self::One,
+			Language::KU => Self::One,
+			Language::KV => Self::Root,
+			Language::KW => Self::Cornish,
+			Language::KY => Self::One,
+			Language::LA => Self::Root,
+			Language::LB => Self::One,
+			Language::LG => Self::One,
+			Language::LI => Self::Root,
+			Language::LN => Self::ZeroOne,
+			Language::LO => Self::None,
+			Language::LT => Self::Lithuanian,
+			Language::LU => Self::Root,
+			Language::LV => Self::Latvian,
+			Language::MG => Self::ZeroOne,
+			Language::MH => Self::Root,
+			Language::MI => Self::Root,
+			Language::MK => Self::Icelandic,
+			Language::ML => Self::One,
+			Language::MN => Self::One,
+			Language::MR => Self::One,
+			Language::MS => Self::None,
+			Language::MT => Self::Maltese,
+			Language::MY => Self::None,
+			Language::NA => Self::Root,
+			Language::NB => Self::One,
+			Language::ND => Self::One,
+			Language::NE => Self::One,
+			Language::NG => Self::Root,
+			Language::NL => Self::One,
+			Language::NN => Self::One,
+			Language::NO => Self::One,
+			Language::NR => Self::One,
+			Language::NV => Self::Root,
+			Language::NY => Self::One,
+			Language::OC => Self::Root,
+			Language::OJ => Self::Root,
+			Language::OM => Self::One,
+			Language::OR => Self::One,
+			Language::OS => Self::One,
+			Language::PA => Self::ZeroOne,
+			Language::PI => Self::Root,
+			Language::PL => Self::Polish,
+			Language::PS => Self::One,
+			Language::PT => Self::ZeroOneMany,
+			Language::QU => Self::Root,
+			Language::RM => Self::One,
+			Language::RN => Self::Root,
+			Language::RO => Self::Romanian,
+			Language::RU => Self::EastSlavic,
+			Language::RW => Self::Root,
+			Language::SA => Self::Root,
+			Language::SC => Self::One,
+			Language::SD => Self::One,
+			Language::SE => Self::OneTwo,
+			Language::SG => Self::None,
+			Language::SI => Self::ZeroOne,
+			Language::SK => Self::CzechSlovak,
+			Language::SL => Self::Slovenian,
+			Language::SM => Self::Root,
+			Language::SN => Self::One,
+			Language::SO => Self::One,
+			Language::SQ => Self::One,
+			Language::SR => Self::SerboCroatian,
+			Language::SS => Self::One,
+			Language::ST => Self::One,
+			Language::SU => Self::None,
+			Language::SV => Self::One,
+			Language::SW => Self::One,
+			Language::TA => Self::One,
+			Language::TE => Self::One,
+			Language::TG => Self::Root,
+			Language::TH => Self::None,
+			Language::TI => Self::ZeroOne,
+			Language::TK => Self::One,
+			Language::TL => Self::Filipino,
+			Language::TN => Self::One,
+			Language::TO => Self::None,
+			Language::TR => Self::One,
+			Language::TS => Self::One,
+			Language::TT => Self::Root,
+			Language::TW => Self::Root,
+			Language::TY => Self::Root,
+			Language::UG => Self::One,
+			Language::UK => Self::EastSlavic,
+			Language::UR => Self::One,
+			Language::UZ => Self::One,
+			Language::VE => Self::One,
+			Language::VI => Self::None,
+			Language::VO => Self::One,
+			Language::WA => Self::ZeroOne,
+			Language::WO => Self::None,
+			Language::XH => Self::One,
+			Language::YI => Self::One,
+			Language::YO => Self::None,
+			Language::ZA => Self::Root,
+			Language::ZH => Self::None,
+			Language::ZU => Self::ZeroOne,
+		}
+	}
+	
+	//		categories															
+	/// Returns the plural categories used by the rule.
+	pub(crate) const fn categories(self) -> &'static [PluralCategory] {
+		use PluralCategory::{Few, Many, One, Other, Two, Zero};
+		match self {
+			Self::Root           |
+			Self::None           => &[Other],
+			Self::One            |
+			Self::ZeroOne        |
+			Self::Icelandic      |
+			Self::Filipino       => &[One, Other],
+			Self::OneMany        |
+			Self::ZeroOneMany    => &[One, Many, Other],
+			Self::Latvian        => &[Zero, One, Other],
+			Self::Romanian       |
+			Self::SerboCroatian  => &[One, Few, Other],
+			Self::Hebrew         |
+			Self::OneTwo         => &[One, Two, Other],
+			Self::Lithuanian     |
+			Self::EastSlavic     |
+			Self::Polish         |
+			Self::CzechSlovak    => &[One, Few, Many, Other],
+			Self::ScottishGaelic |
+			Self::Slovenian      => &[One, Two, Few, Other],
+			Self::Irish          |
+			Self::Manx           |
+			Self::Breton         |
+			Self::Maltese        => &[One, Two, Few, Many, Other],
+			Self::Arabic         |
+			Self::Welsh          |
+			Self::Cornish        => &[Zero, One, Two, Few, Many, Other],
+		}
+	}
+	
+	//		category															
+	/// Returns the plural category for a number.
+	#[expect(clippy::too_many_lines, reason = "Data not logic")]
+	pub(crate) const fn category(self, n: u64) -> PluralCategory {
+		use PluralCategory::{Few, Many, One, Other, Two, Zero};
+		let mod10   = n % 10;
+		let mod100  = n % 100;
+		let teen    = matches!(mod100, 11..=19);
+		let million = n != 0 && n % 1_000_000 == 0;
+		match self {
+			Self::Root | Self::None => Other,
+			Self::One               => if n == 1 { One } else { Other },
+			Self::ZeroOne           => if n <= 1 { One } else { Other },
+			Self::OneMany           => if n == 1 { One } else if million { Many } else { Other },
+			Self::ZeroOneMany       => if n <= 1 { One } else if million { Many } else { Other },
+			Self::Icelandic         => if mod10 == 1 && mod100 != 11 { One } else { Other },
+			Self::Filipino          => if matches!(mod10, 4 | 6 | 9) { Other } else { One },
+			Self::Latvian           => {
+				if mod10 == 0 || teen              { Zero }
+				else if mod10 == 1 && mod100 != 11 { One }
+				else                               { Other }
+			},
+			Self::Irish             => match n {
+				1       => One,
+				2       => Two,
+				3..=6   => Few,
+				7..=10  => Many,
+				_       => Other,
+			},
+			Self::ScottishGaelic    => match n {
+				1 | 11           => One,
+				2 | 12           => Two,
+				3..=10 | 13..=19 => Few,
+				_                => Other,
+			},
+			Self::Manx              => {
+				if mod10 == 1                                   { One }
+				else if mod10 == 2                              { Two }
+				else if matches!(mod100, 0 | 20 | 40 | 60 | 80) { Few }
+				else                                            { Other }
+			},
+			Self::Romanian          => {
+				if n == 1                                  { One }
+				else if n == 0 || matches!(mod100, 1..=19) { Few }
+				else                                       { Other }
+			},
+			Self::Lithuanian        => {
+				if mod10 == 1 && !teen                  { One }
+				else if matches!(mod10, 2..=9) && !teen { Few }
+				else                                    { Other }
+			},
+			Self::EastSlavic        => {
+				if mod10 == 1 && mod100 != 11                                { One }
+				else if matches!(mod10, 2..=4) && !matches!(mod100, 12..=14) { Few }
+				else                                                         { Many }
+			},
+			Self::SerboCroatian     => {
+				if mod10 == 1 && mod100 != 11                                { One }
+				else if matches!(mod10, 2..=4) && !matches!(mod100, 12..=14) { Few }
+				else                                                         { Other }
+			},
+			Self::Polish            => {
+				if n == 1                                                    { One }
+				else if matches!(mod10, 2..=4) && !matches!(mod100, 12..=14) { Few }
+				else                                                         { Many }
+			},
+			Self::CzechSlovak       => match n {
+				1     => One,
+				2..=4 => Few,
+				_     => Other,
+			},
+			Self::Slovenian         => match mod100 {
+				1     => One,
+				2     => Two,
+				3..=4 => Few,
+				_     => Other,
+			},
+			Self::Hebrew | Self::OneTwo => match n {
+				1 => One,
+				2 => Two,
+				_ => Other,
+			},
+			Self::Arabic            => match n {
+				0 => Zero,
+				1 => One,
+				2 => Two,
+				_ => match mod100 {
+					3..=10  => Few,
+					11..=99 => Many,
+					_       => Other,
+				},
+			},
+			Self::Welsh             => match n {
+				0 => Zero,
+				1 => One,
+				2 => Two,
+				3 => Few,
+				6 => Many,
+				_ => Other,
+			},
+			Self::Breton            => {
+				if mod10 == 1 && !matches!(mod100, 11 | 71 | 91)                                     { One }
+				else if mod10 == 2 && !matches!(mod100, 12 | 72 | 92)                                { Two }
+				else if matches!(mod10, 3 | 4 | 9) && !matches!(mod100, 10..=19 | 70..=79 | 90..=99) { Few }
+				else if million                                                                      { Many }
+				else                                                                                 { Other }
+			},
+			Self::Maltese           => {
+				if n == 1                                  { One }
+				else if n == 2                             { Two }
+				else if n == 0 || matches!(mod100, 3..=10) { Few }
+				else if teen                               { Many }
+				else                                       { Other }
+			},
+			Self::Cornish           => {
+				let thousands = n % 1_000 == 0
+					&& matches!(n % 100_000, 1_000..=20_000 | 40_000 | 60_000 | 80_000);
+				let two       = matches!(mod100, 2 | 22 | 42 | 62 | 82)
+					|| thousands
+					|| n % 1_000_000 == 100_000;
+				if n == 0                                       { Zero }
+				else if n == 1                                  { One }
+				else if two                                     { Two }
+				else if matches!(mod100, 3 | 23 | 43 | 63 | 83) { Few }
+				else if matches!(mod100, 1 | 21 | 41 | 61 | 81) { Many }
+				else                                            { Other }
+			},
+		}
+	}
+}
@@ -0,0 +1,47 @@
+//! Commonly-used items, for glob import.
+//! 
+//! This module re-exports the types and traits that most applications need, so
+//! that they can be brought into scope with a single import:
+//! 
+//! ```
+//! use isosphere::prelude::*;
+//! 
+//! assert_eq!(Country::FR.currencies(), &[ CurrencyCode::EUR ]);
+//! assert_eq!(Language::EN.code().as_str(), "en");
+//! ```
+//! 
+//! This includes the traits that provide methods on the types in this crate,
+//! such as [`AsStr`], which would otherwise need to be imported separately.
+//! 
+//! The [Serde helpers](crate::serde) are available as `iso_serde`, to avoid
+//! clashing with the `serde` crate, and so can be referred to in `with`
+//! attributes as e.g. `#[serde(with = "iso_serde::country::alpha3")]`.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/prelude.rs"]
+mod tests;
+
+
+
+//		Packages
+
+pub use crate::{
+	AsStr,
+	country::{Country, CountryCode},
+	currency::{Currency, CurrencyCode},
+	display::DisplayStyle,
+	holiday::HolidayCalendar,
+	language::{Language, LanguageCode},
+	serde::{Coded, self as iso_serde},
+	set::{CountrySet, CurrencySet, LanguageSet, Member, ParseSetError, Set},
+	table::Tabulate,
+	unlocode::{LocationDirectory, UnLocode},
+};
+
+#[cfg(feature = "schema")]
+pub use crate::schema::JsonSchema;
@@ -0,0 +1,162 @@
+//! Queries over the relationships between countries, currencies, and languages.
+//! 
+//! This module provides the [`Query`] type, which finds the countries matching
+//! a combination of criteria, such as the countries where French is an official
+//! language and the euro is used:
+//! 
+//! ```
+//! use isosphere::{Country, CurrencyCode, LanguageCode, query::Query};
+//! 
+//! let countries = Query::new().language(LanguageCode::FR).currency(CurrencyCode::EUR).run();
+//! assert!(countries.contains(Country::FR));
+//! assert!(!countries.contains(Country::CA));
+//! ```
+//! 
+//! Each criterion narrows down the countries matched, and so criteria are
+//! combined as a conjunction. Disjunctions are expressed by combining queries
+//! with [`or()`](Query::or()). Queries are evaluated as they are built, using
+//! [`CountrySet`]s, and so combining criteria is a simple bitwise operation,
+//! with no repeated scanning of the data.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/query.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	country::{Country, CountryCode, LanguageStatus, Region},
+	currency::CurrencyCode,
+	language::LanguageCode,
+	set::CountrySet,
+};
+
+
+
+//		Structs
+
+//		Query																	
+/// A query for countries matching a combination of criteria.
+/// 
+/// A new query matches all countries, and is narrowed down by adding criteria
+/// using builder-style methods. The matching countries are obtained with
+/// [`run()`](Query::run()).
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Query {
+	//		Private properties													
+	/// The countries matching the criteria so far.
+	countries: CountrySet,
+}
+
+//󰭅		Query																	
+impl Query {
+	//		new																	
+	/// Creates a new query, which matches all countries.
+	#[must_use]
+	pub fn new() -> Self {
+		Self { countries: Country::all().into_iter().collect() }
+	}
+	
+	//		language															
+	/// Restricts the query to countries where a language is official.
+	/// 
+	/// Languages with any other status, such as regional or minority languages,
+	/// do not match. For more information, see
+	/// [`Country::languages_with_status()`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `language` - The language that must be official.
+	/// 
+	#[must_use]
+	pub fn language(self, language: LanguageCode) -> Self {
+		let countries = language.language()
+			.countries()
+			.iter()
+			.map(CountryCode::country)
+			.filter(|country| country.languages_by_status(LanguageStatus::Official).contains(&language))
+			.collect();
+		self.matching(countries)
+	}
+	
+	//		currency															
+	/// Restricts the query to countries where a currency is used.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `currency` - The currency that must be used.
+	/// 
+	#[must_use]
+	pub fn currency(self, currency: CurrencyCode) -> Self {
+		self.matching(currency.currency().countries().iter().map(CountryCode::country).collect())
+	}
+	
+	//		region																
+	/// Restricts the query to countries in a region.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `region` - The region that the countries must be in.
+	/// 
+	#[must_use]
+	pub fn region(self, region: Region) -> Self {
+		self.matching(region.countries().into_iter().collect())
+	}
+	
+	//		or																	
+	/// Combines the query with another, matching countries that match either.
+	/// 
+	/// Criteria added after this apply to the combined query, so e.g. the
+	/// countries where French or German is official and the euro is used can
+	/// be found with:
+	/// 
+	/// ```
+	/// use isosphere::{Country, CurrencyCode, LanguageCode, query::Query};
+	/// 
+	/// let countries = Query::new()
+	///     .language(LanguageCode::FR)
+	///     .or(Query::new().language(LanguageCode::DE))
+	///     .currency(CurrencyCode::EUR)
+	///     .run();
+	/// assert!(countries.contains(Country::DE));
+	/// assert!(!countries.contains(Country::CH));
+	/// ```
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The query to combine with.
+	/// 
+	#[must_use]
+	pub fn or(self, other: Self) -> Self {
+		Self { countries: self.countries | other.countries }
+	}
+	
+	//		run																	
+	/// Returns the countries matching the query.
+	#[must_use]
+	pub const fn run(&self) -> CountrySet {
+		self.countries
+	}
+	
+	//		matching															
+	/// Restricts the query to the countries in a set.
+	fn matching(self, countries: CountrySet) -> Self {
+		Self { countries: self.countries & countries }
+	}
+}
+
+//󰭅		Default																	
+impl Default for Query {
+	//		default																
+	fn default() -> Self {
+		Self::new()
+	}
+}
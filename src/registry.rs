@@ -0,0 +1,461 @@
+//! Runtime overrides of the built-in data.
+//! 
+//! This module provides the [`Registry`] type, which holds deployment-specific
+//! overrides of the data provided by this crate, such as the display names of
+//! countries. Some products need to show certain names differently depending on
+//! where they are deployed, and the registry allows this to be done without
+//! forking the data.
+//! 
+//! A registry is built at startup and then installed globally, once, using
+//! [`Registry::install()`]. After that, the overrides take effect everywhere,
+//! e.g. in [`Country::name()`] and the [`Display`](core::fmt::Display)
+//! implementation of [`Country`]. The global registry cannot be changed or
+//! removed once installed, which keeps lookups thread-safe and lock-free.
+//! 
+//! Overridden names are accepted when parsing, in addition to the original
+//! names, so that values serialised with an override in place can still be
+//! deserialised.
+//! 
+//! The registry also serves as an extension point for attaching additional,
+//! application-specific information to countries, in the form of typed tags,
+//! such as membership of a sanctions list or a shipping zone. This keeps the
+//! core data limited to ISO standards, whilst saving the need to maintain a
+//! parallel map keyed by [`Country`].
+//! 
+//! Before a registry is installed, it can be compared with another, such as
+//! the one currently installed, using [`Registry::diff()`], so that the effect
+//! of loading overrides from external data can be audited first.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/registry.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	country::Country,
+	currency::{Currency, CurrencyClass},
+	set::CountrySet,
+};
+use core::{
+	any::{Any, TypeId, type_name},
+	hash::Hash,
+};
+use std::{
+	collections::{HashMap, HashSet},
+	sync::{Arc, OnceLock},
+};
+
+
+
+//		Constants
+
+/// The globally-installed registry.
+static REGISTRY: OnceLock<Registry> = OnceLock::new();
+
+
+
+//		Type aliases
+
+/// A function comparing the values of two tags of the same type.
+type TagComparator = fn(&(dyn Any + Send + Sync), &(dyn Any + Send + Sync)) -> bool;
+
+
+
+//		Structs
+
+//		Registry																
+/// A set of overrides of the built-in data.
+/// 
+/// The registry is configured using builder-style methods, and then installed
+/// globally with [`install()`](Registry::install()).
+/// 
+/// Registries are equal when they have the same overridden names and
+/// classifications, and the same tags attached to the same countries, with
+/// tag values compared by their [`PartialEq`] implementations.
+/// 
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+	//		Private properties													
+	/// The display names of countries, overriding the built-in names.
+	country_names:    HashMap<Country, String>,
+	
+	/// The tags attached to countries, by tag type and then country.
+	country_tags:     HashMap<TypeId, HashMap<Country, Arc<dyn Any + Send + Sync>>>,
+	
+	/// The names of the tag types, for reporting.
+	tag_names:        HashMap<TypeId, &'static str>,
+	
+	/// The functions comparing the values of tags, by tag type.
+	tag_comparators:  HashMap<TypeId, TagComparator>,
+	
+	/// The classifications of currencies, overriding the built-in defaults.
+	currency_classes: HashMap<Currency, CurrencyClass>,
+}
+
+//󰭅		Registry																
+impl Registry {
+	//		new																	
+	/// Creates a new, empty registry.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+	
+	//		global																
+	/// Returns the globally-installed registry, if there is one.
+	#[must_use]
+	pub fn global() -> Option<&'static Self> {
+		REGISTRY.get()
+	}
+	
+	//		install																
+	/// Installs the registry globally.
+	/// 
+	/// This should be called once, at startup, before any lookups are made.
+	/// The installed registry cannot be replaced.
+	/// 
+	/// # Errors
+	/// 
+	/// If a registry has already been installed, the registry passed in is
+	/// returned unchanged as the error.
+	/// 
+	#[expect(clippy::result_large_err, reason = "The registry is returned unchanged, and only once")]
+	pub fn install(self) -> Result<(), Self> {
+		REGISTRY.set(self)
+	}
+	
+	//		with_country_name													
+	/// Overrides the display name of a country.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `country` - The country to override the name of.
+	/// * `name`    - The name to use instead of the built-in name.
+	/// 
+	#[must_use]
+	pub fn with_country_name<S: Into<String>>(mut self, country: Country, name: S) -> Self {
+		drop(self.country_names.insert(country, name.into()));
+		self
+	}
+	
+	//		country_name														
+	/// Returns the overridden display name of a country, if there is one.
+	#[must_use]
+	pub fn country_name(&self, country: Country) -> Option<&str> {
+		self.country_names.get(&country).map(String::as_str)
+	}
+	
+	//		country_by_name														
+	/// Returns the country with the given overridden display name, if there is
+	/// one.
+	#[must_use]
+	pub fn country_by_name(&self, name: &str) -> Option<Country> {
+		self.country_names.iter().find(|&(_, value)| value == name).map(|(&country, _)| country)
+	}
+	
+	//		with_country_tag													
+	/// Attaches a tag to a country.
+	/// 
+	/// Tags are keyed by type, so a country can have one tag of each type, and
+	/// attaching a second tag of the same type replaces the first. Any type can
+	/// be used as a tag, e.g. a unit struct as a marker for membership of a
+	/// list, or a struct or enum carrying a value, as long as it implements
+	/// [`PartialEq`], so that registries can be compared.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `country` - The country to attach the tag to.
+	/// * `tag`     - The tag to attach.
+	/// 
+	#[must_use]
+	pub fn with_country_tag<T: Any + PartialEq + Send + Sync>(mut self, country: Country, tag: T) -> Self {
+		drop(self.country_tags.entry(TypeId::of::<T>()).or_default().insert(country, Arc::new(tag)));
+		_ = self.tag_names.insert(TypeId::of::<T>(), type_name::<T>());
+		_ = self.tag_comparators.insert(TypeId::of::<T>(), tag_eq::<T>);
+		self
+	}
+	
+	//		country_tag															
+	/// Returns the tag of a given type attached to a country, if there is one.
+	#[must_use]
+	pub fn country_tag<T: Any + Send + Sync>(&self, country: Country) -> Option<&T> {
+		self.country_tags
+			.get(&TypeId::of::<T>())
+			.and_then(|tags| tags.get(&country))
+			.and_then(|tag| tag.downcast_ref())
+	}
+	
+	//		countries_tagged													
+	/// Returns the countries that have a tag of a given type attached.
+	#[must_use]
+	pub fn countries_tagged<T: Any + Send + Sync>(&self) -> CountrySet {
+		self.countries_tagged_with(TypeId::of::<T>())
+	}
+	
+	//		with_currency_class													
+	/// Overrides the classification of a currency.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `currency` - The currency to override the classification of.
+	/// * `class`    - The classification to use instead of the default.
+	/// 
+	#[must_use]
+	pub fn with_currency_class(mut self, currency: Currency, class: CurrencyClass) -> Self {
+		_ = self.currency_classes.insert(currency, class);
+		self
+	}
+	
+	//		currency_class														
+	/// Returns the overridden classification of a currency, if there is one.
+	#[must_use]
+	pub fn currency_class(&self, currency: Currency) -> Option<CurrencyClass> {
+		self.currency_classes.get(&currency).copied()
+	}
+	
+	//		diff																
+	/// Compares the registry with another, and returns the differences.
+	/// 
+	/// The registry is treated as the existing state, and the other as the
+	/// proposed state, so that e.g. an overridden name present only in the other
+	/// registry is reported as added. This allows the effect of loading
+	/// overrides from external data to be audited before they are installed,
+	/// typically by comparing against the [`global()`](Registry::global())
+	/// registry, or a new one if none is installed.
+	/// 
+	/// Tags are compared by the countries they are attached to, and so a change
+	/// to the value of a tag alone is not reported, unlike by the [`PartialEq`]
+	/// implementation.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The registry to compare with.
+	/// 
+	#[must_use]
+	pub fn diff(&self, other: &Self) -> RegistryDiff {
+		let tag_types: HashSet<_> = self.country_tags.keys().chain(other.country_tags.keys()).collect();
+		let mut country_tags: Vec<_> = tag_types
+			.into_iter()
+			.map(|type_id| {
+				let before = self.countries_tagged_with(*type_id);
+				let after  = other.countries_tagged_with(*type_id);
+				TagChanges {
+					tag:     self.tag_names.get(type_id).or_else(|| other.tag_names.get(type_id)).copied().unwrap_or_default(),
+					added:   after.difference(&before),
+					removed: before.difference(&after),
+				}
+			})
+			.filter(|changes| !changes.added.is_empty() || !changes.removed.is_empty())
+			.collect();
+		country_tags.sort_unstable_by_key(|changes| changes.tag);
+		RegistryDiff {
+			country_names:    changes(&self.country_names,    &other.country_names),
+			country_tags,
+			currency_classes: changes(&self.currency_classes, &other.currency_classes),
+		}
+	}
+	
+	//		countries_tagged_with												
+	/// Returns the countries that have a tag of a given type attached.
+	fn countries_tagged_with(&self, type_id: TypeId) -> CountrySet {
+		self.country_tags
+			.get(&type_id)
+			.map(|tags| tags.keys().copied().collect())
+			.unwrap_or_default()
+	}
+}
+
+//󰭅		Eq																		
+impl Eq for Registry {}
+
+//󰭅		PartialEq																
+impl PartialEq for Registry {
+	//		eq																	
+	fn eq(&self, other: &Self) -> bool {
+		   self.country_names      == other.country_names
+		&& self.currency_classes   == other.currency_classes
+		&& self.country_tags.len() == other.country_tags.len()
+		&& self.country_tags.iter().all(|(type_id, tags)| {
+			let Some(eq) = self.tag_comparators.get(type_id) else { return false };
+			other.country_tags.get(type_id).is_some_and(|other_tags| {
+				tags.len() == other_tags.len() && tags.iter().all(|(country, tag)| {
+					other_tags.get(country).is_some_and(|other_tag| eq(&**tag, &**other_tag))
+				})
+			})
+		})
+	}
+}
+
+//		RegistryDiff															
+/// The differences between two registries.
+/// 
+/// This is returned by [`Registry::diff()`], and describes the changes that
+/// would be made by replacing one registry with the other.
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RegistryDiff {
+	//		Public properties													
+	/// The changes to the display names of countries.
+	pub country_names:    Changes<Country, String>,
+	
+	/// The changes to the tags attached to countries, by tag type, sorted by
+	/// the name of the type. Tag types with no changes are not included.
+	pub country_tags:     Vec<TagChanges>,
+	
+	/// The changes to the classifications of currencies.
+	pub currency_classes: Changes<Currency, CurrencyClass>,
+}
+
+//󰭅		RegistryDiff															
+impl RegistryDiff {
+	//		is_empty															
+	/// Returns `true` if there are no differences.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.country_names.is_empty() && self.country_tags.is_empty() && self.currency_classes.is_empty()
+	}
+}
+
+//		Changes																	
+/// The changes to a set of overrides.
+/// 
+/// Each list is sorted by key.
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Changes<K, V> {
+	//		Public properties													
+	/// The overrides that have been added, with their values.
+	pub added:   Vec<(K, V)>,
+	
+	/// The overrides that have been removed, with their previous values.
+	pub removed: Vec<(K, V)>,
+	
+	/// The overrides whose values have changed, with the previous and new
+	/// values, e.g. countries that have been renamed.
+	pub changed: Vec<(K, V, V)>,
+}
+
+//󰭅		Changes																	
+impl<K, V> Changes<K, V> {
+	//		is_empty															
+	/// Returns `true` if there are no changes.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+	}
+}
+
+//		TagChanges																
+/// The changes to the countries that a type of tag is attached to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct TagChanges {
+	//		Public properties													
+	/// The name of the type of tag, as given by [`type_name()`].
+	pub tag:     &'static str,
+	
+	/// The countries that the tag has been attached to.
+	pub added:   CountrySet,
+	
+	/// The countries that the tag has been removed from.
+	pub removed: CountrySet,
+}
+
+
+
+//		Functions
+
+//		country_name															
+/// Returns the overridden display name of a country from the global registry.
+pub(crate) fn country_name(country: Country) -> Option<&'static str> {
+	Registry::global().and_then(|registry| registry.country_name(country))
+}
+
+//		country_by_name															
+/// Returns the country with the given overridden display name from the global
+/// registry.
+pub(crate) fn country_by_name(name: &str) -> Option<Country> {
+	Registry::global().and_then(|registry| registry.country_by_name(name))
+}
+
+//		country_by_name_with													
+/// Returns the country with the given overridden display name from the global
+/// registry, comparing names after normalisation.
+/// 
+/// The name is expected to have been normalised already, using the same
+/// function that is passed in to be applied to the overridden names.
+/// 
+pub(crate) fn country_by_name_with<F: Fn(&str) -> String>(name: &str, normalize: F) -> Option<Country> {
+	Registry::global().and_then(|registry| {
+		registry.country_names.iter().find(|&(_, value)| normalize(value) == name).map(|(&country, _)| country)
+	})
+}
+
+//		country_tag																
+/// Returns the tag of a given type attached to a country in the global
+/// registry.
+pub(crate) fn country_tag<T: Any + Send + Sync>(country: Country) -> Option<&'static T> {
+	Registry::global().and_then(|registry| registry.country_tag(country))
+}
+
+//		currency_class															
+/// Returns the overridden classification of a currency from the global
+/// registry.
+pub(crate) fn currency_class(currency: Currency) -> Option<CurrencyClass> {
+	Registry::global().and_then(|registry| registry.currency_class(currency))
+}
+
+//		tag_eq																	
+/// Compares the values of two tags of the same type.
+/// 
+/// # Parameters
+/// 
+/// * `left`  - The first tag.
+/// * `right` - The second tag.
+/// 
+fn tag_eq<T: Any + PartialEq>(left: &(dyn Any + Send + Sync), right: &(dyn Any + Send + Sync)) -> bool {
+	left.downcast_ref::<T>().zip(right.downcast_ref::<T>()).is_some_and(|(left_tag, right_tag)| left_tag == right_tag)
+}
+
+//		changes																	
+/// Returns the changes between two maps of overrides.
+/// 
+/// # Parameters
+/// 
+/// * `before` - The existing overrides.
+/// * `after`  - The proposed overrides.
+/// 
+fn changes<K, V>(before: &HashMap<K, V>, after: &HashMap<K, V>) -> Changes<K, V>
+where
+	K: Copy + Eq + Hash + Ord,
+	V: Clone + PartialEq,
+{
+	let mut added: Vec<_> = after
+		.iter()
+		.filter(|&(key, _)| !before.contains_key(key))
+		.map(|(&key, value)| (key, value.clone()))
+		.collect();
+	let mut removed: Vec<_> = before
+		.iter()
+		.filter(|&(key, _)| !after.contains_key(key))
+		.map(|(&key, value)| (key, value.clone()))
+		.collect();
+	let mut changed: Vec<_> = before
+		.iter()
+		.filter_map(|(&key, old)| after.get(&key).filter(|&new| new != old).map(|new| (key, old.clone(), new.clone())))
+		.collect();
+	added.sort_unstable_by_key(|entry| entry.0);
+	removed.sort_unstable_by_key(|entry| entry.0);
+	changed.sort_unstable_by_key(|entry| entry.0);
+	Changes { added, removed, changed }
+}
@@ -0,0 +1,201 @@
+//! JSON Schema documents for the datasets.
+//! 
+//! This module provides JSON Schema documents describing the serialised forms
+//! of the types in this crate, listing exactly the values in the embedded
+//! dataset. This allows non-Rust consumers of APIs built with this crate to
+//! validate payloads against the same data.
+//! 
+//! The schema for a single type is obtained with [`json_schema_for()`], e.g.
+//! `json_schema_for::<Country>()`, and the schemas for all types are available
+//! from [`json_schemas()`], keyed by type name. The documents follow the JSON
+//! Schema 2020-12 specification.
+//! 
+//! The primary types serialise as names, and so their schemas are an `enum` of
+//! names. The code types serialise as codes, and so their schemas list each
+//! code as a `const`, with the corresponding name as its `title`. For country
+//! codes, both the two-letter and three-letter forms are included, as both are
+//! valid.
+//! 
+//! This module is only available when the `schema` feature is enabled.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/schema.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	country::{Country, CountryCode},
+	currency::{Currency, CurrencyCode},
+	language::{Language, LanguageCode},
+};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+
+
+
+//		Constants
+
+/// The URI of the JSON Schema specification that the documents follow.
+const SPECIFICATION: &str = "https://json-schema.org/draft/2020-12/schema";
+
+
+
+//		Traits
+
+//§		JsonSchema																
+/// A type that has a JSON Schema document.
+/// 
+/// This trait is implemented for [`Country`], [`CountryCode`], [`Currency`],
+/// [`CurrencyCode`], [`Language`], and [`LanguageCode`], and cannot be
+/// implemented outside of this crate.
+/// 
+pub trait JsonSchema: private::Sealed {
+	/// The name of the type, used as the title of the schema.
+	const NAME: &'static str;
+	
+	/// A description of the type, used as the description of the schema.
+	const DESCRIPTION: &'static str;
+	
+	//		values																
+	/// Returns the valid values, each with an optional title.
+	fn values() -> Vec<(String, Option<String>)>;
+}
+
+//󰭅		JsonSchema for Country													
+impl JsonSchema for Country {
+	const NAME:        &'static str = "Country";
+	const DESCRIPTION: &'static str = "The name of a country, as defined by ISO 3166-1.";
+	
+	//		values																
+	fn values() -> Vec<(String, Option<String>)> {
+		Self::all().into_iter().map(|country| (country.short_name().to_owned(), None)).collect()
+	}
+}
+
+//󰭅		JsonSchema for CountryCode												
+impl JsonSchema for CountryCode {
+	const NAME:        &'static str = "CountryCode";
+	const DESCRIPTION: &'static str = "A two-letter or three-letter country code, as defined by ISO 3166-1.";
+	
+	//		values																
+	fn values() -> Vec<(String, Option<String>)> {
+		let alpha2 = Self::all();
+		let alpha3 = alpha2.iter().map(Self::to_alpha3).collect::<Vec<_>>();
+		alpha2.into_iter()
+			.chain(alpha3)
+			.map(|code| (code.to_string(), Some(code.country().short_name().to_owned())))
+			.collect()
+	}
+}
+
+//󰭅		JsonSchema for Currency													
+impl JsonSchema for Currency {
+	const NAME:        &'static str = "Currency";
+	const DESCRIPTION: &'static str = "The name of a currency, as defined by ISO 4217.";
+	
+	//		values																
+	fn values() -> Vec<(String, Option<String>)> {
+		Self::all().into_iter().map(|currency| (currency.name().to_owned(), None)).collect()
+	}
+}
+
+//󰭅		JsonSchema for CurrencyCode												
+impl JsonSchema for CurrencyCode {
+	const NAME:        &'static str = "CurrencyCode";
+	const DESCRIPTION: &'static str = "A three-letter currency code, as defined by ISO 4217.";
+	
+	//		values																
+	fn values() -> Vec<(String, Option<String>)> {
+		Self::all().into_iter().map(|code| (code.to_string(), Some(code.currency().name().to_owned()))).collect()
+	}
+}
+
+//󰭅		JsonSchema for Language													
+impl JsonSchema for Language {
+	const NAME:        &'static str = "Language";
+	const DESCRIPTION: &'static str = "The name of a language, as defined by ISO 639-1.";
+	
+	//		values																
+	fn values() -> Vec<(String, Option<String>)> {
+		Self::all().into_iter().map(|language| (language.name().to_owned(), None)).collect()
+	}
+}
+
+//󰭅		JsonSchema for LanguageCode												
+impl JsonSchema for LanguageCode {
+	const NAME:        &'static str = "LanguageCode";
+	const DESCRIPTION: &'static str = "A two-letter language code, as defined by ISO 639-1.";
+	
+	//		values																
+	fn values() -> Vec<(String, Option<String>)> {
+		Self::all().into_iter().map(|code| (code.to_string(), Some(code.language().name().to_owned()))).collect()
+	}
+}
+
+
+
+//		Functions
+
+//		json_schema_for															
+/// Returns the JSON Schema document for a type.
+/// 
+/// The document lists the values in the embedded dataset. For the primary
+/// types, these are the names as defined by the standards, and not any names
+/// overridden by a [`Registry`](crate::registry::Registry).
+/// 
+#[must_use]
+pub fn json_schema_for<T: JsonSchema>() -> Value {
+	let values = T::values();
+	let (key, list): (&str, Value) = if values.iter().all(|value| value.1.is_none()) {
+		("enum",  values.into_iter().map(|(value, _)| value).collect())
+	} else {
+		("oneOf", values.into_iter().map(|(value, title)| json!({ "const": value, "title": title })).collect())
+	};
+	json!({
+		"$schema":     SPECIFICATION,
+		"title":       T::NAME,
+		"description": T::DESCRIPTION,
+		"type":        "string",
+		key:           list,
+	})
+}
+
+//		json_schemas															
+/// Returns the JSON Schema documents for all types, keyed by type name.
+#[must_use]
+pub fn json_schemas() -> BTreeMap<&'static str, Value> {
+	BTreeMap::from([
+		(Country::NAME,      json_schema_for::<Country>()),
+		(CountryCode::NAME,  json_schema_for::<CountryCode>()),
+		(Currency::NAME,     json_schema_for::<Currency>()),
+		(CurrencyCode::NAME, json_schema_for::<CurrencyCode>()),
+		(Language::NAME,     json_schema_for::<Language>()),
+		(LanguageCode::NAME, json_schema_for::<LanguageCode>()),
+	])
+}
+
+
+
+//		Private
+
+/// Sealing of the [`JsonSchema`] trait.
+mod private {
+	/// Prevents [`JsonSchema`](super::JsonSchema) from being implemented
+	/// elsewhere.
+	pub trait Sealed {}
+	
+	impl Sealed for super::Country {}
+	impl Sealed for super::CountryCode {}
+	impl Sealed for super::Currency {}
+	impl Sealed for super::CurrencyCode {}
+	impl Sealed for super::Language {}
+	impl Sealed for super::LanguageCode {}
+}
@@ -0,0 +1,411 @@
+//! Writing systems.
+//! 
+//! This module provides the [`Script`] enum, which represents a writing system
+//! as defined by ISO 15924, identified by its four-letter code, e.g. `Latn` for
+//! Latin, or `Cyrl` for Cyrillic. The scripts used by each language are
+//! available via [`Language::scripts()`](crate::language::Language::scripts()),
+//! so that rendering layers can choose fonts for a language.
+//! 
+//! Only the scripts that are used to write the languages in this crate are
+//! included, along with the scripts that they combine, such as Hiragana and
+//! Katakana for `Jpan`, rather than the full list of ISO 15924 codes.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/script.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	language::Language,
+};
+use core::{
+	fmt::{Display, self},
+	str::FromStr,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
+
+
+
+//		Enums
+
+//		Script																	
+/// A writing system.
+/// 
+/// The scripts are identified by their ISO 15924 codes, which are used as the
+/// names of the variants, and for (de)serialisation.
+/// 
+/// # Data sources
+/// 
+/// The codes and numbers are available from
+/// [the ISO 15924 Registration Authority](https://www.unicode.org/iso15924/).
+/// 
+/// # See also
+/// 
+/// * [`Language::scripts()`](crate::language::Language::scripts())
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[non_exhaustive]
+pub enum Script {
+	/// Adlam.
+	Adlm,
+	
+	/// Arabic.
+	Arab,
+	
+	/// Armenian.
+	Armn,
+	
+	/// Avestan.
+	Avst,
+	
+	/// Bengali.
+	Beng,
+	
+	/// Unified Canadian Aboriginal Syllabics.
+	Cans,
+	
+	/// Cyrillic.
+	Cyrl,
+	
+	/// Devanagari.
+	Deva,
+	
+	/// Ethiopic.
+	Ethi,
+	
+	/// Georgian.
+	Geor,
+	
+	/// Glagolitic.
+	Glag,
+	
+	/// Greek.
+	Grek,
+	
+	/// Gujarati.
+	Gujr,
+	
+	/// Gurmukhi.
+	Guru,
+	
+	/// Hangul.
+	Hang,
+	
+	/// Han.
+	Hani,
+	
+	/// Han (Simplified).
+	Hans,
+	
+	/// Han (Traditional).
+	Hant,
+	
+	/// Hebrew.
+	Hebr,
+	
+	/// Hiragana.
+	Hira,
+	
+	/// Javanese.
+	Java,
+	
+	/// Japanese, i.e. Han, Hiragana, and Katakana combined.
+	Jpan,
+	
+	/// Katakana.
+	Kana,
+	
+	/// Khmer.
+	Khmr,
+	
+	/// Kannada.
+	Knda,
+	
+	/// Korean, i.e. Hangul and Han combined.
+	Kore,
+	
+	/// Lao.
+	Laoo,
+	
+	/// Latin.
+	Latn,
+	
+	/// Malayalam.
+	Mlym,
+	
+	/// Mongolian.
+	Mong,
+	
+	/// Myanmar.
+	Mymr,
+	
+	/// Oriya.
+	Orya,
+	
+	/// Sinhala.
+	Sinh,
+	
+	/// Sundanese.
+	Sund,
+	
+	/// Tamil.
+	Taml,
+	
+	/// Telugu.
+	Telu,
+	
+	/// Thaana.
+	Thaa,
+	
+	/// Thai.
+	Thai,
+	
+	/// Tibetan.
+	Tibt,
+	
+	/// Yi.
+	Yiii,
+}
+
+//󰭅		Script																	
+impl Script {
+	//		all																	
+	/// Returns all the scripts.
+	#[must_use]
+	pub fn all() -> Vec<Self> {
+		vec![
+			Self::Adlm,
+			Self::Arab,
+			Self::Armn,
+			Self::Avst,
+			Self::Beng,
+			Self::Cans,
+			Self::Cyrl,
+			Self::Deva,
+			Self::Ethi,
+			Self::Geor,
+			Self::Glag,
+			Self::Grek,
+			Self::Gujr,
+			Self::Guru,
+			Self::Hang,
+			Self::Hani,
+			Self::Hans,
+			Self::Hant,
+			Self::Hebr,
+			Self::Hira,
+			Self::Java,
+			Self::Jpan,
+			Self::Kana,
+			Self::Khmr,
+			Self::Knda,
+			Self::Kore,
+			Self::Laoo,
+			Self::Latn,
+			Self::Mlym,
+			Self::Mong,
+			Self::Mymr,
+			Self::Orya,
+			Self::Sinh,
+			Self::Sund,
+			Self::Taml,
+			Self::Telu,
+			Self::Thaa,
+			Self::Thai,
+			Self::Tibt,
+			Self::Yiii,
+		]
+	}
+	
+	//		name																
+	/// Returns the English name of the script, e.g. `Latin`.
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		match *self {
+			Self::Adlm => "Adlam",
+			Self::Arab => "Arabic",
+			Self::Armn => "Armenian",
+			Self::Avst => "Avestan",
+			Self::Beng => "Bengali",
+			Self::Cans => "Unified Canadian Aboriginal Syllabics",
+			Self::Cyrl => "Cyrillic",
+			Self::Deva => "Devanagari",
+			Self::Ethi => "Ethiopic",
+			Self::Geor => "Georgian",
+			Self::Glag => "Glagolitic",
+			Self::Grek => "Greek",
+			Self::Gujr => "Gujarati",
+			Self::Guru => "Gurmukhi",
+			Self::Hang => "Hangul",
+			Self::Hani => "Han",
+			Self::Hans => "Han (Simplified)",
+			Self::Hant => "Han (Traditional)",
+			Self::Hebr => "Hebrew",
+			Self::Hira => "Hiragana",
+			Self::Java => "Javanese",
+			Self::Jpan => "Japanese",
+			Self::Kana => "Katakana",
+			Self::Khmr => "Khmer",
+			Self::Knda => "Kannada",
+			Self::Kore => "Korean",
+			Self::Laoo => "Lao",
+			Self::Latn => "Latin",
+			Self::Mlym => "Malayalam",
+			Self::Mong => "Mongolian",
+			Self::Mymr => "Myanmar",
+			Self::Orya => "Oriya",
+			Self::Sinh => "Sinhala",
+			Self::Sund => "Sundanese",
+			Self::Taml => "Tamil",
+			Self::Telu => "Telugu",
+			Self::Thaa => "Thaana",
+			Self::Thai => "Thai",
+			Self::Tibt => "Tibetan",
+			Self::Yiii => "Yi",
+		}
+	}
+	
+	//		numeric																
+	/// Returns the ISO 15924 numeric code of the script, e.g. `215` for Latin.
+	#[must_use]
+	pub const fn numeric(&self) -> u16 {
+		match *self {
+			Self::Adlm => 166,
+			Self::Arab => 160,
+			Self::Armn => 230,
+			Self::Avst => 134,
+			Self::Beng => 325,
+			Self::Cans => 440,
+			Self::Cyrl => 220,
+			Self::Deva => 315,
+			Self::Ethi => 430,
+			Self::Geor => 240,
+			Self::Glag => 225,
+			Self::Grek => 200,
+			Self::Gujr => 320,
+			Self::Guru => 310,
+			Self::Hang => 286,
+			Self::Hani => 500,
+			Self::Hans => 501,
+			Self::Hant => 502,
+			Self::Hebr => 125,
+			Self::Hira => 410,
+			Self::Java => 361,
+			Self::Jpan => 413,
+			Self::Kana => 411,
+			Self::Khmr => 355,
+			Self::Knda => 345,
+			Self::Kore => 287,
+			Self::Laoo => 356,
+			Self::Latn => 215,
+			Self::Mlym => 347,
+			Self::Mong => 145,
+			Self::Mymr => 350,
+			Self::Orya => 327,
+			Self::Sinh => 348,
+			Self::Sund => 362,
+			Self::Taml => 346,
+			Self::Telu => 340,
+			Self::Thaa => 170,
+			Self::Thai => 352,
+			Self::Tibt => 330,
+			Self::Yiii => 460,
+		}
+	}
+	
+	//		languages															
+	/// Returns the languages that are written in the script.
+	/// 
+	/// This includes the languages for which the script is not the primary
+	/// script, e.g. Serbian is written in both Cyrillic and Latin, and so is
+	/// returned for both.
+	/// 
+	#[must_use]
+	pub fn languages(&self) -> Vec<Language> {
+		Language::all().into_iter().filter(|language| language.scripts().contains(self)).collect()
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for Script {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Adlm => "Adlm",
+			Self::Arab => "Arab",
+			Self::Armn => "Armn",
+			Self::Avst => "Avst",
+			Self::Beng => "Beng",
+			Self::Cans => "Cans",
+			Self::Cyrl => "Cyrl",
+			Self::Deva => "Deva",
+			Self::Ethi => "Ethi",
+			Self::Geor => "Geor",
+			Self::Glag => "Glag",
+			Self::Grek => "Grek",
+			Self::Gujr => "Gujr",
+			Self::Guru => "Guru",
+			Self::Hang => "Hang",
+			Self::Hani => "Hani",
+			Self::Hans => "Hans",
+			Self::Hant => "Hant",
+			Self::Hebr => "Hebr",
+			Self::Hira => "Hira",
+			Self::Java => "Java",
+			Self::Jpan => "Jpan",
+			Self::Kana => "Kana",
+			Self::Khmr => "Khmr",
+			Self::Knda => "Knda",
+			Self::Kore => "Kore",
+			Self::Laoo => "Laoo",
+			Self::Latn => "Latn",
+			Self::Mlym => "Mlym",
+			Self::Mong => "Mong",
+			Self::Mymr => "Mymr",
+			Self::Orya => "Orya",
+			Self::Sinh => "Sinh",
+			Self::Sund => "Sund",
+			Self::Taml => "Taml",
+			Self::Telu => "Telu",
+			Self::Thaa => "Thaa",
+			Self::Thai => "Thai",
+			Self::Tibt => "Tibt",
+			Self::Yiii => "Yiii",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for Script {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for Script {
+	type Err = String;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let trimmed = s.trim();
+		Self::all()
+			.into_iter()
+			.find(|script| script.as_str().eq_ignore_ascii_case(trimmed))
+			.ok_or_else(|| format!("Invalid Script: {s}"))
+	}
+}
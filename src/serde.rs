@@ -0,0 +1,745 @@
+//! Serde helpers for fields holding codes.
+//! 
+//! By default, the primary types are (de)serialised as their names, and the
+//! code types as their codes in whichever form they hold. In compact formats,
+//! such as `bincode`, the country and currency codes are (de)serialised as
+//! their numeric codes instead, as decided by the format. This module provides
+//! ready-made modules for use with Serde's `with` attribute, which allow a
+//! field to be (de)serialised in a specific code form instead, regardless of
+//! whether it holds the primary type or the code type. For example, a field
+//! annotated with `#[serde(with = "isosphere::serde::country::alpha3")]` will
+//! be (de)serialised as a three-letter country code.
+//! 
+//! Each module has `option` and `vec` submodules, for fields holding an
+//! [`Option`] or a [`Vec`] respectively, and a `map` submodule, for fields
+//! holding a map keyed by values, such as a [`HashMap`] or a
+//! [`BTreeMap`](std::collections::BTreeMap). Map keys are always
+//! (de)serialised as strings, e.g. `"826"` for a numeric country code, so that
+//! maps work with formats that only allow string keys, such as JSON and TOML,
+//! as well as with compact formats. Serialisation always produces the chosen
+//! form. Deserialisation of the string forms accepts any valid code, so e.g. a
+//! field using `alpha3` will also accept two-letter country codes, and convert
+//! them.
+//! 
+//! The available modules are:
+//! 
+//!   - [`country::alpha2`]
+//!   - [`country::alpha3`]
+//!   - [`country::numeric`]
+//!   - [`currency::alpha3`]
+//!   - [`currency::numeric`]
+//!   - [`language::alpha2`]
+//! 
+//! It also provides the [`CodeOrName`] enum, for fields that may hold either a
+//! code or a name, such as in API request models, the [`empty_as_none`]
+//! module, for optional fields in data exported from spreadsheets and other
+//! systems, which often leave fields empty rather than omitting them, and the
+//! [`sorted`] module, for sets that need to be serialised deterministically.
+//! The [`Maybe`] enum, and its [`MaybeCountry`], [`MaybeCurrency`], and
+//! [`MaybeLanguage`] aliases, hold codes that may not be recognised, for
+//! services that must pass data through without dropping it.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/serde.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	country::{Country, CountryCode},
+	currency::{Currency, CurrencyCode},
+	language::{Language, LanguageCode},
+};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::{
+	hash::{BuildHasher, Hash},
+	str::FromStr,
+};
+use std::collections::{HashMap, HashSet};
+
+
+
+//		Type aliases
+
+/// A country code that may not be recognised.
+pub type MaybeCountry = Maybe<Country>;
+
+/// A currency code that may not be recognised.
+pub type MaybeCurrency = Maybe<Currency>;
+
+/// A language code that may not be recognised.
+pub type MaybeLanguage = Maybe<Language>;
+
+
+
+//		Enums
+
+//		CodeOrName																
+/// A value given either as a code or as a name, to be resolved later.
+/// 
+/// This is intended for use in API request models, where clients may send
+/// either a code, e.g. `"FR"`, or a name, e.g. `"France"`. It deserialises
+/// from any string, trying it as a code first, and otherwise holding it as a
+/// name, so that deserialisation of the request as a whole does not fail on
+/// an unrecognised value. The value is then resolved with [`resolve()`](CodeOrName::resolve()),
+/// which gives an error naming the value that could not be resolved:
+/// 
+/// ```
+/// use isosphere::{Country, CountryCode, serde::CodeOrName};
+/// use serde::Deserialize;
+/// 
+/// #[derive(Deserialize)]
+/// struct Request {
+///     country: CodeOrName<Country>,
+/// }
+/// 
+/// let request: Request = serde_json::from_str(r#"{"country": "France"}"#).unwrap();
+/// assert_eq!(request.country.resolve(), Ok(Country::FR));
+/// 
+/// let request: Request = serde_json::from_str(r#"{"country": "FR"}"#).unwrap();
+/// assert!(matches!(request.country, CodeOrName::Code(CountryCode::FR)));
+/// 
+/// let request: Request = serde_json::from_str(r#"{"country": "Frnace"}"#).unwrap();
+/// assert_eq!(request.country.resolve(), Err("Invalid Country: Frnace".to_owned()));
+/// ```
+/// 
+/// It serialises as the code or name that it holds.
+/// 
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(untagged)]
+#[expect(clippy::exhaustive_enums, reason = "A value is given either as a code or as a name")]
+pub enum CodeOrName<T: Coded> {
+	/// A value given as a code.
+	Code(T::Code),
+	
+	/// A value given as a name, or as any other text that is not a code.
+	Name(String),
+}
+
+//󰭅		CodeOrName																
+impl<T: Coded + FromStr<Err = String>> CodeOrName<T> {
+	//		resolve																
+	/// Resolves the code or name to a value.
+	/// 
+	/// Codes resolve directly. Names are parsed using the [`FromStr`]
+	/// implementation of the type, and so are matched in the same way, e.g.
+	/// ignoring case and accents for countries.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the name does not identify a value.
+	/// 
+	pub fn resolve(&self) -> Result<T, String> {
+		match *self {
+			Self::Code(code)     => Ok(T::from_coded(code)),
+			Self::Name(ref name) => name.parse(),
+		}
+	}
+}
+
+//		Maybe																	
+/// A code that is either recognised, or preserved as given.
+/// 
+/// This is intended for pass-through services, which must not drop data that
+/// they do not understand. It deserialises from any string, parsing it as a
+/// code, and holding the value if the code is recognised, or otherwise the
+/// original string, so that deserialisation does not fail on an unrecognised
+/// code. The original string is then serialised again unchanged:
+/// 
+/// ```
+/// use isosphere::{Country, serde::MaybeCountry};
+/// 
+/// let known: MaybeCountry = serde_json::from_str(r#""FR""#).unwrap();
+/// assert_eq!(known, MaybeCountry::Known(Country::FR));
+/// 
+/// let unknown: MaybeCountry = serde_json::from_str(r#""XX""#).unwrap();
+/// assert_eq!(unknown, MaybeCountry::Unknown("XX".to_owned()));
+/// assert_eq!(serde_json::to_string(&unknown).unwrap(), r#""XX""#);
+/// ```
+/// 
+/// Recognised codes are serialised in their canonical form, e.g. `"fra"` as
+/// `"FR"` for a [`MaybeCountry`]. To preserve the form of a country code, use
+/// `Maybe<CountryCode>`, which holds `"fra"` as [`CountryCode::FRA`] and
+/// serialises it as `"FRA"`.
+/// 
+/// Codes are always (de)serialised as strings, including in compact formats.
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[expect(clippy::exhaustive_enums, reason = "A code is either recognised or not")]
+pub enum Maybe<T> {
+	/// A recognised code.
+	Known(T),
+	
+	/// An unrecognised code, as given.
+	Unknown(String),
+}
+
+//󰭅		Maybe																	
+impl<T: Copy> Maybe<T> {
+	//		known																
+	/// Returns the value, if the code was recognised.
+	#[must_use]
+	pub const fn known(&self) -> Option<T> {
+		match *self {
+			Self::Known(value) => Some(value),
+			Self::Unknown(_)   => None,
+		}
+	}
+}
+
+//󰭅		Deserialize																
+impl<'de, T> Deserialize<'de> for Maybe<T>
+where
+	T:       Coded,
+	T::Code: FromStr,
+{
+	//		deserialize															
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let text = String::deserialize(deserializer)?;
+		Ok(text.parse().ok().map_or_else(|| Self::Unknown(text), |code| Self::Known(T::from_coded(code))))
+	}
+}
+
+//󰭅		From<T>																	
+impl<T> From<T> for Maybe<T> {
+	//		from																
+	fn from(value: T) -> Self {
+		Self::Known(value)
+	}
+}
+
+//󰭅		Serialize																
+impl<T> Serialize for Maybe<T>
+where
+	T:       Coded,
+	T::Code: AsStr,
+{
+	//		serialize															
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match *self {
+			Self::Known(value)      => serializer.serialize_str(value.to_coded().as_str()),
+			Self::Unknown(ref text) => serializer.serialize_str(text),
+		}
+	}
+}
+
+
+
+//		Traits
+
+//§		Coded																	
+/// A type that can be (de)serialised using the modules in this module.
+/// 
+/// This trait is implemented for [`Country`], [`CountryCode`], [`Currency`],
+/// [`CurrencyCode`], [`Language`], and [`LanguageCode`], and cannot be
+/// implemented outside of this crate.
+/// 
+pub trait Coded: Copy + private::Sealed {
+	/// The code type used to represent the value.
+	type Code: Copy;
+	
+	//		to_coded															
+	/// Converts the value to its code.
+	fn to_coded(self) -> Self::Code;
+	
+	//		from_coded															
+	/// Converts a code to the value.
+	fn from_coded(code: Self::Code) -> Self;
+}
+
+//󰭅		Coded for Country														
+impl Coded for Country {
+	type Code = CountryCode;
+	
+	//		to_coded															
+	fn to_coded(self) -> Self::Code {
+		self.code()
+	}
+	
+	//		from_coded															
+	fn from_coded(code: Self::Code) -> Self {
+		code.country()
+	}
+}
+
+//󰭅		Coded for CountryCode													
+impl Coded for CountryCode {
+	type Code = Self;
+	
+	//		to_coded															
+	fn to_coded(self) -> Self::Code {
+		self
+	}
+	
+	//		from_coded															
+	fn from_coded(code: Self::Code) -> Self {
+		code
+	}
+}
+
+//󰭅		Coded for Currency														
+impl Coded for Currency {
+	type Code = CurrencyCode;
+	
+	//		to_coded															
+	fn to_coded(self) -> Self::Code {
+		self.code()
+	}
+	
+	//		from_coded															
+	fn from_coded(code: Self::Code) -> Self {
+		code.currency()
+	}
+}
+
+//󰭅		Coded for CurrencyCode													
+impl Coded for CurrencyCode {
+	type Code = Self;
+	
+	//		to_coded															
+	fn to_coded(self) -> Self::Code {
+		self
+	}
+	
+	//		from_coded															
+	fn from_coded(code: Self::Code) -> Self {
+		code
+	}
+}
+
+//󰭅		Coded for Language														
+impl Coded for Language {
+	type Code = LanguageCode;
+	
+	//		to_coded															
+	fn to_coded(self) -> Self::Code {
+		self.code()
+	}
+	
+	//		from_coded															
+	fn from_coded(code: Self::Code) -> Self {
+		code.language()
+	}
+}
+
+//󰭅		Coded for LanguageCode													
+impl Coded for LanguageCode {
+	type Code = Self;
+	
+	//		to_coded															
+	fn to_coded(self) -> Self::Code {
+		self
+	}
+	
+	//		from_coded															
+	fn from_coded(code: Self::Code) -> Self {
+		code
+	}
+}
+
+
+
+//		Macros
+
+//		codec!																	
+/// Generates a `with` module, along with its `option`, `vec`, and `map`
+/// submodules.
+/// 
+/// The representation types are what is actually serialised and deserialised,
+/// which differ for the string forms, as these are serialised as borrowed
+/// strings but deserialised as owned ones. The encoder converts a code to the
+/// serialised representation, and the decoder converts the deserialised
+/// representation back to a code, normalising it to the chosen form. The
+/// string forms are always (de)serialised as strings, including in formats
+/// that are not human-readable, where the code types themselves would use
+/// their numeric codes.
+/// 
+macro_rules! codec {
+	($(#[$meta:meta])* $name:ident, $code:ty, $ser:ty, $de:ty, $encode:expr, $decode:expr $(,)?) => {
+		$(#[$meta])*
+		pub mod $name {
+			use super::super::*;
+			
+			//		encode														
+			/// Converts a code to its representation.
+			fn encode(code: $code) -> $ser {
+				let encoder: fn($code) -> $ser = $encode;
+				encoder(code)
+			}
+			
+			//		decode														
+			/// Converts a representation to a code.
+			fn decode<E: ::serde::de::Error>(repr: $de) -> Result<$code, E> {
+				let decoder: fn($de) -> Result<$code, String> = $decode;
+				decoder(repr).map_err(E::custom)
+			}
+			
+			//		serialize													
+			/// Serialises a value.
+			/// 
+			/// # Errors
+			/// 
+			/// Returns any error produced by the serialiser.
+			/// 
+			pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+			where
+				T: Coded<Code = $code>,
+				S: Serializer,
+			{
+				encode(value.to_coded()).serialize(serializer)
+			}
+			
+			//		deserialize													
+			/// Deserialises a value.
+			/// 
+			/// # Errors
+			/// 
+			/// Returns an error if the input is not a valid code.
+			/// 
+			pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+			where
+				T: Coded<Code = $code>,
+				D: Deserializer<'de>,
+			{
+				decode(<$de>::deserialize(deserializer)?).map(T::from_coded)
+			}
+			
+			/// (De)serialisation of optional values.
+			pub mod option {
+				use super::*;
+				
+				//		serialize												
+				/// Serialises an optional value.
+				/// 
+				/// # Errors
+				/// 
+				/// Returns any error produced by the serialiser.
+				/// 
+				pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+				where
+					T: Coded<Code = $code>,
+					S: Serializer,
+				{
+					value.map(|inner| encode(inner.to_coded())).serialize(serializer)
+				}
+				
+				//		deserialize												
+				/// Deserialises an optional value.
+				/// 
+				/// # Errors
+				/// 
+				/// Returns an error if the input is neither null nor a valid code.
+				/// 
+				pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+				where
+					T: Coded<Code = $code>,
+					D: Deserializer<'de>,
+				{
+					Option::<$de>::deserialize(deserializer)?
+						.map(|repr| decode(repr).map(T::from_coded))
+						.transpose()
+				}
+			}
+			
+			/// (De)serialisation of lists of values.
+			pub mod vec {
+				use super::*;
+				
+				//		serialize												
+				/// Serialises a list of values.
+				/// 
+				/// # Errors
+				/// 
+				/// Returns any error produced by the serialiser.
+				/// 
+				pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+				where
+					T: Coded<Code = $code>,
+					S: Serializer,
+				{
+					serializer.collect_seq(values.iter().map(|value| encode(value.to_coded())))
+				}
+				
+				//		deserialize												
+				/// Deserialises a list of values.
+				/// 
+				/// # Errors
+				/// 
+				/// Returns an error if the input is not a list of valid codes.
+				/// 
+				pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+				where
+					T: Coded<Code = $code>,
+					D: Deserializer<'de>,
+				{
+					Vec::<$de>::deserialize(deserializer)?
+						.into_iter()
+						.map(|repr| decode(repr).map(T::from_coded))
+						.collect()
+				}
+			}
+			
+			/// (De)serialisation of maps keyed by values.
+			pub mod map {
+				use super::*;
+				
+				//		serialize												
+				/// Serialises a map keyed by values.
+				/// 
+				/// The keys are serialised as strings, and the values as normal.
+				/// 
+				/// # Errors
+				/// 
+				/// Returns any error produced by the serialiser.
+				/// 
+				pub fn serialize<'a, K, V, M, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+				where
+					K: Coded<Code = $code> + 'a,
+					V: Serialize + 'a,
+					&'a M: IntoIterator<Item = (&'a K, &'a V)>,
+					S: Serializer,
+				{
+					serializer.collect_map(map.into_iter().map(|(key, value)| (encode(key.to_coded()).to_string(), value)))
+				}
+				
+				//		deserialize												
+				/// Deserialises a map keyed by values.
+				/// 
+				/// # Errors
+				/// 
+				/// Returns an error if the input is not a map, or if any of its
+				/// keys is not a valid code.
+				/// 
+				pub fn deserialize<'de, K, V, M, D>(deserializer: D) -> Result<M, D::Error>
+				where
+					K: Coded<Code = $code>,
+					V: Deserialize<'de>,
+					M: FromIterator<(K, V)>,
+					D: Deserializer<'de>,
+				{
+					HashMap::<String, V>::deserialize(deserializer)?
+						.into_iter()
+						.map(|(key, value)| {
+							let repr = <$de>::from_str(&key).map_err(::serde::de::Error::custom)?;
+							decode(repr).map(|code| (K::from_coded(code), value))
+						})
+						.collect()
+				}
+			}
+		}
+	};
+}
+
+
+
+//		Codecs
+
+/// Country code fields.
+pub mod country {
+	codec! {
+		/// (De)serialisation as a two-letter country code (ISO 3166-1 alpha-2).
+		alpha2, CountryCode, &'static str, String,
+		|code| code.to_alpha2().as_str(),
+		|code| code.parse::<CountryCode>().map(|parsed| parsed.to_alpha2()),
+	}
+	
+	codec! {
+		/// (De)serialisation as a three-letter country code (ISO 3166-1
+		/// alpha-3).
+		alpha3, CountryCode, &'static str, String,
+		|code| code.to_alpha3().as_str(),
+		|code| code.parse::<CountryCode>().map(|parsed| parsed.to_alpha3()),
+	}
+	
+	codec! {
+		/// (De)serialisation as a three-digit numeric country code (ISO 3166-1
+		/// numeric).
+		numeric, CountryCode, u16, u16,
+		|code| code.numeric(),
+		CountryCode::try_from,
+	}
+}
+
+/// Currency code fields.
+pub mod currency {
+	codec! {
+		/// (De)serialisation as a three-letter currency code (ISO 4217 alpha).
+		alpha3, CurrencyCode, &'static str, String,
+		|code| code.as_str(),
+		|code| code.parse(),
+	}
+	
+	codec! {
+		/// (De)serialisation as a three-digit numeric currency code (ISO 4217
+		/// numeric).
+		numeric, CurrencyCode, u16, u16,
+		|code| code.numeric(),
+		CurrencyCode::try_from,
+	}
+}
+
+/// Language code fields.
+pub mod language {
+	codec! {
+		/// (De)serialisation as a two-letter language code (ISO 639-1).
+		alpha2, LanguageCode, &'static str, String,
+		|code| code.as_str(),
+		|code| code.parse(),
+	}
+}
+
+/// (De)serialisation of optional fields that may be empty.
+/// 
+/// Deserialisation treats null, an empty string, and a string of only
+/// whitespace as [`None`], and trims whitespace from any other string before
+/// parsing it, using [`FromStr`]. This means it works with any of the types,
+/// e.g. `Option<CountryCode>` or `Option<Currency>`. Serialisation writes
+/// [`None`] as null, and any other value as normal. As empty fields may also be
+/// missing altogether, this should usually be combined with `default`:
+/// 
+/// ```
+/// use isosphere::CountryCode;
+/// use serde::Deserialize;
+/// 
+/// #[derive(Deserialize)]
+/// struct Row {
+///     #[serde(default, with = "isosphere::serde::empty_as_none")]
+///     country: Option<CountryCode>,
+/// }
+/// 
+/// let row: Row = serde_json::from_str(r#"{"country": ""}"#).unwrap();
+/// assert_eq!(row.country, None);
+/// let row: Row = serde_json::from_str(r#"{"country": " GB "}"#).unwrap();
+/// assert_eq!(row.country, Some(CountryCode::GB));
+/// ```
+/// 
+pub mod empty_as_none {
+	use super::{Deserialize, Deserializer, FromStr, Serialize, Serializer};
+	use ::serde::de;
+	
+	//		serialize															
+	/// Serialises an optional value.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns any error produced by the serialiser.
+	/// 
+	pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		T: Serialize,
+		S: Serializer,
+	{
+		value.serialize(serializer)
+	}
+	
+	//		deserialize															
+	/// Deserialises an optional value, treating empty strings as [`None`].
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the input is neither null nor a string, or if the
+	/// trimmed string is not empty and cannot be parsed.
+	/// 
+	pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+	where
+		T: FromStr<Err = String>,
+		D: Deserializer<'de>,
+	{
+		Option::<String>::deserialize(deserializer)?
+			.as_deref()
+			.map(str::trim)
+			.filter(|value| !value.is_empty())
+			.map(|value| value.parse().map_err(de::Error::custom))
+			.transpose()
+	}
+}
+
+/// (De)serialisation of sets as sorted arrays.
+/// 
+/// A [`HashSet`] is serialised in an arbitrary order, which differs between
+/// runs, and so makes API responses and snapshots unstable. This sorts the
+/// values before serialising them, so that e.g. a set of
+/// [`CurrencyCode`]s obtained from [`Country::currencies_set()`] is always
+/// serialised in alphabetical order. Deserialisation accepts an array in any
+/// order.
+/// 
+/// ```
+/// use isosphere::{Country, CurrencyCode};
+/// use serde::Serialize;
+/// use std::collections::HashSet;
+/// 
+/// #[derive(Serialize)]
+/// struct Response {
+///     #[serde(with = "isosphere::serde::sorted")]
+///     currencies: HashSet<CurrencyCode>,
+/// }
+/// 
+/// let response = Response { currencies: Country::CH.currencies_set() };
+/// assert_eq!(serde_json::to_string(&response).unwrap(), r#"{"currencies":["CHE","CHF","CHW"]}"#);
+/// ```
+/// 
+/// The slices held in records, such as [`CountryRecord`](crate::country::CountryRecord),
+/// are already sorted, and the sets in the [`set`](crate::set) module are
+/// always serialised in order, so neither needs this.
+/// 
+pub mod sorted {
+	use super::{BuildHasher, Deserialize, Deserializer, Hash, HashSet, Serialize, Serializer};
+	
+	//		serialize															
+	/// Serialises a set as an array, sorted in ascending order.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns any error produced by the serialiser.
+	/// 
+	pub fn serialize<T, H, S>(set: &HashSet<T, H>, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		T: Ord + Serialize,
+		S: Serializer,
+	{
+		let mut values: Vec<_> = set.iter().collect();
+		values.sort_unstable();
+		serializer.collect_seq(values)
+	}
+	
+	//		deserialize															
+	/// Deserialises a set from an array in any order.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the input is not an array of valid values.
+	/// 
+	pub fn deserialize<'de, T, H, D>(deserializer: D) -> Result<HashSet<T, H>, D::Error>
+	where
+		T: Deserialize<'de> + Eq + Hash,
+		H: BuildHasher + Default,
+		D: Deserializer<'de>,
+	{
+		HashSet::deserialize(deserializer)
+	}
+}
+
+
+
+//		Private
+
+/// Sealing of the [`Coded`] trait.
+mod private {
+	/// Prevents [`Coded`](super::Coded) from being implemented elsewhere.
+	pub trait Sealed {}
+	
+	impl Sealed for super::Country {}
+	impl Sealed for super::CountryCode {}
+	impl Sealed for super::Currency {}
+	impl Sealed for super::CurrencyCode {}
+	impl Sealed for super::Language {}
+	impl Sealed for super::LanguageCode {}
+}
@@ -0,0 +1,630 @@
+//! Compact sets of countries, currencies, and languages.
+//! 
+//! This module provides the [`CountrySet`], [`CurrencySet`], and
+//! [`LanguageSet`] types, which are sets backed by a fixed-size bitset over
+//! the enum variants. They are [`Copy`], require no allocation, and membership
+//! checks are a simple bit test, which makes them well-suited to hot paths such
+//! as geo-fencing checks, where a [`HashSet`] would be comparatively
+//! heavyweight.
+//! 
+//! All three are aliases of the generic [`Set`] type, and support the usual set
+//! operations, along with conversions to and from [`HashSet`]s of the
+//! associated code types. They serialise as arrays of codes, e.g. `["FR",
+//! "GB"]`, in a consistent order.
+//! 
+//! Sets can also be parsed from lists of codes, such as those found in
+//! configuration files, e.g. `"GB, FR;DE US"`, using [`FromStr`], with any
+//! invalid items reported individually by [`ParseSetError`]. They are
+//! displayed as canonical comma-separated lists, e.g. `DE, FR, GB, US`, which
+//! can be parsed back again.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/set.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	country::{Country, CountryCode, self},
+	currency::{Currency, CurrencyCode, self},
+	language::{Language, LanguageCode, self},
+};
+use core::{
+	error::Error,
+	fmt::{Debug, Display, self},
+	hash::{Hash, Hasher},
+	marker::PhantomData,
+	ops::{BitAnd, BitOr, BitXor, Sub},
+	str::FromStr,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::DeserializeOwned};
+use std::{
+	collections::HashSet,
+	sync::LazyLock,
+};
+
+#[cfg(feature = "utoipa")]
+use utoipa::{
+	ToSchema,
+	openapi::{ArrayBuilder, Ref, RefOr, Schema},
+};
+
+
+
+//		Constants
+
+/// The number of 64-bit words in the bitset.
+/// 
+/// This must be enough to hold one bit for every variant of each of the member
+/// types, which is checked at compile time, so that adding codes cannot cause
+/// members to be silently dropped.
+/// 
+const WORDS: usize = 4;
+
+const _: () = assert!(country::COUNT  <= WORDS * 64, "Set is too small for all the countries");
+const _: () = assert!(currency::COUNT <= WORDS * 64, "Set is too small for all the currencies");
+const _: () = assert!(language::COUNT <= WORDS * 64, "Set is too small for all the languages");
+
+/// The countries, in the alphabetical order of their codes.
+static COUNTRIES: LazyLock<Vec<Country>> = LazyLock::new(|| ordered(Country::all()));
+
+/// The currencies, in the alphabetical order of their codes.
+static CURRENCIES: LazyLock<Vec<Currency>> = LazyLock::new(|| ordered(Currency::all()));
+
+/// The languages, in the alphabetical order of their codes.
+static LANGUAGES: LazyLock<Vec<Language>> = LazyLock::new(|| ordered(Language::all()));
+
+
+
+//		Type aliases
+
+/// A set of countries.
+pub type CountrySet = Set<Country>;
+
+/// A set of currencies.
+pub type CurrencySet = Set<Currency>;
+
+/// A set of languages.
+pub type LanguageSet = Set<Language>;
+
+
+
+//		Structs
+
+//		Set																		
+/// A compact set of countries, currencies, or languages.
+/// 
+/// The set is backed by a fixed-size bitset, with one bit per enum variant,
+/// and so is cheap to copy, compare, and combine. It is generally used via the
+/// [`CountrySet`], [`CurrencySet`], and [`LanguageSet`] aliases.
+/// 
+/// Iteration, and serialisation, are in the alphabetical order of the codes of
+/// the members. This does not depend on the discriminants of the enum
+/// variants, which are fixed, and so do not follow alphabetical order for
+/// codes added later.
+/// 
+pub struct Set<T> {
+	//		Private properties													
+	/// The bits representing the members of the set.
+	bits:    [u64; WORDS],
+	
+	/// The type of the members of the set.
+	_member: PhantomData<T>,
+}
+
+//󰭅		Set																		
+impl<T: Member> Set<T> {
+	//		new																	
+	/// Creates a new, empty set.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { bits: [0; WORDS], _member: PhantomData }
+	}
+	
+	//		len																	
+	/// Returns the number of members in the set.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.bits.iter().map(|word| word.count_ones() as usize).sum()
+	}
+	
+	//		is_empty															
+	/// Returns `true` if the set has no members.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.bits.iter().all(|&word| word == 0)
+	}
+	
+	//		contains															
+	/// Returns `true` if the set contains the given member.
+	#[must_use]
+	pub fn contains(&self, member: T) -> bool {
+		let (word, mask) = position(member.index());
+		self.bits.get(word).is_some_and(|&bits| bits & mask != 0)
+	}
+	
+	//		insert																
+	/// Adds a member to the set.
+	/// 
+	/// Returns `true` if the member was not already present.
+	/// 
+	pub fn insert(&mut self, member: T) -> bool {
+		let (word, mask) = position(member.index());
+		self.bits.get_mut(word).is_some_and(|bits| {
+			let absent = *bits & mask == 0;
+			*bits |= mask;
+			absent
+		})
+	}
+	
+	//		remove																
+	/// Removes a member from the set.
+	/// 
+	/// Returns `true` if the member was present.
+	/// 
+	pub fn remove(&mut self, member: T) -> bool {
+		let (word, mask) = position(member.index());
+		self.bits.get_mut(word).is_some_and(|bits| {
+			let present = *bits & mask != 0;
+			*bits &= !mask;
+			present
+		})
+	}
+	
+	//		union																
+	/// Returns the members that are in either set.
+	#[must_use]
+	pub fn union(&self, other: &Self) -> Self {
+		self.combine(other, |a, b| a | b)
+	}
+	
+	//		intersection														
+	/// Returns the members that are in both sets.
+	#[must_use]
+	pub fn intersection(&self, other: &Self) -> Self {
+		self.combine(other, |a, b| a & b)
+	}
+	
+	//		difference															
+	/// Returns the members that are in this set but not the other.
+	#[must_use]
+	pub fn difference(&self, other: &Self) -> Self {
+		self.combine(other, |a, b| a & !b)
+	}
+	
+	//		symmetric_difference												
+	/// Returns the members that are in one set or the other, but not both.
+	#[must_use]
+	pub fn symmetric_difference(&self, other: &Self) -> Self {
+		self.combine(other, |a, b| a ^ b)
+	}
+	
+	//		is_subset															
+	/// Returns `true` if every member of this set is in the other set.
+	#[must_use]
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.difference(other).is_empty()
+	}
+	
+	//		is_superset															
+	/// Returns `true` if every member of the other set is in this set.
+	#[must_use]
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+	
+	//		is_disjoint															
+	/// Returns `true` if the sets have no members in common.
+	#[must_use]
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.intersection(other).is_empty()
+	}
+	
+	//		iter																
+	/// Returns an iterator over the members of the set, in code order.
+	pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+		T::ordered().iter().copied().filter(|&member| self.contains(member))
+	}
+	
+	//		to_codes															
+	/// Returns the codes of the members of the set, as a [`HashSet`].
+	#[must_use]
+	pub fn to_codes(&self) -> HashSet<T::Code> {
+		self.iter().map(Member::to_code).collect()
+	}
+	
+	//		combine																
+	/// Combines two sets word by word using the given operation.
+	fn combine<F: Fn(u64, u64) -> u64>(&self, other: &Self, op: F) -> Self {
+		let mut bits = [0; WORDS];
+		for ((result, &a), &b) in bits.iter_mut().zip(&self.bits).zip(&other.bits) {
+			*result = op(a, b);
+		}
+		Self { bits, _member: PhantomData }
+	}
+}
+
+//󰭅		BitAnd																	
+impl<T: Member> BitAnd for Set<T> {
+	type Output = Self;
+	
+	//		bitand																
+	fn bitand(self, rhs: Self) -> Self::Output {
+		self.intersection(&rhs)
+	}
+}
+
+//󰭅		BitOr																	
+impl<T: Member> BitOr for Set<T> {
+	type Output = Self;
+	
+	//		bitor																
+	fn bitor(self, rhs: Self) -> Self::Output {
+		self.union(&rhs)
+	}
+}
+
+//󰭅		BitXor																	
+impl<T: Member> BitXor for Set<T> {
+	type Output = Self;
+	
+	//		bitxor																
+	fn bitxor(self, rhs: Self) -> Self::Output {
+		self.symmetric_difference(&rhs)
+	}
+}
+
+//󰭅		Clone																	
+impl<T> Clone for Set<T> {
+	//		clone																
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+//󰭅		Copy																	
+impl<T> Copy for Set<T> {}
+
+//󰭅		Debug																	
+impl<T: Member + Debug> Debug for Set<T> {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_set().entries(self.iter()).finish()
+	}
+}
+
+//󰭅		Default																	
+impl<T: Member> Default for Set<T> {
+	//		default																
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+//󰭅		Deserialize																
+impl<'de, T: Member> Deserialize<'de> for Set<T> {
+	//		deserialize															
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Ok(Vec::<T::Code>::deserialize(deserializer)?.into_iter().map(Member::from_code).collect())
+	}
+}
+
+//󰭅		Display																	
+impl<T: Member> Display for Set<T> {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (index, member) in self.iter().enumerate() {
+			if index > 0 {
+				write!(f, ", ")?;
+			}
+			write!(f, "{}", member.to_code())?;
+		}
+		Ok(())
+	}
+}
+
+//󰭅		Eq																		
+impl<T> Eq for Set<T> {}
+
+//󰭅		Extend																	
+impl<T: Member> Extend<T> for Set<T> {
+	//		extend																
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		for member in iter {
+			_ = self.insert(member);
+		}
+	}
+}
+
+//󰭅		From<&HashSet<Code>>													
+impl<T: Member> From<&HashSet<T::Code>> for Set<T> {
+	//		from																
+	fn from(codes: &HashSet<T::Code>) -> Self {
+		codes.iter().copied().map(Member::from_code).collect()
+	}
+}
+
+//󰭅		From<HashSet<Code>>														
+impl<T: Member> From<HashSet<T::Code>> for Set<T> {
+	//		from																
+	fn from(codes: HashSet<T::Code>) -> Self {
+		Self::from(&codes)
+	}
+}
+
+//󰭅		FromIterator															
+impl<T: Member> FromIterator<T> for Set<T> {
+	//		from_iter															
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut set = Self::new();
+		set.extend(iter);
+		set
+	}
+}
+
+//󰭅		FromStr																	
+impl<T: Member> FromStr for Set<T> {
+	type Err = ParseSetError;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut set     = Self::new();
+		let mut invalid = vec![];
+		for item in s.split(|c: char| c == ',' || c == ';' || c.is_whitespace()).filter(|item| !item.is_empty()) {
+			match item.parse() {
+				Ok(code) => _ = set.insert(T::from_code(code)),
+				Err(_)   => invalid.push(item.to_owned()),
+			}
+		}
+		if invalid.is_empty() {
+			Ok(set)
+		} else {
+			Err(ParseSetError { invalid })
+		}
+	}
+}
+
+//󰭅		Hash																	
+impl<T> Hash for Set<T> {
+	//		hash																
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.bits.hash(state);
+	}
+}
+
+//󰭅		PartialEq																
+impl<T> PartialEq for Set<T> {
+	//		eq																	
+	fn eq(&self, other: &Self) -> bool {
+		self.bits == other.bits
+	}
+}
+
+//󰭅		Serialize																
+impl<T: Member> Serialize for Set<T> {
+	//		serialize															
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_seq(self.iter().map(Member::to_code))
+	}
+}
+
+//󰭅		Sub																		
+impl<T: Member> Sub for Set<T> {
+	type Output = Self;
+	
+	//		sub																	
+	fn sub(self, rhs: Self) -> Self::Output {
+		self.difference(&rhs)
+	}
+}
+
+//󰭅		ToSchema																
+#[cfg(feature = "utoipa")]
+impl<'s, T: Member> ToSchema<'s> for Set<T> {
+	//		schema																
+	fn schema() -> (&'s str, RefOr<Schema>) {
+		(T::SET_NAME, ArrayBuilder::new().items(Ref::from_schema_name(T::CODE_NAME)).unique_items(true).into())
+	}
+}
+
+//		ParseSetError															
+/// An error from parsing a list of codes into a [`Set`].
+/// 
+/// All of the items in the list are checked, and every invalid item is
+/// reported, rather than only the first, so that problems in configuration
+/// files can be fixed in one go.
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseSetError {
+	//		Private properties													
+	/// The items that are not valid codes, in the order they appeared.
+	invalid: Vec<String>,
+}
+
+//󰭅		ParseSetError															
+impl ParseSetError {
+	//		invalid																
+	/// Returns the items that are not valid codes, in the order they appeared.
+	#[must_use]
+	pub fn invalid(&self) -> &[String] {
+		&self.invalid
+	}
+}
+
+//󰭅		Display																	
+impl Display for ParseSetError {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Invalid codes: {}", self.invalid.join(", "))
+	}
+}
+
+//󰭅		Error																	
+impl Error for ParseSetError {}
+
+
+
+//		Traits
+
+//§		Member																	
+/// A type that can be a member of a [`Set`].
+/// 
+/// This trait is implemented for [`Country`], [`Currency`], and [`Language`],
+/// and cannot be implemented outside of this crate.
+/// 
+pub trait Member: Copy + Sized + 'static + private::Sealed {
+	/// The code type used to represent members when (de)serialising.
+	type Code: Copy + Display + Eq + FromStr + Hash + Ord + Serialize + DeserializeOwned;
+	
+	/// The name of the set type, used for the Open&#8203;API schema.
+	#[cfg(feature = "utoipa")]
+	const SET_NAME: &'static str;
+	
+	/// The name of the code type, used for the Open&#8203;API schema.
+	#[cfg(feature = "utoipa")]
+	const CODE_NAME: &'static str;
+	
+	//		index																
+	/// Returns the position of the member in the bitset.
+	fn index(self) -> usize;
+	
+	//		ordered																
+	/// Returns all possible members, in the alphabetical order of their codes.
+	fn ordered() -> &'static [Self];
+	
+	//		to_code																
+	/// Converts the member to its code.
+	fn to_code(self) -> Self::Code;
+	
+	//		from_code															
+	/// Converts a code to the member.
+	fn from_code(code: Self::Code) -> Self;
+}
+
+//󰭅		Member for Country														
+impl Member for Country {
+	type Code = CountryCode;
+	
+	#[cfg(feature = "utoipa")]
+	const SET_NAME:  &'static str = "CountrySet";
+	#[cfg(feature = "utoipa")]
+	const CODE_NAME: &'static str = "CountryCode";
+	
+	//		index																
+	fn index(self) -> usize {
+		self as usize
+	}
+	
+	//		ordered																
+	fn ordered() -> &'static [Self] {
+		&COUNTRIES
+	}
+	
+	//		to_code																
+	fn to_code(self) -> Self::Code {
+		self.code()
+	}
+	
+	//		from_code															
+	fn from_code(code: Self::Code) -> Self {
+		code.country()
+	}
+}
+
+//󰭅		Member for Currency														
+impl Member for Currency {
+	type Code = CurrencyCode;
+	
+	#[cfg(feature = "utoipa")]
+	const SET_NAME:  &'static str = "CurrencySet";
+	#[cfg(feature = "utoipa")]
+	const CODE_NAME: &'static str = "CurrencyCode";
+	
+	//		index																
+	fn index(self) -> usize {
+		self as usize
+	}
+	
+	//		ordered																
+	fn ordered() -> &'static [Self] {
+		&CURRENCIES
+	}
+	
+	//		to_code																
+	fn to_code(self) -> Self::Code {
+		self.code()
+	}
+	
+	//		from_code															
+	fn from_code(code: Self::Code) -> Self {
+		code.currency()
+	}
+}
+
+//󰭅		Member for Language														
+impl Member for Language {
+	type Code = LanguageCode;
+	
+	#[cfg(feature = "utoipa")]
+	const SET_NAME:  &'static str = "LanguageSet";
+	#[cfg(feature = "utoipa")]
+	const CODE_NAME: &'static str = "LanguageCode";
+	
+	//		index																
+	fn index(self) -> usize {
+		self as usize
+	}
+	
+	//		ordered																
+	fn ordered() -> &'static [Self] {
+		&LANGUAGES
+	}
+	
+	//		to_code																
+	fn to_code(self) -> Self::Code {
+		self.code()
+	}
+	
+	//		from_code															
+	fn from_code(code: Self::Code) -> Self {
+		code.language()
+	}
+}
+
+
+
+//		Functions
+
+//		ordered																	
+/// Sorts a list of members into the alphabetical order of their codes.
+fn ordered<T: Member>(mut members: Vec<T>) -> Vec<T> {
+	members.sort_unstable_by_key(|&member| member.to_code());
+	members
+}
+
+//		position																
+/// Returns the word index and bit mask for a bitset position.
+const fn position(index: usize) -> (usize, u64) {
+	(index >> 6, 1 << (index & 63))
+}
+
+
+
+//		Private
+
+/// Sealing of the [`Member`] trait.
+mod private {
+	/// Prevents [`Member`](super::Member) from being implemented elsewhere.
+	pub trait Sealed {}
+	
+	impl Sealed for super::Country {}
+	impl Sealed for super::Currency {}
+	impl Sealed for super::Language {}
+}
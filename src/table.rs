@@ -0,0 +1,314 @@
+//! Tabular output of the datasets.
+//! 
+//! This module provides the [`Table`] type, which lays out countries,
+//! currencies, or languages as an aligned plain-text table, with a chosen set
+//! of columns. It is intended for CLI output and debugging dumps.
+//! 
+//! The rows are taken from any iterator, so filtering is done in the usual way
+//! before tabulating, e.g. by passing only the countries that use a particular
+//! currency, as given by [`Currency::countries()`].
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/table.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	country::Country,
+	currency::Currency,
+	language::Language,
+};
+use core::fmt::{Display, self};
+
+
+
+//		Enums
+
+//		CountryColumn															
+/// The columns available when tabulating countries.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CountryColumn {
+	/// The two-letter country code.
+	Code,
+	
+	/// The three-letter country code.
+	Alpha3,
+	
+	/// The three-digit numeric country code.
+	Numeric,
+	
+	/// The name of the country.
+	Name,
+	
+	/// The codes of the currencies used in the country.
+	Currencies,
+	
+	/// The codes of the languages used in the country.
+	Languages,
+}
+
+//		CurrencyColumn															
+/// The columns available when tabulating currencies.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CurrencyColumn {
+	/// The three-letter currency code.
+	Code,
+	
+	/// The three-digit numeric currency code.
+	Numeric,
+	
+	/// The name of the currency.
+	Name,
+	
+	/// The number of digits after the decimal point.
+	Digits,
+	
+	/// The codes of the countries where the currency is used.
+	Countries,
+}
+
+//		LanguageColumn															
+/// The columns available when tabulating languages.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum LanguageColumn {
+	/// The two-letter language code.
+	Code,
+	
+	/// The name of the language.
+	Name,
+	
+	/// The codes of the countries where the language is used.
+	Countries,
+}
+
+
+
+//		Structs
+
+//		Table																	
+/// An aligned plain-text table.
+/// 
+/// The table has a header row, followed by a separator, and then one row per
+/// item. Each column is padded to the width of its widest cell. The table is
+/// rendered using its [`Display`] implementation.
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Table {
+	//		Private properties													
+	/// The column headers.
+	headers: Vec<&'static str>,
+	
+	/// The cells, by row and then column.
+	rows:    Vec<Vec<String>>,
+}
+
+//󰭅		Table																	
+impl Table {
+	//		new																	
+	/// Creates a new table from a list of items and a set of columns.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `items`   - The items to tabulate, one per row, in the order given.
+	/// * `columns` - The columns to include, in the order given.
+	/// 
+	#[must_use]
+	pub fn new<T, I>(items: I, columns: &[T::Column]) -> Self
+	where
+		T: Tabulate,
+		I: IntoIterator<Item = T>,
+	{
+		Self {
+			headers: columns.iter().map(|&column| T::header(column)).collect(),
+			rows:    items.into_iter().map(|item| columns.iter().map(|&column| item.cell(column)).collect()).collect(),
+		}
+	}
+	
+	//		headers																
+	/// Returns the column headers.
+	#[must_use]
+	pub fn headers(&self) -> &[&'static str] {
+		&self.headers
+	}
+	
+	//		rows																
+	/// Returns the cells, by row and then column.
+	#[must_use]
+	pub fn rows(&self) -> &[Vec<String>] {
+		&self.rows
+	}
+	
+	//		len																	
+	/// Returns the number of rows, excluding the header.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.rows.len()
+	}
+	
+	//		is_empty															
+	/// Returns `true` if the table has no rows.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.rows.is_empty()
+	}
+}
+
+//󰭅		Display																	
+impl Display for Table {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let widths: Vec<usize> = self.headers.iter().enumerate().map(|(index, header)| {
+			self.rows.iter()
+				.filter_map(|row| row.get(index))
+				.map(|cell| cell.chars().count())
+				.fold(header.chars().count(), usize::max)
+		}).collect();
+		write_row(f, &widths, self.headers.iter().copied())?;
+		write_row(f, &widths, widths.iter().map(|&width| "-".repeat(width)))?;
+		for row in &self.rows {
+			write_row(f, &widths, row.iter())?;
+		}
+		Ok(())
+	}
+}
+
+
+
+//		Traits
+
+//§		Tabulate																
+/// A type that can be laid out in a [`Table`].
+/// 
+/// This trait is implemented for [`Country`], [`Currency`], and [`Language`].
+/// 
+pub trait Tabulate {
+	/// The type used to select columns.
+	type Column: Copy;
+	
+	//		header																
+	/// Returns the header for a column.
+	fn header(column: Self::Column) -> &'static str;
+	
+	//		cell																
+	/// Returns the contents of a column for the item.
+	fn cell(&self, column: Self::Column) -> String;
+}
+
+//󰭅		Tabulate for Country													
+impl Tabulate for Country {
+	type Column = CountryColumn;
+	
+	//		header																
+	fn header(column: Self::Column) -> &'static str {
+		match column {
+			CountryColumn::Code       => "Code",
+			CountryColumn::Alpha3     => "Alpha3",
+			CountryColumn::Numeric    => "Numeric",
+			CountryColumn::Name       => "Name",
+			CountryColumn::Currencies => "Currencies",
+			CountryColumn::Languages  => "Languages",
+		}
+	}
+	
+	//		cell																
+	fn cell(&self, column: Self::Column) -> String {
+		match column {
+			CountryColumn::Code       => self.code().to_string(),
+			CountryColumn::Alpha3     => self.code().to_alpha3().to_string(),
+			CountryColumn::Numeric    => format!("{:03}", self.code().numeric()),
+			CountryColumn::Name       => self.name().to_owned(),
+			CountryColumn::Currencies => join(self.currencies().iter().map(AsStr::as_str)),
+			CountryColumn::Languages  => join(self.languages().iter().map(AsStr::as_str)),
+		}
+	}
+}
+
+//󰭅		Tabulate for Currency													
+impl Tabulate for Currency {
+	type Column = CurrencyColumn;
+	
+	//		header																
+	fn header(column: Self::Column) -> &'static str {
+		match column {
+			CurrencyColumn::Code      => "Code",
+			CurrencyColumn::Numeric   => "Numeric",
+			CurrencyColumn::Name      => "Name",
+			CurrencyColumn::Digits    => "Digits",
+			CurrencyColumn::Countries => "Countries",
+		}
+	}
+	
+	//		cell																
+	fn cell(&self, column: Self::Column) -> String {
+		match column {
+			CurrencyColumn::Code      => self.code().to_string(),
+			CurrencyColumn::Numeric   => format!("{:03}", self.code().numeric()),
+			CurrencyColumn::Name      => self.name().to_owned(),
+			CurrencyColumn::Digits    => self.digits().to_string(),
+			CurrencyColumn::Countries => join(self.countries().iter().map(AsStr::as_str)),
+		}
+	}
+}
+
+//󰭅		Tabulate for Language													
+impl Tabulate for Language {
+	type Column = LanguageColumn;
+	
+	//		header																
+	fn header(column: Self::Column) -> &'static str {
+		match column {
+			LanguageColumn::Code      => "Code",
+			LanguageColumn::Name      => "Name",
+			LanguageColumn::Countries => "Countries",
+		}
+	}
+	
+	//		cell																
+	fn cell(&self, column: Self::Column) -> String {
+		match column {
+			LanguageColumn::Code      => self.code().to_string(),
+			LanguageColumn::Name      => self.name().to_owned(),
+			LanguageColumn::Countries => join(self.countries().iter().map(AsStr::as_str)),
+		}
+	}
+}
+
+
+
+//		Functions
+
+//		join																	
+/// Joins a list of codes into a comma-separated string.
+fn join<'a, I: Iterator<Item = &'a str>>(codes: I) -> String {
+	codes.collect::<Vec<_>>().join(", ")
+}
+
+//		write_row																
+/// Writes a row of cells, padded to the column widths.
+/// 
+/// Trailing whitespace is removed from the row.
+/// 
+fn write_row<S, I>(f: &mut fmt::Formatter<'_>, widths: &[usize], cells: I) -> fmt::Result
+where
+	S: AsRef<str>,
+	I: Iterator<Item = S>,
+{
+	let line = cells
+		.zip(widths)
+		.map(|(cell, &width)| format!("{:width$}", cell.as_ref()))
+		.collect::<Vec<_>>()
+		.join("  ");
+	writeln!(f, "{}", line.trim_end())
+}
@@ -0,0 +1,360 @@
+//! Tax identifiers.
+//! 
+//! This module provides basic information about the format of the tax
+//! identifiers used to identify businesses in each country, in the form of the
+//! [`TaxIdFormat`] struct, available via [`Country::tax_id_format()`]. This
+//! covers the name of the identifier, the prefix used in international form,
+//! e.g. `DE` for a German VAT identification number, the formats that the
+//! identifier can take, and, where one is standardised, a check digit.
+//! 
+//! This is enough to sanity-check a tax identifier, using
+//! [`Country::validate_tax_id()`], such as when one is entered on an invoice:
+//! 
+//! ```
+//! use isosphere::Country;
+//! 
+//! assert!( Country::GB.validate_tax_id("GB 980 7806 84"));
+//! assert!( Country::DE.validate_tax_id("DE136695976"));
+//! assert!( Country::BR.validate_tax_id("11.222.333/0001-81"));
+//! assert!(!Country::BR.validate_tax_id("11.222.333/0001-82"));
+//! ```
+//! 
+//! Note that this only checks the form of the identifier, and not whether it
+//! has actually been issued. For EU VAT identification numbers, the European
+//! Commission's VIES service can be used to check that.
+//! 
+//! The identifiers covered are the VAT identification numbers of the EU member
+//! states, and the main business tax identifiers of Australia, Brazil, Canada,
+//! India, Norway, Switzerland, the UK, and the US. Other countries return
+//! `None` from [`TaxIdFormat::for_country()`].
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/tax.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	country::{Country, CountryCode},
+	map,
+	text::matches_format,
+};
+
+
+
+//		Constants
+
+/// The formats of the tax identifiers of the countries.
+/// 
+/// The formats are for the identifier with any separators removed, and
+/// without the prefix. Countries without an entry are not covered.
+/// 
+/// # Data sources
+/// 
+/// The formats are based on the list on [Wikipedia](https://en.wikipedia.org/wiki/VAT_identification_number),
+/// and the check digit schemes on the specifications published by the
+/// respective tax authorities.
+/// 
+static FORMATS: [(CountryCode, TaxIdFormat); 35] = [
+	(CountryCode::AT, TaxIdFormat { name: "VAT identification number",      prefix: Some("AT"),  formats: &[ "U########" ],                                                        checksum: None }),
+	(CountryCode::AU, TaxIdFormat { name: "Australian Business Number",     prefix: None,        formats: &[ "###########" ],                                                      checksum: Some(Checksum::Abn) }),
+	(CountryCode::BE, TaxIdFormat { name: "VAT identification number",      prefix: Some("BE"),  formats: &[ "##########" ],                                                       checksum: Some(Checksum::Mod97) }),
+	(CountryCode::BG, TaxIdFormat { name: "VAT identification number",      prefix: Some("BG"),  formats: &[ "#########", "##########" ],                                          checksum: None }),
+	(CountryCode::BR, TaxIdFormat { name: "CNPJ",                           prefix: None,        formats: &[ "************##" ],                                                   checksum: Some(Checksum::Cnpj) }),
+	(CountryCode::CA, TaxIdFormat { name: "Business Number",                prefix: None,        formats: &[ "#########" ],                                                        checksum: None }),
+	(CountryCode::CH, TaxIdFormat { name: "UID",                            prefix: Some("CHE"), formats: &[ "#########", "#########MWST", "#########TVA", "#########IVA" ],       checksum: Some(Checksum::Mod11 { weights: &[ 5, 4, 3, 2, 7, 6, 5, 4 ], complement: true }) }),
+	(CountryCode::CY, TaxIdFormat { name: "VAT identification number",      prefix: Some("CY"),  formats: &[ "########@" ],                                                        checksum: None }),
+	(CountryCode::CZ, TaxIdFormat { name: "VAT identification number",      prefix: Some("CZ"),  formats: &[ "########", "#########", "##########" ],                              checksum: None }),
+	(CountryCode::DE, TaxIdFormat { name: "VAT identification number",      prefix: Some("DE"),  formats: &[ "#########" ],                                                        checksum: Some(Checksum::Mod11Radix10) }),
+	(CountryCode::DK, TaxIdFormat { name: "VAT identification number",      prefix: Some("DK"),  formats: &[ "########" ],                                                         checksum: None }),
+	(CountryCode::EE, TaxIdFormat { name: "VAT identification number",      prefix: Some("EE"),  formats: &[ "#########" ],                                                        checksum: None }),
+	(CountryCode::ES, TaxIdFormat { name: "VAT identification number",      prefix: Some("ES"),  formats: &[ "*#######*" ],                                                        checksum: None }),
+	(CountryCode::FI, TaxIdFormat { name: "VAT identification number",      prefix: Some("FI"),  formats: &[ "########" ],                                                         checksum: None }),
+	(CountryCode::FR, TaxIdFormat { name: "VAT identification number",      prefix: Some("FR"),  formats: &[ "**#########" ],                                                      checksum: Some(Checksum::Siren) }),
+	(CountryCode::GB, TaxIdFormat { name: "VAT registration number",        prefix: Some("GB"),  formats: &[ "#########", "############", "GD###", "HA###" ],                      checksum: Some(Checksum::UkVat) }),
+	(CountryCode::GR, TaxIdFormat { name: "VAT identification number",      prefix: Some("EL"),  formats: &[ "#########" ],                                                        checksum: None }),
+	(CountryCode::HR, TaxIdFormat { name: "VAT identification number",      prefix: Some("HR"),  formats: &[ "###########" ],                                                      checksum: Some(Checksum::Mod11Radix10) }),
+	(CountryCode::HU, TaxIdFormat { name: "VAT identification number",      prefix: Some("HU"),  formats: &[ "########" ],                                                         checksum: None }),
+	(CountryCode::IE, TaxIdFormat { name: "VAT identification number",      prefix: Some("IE"),  formats: &[ "#######@", "#######@@", "#*#####@" ],                                checksum: None }),
+	(CountryCode::IN, TaxIdFormat { name: "GSTIN",                          prefix: None,        formats: &[ "##@@@@@####@*Z*" ],                                                  checksum: None }),
+	(CountryCode::IT, TaxIdFormat { name: "VAT identification number",      prefix: Some("IT"),  formats: &[ "###########" ],                                                      checksum: Some(Checksum::Luhn) }),
+	(CountryCode::LT, TaxIdFormat { name: "VAT identification number",      prefix: Some("LT"),  formats: &[ "#########", "############" ],                                        checksum: None }),
+	(CountryCode::LU, TaxIdFormat { name: "VAT identification number",      prefix: Some("LU"),  formats: &[ "########" ],                                                         checksum: None }),
+	(CountryCode::LV, TaxIdFormat { name: "VAT identification number",      prefix: Some("LV"),  formats: &[ "###########" ],                                                      checksum: None }),
+	(CountryCode::MT, TaxIdFormat { name: "VAT identification number",      prefix: Some("MT"),  formats: &[ "########" ],                                                         checksum: None }),
+	(CountryCode::NL, TaxIdFormat { name: "VAT identification number",      prefix: Some("NL"),  formats: &[ "#########B##" ],                                                     checksum: None }),
+	(CountryCode::NO, TaxIdFormat { name: "Organisation number",            prefix: Some("NO"),  formats: &[ "#########", "#########MVA" ],                                        checksum: Some(Checksum::Mod11 { weights: &[ 3, 2, 7, 6, 5, 4, 3, 2 ], complement: true }) }),
+	(CountryCode::PL, TaxIdFormat { name: "VAT identification number",      prefix: Some("PL"),  formats: &[ "##########" ],                                                       checksum: Some(Checksum::Mod11 { weights: &[ 6, 5, 7, 2, 3, 4, 5, 6, 7 ], complement: false }) }),
+	(CountryCode::PT, TaxIdFormat { name: "VAT identification number",      prefix: Some("PT"),  formats: &[ "#########" ],                                                        checksum: None }),
+	(CountryCode::RO, TaxIdFormat { name: "VAT identification number",      prefix: Some("RO"),  formats: &[ "##", "###", "####", "#####", "######", "#######", "########", "#########", "##########" ], checksum: None }),
+	(CountryCode::SE, TaxIdFormat { name: "VAT identification number",      prefix: Some("SE"),  formats: &[ "##########01" ],                                                     checksum: None }),
+	(CountryCode::SI, TaxIdFormat { name: "VAT identification number",      prefix: Some("SI"),  formats: &[ "########" ],                                                         checksum: None }),
+	(CountryCode::SK, TaxIdFormat { name: "VAT identification number",      prefix: Some("SK"),  formats: &[ "##########" ],                                                       checksum: None }),
+	(CountryCode::US, TaxIdFormat { name: "Employer Identification Number", prefix: None,        formats: &[ "#########" ],                                                        checksum: None }),
+];
+
+
+
+//		Enums
+
+//		Checksum																
+/// The check digit schemes used by tax identifiers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Checksum {
+	/// The Australian Business Number scheme, a weighted sum modulo 89.
+	Abn,
+	
+	/// The Brazilian CNPJ scheme, with two check digits modulo 11. Letters are
+	/// given the value of their ASCII code less 48, so that the alphanumeric
+	/// CNPJs issued from July 2026 are covered as well as the numeric ones.
+	Cnpj,
+	
+	/// The Luhn scheme.
+	Luhn,
+	
+	/// A weighted sum modulo 11, using the given weights. The check digit is
+	/// either the remainder, or its complement from 11, and is invalid if 10.
+	Mod11 {
+		/// The weights of the digits before the check digit.
+		weights:    &'static [u32],
+		
+		/// Whether the check digit is the complement of the remainder.
+		complement: bool,
+	},
+	
+	/// The ISO 7064 MOD 11,10 scheme.
+	Mod11Radix10,
+	
+	/// The Belgian scheme, with two check digits giving the complement of the
+	/// remainder from 97.
+	Mod97,
+	
+	/// The French scheme, with a two-digit key calculated from the SIREN
+	/// number. Keys containing letters are not checked.
+	Siren,
+	
+	/// The UK scheme, a weighted sum modulo 97, with or without an offset of
+	/// 55. Government department and health authority numbers are not checked.
+	UkVat,
+}
+
+//󰭅		Checksum																
+impl Checksum {
+	//		verify																
+	/// Checks whether the check digits of an identifier are correct.
+	/// 
+	/// The identifier must already have been checked against the formats, and
+	/// so contain only the expected characters.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `id` - The identifier, without separators or prefix.
+	/// 
+	fn verify(self, id: &str) -> bool {
+		let digits: Vec<u32> = id.chars().filter_map(|c| c.to_digit(10)).collect();
+		match self {
+			Self::Abn                      => {
+				weighted(&digits, &[ 10, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19 ]).checked_sub(10).is_some_and(|sum| sum % 89 == 0)
+			},
+			Self::Cnpj                     => {
+				let values: Vec<u32> = id.bytes().map(|byte| u32::from(byte).wrapping_sub(48)).collect();
+				let first  = mod11_digit(weighted(&values, &[ 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2 ]));
+				let second = mod11_digit(weighted(&values, &[ 6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2 ]));
+				values.get(12) == Some(&first) && values.get(13) == Some(&second)
+			},
+			Self::Luhn                     => {
+				digits.iter().rev().enumerate().fold(0_u32, |sum, (index, &digit)| {
+					let doubled = if index % 2 == 1 { digit.wrapping_mul(2) } else { digit };
+					sum.wrapping_add(if doubled > 9 { doubled.wrapping_sub(9) } else { doubled })
+				}) % 10 == 0
+			},
+			Self::Mod11 { weights, complement } => {
+				let remainder = weighted(&digits, weights) % 11;
+				let check     = if complement { 11_u32.wrapping_sub(remainder) % 11 } else { remainder };
+				check != 10 && digits.get(weights.len()) == Some(&check)
+			},
+			Self::Mod11Radix10             => {
+				let Some((&last, rest)) = digits.split_last() else {
+					return false;
+				};
+				let product = rest.iter().fold(10_u32, |product, &digit| {
+					let sum = digit.wrapping_add(product) % 10;
+					(if sum == 0 { 10_u32 } else { sum }).wrapping_mul(2) % 11
+				});
+				11_u32.wrapping_sub(product) % 10 == last
+			},
+			Self::Mod97                    => {
+				let number = digits.iter().take(8).fold(0_u32, |number, &digit| number.wrapping_mul(10).wrapping_add(digit));
+				let check  = digits.iter().skip(8).fold(0_u32, |check, &digit| check.wrapping_mul(10).wrapping_add(digit));
+				97_u32.wrapping_sub(number % 97) == check
+			},
+			Self::Siren                    => {
+				let (key, siren) = id.split_at(2);
+				key.parse::<u32>().map_or(true, |expected| {
+					let number = siren.bytes().fold(0_u32, |number, byte| number.wrapping_mul(10).wrapping_add(u32::from(byte).wrapping_sub(48)));
+					12_u32.wrapping_add((number % 97).wrapping_mul(3)) % 97 == expected
+				})
+			},
+			Self::UkVat                    => {
+				if !id.starts_with(|c: char| c.is_ascii_digit()) {
+					return true;
+				}
+				let check = digits.iter().skip(7).take(2).fold(0_u32, |check, &digit| check.wrapping_mul(10).wrapping_add(digit));
+				let sum   = weighted(&digits, &[ 8, 7, 6, 5, 4, 3, 2 ]).wrapping_add(check);
+				sum % 97 == 0 || sum.wrapping_add(55) % 97 == 0
+			},
+		}
+	}
+}
+
+
+
+//		Structs
+
+//		TaxIdFormat																
+/// The format of the tax identifiers of a country.
+/// 
+/// This holds the basic information needed to sanity-check the identifiers
+/// used for businesses for tax purposes, such as VAT identification numbers.
+/// The formats use the same patterns as the postal code formats, in which `#`
+/// represents a digit, `@` represents a letter, `*` represents a letter or
+/// digit, and any other character represents itself. They are for the
+/// identifier as it is after removing any separators and the prefix, e.g.
+/// `#########` for the UK VAT registration number `GB 980 7806 84`.
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TaxIdFormat {
+	//		Private properties													
+	/// The name of the identifier.
+	name:     &'static str,
+	
+	/// The prefix used in international form, if any.
+	prefix:   Option<&'static str>,
+	
+	/// The formats of the identifier, without separators or prefix.
+	formats:  &'static [&'static str],
+	
+	/// The check digit scheme, if any.
+	checksum: Option<Checksum>,
+}
+
+//󰭅		TaxIdFormat																
+impl TaxIdFormat {
+	//		for_country															
+	/// Returns the format of the tax identifiers of a country.
+	/// 
+	/// Countries that are not covered return `None`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `country` - The country to get the tax identifier format for.
+	/// 
+	#[must_use]
+	pub fn for_country(country: Country) -> Option<Self> {
+		map::get(&FORMATS, &country.code()).copied()
+	}
+	
+	//		name																
+	/// Returns the name of the identifier, e.g. `CNPJ` for Brazil.
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		self.name
+	}
+	
+	//		prefix																
+	/// Returns the prefix used in international form, e.g. `DE` for Germany.
+	/// 
+	/// This is usually the country code, but not always, e.g. `EL` for Greece.
+	/// Identifiers that are only used nationally return `None`.
+	/// 
+	#[must_use]
+	pub const fn prefix(&self) -> Option<&'static str> {
+		self.prefix
+	}
+	
+	//		formats																
+	/// Returns the formats of the identifier.
+	/// 
+	/// These are for the identifier without separators or prefix, and are
+	/// given in order of preference.
+	/// 
+	#[must_use]
+	pub const fn formats(&self) -> &'static [&'static str] {
+		self.formats
+	}
+	
+	//		has_checksum														
+	/// Returns whether the identifier has a standardised check digit.
+	#[must_use]
+	pub const fn has_checksum(&self) -> bool {
+		self.checksum.is_some()
+	}
+	
+	//		is_valid															
+	/// Checks whether a tax identifier is valid for the country.
+	/// 
+	/// Whitespace, dots, hyphens, and slashes are ignored, as is the prefix,
+	/// if given, and letters are matched case-insensitively. The remainder must
+	/// match one of the [`formats()`](Self::formats()), and, where there is a
+	/// check digit, it must be correct.
+	/// 
+	/// Note that this only checks the form of the identifier, and not whether
+	/// it has actually been issued.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `id` - The tax identifier to check.
+	/// 
+	#[must_use]
+	pub fn is_valid(&self, id: &str) -> bool {
+		let cleaned: String = id.chars()
+			.filter(|&c| !c.is_whitespace() && !matches!(c, '.' | '-' | '/'))
+			.map(|c| c.to_ascii_uppercase())
+			.collect();
+		let stripped = self.prefix.and_then(|prefix| cleaned.strip_prefix(prefix));
+		let check    = |candidate: &str| {
+			self.formats.iter().any(|format| matches_format(format, candidate))
+				&& self.checksum.is_none_or(|checksum| checksum.verify(candidate))
+		};
+		check(&cleaned) || stripped.is_some_and(check)
+	}
+}
+
+
+
+//		Functions
+
+//		weighted																
+/// Calculates the weighted sum of a sequence of digits.
+/// 
+/// Digits beyond the number of weights are ignored.
+/// 
+/// # Parameters
+/// 
+/// * `digits`  - The digits.
+/// * `weights` - The weights to multiply the digits by.
+/// 
+fn weighted(digits: &[u32], weights: &[u32]) -> u32 {
+	digits.iter().zip(weights).fold(0, |sum, (&digit, &weight)| sum.wrapping_add(digit.wrapping_mul(weight)))
+}
+
+//		mod11_digit																
+/// Calculates a check digit as the complement of a sum modulo 11.
+/// 
+/// Remainders of 0 and 1 give a check digit of 0.
+/// 
+/// # Parameters
+/// 
+/// * `sum` - The weighted sum.
+/// 
+const fn mod11_digit(sum: u32) -> u32 {
+	let remainder = sum % 11;
+	if remainder < 2 { 0 } else { 11_u32.wrapping_sub(remainder) }
+}
@@ -0,0 +1,460 @@
+//! Telephone numbering plans.
+//! 
+//! This module provides basic information about the telephone numbering plan
+//! of each country, in the form of the [`NumberingPlan`] struct, available via
+//! [`Country::numbering_plan()`]. This covers the international calling code,
+//! the trunk prefix used when dialling within the country, the prefix used to
+//! dial out of the country, and the range of lengths of national numbers.
+//! 
+//! This is enough to sanity-check a telephone number, using
+//! [`NumberingPlan::is_plausible()`], but is not a full implementation of
+//! number parsing and validation in the manner of `libphonenumber`. Numbers
+//! that pass the check may still not exist, and the information given is for
+//! the most common forms of number, so some unusual numbers, such as short
+//! codes, will not pass.
+//! 
+//! The data is based on the ITU-T E.164 assignments and national numbering
+//! plans, and is current as of the [`DATA_VERSION`](crate::DATA_VERSION). Where
+//! a country shares a calling code with others, such as the countries of the
+//! North American Numbering Plan, each of them has the shared code.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/telephony.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	country::{Country, CountryCode},
+	map,
+};
+use core::ops::RangeInclusive;
+
+
+
+//		Constants
+
+/// The numbering plans of the countries.
+/// 
+/// Countries without a numbering plan of their own, such as uninhabited
+/// territories, are not included.
+/// 
+static PLANS: [(CountryCode, NumberingPlan); 246] = [
+	(CountryCode::AD, NumberingPlan { calling_code: 376,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (6, 9)   }),
+	(CountryCode::AE, NumberingPlan { calling_code: 971,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::AF, NumberingPlan { calling_code: 93,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::AG, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::AI, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::AL, NumberingPlan { calling_code: 355,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (6, 9)   }),
+	(CountryCode::AM, NumberingPlan { calling_code: 374,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::AO, NumberingPlan { calling_code: 244,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::AQ, NumberingPlan { calling_code: 672,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (6, 6)   }),
+	(CountryCode::AR, NumberingPlan { calling_code: 54,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (10, 11) }),
+	(CountryCode::AS, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::AT, NumberingPlan { calling_code: 43,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (4, 13)  }),
+	(CountryCode::AU, NumberingPlan { calling_code: 61,   trunk_prefix: Some("0"),   international_prefix: "0011", national_number_lengths: (9, 9)   }),
+	(CountryCode::AW, NumberingPlan { calling_code: 297,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::AX, NumberingPlan { calling_code: 358,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (5, 12)  }),
+	(CountryCode::AZ, NumberingPlan { calling_code: 994,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::BA, NumberingPlan { calling_code: 387,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::BB, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::BD, NumberingPlan { calling_code: 880,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (6, 10)  }),
+	(CountryCode::BE, NumberingPlan { calling_code: 32,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::BF, NumberingPlan { calling_code: 226,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::BG, NumberingPlan { calling_code: 359,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (6, 9)   }),
+	(CountryCode::BH, NumberingPlan { calling_code: 973,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::BI, NumberingPlan { calling_code: 257,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::BJ, NumberingPlan { calling_code: 229,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 10)  }),
+	(CountryCode::BL, NumberingPlan { calling_code: 590,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::BM, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::BN, NumberingPlan { calling_code: 673,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::BO, NumberingPlan { calling_code: 591,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::BQ, NumberingPlan { calling_code: 599,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::BR, NumberingPlan { calling_code: 55,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (10, 11) }),
+	(CountryCode::BS, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::BT, NumberingPlan { calling_code: 975,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 8)   }),
+	(CountryCode::BW, NumberingPlan { calling_code: 267,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 8)   }),
+	(CountryCode::BY, NumberingPlan { calling_code: 375,  trunk_prefix: Some("8"),   international_prefix: "810",  national_number_lengths: (9, 9)   }),
+	(CountryCode::BZ, NumberingPlan { calling_code: 501,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::CA, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::CC, NumberingPlan { calling_code: 61,   trunk_prefix: Some("0"),   international_prefix: "0011", national_number_lengths: (9, 9)   }),
+	(CountryCode::CD, NumberingPlan { calling_code: 243,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::CF, NumberingPlan { calling_code: 236,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::CG, NumberingPlan { calling_code: 242,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::CH, NumberingPlan { calling_code: 41,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::CI, NumberingPlan { calling_code: 225,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (10, 10) }),
+	(CountryCode::CK, NumberingPlan { calling_code: 682,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (5, 5)   }),
+	(CountryCode::CL, NumberingPlan { calling_code: 56,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::CM, NumberingPlan { calling_code: 237,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::CN, NumberingPlan { calling_code: 86,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (7, 12)  }),
+	(CountryCode::CO, NumberingPlan { calling_code: 57,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (10, 10) }),
+	(CountryCode::CR, NumberingPlan { calling_code: 506,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::CU, NumberingPlan { calling_code: 53,   trunk_prefix: Some("0"),   international_prefix: "119",  national_number_lengths: (6, 8)   }),
+	(CountryCode::CV, NumberingPlan { calling_code: 238,  trunk_prefix: None,        international_prefix: "0",    national_number_lengths: (7, 7)   }),
+	(CountryCode::CW, NumberingPlan { calling_code: 599,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 8)   }),
+	(CountryCode::CX, NumberingPlan { calling_code: 61,   trunk_prefix: Some("0"),   international_prefix: "0011", national_number_lengths: (9, 9)   }),
+	(CountryCode::CY, NumberingPlan { calling_code: 357,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::CZ, NumberingPlan { calling_code: 420,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::DE, NumberingPlan { calling_code: 49,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (5, 13)  }),
+	(CountryCode::DJ, NumberingPlan { calling_code: 253,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::DK, NumberingPlan { calling_code: 45,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::DM, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::DO, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::DZ, NumberingPlan { calling_code: 213,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::EC, NumberingPlan { calling_code: 593,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::EE, NumberingPlan { calling_code: 372,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 8)   }),
+	(CountryCode::EG, NumberingPlan { calling_code: 20,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 10)  }),
+	(CountryCode::EH, NumberingPlan { calling_code: 212,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::ER, NumberingPlan { calling_code: 291,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::ES, NumberingPlan { calling_code: 34,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::ET, NumberingPlan { calling_code: 251,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::FI, NumberingPlan { calling_code: 358,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (5, 12)  }),
+	(CountryCode::FJ, NumberingPlan { calling_code: 679,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::FK, NumberingPlan { calling_code: 500,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (5, 5)   }),
+	(CountryCode::FM, NumberingPlan { calling_code: 691,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::FO, NumberingPlan { calling_code: 298,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (6, 6)   }),
+	(CountryCode::FR, NumberingPlan { calling_code: 33,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::GA, NumberingPlan { calling_code: 241,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 8)   }),
+	(CountryCode::GB, NumberingPlan { calling_code: 44,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (7, 10)  }),
+	(CountryCode::GD, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::GE, NumberingPlan { calling_code: 995,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::GF, NumberingPlan { calling_code: 594,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::GG, NumberingPlan { calling_code: 44,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (10, 10) }),
+	(CountryCode::GH, NumberingPlan { calling_code: 233,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::GI, NumberingPlan { calling_code: 350,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::GL, NumberingPlan { calling_code: 299,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (6, 6)   }),
+	(CountryCode::GM, NumberingPlan { calling_code: 220,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::GN, NumberingPlan { calling_code: 224,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::GP, NumberingPlan { calling_code: 590,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::GQ, NumberingPlan { calling_code: 240,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::GR, NumberingPlan { calling_code: 30,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (10, 10) }),
+	(CountryCode::GS, NumberingPlan { calling_code: 500,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (5, 5)   }),
+	(CountryCode::GT, NumberingPlan { calling_code: 502,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::GU, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::GW, NumberingPlan { calling_code: 245,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::GY, NumberingPlan { calling_code: 592,  trunk_prefix: None,        international_prefix: "001",  national_number_lengths: (7, 7)   }),
+	(CountryCode::HK, NumberingPlan { calling_code: 852,  trunk_prefix: None,        international_prefix: "001",  national_number_lengths: (8, 8)   }),
+	(CountryCode::HN, NumberingPlan { calling_code: 504,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::HR, NumberingPlan { calling_code: 385,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (6, 9)   }),
+	(CountryCode::HT, NumberingPlan { calling_code: 509,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::HU, NumberingPlan { calling_code: 36,   trunk_prefix: Some("06"),  international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::ID, NumberingPlan { calling_code: 62,   trunk_prefix: Some("0"),   international_prefix: "001",  national_number_lengths: (7, 12)  }),
+	(CountryCode::IE, NumberingPlan { calling_code: 353,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (7, 10)  }),
+	(CountryCode::IL, NumberingPlan { calling_code: 972,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::IM, NumberingPlan { calling_code: 44,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (10, 10) }),
+	(CountryCode::IN, NumberingPlan { calling_code: 91,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (10, 10) }),
+	(CountryCode::IO, NumberingPlan { calling_code: 246,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::IQ, NumberingPlan { calling_code: 964,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 10)  }),
+	(CountryCode::IR, NumberingPlan { calling_code: 98,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (10, 10) }),
+	(CountryCode::IS, NumberingPlan { calling_code: 354,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 9)   }),
+	(CountryCode::IT, NumberingPlan { calling_code: 39,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (6, 11)  }),
+	(CountryCode::JE, NumberingPlan { calling_code: 44,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (10, 10) }),
+	(CountryCode::JM, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::JO, NumberingPlan { calling_code: 962,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::JP, NumberingPlan { calling_code: 81,   trunk_prefix: Some("0"),   international_prefix: "010",  national_number_lengths: (9, 10)  }),
+	(CountryCode::KE, NumberingPlan { calling_code: 254,  trunk_prefix: Some("0"),   international_prefix: "000",  national_number_lengths: (7, 10)  }),
+	(CountryCode::KG, NumberingPlan { calling_code: 996,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::KH, NumberingPlan { calling_code: 855,  trunk_prefix: Some("0"),   international_prefix: "001",  national_number_lengths: (8, 9)   }),
+	(CountryCode::KI, NumberingPlan { calling_code: 686,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (5, 8)   }),
+	(CountryCode::KM, NumberingPlan { calling_code: 269,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::KN, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::KP, NumberingPlan { calling_code: 850,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 10)  }),
+	(CountryCode::KR, NumberingPlan { calling_code: 82,   trunk_prefix: Some("0"),   international_prefix: "001",  national_number_lengths: (8, 10)  }),
+	(CountryCode::KW, NumberingPlan { calling_code: 965,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::KY, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::KZ, NumberingPlan { calling_code: 7,    trunk_prefix: Some("8"),   international_prefix: "810",  national_number_lengths: (10, 10) }),
+	(CountryCode::LA, NumberingPlan { calling_code: 856,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 10)  }),
+	(CountryCode::LB, NumberingPlan { calling_code: 961,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (7, 8)   }),
+	(CountryCode::LC, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::LI, NumberingPlan { calling_code: 423,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 9)   }),
+	(CountryCode::LK, NumberingPlan { calling_code: 94,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::LR, NumberingPlan { calling_code: 231,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (7, 9)   }),
+	(CountryCode::LS, NumberingPlan { calling_code: 266,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::LT, NumberingPlan { calling_code: 370,  trunk_prefix: Some("8"),   international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::LU, NumberingPlan { calling_code: 352,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (4, 11)  }),
+	(CountryCode::LV, NumberingPlan { calling_code: 371,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::LY, NumberingPlan { calling_code: 218,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::MA, NumberingPlan { calling_code: 212,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::MC, NumberingPlan { calling_code: 377,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::MD, NumberingPlan { calling_code: 373,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::ME, NumberingPlan { calling_code: 382,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::MF, NumberingPlan { calling_code: 590,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::MG, NumberingPlan { calling_code: 261,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::MH, NumberingPlan { calling_code: 692,  trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (7, 7)   }),
+	(CountryCode::MK, NumberingPlan { calling_code: 389,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::ML, NumberingPlan { calling_code: 223,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::MM, NumberingPlan { calling_code: 95,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (6, 10)  }),
+	(CountryCode::MN, NumberingPlan { calling_code: 976,  trunk_prefix: Some("0"),   international_prefix: "001",  national_number_lengths: (8, 10)  }),
+	(CountryCode::MO, NumberingPlan { calling_code: 853,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::MP, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::MQ, NumberingPlan { calling_code: 596,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::MR, NumberingPlan { calling_code: 222,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::MS, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::MT, NumberingPlan { calling_code: 356,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::MU, NumberingPlan { calling_code: 230,  trunk_prefix: None,        international_prefix: "020",  national_number_lengths: (7, 8)   }),
+	(CountryCode::MV, NumberingPlan { calling_code: 960,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::MW, NumberingPlan { calling_code: 265,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (7, 9)   }),
+	(CountryCode::MX, NumberingPlan { calling_code: 52,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (10, 10) }),
+	(CountryCode::MY, NumberingPlan { calling_code: 60,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 10)  }),
+	(CountryCode::MZ, NumberingPlan { calling_code: 258,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::NA, NumberingPlan { calling_code: 264,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::NC, NumberingPlan { calling_code: 687,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (6, 6)   }),
+	(CountryCode::NE, NumberingPlan { calling_code: 227,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::NF, NumberingPlan { calling_code: 672,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (6, 6)   }),
+	(CountryCode::NG, NumberingPlan { calling_code: 234,  trunk_prefix: Some("0"),   international_prefix: "009",  national_number_lengths: (8, 10)  }),
+	(CountryCode::NI, NumberingPlan { calling_code: 505,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::NL, NumberingPlan { calling_code: 31,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::NO, NumberingPlan { calling_code: 47,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::NP, NumberingPlan { calling_code: 977,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 10)  }),
+	(CountryCode::NR, NumberingPlan { calling_code: 674,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::NU, NumberingPlan { calling_code: 683,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (4, 7)   }),
+	(CountryCode::NZ, NumberingPlan { calling_code: 64,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 10)  }),
+	(CountryCode::OM, NumberingPlan { calling_code: 968,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::PA, NumberingPlan { calling_code: 507,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 8)   }),
+	(CountryCode::PE, NumberingPlan { calling_code: 51,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::PF, NumberingPlan { calling_code: 689,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::PG, NumberingPlan { calling_code: 675,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 8)   }),
+	(CountryCode::PH, NumberingPlan { calling_code: 63,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 10)  }),
+	(CountryCode::PK, NumberingPlan { calling_code: 92,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 10)  }),
+	(CountryCode::PL, NumberingPlan { calling_code: 48,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::PM, NumberingPlan { calling_code: 508,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (6, 6)   }),
+	(CountryCode::PN, NumberingPlan { calling_code: 64,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::PR, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::PS, NumberingPlan { calling_code: 970,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::PT, NumberingPlan { calling_code: 351,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::PW, NumberingPlan { calling_code: 680,  trunk_prefix: None,        international_prefix: "011",  national_number_lengths: (7, 7)   }),
+	(CountryCode::PY, NumberingPlan { calling_code: 595,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (6, 9)   }),
+	(CountryCode::QA, NumberingPlan { calling_code: 974,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::RE, NumberingPlan { calling_code: 262,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::RO, NumberingPlan { calling_code: 40,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::RS, NumberingPlan { calling_code: 381,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 10)  }),
+	(CountryCode::RU, NumberingPlan { calling_code: 7,    trunk_prefix: Some("8"),   international_prefix: "810",  national_number_lengths: (10, 10) }),
+	(CountryCode::RW, NumberingPlan { calling_code: 250,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::SA, NumberingPlan { calling_code: 966,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::SB, NumberingPlan { calling_code: 677,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (5, 7)   }),
+	(CountryCode::SC, NumberingPlan { calling_code: 248,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::SD, NumberingPlan { calling_code: 249,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::SE, NumberingPlan { calling_code: 46,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (7, 10)  }),
+	(CountryCode::SG, NumberingPlan { calling_code: 65,   trunk_prefix: None,        international_prefix: "001",  national_number_lengths: (8, 8)   }),
+	(CountryCode::SH, NumberingPlan { calling_code: 290,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (4, 5)   }),
+	(CountryCode::SI, NumberingPlan { calling_code: 386,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::SJ, NumberingPlan { calling_code: 47,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::SK, NumberingPlan { calling_code: 421,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::SL, NumberingPlan { calling_code: 232,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::SM, NumberingPlan { calling_code: 378,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (6, 10)  }),
+	(CountryCode::SN, NumberingPlan { calling_code: 221,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::SO, NumberingPlan { calling_code: 252,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (7, 9)   }),
+	(CountryCode::SR, NumberingPlan { calling_code: 597,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (6, 7)   }),
+	(CountryCode::SS, NumberingPlan { calling_code: 211,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::ST, NumberingPlan { calling_code: 239,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 7)   }),
+	(CountryCode::SV, NumberingPlan { calling_code: 503,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::SX, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::SY, NumberingPlan { calling_code: 963,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 9)   }),
+	(CountryCode::SZ, NumberingPlan { calling_code: 268,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::TC, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::TD, NumberingPlan { calling_code: 235,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::TF, NumberingPlan { calling_code: 262,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::TG, NumberingPlan { calling_code: 228,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::TH, NumberingPlan { calling_code: 66,   trunk_prefix: Some("0"),   international_prefix: "001",  national_number_lengths: (8, 9)   }),
+	(CountryCode::TJ, NumberingPlan { calling_code: 992,  trunk_prefix: None,        international_prefix: "810",  national_number_lengths: (9, 9)   }),
+	(CountryCode::TK, NumberingPlan { calling_code: 690,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (4, 7)   }),
+	(CountryCode::TL, NumberingPlan { calling_code: 670,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (7, 8)   }),
+	(CountryCode::TM, NumberingPlan { calling_code: 993,  trunk_prefix: Some("8"),   international_prefix: "810",  national_number_lengths: (8, 8)   }),
+	(CountryCode::TN, NumberingPlan { calling_code: 216,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::TO, NumberingPlan { calling_code: 676,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (5, 7)   }),
+	(CountryCode::TR, NumberingPlan { calling_code: 90,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (10, 10) }),
+	(CountryCode::TT, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::TV, NumberingPlan { calling_code: 688,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (5, 7)   }),
+	(CountryCode::TW, NumberingPlan { calling_code: 886,  trunk_prefix: Some("0"),   international_prefix: "002",  national_number_lengths: (8, 9)   }),
+	(CountryCode::TZ, NumberingPlan { calling_code: 255,  trunk_prefix: Some("0"),   international_prefix: "000",  national_number_lengths: (9, 9)   }),
+	(CountryCode::UA, NumberingPlan { calling_code: 380,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::UG, NumberingPlan { calling_code: 256,  trunk_prefix: Some("0"),   international_prefix: "000",  national_number_lengths: (9, 9)   }),
+	(CountryCode::US, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::UY, NumberingPlan { calling_code: 598,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (8, 8)   }),
+	(CountryCode::UZ, NumberingPlan { calling_code: 998,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::VA, NumberingPlan { calling_code: 39,   trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (6, 11)  }),
+	(CountryCode::VC, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::VE, NumberingPlan { calling_code: 58,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (10, 10) }),
+	(CountryCode::VG, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::VI, NumberingPlan { calling_code: 1,    trunk_prefix: Some("1"),   international_prefix: "011",  national_number_lengths: (10, 10) }),
+	(CountryCode::VN, NumberingPlan { calling_code: 84,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 10)  }),
+	(CountryCode::VU, NumberingPlan { calling_code: 678,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (5, 7)   }),
+	(CountryCode::WF, NumberingPlan { calling_code: 681,  trunk_prefix: None,        international_prefix: "00",   national_number_lengths: (6, 6)   }),
+	(CountryCode::WS, NumberingPlan { calling_code: 685,  trunk_prefix: None,        international_prefix: "0",    national_number_lengths: (5, 7)   }),
+	(CountryCode::YE, NumberingPlan { calling_code: 967,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (7, 9)   }),
+	(CountryCode::YT, NumberingPlan { calling_code: 262,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::ZA, NumberingPlan { calling_code: 27,   trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::ZM, NumberingPlan { calling_code: 260,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (9, 9)   }),
+	(CountryCode::ZW, NumberingPlan { calling_code: 263,  trunk_prefix: Some("0"),   international_prefix: "00",   national_number_lengths: (5, 10)  }),
+];
+
+
+
+//		Structs
+
+//		NumberingPlan															
+/// The telephone numbering plan of a country.
+/// 
+/// This holds the basic information needed to dial and sanity-check telephone
+/// numbers in a country. The national number is the number without the
+/// calling code or the trunk prefix, e.g. `20 7946 0018` for the UK number
+/// `+44 20 7946 0018`, which is dialled as `020 7946 0018` within the UK.
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NumberingPlan {
+	//		Private properties													
+	/// The international calling code.
+	calling_code:            u16,
+	
+	/// The trunk prefix, if any.
+	trunk_prefix:            Option<&'static str>,
+	
+	/// The international prefix.
+	international_prefix:    &'static str,
+	
+	/// The minimum and maximum lengths of national numbers.
+	national_number_lengths: (u8, u8),
+}
+
+//󰭅		NumberingPlan															
+impl NumberingPlan {
+	//		for_country															
+	/// Returns the numbering plan of a country.
+	/// 
+	/// Countries without a numbering plan of their own, such as Bouvet Island,
+	/// return `None`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `country` - The country to get the numbering plan for.
+	/// 
+	#[must_use]
+	pub fn for_country(country: Country) -> Option<Self> {
+		map::get(&PLANS, &country.code()).copied()
+	}
+	
+	//		calling_code														
+	/// Returns the international calling code, e.g. `44` for the UK.
+	#[must_use]
+	pub const fn calling_code(&self) -> u16 {
+		self.calling_code
+	}
+	
+	//		trunk_prefix														
+	/// Returns the trunk prefix, e.g. `0` for the UK.
+	/// 
+	/// This is the prefix dialled before a national number when calling from
+	/// within the country. Countries that do not use a trunk prefix return
+	/// `None`.
+	/// 
+	#[must_use]
+	pub const fn trunk_prefix(&self) -> Option<&'static str> {
+		self.trunk_prefix
+	}
+	
+	//		international_prefix												
+	/// Returns the international prefix, e.g. `00` for the UK.
+	/// 
+	/// This is the prefix dialled before a calling code when calling another
+	/// country. Where there is a choice of prefix, the most common is given.
+	/// 
+	#[must_use]
+	pub const fn international_prefix(&self) -> &'static str {
+		self.international_prefix
+	}
+	
+	//		national_number_lengths												
+	/// Returns the range of lengths of national numbers, in digits.
+	#[must_use]
+	pub const fn national_number_lengths(&self) -> RangeInclusive<u8> {
+		self.national_number_lengths.0..=self.national_number_lengths.1
+	}
+	
+	//		is_plausible														
+	/// Checks whether a telephone number is plausible for the country.
+	/// 
+	/// The number may be given in international form, starting with `+` or the
+	/// international prefix followed by the calling code, or in national form,
+	/// optionally starting with the trunk prefix. A trunk prefix in brackets
+	/// after the calling code, as in `+44 (0)20 7946 0018`, is also accepted.
+	/// Spaces, hyphens, dots, and parentheses are ignored, and the remaining
+	/// national number must be made up of digits, with a length in the range
+	/// given by
+	/// [`national_number_lengths()`](Self::national_number_lengths()).
+	/// 
+	/// Note that this only checks the form of the number, and not whether it
+	/// actually exists.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `number` - The telephone number to check.
+	/// 
+	#[must_use]
+	pub fn is_plausible(&self, number: &str) -> bool {
+		let calling_code      = self.calling_code.to_string();
+		let mut digits        = String::with_capacity(number.len());
+		let mut international = false;
+		let mut bracket       = None;
+		for (index, c) in number.trim().char_indices() {
+			match c {
+				'0'..='9'         => digits.push(c),
+				'+' if index == 0 => international = true,
+				'('               => bracket = Some(digits.len()),
+				')'               => {
+					//	A trunk prefix in brackets straight after the calling code, as in
+					//	"+44 (0)20", is only dialled from within the country, and so is
+					//	dropped.
+					if let Some((before, within)) = bracket.take().and_then(|start| digits.split_at_checked(start)) {
+						let after_code = if international {
+							before == calling_code
+						} else {
+							before.strip_prefix(self.international_prefix) == Some(calling_code.as_str())
+						};
+						if after_code && Some(within) == self.trunk_prefix {
+							digits.truncate(before.len());
+						}
+					}
+				},
+				' ' | '-' | '.'   => {},
+				_                 => return false,
+			}
+		}
+		let national = if international {
+			digits.strip_prefix(calling_code.as_str())
+		} else if let Some(rest) = digits.strip_prefix(self.international_prefix) {
+			rest.strip_prefix(calling_code.as_str())
+		} else {
+			Some(self.trunk_prefix.and_then(|prefix| digits.strip_prefix(prefix)).unwrap_or(&digits))
+		};
+		national.is_some_and(|rest| {
+			(usize::from(self.national_number_lengths.0)..=usize::from(self.national_number_lengths.1)).contains(&rest.len())
+		})
+	}
+}
+
+
+
+//		Functions
+
+//		countries_with_calling_code												
+/// Returns the countries that use an international calling code.
+/// 
+/// Calling codes can be shared by several countries, e.g. `1` is used by the
+/// countries of the North American Numbering Plan. An unassigned calling code
+/// returns an empty list.
+/// 
+/// # Parameters
+/// 
+/// * `calling_code` - The international calling code, e.g. `44`.
+/// 
+#[must_use]
+pub fn countries_with_calling_code(calling_code: u16) -> Vec<Country> {
+	PLANS.iter().filter(|&&(_, plan)| plan.calling_code == calling_code).map(|&(code, _)| code.country()).collect()
+}
@@ -0,0 +1,236 @@
+//! Test utilities.
+//! 
+//! This module provides helpers for asserting that the types in this crate
+//! round-trip correctly between their various representations, i.e. string,
+//! numeric, and serialised forms, and between alpha2 and alpha3 country codes.
+//! 
+//! The helpers are generic, so they can be applied to any of the crate's types,
+//! and an exhaustive check across all of the datasets is also provided. This
+//! allows downstream crates that embed the types in their own models to reuse
+//! the same harness in their own tests.
+//! 
+//! It also provides the corpus of inputs used by the crate's own benchmarks,
+//! via [`benchmark_codes()`] and [`benchmark_inputs()`], so that downstream
+//! crates can benchmark their integration against the same data.
+//! 
+//! This module is only available when the `test-util` feature is enabled.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/test_util.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	country::{Country, CountryCode},
+	currency::{Currency, CurrencyCode},
+	language::{Language, LanguageCode},
+};
+use core::{
+	fmt::Debug,
+	str::FromStr,
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+
+
+//		Constants
+
+/// Invalid inputs included in the benchmark corpus.
+/// 
+/// These resemble the mistakes seen in real requests, such as empty values,
+/// unknown codes, stray whitespace, and made-up names.
+/// 
+const INVALID_INPUTS: [&str; 10] = [
+	"",
+	" ",
+	"XX",
+	"XXX",
+	"G B",
+	" GB",
+	"826",
+	"Atlantis",
+	"Imaginary dollar",
+	"Klingon",
+];
+
+
+
+//		Functions
+
+//		assert_string_round_trip												
+/// Asserts that a value round-trips through its string representation.
+/// 
+/// The value is converted into a [`String`], and then parsed back using
+/// [`FromStr`], and the result is expected to equal the original value.
+/// 
+/// # Panics
+/// 
+/// Panics if the value does not round-trip correctly.
+/// 
+pub fn assert_string_round_trip<T>(value: T)
+where
+	T:                   Copy + Debug + FromStr + Into<String> + PartialEq,
+	<T as FromStr>::Err: Debug,
+{
+	let string: String = value.into();
+	let parsed          = string.parse::<T>();
+	assert_eq!(parsed.ok(), Some(value), "String round-trip failed for {value:?} via {string:?}");
+}
+
+//		assert_numeric_round_trip												
+/// Asserts that a value round-trips through its numeric representation.
+/// 
+/// The value is converted into a [`u16`], and then converted back using
+/// [`TryFrom`], and the numeric representation of the result is expected to
+/// equal the original number. Note that the result is not required to be the
+/// same variant, as e.g. both the alpha2 and alpha3 forms of a [`CountryCode`]
+/// share the same numeric code.
+/// 
+/// # Panics
+/// 
+/// Panics if the value does not round-trip correctly.
+/// 
+pub fn assert_numeric_round_trip<T>(value: T)
+where
+	T:                          Copy + Debug + Into<u16> + TryFrom<u16>,
+	<T as TryFrom<u16>>::Error: Debug,
+{
+	let number: u16 = value.into();
+	let converted   = T::try_from(number).ok().map(Into::into);
+	assert_eq!(converted, Some(number), "Numeric round-trip failed for {value:?} via {number}");
+}
+
+//		assert_serde_round_trip													
+/// Asserts that a value round-trips through serialisation.
+/// 
+/// The value is serialised to JSON, and then deserialised back, and the result
+/// is expected to equal the original value.
+/// 
+/// # Panics
+/// 
+/// Panics if the value does not round-trip correctly.
+/// 
+pub fn assert_serde_round_trip<T>(value: T)
+where
+	T: Copy + Debug + DeserializeOwned + PartialEq + Serialize,
+{
+	let serialised = serde_json::to_string(&value);
+	assert!(serialised.is_ok(), "Serialisation failed for {value:?}");
+	let parsed     = serialised.ok().and_then(|json| serde_json::from_str::<T>(&json).ok());
+	assert_eq!(parsed, Some(value), "Serde round-trip failed for {value:?}");
+}
+
+//		assert_alpha_round_trip													
+/// Asserts that a country code round-trips between alpha2 and alpha3 forms.
+/// 
+/// # Panics
+/// 
+/// Panics if the code does not round-trip correctly.
+/// 
+pub fn assert_alpha_round_trip(code: CountryCode) {
+	let alpha2 = code.to_alpha2();
+	let alpha3 = code.to_alpha3();
+	assert!(alpha2.is_alpha2(),                    "Not an alpha2 code: {alpha2:?}");
+	assert!(alpha3.is_alpha3(),                    "Not an alpha3 code: {alpha3:?}");
+	assert_eq!(alpha2.to_alpha3(), alpha3,         "Alpha2 to alpha3 round-trip failed for {code:?}");
+	assert_eq!(alpha3.to_alpha2(), alpha2,         "Alpha3 to alpha2 round-trip failed for {code:?}");
+	assert_eq!(alpha2.country(), alpha3.country(), "Alpha codes differ in country for {code:?}");
+}
+
+//		assert_all_round_trips													
+/// Asserts that every value in every dataset round-trips correctly.
+/// 
+/// This runs all of the applicable round-trip checks against every country,
+/// currency, and language, and their codes.
+/// 
+/// # Panics
+/// 
+/// Panics if any value does not round-trip correctly.
+/// 
+pub fn assert_all_round_trips() {
+	for country in Country::all() {
+		assert_string_round_trip(country);
+		assert_numeric_round_trip(country.code());
+		assert_serde_round_trip(country);
+	}
+	for code in CountryCode::all() {
+		for alpha in [code.to_alpha2(), code.to_alpha3()] {
+			assert_string_round_trip(alpha);
+			assert_numeric_round_trip(alpha);
+			assert_serde_round_trip(alpha);
+			assert_alpha_round_trip(alpha);
+		}
+	}
+	for currency in Currency::all() {
+		assert_string_round_trip(currency);
+		assert_serde_round_trip(currency);
+	}
+	for code in CurrencyCode::all() {
+		assert_string_round_trip(code);
+		assert_numeric_round_trip(code);
+		assert_serde_round_trip(code);
+	}
+	for language in Language::all() {
+		assert_string_round_trip(language);
+		assert_serde_round_trip(language);
+	}
+	for code in LanguageCode::all() {
+		assert_string_round_trip(code);
+		assert_serde_round_trip(code);
+	}
+}
+
+//		benchmark_codes															
+/// Returns every code in every dataset, in string form.
+/// 
+/// This includes both the alpha2 and alpha3 forms of each country code, and
+/// each currency and language code, in their canonical case. The order is
+/// fixed, so that results are comparable between runs.
+/// 
+#[must_use]
+pub fn benchmark_codes() -> Vec<String> {
+	CountryCode::all().into_iter()
+		.flat_map(|code| [code.to_alpha2(), code.to_alpha3()])
+		.map(|code| code.as_str().to_owned())
+		.chain(CurrencyCode::all().into_iter().map(|code| code.as_str().to_owned()))
+		.chain(LanguageCode::all().into_iter().map(|code| code.as_str().to_owned()))
+		.collect()
+}
+
+//		benchmark_inputs														
+/// Returns a realistic mix of inputs for parsing.
+/// 
+/// This is intended to resemble the values seen in real requests, and contains
+/// the codes from [`benchmark_codes()`] in upper, lower, and mixed case, along
+/// with the names of every country, currency, and language, and a proportion
+/// of invalid values. The order is fixed, so that results are comparable
+/// between runs.
+/// 
+#[must_use]
+pub fn benchmark_inputs() -> Vec<String> {
+	benchmark_codes().into_iter()
+		.flat_map(|code| [code.to_uppercase(), code.to_lowercase(), mixed_case(&code)])
+		.chain(Country::all().into_iter().map(|country| country.short_name().to_owned()))
+		.chain(Currency::all().into_iter().map(|currency| currency.name().to_owned()))
+		.chain(Language::all().into_iter().map(|language| language.name().to_owned()))
+		.chain(INVALID_INPUTS.into_iter().map(ToOwned::to_owned))
+		.collect()
+}
+
+//		mixed_case																
+/// Alternates the case of the letters in a piece of text.
+fn mixed_case(text: &str) -> String {
+	text.chars()
+		.enumerate()
+		.flat_map(|(index, c)| if index % 2 == 0 { c.to_uppercase().collect::<Vec<_>>() } else { c.to_lowercase().collect() })
+		.collect()
+}
@@ -0,0 +1,71 @@
+//		Tests
+
+//		Constants
+#[cfg(test)]
+mod constants {
+	use super::super::*;
+	use crate::AsStr;
+	
+	//		nationality_marks													
+	#[test]
+	fn nationality_marks() {
+		assert!(NATIONALITY_MARKS.windows(2).all(|pair| matches!(pair, [a, b] if a.0.as_str() < b.0.as_str())));
+		assert!(NATIONALITY_MARKS.iter().all(|entry| entry.0.is_alpha2()));
+		assert!(NATIONALITY_MARKS.iter().all(|entry| entry.1.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'-')));
+	}
+}
+
+//		Functions
+#[cfg(test)]
+mod functions {
+	use super::super::*;
+	
+	//		nationality_mark													
+	#[test]
+	fn nationality_mark() {
+		assert_eq!(super::super::nationality_mark(Country::US), Some("N"));
+		assert_eq!(super::super::nationality_mark(Country::RW), Some("9XR"));
+		assert_eq!(super::super::nationality_mark(Country::BV), None);
+	}
+	
+	//		countries_with_nationality_mark										
+	#[test]
+	fn countries_with_nationality_mark() {
+		assert_eq!(super::super::countries_with_nationality_mark("VH"),   vec![ Country::AU ]);
+		assert_eq!(super::super::countries_with_nationality_mark("vp-c"), vec![ Country::KY ]);
+		assert_eq!(super::super::countries_with_nationality_mark("B"),    vec![ Country::CN, Country::TW ]);
+		assert!(super::super::countries_with_nationality_mark("QQ").is_empty());
+	}
+	
+	//		country_for_registration											
+	#[test]
+	fn country_for_registration() {
+		assert_eq!(super::super::country_for_registration("G-EUPT"),   Some(Country::GB));
+		assert_eq!(super::super::country_for_registration("N12345"),   Some(Country::US));
+		assert_eq!(super::super::country_for_registration("JA8089"),   Some(Country::JP));
+		assert_eq!(super::super::country_for_registration("C-GKWJ"),   Some(Country::CA));
+		assert_eq!(super::super::country_for_registration("CC-AWA"),   Some(Country::CL));
+		assert_eq!(super::super::country_for_registration("D-AIMA"),   Some(Country::DE));
+		assert_eq!(super::super::country_for_registration("D2-TEE"),   Some(Country::AO));
+		assert_eq!(super::super::country_for_registration("ZS-SNA"),   Some(Country::ZA));
+		assert_eq!(super::super::country_for_registration("Z-WPF"),    Some(Country::ZW));
+		assert_eq!(super::super::country_for_registration("B-HNR"),    Some(Country::HK));
+		assert_eq!(super::super::country_for_registration("vp-cal"),   Some(Country::KY));
+		assert_eq!(super::super::country_for_registration(" 9V-SKA "), Some(Country::SG));
+		assert_eq!(super::super::country_for_registration("B-1234"),   None);
+		assert_eq!(super::super::country_for_registration("QQ-ABC"),   None);
+		assert_eq!(super::super::country_for_registration(""),         None);
+	}
+	#[test]
+	fn country_for_registration__all() {
+		for country in Country::all() {
+			let Some(mark) = country.nationality_mark() else {
+				continue;
+			};
+			if mark == "B" {
+				continue;
+			}
+			assert_eq!(super::super::country_for_registration(&format!("{mark}-ABC")), Some(country), "{country:?}");
+		}
+	}
+}
@@ -1,9 +1,44 @@
 //		Tests
 
+//		Constants
+#[cfg(test)]
+mod constants {
+	use super::super::*;
+	
+	//		count																
+	#[test]
+	fn count() {
+		assert_eq!(COUNT, Country::all().len());
+		assert_eq!(COUNT, CountryCode::all().len());
+	}
+	
+	//		alpha2_codes														
+	#[test]
+	fn alpha2_codes() {
+		let codes: Vec<&str> = Country::all().iter().map(|country| country.code().as_str()).collect();
+		assert_eq!(ALPHA2_CODES, codes.as_slice());
+	}
+	
+	//		alpha3_codes														
+	#[test]
+	fn alpha3_codes() {
+		let codes: Vec<&str> = Country::all().iter().map(|country| country.code().to_alpha3().as_str()).collect();
+		assert_eq!(ALPHA3_CODES, codes.as_slice());
+	}
+	
+	//		numeric_codes														
+	#[test]
+	fn numeric_codes() {
+		let codes: Vec<u16> = Country::all().iter().map(|country| country.code().numeric()).collect();
+		assert_eq!(NUMERIC_CODES, codes.as_slice());
+	}
+}
+
 //		CountryCode																
 #[cfg(test)]
 mod country_code__enum {
 	use super::super::*;
+	use rubedo::sugar::s;
 	
 	//		all																	
 	#[test]
@@ -15,6 +50,70 @@ mod country_code__enum {
 		assert!(codes.contains(&CountryCode::GB));
 	}
 	
+	//		complete															
+	#[test]
+	fn complete() {
+		assert_eq!(CountryCode::complete("GR"),  vec![ CountryCode::GR, CountryCode::GRC, CountryCode::GRD, CountryCode::GRL ]);
+		assert_eq!(CountryCode::complete("gr"),  vec![ CountryCode::GR, CountryCode::GRC, CountryCode::GRD, CountryCode::GRL ]);
+		assert_eq!(CountryCode::complete("GBR"), vec![ CountryCode::GBR ]);
+		assert_eq!(CountryCode::complete("").len(), 498);
+		assert!(CountryCode::complete("GBRX").is_empty());
+		assert!(CountryCode::complete("Q").iter().all(|code| code.as_str().starts_with('Q')));
+	}
+	
+	//		from_code															
+	#[test]
+	fn from_code() {
+		assert_eq!(CountryCode::from_code("US"),  Some(CountryCode::US));
+		assert_eq!(CountryCode::from_code("us"),  Some(CountryCode::US));
+		assert_eq!(CountryCode::from_code("USA"), Some(CountryCode::USA));
+		assert_eq!(CountryCode::from_code("usa"), Some(CountryCode::USA));
+		assert_eq!(CountryCode::from_code("FOO"), None);
+		assert_eq!(CountryCode::from_code("U"),   None);
+		assert_eq!(CountryCode::from_code(""),    None);
+	}
+	#[test]
+	fn from_code__all() {
+		for code in CountryCode::all() {
+			assert_eq!(CountryCode::from_code(code.to_alpha2().as_str()), Some(code.to_alpha2()));
+			assert_eq!(CountryCode::from_code(code.to_alpha3().as_str()), Some(code.to_alpha3()));
+		}
+	}
+	
+	//		looks_valid															
+	#[test]
+	fn looks_valid() {
+		assert!( CountryCode::looks_valid("GB"));
+		assert!( CountryCode::looks_valid("GBR"));
+		assert!( CountryCode::looks_valid("XX"));
+		assert!(!CountryCode::looks_valid("gb"));
+		assert!(!CountryCode::looks_valid("G"));
+		assert!(!CountryCode::looks_valid("GBRX"));
+		assert!(!CountryCode::looks_valid("G1"));
+		assert!(!CountryCode::looks_valid(""));
+		assert!(!CountryCode::looks_valid("ÉU"));
+	}
+	
+	//		parse_all															
+	#[test]
+	fn parse_all() {
+		assert_eq!(CountryCode::parse_all([ "US", "gbr", "fr" ]), Ok(vec![ CountryCode::US, CountryCode::GBR, CountryCode::FR ]));
+		assert_eq!(CountryCode::parse_all(Vec::<&str>::new()),    Ok(vec![]));
+		assert_eq!(CountryCode::parse_all([ "US", "XX", "YY" ]),  Err((1, s!("Invalid CountryCode: XX"))));
+	}
+	
+	//		const_from_str														
+	#[test]
+	fn const_from_str() {
+		const CODES: [CountryCode; 2] = [CountryCode::const_from_str("us"), CountryCode::const_from_str("GBR")];
+		assert_eq!(CODES, [CountryCode::US, CountryCode::GBR]);
+	}
+	#[test]
+	#[should_panic(expected = "Invalid CountryCode")]
+	fn const_from_str__invalid() {
+		let _code = CountryCode::const_from_str("FOO");
+	}
+	
 	//		country																
 	#[test]
 	fn country() {
@@ -28,9 +127,8 @@ mod country_code__enum {
 	}
 	#[test]
 	fn country__all() {
-		#[expect(clippy::iter_over_hash_type, reason = "Order is not important here")]
-		for country in COUNTRIES.keys() {
-			assert_eq!(country.code().country(), *country);
+		for country in Country::all() {
+			assert_eq!(country.code().country(), country);
 		}
 	}
 	
@@ -48,6 +146,24 @@ mod country_code__enum {
 		assert!( CountryCode::USA.is_alpha3());
 	}
 	
+	//		numeric																
+	#[test]
+	fn numeric() {
+		assert_eq!(CountryCode::US .numeric(), 840);
+		assert_eq!(CountryCode::USA.numeric(), 840);
+		assert_eq!(CountryCode::AF .numeric(), 4);
+		assert_eq!(CountryCode::AFG.numeric(), 4);
+	}
+	#[test]
+	fn numeric__all() {
+		for code in CountryCode::all() {
+			assert!(code.numeric() < 1_000);
+			assert_eq!(code.numeric(), u16::from(code));
+			assert_eq!(code.to_alpha2().numeric(), code.to_alpha3().numeric());
+			assert_eq!(CountryCode::try_from(code.numeric()).unwrap(), code.to_alpha2());
+		}
+	}
+	
 	//		to_alpha2															
 	#[test]
 	fn to_alpha2() {
@@ -61,13 +177,30 @@ mod country_code__enum {
 		assert_eq!(CountryCode::US .to_alpha3(), CountryCode::USA);
 		assert_eq!(CountryCode::USA.to_alpha3(), CountryCode::USA);
 	}
+	
+	//		status																
+	#[test]
+	fn status() {
+		assert_eq!(CountryCode::GB .status(), CodeStatus::OfficiallyAssigned);
+		assert_eq!(CountryCode::GBR.status(), CodeStatus::OfficiallyAssigned);
+	}
+	
+	//		stable_hash															
+	#[test]
+	fn stable_hash() {
+		assert_eq!(CountryCode::GB.stable_hash(),  0x0902_1407_b59b_fa0c);
+		assert_eq!(CountryCode::GBR.stable_hash(), CountryCode::GB.stable_hash());
+		assert_ne!(CountryCode::FR.stable_hash(),  CountryCode::GB.stable_hash());
+	}
 }
 
 #[cfg(test)]
 mod country_code__traits {
 	use super::super::*;
 	use claims::assert_err;
+	use rubedo::sugar::s;
 	use serde_json;
+	use serde_test::{Compact, Configure, Token, assert_de_tokens, assert_de_tokens_error, assert_ser_tokens};
 	
 	//		as_str																
 	#[test]
@@ -96,6 +229,12 @@ mod country_code__traits {
 		let code4: CountryCode = serde_json::from_str(r#""usa""#).unwrap();
 		assert_eq!(code4, CountryCode::USA);
 	}
+	#[test]
+	fn deserialize__compact() {
+		assert_de_tokens(&CountryCode::US.compact(), &[ Token::U16(840) ]);
+		assert_de_tokens_error::<Compact<CountryCode>>(&[ Token::U16(1) ], "Invalid CountryCode: 1");
+		assert_de_tokens_error::<Compact<CountryCode>>(&[ Token::Str("US") ], "invalid type: string \"US\", expected u16");
+	}
 	
 	//		display																
 	#[test]
@@ -159,6 +298,25 @@ mod country_code__traits {
 		assert_err!(&err);
 		assert_eq!(err.unwrap_err(), "Invalid CountryCode: FOO");
 	}
+	#[test]
+	fn from_str__mixed_case() {
+		assert_eq!(CountryCode::from_str("gBr").unwrap(), CountryCode::GBR);
+		assert_err!(CountryCode::from_str("ＧＢ"));
+	}
+	#[test]
+	fn from_str__whitespace() {
+		assert_err!(CountryCode::from_str(" GB\t"));
+		assert_err!(CountryCode::from_str("GBR\u{a0}"));
+		assert_eq!(Country::parse_with(" GB\t", &ParseOptions::strict().trim(true)).unwrap(), Country::GB);
+	}
+	
+	//		ord																	
+	#[test]
+	fn ord() {
+		assert!(CountryCode::FR < CountryCode::GB);
+		assert!(CountryCode::GB < CountryCode::GBR);
+		assert!(CountryCode::ZA > CountryCode::USA);
+	}
 	
 	//		serialize															
 	#[test]
@@ -166,6 +324,12 @@ mod country_code__traits {
 		assert_eq!(serde_json::to_string(&CountryCode::US) .unwrap(), r#""US""#);
 		assert_eq!(serde_json::to_string(&CountryCode::USA).unwrap(), r#""USA""#);
 	}
+	#[test]
+	fn serialize__compact() {
+		assert_ser_tokens(&CountryCode::US.compact(),  &[ Token::U16(840) ]);
+		assert_ser_tokens(&CountryCode::USA.compact(), &[ Token::U16(840) ]);
+		assert_ser_tokens(&CountryCode::US.readable(), &[ Token::Str("US") ]);
+	}
 	
 	//		try_from															
 	#[test]
@@ -206,6 +370,97 @@ mod country__enum {
 		assert!(countries.contains(&Country::GB));
 	}
 	
+	//		discriminants														
+	#[test]
+	fn discriminants() {
+		//	These values are frozen, and must never change. New variants must be
+		//	added to the end of this list.
+		const FROZEN: [&str; 249] = [
+			"AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+			"BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+			"BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+			"CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+			"EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+			"GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+			"HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+			"JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+			"LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+			"ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+			"NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+			"PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+			"SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+			"ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+			"TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+			"VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+		];
+		for (index, code) in FROZEN.iter().enumerate() {
+			assert_eq!(CountryCode::from_str(code).unwrap().country() as usize, index);
+		}
+		assert_eq!(Country::AD as u16, 0);
+		assert_eq!(Country::ZW as u16, 248);
+		assert!(Country::AD < Country::ZW);
+	}
+	
+	//		changes_since														
+	#[test]
+	fn changes_since() {
+		assert_eq!(Country::changes_since("2022-01-01"),        vec![ Country::HR, Country::SL, Country::TR ]);
+		assert_eq!(Country::changes_since("2022-07-01"),        vec![ Country::HR ]);
+		assert_eq!(Country::changes_since(crate::DATA_VERSION), vec![]);
+		assert_eq!(Country::changes_since("").len(),            CHANGES.len());
+	}
+	
+	//		records																
+	#[test]
+	fn records() {
+		let records: Vec<_> = Country::records().collect();
+		assert_eq!(records.len(), 249);
+		assert_eq!(Country::records().len(), COUNT);
+		for (record, country) in records.iter().zip(Country::all()) {
+			assert_eq!(record.country, country);
+			assert_eq!(record.code,    country.code());
+		}
+	}
+	
+	//		record																
+	#[test]
+	fn record() {
+		let record = Country::CH.record();
+		assert_eq!(record.country,      Country::CH);
+		assert_eq!(record.code,         CountryCode::CH);
+		assert_eq!(record.alpha3,       CountryCode::CHE);
+		assert_eq!(record.numeric,      756);
+		assert_eq!(record.name,         "Switzerland");
+		assert_eq!(record.vehicle_code, Some("CH"));
+		assert_eq!(record.first_day,    Weekday::Monday);
+		assert_eq!(record.weekend,      &[ Weekday::Saturday, Weekday::Sunday ]);
+		assert_eq!(record.national_day, MonthDay::new(8, 1));
+		assert_eq!(record.postal_codes, &[ "####" ]);
+		assert_eq!(record.driving_side, DrivingSide::Right);
+		assert_eq!(record.measurement,  MeasurementSystem::Metric);
+		assert!(record.observes_dst);
+		assert_eq!(record.un_status,    UnStatus::Member);
+		assert_eq!(record.region,       Region::Europe);
+		assert_eq!(record.currencies,   Country::CH.currencies());
+		assert_eq!(record.languages,    Country::CH.languages());
+	}
+	
+	//		summary																
+	#[test]
+	fn summary() {
+		let summary = Country::FR.summary();
+		assert_eq!(summary.code,    CountryCode::FR);
+		assert_eq!(summary.alpha3,  CountryCode::FRA);
+		assert_eq!(summary.numeric, 250);
+		assert_eq!(summary.name,    "France");
+		assert_eq!(serde_json::to_value(summary).unwrap(), serde_json::json!({
+			"code":    "FR",
+			"alpha3":  "FRA",
+			"numeric": 250,
+			"name":    "France",
+		}));
+	}
+	
 	//		info																
 	#[test]
 	fn info() {
@@ -220,41 +475,672 @@ mod country__enum {
 		assert_eq!(Country::CH.name(), "Switzerland");
 	}
 	
+	//		names_of															
+	#[test]
+	fn names_of() {
+		assert_eq!(Country::names_of(&[ CountryCode::CH, CountryCode::FRA ]), vec![ "Switzerland", "France" ]);
+		assert!(Country::names_of(&[]).is_empty());
+	}
+	
+	//		ascii_name															
+	#[test]
+	fn ascii_name() {
+		assert_eq!(Country::AX.ascii_name(), "Aland Islands");
+		assert_eq!(Country::CI.ascii_name(), "Cote d'Ivoire");
+		assert_eq!(Country::TR.ascii_name(), "Turkiye");
+		assert_eq!(Country::CH.ascii_name(), "Switzerland");
+	}
+	#[test]
+	fn ascii_name__all() {
+		for country in Country::all() {
+			assert!(country.ascii_name().is_ascii(), "{country:?}");
+			assert_eq!(normalize(country.ascii_name()), normalize(country.info().name), "{country:?}");
+			assert_eq!(country.ascii_name().len(), country.info().name.chars().count(), "{country:?}");
+		}
+		assert!(ASCII_NAMES.windows(2).all(|pair| matches!(pair, [a, b] if a.0.as_str() < b.0.as_str())));
+		assert!(ASCII_NAMES.iter().all(|&(code, _)| !code.country().info().name.is_ascii()));
+	}
+	
+	//		postal_name															
+	#[test]
+	fn postal_name() {
+		assert_eq!(Country::GB.postal_name(), "UNITED KINGDOM");
+		assert_eq!(Country::US.postal_name(), "UNITED STATES");
+		assert_eq!(Country::CI.postal_name(), "COTE D'IVOIRE");
+		assert_eq!(Country::KR.postal_name(), "SOUTH KOREA");
+		assert_eq!(Country::DE.postal_name(), "GERMANY");
+	}
+	#[test]
+	fn postal_name__all() {
+		for country in Country::all() {
+			let name = country.postal_name();
+			assert!(!name.is_empty(), "{country:?}");
+			assert!(name.is_ascii(), "{country:?}");
+			assert_eq!(name, name.to_uppercase(), "{country:?}");
+		}
+		assert!(POSTAL_NAMES.windows(2).all(|pair| matches!(pair, [a, b] if a.0.as_str() < b.0.as_str())));
+	}
+	
+	//		sort_key															
+	#[test]
+	fn sort_key() {
+		assert_eq!(Country::AX.sort_key(), "aland islands");
+		assert_eq!(Country::CI.sort_key(), "cote divoire");
+		assert_eq!(Country::KR.sort_key(), "korea republic of");
+		assert_eq!(Country::GB.sort_key(), "united kingdom of great britain and northern ireland");
+	}
+	#[test]
+	fn sort_key__order() {
+		let mut countries = vec![Country::ZW, Country::AL, Country::AX, Country::AF];
+		countries.sort_by_key(Country::sort_key);
+		assert_eq!(countries, vec![Country::AF, Country::AX, Country::AL, Country::ZW]);
+	}
+	#[test]
+	fn sort_key__all() {
+		for country in Country::all() {
+			assert_eq!(country.sort_key(), bare(country.info().name), "{country:?}");
+		}
+	}
+	
+	//		population															
+	#[cfg(feature = "extended-data")]
+	#[test]
+	fn population() {
+		assert_eq!(Country::GB.population(), 68_300_000);
+		assert_eq!(Country::CN.population(), 1_410_000_000);
+		assert_eq!(Country::AQ.population(), 0);
+	}
+	#[cfg(feature = "extended-data")]
+	#[test]
+	fn population__all() {
+		assert!(STATISTICS.windows(2).all(|pair| matches!(pair, [a, b] if a.0.as_str() < b.0.as_str())));
+		assert_eq!(STATISTICS.iter().map(|entry| entry.0.country()).collect::<Vec<_>>(), Country::all());
+	}
+	
+	//		area																
+	#[cfg(feature = "extended-data")]
+	#[test]
+	fn area() {
+		assert_eq!(Country::GB.area(), 242_495);
+		assert_eq!(Country::RU.area(), 17_098_246);
+		assert_eq!(Country::VA.area(), 1);
+	}
+	#[cfg(feature = "extended-data")]
+	#[test]
+	fn area__all() {
+		for country in Country::all() {
+			assert!(country.area() >= 1, "{country:?}");
+		}
+	}
+	
+	//		display_as															
+	#[test]
+	fn display_as() {
+		assert_eq!(Country::FR.display_as(DisplayStyle::Code).to_string(),        "FR");
+		assert_eq!(Country::FR.display_as(DisplayStyle::CodeAndName).to_string(), "FR — France");
+	}
+	
+	//		short_name															
+	#[test]
+	fn short_name() {
+		assert_eq!(Country::GB.short_name(), "United Kingdom of Great Britain and Northern Ireland");
+	}
+	
+	//		full_name															
+	#[test]
+	fn full_name() {
+		assert_eq!(Country::GB.full_name(), Some("the United Kingdom of Great Britain and Northern Ireland"));
+		assert_eq!(Country::CH.full_name(), Some("the Swiss Confederation"));
+		assert_eq!(Country::AX.full_name(), None);
+	}
+	
+	//		fuzzy_match															
+	#[test]
+	fn fuzzy_match() {
+		let matches = Country::fuzzy_match("Untied States of America", 0.8);
+		assert_eq!(matches.len(), 1);
+		assert_eq!(matches.first().map(|&(country, _)| country), Some(Country::US));
+		assert!(matches.iter().all(|&(_, score)| (0.8..1.0).contains(&score)));
+		let exact   = Country::fuzzy_match("switzerland", 0.8);
+		assert_eq!(exact.first().map(|&(country, _)| country), Some(Country::CH));
+		assert!(Country::fuzzy_match("Fooland", 0.9).is_empty());
+	}
+	#[test]
+	fn fuzzy_match__typos() {
+		assert_eq!(Country::fuzzy_match("Untied Kingdom", 0.7).first().map(|&(country, _)| country), Some(Country::GB));
+		assert_eq!(Country::fuzzy_match("Frnace",         0.7).first().map(|&(country, _)| country), Some(Country::FR));
+		assert_eq!(Country::fuzzy_match("Turkiye",        0.9).first().map(|&(country, _)| country), Some(Country::TR));
+	}
+	#[test]
+	fn fuzzy_match__sorted() {
+		let matches = Country::fuzzy_match("Niger", 0.5);
+		assert_eq!(matches.first().map(|&(country, _)| country), Some(Country::NE));
+		assert!(matches.iter().any(|&(country, _)| country == Country::NG));
+		assert!(matches.iter().zip(matches.iter().skip(1)).all(|(a, b)| a.1 >= b.1));
+	}
+	
+	//		try_from_any														
+	#[cfg(feature = "json")]
+	#[test]
+	fn try_from_any() {
+		use claims::assert_err;
+		use serde_json::json;
+		assert_eq!(Country::try_from_any(&json!("France")).unwrap(),    Country::FR);
+		assert_eq!(Country::try_from_any(&json!("fr")).unwrap(),        Country::FR);
+		assert_eq!(Country::try_from_any(&json!("FRA")).unwrap(),       Country::FR);
+		assert_eq!(Country::try_from_any(&json!(250)).unwrap(),         Country::FR);
+		assert_eq!(Country::try_from_any(&json!(true)).unwrap_err(),    "Invalid Country: true");
+		assert_err!(Country::try_from_any(&json!("Fooland")));
+		assert_err!(Country::try_from_any(&json!(70_000)));
+		assert_err!(Country::try_from_any(&json!(-826)));
+		assert_err!(Country::try_from_any(&json!(null)));
+	}
+	
+	//		parse_with															
+	#[test]
+	fn parse_with() {
+		use crate::parse::ParseOptions;
+		assert_eq!(Country::parse_with("GB",  &ParseOptions::strict()).unwrap(),     Country::GB);
+		assert_eq!(Country::parse_with("826", &ParseOptions::lenient()).unwrap(),    Country::GB);
+		assert_eq!(Country::parse_with("gb",  &ParseOptions::strict()).unwrap_err(), "Invalid Country: gb");
+		assert_eq!(Country::parse_with("UK",  &ParseOptions::strict().quirks(true)).unwrap(), Country::GB);
+	}
+	#[test]
+	fn parse_with__quirks() {
+		assert!(QUIRKS.windows(2).all(|pair| matches!(pair, [a, b] if a.0 < b.0)));
+		assert!(QUIRKS.iter().all(|entry| CountryCode::from_code(entry.0).is_none()));
+	}
+	
 	//		code																
 	#[test]
 	fn code() {
 		assert_eq!(Country::CH.code(), CountryCode::CH);
 	}
 	
+	//		last_changed														
+	#[test]
+	fn last_changed() {
+		assert_eq!(Country::HR.last_changed(), Some("2023-01-01"));
+		assert_eq!(Country::MK.last_changed(), Some("2019-02-12"));
+		assert_eq!(Country::GB.last_changed(), None);
+	}
+	#[test]
+	fn last_changed__data() {
+		assert!(CHANGES.windows(2).all(|pair| matches!(pair, [a, b] if a.0 < b.0)));
+		assert!(CHANGES.iter().all(|entry| entry.0.is_alpha2() && entry.1.len() == 10 && entry.1 <= crate::DATA_VERSION));
+	}
+	
+	//		cldr_region															
+	#[test]
+	fn cldr_region() {
+		assert_eq!(Country::GB.cldr_region(), "GB");
+		assert_eq!(Country::TW.cldr_region(), "TW");
+	}
+	
+	//		vehicle_code														
+	#[test]
+	fn vehicle_code() {
+		assert_eq!(Country::DE.vehicle_code(), Some("D"));
+		assert_eq!(Country::GB.vehicle_code(), Some("UK"));
+		assert_eq!(Country::AQ.vehicle_code(), None);
+	}
+	
+	//		from_vehicle_code													
+	#[test]
+	fn from_vehicle_code() {
+		assert_eq!(Country::from_vehicle_code("D"),   Some(Country::DE));
+		assert_eq!(Country::from_vehicle_code("ch"),  Some(Country::CH));
+		assert_eq!(Country::from_vehicle_code("UK"),  Some(Country::GB));
+		assert_eq!(Country::from_vehicle_code("GB"),  Some(Country::GB));
+		assert_eq!(Country::from_vehicle_code("FOO"), None);
+	}
+	#[test]
+	fn from_vehicle_code__all() {
+		for country in Country::all() {
+			if let Some(code) = country.vehicle_code() {
+				assert_eq!(Country::from_vehicle_code(code), Some(country));
+			}
+		}
+	}
+	
+	//		first_day_of_week													
+	#[test]
+	fn first_day_of_week() {
+		assert_eq!(Country::GB.first_day_of_week(), Weekday::Monday);
+		assert_eq!(Country::US.first_day_of_week(), Weekday::Sunday);
+		assert_eq!(Country::EG.first_day_of_week(), Weekday::Saturday);
+		assert_eq!(Country::MV.first_day_of_week(), Weekday::Friday);
+	}
+	
+	//		weekend_days														
+	#[test]
+	fn weekend_days() {
+		assert_eq!(Country::GB.weekend_days(), &[ Weekday::Saturday, Weekday::Sunday ]);
+		assert_eq!(Country::SA.weekend_days(), &[ Weekday::Friday, Weekday::Saturday ]);
+		assert_eq!(Country::AF.weekend_days(), &[ Weekday::Thursday, Weekday::Friday ]);
+		assert_eq!(Country::IN.weekend_days(), &[ Weekday::Sunday ]);
+	}
+	#[test]
+	fn weekend_days__all() {
+		for country in Country::all() {
+			assert!(!country.weekend_days().is_empty());
+		}
+	}
+	
+	//		national_day														
+	#[test]
+	fn national_day() {
+		assert_eq!(Country::FR.national_day(), MonthDay::new(7, 14));
+		assert_eq!(Country::US.national_day(), MonthDay::new(7, 4));
+		assert_eq!(Country::GB.national_day(), None);
+	}
+	#[test]
+	fn national_day__known() {
+		assert_eq!(Country::CH.national_day(), MonthDay::new(8, 1));
+		assert_eq!(Country::CN.national_day(), MonthDay::new(10, 1));
+		assert_eq!(Country::DE.national_day(), MonthDay::new(10, 3));
+		assert_eq!(Country::IE.national_day(), MonthDay::new(3, 17));
+		assert_eq!(Country::IN.national_day(), MonthDay::new(1, 26));
+		assert_eq!(Country::AQ.national_day(), None);
+	}
+	
+	//		postal_code_formats													
+	#[test]
+	fn postal_code_formats() {
+		assert_eq!(Country::US.postal_code_formats(), &[ "#####", "#####-####" ]);
+		assert!(Country::AE.postal_code_formats().is_empty());
+	}
+	
+	//		validate_postal_code												
+	#[test]
+	fn validate_postal_code() {
+		assert!( Country::US.validate_postal_code("90210"));
+		assert!( Country::US.validate_postal_code("90210-1234"));
+		assert!(!Country::US.validate_postal_code("9021"));
+		assert!(!Country::US.validate_postal_code("ABCDE"));
+		assert!( Country::GB.validate_postal_code("SW1A 1AA"));
+		assert!( Country::GB.validate_postal_code(" m1 1ae "));
+		assert!(!Country::GB.validate_postal_code("SW1A1AA"));
+		assert!( Country::IE.validate_postal_code("D02 X285"));
+		assert!( Country::JE.validate_postal_code("JE2 3AB"));
+		assert!(!Country::JE.validate_postal_code("GY1 1AB"));
+		assert!(!Country::AE.validate_postal_code(""));
+		assert!(!Country::AE.validate_postal_code("12345"));
+	}
+	#[test]
+	fn validate_postal_code__formats() {
+		for country in Country::all() {
+			for format in country.postal_code_formats() {
+				let example = format.replace('#', "1").replace('@', "A").replace('*', "B");
+				assert!(country.validate_postal_code(&example), "{country:?}: {format}");
+			}
+		}
+	}
+	
+	//		tax_id_format														
+	#[test]
+	fn tax_id_format() {
+		assert_eq!(Country::DE.tax_id_format().unwrap().prefix(), Some("DE"));
+		assert_eq!(Country::BR.tax_id_format().unwrap().name(),   "CNPJ");
+		assert_eq!(Country::AQ.tax_id_format(),                   None);
+	}
+	
+	//		validate_tax_id														
+	#[test]
+	fn validate_tax_id() {
+		assert!( Country::DE.validate_tax_id("DE136695976"));
+		assert!( Country::DE.validate_tax_id("136 695 976"));
+		assert!(!Country::DE.validate_tax_id("DE136695977"));
+		assert!( Country::IT.validate_tax_id("IT00743110157"));
+		assert!( Country::FR.validate_tax_id("FR 40 303 265 045"));
+		assert!( Country::BE.validate_tax_id("BE 0428.759.497"));
+		assert!( Country::PL.validate_tax_id("PL526-025-02-74"));
+		assert!( Country::NO.validate_tax_id("NO 974 760 673 MVA"));
+		assert!( Country::HR.validate_tax_id("HR33392005961"));
+		assert!( Country::AU.validate_tax_id("51 824 753 556"));
+		assert!( Country::US.validate_tax_id("12-3456789"));
+		assert!(!Country::AQ.validate_tax_id("123456789"));
+	}
+	
+	//		numbering_plan														
+	#[test]
+	fn numbering_plan() {
+		assert_eq!(Country::GB.numbering_plan().unwrap().calling_code(), 44);
+		assert_eq!(Country::US.numbering_plan().unwrap().calling_code(), 1);
+		assert_eq!(Country::BV.numbering_plan(), None);
+	}
+	
+	//		nationality_mark													
+	#[test]
+	fn nationality_mark() {
+		assert_eq!(Country::GB.nationality_mark(), Some("G"));
+		assert_eq!(Country::KY.nationality_mark(), Some("VP-C"));
+		assert_eq!(Country::AQ.nationality_mark(), None);
+	}
+	
+	//		driving_side														
+	#[test]
+	fn driving_side() {
+		assert_eq!(Country::GB.driving_side(), DrivingSide::Left);
+		assert_eq!(Country::JP.driving_side(), DrivingSide::Left);
+		assert_eq!(Country::FR.driving_side(), DrivingSide::Right);
+		assert_eq!(Country::US.driving_side(), DrivingSide::Right);
+	}
+	
+	//		measurement_system													
+	#[test]
+	fn measurement_system() {
+		assert_eq!(Country::FR.measurement_system(), MeasurementSystem::Metric);
+		assert_eq!(Country::GB.measurement_system(), MeasurementSystem::Imperial);
+		assert_eq!(Country::US.measurement_system(), MeasurementSystem::Us);
+	}
+	
+	//		observes_dst														
+	#[test]
+	fn observes_dst() {
+		assert!(Country::GB.observes_dst());
+		assert!(Country::US.observes_dst());
+		assert!(Country::AU.observes_dst());
+		assert!(Country::CL.observes_dst());
+		assert!(!Country::JP.observes_dst());
+		assert!(!Country::RU.observes_dst());
+		assert!(!Country::BR.observes_dst());
+		assert!(!Country::TR.observes_dst());
+	}
+	#[test]
+	fn observes_dst__partial() {
+		assert!(Country::AU.observes_dst());
+		assert!(Country::CA.observes_dst());
+		assert!(Country::GL.observes_dst());
+		assert!(Country::MX.observes_dst());
+	}
+	
+	//		timezones															
+	#[test]
+	fn timezones() {
+		assert_eq!(Country::GB.timezones(), &[ "Europe/London" ]);
+		assert_eq!(Country::ES.timezones(), &[ "Europe/Madrid", "Africa/Ceuta", "Atlantic/Canary" ]);
+		assert_eq!(Country::US.timezones().first(), Some(&"America/New_York"));
+		assert!(Country::BV.timezones().is_empty());
+	}
+	#[test]
+	fn timezones__all() {
+		assert!(TIMEZONES.windows(2).all(|pair| matches!(pair, [a, b] if a.0 < b.0)));
+		for country in Country::all() {
+			for timezone in country.timezones() {
+				assert!(timezone.contains('/'), "{country:?}: {timezone}");
+			}
+		}
+	}
+	
+	//		chrono_timezones													
+	#[cfg(feature = "chrono-tz")]
+	#[test]
+	fn chrono_timezones() {
+		use chrono_tz::Tz;
+		assert_eq!(Country::GB.chrono_timezones(), vec![ Tz::Europe__London ]);
+		for country in Country::all() {
+			assert_eq!(country.chrono_timezones().len(), country.timezones().len(), "{country:?}");
+		}
+	}
+	
+	//		local_time_candidates												
+	#[cfg(feature = "chrono-tz")]
+	#[test]
+	fn local_time_candidates() {
+		use chrono::{TimeZone, Timelike, Utc};
+		let utc   = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+		let hours: Vec<_> = Country::ES.local_time_candidates(utc).iter().map(Timelike::hour).collect();
+		assert_eq!(hours, vec![ 14, 14, 13 ]);
+		assert!(Country::BV.local_time_candidates(utc).is_empty());
+	}
+	
+	//		region																
+	#[test]
+	fn region() {
+		assert_eq!(Country::GB.region(), Region::Europe);
+		assert_eq!(Country::EG.region(), Region::Africa);
+		assert_eq!(Country::TR.region(), Region::Asia);
+		assert_eq!(Country::GL.region(), Region::Americas);
+		assert_eq!(Country::NZ.region(), Region::Oceania);
+		assert_eq!(Country::AQ.region(), Region::Antarctica);
+	}
+	
+	//		is_in_region														
+	#[test]
+	fn is_in_region() {
+		assert!( Country::FR.is_in_region(Region::Europe));
+		assert!(!Country::FR.is_in_region(Region::Africa));
+	}
+	
+	//		is_independent														
+	#[test]
+	fn is_independent() {
+		assert!( Country::US.is_independent());
+		assert!( Country::VA.is_independent());
+		assert!(!Country::PR.is_independent());
+		assert!(!Country::EH.is_independent());
+	}
+	
+	//		un_status															
+	#[test]
+	fn un_status() {
+		assert_eq!(Country::FR.un_status(), UnStatus::Member);
+		assert_eq!(Country::VA.un_status(), UnStatus::Observer);
+		assert_eq!(Country::PS.un_status(), UnStatus::Observer);
+		assert_eq!(Country::TW.un_status(), UnStatus::NonMember);
+		assert_eq!(Country::PR.un_status(), UnStatus::NonMember);
+	}
+	
+	//		un_member															
+	#[test]
+	fn un_member() {
+		assert!( Country::US.un_member());
+		assert!(!Country::VA.un_member());
+		assert!(!Country::PR.un_member());
+	}
+	#[test]
+	fn un_member__all() {
+		assert_eq!(Country::all().into_iter().filter(Country::un_member).count(), 193);
+		assert!(Country::all().into_iter().filter(Country::un_member).all(|country| country.is_independent()));
+	}
+	
+	//		sovereign															
+	#[test]
+	fn sovereign() {
+		assert_eq!(Country::PR.sovereign(), Some(Country::US));
+		assert_eq!(Country::GL.sovereign(), Some(Country::DK));
+		assert_eq!(Country::HK.sovereign(), Some(Country::CN));
+		assert_eq!(Country::US.sovereign(), None);
+		assert_eq!(Country::AQ.sovereign(), None);
+	}
+	#[test]
+	fn sovereign__all() {
+		for country in Country::all() {
+			if let Some(sovereign) = country.sovereign() {
+				assert!(!country.is_independent(), "{country:?}");
+				assert!( sovereign.is_independent(), "{country:?}");
+			}
+		}
+	}
+	
+	//		territories															
+	#[test]
+	fn territories() {
+		assert_eq!(Country::DK.territories(), vec![ Country::FO, Country::GL ]);
+		assert_eq!(Country::GB.territories(), vec![
+			Country::AI, Country::BM, Country::FK, Country::GG, Country::GI, Country::GS, Country::IM, Country::IO,
+			Country::JE, Country::KY, Country::MS, Country::PN, Country::SH, Country::TC, Country::VG,
+		]);
+		assert_eq!(Country::CN.territories(), vec![ Country::HK, Country::MO ]);
+		assert!(Country::DE.territories().is_empty());
+	}
+	#[test]
+	fn territories__all() {
+		for country in Country::all() {
+			for territory in country.territories() {
+				assert_eq!(territory.sovereign(), Some(country));
+			}
+		}
+		assert_eq!(Country::all().iter().map(|country| country.territories().len()).sum::<usize>(), 51);
+	}
+	
 	//		currencies															
 	#[test]
 	fn currencies() {
-		assert_eq!(Country::CH.currencies(), &vh![ CurrencyCode: CHE, CHF, CHW ]);
+		assert_eq!(Country::CH.currencies(), &[ CurrencyCode::CHE, CurrencyCode::CHF, CurrencyCode::CHW ]);
+	}
+	
+	//		currencies_set														
+	#[test]
+	fn currencies_set() {
+		assert_eq!(Country::CH.currencies_set(), HashSet::from([ CurrencyCode::CHE, CurrencyCode::CHF, CurrencyCode::CHW ]));
+	}
+	
+	//		currencies_in_use													
+	#[test]
+	fn currencies_in_use() {
+		assert_eq!(Country::CH.currencies_in_use(), Country::CH.currencies());
+		assert_eq!(Country::PS.currencies_in_use(), &[ CurrencyCode::ILS, CurrencyCode::JOD ]);
+		assert_eq!(Country::GS.currencies_in_use(), &[ CurrencyCode::GBP ]);
+		assert!(Country::AQ.currencies_in_use().is_empty());
+	}
+	#[test]
+	fn currencies_in_use__all() {
+		for country in Country::all() {
+			assert_eq!(country.currencies_in_use().is_empty(), country == Country::AQ, "{country:?}");
+		}
+		for &(code, currencies) in &CONVENTIONAL_CURRENCIES {
+			assert!(code.country().currencies().is_empty(), "{code}");
+			assert!(currencies.windows(2).all(|pair| matches!(pair, [a, b] if a.as_str() < b.as_str())));
+		}
 	}
 	#[test]
 	fn currencies__relationships() {
-		#[expect(clippy::iter_over_hash_type, reason = "Order is not important here")]
-		for country in COUNTRIES.keys() {
+		for country in Country::all() {
 			for currency_code in country.currencies() {
 				assert!(currency_code.currency().countries().contains(&country.code()));
 			}
 		}
 	}
+	#[test]
+	fn currencies__sorted() {
+		for country in Country::all() {
+			assert!(country.currencies().windows(2).all(|pair| matches!(pair, [a, b] if a < b)), "{country:?}");
+		}
+	}
 	
 	//		languages															
 	#[test]
 	fn languages() {
-		assert_eq!(Country::CH.languages(), &vh![ LanguageCode: DE, FR, IT, RM ]);
+		assert_eq!(Country::CH.languages(), &[ LanguageCode::DE, LanguageCode::FR, LanguageCode::IT, LanguageCode::RM ]);
+	}
+	
+	//		languages_set														
+	#[test]
+	fn languages_set() {
+		assert_eq!(Country::CH.languages_set(), HashSet::from([ LanguageCode::DE, LanguageCode::FR, LanguageCode::IT, LanguageCode::RM ]));
 	}
 	#[test]
 	fn languages__relationships() {
-		#[expect(clippy::iter_over_hash_type, reason = "Order is not important here")]
-		for country in COUNTRIES.keys() {
+		for country in Country::all() {
 			for language_code in country.languages() {
 				assert!(language_code.language().countries().contains(&country.code()));
 			}
 		}
 	}
+	#[test]
+	fn languages__sorted() {
+		for country in Country::all() {
+			assert!(country.languages().windows(2).all(|pair| matches!(pair, [a, b] if a < b)), "{country:?}");
+		}
+	}
+	
+	//		languages_with_status												
+	#[test]
+	fn languages_with_status() {
+		assert_eq!(Country::CH.languages_with_status(), vec![
+			(LanguageCode::DE, LanguageStatus::Official),
+			(LanguageCode::FR, LanguageStatus::Official),
+			(LanguageCode::IT, LanguageStatus::Official),
+			(LanguageCode::RM, LanguageStatus::National),
+		]);
+		assert_eq!(Country::GB.languages_with_status(), vec![
+			(LanguageCode::EN, LanguageStatus::Official),
+			(LanguageCode::CY, LanguageStatus::Regional),
+			(LanguageCode::GA, LanguageStatus::Regional),
+			(LanguageCode::GD, LanguageStatus::Regional),
+			(LanguageCode::KW, LanguageStatus::Minority),
+		]);
+		assert_eq!(Country::DK.languages_with_status(), vec![
+			(LanguageCode::DA, LanguageStatus::Official),
+			(LanguageCode::DE, LanguageStatus::Minority),
+		]);
+	}
+	#[test]
+	fn languages_with_status__all() {
+		for country in Country::all() {
+			let languages = country.languages_with_status();
+			assert!(languages.len() >= country.languages().len());
+			for (index, &(language, _)) in languages.iter().enumerate() {
+				assert!(!languages.iter().skip(index + 1).any(|&(other, _)| other == language));
+			}
+		}
+		for (code, statuses) in LANGUAGE_STATUSES {
+			assert!(statuses.iter().all(|&(_, status)| status != LanguageStatus::Official), "{code:?}");
+			assert!(statuses.windows(2).all(|pair| matches!(pair, [a, b] if a.0.as_str() < b.0.as_str())), "{code:?}");
+		}
+		assert!(LANGUAGE_STATUSES.windows(2).all(|pair| matches!(pair, [a, b] if a.0.as_str() < b.0.as_str())));
+	}
+	
+	//		languages_by_status													
+	#[test]
+	fn languages_by_status() {
+		assert_eq!(Country::ZA.languages_by_status(LanguageStatus::Official), Country::ZA.languages());
+		assert_eq!(Country::ES.languages_by_status(LanguageStatus::Official), vec![ LanguageCode::ES ]);
+		assert_eq!(Country::ES.languages_by_status(LanguageStatus::Regional), vec![
+			LanguageCode::CA,
+			LanguageCode::EU,
+			LanguageCode::GL,
+			LanguageCode::OC,
+		]);
+		assert!(Country::ES.languages_by_status(LanguageStatus::Minority).is_empty());
+	}
+	
+	//		data_gaps															
+	#[test]
+	fn data_gaps() {
+		assert!(Country::GB.data_gaps().is_empty());
+		assert_eq!(Country::PS.data_gaps(), vec![ DataGap::Currencies ]);
+		assert_eq!(Country::AQ.data_gaps(), vec![ DataGap::Currencies, DataGap::Languages ]);
+	}
+	#[test]
+	fn data_gaps__all() {
+		let incomplete: Vec<_> = Country::all().into_iter().filter(|country| !country.data_gaps().is_empty()).collect();
+		assert_eq!(incomplete, vec![ Country::AQ, Country::GS, Country::PS ]);
+	}
+	
+	//		is_member_of														
+	#[cfg(feature = "orgs")]
+	#[test]
+	fn is_member_of() {
+		use crate::orgs::Org;
+		assert!( Country::GB.is_member_of(Org::Commonwealth));
+		assert!( Country::GB.is_member_of(Org::G7));
+		assert!(!Country::CH.is_member_of(Org::G20));
+		assert!( Country::CH.is_member_of(Org::Oecd));
+	}
+	
+	//		is_in_zone															
+	#[test]
+	fn is_in_zone() {
+		use crate::zones::MonetaryZone;
+		assert!( Country::FR.is_in_zone(MonetaryZone::Eurozone));
+		assert!(!Country::MC.is_in_zone(MonetaryZone::Eurozone));
+		assert!( Country::SN.is_in_zone(MonetaryZone::Waemu));
+		assert!(!Country::SN.is_in_zone(MonetaryZone::Cemac));
+		assert!( Country::EC.is_in_zone(MonetaryZone::Dollarised));
+	}
 }
 
 #[cfg(test)]
@@ -302,12 +1188,27 @@ mod country__traits {
 	
 	//		from																
 	#[test]
+	fn from__country_for_u16() {
+		let country = Country::US;
+		assert_eq!(u16::from(country), 840);
+		let int: u16 = country.into();
+		assert_eq!(int,                840);
+	}
+	#[test]
 	fn from__country_for_string() {
 		let country = Country::US;
 		assert_eq!(String::from(country), "United States of America");
 		let str: String = country.into();
 		assert_eq!(str,                   "United States of America");
 	}
+	#[cfg(feature = "icu")]
+	#[test]
+	fn from__country_for_region() {
+		use icu_locale_core::subtags::Region;
+		assert_eq!(Region::from(Country::GB).as_str(), "GB");
+		let region: Region = Country::TW.into();
+		assert_eq!(region.as_str(),                    "TW");
+	}
 	
 	//		from_str															
 	#[test]
@@ -317,6 +1218,29 @@ mod country__traits {
 		assert_err!(&err);
 		assert_eq!(err.unwrap_err(), "Invalid Country: Fooland");
 	}
+	#[test]
+	fn from_str__normalized() {
+		assert_eq!(Country::from_str("cote divoire").unwrap(),             Country::CI);
+		assert_eq!(Country::from_str("GUINEA  BISSAU").unwrap(),           Country::GW);
+		assert_eq!(Country::from_str("united states of america").unwrap(), Country::US);
+		assert_eq!(Country::from_str(" New\u{a0}Zealand ").unwrap(),       Country::NZ);
+	}
+	#[test]
+	fn from_str__full_name() {
+		assert_eq!(Country::from_str("the Swiss Confederation").unwrap(),   Country::CH);
+		assert_eq!(Country::from_str("Swiss Confederation").unwrap(),       Country::CH);
+		assert_eq!(Country::from_str("The French Republic").unwrap(),       Country::FR);
+		assert_eq!(Country::from_str("the Gambia").unwrap(),                Country::GM);
+	}
+	#[test]
+	fn from_str__all() {
+		for country in Country::all() {
+			assert_eq!(Country::from_str(country.short_name()).unwrap(), country);
+			if let Some(full_name) = country.full_name() {
+				assert_eq!(Country::from_str(full_name).unwrap(), country);
+			}
+		}
+	}
 	
 	//		serialize															
 	#[test]
@@ -332,6 +1256,252 @@ mod country__traits {
 		assert_err!(&err);
 		assert_eq!(err.unwrap_err(), "Invalid Country: Fooland");
 	}
+	#[cfg(feature = "icu")]
+	#[test]
+	fn try_from__region() {
+		use icu_locale_core::subtags::region;
+		assert_eq!(Country::try_from(region!("GB")), Ok(Country::GB));
+		assert_err!(Country::try_from(region!("419")));
+		assert_err!(Country::try_from(region!("XK")));
+	}
+}
+
+//		CodeStatus																
+#[cfg(test)]
+mod code_status__traits {
+	use super::super::*;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(CodeStatus::OfficiallyAssigned.as_str(), "Officially assigned");
+		assert_eq!(CodeStatus::UserAssigned.as_str(),       "User-assigned");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(CodeStatus::ExceptionallyReserved.to_string(), "Exceptionally reserved");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&CodeStatus::TransitionallyReserved).unwrap(), r#""TransitionallyReserved""#);
+	}
+}
+
+//		DataGap																	
+#[cfg(test)]
+mod data_gap__traits {
+	use super::super::*;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(DataGap::Currencies.as_str(), "Currencies");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(DataGap::Languages.to_string(), "Languages");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&DataGap::Currencies).unwrap(), r#""Currencies""#);
+	}
+}
+
+//		Jurisdiction															
+#[cfg(test)]
+mod jurisdiction__enum {
+	use super::super::*;
+	use crate::parse::ParseOptions;
+	
+	//		parse_with															
+	#[test]
+	fn parse_with() {
+		let options = ParseOptions::strict().quirks(true);
+		assert_eq!(Jurisdiction::parse_with("EU",  &options).unwrap(),                    Jurisdiction::EuropeanUnion);
+		assert_eq!(Jurisdiction::parse_with("EM",  &options).unwrap(),                    Jurisdiction::EuropeanUnion);
+		assert_eq!(Jurisdiction::parse_with("UK",  &options).unwrap(),                    Jurisdiction::Country(Country::GB));
+		assert_eq!(Jurisdiction::parse_with("FR",  &options).unwrap(),                    Jurisdiction::Country(Country::FR));
+		assert_eq!(Jurisdiction::parse_with("eu",  &options).unwrap_err(),                "Invalid Jurisdiction: eu");
+		assert_eq!(Jurisdiction::parse_with("EU",  &ParseOptions::strict()).unwrap_err(), "Invalid Jurisdiction: EU");
+		assert_eq!(Jurisdiction::parse_with("eu",  &ParseOptions::lenient()).unwrap(),    Jurisdiction::EuropeanUnion);
+		assert_eq!(Jurisdiction::parse_with("250", &ParseOptions::lenient()).unwrap(),    Jurisdiction::Country(Country::FR));
+	}
+	
+	//		country																
+	#[test]
+	fn country() {
+		assert_eq!(Jurisdiction::Country(Country::DE).country(), Some(Country::DE));
+		assert_eq!(Jurisdiction::EuropeanUnion.country(),        None);
+	}
+}
+
+#[cfg(test)]
+mod jurisdiction__traits {
+	use super::super::*;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(Jurisdiction::Country(Country::FR).as_str(), "France");
+		assert_eq!(Jurisdiction::EuropeanUnion.as_str(),        "European Union");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(Jurisdiction::EuropeanUnion.to_string(), "European Union");
+	}
+	
+	//		from																
+	#[test]
+	fn from() {
+		assert_eq!(Jurisdiction::from(Country::JP), Jurisdiction::Country(Country::JP));
+	}
+}
+
+//		LanguageStatus															
+#[cfg(test)]
+mod language_status__traits {
+	use super::super::*;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(LanguageStatus::Official.as_str(),     "Official");
+		assert_eq!(LanguageStatus::WidelySpoken.as_str(), "Widely spoken");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(LanguageStatus::Regional.to_string(), "Regional");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&LanguageStatus::Minority).unwrap(), r#""Minority""#);
+	}
+}
+
+//		Region																	
+#[cfg(test)]
+mod region__enum {
+	use super::super::*;
+	
+	//		all																	
+	#[test]
+	fn all() {
+		assert_eq!(Region::all().len(), 6);
+	}
+	
+	//		countries															
+	#[test]
+	fn countries() {
+		assert_eq!(Region::Antarctica.countries(), vec![ Country::AQ ]);
+		assert!(Region::Europe.countries().contains(&Country::FR));
+		assert!(!Region::Europe.countries().contains(&Country::US));
+	}
+	#[test]
+	fn countries__all() {
+		let total: usize = Region::all().iter().map(|region| region.countries().len()).sum();
+		assert_eq!(total, Country::all().len());
+	}
+}
+
+//		Region																	
+#[cfg(test)]
+mod region__traits {
+	use super::super::*;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(Region::Americas.as_str(), "Americas");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(Region::Oceania.to_string(), "Oceania");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&Region::Asia).unwrap(), r#""Asia""#);
+	}
+}
+
+//		UnStatus																
+#[cfg(test)]
+mod un_status__traits {
+	use super::super::*;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(UnStatus::Member.as_str(),    "Member");
+		assert_eq!(UnStatus::NonMember.as_str(), "Non-member");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(UnStatus::Observer.to_string(), "Observer");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&UnStatus::NonMember).unwrap(), r#""NonMember""#);
+	}
+}
+
+//		Weekday																	
+#[cfg(test)]
+mod weekday__traits {
+	use super::super::*;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(Weekday::Monday.as_str(), "Monday");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(format!("{}", Weekday::Friday), "Friday");
+		assert_eq!(Weekday::Sunday.to_string(),    "Sunday");
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let weekday: Weekday = serde_json::from_str(r#""Saturday""#).unwrap();
+		assert_eq!(weekday, Weekday::Saturday);
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&Weekday::Thursday).unwrap(), r#""Thursday""#);
+	}
 }
 
 
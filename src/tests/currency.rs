@@ -1,5 +1,32 @@
 //		Tests
 
+//		Constants
+#[cfg(test)]
+mod constants {
+	use super::super::*;
+	
+	//		count																
+	#[test]
+	fn count() {
+		assert_eq!(COUNT, Currency::all().len());
+		assert_eq!(COUNT, CurrencyCode::all().len());
+	}
+	
+	//		alpha3_codes														
+	#[test]
+	fn alpha3_codes() {
+		let codes: Vec<&str> = Currency::all().iter().map(|currency| currency.code().as_str()).collect();
+		assert_eq!(ALPHA3_CODES, codes.as_slice());
+	}
+	
+	//		numeric_codes														
+	#[test]
+	fn numeric_codes() {
+		let codes: Vec<u16> = Currency::all().iter().map(|currency| currency.code().numeric()).collect();
+		assert_eq!(NUMERIC_CODES, codes.as_slice());
+	}
+}
+
 //		CurrencyCode															
 #[cfg(test)]
 mod currency_code__enum {
@@ -15,6 +42,55 @@ mod currency_code__enum {
 		assert!(codes.contains(&CurrencyCode::GBP));
 	}
 	
+	//		complete															
+	#[test]
+	fn complete() {
+		assert_eq!(CurrencyCode::complete("CH"),  vec![ CurrencyCode::CHE, CurrencyCode::CHF, CurrencyCode::CHW ]);
+		assert_eq!(CurrencyCode::complete("ch"),  vec![ CurrencyCode::CHE, CurrencyCode::CHF, CurrencyCode::CHW ]);
+		assert_eq!(CurrencyCode::complete("GBP"), vec![ CurrencyCode::GBP ]);
+		assert_eq!(CurrencyCode::complete("").len(), 179);
+		assert!(CurrencyCode::complete("GBPX").is_empty());
+	}
+	
+	//		from_code															
+	#[test]
+	fn from_code() {
+		assert_eq!(CurrencyCode::from_code("USD"), Some(CurrencyCode::USD));
+		assert_eq!(CurrencyCode::from_code("usd"), Some(CurrencyCode::USD));
+		assert_eq!(CurrencyCode::from_code("FOO"), None);
+		assert_eq!(CurrencyCode::from_code("US"),  None);
+	}
+	#[test]
+	fn from_code__all() {
+		for code in CurrencyCode::all() {
+			assert_eq!(CurrencyCode::from_code(code.as_str()), Some(code));
+		}
+	}
+	
+	//		looks_valid															
+	#[test]
+	fn looks_valid() {
+		assert!( CurrencyCode::looks_valid("GBP"));
+		assert!( CurrencyCode::looks_valid("ABC"));
+		assert!(!CurrencyCode::looks_valid("gbp"));
+		assert!(!CurrencyCode::looks_valid("GB"));
+		assert!(!CurrencyCode::looks_valid("GBPX"));
+		assert!(!CurrencyCode::looks_valid("826"));
+		assert!(!CurrencyCode::looks_valid(""));
+	}
+	
+	//		const_from_str														
+	#[test]
+	fn const_from_str() {
+		const CODES: [CurrencyCode; 2] = [CurrencyCode::const_from_str("usd"), CurrencyCode::const_from_str("EUR")];
+		assert_eq!(CODES, [CurrencyCode::USD, CurrencyCode::EUR]);
+	}
+	#[test]
+	#[should_panic(expected = "Invalid CurrencyCode")]
+	fn const_from_str__invalid() {
+		let _code = CurrencyCode::const_from_str("FOO");
+	}
+	
 	//		currency															
 	#[test]
 	fn currency() {
@@ -24,18 +100,53 @@ mod currency_code__enum {
 	}
 	#[test]
 	fn currency__all() {
-		#[expect(clippy::iter_over_hash_type, reason = "Order is not important here")]
-		for currency in CURRENCIES.keys() {
-			assert_eq!(currency.code().currency(), *currency);
+		for currency in Currency::all() {
+			assert_eq!(currency.code().currency(), currency);
 		}
 	}
+	
+	//		numeric																
+	#[test]
+	fn numeric() {
+		assert_eq!(CurrencyCode::USD.numeric(), 840);
+		assert_eq!(CurrencyCode::ALL.numeric(), 8);
+	}
+	#[test]
+	fn numeric__all() {
+		for code in CurrencyCode::all() {
+			assert!(code.numeric() < 1_000);
+			assert_eq!(code.numeric(), u16::from(code));
+			assert_eq!(CurrencyCode::try_from(code.numeric()).unwrap(), code);
+		}
+	}
+	
+	//		shares_numeric_with_country											
+	#[test]
+	fn shares_numeric_with_country() {
+		assert_eq!(CurrencyCode::GBP.shares_numeric_with_country(), Some(CountryCode::GB));
+		assert_eq!(CurrencyCode::CHF.shares_numeric_with_country(), Some(CountryCode::CH));
+		assert_eq!(CurrencyCode::EUR.shares_numeric_with_country(), None);
+		for code in CurrencyCode::all() {
+			if let Some(country) = code.shares_numeric_with_country() {
+				assert!(country.country().currencies().contains(&code), "{code} is not used in {country}");
+			}
+		}
+	}
+	
+	//		stable_hash															
+	#[test]
+	fn stable_hash() {
+		assert_eq!(CurrencyCode::GBP.stable_hash(), 0xea82_6519_980a_6a54);
+	}
 }
 
 #[cfg(test)]
 mod currency_code__traits {
 	use super::super::*;
 	use claims::assert_err;
+	use rubedo::sugar::s;
 	use serde_json;
+	use serde_test::{Compact, Configure, Token, assert_de_tokens, assert_de_tokens_error, assert_ser_tokens};
 	
 	//		as_str																
 	#[test]
@@ -57,6 +168,12 @@ mod currency_code__traits {
 		let code2: CurrencyCode = serde_json::from_str(r#""usd""#).unwrap();
 		assert_eq!(code2, CurrencyCode::USD);
 	}
+	#[test]
+	fn deserialize__compact() {
+		assert_de_tokens(&CurrencyCode::USD.compact(), &[ Token::U16(840) ]);
+		assert_de_tokens_error::<Compact<CurrencyCode>>(&[ Token::U16(1) ], "Invalid CurrencyCode: 1");
+		assert_de_tokens_error::<Compact<CurrencyCode>>(&[ Token::Str("USD") ], "invalid type: string \"USD\", expected u16");
+	}
 	
 	//		display																
 	#[test]
@@ -102,12 +219,35 @@ mod currency_code__traits {
 		assert_err!(&err);
 		assert_eq!(err.unwrap_err(), "Invalid CurrencyCode: FOO");
 	}
+	#[test]
+	fn from_str__mixed_case() {
+		assert_eq!(CurrencyCode::from_str("gBp").unwrap(), CurrencyCode::GBP);
+		assert_err!(CurrencyCode::from_str("ＧＢＰ"));
+	}
+	#[test]
+	fn from_str__whitespace() {
+		assert_err!(CurrencyCode::from_str(" GBP "));
+		assert_err!(CurrencyCode::from_str("\u{a0}USD"));
+		assert_eq!(Currency::parse_with(" GBP ", &ParseOptions::strict().trim(true)).unwrap(), Currency::GBP);
+	}
+	
+	//		ord																	
+	#[test]
+	fn ord() {
+		assert!(CurrencyCode::EUR < CurrencyCode::GBP);
+		assert!(CurrencyCode::GBP < CurrencyCode::USD);
+	}
 	
 	//		serialize															
 	#[test]
 	fn serialize() {
 		assert_eq!(serde_json::to_string(&CurrencyCode::USD).unwrap(), r#""USD""#);
 	}
+	#[test]
+	fn serialize__compact() {
+		assert_ser_tokens(&CurrencyCode::USD.compact(), &[ Token::U16(840) ]);
+		assert_ser_tokens(&CurrencyCode::USD.readable(), &[ Token::Str("USD") ]);
+	}
 	
 	//		try_from															
 	#[test]
@@ -131,6 +271,7 @@ mod currency_code__traits {
 #[cfg(test)]
 mod currency__enum {
 	use super::super::*;
+	use crate::display::PriceRounding;
 	
 	//		all																	
 	#[test]
@@ -142,6 +283,75 @@ mod currency__enum {
 		assert!(currencies.contains(&Currency::GBP));
 	}
 	
+	//		discriminants														
+	#[test]
+	fn discriminants() {
+		//	These values are frozen, and must never change. New variants must be
+		//	added to the end of this list.
+		const FROZEN: [&str; 179] = [
+			"AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD",
+			"BDT", "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP",
+			"BYN", "BZD", "CAD", "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU",
+			"CRC", "CUP", "CVE", "CZK", "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR",
+			"FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL",
+			"HTG", "HUF", "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES",
+			"KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD",
+			"LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP", "MRU", "MUR", "MVR",
+			"MWK", "MXN", "MXV", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK", "NPR", "NZD", "OMR",
+			"PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "RWF",
+			"SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SLL", "SOS", "SRD", "SSP",
+			"STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY", "TTD", "TWD",
+			"TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES", "VND",
+			"VUV", "WST", "XAF", "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XDR", "XOF",
+			"XPD", "XPF", "XPT", "XSU", "XTS", "XUA", "XXX", "YER", "ZAR", "ZMW", "ZWL",
+		];
+		for (index, code) in FROZEN.iter().enumerate() {
+			assert_eq!(CurrencyCode::from_str(code).unwrap().currency() as usize, index);
+		}
+		assert_eq!(Currency::AED as u16, 0);
+		assert_eq!(Currency::ZWL as u16, 178);
+		assert!(Currency::AED < Currency::ZWL);
+	}
+	
+	//		changes_since														
+	#[test]
+	fn changes_since() {
+		assert_eq!(Currency::changes_since("2021-01-01"),        vec![ Currency::EUR, Currency::SLE, Currency::VED ]);
+		assert_eq!(Currency::changes_since("2022-07-01"),        vec![ Currency::EUR ]);
+		assert_eq!(Currency::changes_since(crate::DATA_VERSION), vec![]);
+		assert_eq!(Currency::changes_since("").len(),            CHANGES.len());
+	}
+	
+	//		records																
+	#[test]
+	fn records() {
+		let records: Vec<_> = Currency::records().collect();
+		assert_eq!(records.len(), 179);
+		assert_eq!(Currency::records().len(), COUNT);
+		for (record, currency) in records.iter().zip(Currency::all()) {
+			assert_eq!(record.currency, currency);
+			assert_eq!(record.code,     currency.code());
+			assert_eq!(record.name,     currency.name());
+		}
+	}
+	
+	//		record																
+	#[test]
+	fn record() {
+		let record = Currency::CHF.record();
+		assert_eq!(record.currency,   Currency::CHF);
+		assert_eq!(record.code,       CurrencyCode::CHF);
+		assert_eq!(record.numeric,    756);
+		assert_eq!(record.name,       "Swiss franc");
+		assert_eq!(record.digits,     MinorUnits::Digits(2));
+		assert_eq!(record.unit_names, Currency::CHF.unit_names());
+		assert_eq!(record.introduced, Currency::CHF.introduced());
+		assert_eq!(record.replaces,   Currency::CHF.replaces());
+		assert_eq!(record.entity,     None);
+		assert_eq!(record.countries,  &[ CountryCode::CH, CountryCode::LI ]);
+		assert_eq!(record.languages,  Currency::CHF.languages());
+	}
+	
 	//		info																
 	#[test]
 	fn info() {
@@ -156,6 +366,106 @@ mod currency__enum {
 		assert_eq!(Currency::GBP.name(), "Pound sterling");
 	}
 	
+	//		display_as															
+	#[test]
+	fn display_as() {
+		assert_eq!(Currency::EUR.display_as(DisplayStyle::Code).to_string(),        "EUR");
+		assert_eq!(Currency::EUR.display_as(DisplayStyle::CodeAndName).to_string(), "EUR — Euro");
+	}
+	
+	//		alternative_names													
+	#[test]
+	fn alternative_names() {
+		assert_eq!(Currency::CNY.alternative_names(), vec![ "Chinese yuan", "RMB", "Yuan" ]);
+		assert_eq!(Currency::GBP.alternative_names(), vec![ "British pound", "Sterling" ]);
+		assert!(Currency::EUR.alternative_names().is_empty());
+	}
+	#[test]
+	fn alternative_names__all() {
+		for currency in Currency::all() {
+			for alias in currency.alternative_names() {
+				assert_eq!(Currency::from_str(alias).unwrap(), currency, "{alias}");
+			}
+		}
+		assert!(ALIASES.windows(2).all(|pair| matches!(pair, [a, b] if (a.1.as_str(), a.0) < (b.1.as_str(), b.0))));
+	}
+	
+	//		find																
+	#[test]
+	fn find() {
+		assert_eq!(Currency::find("RMB"),      Some(Currency::CNY));
+		assert_eq!(Currency::find("Sterling"), Some(Currency::GBP));
+		assert_eq!(Currency::find("NIS"),      Some(Currency::ILS));
+		assert_eq!(Currency::find("NT$"),      Some(Currency::TWD));
+		assert_eq!(Currency::find("Foo"),      None);
+	}
+	#[test]
+	fn find__codes() {
+		assert_eq!(Currency::find("cny"),   Some(Currency::CNY));
+		assert_eq!(Currency::find(" GBP "), Some(Currency::GBP));
+	}
+	#[test]
+	fn find__names() {
+		assert_eq!(Currency::find("Renminbi"),       Some(Currency::CNY));
+		assert_eq!(Currency::find("pound sterling"), Some(Currency::GBP));
+	}
+	
+	//		from_symbol															
+	#[test]
+	fn from_symbol() {
+		let dollars = Currency::from_symbol("$");
+		assert!(dollars.contains(&Currency::USD));
+		assert!(dollars.contains(&Currency::CAD));
+		assert!(dollars.contains(&Currency::AUD));
+		assert!(!dollars.contains(&Currency::GBP));
+		assert_eq!(Currency::from_symbol("€"),   vec![ Currency::EUR ]);
+		assert_eq!(Currency::from_symbol(" C$"), vec![ Currency::CAD ]);
+		assert!(Currency::from_symbol("¤").is_empty());
+	}
+	
+	//		from_symbol_in_country												
+	#[test]
+	fn from_symbol_in_country() {
+		assert_eq!(Currency::from_symbol_in_country("$", CountryCode::CA),  Some(Currency::CAD));
+		assert_eq!(Currency::from_symbol_in_country("$", CountryCode::US),  Some(Currency::USD));
+		assert_eq!(Currency::from_symbol_in_country("¥", CountryCode::JP),  Some(Currency::JPY));
+		assert_eq!(Currency::from_symbol_in_country("£", CountryCode::GB),  Some(Currency::GBP));
+		assert_eq!(Currency::from_symbol_in_country("$", CountryCode::AUS), Some(Currency::AUD));
+		assert_eq!(Currency::from_symbol_in_country("€", CountryCode::US),  None);
+	}
+	
+	//		fuzzy_match															
+	#[test]
+	fn fuzzy_match() {
+		let matches = Currency::fuzzy_match("Pound sterlng", 0.8);
+		assert_eq!(matches.len(), 1);
+		assert_eq!(matches.first().map(|&(currency, _)| currency), Some(Currency::GBP));
+		assert!(Currency::fuzzy_match("Foo dollar", 0.9).is_empty());
+	}
+	
+	//		try_from_any														
+	#[cfg(feature = "json")]
+	#[test]
+	fn try_from_any() {
+		use claims::assert_err;
+		use serde_json::json;
+		assert_eq!(Currency::try_from_any(&json!("Pound sterling")).unwrap(), Currency::GBP);
+		assert_eq!(Currency::try_from_any(&json!("gbp")).unwrap(),            Currency::GBP);
+		assert_eq!(Currency::try_from_any(&json!(826)).unwrap(),              Currency::GBP);
+		assert_eq!(Currency::try_from_any(&json!([])).unwrap_err(),           "Invalid Currency: []");
+		assert_err!(Currency::try_from_any(&json!("Foo dollar")));
+		assert_err!(Currency::try_from_any(&json!(1)));
+	}
+	
+	//		parse_with															
+	#[test]
+	fn parse_with() {
+		use crate::parse::ParseOptions;
+		assert_eq!(Currency::parse_with("GBP",      &ParseOptions::strict()).unwrap(),     Currency::GBP);
+		assert_eq!(Currency::parse_with("Sterling", &ParseOptions::lenient()).unwrap(),    Currency::GBP);
+		assert_eq!(Currency::parse_with("gbp",      &ParseOptions::strict()).unwrap_err(), "Invalid Currency: gbp");
+	}
+	
 	//		code																
 	#[test]
 	fn code() {
@@ -165,23 +475,332 @@ mod currency__enum {
 	//		digits																
 	#[test]
 	fn digits() {
-		assert_eq!(Currency::GBP.digits(), 2);
+		assert_eq!(Currency::GBP.digits(), MinorUnits::Digits(2));
+		assert_eq!(Currency::JPY.digits(), MinorUnits::Digits(0));
+		assert_eq!(Currency::XAU.digits(), MinorUnits::NotApplicable);
+		assert_eq!(Currency::XDR.digits(), MinorUnits::NotApplicable);
+	}
+	
+	//		unit_names															
+	#[test]
+	fn unit_names() {
+		let gbp = Currency::GBP.unit_names().unwrap();
+		assert_eq!(gbp.major, UnitName { singular: "pound", plural: "pounds" });
+		assert_eq!(gbp.minor, Some(UnitName { singular: "penny", plural: "pence" }));
+		let jpy = Currency::JPY.unit_names().unwrap();
+		assert_eq!(jpy.major, UnitName { singular: "yen", plural: "yen" });
+		assert_eq!(jpy.minor, None);
+		assert_eq!(Currency::XAU.unit_names(), None);
+		assert_eq!(Currency::CLF.unit_names(), None);
+	}
+	#[test]
+	fn unit_names__all() {
+		for currency in Currency::all() {
+			let Some(names) = currency.unit_names() else { continue };
+			let digits      = currency.digits().digits().unwrap_or(0);
+			assert_eq!(names.minor.is_some(), digits > 0, "{currency:?}");
+			assert!(!names.major.singular.is_empty() && !names.major.plural.is_empty(), "{currency:?}");
+		}
+	}
+	
+	//		classification														
+	#[test]
+	fn classification() {
+		assert_eq!(Currency::USD.classification(), CurrencyClass::Major);
+		assert_eq!(Currency::EUR.classification(), CurrencyClass::Major);
+		assert_eq!(Currency::SEK.classification(), CurrencyClass::Minor);
+		assert_eq!(Currency::TRY.classification(), CurrencyClass::Exotic);
+		assert_eq!(Currency::XAU.classification(), CurrencyClass::Exotic);
+	}
+	
+	//		round_decimal														
+	#[cfg(feature = "rust_decimal")]
+	#[test]
+	fn round_decimal() {
+		use rust_decimal::Decimal;
+		assert_eq!(Currency::GBP.round_decimal(Decimal::new(12_345, 3)), Decimal::new(1_234, 2));
+		assert_eq!(Currency::GBP.round_decimal(Decimal::new(12_355, 3)), Decimal::new(1_236, 2));
+		assert_eq!(Currency::GBP.round_decimal(Decimal::new(-12_345, 3)), Decimal::new(-1_234, 2));
+		assert_eq!(Currency::JPY.round_decimal(Decimal::new(125, 1)), Decimal::new(12, 0));
+		assert_eq!(Currency::BHD.round_decimal(Decimal::new(12_345, 3)), Decimal::new(12_345, 3));
+		assert_eq!(Currency::XAU.round_decimal(Decimal::new(125, 1)), Decimal::new(12, 0));
+	}
+	
+	//		round_bigdecimal													
+	#[cfg(feature = "bigdecimal")]
+	#[test]
+	fn round_bigdecimal() {
+		use bigdecimal::{BigDecimal, num_bigint::BigInt};
+		assert_eq!(Currency::GBP.round_bigdecimal(&BigDecimal::new(BigInt::from(12_345), 3)), BigDecimal::new(BigInt::from(1_234), 2));
+		assert_eq!(Currency::GBP.round_bigdecimal(&BigDecimal::new(BigInt::from(12_355), 3)), BigDecimal::new(BigInt::from(1_236), 2));
+		assert_eq!(Currency::JPY.round_bigdecimal(&BigDecimal::new(BigInt::from(135), 1)), BigDecimal::new(BigInt::from(14), 0));
+		assert_eq!(Currency::BHD.round_bigdecimal(&BigDecimal::new(BigInt::from(12), 0)), BigDecimal::new(BigInt::from(12_000), 3));
+	}
+	
+	//		price_increment														
+	#[test]
+	fn price_increment() {
+		assert_eq!(Currency::GBP.price_increment(), 1);
+		assert_eq!(Currency::JPY.price_increment(), 1);
+		assert_eq!(Currency::CHF.price_increment(), 5);
+		assert_eq!(Currency::SEK.price_increment(), 100);
+		assert!(PRICE_INCREMENTS.windows(2).all(|pair| matches!(pair, [a, b] if a.0 < b.0)));
+	}
+	
+	//		round_price															
+	#[test]
+	fn round_price() {
+		let style = PriceStyle::new();
+		assert_eq!(Currency::GBP.round_price(1_234,   &style),               1_234);
+		assert_eq!(Currency::CHF.round_price(1_232,   &style),               1_230);
+		assert_eq!(Currency::CHF.round_price(1_233,   &style),               1_235);
+		assert_eq!(Currency::SEK.round_price(19_950,  &style),               20_000);
+		assert_eq!(Currency::SEK.round_price(-19_940, &style),               -19_900);
+		assert_eq!(Currency::GBP.round_price(1_234,   &style.increment(50)), 1_250);
+		assert_eq!(Currency::GBP.round_price(1_234,   &style.increment(0)),  1_234);
+	}
+	#[test]
+	fn round_price__charm() {
+		let style = PriceStyle::new().charm(99);
+		assert_eq!(Currency::GBP.round_price(1_000,  &style),                          999);
+		assert_eq!(Currency::GBP.round_price(1_048,  &style),                          999);
+		assert_eq!(Currency::GBP.round_price(1_049,  &style),                          1_099);
+		assert_eq!(Currency::GBP.round_price(999,    &style),                          999);
+		assert_eq!(Currency::GBP.round_price(5,      &style),                          99);
+		assert_eq!(Currency::GBP.round_price(0,      &style),                          0);
+		assert_eq!(Currency::GBP.round_price(-5,     &style),                          -99);
+		assert_eq!(Currency::GBP.round_price(-999,   &style),                          -999);
+		assert_eq!(Currency::GBP.round_price(-1_000, &style),                          -999);
+		assert_eq!(Currency::GBP.round_price(-1_049, &style),                          -1_099);
+		assert_eq!(Currency::JPY.round_price(100,    &PriceStyle::new().charm(980)),   980);
+		assert_eq!(Currency::GBP.round_price(-50,    &PriceStyle::new().charm(10)),    -10);
+		assert_eq!(Currency::EUR.round_price(480,    &PriceStyle::new().charm(95)),    495);
+		assert_eq!(Currency::JPY.round_price(2_013,  &PriceStyle::new().charm(980)),   1_980);
+		assert_eq!(Currency::SEK.round_price(20_000, &PriceStyle::new().charm(9_900)), 19_900);
+	}
+	#[test]
+	fn round_price__rounding() {
+		let up   = PriceStyle::new().charm(99).rounding(PriceRounding::Up);
+		let down = PriceStyle::new().charm(99).rounding(PriceRounding::Down);
+		assert_eq!(Currency::GBP.round_price(1_000,    &up),   1_099);
+		assert_eq!(Currency::GBP.round_price(1_098,    &down), 999);
+		assert_eq!(Currency::GBP.round_price(1_099,    &down), 1_099);
+		assert_eq!(Currency::GBP.round_price(i64::MAX, &up),   i64::MAX);
+		assert_eq!(Currency::GBP.round_price(-1_000,   &up),   -1_099);
+		assert_eq!(Currency::GBP.round_price(-1_098,   &down), -999);
+		assert_eq!(Currency::GBP.round_price(-5,       &down), -99);
+		assert_eq!(Currency::GBP.round_price(i64::MIN, &up),   -i64::MAX);
+		assert_eq!(Currency::CHF.round_price(1_231, &PriceStyle::new().rounding(PriceRounding::Up)), 1_235);
+	}
+	
+	//		format_price														
+	#[test]
+	fn format_price() {
+		let style = PriceStyle::new();
+		assert_eq!(Currency::GBP.format_price(1_000,  &style),              "10.00");
+		assert_eq!(Currency::GBP.format_price(1_000,  &style.charm(99)),    "9.99");
+		assert_eq!(Currency::GBP.format_price(-1_234, &style),              "-12.34");
+		assert_eq!(Currency::GBP.format_price(-1_000, &style.charm(99)),    "-9.99");
+		assert_eq!(Currency::GBP.format_price(5,      &style),              "0.05");
+		assert_eq!(Currency::GBP.format_price(5,      &style.charm(99)),    "0.99");
+		assert_eq!(Currency::GBP.format_price(0,      &style.charm(99)),    "0.00");
+		assert_eq!(Currency::JPY.format_price(100,    &style.charm(980)),   "980");
+		assert_eq!(Currency::JPY.format_price(1_980,  &style),              "1980");
+		assert_eq!(Currency::SEK.format_price(19_940, &style),              "199");
+		assert_eq!(Currency::SEK.format_price(19_940, &style.increment(1)), "199.40");
+		assert_eq!(Currency::SEK.format_price(19_900, &style.charm(99)),    "198.99");
+		assert_eq!(Currency::CHF.format_price(1_000,  &style),              "10.00");
+		assert_eq!(Currency::BHD.format_price(1_234,  &style),              "1.234");
+		assert_eq!(Currency::XAU.format_price(12,     &style),              "12");
+	}
+	
+	//		parse_amount														
+	#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+	#[test]
+	fn parse_amount() {
+		use crate::money::Money;
+		assert_eq!(Currency::GBP.parse_amount("1,234.56"),     Ok(Money::new(123_456,    Currency::GBP)));
+		assert_eq!(Currency::EUR.parse_amount("1.234,56"),     Ok(Money::new(123_456,    Currency::EUR)));
+		assert_eq!(Currency::EUR.parse_amount("1 234,56"),     Ok(Money::new(123_456,    Currency::EUR)));
+		assert_eq!(Currency::EUR.parse_amount("1\u{a0}234,56"), Ok(Money::new(123_456,    Currency::EUR)));
+		assert_eq!(Currency::CHF.parse_amount("1'234.56"),     Ok(Money::new(123_456,    Currency::CHF)));
+		assert_eq!(Currency::INR.parse_amount("1,23,456.78"),  Ok(Money::new(12_345_678, Currency::INR)));
+		assert_eq!(Currency::GBP.parse_amount("1,234,567"),    Ok(Money::new(123_456_700, Currency::GBP)));
+		assert_eq!(Currency::GBP.parse_amount(" 12.5 "),       Ok(Money::new(1_250,      Currency::GBP)));
+		assert_eq!(Currency::GBP.parse_amount("-12.34"),       Ok(Money::new(-1_234,     Currency::GBP)));
+		assert_eq!(Currency::GBP.parse_amount("+12"),          Ok(Money::new(1_200,      Currency::GBP)));
+		assert_eq!(Currency::GBP.parse_amount(".5"),           Ok(Money::new(50,         Currency::GBP)));
+		assert_eq!(Currency::JPY.parse_amount("1,234"),        Ok(Money::new(1_234,      Currency::JPY)));
+		assert_eq!(Currency::XAU.parse_amount("12"),           Ok(Money::new(12,         Currency::XAU)));
+	}
+	#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+	#[test]
+	fn parse_amount__ambiguous() {
+		use crate::money::Money;
+		assert_eq!(Currency::GBP.parse_amount("1,234"),        Ok(Money::new(123_400,    Currency::GBP)));
+		assert_eq!(Currency::EUR.parse_amount("1.234"),        Ok(Money::new(123_400,    Currency::EUR)));
+		assert_eq!(Currency::KWD.parse_amount("1,234"),        Ok(Money::new(1_234,      Currency::KWD)));
+		assert_eq!(Currency::EUR.parse_amount("1,23"),         Ok(Money::new(123,        Currency::EUR)));
+	}
+	#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+	#[test]
+	fn parse_amount__invalid() {
+		use rubedo::sugar::s;
+		assert_eq!(Currency::GBP.parse_amount("1,234.567"),    Err(s!("Too many decimal places for GBP: 1,234.567")));
+		assert_eq!(Currency::GBP.parse_amount("12.3456"),      Err(s!("Too many decimal places for GBP: 12.3456")));
+		assert_eq!(Currency::JPY.parse_amount("12.5"),         Err(s!("Too many decimal places for JPY: 12.5")));
+		assert_eq!(Currency::XAU.parse_amount("12.5"),         Err(s!("Too many decimal places for XAU: 12.5")));
+		assert_eq!(Currency::GBP.parse_amount(""),             Err(s!("Invalid amount: ")));
+		assert_eq!(Currency::GBP.parse_amount("12."),          Err(s!("Invalid amount: 12.")));
+		assert_eq!(Currency::GBP.parse_amount("abc"),          Err(s!("Invalid amount: abc")));
+		assert_eq!(Currency::GBP.parse_amount("1,2,3"),        Err(s!("Invalid amount: 1,2,3")));
+		assert_eq!(Currency::GBP.parse_amount("1,234 567.89"), Err(s!("Invalid amount: 1,234 567.89")));
+		assert_eq!(Currency::GBP.parse_amount("1.234.567,8.9"), Err(s!("Invalid amount: 1.234.567,8.9")));
+		assert_eq!(Currency::GBP.parse_amount("£12"),          Err(s!("Invalid amount: £12")));
+		assert_eq!(Currency::GBP.parse_amount("99999999999999999999"), Err(s!("Amount out of range: 99999999999999999999")));
+	}
+	
+	//		introduced															
+	#[test]
+	fn introduced() {
+		assert_eq!(Currency::EUR.introduced(), Some(1999));
+		assert_eq!(Currency::SLE.introduced(), Some(2022));
+		assert_eq!(Currency::GBP.introduced(), None);
+	}
+	
+	//		last_changed														
+	#[test]
+	fn last_changed() {
+		assert_eq!(Currency::EUR.last_changed(), Some("2023-01-01"));
+		assert_eq!(Currency::VES.last_changed(), Some("2018-08-20"));
+		assert_eq!(Currency::GBP.last_changed(), None);
+	}
+	#[test]
+	fn last_changed__data() {
+		assert!(CHANGES.windows(2).all(|pair| matches!(pair, [a, b] if a.0 < b.0)));
+		assert!(CHANGES.iter().all(|entry| entry.1 <= crate::DATA_VERSION));
+		for currency in Currency::changes_since("") {
+			let year = currency.last_changed().and_then(|date| date.get(..4)).and_then(|year| year.parse().ok());
+			assert!(year >= currency.introduced());
+		}
+	}
+	
+	//		replaces															
+	#[test]
+	fn replaces() {
+		assert_eq!(Currency::SLE.replaces(), Some("SLL"));
+		assert_eq!(Currency::VES.replaces(), Some("VEF"));
+		assert_eq!(Currency::GBP.replaces(), None);
+	}
+	
+	//		replaced_by															
+	#[test]
+	fn replaced_by() {
+		assert_eq!(Currency::SLL.replaced_by(), Some(Currency::SLE));
+		assert_eq!(Currency::VES.replaced_by(), None);
+		assert_eq!(Currency::SDG.replaced_by(), None);
+		assert_eq!(Currency::SLE.replaced_by(), None);
+	}
+	#[test]
+	fn replaced_by__relationships() {
+		for currency in Currency::all() {
+			if let Some(successor) = currency.replaced_by() {
+				assert_eq!(successor.replaces(), Some(currency.code().as_str()));
+			}
+		}
+	}
+	
+	//		is_phasing_out														
+	#[test]
+	fn is_phasing_out() {
+		assert!( Currency::SLL.is_phasing_out());
+		assert!(!Currency::SLE.is_phasing_out());
+		assert!(!Currency::SDG.is_phasing_out());
+		assert!(!Currency::VES.is_phasing_out());
+		assert!(!Currency::GBP.is_phasing_out());
+	}
+	
+	//		entity																
+	#[test]
+	fn entity() {
+		assert_eq!(Currency::XDR.entity(), Some("International Monetary Fund (IMF)"));
+		assert_eq!(Currency::XUA.entity(), Some("African Development Bank Group (AfDB)"));
+		assert_eq!(Currency::XAU.entity(), None);
+		assert_eq!(Currency::GBP.entity(), None);
+	}
+	#[test]
+	fn entity__all() {
+		for currency in Currency::all() {
+			if currency.entity().is_some() {
+				assert!(currency.code().as_str().starts_with('X'), "{currency:?}");
+			}
+		}
+	}
+	
+	//		symbols																
+	#[test]
+	fn symbols() {
+		assert_eq!(Currency::CAD.symbols(), vec![ "$", "C$", "CA$" ]);
+		assert_eq!(Currency::GBP.symbols(), vec![ "£" ]);
+		assert!(Currency::XAU.symbols().is_empty());
+	}
+	#[test]
+	fn symbols__all() {
+		for currency in Currency::all() {
+			for symbol in currency.symbols() {
+				assert!(Currency::from_symbol(symbol).contains(&currency), "{symbol}");
+			}
+		}
+		assert!(SYMBOLS.windows(2).all(|pair| matches!(pair, [a, b] if (a.1.as_str(), a.0) < (b.1.as_str(), b.0))));
 	}
 	
 	//		countries															
 	#[test]
 	fn countries() {
-		assert_eq!(Currency::GBP.countries(), &vh![ CountryCode: GB, GG, IM, JE, SH ]);
+		assert_eq!(Currency::GBP.countries(), &[ CountryCode::GB, CountryCode::GG, CountryCode::IM, CountryCode::JE, CountryCode::SH ]);
+	}
+	#[test]
+	fn countries__sorted() {
+		for currency in Currency::all() {
+			let countries = currency.countries();
+			assert!(countries.iter().zip(countries.iter().skip(1)).all(|(a, b)| a.as_str() < b.as_str()));
+		}
 	}
 	#[test]
 	fn countries__relationships() {
-		#[expect(clippy::iter_over_hash_type, reason = "Order is not important here")]
-		for currency in CURRENCIES.keys() {
+		for currency in Currency::all() {
 			for country_code in currency.countries() {
 				assert!(country_code.country().currencies().contains(&currency.code()));
 			}
 		}
 	}
+	
+	//		countries_set														
+	#[test]
+	fn countries_set() {
+		assert_eq!(Currency::GBP.countries_set(), HashSet::from([ CountryCode::GB, CountryCode::GG, CountryCode::IM, CountryCode::JE, CountryCode::SH ]));
+	}
+	
+	//		languages															
+	#[test]
+	fn languages() {
+		assert_eq!(Currency::CHF.languages(), &[ LanguageCode::DE, LanguageCode::FR, LanguageCode::IT, LanguageCode::RM ]);
+		assert!(Currency::XAU.languages().is_empty());
+	}
+	#[test]
+	fn languages__relationships() {
+		for currency in Currency::all() {
+			for language_code in currency.languages() {
+				assert!(language_code.language().currencies().contains(&currency.code()));
+			}
+		}
+	}
+	
+	//		languages_set														
+	#[test]
+	fn languages_set() {
+		assert_eq!(Currency::CHF.languages_set(), HashSet::from([ LanguageCode::DE, LanguageCode::FR, LanguageCode::IT, LanguageCode::RM ]));
+	}
 }
 
 #[cfg(test)]
@@ -229,6 +848,13 @@ mod currency__traits {
 	
 	//		from																
 	#[test]
+	fn from__currency_for_u16() {
+		let currency = Currency::USD;
+		assert_eq!(u16::from(currency), 840);
+		let int: u16 = currency.into();
+		assert_eq!(int,                 840);
+	}
+	#[test]
 	fn from__currency_for_string() {
 		let currency = Currency::USD;
 		assert_eq!(String::from(currency), "United States dollar");
@@ -244,6 +870,24 @@ mod currency__traits {
 		assert_err!(&err);
 		assert_eq!(err.unwrap_err(), "Invalid Currency: Foo dollar");
 	}
+	#[test]
+	fn from_str__normalized() {
+		assert_eq!(Currency::from_str("Polish zloty").unwrap(),    Currency::PLN);
+		assert_eq!(Currency::from_str("vietnamese dong").unwrap(), Currency::VND);
+		assert_eq!(Currency::from_str("Tongan pa'anga").unwrap(),  Currency::TOP);
+	}
+	#[test]
+	fn from_str__all() {
+		for currency in Currency::all() {
+			assert_eq!(Currency::from_str(currency.name()).unwrap(), currency);
+		}
+	}
+	#[test]
+	fn from_str__alternative_names() {
+		assert_eq!(Currency::from_str("RMB").unwrap(),       Currency::CNY);
+		assert_eq!(Currency::from_str("sterling").unwrap(),  Currency::GBP);
+		assert_eq!(Currency::from_str("Greenback").unwrap(), Currency::USD);
+	}
 	
 	//		serialize															
 	#[test]
@@ -261,4 +905,97 @@ mod currency__traits {
 	}
 }
 
+//		CurrencyClass															
+#[cfg(test)]
+mod currency_class__traits {
+	use super::super::*;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(CurrencyClass::Major.as_str(),  "Major");
+		assert_eq!(CurrencyClass::Exotic.as_str(), "Exotic");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(CurrencyClass::Minor.to_string(), "Minor");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&CurrencyClass::Major).unwrap(), r#""Major""#);
+	}
+}
+
+//		MinorUnits																
+#[cfg(test)]
+mod minor_units__enum {
+	use super::super::*;
+	
+	//		digits																
+	#[test]
+	fn digits() {
+		assert_eq!(MinorUnits::Digits(2).digits(),     Some(2));
+		assert_eq!(MinorUnits::NotApplicable.digits(), None);
+	}
+	
+	//		decimal_places														
+	#[test]
+	fn decimal_places() {
+		assert_eq!(MinorUnits::Digits(3).decimal_places(),     3);
+		assert_eq!(MinorUnits::NotApplicable.decimal_places(), 0);
+	}
+	
+	//		is_applicable														
+	#[test]
+	fn is_applicable() {
+		assert!( MinorUnits::Digits(0).is_applicable());
+		assert!(!MinorUnits::NotApplicable.is_applicable());
+	}
+}
 
+#[cfg(test)]
+mod minor_units__traits {
+	use super::super::*;
+	use serde_json;
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		assert_eq!(serde_json::from_str::<MinorUnits>("2").unwrap(),    MinorUnits::Digits(2));
+		assert_eq!(serde_json::from_str::<MinorUnits>("null").unwrap(), MinorUnits::NotApplicable);
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(MinorUnits::Digits(2).to_string(),     "2");
+		assert_eq!(MinorUnits::NotApplicable.to_string(), "N.A.");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&MinorUnits::Digits(2)).unwrap(),     "2");
+		assert_eq!(serde_json::to_string(&MinorUnits::NotApplicable).unwrap(), "null");
+	}
+}
+
+//		UnitName																
+#[cfg(test)]
+mod unit_name__struct {
+	use super::super::*;
+	
+	//		for_count															
+	#[test]
+	fn for_count() {
+		let penny = UnitName { singular: "penny", plural: "pence" };
+		assert_eq!(penny.for_count(1), "penny");
+		assert_eq!(penny.for_count(0), "pence");
+		assert_eq!(penny.for_count(2), "pence");
+	}
+}
@@ -0,0 +1,89 @@
+//		Tests
+
+//		DisplayStyle															
+#[cfg(test)]
+mod display_style__traits {
+	use super::super::*;
+	use serde_json;
+	
+	//		default																
+	#[test]
+	fn default() {
+		assert_eq!(DisplayStyle::default(), DisplayStyle::Name);
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&DisplayStyle::CodeAndName).unwrap(), r#""CodeAndName""#);
+	}
+}
+
+//		Styled																	
+#[cfg(test)]
+mod styled__traits {
+	use super::super::*;
+	use crate::{
+		country::Country,
+		currency::Currency,
+		language::Language,
+	};
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(Styled::new(Country::DE,   DisplayStyle::Code).to_string(),        "DE");
+		assert_eq!(Styled::new(Country::DE,   DisplayStyle::Name).to_string(),        "Germany");
+		assert_eq!(Styled::new(Country::DE,   DisplayStyle::CodeAndName).to_string(), "DE — Germany");
+		assert_eq!(Styled::new(Currency::JPY, DisplayStyle::CodeAndName).to_string(), "JPY — Japanese yen");
+		assert_eq!(Styled::new(Language::DE,  DisplayStyle::CodeAndName).to_string(), "de — German");
+	}
+}
+
+//		PriceRounding															
+#[cfg(test)]
+mod price_rounding__traits {
+	use super::super::*;
+	
+	//		default																
+	#[test]
+	fn default() {
+		assert_eq!(PriceRounding::default(), PriceRounding::Nearest);
+	}
+}
+
+//		PriceStyle																
+#[cfg(test)]
+mod price_style__struct {
+	use super::super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		assert_eq!(PriceStyle::new(), PriceStyle::default());
+		assert_eq!(PriceStyle::new().increment_or(5), 5);
+		assert_eq!(PriceStyle::new().increment(0).increment_or(5), 1);
+	}
+	
+	//		apply																
+	#[test]
+	fn apply() {
+		assert_eq!(PriceStyle::new().apply(1_234, 1),                                 1_234);
+		assert_eq!(PriceStyle::new().apply(1_250, 100),                               1_300);
+		assert_eq!(PriceStyle::new().apply(1_249, 100),                               1_200);
+		assert_eq!(PriceStyle::new().charm(0).apply(1_234, 100),                      1_230);
+		assert_eq!(PriceStyle::new().charm(9).apply(1_234, 1),                        1_239);
+		assert_eq!(PriceStyle::new().charm(u32::MAX).apply(1_234, 1),                 i64::from(u32::MAX));
+		assert_eq!(PriceStyle::new().rounding(PriceRounding::Down).apply(1_299, 100), 1_200);
+	}
+	#[test]
+	fn apply__sign() {
+		assert_eq!(PriceStyle::new().charm(99).apply(0, 1),                                 0);
+		assert_eq!(PriceStyle::new().charm(99).apply(5, 1),                                 99);
+		assert_eq!(PriceStyle::new().charm(99).rounding(PriceRounding::Down).apply(5, 1),   99);
+		assert_eq!(PriceStyle::new().charm(10).apply(-50, 1),                               -10);
+		assert_eq!(PriceStyle::new().charm(10).rounding(PriceRounding::Up).apply(-50, 1),   -110);
+		assert_eq!(PriceStyle::new().charm(10).rounding(PriceRounding::Down).apply(-50, 1), -10);
+		assert_eq!(PriceStyle::new().charm(99).apply(-5, 1),                                -99);
+	}
+}
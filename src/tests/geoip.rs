@@ -0,0 +1,62 @@
+//		Tests
+
+//		CountryResolver															
+#[cfg(test)]
+mod country_resolver__traits {
+	use super::super::*;
+	use core::net::{Ipv4Addr, Ipv6Addr};
+	
+	//		Resolver															
+	/// A resolver backed by a fixed list of addresses.
+	struct Resolver {
+		//		Private properties												
+		/// The addresses and their countries.
+		addresses: Vec<(IpAddr, Country)>,
+	}
+	
+	//󰭅		CountryResolver														
+	impl CountryResolver for Resolver {
+		//		resolve															
+		fn resolve(&self, ip: IpAddr) -> Option<Country> {
+			self.addresses.iter().find(|&&(address, _)| address == ip).map(|&(_, country)| country)
+		}
+	}
+	
+	//		resolve																
+	#[test]
+	fn resolve() {
+		let resolver = Resolver { addresses: vec![
+			(IpAddr::V4(Ipv4Addr::new(81, 2, 69, 160)), Country::GB),
+			(IpAddr::V6(Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 1)), Country::FR),
+		] };
+		assert_eq!(resolver.resolve(IpAddr::V4(Ipv4Addr::new(81, 2, 69, 160))),               Some(Country::GB));
+		assert_eq!(resolver.resolve(IpAddr::V6(Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 1))), Some(Country::FR));
+		assert_eq!(resolver.resolve(IpAddr::V4(Ipv4Addr::LOCALHOST)),                          None);
+	}
+	#[test]
+	fn resolve__closure() {
+		let resolver = |ip: IpAddr| ip.is_ipv4().then_some(Country::US);
+		assert_eq!(resolver.resolve(IpAddr::V4(Ipv4Addr::LOCALHOST)), Some(Country::US));
+		assert_eq!(resolver.resolve(IpAddr::V6(Ipv6Addr::LOCALHOST)), None);
+	}
+	#[test]
+	fn resolve__dyn() {
+		let resolver: Box<dyn CountryResolver> = Box::new(|_: IpAddr| Some(Country::DE));
+		assert_eq!(resolver.resolve(IpAddr::V4(Ipv4Addr::UNSPECIFIED)), Some(Country::DE));
+	}
+	#[cfg(feature = "maxminddb")]
+	#[test]
+	fn resolve__maxminddb() {
+		//	The fixture is a GeoIP2-Country database holding 81.2.69.160/27, which
+		//	has both a country and a registered country, and 217.65.48.0/29, which
+		//	only has a registered country.
+		let reader = Reader::from_source(include_bytes!("data/geoip2-country.mmdb").as_slice()).unwrap();
+		assert_eq!(reader.metadata.database_type,                             "GeoIP2-Country");
+		assert_eq!(reader.resolve(IpAddr::V4(Ipv4Addr::new(81, 2, 69, 160))), Some(Country::GB));
+		assert_eq!(reader.resolve(IpAddr::V4(Ipv4Addr::new(81, 2, 69, 191))), Some(Country::GB));
+		assert_eq!(reader.resolve(IpAddr::V4(Ipv4Addr::new(217, 65, 48, 1))), Some(Country::GI));
+		assert_eq!(reader.resolve(IpAddr::V4(Ipv4Addr::new(81, 2, 69, 192))), None);
+		assert_eq!(reader.resolve(IpAddr::V4(Ipv4Addr::LOCALHOST)),           None);
+		assert_eq!(reader.resolve(IpAddr::V6(Ipv6Addr::LOCALHOST)),           None);
+	}
+}
@@ -0,0 +1,177 @@
+//		Tests
+
+//		RetiredCurrencyCode														
+#[cfg(test)]
+mod retired_currency_code__enum {
+	use super::super::*;
+	
+	//		all																	
+	#[test]
+	fn all() {
+		let all = RetiredCurrencyCode::all();
+		assert_eq!(all.len(), 36);
+		for code in all {
+			assert_eq!(code.as_str().parse::<RetiredCurrencyCode>().unwrap(), code);
+			assert_eq!(RetiredCurrencyCode::try_from(code.numeric()).unwrap(), code);
+			assert!(CurrencyCode::from_str(code.as_str()).is_err());
+			assert!(CurrencyCode::try_from(code.numeric()).is_err());
+		}
+	}
+	
+	//		name																
+	#[test]
+	fn name() {
+		assert_eq!(RetiredCurrencyCode::HRK.name(), "Croatian kuna");
+	}
+	
+	//		numeric																
+	#[test]
+	fn numeric() {
+		assert_eq!(RetiredCurrencyCode::HRK.numeric(), 191);
+		assert_eq!(RetiredCurrencyCode::ATS.numeric(), 40);
+	}
+	
+	//		withdrawn															
+	#[test]
+	fn withdrawn() {
+		assert_eq!(RetiredCurrencyCode::HRK.withdrawn(), 2023);
+	}
+	
+	//		replaced_by															
+	#[test]
+	fn replaced_by() {
+		assert_eq!(RetiredCurrencyCode::HRK.replaced_by(), CurrencyCode::EUR);
+		assert_eq!(RetiredCurrencyCode::ROL.replaced_by(), CurrencyCode::RON);
+	}
+}
+
+#[cfg(test)]
+mod retired_currency_code__traits {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(RetiredCurrencyCode::HRK.as_str(), "HRK");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(RetiredCurrencyCode::DEM.to_string(), "DEM");
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let code: RetiredCurrencyCode = serde_json::from_str(r#""HRK""#).unwrap();
+		assert_eq!(code, RetiredCurrencyCode::HRK);
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		assert_eq!(RetiredCurrencyCode::from_str("HRK").unwrap(), RetiredCurrencyCode::HRK);
+		assert_eq!(RetiredCurrencyCode::from_str("hrk").unwrap(), RetiredCurrencyCode::HRK);
+		let err = RetiredCurrencyCode::from_str("EUR");
+		assert_err!(&err);
+		assert_eq!(err.unwrap_err(), "Invalid RetiredCurrencyCode: EUR");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&RetiredCurrencyCode::HRK).unwrap(), r#""HRK""#);
+	}
+	
+	//		try_from__u16														
+	#[test]
+	fn try_from__u16() {
+		assert_eq!(RetiredCurrencyCode::try_from(191).unwrap(), RetiredCurrencyCode::HRK);
+		let err = RetiredCurrencyCode::try_from(978);
+		assert_err!(&err);
+		assert_eq!(err.unwrap_err(), "Invalid RetiredCurrencyCode: 978");
+	}
+}
+
+//		AnyCurrencyCode															
+#[cfg(test)]
+mod any_currency_code__enum {
+	use super::super::*;
+	
+	//		is_retired															
+	#[test]
+	fn is_retired() {
+		assert!( AnyCurrencyCode::Retired(RetiredCurrencyCode::HRK).is_retired());
+		assert!(!AnyCurrencyCode::Current(CurrencyCode::EUR).is_retired());
+	}
+	
+	//		numeric																
+	#[test]
+	fn numeric() {
+		assert_eq!(AnyCurrencyCode::Retired(RetiredCurrencyCode::HRK).numeric(), 191);
+		assert_eq!(AnyCurrencyCode::Current(CurrencyCode::EUR).numeric(),        978);
+	}
+	
+	//		current																
+	#[test]
+	fn current() {
+		assert_eq!(AnyCurrencyCode::Retired(RetiredCurrencyCode::HRK).current(), CurrencyCode::EUR);
+		assert_eq!(AnyCurrencyCode::Current(CurrencyCode::GBP).current(),        CurrencyCode::GBP);
+	}
+}
+
+#[cfg(test)]
+mod any_currency_code__traits {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json;
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let retired: AnyCurrencyCode = serde_json::from_str(r#""VEF""#).unwrap();
+		assert_eq!(retired, AnyCurrencyCode::Retired(RetiredCurrencyCode::VEF));
+		let current: AnyCurrencyCode = serde_json::from_str(r#""VES""#).unwrap();
+		assert_eq!(current, AnyCurrencyCode::Current(CurrencyCode::VES));
+	}
+	
+	//		from																
+	#[test]
+	fn from() {
+		assert_eq!(AnyCurrencyCode::from(CurrencyCode::EUR),        AnyCurrencyCode::Current(CurrencyCode::EUR));
+		assert_eq!(AnyCurrencyCode::from(RetiredCurrencyCode::HRK), AnyCurrencyCode::Retired(RetiredCurrencyCode::HRK));
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		assert_eq!(AnyCurrencyCode::from_str("EUR").unwrap(), AnyCurrencyCode::Current(CurrencyCode::EUR));
+		assert_eq!(AnyCurrencyCode::from_str("hrk").unwrap(), AnyCurrencyCode::Retired(RetiredCurrencyCode::HRK));
+		let err = AnyCurrencyCode::from_str("XYZ");
+		assert_err!(&err);
+		assert_eq!(err.unwrap_err(), "Invalid AnyCurrencyCode: XYZ");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&AnyCurrencyCode::Retired(RetiredCurrencyCode::HRK)).unwrap(), r#""HRK""#);
+		assert_eq!(serde_json::to_string(&AnyCurrencyCode::Current(CurrencyCode::EUR)).unwrap(),        r#""EUR""#);
+	}
+	
+	//		try_from__u16														
+	#[test]
+	fn try_from__u16() {
+		assert_eq!(AnyCurrencyCode::try_from(978).unwrap(), AnyCurrencyCode::Current(CurrencyCode::EUR));
+		assert_eq!(AnyCurrencyCode::try_from(191).unwrap(), AnyCurrencyCode::Retired(RetiredCurrencyCode::HRK));
+		assert_eq!(AnyCurrencyCode::try_from(642).unwrap(), AnyCurrencyCode::Retired(RetiredCurrencyCode::ROL));
+		let err = AnyCurrencyCode::try_from(1);
+		assert_err!(&err);
+		assert_eq!(err.unwrap_err(), "Invalid AnyCurrencyCode: 1");
+	}
+}
+
+
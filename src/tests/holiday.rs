@@ -0,0 +1,83 @@
+//		Tests
+
+//		MonthDay																
+#[cfg(test)]
+mod month_day__struct {
+	use super::super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let date = MonthDay::new(7, 14).unwrap();
+		assert_eq!(date.month(), 7);
+		assert_eq!(date.day(),   14);
+		assert!(MonthDay::new(2, 29) .is_some());
+		assert!(MonthDay::new(2, 30) .is_none());
+		assert!(MonthDay::new(4, 31) .is_none());
+		assert!(MonthDay::new(0, 1)  .is_none());
+		assert!(MonthDay::new(13, 1) .is_none());
+		assert!(MonthDay::new(1, 0)  .is_none());
+	}
+}
+
+#[cfg(test)]
+mod month_day__traits {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json;
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(MonthDay::new(7, 4).unwrap().to_string(), "--07-04");
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let date: MonthDay = serde_json::from_str(r#""--12-25""#).unwrap();
+		assert_eq!(date, MonthDay::new(12, 25).unwrap());
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		assert_eq!(MonthDay::from_str("--07-14").unwrap(), MonthDay::new(7, 14).unwrap());
+		let err = MonthDay::from_str("07-14");
+		assert_err!(&err);
+		assert_eq!(err.unwrap_err(), "Invalid MonthDay: 07-14");
+		assert_err!(MonthDay::from_str("--02-30"));
+		assert_err!(MonthDay::from_str("--7-14"));
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&MonthDay::new(1, 26).unwrap()).unwrap(), r#""--01-26""#);
+	}
+}
+
+//		NationalDays															
+#[cfg(test)]
+mod national_days__traits {
+	use super::super::*;
+	
+	//		holidays															
+	#[test]
+	fn holidays() {
+		let holidays = NationalDays.holidays(Country::FR, None, 2024);
+		assert_eq!(holidays.len(),     1);
+		assert_eq!(holidays[0].name(), "National Day");
+		assert_eq!(holidays[0].date(), MonthDay::new(7, 14).unwrap());
+		assert!(NationalDays.holidays(Country::GB, None, 2024).is_empty());
+	}
+	
+	//		is_holiday															
+	#[test]
+	fn is_holiday() {
+		assert!( NationalDays.is_holiday(Country::US, None, 2024, MonthDay::new(7, 4).unwrap()));
+		assert!(!NationalDays.is_holiday(Country::US, None, 2024, MonthDay::new(7, 5).unwrap()));
+	}
+}
+
+
@@ -0,0 +1,62 @@
+//		Tests
+
+//		CodeColumn																
+#[cfg(test)]
+mod code_column__struct {
+	use super::super::*;
+	use crate::{
+		country::CountryCode,
+		currency::{Currency, CurrencyCode},
+	};
+	use claims::assert_err_eq;
+	use csv::ReaderBuilder;
+	use rubedo::sugar::s;
+	
+	/// Sample data with a mix of valid and invalid codes.
+	const DATA: &str = "name,country,currency\nAlice,GB,GBP\nBob,XX,EUR\nCarol,fra,usd\n";
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let rows: Vec<_> = CodeColumn::<_, CountryCode>::new(Reader::from_reader(DATA.as_bytes()), "country").unwrap().collect();
+		assert_eq!(rows.len(), 3);
+		assert_eq!(rows[0].as_ref().unwrap().0,         CountryCode::GB);
+		assert_eq!(rows[0].as_ref().unwrap().1.get(0), Some("Alice"));
+		assert_eq!(rows[1],                             Err((3, s!("Invalid CountryCode: XX"))));
+		assert_eq!(rows[2].as_ref().unwrap().0,         CountryCode::FRA);
+	}
+	#[test]
+	fn new__currency() {
+		let codes: Vec<_> = CodeColumn::<_, CurrencyCode>::new(Reader::from_reader(DATA.as_bytes()), "currency")
+			.unwrap()
+			.map(|row| row.map(|(code, _)| code))
+			.collect();
+		assert_eq!(codes, vec![ Ok(CurrencyCode::GBP), Ok(CurrencyCode::EUR), Ok(CurrencyCode::USD) ]);
+	}
+	#[test]
+	fn new__names() {
+		let data       = "code,name
+GBP,Pound Sterling
+";
+		let mut values = CodeColumn::<_, Currency>::new(Reader::from_reader(data.as_bytes()), "name").unwrap();
+		assert_eq!(values.next().unwrap().unwrap().0, Currency::GBP);
+	}
+	#[test]
+	fn new__missing_column() {
+		assert_err_eq!(CodeColumn::<_, CountryCode>::new(Reader::from_reader(DATA.as_bytes()), "region"), s!("Missing column: region"));
+	}
+	
+	//		with_index															
+	#[test]
+	fn with_index() {
+		let reader = ReaderBuilder::new().has_headers(false).flexible(true).from_reader(&b"GB,1\nDE\n2,FR\n"[..]);
+		let rows: Vec<_> = CodeColumn::<_, CountryCode>::with_index(reader, 1)
+			.map(|row| row.map(|(code, _)| code))
+			.collect();
+		assert_eq!(rows, vec![
+			Err((1, s!("Invalid CountryCode: 1"))),
+			Err((2, s!("Missing column: 1"))),
+			Ok(CountryCode::FR),
+		]);
+	}
+}
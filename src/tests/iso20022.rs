@@ -0,0 +1,108 @@
+//		Tests
+
+//		Functions
+#[cfg(test)]
+mod functions {
+	use super::super::*;
+	use claims::assert_err_eq;
+	use rubedo::sugar::s;
+	
+	//		validate_active_currency_code										
+	#[test]
+	fn validate_active_currency_code() {
+		assert_eq!(super::super::validate_active_currency_code("EUR"), Ok(CurrencyCode::EUR));
+		assert_eq!(super::super::validate_active_currency_code("XAU"), Ok(CurrencyCode::XAU));
+		assert_err_eq!(super::super::validate_active_currency_code("eur"),  s!("Invalid ActiveCurrencyCode: eur"));
+		assert_err_eq!(super::super::validate_active_currency_code(" EUR"), s!("Invalid ActiveCurrencyCode:  EUR"));
+		assert_err_eq!(super::super::validate_active_currency_code("ABC"),  s!("Invalid ActiveCurrencyCode: ABC"));
+		assert_err_eq!(super::super::validate_active_currency_code("978"),  s!("Invalid ActiveCurrencyCode: 978"));
+	}
+	#[test]
+	fn validate_active_currency_code__all() {
+		for code in CurrencyCode::all() {
+			assert_eq!(super::super::validate_active_currency_code(code.as_str()), Ok(code));
+		}
+	}
+	
+	//		validate_active_or_historic_currency_code							
+	#[cfg(feature = "historic")]
+	#[test]
+	fn validate_active_or_historic_currency_code() {
+		use crate::historic::{AnyCurrencyCode, RetiredCurrencyCode};
+		assert_eq!(super::super::validate_active_or_historic_currency_code("EUR"), Ok(AnyCurrencyCode::Current(CurrencyCode::EUR)));
+		assert_eq!(super::super::validate_active_or_historic_currency_code("DEM"), Ok(AnyCurrencyCode::Retired(RetiredCurrencyCode::DEM)));
+		assert_err_eq!(super::super::validate_active_or_historic_currency_code("dem"), s!("Invalid ActiveOrHistoricCurrencyCode: dem"));
+		assert_err_eq!(super::super::validate_active_or_historic_currency_code("ABC"), s!("Invalid ActiveOrHistoricCurrencyCode: ABC"));
+	}
+	
+	//		validate_country_code												
+	#[test]
+	fn validate_country_code() {
+		assert_eq!(super::super::validate_country_code("GB"), Ok(CountryCode::GB));
+		assert_err_eq!(super::super::validate_country_code("gb"),  s!("Invalid CountryCode: gb"));
+		assert_err_eq!(super::super::validate_country_code("GBR"), s!("Invalid CountryCode: GBR"));
+		assert_err_eq!(super::super::validate_country_code("XX"),  s!("Invalid CountryCode: XX"));
+		assert_err_eq!(super::super::validate_country_code(""),    s!("Invalid CountryCode: "));
+	}
+	#[test]
+	fn validate_country_code__all() {
+		for code in CountryCode::all().into_iter().filter(CountryCode::is_alpha2) {
+			assert_eq!(super::super::validate_country_code(code.as_str()), Ok(code));
+		}
+	}
+}
+
+//		Codecs																	
+#[cfg(test)]
+mod codecs {
+	use super::super::*;
+	use crate::{country::Country, currency::Currency};
+	use serde_json::{from_str, json, to_value};
+	
+	//		Transaction															
+	/// A message fragment with ISO 20022 code fields.
+	#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+	struct Transaction {
+		/// The currency.
+		#[serde(rename = "Ccy", with = "crate::iso20022::active_currency_code")]
+		currency: Currency,
+		
+		/// The country.
+		#[serde(rename = "Ctry", with = "crate::iso20022::country_code")]
+		country:  CountryCode,
+		
+		/// The optional charges currency.
+		#[serde(rename = "ChrgsCcy", with = "crate::iso20022::active_currency_code::option")]
+		charges:  Option<CurrencyCode>,
+		
+		/// The optional country of residence.
+		#[serde(rename = "CtryOfRes", with = "crate::iso20022::country_code::option")]
+		resident: Option<Country>,
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		assert_eq!(
+			from_str::<Transaction>(r#"{"Ccy": "EUR", "Ctry": "FR", "ChrgsCcy": "GBP", "CtryOfRes": null}"#).unwrap(),
+			Transaction { currency: Currency::EUR, country: CountryCode::FR, charges: Some(CurrencyCode::GBP), resident: None },
+		);
+	}
+	#[test]
+	fn deserialize__invalid() {
+		assert!(from_str::<Transaction>(r#"{"Ccy": "eur", "Ctry": "FR",  "ChrgsCcy": null, "CtryOfRes": null}"#).is_err());
+		assert!(from_str::<Transaction>(r#"{"Ccy": "EUR", "Ctry": "FRA", "ChrgsCcy": null, "CtryOfRes": null}"#).is_err());
+		assert!(from_str::<Transaction>(r#"{"Ccy": "EUR", "Ctry": "FR",  "ChrgsCcy": "ABC", "CtryOfRes": null}"#).is_err());
+		assert!(from_str::<Transaction>(r#"{"Ccy": "EUR", "Ctry": "FR",  "ChrgsCcy": null, "CtryOfRes": "XX"}"#).is_err());
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		let transaction = Transaction { currency: Currency::EUR, country: CountryCode::FRA, charges: None, resident: Some(Country::DE) };
+		assert_eq!(
+			to_value(&transaction).unwrap(),
+			json!({ "Ccy": "EUR", "Ctry": "FR", "ChrgsCcy": null, "CtryOfRes": "DE" }),
+		);
+	}
+}
@@ -1,5 +1,18 @@
 //		Tests
 
+//		Constants
+#[cfg(test)]
+mod constants {
+	use super::super::*;
+	
+	//		count																
+	#[test]
+	fn count() {
+		assert_eq!(COUNT, Language::all().len());
+		assert_eq!(COUNT, LanguageCode::all().len());
+	}
+}
+
 //		LanguageCode															
 #[cfg(test)]
 mod language_code__enum {
@@ -15,6 +28,42 @@ mod language_code__enum {
 		assert!(codes.contains(&LanguageCode::ES));
 	}
 	
+	//		complete															
+	#[test]
+	fn complete() {
+		assert_eq!(LanguageCode::complete("e"),  vec![ LanguageCode::EE, LanguageCode::EL, LanguageCode::EN, LanguageCode::EO, LanguageCode::ES, LanguageCode::ET, LanguageCode::EU ]);
+		assert_eq!(LanguageCode::complete("EN"), vec![ LanguageCode::EN ]);
+		assert_eq!(LanguageCode::complete("").len(), 183);
+		assert!(LanguageCode::complete("enx").is_empty());
+	}
+	
+	//		from_code															
+	#[test]
+	fn from_code() {
+		assert_eq!(LanguageCode::from_code("en"),  Some(LanguageCode::EN));
+		assert_eq!(LanguageCode::from_code("EN"),  Some(LanguageCode::EN));
+		assert_eq!(LanguageCode::from_code("xx"),  None);
+		assert_eq!(LanguageCode::from_code("eng"), None);
+	}
+	#[test]
+	fn from_code__all() {
+		for code in LanguageCode::all() {
+			assert_eq!(LanguageCode::from_code(code.as_str()), Some(code));
+		}
+	}
+	
+	//		const_from_str														
+	#[test]
+	fn const_from_str() {
+		const CODES: [LanguageCode; 2] = [LanguageCode::const_from_str("en"), LanguageCode::const_from_str("fr")];
+		assert_eq!(CODES, [LanguageCode::EN, LanguageCode::FR]);
+	}
+	#[test]
+	#[should_panic(expected = "Invalid LanguageCode")]
+	fn const_from_str__invalid() {
+		let _code = LanguageCode::const_from_str("xx");
+	}
+	
 	//		language															
 	#[test]
 	fn language() {
@@ -24,17 +73,42 @@ mod language_code__enum {
 	}
 	#[test]
 	fn language__all() {
-		#[expect(clippy::iter_over_hash_type, reason = "Order is not important here")]
-		for language in LANGUAGES.keys() {
-			assert_eq!(language.code().language(), *language);
+		for language in Language::all() {
+			assert_eq!(language.code().language(), language);
 		}
 	}
+	
+	//		numeric																
+	#[test]
+	fn numeric() {
+		assert_eq!(LanguageCode::AA.numeric(), 1);
+		assert_eq!(LanguageCode::AB.numeric(), 2);
+		assert_eq!(LanguageCode::EN.numeric(), 118);
+		assert_eq!(LanguageCode::ZU.numeric(), 671);
+	}
+	#[test]
+	fn numeric__all() {
+		let mut ids = HashSet::new();
+		for code in LanguageCode::all() {
+			assert!((1..=676).contains(&code.numeric()));
+			assert_eq!(code.numeric(), u16::from(code));
+			assert_eq!(LanguageCode::try_from(code.numeric()).unwrap(), code);
+			assert!(ids.insert(code.numeric()));
+		}
+	}
+	
+	//		stable_hash															
+	#[test]
+	fn stable_hash() {
+		assert_eq!(LanguageCode::EN.stable_hash(), 0x088e_3807_b539_46aa);
+	}
 }
 
 #[cfg(test)]
 mod language_code__traits {
 	use super::super::*;
 	use claims::assert_err;
+	use rubedo::sugar::s;
 	use serde_json;
 	
 	//		as_str																
@@ -78,6 +152,13 @@ mod language_code__traits {
 	
 	//		from																
 	#[test]
+	fn from__language_code_for_u16() {
+		let code = LanguageCode::EN;
+		assert_eq!(u16::from(code), 118);
+		let int: u16 = code.into();
+		assert_eq!(int,             118);
+	}
+	#[test]
 	fn from__language_code_for_string() {
 		let code = LanguageCode::EN;
 		assert_eq!(String::from(code), "en");
@@ -94,6 +175,23 @@ mod language_code__traits {
 		assert_err!(&err);
 		assert_eq!(err.unwrap_err(), "Invalid LanguageCode: foo");
 	}
+	#[test]
+	fn from_str__mixed_case() {
+		assert_eq!(LanguageCode::from_str("eN").unwrap(), LanguageCode::EN);
+		assert_err!(LanguageCode::from_str("ｅｎ"));
+	}
+	#[test]
+	fn from_str__whitespace() {
+		assert_err!(LanguageCode::from_str(" en "));
+		assert_eq!(Language::parse_with(" en ", &ParseOptions::strict().trim(true)).unwrap(), Language::EN);
+	}
+	
+	//		ord																	
+	#[test]
+	fn ord() {
+		assert!(LanguageCode::DE < LanguageCode::EN);
+		assert!(LanguageCode::EN < LanguageCode::FR);
+	}
 	
 	//		serialize															
 	#[test]
@@ -103,6 +201,16 @@ mod language_code__traits {
 	
 	//		try_from															
 	#[test]
+	fn try_from__u16() {
+		assert_eq!(LanguageCode::try_from(118).unwrap(), LanguageCode::EN);
+		let err = LanguageCode::try_from(0);
+		assert_err!(&err);
+		assert_eq!(err.unwrap_err(), "Invalid LanguageCode: 0");
+		assert_err!(LanguageCode::try_from(3));
+		assert_err!(LanguageCode::try_from(677));
+		assert_err!(LanguageCode::try_from(u16::MAX));
+	}
+	#[test]
 	fn try_from__string() {
 		assert_eq!(LanguageCode::try_from(s!("en")).unwrap(), LanguageCode::EN);
 		assert_eq!(LanguageCode::try_from(s!("EN")).unwrap(), LanguageCode::EN);
@@ -127,6 +235,59 @@ mod language__enum {
 		assert!(languages.contains(&Language::ES));
 	}
 	
+	//		discriminants														
+	#[test]
+	fn discriminants() {
+		//	These values are frozen, and must never change. New variants must be
+		//	added to the end of this list.
+		const FROZEN: [&str; 183] = [
+			"AA", "AB", "AE", "AF", "AK", "AM", "AN", "AR", "AS", "AV", "AY", "AZ", "BA", "BE", "BG", "BI",
+			"BM", "BN", "BO", "BR", "BS", "CA", "CE", "CH", "CO", "CR", "CS", "CU", "CV", "CY", "DA", "DE",
+			"DV", "DZ", "EE", "EL", "EN", "EO", "ES", "ET", "EU", "FA", "FF", "FI", "FJ", "FO", "FR", "FY",
+			"GA", "GD", "GL", "GN", "GU", "GV", "HA", "HE", "HI", "HO", "HR", "HT", "HU", "HY", "HZ", "IA",
+			"ID", "IE", "IG", "II", "IK", "IO", "IS", "IT", "IU", "JA", "JV", "KA", "KG", "KI", "KJ", "KK",
+			"KL", "KM", "KN", "KO", "KR", "KS", "KU", "KV", "KW", "KY", "LA", "LB", "LG", "LI", "LN", "LO",
+			"LT", "LU", "LV", "MG", "MH", "MI", "MK", "ML", "MN", "MR", "MS", "MT", "MY", "NA", "NB", "ND",
+			"NE", "NG", "NL", "NN", "NO", "NR", "NV", "NY", "OC", "OJ", "OM", "OR", "OS", "PA", "PI", "PL",
+			"PS", "PT", "QU", "RM", "RN", "RO", "RU", "RW", "SA", "SC", "SD", "SE", "SG", "SI", "SK", "SL",
+			"SM", "SN", "SO", "SQ", "SR", "SS", "ST", "SU", "SV", "SW", "TA", "TE", "TG", "TH", "TI", "TK",
+			"TL", "TN", "TO", "TR", "TS", "TT", "TW", "TY", "UG", "UK", "UR", "UZ", "VE", "VI", "VO", "WA",
+			"WO", "XH", "YI", "YO", "ZA", "ZH", "ZU",
+		];
+		for (index, code) in FROZEN.iter().enumerate() {
+			assert_eq!(LanguageCode::from_str(code).unwrap().language() as usize, index);
+		}
+		assert_eq!(Language::AA as u16, 0);
+		assert_eq!(Language::ZU as u16, 182);
+		assert!(Language::AA < Language::ZU);
+	}
+	
+	//		records																
+	#[test]
+	fn records() {
+		let records: Vec<_> = Language::records().collect();
+		assert_eq!(records.len(), 183);
+		assert_eq!(Language::records().len(), COUNT);
+		for (record, language) in records.iter().zip(Language::all()) {
+			assert_eq!(record.language, language);
+			assert_eq!(record.code,     language.code());
+			assert_eq!(record.name,     language.name());
+		}
+	}
+	
+	//		record																
+	#[test]
+	fn record() {
+		let record = Language::NO.record();
+		assert_eq!(record.language,   Language::NO);
+		assert_eq!(record.code,       LanguageCode::NO);
+		assert_eq!(record.name,       "Norwegian");
+		assert_eq!(record.family,     Some(LanguageFamily::GMQ));
+		assert_eq!(record.scripts,    &[ Script::Latn ]);
+		assert_eq!(record.countries,  &[ CountryCode::BV, CountryCode::NO, CountryCode::SJ ]);
+		assert_eq!(record.currencies, &[ CurrencyCode::NOK ]);
+	}
+	
 	//		info																
 	#[test]
 	fn info() {
@@ -141,6 +302,114 @@ mod language__enum {
 		assert_eq!(Language::NO.name(), "Norwegian");
 	}
 	
+	//		display_as															
+	#[test]
+	fn display_as() {
+		assert_eq!(Language::FR.display_as(DisplayStyle::Code).to_string(),        "fr");
+		assert_eq!(Language::FR.display_as(DisplayStyle::CodeAndName).to_string(), "fr — French");
+	}
+	
+	//		alternative_names													
+	#[test]
+	fn alternative_names() {
+		assert_eq!(Language::ES.alternative_names(), vec![ "Castilian" ]);
+		assert_eq!(Language::RO.alternative_names(), vec![ "Moldavian", "Moldovan" ]);
+		assert!(Language::EN.alternative_names().is_empty());
+	}
+	#[test]
+	fn alternative_names__all() {
+		for language in Language::all() {
+			for alias in language.alternative_names() {
+				assert_eq!(Language::from_str(alias).unwrap(), language, "{alias}");
+			}
+		}
+		assert!(ALIASES.windows(2).all(|pair| matches!(pair, [a, b] if (a.1.as_str(), a.0) < (b.1.as_str(), b.0))));
+	}
+	
+	//		family																
+	#[test]
+	fn family() {
+		assert_eq!(Language::EN.family(), Some(LanguageFamily::GMW));
+		assert_eq!(Language::PL.family(), Some(LanguageFamily::ZLW));
+		assert_eq!(Language::EU.family(), None);
+	}
+	
+	//		families															
+	#[test]
+	fn families() {
+		assert_eq!(Language::EN.families(), vec![ LanguageFamily::GMW, LanguageFamily::GEM, LanguageFamily::INE ]);
+		assert_eq!(Language::FR.families(), vec![ LanguageFamily::ROA, LanguageFamily::ITC, LanguageFamily::INE ]);
+		assert!(Language::KO.families().is_empty());
+	}
+	
+	//		is_in_family														
+	#[test]
+	fn is_in_family() {
+		assert!( Language::EN.is_in_family(LanguageFamily::GEM));
+		assert!( Language::RU.is_in_family(LanguageFamily::SLA));
+		assert!(!Language::RU.is_in_family(LanguageFamily::ZLW));
+		assert!(!Language::EU.is_in_family(LanguageFamily::INE));
+	}
+	
+	//		scripts																
+	#[test]
+	fn scripts() {
+		assert_eq!(Language::EN.scripts(), &[ Script::Latn ]);
+		assert_eq!(Language::SR.scripts(), &[ Script::Cyrl, Script::Latn ]);
+		assert_eq!(Language::UZ.scripts(), &[ Script::Latn, Script::Cyrl ]);
+		assert_eq!(Language::KK.scripts(), &[ Script::Cyrl, Script::Latn, Script::Arab ]);
+		assert_eq!(Language::ZH.scripts(), &[ Script::Hans, Script::Hant ]);
+		assert_eq!(Language::JA.scripts(), &[ Script::Jpan ]);
+	}
+	
+	//		likely_script														
+	#[test]
+	fn likely_script() {
+		assert_eq!(Language::ZH.likely_script(None),              Some(Script::Hans));
+		assert_eq!(Language::ZH.likely_script(Some(Country::CN)), Some(Script::Hans));
+		assert_eq!(Language::ZH.likely_script(Some(Country::TW)), Some(Script::Hant));
+		assert_eq!(Language::SR.likely_script(Some(Country::RS)), Some(Script::Cyrl));
+		assert_eq!(Language::SR.likely_script(Some(Country::ME)), Some(Script::Latn));
+		assert_eq!(Language::EN.likely_script(Some(Country::TW)), Some(Script::Latn));
+	}
+	#[test]
+	fn likely_script__all() {
+		assert!(LIKELY_SCRIPTS.windows(2).all(|pair| matches!(pair, [a, b] if a.0 < b.0)));
+		for &((language, country), script) in &LIKELY_SCRIPTS {
+			assert!(language.language().scripts().contains(&script), "{language}-{country}");
+			assert_ne!(language.language().scripts().first(), Some(&script), "{language}-{country}");
+		}
+	}
+	
+	//		fuzzy_match															
+	#[test]
+	fn fuzzy_match() {
+		let matches = Language::fuzzy_match("Englsh", 0.8);
+		assert_eq!(matches.first().map(|&(language, _)| language), Some(Language::EN));
+		assert!(Language::fuzzy_match("Fooish", 0.9).is_empty());
+	}
+	
+	//		try_from_any														
+	#[cfg(feature = "json")]
+	#[test]
+	fn try_from_any() {
+		use claims::assert_err;
+		use serde_json::json;
+		assert_eq!(Language::try_from_any(&json!("English")).unwrap(), Language::EN);
+		assert_eq!(Language::try_from_any(&json!("en")).unwrap(),      Language::EN);
+		assert_eq!(Language::try_from_any(&json!(1)).unwrap_err(),     "Invalid Language: 1");
+		assert_err!(Language::try_from_any(&json!("Fooish")));
+	}
+	
+	//		parse_with															
+	#[test]
+	fn parse_with() {
+		use crate::parse::ParseOptions;
+		assert_eq!(Language::parse_with("en",      &ParseOptions::strict()).unwrap(),     Language::EN);
+		assert_eq!(Language::parse_with("English", &ParseOptions::lenient()).unwrap(),    Language::EN);
+		assert_eq!(Language::parse_with("EN",      &ParseOptions::strict()).unwrap_err(), "Invalid Language: EN");
+	}
+	
 	//		code																
 	#[test]
 	fn code() {
@@ -150,17 +419,70 @@ mod language__enum {
 	//		countries															
 	#[test]
 	fn countries() {
-		assert_eq!(Language::NO.countries(), &vh![ CountryCode: BV, NO, SJ ]);
+		assert_eq!(Language::NO.countries(), &[ CountryCode::BV, CountryCode::NO, CountryCode::SJ ]);
+	}
+	
+	//		countries_set														
+	#[test]
+	fn countries_set() {
+		assert_eq!(Language::NO.countries_set(), HashSet::from([ CountryCode::BV, CountryCode::NO, CountryCode::SJ ]));
 	}
 	#[test]
 	fn countries__relationships() {
-		#[expect(clippy::iter_over_hash_type, reason = "Order is not important here")]
-		for language in LANGUAGES.keys() {
+		for language in Language::all() {
 			for country_code in language.countries() {
 				assert!(country_code.country().languages().contains(&language.code()));
 			}
 		}
 	}
+	#[test]
+	fn countries__sorted() {
+		for language in Language::all() {
+			assert!(language.countries().windows(2).all(|pair| matches!(pair, [a, b] if a < b)), "{language:?}");
+		}
+	}
+	
+	//		currencies															
+	#[test]
+	fn currencies() {
+		assert_eq!(Language::NO.currencies(), &[ CurrencyCode::NOK ]);
+		assert_eq!(Language::RM.currencies(), &[ CurrencyCode::CHE, CurrencyCode::CHF, CurrencyCode::CHW ]);
+		assert!(Language::EO.currencies().is_empty());
+	}
+	#[test]
+	fn currencies__sorted() {
+		for language in Language::all() {
+			let currencies = language.currencies();
+			assert!(currencies.iter().zip(currencies.iter().skip(1)).all(|(a, b)| a.as_str() < b.as_str()));
+		}
+	}
+	
+	//		currencies_set														
+	#[test]
+	fn currencies_set() {
+		assert_eq!(Language::RM.currencies_set(), HashSet::from([ CurrencyCode::CHE, CurrencyCode::CHF, CurrencyCode::CHW ]));
+	}
+	
+	//		plural_categories													
+	#[cfg(feature = "plurals")]
+	#[test]
+	fn plural_categories() {
+		use crate::plural::PluralCategory::{Few, Many, One, Other};
+		assert_eq!(Language::EN.plural_categories(), &[One, Other]);
+		assert_eq!(Language::PL.plural_categories(), &[One, Few, Many, Other]);
+		assert_eq!(Language::ZH.plural_categories(), &[Other]);
+	}
+	
+	//		plural_category														
+	#[cfg(feature = "plurals")]
+	#[test]
+	fn plural_category() {
+		use crate::plural::PluralCategory::{Few, Many, One, Other};
+		assert_eq!(Language::EN.plural_category(1),  One);
+		assert_eq!(Language::EN.plural_category(5),  Other);
+		assert_eq!(Language::PL.plural_category(22), Few);
+		assert_eq!(Language::PL.plural_category(25), Many);
+	}
 }
 
 #[cfg(test)]
@@ -208,12 +530,25 @@ mod language__traits {
 	
 	//		from																
 	#[test]
+	fn from__language_for_u16() {
+		let language = Language::EN;
+		assert_eq!(u16::from(language), 118);
+		let int: u16 = language.into();
+		assert_eq!(int,                 118);
+	}
+	#[test]
 	fn from__language_for_string() {
 		let language = Language::EN;
 		assert_eq!(String::from(language), "English");
 		let str: String = language.into();
 		assert_eq!(str,                    "English");
 	}
+	#[cfg(feature = "icu")]
+	#[test]
+	fn from__language_for_icu_language() {
+		use icu_locale_core::subtags;
+		assert_eq!(subtags::Language::from(Language::EN).as_str(), "en");
+	}
 	
 	//		from_str															
 	#[test]
@@ -223,6 +558,30 @@ mod language__traits {
 		assert_err!(&err);
 		assert_eq!(err.unwrap_err(), "Invalid Language: Fooish");
 	}
+	#[test]
+	fn from_str__normalized() {
+		assert_eq!(Language::from_str("norwegian bokmal").unwrap(), Language::NB);
+		assert_eq!(Language::from_str("VOLAPUK").unwrap(),          Language::VO);
+	}
+	#[test]
+	fn from_str__all() {
+		for language in Language::all() {
+			assert_eq!(Language::from_str(language.name()).unwrap(), language);
+		}
+	}
+	#[test]
+	fn from_str__alternative_names() {
+		assert_eq!(Language::from_str("Farsi").unwrap(),                Language::FA);
+		assert_eq!(Language::from_str("Greek, Modern (1453-)").unwrap(), Language::EL);
+		assert_eq!(Language::from_str("modern greek").unwrap(),         Language::EL);
+		assert_eq!(Language::from_str("Flemish").unwrap(),              Language::NL);
+	}
+	#[test]
+	fn from_str__codes() {
+		assert_eq!(Language::from_str("fa").unwrap(),   Language::FA);
+		assert_eq!(Language::from_str(" EL ").unwrap(), Language::EL);
+		assert_err!(Language::from_str("xx"));
+	}
 	
 	//		serialize															
 	#[test]
@@ -238,6 +597,104 @@ mod language__traits {
 		assert_err!(&err);
 		assert_eq!(err.unwrap_err(), "Invalid Language: Fooish");
 	}
+	#[cfg(feature = "icu")]
+	#[test]
+	fn try_from__icu_language() {
+		use icu_locale_core::subtags::language;
+		use rubedo::sugar::s;
+		assert_eq!(Language::try_from(language!("fr")),  Ok(Language::FR));
+		assert_eq!(Language::try_from(language!("yue")), Err(s!("Invalid Language: yue")));
+		assert_err!(Language::try_from(language!("und")));
+	}
+}
+
+//		LanguageFamily															
+#[cfg(test)]
+mod language_family__enum {
+	use super::super::*;
+	
+	//		all																	
+	#[test]
+	fn all() {
+		let all = LanguageFamily::all();
+		assert_eq!(all.len(), 51);
+		for (index, family) in all.iter().enumerate() {
+			assert_eq!(*family as usize, index);
+		}
+	}
+	
+	//		name																
+	#[test]
+	fn name() {
+		assert_eq!(LanguageFamily::GEM.name(), "Germanic languages");
+		assert_eq!(LanguageFamily::SLA.name(), "Slavic languages");
+	}
+	
+	//		parent																
+	#[test]
+	fn parent() {
+		assert_eq!(LanguageFamily::GMW.parent(), Some(LanguageFamily::GEM));
+		assert_eq!(LanguageFamily::GEM.parent(), Some(LanguageFamily::INE));
+		assert_eq!(LanguageFamily::INE.parent(), None);
+	}
+	
+	//		languages															
+	#[test]
+	fn languages() {
+		assert_eq!(LanguageFamily::ZLE.languages(), vec![ Language::BE, Language::RU, Language::UK ]);
+		assert!(LanguageFamily::SLA.languages().contains(&Language::PL));
+		assert!(!LanguageFamily::GEM.languages().contains(&Language::FR));
+	}
+	#[test]
+	fn languages__all() {
+		for family in LanguageFamily::all() {
+			assert!(!family.languages().is_empty(), "{family} has no languages");
+		}
+	}
+}
+
+//		LanguageFamily															
+#[cfg(test)]
+mod language_family__traits {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(LanguageFamily::GEM.as_str(), "gem");
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let family: LanguageFamily = serde_json::from_str(r#""gem""#).unwrap();
+		assert_eq!(family, LanguageFamily::GEM);
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(LanguageFamily::GEM.to_string(), "gem");
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		assert_eq!(LanguageFamily::from_str("sla").unwrap(), LanguageFamily::SLA);
+		assert_eq!(LanguageFamily::from_str("SLA").unwrap(), LanguageFamily::SLA);
+		let err = LanguageFamily::from_str("foo");
+		assert_err!(&err);
+		assert_eq!(err.unwrap_err(), "Invalid LanguageFamily: foo");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&LanguageFamily::GEM).unwrap(), r#""gem""#);
+	}
+
 }
 
 
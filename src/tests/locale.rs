@@ -0,0 +1,92 @@
+//		Tests
+
+//		Functions																
+#[cfg(test)]
+mod functions {
+	use super::super::*;
+	
+	//		negotiate															
+	#[test]
+	fn negotiate() {
+		assert_eq!(super::super::negotiate("fr-CA, fr;q=0.9, en;q=0.8", None), vec![
+			(Language::FR, Some(Country::CA)),
+			(Language::FR, None),
+			(Language::EN, None),
+		]);
+	}
+	#[test]
+	fn negotiate__quality_order() {
+		assert_eq!(super::super::negotiate("en;q=0.5, de, fr;q=0.7", None), vec![
+			(Language::DE, None),
+			(Language::FR, None),
+			(Language::EN, None),
+		]);
+	}
+	#[test]
+	fn negotiate__country_hint() {
+		assert_eq!(super::super::negotiate("fr, en-GB;q=0.8, nl;q=0.5", Some(Country::BE)), vec![
+			(Language::FR, Some(Country::BE)),
+			(Language::EN, Some(Country::GB)),
+			(Language::NL, Some(Country::BE)),
+		]);
+		assert_eq!(super::super::negotiate("ja", Some(Country::BE)), vec![ (Language::JA, None) ]);
+	}
+	#[test]
+	fn negotiate__fallback_to_hint() {
+		assert_eq!(super::super::negotiate("", Some(Country::CH)), vec![
+			(Language::DE, Some(Country::CH)),
+			(Language::FR, Some(Country::CH)),
+			(Language::IT, Some(Country::CH)),
+			(Language::RM, Some(Country::CH)),
+		]);
+		assert_eq!(super::super::negotiate("*", Some(Country::FR)), vec![ (Language::FR, Some(Country::FR)) ]);
+		assert!(super::super::negotiate("*", None).is_empty());
+	}
+	#[test]
+	fn negotiate__ignored() {
+		assert_eq!(super::super::negotiate("xx, en;q=0, de;q=2, fr;q=abc, es;q=0.1, *;q=0.5", None), vec![
+			(Language::ES, None),
+		]);
+	}
+	#[test]
+	fn negotiate__duplicates() {
+		assert_eq!(super::super::negotiate("en-GB, en-gb;q=0.9, en_GB;q=0.8", None), vec![ (Language::EN, Some(Country::GB)) ]);
+	}
+	#[test]
+	fn negotiate__subtags() {
+		assert_eq!(super::super::negotiate("zh-Hant-TW, sr-Latn, es-419, en-x-gb", None), vec![
+			(Language::ZH, Some(Country::TW)),
+			(Language::SR, None),
+			(Language::ES, None),
+			(Language::EN, None),
+		]);
+	}
+	
+	//		select																
+	#[test]
+	fn select() {
+		assert_eq!(super::super::select("pt-BR", None),           Some((Language::PT, Some(Country::BR))));
+		assert_eq!(super::super::select("PT", Some(Country::PT)),  Some((Language::PT, Some(Country::PT))));
+		assert_eq!(super::super::select("tlh", Some(Country::US)), None);
+	}
+	
+	//		to_icu_locale														
+	#[cfg(feature = "icu")]
+	#[test]
+	fn to_icu_locale() {
+		assert_eq!(super::super::to_icu_locale(Language::FR, Some(Country::CA)).to_string(), "fr-CA");
+		assert_eq!(super::super::to_icu_locale(Language::EN, None).to_string(),             "en");
+	}
+	
+	//		from_icu_locale														
+	#[cfg(feature = "icu")]
+	#[test]
+	fn from_icu_locale() {
+		use icu_locale_core::locale;
+		assert_eq!(super::super::from_icu_locale(&locale!("fr-CA")),              Ok((Language::FR, Some(Country::CA))));
+		assert_eq!(super::super::from_icu_locale(&locale!("zh-Hant-TW-u-ca-roc")), Ok((Language::ZH, Some(Country::TW))));
+		assert_eq!(super::super::from_icu_locale(&locale!("de")),                 Ok((Language::DE, None)));
+		assert!(super::super::from_icu_locale(&locale!("es-419")).is_err());
+		assert!(super::super::from_icu_locale(&locale!("und")).is_err());
+	}
+}
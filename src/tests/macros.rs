@@ -0,0 +1,37 @@
+//		Tests
+
+//		Macros																	
+#[cfg(test)]
+mod macros {
+	use crate::{Country, Currency, Language, country, currency, language};
+	
+	//		country!															
+	#[test]
+	fn country() {
+		const COUNTRY: Country = country!("US");
+		assert_eq!(country!("GB"),  Country::GB);
+		assert_eq!(country!("gb"),  Country::GB);
+		assert_eq!(country!("GBR"), Country::GB);
+		assert_eq!(COUNTRY,         Country::US);
+	}
+	
+	//		currency!															
+	#[test]
+	fn currency() {
+		const CURRENCY: Currency = currency!("EUR");
+		assert_eq!(currency!("USD"), Currency::USD);
+		assert_eq!(currency!("usd"), Currency::USD);
+		assert_eq!(CURRENCY,         Currency::EUR);
+	}
+	
+	//		language!															
+	#[test]
+	fn language() {
+		const LANGUAGE: Language = language!("fr");
+		assert_eq!(language!("en"), Language::EN);
+		assert_eq!(language!("EN"), Language::EN);
+		assert_eq!(LANGUAGE,        Language::FR);
+	}
+}
+
+
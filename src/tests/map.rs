@@ -0,0 +1,55 @@
+//		Tests
+
+//		SortedMap																
+#[cfg(test)]
+mod sorted_map__struct {
+	use super::super::*;
+	use rubedo::sugar::s;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let map = SortedMap::new([ ("b", 2), ("a", 1), ("c", 3) ]);
+		assert_eq!(&*map.entries, &[ ("a", 1), ("b", 2), ("c", 3) ]);
+	}
+	#[test]
+	fn new__duplicates() {
+		let map = SortedMap::new([ ("b", 1), ("a", 2), ("b", 3), ("b", 4) ]);
+		assert_eq!(&*map.entries, &[ ("a", 2), ("b", 1) ]);
+	}
+	
+	//		get																	
+	#[test]
+	fn get() {
+		let map = SortedMap::new([ (s!("france"), 1), (s!("germany"), 2) ]);
+		assert_eq!(map.get("germany"),     Some(&2));
+		assert_eq!(map.get(&s!("france")), Some(&1));
+		assert_eq!(map.get("spain"),       None);
+	}
+	
+}
+
+//		Functions
+#[cfg(test)]
+mod functions {
+	//		get																	
+	#[test]
+	fn get() {
+		let entries = [ (1, "a"), (3, "c"), (5, "e"), (7, "g") ];
+		assert_eq!(super::super::get(&entries, &1), Some(&"a"));
+		assert_eq!(super::super::get(&entries, &7), Some(&"g"));
+		assert_eq!(super::super::get(&entries, &4), None);
+		assert_eq!(super::super::get::<i32, i32, &str>(&[], &1), None);
+	}
+	
+	//		get_ignore_ascii_case												
+	#[test]
+	fn get_ignore_ascii_case() {
+		let entries = [ ("AB", 1), ("CD", 2), ("EF", 3) ];
+		assert_eq!(super::super::get_ignore_ascii_case(&entries, "AB"), Some(&1));
+		assert_eq!(super::super::get_ignore_ascii_case(&entries, "cd"), Some(&2));
+		assert_eq!(super::super::get_ignore_ascii_case(&entries, "eF"), Some(&3));
+		assert_eq!(super::super::get_ignore_ascii_case(&entries, "ab<"), None);
+		assert_eq!(super::super::get_ignore_ascii_case::<i32>(&[], "AB"), None);
+	}
+}
@@ -0,0 +1,62 @@
+//		Tests
+
+//		Constants
+#[cfg(test)]
+mod constants {
+	use super::super::*;
+	
+	//		crate_version														
+	#[test]
+	fn crate_version() {
+		assert_eq!(CRATE_VERSION, env!("CARGO_PKG_VERSION"));
+	}
+	
+	//		data_version														
+	#[test]
+	fn data_version() {
+		use crate::text::matches_format;
+		assert!(matches_format("####-##-##", DATA_VERSION));
+		let parts: Vec<u16> = DATA_VERSION.split('-').map(|part| part.parse().unwrap()).collect();
+		let (month, day)    = (parts[1], parts[2]);
+		assert!((1..=12).contains(&month));
+		assert!((1..=31).contains(&day));
+	}
+}
+
+//		Functions
+#[cfg(test)]
+mod functions {
+	//		sources																
+	#[test]
+	fn sources() {
+		let sources = super::super::sources();
+		assert_eq!(sources.len(), 26);
+		assert!(sources.iter().all(|source| source.url.starts_with("https://")));
+		assert!(sources.windows(2).all(|pair| matches!(pair, [a, b] if a.module <= b.module)));
+	}
+	#[test]
+	fn sources__editions() {
+		use crate::DATA_VERSION;
+		let year = DATA_VERSION.split('-').next().unwrap();
+		for edition in super::super::sources().iter().filter_map(|source| source.edition) {
+			let published = edition.rsplit(':').next().and_then(|part| part.get(..4)).unwrap();
+			assert!(published <= year, "{edition}");
+		}
+	}
+	
+	//		sources_for															
+	#[test]
+	fn sources_for() {
+		let sources = super::super::sources_for("country");
+		assert_eq!(sources.len(), 5);
+		assert_eq!(sources[0].name,    "ISO 3166-1");
+		assert_eq!(sources[0].edition, Some("ISO 3166-1:2020"));
+		assert_eq!(sources[0].url,     "https://www.iso.org/iso-3166-country-codes.html");
+		assert_eq!(super::super::sources_for("currency")[0].name, "ISO 4217");
+	}
+	#[test]
+	fn sources_for__unknown() {
+		assert!(super::super::sources_for("display").is_empty());
+		assert!(super::super::sources_for("foo").is_empty());
+	}
+}
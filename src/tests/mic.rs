@@ -0,0 +1,153 @@
+//		Tests
+
+//		Constants
+#[cfg(test)]
+mod constants {
+	use super::super::*;
+	
+	//		count																
+	#[test]
+	fn count() {
+		assert_eq!(Mic::all().len(), COUNT);
+		for (index, info) in MICS.iter().enumerate() {
+			assert_eq!(info.code as usize, index);
+			assert_eq!(info.operating.operating(), info.operating);
+		}
+	}
+}
+
+//		Mic																		
+#[cfg(test)]
+mod mic__enum {
+	use super::super::*;
+	
+	//		all																	
+	#[test]
+	fn all() {
+		for code in Mic::all() {
+			assert_eq!(code.as_str().parse::<Mic>().unwrap(), code);
+			assert_eq!(code.operating().country_code(), code.country_code());
+		}
+	}
+	
+	//		in_country															
+	#[test]
+	fn in_country() {
+		assert_eq!(Mic::in_country(CountryCode::JP), vec![ Mic::XJPX, Mic::XOSE, Mic::XTKS ]);
+		assert!(Mic::in_country(CountryCode::AQ).is_empty());
+	}
+	
+	//		name																
+	#[test]
+	fn name() {
+		assert_eq!(Mic::XLON.name(), "London Stock Exchange");
+		assert_eq!(Mic::XNGS.name(), "Nasdaq Global Select Market");
+	}
+	
+	//		kind																
+	#[test]
+	fn kind() {
+		assert_eq!(Mic::XNAS.kind(), MicType::Operating);
+		assert_eq!(Mic::XNGS.kind(), MicType::Segment);
+	}
+	
+	//		is_operating														
+	#[test]
+	fn is_operating() {
+		assert!( Mic::XLON.is_operating());
+		assert!(!Mic::AIMX.is_operating());
+	}
+	
+	//		is_segment															
+	#[test]
+	fn is_segment() {
+		assert!( Mic::AIMX.is_segment());
+		assert!(!Mic::XLON.is_segment());
+	}
+	
+	//		operating															
+	#[test]
+	fn operating() {
+		assert_eq!(Mic::AIMX.operating(), Mic::XLON);
+		assert_eq!(Mic::XTKS.operating(), Mic::XJPX);
+		assert_eq!(Mic::XLON.operating(), Mic::XLON);
+	}
+	
+	//		segments															
+	#[test]
+	fn segments() {
+		assert_eq!(Mic::XNAS.segments(), vec![ Mic::XNCM, Mic::XNGS, Mic::XNMS ]);
+		assert_eq!(Mic::XCME.segments(), vec![ Mic::XCBT, Mic::XCEC, Mic::XNYM ]);
+		assert!(Mic::XSWX.segments().is_empty());
+		assert!(Mic::XNGS.segments().is_empty());
+	}
+	
+	//		country_code														
+	#[test]
+	fn country_code() {
+		assert_eq!(Mic::XPAR.country_code(), CountryCode::FR);
+		assert_eq!(Mic::IFEU.country_code(), CountryCode::GB);
+	}
+	
+	//		country																
+	#[test]
+	fn country() {
+		assert_eq!(Mic::XETR.country(), Country::DE);
+	}
+}
+
+#[cfg(test)]
+mod mic__traits {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(Mic::XLON.as_str(), "XLON");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(Mic::XNYS.to_string(), "XNYS");
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let code: Mic = serde_json::from_str(r#""XNAS""#).unwrap();
+		assert_eq!(code, Mic::XNAS);
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		assert_eq!(Mic::from_str("XLON").unwrap(),   Mic::XLON);
+		assert_eq!(Mic::from_str("xlon").unwrap(),   Mic::XLON);
+		assert_eq!(Mic::from_str(" XLON ").unwrap(), Mic::XLON);
+		let err = Mic::from_str("XXXX");
+		assert_err!(&err);
+		assert_eq!(err.unwrap_err(), "Invalid Mic: XXXX");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&Mic::XLON).unwrap(), r#""XLON""#);
+	}
+}
+
+//		MicType																	
+#[cfg(test)]
+mod mic_type__traits {
+	use super::super::*;
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(MicType::Operating.to_string(), "Operating");
+		assert_eq!(MicType::Segment.to_string(),   "Segment");
+	}
+}
@@ -0,0 +1,54 @@
+//		Tests
+
+//		Money																	
+#[cfg(test)]
+mod money__struct {
+	use super::super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let money = Money::new(1_234, Currency::GBP);
+		assert_eq!(money.minor_units(), 1_234);
+		assert_eq!(money.currency(),    Currency::GBP);
+	}
+	
+	//		from_decimal														
+	#[cfg(feature = "rust_decimal")]
+	#[test]
+	fn from_decimal() {
+		assert_eq!(Money::from_decimal(Decimal::new(12_345, 3), Currency::GBP), Some(Money::new(1_234, Currency::GBP)));
+		assert_eq!(Money::from_decimal(Decimal::new(12, 0),     Currency::GBP), Some(Money::new(1_200, Currency::GBP)));
+		assert_eq!(Money::from_decimal(Decimal::new(-125, 1),   Currency::JPY), Some(Money::new(-12,   Currency::JPY)));
+		assert_eq!(Money::from_decimal(Decimal::new(1_234, 2),  Currency::BHD), Some(Money::new(12_340, Currency::BHD)));
+	}
+	
+	//		from_bigdecimal														
+	#[cfg(feature = "bigdecimal")]
+	#[test]
+	fn from_bigdecimal() {
+		assert_eq!(Money::from_bigdecimal(&BigDecimal::new(BigInt::from(12_345), 3), Currency::GBP), Some(Money::new(1_234, Currency::GBP)));
+		assert_eq!(Money::from_bigdecimal(&BigDecimal::new(BigInt::from(12), 0),     Currency::GBP), Some(Money::new(1_200, Currency::GBP)));
+		assert_eq!(Money::from_bigdecimal(&BigDecimal::new(BigInt::from(135), 1),    Currency::JPY), Some(Money::new(14,    Currency::JPY)));
+	}
+	
+	//		to_decimal															
+	#[cfg(feature = "rust_decimal")]
+	#[test]
+	fn to_decimal() {
+		let decimal = Money::new(1_200, Currency::GBP).to_decimal();
+		assert_eq!(decimal,         Decimal::new(12, 0));
+		assert_eq!(decimal.scale(), 2);
+		assert_eq!(Money::new(12, Currency::JPY).to_decimal(), Decimal::new(12, 0));
+	}
+	
+	//		to_bigdecimal														
+	#[cfg(feature = "bigdecimal")]
+	#[test]
+	fn to_bigdecimal() {
+		assert_eq!(Money::new(1_234, Currency::GBP).to_bigdecimal(), BigDecimal::new(BigInt::from(1_234), 2));
+		assert_eq!(Money::new(12,    Currency::JPY).to_bigdecimal(), BigDecimal::new(BigInt::from(12),    0));
+	}
+}
+
+
@@ -0,0 +1,205 @@
+//		Tests
+
+//		MrzCode																	
+#[cfg(test)]
+mod mrz_code__enum {
+	use super::super::*;
+	
+	//		country																
+	#[test]
+	fn country() {
+		assert_eq!(MrzCode::Country(Country::FR).country(),                        Some(Country::FR));
+		assert_eq!(MrzCode::BritishNationality(BritishNationality::GBN).country(), Some(Country::GB));
+		assert_eq!(MrzCode::Organisation(MrzOrganisation::UNO).country(),          None);
+		assert_eq!(MrzCode::Stateless.country(),                                   None);
+	}
+	
+	//		is_special															
+	#[test]
+	fn is_special() {
+		assert!(!MrzCode::Country(Country::DE).is_special());
+		assert!(!MrzCode::BritishNationality(BritishNationality::GBO).is_special());
+		assert!( MrzCode::Organisation(MrzOrganisation::XPO).is_special());
+		assert!( MrzCode::Unspecified.is_special());
+	}
+}
+
+#[cfg(test)]
+mod mrz_code__traits {
+	use super::super::*;
+	use claims::assert_err_eq;
+	use rubedo::sugar::s;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(MrzCode::Country(Country::DE).as_str(),                        "D");
+		assert_eq!(MrzCode::Country(Country::GB).as_str(),                        "GBR");
+		assert_eq!(MrzCode::BritishNationality(BritishNationality::GBD).as_str(), "GBD");
+		assert_eq!(MrzCode::Organisation(MrzOrganisation::UNO).as_str(),          "UNO");
+		assert_eq!(MrzCode::Refugee.as_str(),                                     "XXB");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(MrzCode::Country(Country::FR).to_string(), "FRA");
+		assert_eq!(MrzCode::OtherRefugee.to_string(),         "XXC");
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let german: MrzCode = serde_json::from_str(r#""D<<""#).unwrap();
+		assert_eq!(german,  MrzCode::Country(Country::DE));
+		let british: MrzCode = serde_json::from_str(r#""GBS<""#).unwrap();
+		assert_eq!(british, MrzCode::BritishNationality(BritishNationality::GBS));
+	}
+	
+	//		from																
+	#[test]
+	fn from() {
+		assert_eq!(MrzCode::from(Country::JP), MrzCode::Country(Country::JP));
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		assert_eq!(MrzCode::from_str("FRA").unwrap(),   MrzCode::Country(Country::FR));
+		assert_eq!(MrzCode::from_str("D").unwrap(),     MrzCode::Country(Country::DE));
+		assert_eq!(MrzCode::from_str("D<<").unwrap(),   MrzCode::Country(Country::DE));
+		assert_eq!(MrzCode::from_str("DEU").unwrap(),   MrzCode::Country(Country::DE));
+		assert_eq!(MrzCode::from_str("GBN").unwrap(),   MrzCode::BritishNationality(BritishNationality::GBN));
+		assert_eq!(MrzCode::from_str("gbp<").unwrap(),  MrzCode::BritishNationality(BritishNationality::GBP));
+		assert_eq!(MrzCode::from_str("uno").unwrap(),   MrzCode::Organisation(MrzOrganisation::UNO));
+		assert_eq!(MrzCode::from_str(" XXA ").unwrap(), MrzCode::Stateless);
+		assert_eq!(MrzCode::from_str("XXB").unwrap(),   MrzCode::Refugee);
+		assert_eq!(MrzCode::from_str("XXC").unwrap(),   MrzCode::OtherRefugee);
+		assert_eq!(MrzCode::from_str("XXX").unwrap(),   MrzCode::Unspecified);
+		assert_err_eq!(MrzCode::from_str("FR"),  s!("Invalid MrzCode: FR"));
+		assert_err_eq!(MrzCode::from_str("UTO"), s!("Invalid MrzCode: UTO"));
+		assert_err_eq!(MrzCode::from_str("276"), s!("Invalid MrzCode: 276"));
+		assert_err_eq!(MrzCode::from_str("<<<"), s!("Invalid MrzCode: <<<"));
+	}
+	#[test]
+	fn from_str__all() {
+		for country in Country::all() {
+			let code = MrzCode::Country(country);
+			assert_eq!(MrzCode::from_str(code.as_str()).unwrap(), code);
+		}
+		for nationality in BritishNationality::all() {
+			let code = MrzCode::BritishNationality(nationality);
+			assert_eq!(MrzCode::from_str(code.as_str()).unwrap(), code);
+		}
+		for organisation in MrzOrganisation::all() {
+			let code = MrzCode::Organisation(organisation);
+			assert_eq!(MrzCode::from_str(code.as_str()).unwrap(), code);
+		}
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&MrzCode::Country(Country::DE)).unwrap(),                        r#""D""#);
+		assert_eq!(serde_json::to_string(&MrzCode::BritishNationality(BritishNationality::GBN)).unwrap(), r#""GBN""#);
+		assert_eq!(serde_json::to_string(&MrzCode::Stateless).unwrap(),                                   r#""XXA""#);
+	}
+	#[test]
+	fn serialize__round_trip() {
+		for nationality in BritishNationality::all() {
+			let code = MrzCode::BritishNationality(nationality);
+			let json = serde_json::to_string(&code).unwrap();
+			assert_eq!(json, format!(r#""{}""#, nationality.as_str()));
+			assert_eq!(serde_json::from_str::<MrzCode>(&json).unwrap(), code);
+		}
+	}
+}
+
+//		BritishNationality														
+#[cfg(test)]
+mod british_nationality__enum {
+	use super::super::*;
+	
+	//		all																	
+	#[test]
+	fn all() {
+		let all = BritishNationality::all();
+		assert_eq!(all.len(), 5);
+		for code in all {
+			assert_eq!(code.as_str().parse::<BritishNationality>().unwrap(), code);
+			assert!(!code.name().is_empty());
+		}
+	}
+	
+	//		name																
+	#[test]
+	fn name() {
+		assert_eq!(BritishNationality::GBN.name(), "British National (Overseas)");
+		assert_eq!(BritishNationality::GBS.name(), "British subject");
+	}
+}
+
+#[cfg(test)]
+mod british_nationality__traits {
+	use super::super::*;
+	use claims::assert_err_eq;
+	use rubedo::sugar::s;
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(BritishNationality::GBO.to_string(), "GBO");
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		assert_eq!(BritishNationality::from_str(" gbd ").unwrap(), BritishNationality::GBD);
+		assert_err_eq!(BritishNationality::from_str("GBR"), s!("Invalid BritishNationality: GBR"));
+	}
+}
+
+//		MrzOrganisation															
+#[cfg(test)]
+mod mrz_organisation__enum {
+	use super::super::*;
+	
+	//		all																	
+	#[test]
+	fn all() {
+		let all = MrzOrganisation::all();
+		assert_eq!(all.len(), 14);
+		for code in all {
+			assert_eq!(code.as_str().parse::<MrzOrganisation>().unwrap(), code);
+			assert!(!code.name().is_empty());
+		}
+	}
+	
+	//		name																
+	#[test]
+	fn name() {
+		assert_eq!(MrzOrganisation::XPO.name(), "International Criminal Police Organization");
+		assert_eq!(MrzOrganisation::EUE.name(), "European Union");
+	}
+}
+
+#[cfg(test)]
+mod mrz_organisation__traits {
+	use super::super::*;
+	use claims::assert_err_eq;
+	use rubedo::sugar::s;
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(MrzOrganisation::UNO.to_string(), "UNO");
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		assert_eq!(MrzOrganisation::from_str("xpo").unwrap(), MrzOrganisation::XPO);
+		assert_err_eq!(MrzOrganisation::from_str("GBR"), s!("Invalid MrzOrganisation: GBR"));
+	}
+}
@@ -0,0 +1,48 @@
+//		Tests
+
+//		Org																		
+#[cfg(test)]
+mod org__enum {
+	use super::super::*;
+	
+	//		all																	
+	#[test]
+	fn all() {
+		assert_eq!(Org::all().len(), 5);
+		assert!(Org::all().contains(&Org::G20));
+	}
+	
+	//		members																
+	#[test]
+	fn members() {
+		assert_eq!(Org::Asean       .members().len(), 10);
+		assert_eq!(Org::Commonwealth.members().len(), 56);
+		assert_eq!(Org::G7          .members().len(), 7);
+		assert_eq!(Org::G20         .members().len(), 19);
+		assert_eq!(Org::Oecd        .members().len(), 38);
+		assert!(Org::G7.members().is_subset(Org::G20.members()));
+		assert!(Org::G7.members().is_subset(Org::Oecd.members()));
+		assert!(ASEAN.contains(Country::SG));
+		assert!(!G20.contains(Country::CH));
+	}
+}
+
+#[cfg(test)]
+mod org__traits {
+	use super::super::*;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(Org::Asean.as_str(),        "ASEAN");
+		assert_eq!(Org::Commonwealth.as_str(), "Commonwealth");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(Org::Oecd.to_string(), "OECD");
+	}
+}
+
+
@@ -0,0 +1,203 @@
+//		Tests
+
+//		ParseOptions															
+#[cfg(test)]
+mod parse_options__struct {
+	use super::super::*;
+	use crate::{Country, Currency, Language, country::Jurisdiction};
+	
+	//		default																
+	#[test]
+	fn default() {
+		assert_eq!(ParseOptions::default(), ParseOptions::strict());
+	}
+	
+	//		strict																
+	#[test]
+	fn strict() {
+		let options = ParseOptions::strict();
+		assert_eq!(options.parse::<Country>("FR"),  Some(Country::FR));
+		assert_eq!(options.parse::<Country>("FRA"), Some(Country::FR));
+		assert_eq!(options.parse::<Language>("fr"), Some(Language::FR));
+		assert_eq!(options.parse::<Country>("fr"),     None);
+		assert_eq!(options.parse::<Country>("250"),    None);
+		assert_eq!(options.parse::<Country>("France"), None);
+		assert_eq!(options.parse::<Language>("FR"),    None);
+		assert_eq!(options.parse::<Currency>("XTS"),   None);
+		assert_eq!(options.parse::<Country>(" FR"),    None);
+	}
+	
+	//		lenient																
+	#[test]
+	fn lenient() {
+		let options = ParseOptions::lenient();
+		assert_eq!(options.parse::<Country>("fr"),                  Some(Country::FR));
+		assert_eq!(options.parse::<Country>("250"),                 Some(Country::FR));
+		assert_eq!(options.parse::<Country>("the French Republic"), Some(Country::FR));
+		assert_eq!(options.parse::<Currency>("rmb"),                Some(Currency::CNY));
+		assert_eq!(options.parse::<Currency>("XTS"),                Some(Currency::XTS));
+		assert_eq!(options.parse::<Language>("FR"),                 Some(Language::FR));
+		assert_eq!(options.parse::<Country>("Atlantis"),            None);
+	}
+	
+	//		case_insensitive													
+	#[test]
+	fn case_insensitive() {
+		let options = ParseOptions::strict().case_insensitive(true);
+		assert_eq!(options.parse::<Currency>("eur"),   Some(Currency::EUR));
+		assert_eq!(options.parse::<Currency>("Euro"),  None);
+		assert_eq!(ParseOptions::lenient().case_insensitive(false).parse::<Currency>("eur"), None);
+	}
+	
+	//		names																
+	#[test]
+	fn names() {
+		let options = ParseOptions::strict().names(true);
+		assert_eq!(options.parse::<Currency>("Pound sterling"), Some(Currency::GBP));
+		assert_eq!(options.parse::<Language>("french"),         Some(Language::FR));
+		assert_eq!(options.parse::<Currency>("Sterling"),       None);
+	}
+	
+	//		aliases																
+	#[test]
+	fn aliases() {
+		let options = ParseOptions::strict().aliases(true);
+		assert_eq!(options.parse::<Currency>("Sterling"), Some(Currency::GBP));
+		assert_eq!(options.parse::<Language>("Chuang"),   Some(Language::ZA));
+		assert_eq!(options.parse::<Country>("France"),    None);
+	}
+	
+	//		numeric																
+	#[test]
+	fn numeric() {
+		let options = ParseOptions::strict().numeric(true);
+		assert_eq!(options.parse::<Country>("008"),  Some(Country::AL));
+		assert_eq!(options.parse::<Currency>("978"), Some(Currency::EUR));
+		assert_eq!(options.parse::<Country>("8"),    None);
+		assert_eq!(options.parse::<Country>("+250"), None);
+	}
+	
+	//		user_assigned														
+	#[test]
+	fn user_assigned() {
+		let options = ParseOptions::strict().user_assigned(true);
+		assert_eq!(options.parse::<Currency>("XTS"), Some(Currency::XTS));
+		assert_eq!(options.parse::<Currency>("XXX"), Some(Currency::XXX));
+		assert_eq!(ParseOptions::lenient().user_assigned(false).parse::<Currency>("999"), None);
+	}
+	
+	//		parse																
+	#[test]
+	fn parse() {
+		let options = ParseOptions::lenient();
+		assert_eq!(options.parse::<Country>("GB"),             Some(Country::GB));
+		assert_eq!(options.parse::<Country>("gbr"),            Some(Country::GB));
+		assert_eq!(options.parse::<Country>("uk"),             Some(Country::GB));
+		assert_eq!(options.parse::<Country>("826"),            Some(Country::GB));
+		assert_eq!(options.parse::<Country>("France"),         Some(Country::FR));
+		assert_eq!(options.parse::<Currency>("Sterling"),      Some(Currency::GBP));
+		assert_eq!(options.parse::<Country>("Atlantis"),       None);
+	}
+	#[cfg(feature = "tracing")]
+	#[test]
+	fn parse__tracing() {
+		use core::fmt::Debug;
+		use rubedo::sugar::s;
+		use std::sync::{Arc, Mutex};
+		use tracing::{Event, Metadata, Subscriber, field, span, subscriber};
+		
+		/// A subscriber that records the fields of each event.
+		#[derive(Clone, Default)]
+		struct Recorder(Arc<Mutex<Vec<String>>>);
+		
+		impl Subscriber for Recorder {
+			fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+				true
+			}
+			fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+				span::Id::from_u64(1)
+			}
+			fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+			fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+			fn event(&self, event: &Event<'_>) {
+				let mut fields = Fields::default();
+				event.record(&mut fields);
+				self.0.lock().unwrap().push(fields.0.join(" "));
+			}
+			fn enter(&self, _span: &span::Id) {}
+			fn exit(&self, _span: &span::Id) {}
+		}
+		
+		/// A visitor that collects the fields of an event.
+		#[derive(Default)]
+		struct Fields(Vec<String>);
+		
+		impl field::Visit for Fields {
+			fn record_debug(&mut self, field: &field::Field, value: &dyn Debug) {
+				self.0.push(format!("{}={value:?}", field.name()));
+			}
+		}
+		
+		let recorder = Recorder::default();
+		subscriber::with_default(recorder.clone(), || {
+			let options = ParseOptions::lenient();
+			assert_eq!(options.parse::<Country>("GB"),             Some(Country::GB));
+			assert_eq!(options.parse::<Country>("UK"),             Some(Country::GB));
+			assert_eq!(options.parse::<Currency>("Sterling"),      Some(Currency::GBP));
+			assert_eq!(options.parse::<Country>(" FR"),            Some(Country::FR));
+			assert_eq!(options.parse::<Country>("Atlantis"),       None);
+		});
+		assert_eq!(*recorder.0.lock().unwrap(), vec![
+			s!(r#"message=Parsed leniently input="UK" resolved=GB: United Kingdom of Great Britain and Northern Ireland via="quirk""#),
+			s!(r#"message=Parsed leniently input="Sterling" resolved=GBP: Pound sterling via="alias""#),
+			s!(r#"message=Parsed leniently input=" FR" resolved=FR: France via="code""#),
+		]);
+	}
+	
+	//		quirks																
+	#[test]
+	fn quirks() {
+		let options = ParseOptions::strict().quirks(true);
+		assert_eq!(options.parse::<Country>("UK"), Some(Country::GB));
+		assert_eq!(options.parse::<Country>("EL"), Some(Country::GR));
+		assert_eq!(options.parse::<Country>("FX"), Some(Country::FR));
+		assert_eq!(options.parse::<Country>("GB"), Some(Country::GB));
+		assert_eq!(options.parse::<Country>("uk"), None);
+		assert_eq!(options.parse::<Country>("EU"), None);
+		assert_eq!(options.parse::<Country>("EM"), None);
+		assert_eq!(options.parse::<Jurisdiction>("EU"), Some(Jurisdiction::EuropeanUnion));
+		assert_eq!(options.parse::<Jurisdiction>("UK"), Some(Jurisdiction::Country(Country::GB)));
+		assert_eq!(options.case_insensitive(true).parse::<Country>("uk"), Some(Country::GB));
+		assert_eq!(ParseOptions::strict().parse::<Country>("UK"),         None);
+		assert_eq!(ParseOptions::lenient().parse::<Country>("el"),        Some(Country::GR));
+	}
+	
+	//		mrz																	
+	#[test]
+	fn mrz() {
+		let options = ParseOptions::strict().mrz(true);
+		assert_eq!(options.parse::<Country>("D"),   Some(Country::DE));
+		assert_eq!(options.parse::<Country>("D<<"), Some(Country::DE));
+		assert_eq!(options.parse::<Country>("GBD"), Some(Country::GB));
+		assert_eq!(options.parse::<Country>("DEU"), Some(Country::DE));
+		assert_eq!(options.parse::<Country>("d"),   None);
+		assert_eq!(options.parse::<Country>("UNO"), None);
+		assert_eq!(options.parse::<Currency>("D"),  None);
+		assert_eq!(options.case_insensitive(true).parse::<Country>("gbs<"), Some(Country::GB));
+		assert_eq!(ParseOptions::strict().parse::<Country>("D"),            None);
+		assert_eq!(ParseOptions::lenient().parse::<Country>("D<<"),         None);
+	}
+	
+	//		trim																
+	#[test]
+	fn trim() {
+		let options = ParseOptions::strict().trim(true);
+		assert_eq!(options.parse::<Country>(" FR "),          Some(Country::FR));
+		assert_eq!(options.parse::<Currency>("EUR\u{a0}"),    Some(Currency::EUR));
+		assert_eq!(options.parse::<Language>("\tfr\n"),       Some(Language::FR));
+		assert_eq!(options.numeric(true).parse::<Country>(" 250"), Some(Country::FR));
+		assert_eq!(options.parse::<Country>("F R"),           None);
+		assert_eq!(ParseOptions::lenient().parse::<Country>("fr "),             Some(Country::FR));
+		assert_eq!(ParseOptions::lenient().trim(false).parse::<Country>("fr "), None);
+	}
+}
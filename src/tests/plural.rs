@@ -0,0 +1,119 @@
+//		Tests
+
+//		PluralCategory															
+#[cfg(test)]
+mod plural_category__traits {
+	use super::super::*;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(PluralCategory::Zero .as_str(), "zero");
+		assert_eq!(PluralCategory::Other.as_str(), "other");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(format!("{}", PluralCategory::Few), "few");
+		assert_eq!(PluralCategory::Many.to_string(),   "many");
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let category: PluralCategory = serde_json::from_str(r#""two""#).unwrap();
+		assert_eq!(category, PluralCategory::Two);
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&PluralCategory::One).unwrap(), r#""one""#);
+	}
+}
+
+//		PluralRule																
+#[cfg(test)]
+mod plural_rule__enum {
+	use super::super::*;
+	
+	//		for_language														
+	#[test]
+	fn for_language() {
+		assert_eq!(PluralRule::for_language(Language::EN), PluralRule::One);
+		assert_eq!(PluralRule::for_language(Language::FR), PluralRule::ZeroOneMany);
+		assert_eq!(PluralRule::for_language(Language::JA), PluralRule::None);
+		assert_eq!(PluralRule::for_language(Language::AR), PluralRule::Arabic);
+	}
+	
+	//		categories															
+	#[test]
+	fn categories__all() {
+		for language in Language::all() {
+			let categories = PluralRule::for_language(language).categories();
+			assert!(!categories.is_empty());
+			assert_eq!(categories.last(), Some(&PluralCategory::Other));
+		}
+	}
+	
+	//		category															
+	#[test]
+	fn category__english() {
+		let rule = PluralRule::One;
+		assert_eq!(rule.category(0), PluralCategory::Other);
+		assert_eq!(rule.category(1), PluralCategory::One);
+		assert_eq!(rule.category(2), PluralCategory::Other);
+	}
+	#[test]
+	fn category__russian() {
+		let rule = PluralRule::EastSlavic;
+		assert_eq!(rule.category(1),   PluralCategory::One);
+		assert_eq!(rule.category(21),  PluralCategory::One);
+		assert_eq!(rule.category(11),  PluralCategory::Many);
+		assert_eq!(rule.category(3),   PluralCategory::Few);
+		assert_eq!(rule.category(13),  PluralCategory::Many);
+		assert_eq!(rule.category(104), PluralCategory::Few);
+		assert_eq!(rule.category(5),   PluralCategory::Many);
+	}
+	#[test]
+	fn category__arabic() {
+		let rule = PluralRule::Arabic;
+		assert_eq!(rule.category(0),   PluralCategory::Zero);
+		assert_eq!(rule.category(1),   PluralCategory::One);
+		assert_eq!(rule.category(2),   PluralCategory::Two);
+		assert_eq!(rule.category(5),   PluralCategory::Few);
+		assert_eq!(rule.category(50),  PluralCategory::Many);
+		assert_eq!(rule.category(100), PluralCategory::Other);
+	}
+	#[test]
+	fn category__french() {
+		let rule = PluralRule::ZeroOneMany;
+		assert_eq!(rule.category(0),         PluralCategory::One);
+		assert_eq!(rule.category(1),         PluralCategory::One);
+		assert_eq!(rule.category(2),         PluralCategory::Other);
+		assert_eq!(rule.category(1_000_000), PluralCategory::Many);
+	}
+	#[test]
+	fn category__cornish() {
+		let rule = PluralRule::Cornish;
+		assert_eq!(rule.category(0),       PluralCategory::Zero);
+		assert_eq!(rule.category(1),       PluralCategory::One);
+		assert_eq!(rule.category(22),      PluralCategory::Two);
+		assert_eq!(rule.category(20_000),  PluralCategory::Two);
+		assert_eq!(rule.category(100_000), PluralCategory::Two);
+		assert_eq!(rule.category(23),      PluralCategory::Few);
+		assert_eq!(rule.category(21),      PluralCategory::Many);
+		assert_eq!(rule.category(5),       PluralCategory::Other);
+	}
+	#[test]
+	fn category__all() {
+		for language in Language::all() {
+			let rule = PluralRule::for_language(language);
+			for n in (0..1_000_u64).chain((1..=2_000).map(|k| k * 1_000)) {
+				assert!(rule.categories().contains(&rule.category(n)));
+			}
+		}
+	}
+}
@@ -0,0 +1,44 @@
+//		Tests
+
+//		Exports																	
+#[cfg(test)]
+mod exports {
+	use super::super::*;
+	use ::serde::{Deserialize, Serialize};
+	use serde_json::{from_str, json, to_value};
+	
+	//		Record																
+	/// A record using the Serde helpers from the prelude.
+	#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+	struct Record {
+		/// A country, as a three-letter code.
+		#[serde(with = "iso_serde::country::alpha3")]
+		country: Country,
+	}
+	
+	//		types																
+	#[test]
+	fn types() {
+		assert_eq!(Country::FR.code(),   CountryCode::FR);
+		assert_eq!(Currency::EUR.code(), CurrencyCode::EUR);
+		assert_eq!(Language::FR.code(),  LanguageCode::FR);
+		assert!([ Country::FR, Country::GB ].into_iter().collect::<CountrySet>().contains(Country::FR));
+	}
+	
+	//		traits																
+	#[test]
+	fn traits() {
+		assert_eq!(CountryCode::FR.as_str(), "FR");
+		assert_eq!(Country::FR.to_coded(),   CountryCode::FR);
+	}
+	
+	//		serde																
+	#[test]
+	fn serde() {
+		let record = Record { country: Country::FR };
+		assert_eq!(to_value(&record).unwrap(), json!({ "country": "FRA" }));
+		assert_eq!(from_str::<Record>(r#"{ "country": "FR" }"#).unwrap(), record);
+	}
+}
+
+
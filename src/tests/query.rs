@@ -0,0 +1,85 @@
+//		Tests
+
+//		Query																	
+#[cfg(test)]
+mod query__struct {
+	use super::super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		assert_eq!(Query::new().run().len(), Country::all().len());
+	}
+	
+	//		language															
+	#[test]
+	fn language() {
+		let countries = Query::new().language(LanguageCode::FR).run();
+		assert!(countries.contains(Country::FR));
+		assert!(countries.contains(Country::CA));
+		assert!(!countries.contains(Country::GB));
+	}
+	#[test]
+	fn language__official_only() {
+		assert!(!Query::new().language(LanguageCode::RM).run().contains(Country::CH));
+		assert!(!Query::new().language(LanguageCode::CY).run().contains(Country::GB));
+	}
+	
+	//		currency															
+	#[test]
+	fn currency() {
+		let countries = Query::new().currency(CurrencyCode::GBP).run();
+		assert!(countries.contains(Country::GB));
+		assert!(countries.contains(Country::JE));
+		assert!(!countries.contains(Country::FR));
+	}
+	
+	//		region																
+	#[test]
+	fn region() {
+		assert_eq!(Query::new().region(Region::Antarctica).run(), CountrySet::from_iter([ Country::AQ ]));
+	}
+	
+	//		or																	
+	#[test]
+	fn or() {
+		let countries = Query::new()
+			.language(LanguageCode::FR)
+			.or(Query::new().language(LanguageCode::DE))
+			.currency(CurrencyCode::EUR)
+			.run();
+		assert!(countries.contains(Country::FR));
+		assert!(countries.contains(Country::DE));
+		assert!(!countries.contains(Country::CH));
+		assert!(!countries.contains(Country::CA));
+	}
+	
+	//		run																	
+	#[test]
+	fn run() {
+		let countries = Query::new().language(LanguageCode::FR).currency(CurrencyCode::EUR).run();
+		for country in countries.iter() {
+			assert!(country.languages().contains(&LanguageCode::FR));
+			assert!(country.currencies().contains(&CurrencyCode::EUR));
+		}
+		assert!(countries.contains(Country::BE));
+		assert!(countries.contains(Country::LU));
+		assert!(!countries.contains(Country::CH));
+	}
+	#[test]
+	fn run__no_match() {
+		assert!(Query::new().language(LanguageCode::JA).currency(CurrencyCode::EUR).run().is_empty());
+	}
+}
+
+//		Query																	
+#[cfg(test)]
+mod query__traits {
+	use super::super::*;
+	
+	//		default																
+	#[test]
+	fn default() {
+		assert_eq!(Query::default(), Query::new());
+	}
+}
@@ -0,0 +1,170 @@
+//		Tests
+
+//		Registry																
+#[cfg(test)]
+mod registry__struct {
+	use super::super::*;
+	use core::str::FromStr;
+	use rubedo::sugar::s;
+	
+	/// A tag marking membership of a list.
+	#[derive(Debug, PartialEq)]
+	struct Listed;
+	
+	/// A tag carrying a value.
+	#[derive(Debug, PartialEq)]
+	struct Zone(u8);
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let registry = Registry::new();
+		assert_eq!(registry.country_name(Country::GB), None);
+		assert!(registry.countries_tagged::<Listed>().is_empty());
+		assert_eq!(registry, Registry::default());
+	}
+	
+	//		install																
+	#[test]
+	fn install() {
+		//	Only one registry can be installed per process, so the override used
+		//	here must not be relied upon by any other test.
+		let registry = Registry::new()
+			.with_country_name(Country::TW, "Taiwan")
+			.with_country_tag(Country::TW, Zone(3));
+		assert!(registry.clone().install().is_ok());
+		assert_eq!(Registry::global(), Some(&registry));
+		assert_eq!(Country::TW.name(),      "Taiwan");
+		assert_eq!(Country::TW.to_string(), "Taiwan");
+		assert_eq!(Country::from_str("Taiwan").unwrap(),                     Country::TW);
+		assert_eq!(Country::from_str("Taiwan, Province of China").unwrap(), Country::TW);
+		assert_eq!(Country::TW.tag::<Zone>(),   Some(&Zone(3)));
+		assert_eq!(Country::TW.tag::<Listed>(), None);
+		assert_eq!(Registry::new().install(), Err(Registry::new()));
+	}
+	
+	//		with_country_name													
+	#[test]
+	fn with_country_name() {
+		let registry = Registry::new()
+			.with_country_name(Country::PS, "Palestine")
+			.with_country_name(Country::PS, "Palestinian Territories");
+		assert_eq!(registry.country_name(Country::PS), Some("Palestinian Territories"));
+		assert_eq!(registry.country_name(Country::GB), None);
+	}
+	
+	//		country_by_name														
+	#[test]
+	fn country_by_name() {
+		let registry = Registry::new().with_country_name(Country::PS, "Palestine");
+		assert_eq!(registry.country_by_name("Palestine"),           Some(Country::PS));
+		assert_eq!(registry.country_by_name("Palestine, State of"), None);
+	}
+	
+	//		with_country_tag													
+	#[test]
+	fn with_country_tag() {
+		let registry = Registry::new()
+			.with_country_tag(Country::FR, Zone(1))
+			.with_country_tag(Country::FR, Zone(2))
+			.with_country_tag(Country::FR, Listed);
+		assert_eq!(registry.country_tag::<Zone>(Country::FR),   Some(&Zone(2)));
+		assert_eq!(registry.country_tag::<Listed>(Country::FR), Some(&Listed));
+		assert_eq!(registry.country_tag::<Zone>(Country::DE),   None);
+	}
+	
+	//		countries_tagged													
+	#[test]
+	fn countries_tagged() {
+		let registry = Registry::new()
+			.with_country_tag(Country::FR, Listed)
+			.with_country_tag(Country::DE, Listed)
+			.with_country_tag(Country::IT, Zone(1));
+		assert_eq!(registry.countries_tagged::<Listed>(), CountrySet::from_iter([ Country::DE, Country::FR ]));
+		assert_eq!(registry.countries_tagged::<Zone>(),   CountrySet::from_iter([ Country::IT ]));
+		assert!(registry.countries_tagged::<u8>().is_empty());
+	}
+	
+	//		with_currency_class													
+	#[test]
+	fn with_currency_class() {
+		let registry = Registry::new()
+			.with_currency_class(Currency::CNY, CurrencyClass::Major)
+			.with_currency_class(Currency::CNY, CurrencyClass::Exotic);
+		assert_eq!(registry.currency_class(Currency::CNY), Some(CurrencyClass::Exotic));
+		assert_eq!(registry.currency_class(Currency::USD), None);
+	}
+	
+	//		diff																
+	#[test]
+	fn diff() {
+		let current  = Registry::new()
+			.with_country_name(Country::PS, "Palestine")
+			.with_country_name(Country::TW, "Taiwan")
+			.with_country_tag(Country::FR, Listed)
+			.with_country_tag(Country::DE, Listed)
+			.with_country_tag(Country::IT, Zone(1))
+			.with_currency_class(Currency::CNY, CurrencyClass::Exotic);
+		let proposed = Registry::new()
+			.with_country_name(Country::PS, "Palestinian Territories")
+			.with_country_name(Country::CZ, "Czechia")
+			.with_country_tag(Country::FR, Listed)
+			.with_country_tag(Country::NL, Listed)
+			.with_country_tag(Country::IT, Zone(2))
+			.with_currency_class(Currency::CNY, CurrencyClass::Exotic)
+			.with_currency_class(Currency::INR, CurrencyClass::Minor);
+		let diff     = current.diff(&proposed);
+		assert!(!diff.is_empty());
+		assert_eq!(diff.country_names.added,   vec![ (Country::CZ, s!("Czechia")) ]);
+		assert_eq!(diff.country_names.removed, vec![ (Country::TW, s!("Taiwan")) ]);
+		assert_eq!(diff.country_names.changed, vec![ (Country::PS, s!("Palestine"), s!("Palestinian Territories")) ]);
+		assert_eq!(diff.country_tags.len(), 1);
+		assert!(diff.country_tags[0].tag.ends_with("::Listed"));
+		assert_eq!(diff.country_tags[0].added,   CountrySet::from_iter([ Country::NL ]));
+		assert_eq!(diff.country_tags[0].removed, CountrySet::from_iter([ Country::DE ]));
+		assert_eq!(diff.currency_classes.added,  vec![ (Currency::INR, CurrencyClass::Minor) ]);
+		assert!(diff.currency_classes.removed.is_empty());
+		assert!(diff.currency_classes.changed.is_empty());
+	}
+	#[test]
+	fn diff__reversed() {
+		let current = Registry::new().with_country_name(Country::TW, "Taiwan").with_country_tag(Country::FR, Listed);
+		let diff    = Registry::new().diff(&current);
+		assert_eq!(diff.country_names.added,   vec![ (Country::TW, s!("Taiwan")) ]);
+		assert_eq!(diff.country_tags[0].added, CountrySet::from_iter([ Country::FR ]));
+		let reverse = current.diff(&Registry::new());
+		assert_eq!(reverse.country_names.removed,   vec![ (Country::TW, s!("Taiwan")) ]);
+		assert_eq!(reverse.country_tags[0].removed, CountrySet::from_iter([ Country::FR ]));
+	}
+	#[test]
+	fn diff__empty() {
+		let registry = Registry::new()
+			.with_country_name(Country::PS, "Palestine")
+			.with_country_tag(Country::FR, Listed)
+			.with_currency_class(Currency::CNY, CurrencyClass::Major);
+		assert!(registry.diff(&registry).is_empty());
+		assert!(Registry::new().diff(&Registry::new()).is_empty());
+	}
+}
+
+#[cfg(test)]
+mod registry__traits {
+	use super::super::*;
+	
+	//		eq																	
+	#[test]
+	fn eq() {
+		let registry = Registry::new()
+			.with_country_name(Country::PS, "Palestine")
+			.with_country_tag(Country::FR, 1_u8)
+			.with_currency_class(Currency::CNY, CurrencyClass::Major);
+		assert_eq!(registry, registry.clone());
+		assert_eq!(registry, registry.clone().with_country_tag(Country::FR, 1_u8));
+		assert_ne!(registry, registry.clone().with_country_tag(Country::FR, 2_u8));
+		assert_ne!(registry, registry.clone().with_country_tag(Country::DE, 1_u8));
+		assert_ne!(registry, registry.clone().with_country_tag(Country::FR, 1_u16));
+		assert_ne!(registry, registry.clone().with_country_name(Country::PS, "Palestinian Territories"));
+		assert_ne!(registry, registry.clone().with_currency_class(Currency::CNY, CurrencyClass::Minor));
+		assert_ne!(registry, Registry::new());
+	}
+}
@@ -0,0 +1,51 @@
+//		Tests
+
+//		Functions																
+#[cfg(test)]
+mod functions {
+	use super::super::*;
+	use serde_json::json;
+	
+	//		json_schema_for														
+	#[test]
+	fn json_schema_for() {
+		let schema = super::super::json_schema_for::<Country>();
+		assert_eq!(schema["$schema"], SPECIFICATION);
+		assert_eq!(schema["title"],   "Country");
+		assert_eq!(schema["type"],    "string");
+		let names = schema["enum"].as_array().unwrap();
+		assert_eq!(names.len(), 249);
+		assert!(names.contains(&json!("Switzerland")));
+	}
+	#[test]
+	fn json_schema_for__codes() {
+		let schema = super::super::json_schema_for::<CountryCode>();
+		let codes  = schema["oneOf"].as_array().unwrap();
+		assert_eq!(codes.len(), 498);
+		assert!(codes.contains(&json!({ "const": "CH",  "title": "Switzerland" })));
+		assert!(codes.contains(&json!({ "const": "CHE", "title": "Switzerland" })));
+		let currencies = super::super::json_schema_for::<CurrencyCode>();
+		assert!(currencies["oneOf"].as_array().unwrap().contains(&json!({ "const": "CHF", "title": "Swiss franc" })));
+	}
+	#[test]
+	fn json_schema_for__valid() {
+		let schema = super::super::json_schema_for::<LanguageCode>();
+		for language in Language::all() {
+			let value = serde_json::to_value(language.code()).unwrap();
+			assert!(schema["oneOf"].as_array().unwrap().iter().any(|entry| entry["const"] == value));
+		}
+	}
+	
+	//		json_schemas														
+	#[test]
+	fn json_schemas() {
+		let schemas = super::super::json_schemas();
+		assert_eq!(schemas.len(), 6);
+		assert_eq!(schemas.keys().copied().collect::<Vec<_>>(), vec![
+			"Country", "CountryCode", "Currency", "CurrencyCode", "Language", "LanguageCode",
+		]);
+		assert_eq!(schemas["Currency"], super::super::json_schema_for::<Currency>());
+	}
+}
+
+
@@ -0,0 +1,97 @@
+//		Tests
+
+//		Script																	
+#[cfg(test)]
+mod script__enum {
+	use super::super::*;
+	
+	//		all																	
+	#[test]
+	fn all() {
+		let scripts = Script::all();
+		assert_eq!(scripts.len(), 40);
+		assert!(scripts.contains(&Script::Latn));
+		assert!(scripts.windows(2).all(|pair| matches!(pair, [a, b] if a < b)));
+	}
+	
+	//		name																
+	#[test]
+	fn name() {
+		assert_eq!(Script::Latn.name(), "Latin");
+		assert_eq!(Script::Hant.name(), "Han (Traditional)");
+	}
+	
+	//		numeric																
+	#[test]
+	fn numeric() {
+		assert_eq!(Script::Latn.numeric(), 215);
+		assert_eq!(Script::Cyrl.numeric(), 220);
+	}
+	
+	//		languages															
+	#[test]
+	fn languages() {
+		let languages = Script::Cyrl.languages();
+		assert!(languages.contains(&Language::RU));
+		assert!(languages.contains(&Language::SR));
+		assert!(!languages.contains(&Language::EN));
+		assert!(Script::Latn.languages().contains(&Language::SR));
+	}
+	#[test]
+	fn languages__all() {
+		for language in Language::all() {
+			assert!(!language.scripts().is_empty(), "{language:?}");
+			for script in language.scripts() {
+				assert!(Script::all().contains(script), "{language:?}");
+			}
+		}
+	}
+}
+
+//		Script																	
+#[cfg(test)]
+mod script__traits {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(Script::Cyrl.as_str(), "Cyrl");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(Script::Hans.to_string(), "Hans");
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		assert_eq!(Script::from_str("Latn").unwrap(), Script::Latn);
+		assert_eq!(Script::from_str("cyrl").unwrap(), Script::Cyrl);
+		let err = Script::from_str("Zzzz");
+		assert_err!(&err);
+		assert_eq!(err.unwrap_err(), "Invalid Script: Zzzz");
+	}
+	#[test]
+	fn from_str__all() {
+		for script in Script::all() {
+			assert_eq!(Script::from_str(script.as_str()).unwrap(), script);
+		}
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&Script::Latn).unwrap(), r#""Latn""#);
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		assert_eq!(serde_json::from_str::<Script>(r#""Arab""#).unwrap(), Script::Arab);
+	}
+}
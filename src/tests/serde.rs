@@ -0,0 +1,457 @@
+//		Tests
+
+//		CodeOrName																
+#[cfg(test)]
+mod code_or_name__enum {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json::{from_str, json, to_value};
+	
+	//		resolve																
+	#[test]
+	fn resolve() {
+		assert_eq!(CodeOrName::<Country>::Code(CountryCode::FR).resolve(),       Ok(Country::FR));
+		assert_eq!(CodeOrName::<Country>::Name("France".to_owned()).resolve(),   Ok(Country::FR));
+		assert_eq!(CodeOrName::<Currency>::Name("Sterling".to_owned()).resolve(), Ok(Currency::GBP));
+		assert_eq!(CodeOrName::<Language>::Name("French".to_owned()).resolve(),  Ok(Language::FR));
+		assert_eq!(CodeOrName::<CountryCode>::Code(CountryCode::FRA).resolve(),  Ok(CountryCode::FRA));
+	}
+	#[test]
+	fn resolve__invalid() {
+		assert_eq!(CodeOrName::<Country>::Name("Frnace".to_owned()).resolve(),   Err("Invalid Country: Frnace".to_owned()));
+		assert_eq!(CodeOrName::<Currency>::Name("Dollar".to_owned()).resolve(),  Err("Invalid Currency: Dollar".to_owned()));
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		assert_eq!(from_str::<CodeOrName<Country>>(r#""FR""#).unwrap(),     CodeOrName::Code(CountryCode::FR));
+		assert_eq!(from_str::<CodeOrName<Country>>(r#""FRA""#).unwrap(),    CodeOrName::Code(CountryCode::FRA));
+		assert_eq!(from_str::<CodeOrName<Country>>(r#""France""#).unwrap(), CodeOrName::Name("France".to_owned()));
+		assert_eq!(from_str::<CodeOrName<Currency>>(r#""GBP""#).unwrap(),   CodeOrName::Code(CurrencyCode::GBP));
+		assert_eq!(from_str::<CodeOrName<Language>>(r#""en""#).unwrap(),    CodeOrName::Code(LanguageCode::EN));
+		assert_eq!(from_str::<CodeOrName<Country>>(r#""Frnace""#).unwrap(), CodeOrName::Name("Frnace".to_owned()));
+		assert_err!(from_str::<CodeOrName<Country>>("true"));
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(to_value(CodeOrName::<Country>::Code(CountryCode::FR)).unwrap(),     json!("FR"));
+		assert_eq!(to_value(CodeOrName::<Country>::Name("France".to_owned())).unwrap(), json!("France"));
+	}
+}
+
+//		Maybe																	
+#[cfg(test)]
+mod maybe__enum {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json::{from_str, from_value, json, to_value};
+	
+	//		known																
+	#[test]
+	fn known() {
+		assert_eq!(MaybeCountry::Known(Country::FR).known(),       Some(Country::FR));
+		assert_eq!(MaybeCountry::Unknown("XX".to_owned()).known(), None);
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		assert_eq!(from_str::<MaybeCountry>(r#""FR""#).unwrap(),         MaybeCountry::Known(Country::FR));
+		assert_eq!(from_str::<MaybeCountry>(r#""fra""#).unwrap(),        MaybeCountry::Known(Country::FR));
+		assert_eq!(from_str::<MaybeCountry>(r#""XX""#).unwrap(),         MaybeCountry::Unknown("XX".to_owned()));
+		assert_eq!(from_str::<MaybeCountry>(r#""France""#).unwrap(),     MaybeCountry::Unknown("France".to_owned()));
+		assert_eq!(from_str::<MaybeCurrency>(r#""GBP""#).unwrap(),       MaybeCurrency::Known(Currency::GBP));
+		assert_eq!(from_str::<MaybeCurrency>(r#""XBT""#).unwrap(),       MaybeCurrency::Unknown("XBT".to_owned()));
+		assert_eq!(from_str::<MaybeLanguage>(r#""en""#).unwrap(),        MaybeLanguage::Known(Language::EN));
+		assert_eq!(from_str::<MaybeLanguage>(r#""eng""#).unwrap(),       MaybeLanguage::Unknown("eng".to_owned()));
+		assert_eq!(from_str::<Maybe<CountryCode>>(r#""fra""#).unwrap(), Maybe::Known(CountryCode::FRA));
+		assert_err!(from_str::<MaybeCountry>("250"));
+	}
+	
+	//		from																
+	#[test]
+	fn from() {
+		assert_eq!(MaybeCurrency::from(Currency::EUR), MaybeCurrency::Known(Currency::EUR));
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(to_value(MaybeCountry::Known(Country::FR)).unwrap(),         json!("FR"));
+		assert_eq!(to_value(Maybe::Known(CountryCode::FRA)).unwrap(),           json!("FRA"));
+		assert_eq!(to_value(MaybeLanguage::Known(Language::EN)).unwrap(),       json!("en"));
+		assert_eq!(to_value(MaybeCurrency::Unknown("XBT".to_owned())).unwrap(), json!("XBT"));
+	}
+	#[test]
+	fn serialize__round_trip() {
+		let values = json!([ "GB", "XK", "Atlantis", "" ]);
+		let parsed: Vec<MaybeCountry> = from_value(values.clone()).unwrap();
+		assert_eq!(parsed[0], MaybeCountry::Known(Country::GB));
+		assert_eq!(to_value(parsed).unwrap(), values);
+	}
+}
+
+//		Codecs																	
+#[cfg(test)]
+mod codecs {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json::{from_str, json, to_value};
+	use serde_test::{Configure, Token, assert_de_tokens, assert_tokens};
+	
+	//		Record																
+	/// A record with fields in each of the available forms.
+	#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+	struct Record {
+		/// A country, as a two-letter code.
+		#[serde(with = "crate::serde::country::alpha2")]
+		alpha2:    Country,
+		
+		/// A country code, as a three-letter code.
+		#[serde(with = "crate::serde::country::alpha3")]
+		alpha3:    CountryCode,
+		
+		/// A country, as a numeric code.
+		#[serde(with = "crate::serde::country::numeric")]
+		numeric:   Country,
+		
+		/// A currency, as a numeric code.
+		#[serde(with = "crate::serde::currency::numeric")]
+		currency:  Currency,
+		
+		/// An optional language.
+		#[serde(with = "crate::serde::language::alpha2::option")]
+		language:  Option<Language>,
+		
+		/// A list of currency codes.
+		#[serde(with = "crate::serde::currency::alpha3::vec")]
+		accepted:  Vec<CurrencyCode>,
+	}
+	
+	//		Alphas																
+	/// A record with fields in each of the string forms.
+	#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+	struct Alphas {
+		/// A country, as a two-letter code.
+		#[serde(with = "crate::serde::country::alpha2")]
+		alpha2:   Country,
+		
+		/// A country code, as a three-letter code.
+		#[serde(with = "crate::serde::country::alpha3")]
+		alpha3:   CountryCode,
+		
+		/// A currency, as a three-letter code.
+		#[serde(with = "crate::serde::currency::alpha3")]
+		currency: Currency,
+		
+		/// A language, as a two-letter code.
+		#[serde(with = "crate::serde::language::alpha2")]
+		language: Language,
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		let record = Record {
+			alpha2:   Country::GB,
+			alpha3:   CountryCode::GB,
+			numeric:  Country::US,
+			currency: Currency::EUR,
+			language: Some(Language::EN),
+			accepted: vec![ CurrencyCode::GBP, CurrencyCode::USD ],
+		};
+		assert_eq!(to_value(&record).unwrap(), json!({
+			"alpha2":   "GB",
+			"alpha3":   "GBR",
+			"numeric":  840,
+			"currency": 978,
+			"language": "en",
+			"accepted": [ "GBP", "USD" ],
+		}));
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let record: Record = from_str(r#"{
+			"alpha2":   "GBR",
+			"alpha3":   "GB",
+			"numeric":  840,
+			"currency": 978,
+			"language": null,
+			"accepted": [ "GBP", "USD" ]
+		}"#).unwrap();
+		assert_eq!(record, Record {
+			alpha2:   Country::GB,
+			alpha3:   CountryCode::GBR,
+			numeric:  Country::US,
+			currency: Currency::EUR,
+			language: None,
+			accepted: vec![ CurrencyCode::GBP, CurrencyCode::USD ],
+		});
+	}
+	#[test]
+	fn deserialize__invalid() {
+		assert_err!(from_str::<Record>(r#"{
+			"alpha2":   "XX",
+			"alpha3":   "GB",
+			"numeric":  840,
+			"currency": 978,
+			"language": null,
+			"accepted": []
+		}"#));
+		assert_err!(from_str::<Record>(r#"{
+			"alpha2":   "GB",
+			"alpha3":   "GB",
+			"numeric":  999,
+			"currency": 978,
+			"language": null,
+			"accepted": []
+		}"#));
+	}
+	
+	//		compact																
+	#[test]
+	fn compact() {
+		let alphas = Alphas {
+			alpha2:   Country::GB,
+			alpha3:   CountryCode::GBR,
+			currency: Currency::GBP,
+			language: Language::EN,
+		};
+		assert_tokens(&alphas.compact(), &[
+			Token::Struct { name: "Alphas", len: 4 },
+			Token::Str("alpha2"),
+			Token::Str("GB"),
+			Token::Str("alpha3"),
+			Token::Str("GBR"),
+			Token::Str("currency"),
+			Token::Str("GBP"),
+			Token::Str("language"),
+			Token::Str("en"),
+			Token::StructEnd,
+		]);
+		assert_de_tokens(&alphas.compact(), &[
+			Token::Struct { name: "Alphas", len: 4 },
+			Token::Str("alpha2"),
+			Token::String("GBR"),
+			Token::Str("alpha3"),
+			Token::String("GB"),
+			Token::Str("currency"),
+			Token::String("GBP"),
+			Token::Str("language"),
+			Token::String("en"),
+			Token::StructEnd,
+		]);
+	}
+}
+
+//		Maps																	
+#[cfg(test)]
+mod maps {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json::{from_str, json, to_value};
+	use serde_test::{Configure, Token, assert_tokens};
+	use std::collections::BTreeMap;
+	
+	//		Prices																
+	/// A record with maps keyed in each of the available forms.
+	#[derive(Debug, Deserialize, PartialEq, Serialize)]
+	struct Prices {
+		/// Prices by country, keyed by two-letter code.
+		#[serde(with = "crate::serde::country::alpha2::map")]
+		countries:  BTreeMap<Country, u32>,
+		
+		/// Prices by country, keyed by numeric code.
+		#[serde(with = "crate::serde::country::numeric::map")]
+		numerics:   HashMap<CountryCode, u32>,
+		
+		/// Rates by currency, keyed by three-letter code.
+		#[serde(with = "crate::serde::currency::alpha3::map")]
+		currencies: BTreeMap<CurrencyCode, u32>,
+		
+		/// Greetings by language, without a codec.
+		languages:  BTreeMap<LanguageCode, String>,
+	}
+	
+	//		prices																
+	/// Creates a set of prices for testing.
+	fn prices() -> Prices {
+		Prices {
+			countries:  BTreeMap::from([ (Country::GB, 100), (Country::FR, 120) ]),
+			numerics:   HashMap::from([ (CountryCode::AF, 5) ]),
+			currencies: BTreeMap::from([ (CurrencyCode::GBP, 1), (CurrencyCode::EUR, 2) ]),
+			languages:  BTreeMap::from([ (LanguageCode::EN, "Hello".to_owned()) ]),
+		}
+	}
+	
+	//		json																
+	#[test]
+	fn json() {
+		assert_eq!(to_value(prices()).unwrap(), json!({
+			"countries":  { "FR": 120, "GB": 100 },
+			"numerics":   { "4": 5 },
+			"currencies": { "EUR": 2, "GBP": 1 },
+			"languages":  { "en": "Hello" },
+		}));
+		assert_eq!(from_str::<Prices>(&serde_json::to_string(&prices()).unwrap()).unwrap(), prices());
+	}
+	#[test]
+	fn json__any_form() {
+		let decoded: Prices = from_str(r#"{
+			"countries":  { "GBR": 100, "fr": 120 },
+			"numerics":   { "004": 5 },
+			"currencies": { "EUR": 2, "gbp": 1 },
+			"languages":  { "en": "Hello" }
+		}"#).unwrap();
+		assert_eq!(decoded, prices());
+	}
+	#[test]
+	fn json__invalid() {
+		assert_err!(from_str::<Prices>(r#"{
+			"countries":  { "XX": 100 },
+			"numerics":   {},
+			"currencies": {},
+			"languages":  {}
+		}"#));
+		assert_err!(from_str::<Prices>(r#"{
+			"countries":  {},
+			"numerics":   { "GB": 5 },
+			"currencies": {},
+			"languages":  {}
+		}"#));
+	}
+	
+	//		toml																
+	#[test]
+	fn toml() {
+		let encoded = toml::to_string(&prices()).unwrap();
+		assert!(encoded.contains("GB = 100"));
+		assert!(encoded.contains("4 = 5"));
+		assert_eq!(toml::from_str::<Prices>(&encoded).unwrap(), prices());
+	}
+	
+	//		yaml																
+	#[test]
+	fn yaml() {
+		let encoded = serde_yaml::to_string(&prices()).unwrap();
+		assert!(encoded.contains("GB: 100"));
+		assert_eq!(serde_yaml::from_str::<Prices>(&encoded).unwrap(), prices());
+	}
+	
+	//		compact																
+	#[test]
+	fn compact() {
+		let prices = Prices {
+			countries:  BTreeMap::from([ (Country::GB, 100) ]),
+			numerics:   HashMap::from([ (CountryCode::GB, 5) ]),
+			currencies: BTreeMap::new(),
+			languages:  BTreeMap::new(),
+		};
+		assert_tokens(&prices.compact(), &[
+			Token::Struct { name: "Prices", len: 4 },
+			Token::Str("countries"),
+			Token::Map { len: Some(1) },
+			Token::Str("GB"),
+			Token::U32(100),
+			Token::MapEnd,
+			Token::Str("numerics"),
+			Token::Map { len: Some(1) },
+			Token::Str("826"),
+			Token::U32(5),
+			Token::MapEnd,
+			Token::Str("currencies"),
+			Token::Map { len: Some(0) },
+			Token::MapEnd,
+			Token::Str("languages"),
+			Token::Map { len: Some(0) },
+			Token::MapEnd,
+			Token::StructEnd,
+		]);
+	}
+	
+	//		ordering															
+	#[test]
+	fn ordering() {
+		let map: BTreeMap<CurrencyCode, u32> = BTreeMap::from([ (CurrencyCode::USD, 1), (CurrencyCode::EUR, 2), (CurrencyCode::GBP, 3) ]);
+		assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![ CurrencyCode::EUR, CurrencyCode::GBP, CurrencyCode::USD ]);
+	}
+}
+
+//		EmptyAsNone																
+#[cfg(test)]
+mod empty_as_none {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json::{from_str, json, to_value};
+	
+	//		Row																	
+	/// A row with optional fields that may be empty.
+	#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+	struct Row {
+		/// An optional country code.
+		#[serde(default, with = "crate::serde::empty_as_none")]
+		country:  Option<CountryCode>,
+		
+		/// An optional currency.
+		#[serde(default, with = "crate::serde::empty_as_none")]
+		currency: Option<Currency>,
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		assert_eq!(from_str::<Row>(r#"{"country": "gbr", "currency": "Euro"}"#).unwrap(), Row { country: Some(CountryCode::GBR), currency: Some(Currency::EUR) });
+		assert_eq!(from_str::<Row>(r#"{"country": " GB ", "currency": null}"#).unwrap(),  Row { country: Some(CountryCode::GB),  currency: None });
+		assert_eq!(from_str::<Row>(r#"{"country": "", "currency": "  "}"#).unwrap(),      Row { country: None,                   currency: None });
+		assert_eq!(from_str::<Row>("{}").unwrap(),                                      Row { country: None,                   currency: None });
+	}
+	#[test]
+	fn deserialize__invalid() {
+		assert_err!(from_str::<Row>(r#"{"country": "XX"}"#));
+		assert_err!(from_str::<Row>(r#"{"country": 826}"#));
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(to_value(Row { country: Some(CountryCode::GB), currency: None }).unwrap(), json!({ "country": "GB", "currency": null }));
+	}
+}
+
+//		Sorted																	
+#[cfg(test)]
+mod sorted {
+	use super::super::*;
+	use serde_json::{from_str, json, to_value};
+	
+	//		Languages															
+	/// A set of languages that must be serialised in order.
+	#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+	struct Languages {
+		/// The languages.
+		#[serde(with = "crate::serde::sorted")]
+		languages: HashSet<LanguageCode>,
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		assert_eq!(
+			from_str::<Languages>(r#"{"languages": ["it", "de", "fr"]}"#).unwrap(),
+			Languages { languages: HashSet::from([ LanguageCode::DE, LanguageCode::FR, LanguageCode::IT ]) },
+		);
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		let languages = Languages { languages: Country::CH.languages_set() };
+		assert_eq!(to_value(&languages).unwrap(), json!({ "languages": [ "de", "fr", "it", "rm" ] }));
+		assert_eq!(to_value(Languages { languages: HashSet::new() }).unwrap(), json!({ "languages": [] }));
+	}
+}
@@ -0,0 +1,202 @@
+//		Tests
+
+//		Set																		
+#[cfg(test)]
+mod set__struct {
+	use super::super::*;
+	use core::iter;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let set = CountrySet::new();
+		assert!(set.is_empty());
+		assert_eq!(set.len(), 0);
+	}
+	
+	//		insert																
+	#[test]
+	fn insert() {
+		let mut set = CountrySet::new();
+		assert!( set.insert(Country::GB));
+		assert!(!set.insert(Country::GB));
+		assert!( set.insert(Country::ZW));
+		assert_eq!(set.len(), 2);
+	}
+	
+	//		remove																
+	#[test]
+	fn remove() {
+		let mut set: CountrySet = [Country::FR, Country::GB].into_iter().collect();
+		assert!( set.remove(Country::FR));
+		assert!(!set.remove(Country::FR));
+		assert_eq!(set.len(), 1);
+	}
+	
+	//		contains															
+	#[test]
+	fn contains() {
+		let set: CurrencySet = [Currency::EUR, Currency::USD].into_iter().collect();
+		assert!( set.contains(Currency::EUR));
+		assert!(!set.contains(Currency::GBP));
+	}
+	#[test]
+	fn contains__all() {
+		let countries: CountrySet  = Country::all().into_iter().collect();
+		let currencies: CurrencySet = Currency::all().into_iter().collect();
+		let languages: LanguageSet = Language::all().into_iter().collect();
+		assert_eq!(countries.len(),  249);
+		assert_eq!(currencies.len(), 179);
+		assert_eq!(languages.len(),  183);
+		for country in Country::all() {
+			assert!(countries.contains(country));
+		}
+	}
+	
+	//		set operations														
+	#[test]
+	fn union() {
+		let a: LanguageSet = [Language::EN, Language::FR].into_iter().collect();
+		let b: LanguageSet = [Language::FR, Language::DE].into_iter().collect();
+		assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), vec![Language::DE, Language::EN, Language::FR]);
+		assert_eq!(a | b, a.union(&b));
+	}
+	#[test]
+	fn intersection() {
+		let a: LanguageSet = [Language::EN, Language::FR].into_iter().collect();
+		let b: LanguageSet = [Language::FR, Language::DE].into_iter().collect();
+		assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), vec![Language::FR]);
+		assert_eq!(a & b, a.intersection(&b));
+	}
+	#[test]
+	fn difference() {
+		let a: LanguageSet = [Language::EN, Language::FR].into_iter().collect();
+		let b: LanguageSet = [Language::FR, Language::DE].into_iter().collect();
+		assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), vec![Language::EN]);
+		assert_eq!(a - b, a.difference(&b));
+		assert_eq!((a ^ b).iter().collect::<Vec<_>>(), vec![Language::DE, Language::EN]);
+	}
+	#[test]
+	fn subsets() {
+		let a: CountrySet = iter::once(Country::FR).collect();
+		let b: CountrySet = [Country::FR, Country::GB].into_iter().collect();
+		let c: CountrySet = iter::once(Country::US).collect();
+		assert!( a.is_subset(&b));
+		assert!(!b.is_subset(&a));
+		assert!( b.is_superset(&a));
+		assert!( a.is_disjoint(&c));
+		assert!(!a.is_disjoint(&b));
+	}
+	
+	//		iter																
+	#[test]
+	fn iter() {
+		let set: CountrySet = [Country::US, Country::AD, Country::GB].into_iter().collect();
+		assert_eq!(set.iter().collect::<Vec<_>>(), vec![Country::AD, Country::GB, Country::US]);
+	}
+	#[test]
+	fn iter__code_order() {
+		let countries: Vec<Country> = CountrySet::from_iter(Country::all()).iter().collect();
+		assert!(countries.windows(2).all(|pair| matches!(pair, [a, b] if a.code() < b.code())));
+		let currencies: Vec<Currency> = CurrencySet::from_iter(Currency::all()).iter().collect();
+		assert!(currencies.windows(2).all(|pair| matches!(pair, [a, b] if a.code() < b.code())));
+		let languages: Vec<Language> = LanguageSet::from_iter(Language::all()).iter().collect();
+		assert!(languages.windows(2).all(|pair| matches!(pair, [a, b] if a.code() < b.code())));
+	}
+	
+	//		to_codes															
+	#[test]
+	fn to_codes() {
+		let set: CountrySet = [Country::FR, Country::GB].into_iter().collect();
+		assert_eq!(set.to_codes(), HashSet::from([CountryCode::FR, CountryCode::GB]));
+	}
+}
+
+//		Member																	
+#[cfg(test)]
+mod member__traits {
+	use super::super::*;
+	
+	//		ordered																
+	#[test]
+	fn ordered__all() {
+		for (index, country) in Country::ordered().iter().enumerate() {
+			assert_eq!(country.index(), index);
+		}
+		for (index, currency) in Currency::ordered().iter().enumerate() {
+			assert_eq!(currency.index(), index);
+		}
+		for (index, language) in Language::ordered().iter().enumerate() {
+			assert_eq!(language.index(), index);
+		}
+		assert!(Country::ordered().len() <= WORDS * 64);
+	}
+}
+
+#[cfg(test)]
+mod set__traits {
+	use super::super::*;
+	use rubedo::sugar::s;
+	use serde_json;
+	
+	//		debug																
+	#[test]
+	fn debug() {
+		let set: CurrencySet = [Currency::USD, Currency::EUR].into_iter().collect();
+		assert_eq!(format!("{set:?}"), "{EUR: Euro, USD: United States dollar}");
+	}
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		let set: CountrySet = serde_json::from_str(r#"["GB", "fra"]"#).unwrap();
+		assert_eq!(set, [Country::FR, Country::GB].into_iter().collect());
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		let set: CountrySet = [Country::US, Country::GB, Country::DE].into_iter().collect();
+		assert_eq!(set.to_string(),                "DE, GB, US");
+		assert_eq!(CurrencySet::new().to_string(), "");
+		assert_eq!(set.to_string().parse::<CountrySet>().unwrap(), set);
+	}
+	
+	//		from																
+	#[test]
+	fn from__hash_set() {
+		let codes = HashSet::from([LanguageCode::EN, LanguageCode::CY]);
+		let set   = LanguageSet::from(&codes);
+		assert_eq!(set.len(), 2);
+		assert!(set.contains(Language::CY));
+		assert_eq!(set.to_codes(), codes);
+		assert_eq!(CurrencySet::from(Country::CH.currencies_set()).len(), 3);
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		let set1: CountrySet = "GB, FR;DE US".parse().unwrap();
+		assert_eq!(set1, [Country::DE, Country::FR, Country::GB, Country::US].into_iter().collect());
+		let set2: CountrySet = " gbr,,\tfra\n".parse().unwrap();
+		assert_eq!(set2, [Country::FR, Country::GB].into_iter().collect());
+		let set3: CurrencySet = "EUR,USD".parse().unwrap();
+		assert_eq!(set3.len(), 2);
+		assert!("".parse::<LanguageSet>().unwrap().is_empty());
+	}
+	#[test]
+	fn from_str__invalid() {
+		let err = "GB, XX, FR, YYY".parse::<CountrySet>().unwrap_err();
+		assert_eq!(err.invalid(),   &[ s!("XX"), s!("YYY") ]);
+		assert_eq!(err.to_string(), "Invalid codes: XX, YYY");
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		let set: CountrySet = [Country::GB, Country::FR].into_iter().collect();
+		assert_eq!(serde_json::to_string(&set).unwrap(), r#"["FR","GB"]"#);
+	}
+}
+
+
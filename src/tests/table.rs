@@ -0,0 +1,89 @@
+//		Tests
+
+//		Table																	
+#[cfg(test)]
+mod table__struct {
+	use super::super::*;
+	use crate::{country::CountryCode, currency::CurrencyCode};
+	use rubedo::sugar::s;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let table = Table::new(Currency::CHF.countries().iter().map(CountryCode::country), &[ CountryColumn::Code, CountryColumn::Name ]);
+		assert_eq!(table.headers(), &[ "Code", "Name" ]);
+		assert_eq!(table.rows(),    &[ vec![ s!("CH"), s!("Switzerland") ], vec![ s!("LI"), s!("Liechtenstein") ] ]);
+		assert_eq!(table.len(),     2);
+		assert!(!table.is_empty());
+	}
+	
+	//		new__filtered														
+	#[test]
+	fn new__filtered() {
+		let table = Table::new(
+			Country::all().into_iter().filter(|country| country.currencies().contains(&CurrencyCode::EUR)),
+			&[ CountryColumn::Code ],
+		);
+		assert_eq!(table.len(), Currency::EUR.countries().len());
+		let empty = Table::new(Vec::<Language>::new(), &[ LanguageColumn::Code ]);
+		assert!(empty.is_empty());
+	}
+}
+
+#[cfg(test)]
+mod table__traits {
+	use super::super::*;
+	
+	//		display																
+	#[test]
+	fn display() {
+		let table = Table::new([ Currency::GBP, Currency::JPY ], &[ CurrencyColumn::Code, CurrencyColumn::Numeric, CurrencyColumn::Digits, CurrencyColumn::Name ]);
+		assert_eq!(table.to_string(), concat!(
+			"Code  Numeric  Digits  Name\n",
+			"----  -------  ------  --------------\n",
+			"GBP   826      2       Pound sterling\n",
+			"JPY   392      0       Japanese yen\n",
+		));
+	}
+	
+	//		display__unicode													
+	#[test]
+	fn display__unicode() {
+		let table = Table::new([ Country::AX, Country::CH ], &[ CountryColumn::Name, CountryColumn::Alpha3 ]);
+		assert_eq!(table.to_string(), concat!(
+			"Name           Alpha3\n",
+			"-------------  ------\n",
+			"Åland Islands  ALA\n",
+			"Switzerland    CHE\n",
+		));
+	}
+}
+
+//§		Tabulate																
+#[cfg(test)]
+mod tabulate__traits {
+	use super::super::*;
+	
+	//		cell__country														
+	#[test]
+	fn cell__country() {
+		assert_eq!(Country::CH.cell(CountryColumn::Numeric),    "756");
+		assert_eq!(Country::CH.cell(CountryColumn::Currencies), "CHE, CHF, CHW");
+		assert_eq!(Country::AD.cell(CountryColumn::Numeric),    "020");
+	}
+	
+	//		cell__currency														
+	#[test]
+	fn cell__currency() {
+		assert_eq!(Currency::GBP.cell(CurrencyColumn::Countries), "GB, GG, IM, JE, SH");
+	}
+	
+	//		cell__language														
+	#[test]
+	fn cell__language() {
+		assert_eq!(Language::NO.cell(LanguageColumn::Countries), "BV, NO, SJ");
+		assert_eq!(Language::NO.cell(LanguageColumn::Code),      "no");
+	}
+}
+
+
@@ -0,0 +1,88 @@
+//		Tests
+
+//		Checksum																
+#[cfg(test)]
+mod checksum__enum {
+	use super::super::*;
+	
+	//		verify																
+	#[test]
+	fn verify() {
+		assert!( Checksum::Abn.verify("51824753556"));
+		assert!(!Checksum::Abn.verify("51824753557"));
+		assert!( Checksum::Cnpj.verify("11222333000181"));
+		assert!(!Checksum::Cnpj.verify("11222333000182"));
+		assert!( Checksum::Cnpj.verify("12ABC34501DE35"));
+		assert!( Checksum::Luhn.verify("00743110157"));
+		assert!(!Checksum::Luhn.verify("00743110158"));
+		assert!( Checksum::Mod11Radix10.verify("136695976"));
+		assert!(!Checksum::Mod11Radix10.verify("136695977"));
+		assert!( Checksum::Mod97.verify("0428759497"));
+		assert!(!Checksum::Mod97.verify("0428759496"));
+		assert!( Checksum::Siren.verify("40303265045"));
+		assert!(!Checksum::Siren.verify("41303265045"));
+		assert!( Checksum::Siren.verify("AB303265045"));
+		assert!( Checksum::UkVat.verify("980780684"));
+		assert!(!Checksum::UkVat.verify("980780685"));
+		assert!( Checksum::UkVat.verify("GD001"));
+	}
+	#[test]
+	fn verify__mod11() {
+		let pl = Checksum::Mod11 { weights: &[ 6, 5, 7, 2, 3, 4, 5, 6, 7 ], complement: false };
+		assert!( pl.verify("5260250274"));
+		assert!(!pl.verify("5260250275"));
+		let no = Checksum::Mod11 { weights: &[ 3, 2, 7, 6, 5, 4, 3, 2 ], complement: true };
+		assert!( no.verify("974760673"));
+		assert!(!no.verify("974760674"));
+	}
+}
+
+//		TaxIdFormat																
+#[cfg(test)]
+mod tax_id_format__struct {
+	use super::super::*;
+	use crate::AsStr;
+	
+	//		for_country															
+	#[test]
+	fn for_country() {
+		let format = TaxIdFormat::for_country(Country::GR).unwrap();
+		assert_eq!(format.name(),         "VAT identification number");
+		assert_eq!(format.prefix(),       Some("EL"));
+		assert_eq!(format.formats(),      &[ "#########" ]);
+		assert!(!format.has_checksum());
+		assert_eq!(TaxIdFormat::for_country(Country::AQ), None);
+	}
+	#[test]
+	fn for_country__all() {
+		for entry in &FORMATS {
+			assert!(!entry.1.formats().is_empty(), "{:?}", entry.0);
+		}
+		assert!(FORMATS.windows(2).all(|pair| matches!(pair, [a, b] if a.0.as_str() < b.0.as_str())));
+	}
+	
+	//		is_valid															
+	#[test]
+	fn is_valid() {
+		let gb = TaxIdFormat::for_country(Country::GB).unwrap();
+		assert!( gb.is_valid("GB980780684"));
+		assert!( gb.is_valid("gb 980 7806 84"));
+		assert!( gb.is_valid("980780684"));
+		assert!( gb.is_valid("GB980780684001"));
+		assert!( gb.is_valid("GBGD001"));
+		assert!(!gb.is_valid("GB980780685"));
+		assert!(!gb.is_valid("DE980780684"));
+		assert!(!gb.is_valid("GB98078068"));
+		assert!(!gb.is_valid(""));
+		let br = TaxIdFormat::for_country(Country::BR).unwrap();
+		assert!( br.is_valid("11.222.333/0001-81"));
+		assert!(!br.is_valid("11.222.333/0001-82"));
+		let ch = TaxIdFormat::for_country(Country::CH).unwrap();
+		assert!( ch.is_valid("CHE-109.322.551 MWST"));
+		assert!( ch.is_valid("CHE109322551"));
+		assert!(!ch.is_valid("CHE-109.322.552"));
+		let nl = TaxIdFormat::for_country(Country::NL).unwrap();
+		assert!( nl.is_valid("NL123456789B01"));
+		assert!(!nl.is_valid("NL123456789C01"));
+	}
+}
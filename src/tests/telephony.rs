@@ -0,0 +1,73 @@
+//		Tests
+
+//		NumberingPlan															
+#[cfg(test)]
+mod numbering_plan__struct {
+	use super::super::*;
+	use crate::AsStr;
+	
+	//		for_country															
+	#[test]
+	fn for_country() {
+		let plan = NumberingPlan::for_country(Country::GB).unwrap();
+		assert_eq!(plan.calling_code(),            44);
+		assert_eq!(plan.trunk_prefix(),            Some("0"));
+		assert_eq!(plan.international_prefix(),    "00");
+		assert_eq!(plan.national_number_lengths(), 7..=10);
+		assert_eq!(NumberingPlan::for_country(Country::HM), None);
+	}
+	#[test]
+	fn for_country__all() {
+		for country in Country::all() {
+			let Some(plan) = NumberingPlan::for_country(country) else {
+				continue;
+			};
+			assert!(plan.calling_code() > 0, "{country:?}");
+			assert!(plan.national_number_lengths().start() <= plan.national_number_lengths().end(), "{country:?}");
+			assert!(plan.international_prefix().bytes().all(|b| b.is_ascii_digit()), "{country:?}");
+			assert!(plan.trunk_prefix().is_none_or(|prefix| prefix.bytes().all(|b| b.is_ascii_digit())), "{country:?}");
+		}
+		assert!(PLANS.windows(2).all(|pair| matches!(pair, [a, b] if a.0.as_str() < b.0.as_str())));
+	}
+	
+	//		is_plausible														
+	#[test]
+	fn is_plausible() {
+		let gb = NumberingPlan::for_country(Country::GB).unwrap();
+		assert!( gb.is_plausible("+44 20 7946 0018"));
+		assert!( gb.is_plausible("0044 20 7946 0018"));
+		assert!( gb.is_plausible("020 7946 0018"));
+		assert!( gb.is_plausible("(020) 7946-0018"));
+		assert!( gb.is_plausible("+44 (0)20 7946 0018"));
+		assert!( gb.is_plausible("0044 (0) 20 7946 0018"));
+		assert!(!gb.is_plausible("+44 20 (0)7946 0018"));
+		assert!(!gb.is_plausible("+33 1 23 45 67 89"));
+		assert!(!gb.is_plausible("020 7946 00189"));
+		assert!(!gb.is_plausible("020 7946 001A"));
+		assert!(!gb.is_plausible("020 +7946 0018"));
+		assert!(!gb.is_plausible(""));
+		let us = NumberingPlan::for_country(Country::US).unwrap();
+		assert!( us.is_plausible("+1 (212) 555-0100"));
+		assert!( us.is_plausible("1-212-555-0100"));
+		assert!( us.is_plausible("212.555.0100"));
+		assert!(!us.is_plausible("555-0100"));
+		let it = NumberingPlan::for_country(Country::IT).unwrap();
+		assert!( it.is_plausible("06 6982 1234"));
+		assert!( it.is_plausible("+39 06 6982 1234"));
+	}
+}
+
+//		Functions																
+#[cfg(test)]
+mod functions {
+	use super::super::*;
+	
+	//		countries_with_calling_code											
+	#[test]
+	fn countries_with_calling_code() {
+		assert_eq!(super::super::countries_with_calling_code(44), vec![ Country::GB, Country::GG, Country::IM, Country::JE ]);
+		assert_eq!(super::super::countries_with_calling_code(7),  vec![ Country::KZ, Country::RU ]);
+		assert!(super::super::countries_with_calling_code(1).contains(&Country::CA));
+		assert!(super::super::countries_with_calling_code(999).is_empty());
+	}
+}
@@ -0,0 +1,70 @@
+//		Tests
+
+//		Functions																
+#[cfg(test)]
+mod functions {
+	use super::super::*;
+	use rubedo::sugar::s;
+	
+	//		assert_string_round_trip											
+	#[test]
+	fn assert_string_round_trip__valid() {
+		assert_string_round_trip(Country::GB);
+		assert_string_round_trip(CountryCode::GBR);
+		assert_string_round_trip(CurrencyCode::GBP);
+		assert_string_round_trip(Language::EN);
+	}
+	
+	//		assert_numeric_round_trip											
+	#[test]
+	fn assert_numeric_round_trip__valid() {
+		assert_numeric_round_trip(CountryCode::GB);
+		assert_numeric_round_trip(CountryCode::GBR);
+		assert_numeric_round_trip(CurrencyCode::GBP);
+	}
+	
+	//		assert_serde_round_trip												
+	#[test]
+	fn assert_serde_round_trip__valid() {
+		assert_serde_round_trip(Country::GB);
+		assert_serde_round_trip(CountryCode::GBR);
+		assert_serde_round_trip(Currency::GBP);
+		assert_serde_round_trip(LanguageCode::EN);
+	}
+	
+	//		assert_alpha_round_trip												
+	#[test]
+	fn assert_alpha_round_trip__valid() {
+		assert_alpha_round_trip(CountryCode::GB);
+		assert_alpha_round_trip(CountryCode::GBR);
+	}
+	
+	//		assert_all_round_trips												
+	#[test]
+	fn assert_all_round_trips__valid() {
+		assert_all_round_trips();
+	}
+	
+	//		benchmark_codes														
+	#[test]
+	fn benchmark_codes() {
+		let codes = super::super::benchmark_codes();
+		assert_eq!(codes.len(), CountryCode::all().len() * 2 + CurrencyCode::all().len() + LanguageCode::all().len());
+		assert!(codes.contains(&s!("GB")));
+		assert!(codes.contains(&s!("GBR")));
+		assert!(codes.contains(&s!("GBP")));
+		assert!(codes.contains(&s!("en")));
+	}
+	
+	//		benchmark_inputs													
+	#[test]
+	fn benchmark_inputs() {
+		let inputs = super::super::benchmark_inputs();
+		assert!(inputs.contains(&s!("gbr")));
+		assert!(inputs.contains(&s!("GbR")));
+		assert!(inputs.contains(&s!("Germany")));
+		assert!(inputs.contains(&s!("Pound sterling")));
+		assert!(inputs.contains(&s!("Atlantis")));
+		assert_eq!(inputs, super::super::benchmark_inputs());
+	}
+}
@@ -0,0 +1,52 @@
+//		Tests
+
+//		Functions																
+#[cfg(test)]
+mod functions {
+	//		matches_format														
+	#[test]
+	fn matches_format() {
+		assert!( super::super::matches_format("@@# #@@", "SW1 1AA"));
+		assert!( super::super::matches_format("@@# #@@", "sw1 1aa"));
+		assert!( super::super::matches_format("*#B#",    "A1b2"));
+		assert!(!super::super::matches_format("@@# #@@", "SW11AA"));
+		assert!(!super::super::matches_format("#####",   "1234A"));
+		assert!(!super::super::matches_format("*#B#",    "A1C2"));
+	}
+	
+	//		normalize															
+	#[test]
+	fn normalize() {
+		assert_eq!(super::super::normalize("Côte d'Ivoire"),               "cote divoire");
+		assert_eq!(super::super::normalize("Guinea-Bissau"),               "guinea bissau");
+		assert_eq!(super::super::normalize("  Korea (Republic of)  "),     "korea republic of");
+		assert_eq!(super::super::normalize("São Tomé and Príncipe"),       "sao tome and principe");
+		assert_eq!(super::super::normalize("Vietnamese đồng"),             "vietnamese dong");
+		assert_eq!(super::super::normalize("Tongan paʻanga"),              "tongan paanga");
+		assert_eq!(super::super::normalize("Straße / Œuvre"),              "strasse oeuvre");
+		assert_eq!(super::super::normalize("St. Helena, Ascension"),       "st helena ascension");
+		assert_eq!(super::super::normalize(""),                            "");
+	}
+	
+	//		similarity															
+	#[test]
+	fn similarity() {
+		assert!((super::super::similarity("Côte d'Ivoire", "cote divoire") - 1.0).abs() < f32::EPSILON);
+		assert!((super::super::similarity("kitten", "sitting") - 4.0 / 7.0).abs() < f32::EPSILON);
+		assert!((super::super::similarity("Frnace", "France")  - 5.0 / 6.0).abs() < f32::EPSILON);
+		assert!((super::super::similarity("ab", "ba")          - 0.5).abs()       < f32::EPSILON);
+		assert!((super::super::similarity("", "")  - 1.0).abs() < f32::EPSILON);
+		assert!(super::super::similarity("abc", "xyz").abs() < f32::EPSILON);
+	}
+	
+	//		stable_hash															
+	#[test]
+	fn stable_hash() {
+		assert_eq!(super::super::stable_hash(""),    0xcbf2_9ce4_8422_2325);
+		assert_eq!(super::super::stable_hash("a"),   0xaf63_dc4c_8601_ec8c);
+		assert_eq!(super::super::stable_hash("GB"),  0x0902_1407_b59b_fa0c);
+		assert_eq!(super::super::stable_hash("GBP"), 0xea82_6519_980a_6a54);
+	}
+}
+
+
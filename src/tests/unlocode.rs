@@ -0,0 +1,136 @@
+//		Tests
+
+//		UnLocode																
+#[cfg(test)]
+mod unlocode__struct {
+	use super::super::*;
+	use claims::{assert_err, assert_ok};
+	use rubedo::sugar::s;
+	
+	//		Directory															
+	/// A directory that only knows the subdivision of London.
+	struct Directory;
+	
+	//󰭅		LocationDirectory													
+	impl LocationDirectory for Directory {
+		//		subdivision														
+		fn subdivision(&self, locode: UnLocode) -> Option<String> {
+			(locode.to_string() == "GBLON").then(|| s!("GB-LND"))
+		}
+	}
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let locode = UnLocode::new(CountryCode::GBR, "lon").unwrap();
+		assert_eq!(locode.country_code(), CountryCode::GB);
+		assert_eq!(locode.location(),     "LON");
+		assert_ok!(UnLocode::new(CountryCode::US, "NY2"));
+		assert_err!(UnLocode::new(CountryCode::US, "NY1"));
+		assert_err!(UnLocode::new(CountryCode::US, "NYCX"));
+	}
+	
+	//		country																
+	#[test]
+	fn country() {
+		assert_eq!(UnLocode::new(CountryCode::NL, "RTM").unwrap().country(), Country::NL);
+	}
+	
+	//		subdivision															
+	#[test]
+	fn subdivision() {
+		assert_eq!(UnLocode::new(CountryCode::GB, "LON").unwrap().subdivision(&Directory), Some(s!("GB-LND")));
+		assert_eq!(UnLocode::new(CountryCode::GB, "MNC").unwrap().subdivision(&Directory), None);
+	}
+}
+
+//		UnLocode																
+#[cfg(test)]
+mod unlocode__traits {
+	use super::super::*;
+	use claims::assert_err;
+	use serde_json;
+	
+	//		deserialize															
+	#[test]
+	fn deserialize() {
+		assert_eq!(serde_json::from_str::<UnLocode>(r#""GBLON""#).unwrap(), UnLocode::new(CountryCode::GB, "LON").unwrap());
+		assert_err!(serde_json::from_str::<UnLocode>(r#""XZLON""#));
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(UnLocode::new(CountryCode::GB, "LON").unwrap().to_string(), "GBLON");
+	}
+	
+	//		from_str															
+	#[test]
+	fn from_str() {
+		let locode = UnLocode::new(CountryCode::GB, "LON").unwrap();
+		assert_eq!(UnLocode::from_str("GBLON").unwrap(),   locode);
+		assert_eq!(UnLocode::from_str("GB LON").unwrap(),   locode);
+		assert_eq!(UnLocode::from_str(" gblon ").unwrap(), locode);
+	}
+	#[test]
+	fn from_str__invalid() {
+		assert_err!(UnLocode::from_str(""));
+		assert_err!(UnLocode::from_str("GB"));
+		assert_err!(UnLocode::from_str("GBR LON"));
+		assert_err!(UnLocode::from_str("XZLON"));
+		assert_err!(UnLocode::from_str("GBLO"));
+		assert_err!(UnLocode::from_str("GB-LON"));
+		assert_err!(UnLocode::from_str("ÉLON"));
+	}
+	
+	//		serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&UnLocode::new(CountryCode::US, "NYC").unwrap()).unwrap(), r#""USNYC""#);
+	}
+}
+
+//		LocationDirectory														
+#[cfg(test)]
+mod location_directory__traits {
+	use super::super::*;
+	
+	//		Airports															
+	/// A directory that recognises IATA airport codes.
+	struct Airports;
+	
+	//󰭅		LocationDirectory													
+	impl LocationDirectory for Airports {
+		//		subdivision														
+		fn subdivision(&self, _locode: UnLocode) -> Option<String> {
+			None
+		}
+		
+		//		locate															
+		fn locate(&self, identifier: &str) -> Option<UnLocode> {
+			match identifier {
+				"LHR" => UnLocode::new(CountryCode::GB, "LHR").ok(),
+				"JFK" => UnLocode::new(CountryCode::US, "JFK").ok(),
+				_     => identifier.parse().ok(),
+			}
+		}
+	}
+	
+	//		locate																
+	#[test]
+	fn locate() {
+		assert_eq!(Airports.locate("LHR"),   UnLocode::new(CountryCode::GB, "LHR").ok());
+		assert_eq!(Airports.locate("FRPAR"), UnLocode::new(CountryCode::FR, "PAR").ok());
+		assert_eq!(Airports.locate("XXX"),   None);
+	}
+	
+	//		country																
+	#[test]
+	fn country() {
+		assert_eq!(Airports.country("JFK"),   Some(Country::US));
+		assert_eq!(Airports.country("DEBER"), Some(Country::DE));
+		assert_eq!(Airports.country("XXX"),   None);
+	}
+}
+
+
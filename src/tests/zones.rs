@@ -0,0 +1,64 @@
+//		Tests
+
+//		MonetaryZone															
+#[cfg(test)]
+mod monetary_zone__enum {
+	use super::super::*;
+	
+	//		all																	
+	#[test]
+	fn all() {
+		assert_eq!(MonetaryZone::all().len(), 4);
+		assert!(MonetaryZone::all().contains(&MonetaryZone::Eurozone));
+	}
+	
+	//		countries															
+	#[test]
+	fn countries() {
+		assert_eq!(MonetaryZone::Cemac     .countries().len(), 6);
+		assert_eq!(MonetaryZone::Dollarised.countries().len(), 10);
+		assert_eq!(MonetaryZone::Eurozone  .countries().len(), 20);
+		assert_eq!(MonetaryZone::Waemu     .countries().len(), 8);
+		assert!(EUROZONE.contains(Country::FR));
+		assert!(!EUROZONE.contains(Country::MC));
+		assert!(!EUROZONE.contains(Country::ME));
+		assert!(DOLLARISED.contains(Country::EC));
+		assert!(!DOLLARISED.contains(Country::US));
+		assert!(!DOLLARISED.contains(Country::PR));
+	}
+	#[test]
+	fn countries__currency() {
+		for zone in MonetaryZone::all() {
+			for country in zone.countries().iter() {
+				assert!(country.currencies().contains(&zone.currency()), "{zone}: {country:?}");
+			}
+		}
+	}
+	
+	//		currency															
+	#[test]
+	fn currency() {
+		assert_eq!(MonetaryZone::Cemac     .currency(), CurrencyCode::XAF);
+		assert_eq!(MonetaryZone::Dollarised.currency(), CurrencyCode::USD);
+		assert_eq!(MonetaryZone::Eurozone  .currency(), CurrencyCode::EUR);
+		assert_eq!(MonetaryZone::Waemu     .currency(), CurrencyCode::XOF);
+	}
+}
+
+#[cfg(test)]
+mod monetary_zone__traits {
+	use super::super::*;
+	
+	//		as_str																
+	#[test]
+	fn as_str() {
+		assert_eq!(MonetaryZone::Eurozone.as_str(), "Eurozone");
+		assert_eq!(MonetaryZone::Waemu.as_str(),    "WAEMU");
+	}
+	
+	//		display																
+	#[test]
+	fn display() {
+		assert_eq!(MonetaryZone::Cemac.to_string(), "CEMAC");
+	}
+}
@@ -0,0 +1,212 @@
+//! Text handling utilities.
+//! 
+//! This module provides the [`normalize()`] function, which is used when
+//! looking up countries, currencies, and languages by name, so that minor
+//! differences in case, accents, and punctuation do not prevent a match. It is
+//! public so that callers can pre-normalise their own data in the same way.
+//! 
+//! It also provides the [`similarity()`] function, which scores how closely two
+//! pieces of text match, and is used for fuzzy matching of names, e.g. by
+//! [`Country::fuzzy_match()`](crate::country::Country::fuzzy_match()).
+//! 
+//! Finally, it provides the [`stable_hash()`] function, which calculates a
+//! hash of a piece of text that is guaranteed not to change, and is used for
+//! the `stable_hash()` methods of the code types.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/text.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use core::mem;
+
+
+
+//		Functions
+
+//		normalize																
+/// Normalises text for comparison.
+/// 
+/// The text is folded to lowercase, accents are removed from Latin letters,
+/// and common punctuation is stripped. Hyphens, slashes, and whitespace are
+/// treated as word separators, and runs of them are collapsed into a single
+/// space, with none at the start or end. For example, "Côte d'Ivoire" becomes
+/// "cote divoire", and "Guinea-Bissau" becomes "guinea bissau".
+/// 
+/// Characters that are not letters or digits, and are not separators, are
+/// removed.
+/// 
+/// # Parameters
+/// 
+/// * `text` - The text to normalise.
+/// 
+#[must_use]
+pub fn normalize(text: &str) -> String {
+	let mut normalized = String::with_capacity(text.len());
+	let mut pending    = false;
+	for c in text.chars().flat_map(char::to_lowercase) {
+		if c.is_whitespace() || matches!(c, '-' | '‐' | '‑' | '–' | '—' | '/' | '_') {
+			pending = !normalized.is_empty();
+		} else if c.is_alphanumeric() && !matches!(c, 'ʻ' | 'ʼ') {
+			if pending {
+				normalized.push(' ');
+				pending = false;
+			}
+			match fold(c) {
+				Some(folded) => normalized.push_str(folded),
+				None         => normalized.push(c),
+			}
+		}
+	}
+	normalized
+}
+
+//		similarity																
+/// Scores how similar two pieces of text are.
+/// 
+/// Both pieces of text are first normalised using [`normalize()`], and then the
+/// optimal string alignment distance between them is calculated, i.e. the
+/// number of single-character insertions, deletions, substitutions, and swaps
+/// of adjacent characters needed to turn one into the other. This is a
+/// restricted form of the Damerau-Levenshtein distance, so a typo such as
+/// "Frnace" counts as one edit rather than two. The score is this distance
+/// relative to the length of the longer of the two, subtracted from one, giving
+/// a value between `0.0` (completely different) and `1.0` (identical after
+/// normalisation).
+/// 
+/// # Parameters
+/// 
+/// * `first`  - The first piece of text.
+/// * `second` - The second piece of text.
+/// 
+#[expect(clippy::cast_precision_loss, reason = "Lengths of names are small")]
+#[must_use]
+pub fn similarity(first: &str, second: &str) -> f32 {
+	let first_chars:  Vec<char> = normalize(first).chars().collect();
+	let second_chars: Vec<char> = normalize(second).chars().collect();
+	let longest                 = first_chars.len().max(second_chars.len());
+	if longest == 0 {
+		return 1.0_f32;
+	}
+	1.0_f32 - osa_distance(&first_chars, &second_chars) as f32 / longest as f32
+}
+
+//		stable_hash																
+/// Calculates a stable 64-bit hash of a piece of text.
+/// 
+/// This uses the 64-bit FNV-1a algorithm over the UTF-8 bytes of the text, with
+/// the standard offset basis of `0xcbf29ce484222325` and prime of
+/// `0x100000001b3`. Unlike the hashes produced by [`Hash`](core::hash::Hash)
+/// and Rust's hashers, the result is fully specified, and so will not change
+/// between versions of Rust or of this crate, or between platforms. This makes
+/// it suitable for consistent hashing and sharding across services, e.g. by
+/// using the `stable_hash()` methods of the code types, such as
+/// [`CountryCode::stable_hash()`](crate::country::CountryCode::stable_hash()).
+/// 
+/// Note that FNV-1a is not a cryptographic hash, and should not be used where
+/// resistance to deliberate collisions is needed.
+/// 
+/// # Parameters
+/// 
+/// * `text` - The text to hash.
+/// 
+#[must_use]
+pub fn stable_hash(text: &str) -> u64 {
+	text.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+		(hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+	})
+}
+
+//		matches_format															
+/// Checks whether text matches a simple format pattern.
+/// 
+/// In the pattern, `#` represents a digit, `@` represents a letter, `*`
+/// represents a letter or digit, and any other character represents itself,
+/// matched case-insensitively. This is used for the postal code and tax
+/// identifier formats.
+/// 
+/// # Parameters
+/// 
+/// * `format` - The format pattern.
+/// * `text`   - The text to check.
+/// 
+pub(crate) fn matches_format(format: &str, text: &str) -> bool {
+	format.len() == text.len() && format.bytes().zip(text.bytes()).all(|(f, c)| match f {
+		b'#' => c.is_ascii_digit(),
+		b'@' => c.is_ascii_alphabetic(),
+		b'*' => c.is_ascii_alphanumeric(),
+		_    => c.eq_ignore_ascii_case(&f),
+	})
+}
+
+//		fold																	
+/// Folds an accented or special Latin letter to its plain equivalent.
+/// 
+/// Returns [`None`] if the character does not need folding. The character is
+/// expected to be lowercase already.
+/// 
+const fn fold(c: char) -> Option<&'static str> {
+	Some(match c {
+		'à'..='å' | 'ā' | 'ă' | 'ą' | 'ạ'..='ặ'                         => "a",
+		'ç' | 'ć' | 'ĉ' | 'ċ' | 'č'                                     => "c",
+		'ď' | 'đ'                                                       => "d",
+		'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' | 'ẹ'..='ệ'             => "e",
+		'ĝ' | 'ğ' | 'ġ' | 'ģ'                                           => "g",
+		'ĥ' | 'ħ'                                                       => "h",
+		'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' | 'ỉ' | 'ị'             => "i",
+		'ĵ'                                                             => "j",
+		'ķ'                                                             => "k",
+		'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł'                                     => "l",
+		'ñ' | 'ń' | 'ņ' | 'ň'                                           => "n",
+		'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' | 'ơ' | 'ọ'..='ợ'             => "o",
+		'ŕ' | 'ŗ' | 'ř'                                                 => "r",
+		'ś' | 'ŝ' | 'ş' | 'š' | 'ș'                                     => "s",
+		'ţ' | 'ť' | 'ŧ' | 'ț'                                           => "t",
+		'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' | 'ư' | 'ụ'..='ự' => "u",
+		'ŵ'                                                             => "w",
+		'ý' | 'ÿ' | 'ŷ' | 'ỳ'..='ỹ'                                     => "y",
+		'ź' | 'ż' | 'ž'                                                 => "z",
+		'æ'                                                             => "ae",
+		'œ'                                                             => "oe",
+		'ß'                                                             => "ss",
+		'þ'                                                             => "th",
+		_                                                               => return None,
+	})
+}
+
+//		osa_distance																
+/// Calculates the optimal string alignment distance between two sequences of
+/// characters.
+fn osa_distance(first: &[char], second: &[char]) -> usize {
+	let mut previous: Vec<usize> = Vec::new();
+	let mut row:      Vec<usize> = (0..=second.len()).collect();
+	for (index, &a) in first.iter().enumerate() {
+		let before   = index.checked_sub(1).and_then(|earlier| first.get(earlier)).copied();
+		let mut next = Vec::with_capacity(row.len());
+		next.push(index.saturating_add(1));
+		for (column, &b) in second.iter().enumerate() {
+			let diagonal     = row.get(column).copied().unwrap_or_default();
+			let above        = row.get(column.saturating_add(1)).copied().unwrap_or_default();
+			let left         = next.last().copied().unwrap_or_default();
+			let mut distance = diagonal.saturating_add(usize::from(a != b))
+				.min(above.saturating_add(1))
+				.min(left.saturating_add(1));
+			let prior = column.checked_sub(1);
+			if before == Some(b) && prior.and_then(|earlier| second.get(earlier)) == Some(&a) {
+				let swapped = prior.and_then(|earlier| previous.get(earlier)).copied().unwrap_or_default();
+				distance    = distance.min(swapped.saturating_add(1));
+			}
+			next.push(distance);
+		}
+		previous = mem::replace(&mut row, next);
+	}
+	row.last().copied().unwrap_or_default()
+}
@@ -0,0 +1,245 @@
+//! UN/LOCODE location codes.
+//! 
+//! This module provides the [`UnLocode`] type, which represents a United
+//! Nations Code for Trade and Transport Locations, as used to identify ports,
+//! airports, inland depots, and other locations in supply-chain and logistics
+//! systems. A UN/LOCODE is made up of a two-letter ISO 3166-1 country code,
+//! followed by a three-character code for the location within the country,
+//! e.g. `GBLON` for London, often written with a space as `GB LON`.
+//! 
+//! Parsing a UN/LOCODE splits it into its [`CountryCode`] and location parts,
+//! and validates the country part against ISO 3166-1. The location part is
+//! only checked for form, i.e. that it is made up of three letters or the
+//! digits `2` to `9`, and not against the published list of locations.
+//! 
+//! Note that UN/LOCODE uses the code `XZ` for installations in international
+//! waters, which is not part of ISO 3166-1, and so is not accepted.
+//! 
+//! This crate does not include the published list of locations, or the ISO
+//! 3166-2 subdivisions that they are in. Instead, the [`LocationDirectory`]
+//! trait allows a directory of locations to be plugged in, to provide the
+//! subdivision of a location, and to infer locations, and hence countries,
+//! from other identifiers, such as IATA airport codes.
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/unlocode.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::country::{Country, CountryCode};
+use core::{
+	fmt::{Display, self},
+	str::{FromStr, from_utf8},
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "utoipa")]
+use utoipa::{
+	ToSchema,
+	openapi::{ObjectBuilder, RefOr, Schema, SchemaType},
+};
+
+
+
+//		Structs
+
+//		UnLocode																
+/// A UN/LOCODE location code.
+/// 
+/// This is made up of a country code and a location code, e.g. `GB` and `LON`
+/// for London. It is displayed and serialised without a space, e.g. `GBLON`,
+/// but can be parsed with or without one.
+/// 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct UnLocode {
+	//		Private properties													
+	/// The two-letter country code.
+	country:  CountryCode,
+	
+	/// The location code, as uppercase ASCII.
+	location: [u8; 3],
+}
+
+//󰭅		UnLocode																
+impl UnLocode {
+	//		new																	
+	/// Creates a new UN/LOCODE from its parts.
+	/// 
+	/// The country code is converted to its two-letter form, and the location
+	/// code to uppercase.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `country`  - The country code.
+	/// * `location` - The three-character location code, e.g. `LON`.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the location code is not made up of three letters
+	/// or the digits `2` to `9`.
+	/// 
+	pub fn new(country: CountryCode, location: &str) -> Result<Self, String> {
+		match *location.as_bytes() {
+			[a, b, c] if [a, b, c].iter().all(|byte| matches!(byte.to_ascii_uppercase(), b'A'..=b'Z' | b'2'..=b'9')) => {
+				Ok(Self { country: country.to_alpha2(), location: [a, b, c].map(|byte| byte.to_ascii_uppercase()) })
+			},
+			_ => Err(format!("Invalid UnLocode location: {location}")),
+		}
+	}
+	
+	//		country_code														
+	/// Returns the two-letter country code.
+	#[must_use]
+	pub const fn country_code(&self) -> CountryCode {
+		self.country
+	}
+	
+	//		country																
+	/// Returns the country that the location is in.
+	#[must_use]
+	pub const fn country(&self) -> Country {
+		self.country.country()
+	}
+	
+	//		location															
+	/// Returns the location code, e.g. `LON`.
+	#[must_use]
+	pub fn location(&self) -> &str {
+		//	The location code is checked to be ASCII when the UnLocode is created, so
+		//	this will always succeed.
+		from_utf8(&self.location).unwrap_or_default()
+	}
+	
+	//		subdivision															
+	/// Returns the subdivision of the country that the location is in.
+	/// 
+	/// The subdivision is looked up in the given directory, and is returned in
+	/// whichever scheme the directory uses, e.g. ISO 3166-2 codes such as
+	/// `GB-LND`. Locations that are not in the directory, or that have no
+	/// subdivision, return `None`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `directory` - The directory of locations to look the location up in.
+	/// 
+	#[must_use]
+	pub fn subdivision<D: LocationDirectory + ?Sized>(&self, directory: &D) -> Option<String> {
+		directory.subdivision(*self)
+	}
+}
+
+//󰭅		Display																	
+impl Display for UnLocode {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}{}", self.country, self.location())
+	}
+}
+
+//󰭅		From<UnLocode> for String												
+impl From<UnLocode> for String {
+	//		from																
+	fn from(locode: UnLocode) -> Self {
+		locode.to_string()
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for UnLocode {
+	type Err = String;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let trimmed             = s.trim();
+		let (country, location) = trimmed
+			.split_once(' ')
+			.or_else(|| trimmed.split_at_checked(2))
+			.filter(|&(country, _)| country.len() == 2)
+			.ok_or_else(|| format!("Invalid UnLocode: {s}"))?;
+		let code = CountryCode::from_code(country).ok_or_else(|| format!("Invalid UnLocode country: {s}"))?;
+		Self::new(code, location)
+	}
+}
+
+//󰭅		ToSchema																
+#[cfg(feature = "utoipa")]
+impl<'s> ToSchema<'s> for UnLocode {
+	//		schema																
+	fn schema() -> (&'s str, RefOr<Schema>) {
+		("UnLocode", ObjectBuilder::new().schema_type(SchemaType::String).pattern(Some("^[A-Z]{2}[A-Z2-9]{3}$")).into())
+	}
+}
+
+//󰭅		TryFrom<String>															
+impl TryFrom<String> for UnLocode {
+	type Error = String;
+	
+	//		try_from															
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.as_str().parse()
+	}
+}
+
+
+
+//		Traits
+
+//§		LocationDirectory														
+/// A directory of UN/LOCODE locations.
+/// 
+/// This trait is the extension point for location data, which this crate does
+/// not include. Subdivisions are returned as strings, so that any subdivision
+/// scheme can be used, e.g. ISO 3166-2 codes such as `GB-LND`.
+/// 
+/// Only [`subdivision()`](LocationDirectory::subdivision()) needs to be
+/// implemented. The default implementation of
+/// [`locate()`](LocationDirectory::locate()) only recognises UN/LOCODEs, and
+/// can be overridden to recognise other identifiers, such as IATA airport
+/// codes.
+/// 
+pub trait LocationDirectory {
+	//		subdivision															
+	/// Returns the subdivision of the country that a location is in.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `locode` - The location to get the subdivision for.
+	/// 
+	fn subdivision(&self, locode: UnLocode) -> Option<String>;
+	
+	//		locate																
+	/// Returns the location identified by a string.
+	/// 
+	/// The default implementation parses the string as a UN/LOCODE.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `identifier` - The identifier of the location, e.g. `GBLON`.
+	/// 
+	fn locate(&self, identifier: &str) -> Option<UnLocode> {
+		identifier.parse().ok()
+	}
+	
+	//		country																
+	/// Returns the country that the location identified by a string is in.
+	/// 
+	/// The default implementation uses [`locate()`](LocationDirectory::locate())
+	/// to find the location.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `identifier` - The identifier of the location, e.g. `GBLON`.
+	/// 
+	fn country(&self, identifier: &str) -> Option<Country> {
+		self.locate(identifier).map(|locode| locode.country())
+	}
+}
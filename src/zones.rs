@@ -0,0 +1,160 @@
+//! Monetary zones.
+//! 
+//! This module provides curated sets of the countries in some commonly used
+//! monetary zones, such as the [`EUROZONE`] and the CFA franc zones, as
+//! [`CountrySet`]s. The currencies that a country uses, as given by
+//! [`Country::currencies()`], do not distinguish between a country that has
+//! officially adopted a currency and one where it merely circulates, e.g.
+//! alongside a local currency, and so these sets record official adoption
+//! specifically.
+//! 
+//! The sets can be used directly, e.g. `zones::EUROZONE.contains(country)`, or
+//! via the [`MonetaryZone`] enum and [`Country::is_in_zone()`].
+//! 
+//! The membership data is current as of the [`DATA_VERSION`](crate::DATA_VERSION).
+//! 
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/zones.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{
+	AsStr,
+	country::Country,
+	currency::CurrencyCode,
+	set::CountrySet,
+};
+use core::fmt::{Display, self};
+use std::sync::LazyLock;
+
+
+
+//		Constants
+
+/// The member states of the Central African Economic and Monetary Community,
+/// which use the Central African CFA franc, `XAF`.
+pub static CEMAC: LazyLock<CountrySet> = LazyLock::new(|| {
+	CountrySet::from_iter([
+		Country::CF, Country::CG, Country::CM, Country::GA, Country::GQ, Country::TD,
+	])
+});
+
+/// The countries and territories that have officially adopted the US dollar as
+/// their currency, either solely or alongside a local currency.
+/// 
+/// The United States and its territories are not included, as they are not
+/// dollarised, but issue or share the currency.
+/// 
+pub static DOLLARISED: LazyLock<CountrySet> = LazyLock::new(|| {
+	CountrySet::from_iter([
+		Country::BQ, Country::EC, Country::FM, Country::MH, Country::PA, Country::PW, Country::SV, Country::TC, Country::TL, Country::VG,
+	])
+});
+
+/// The member states of the euro area, which have adopted the euro, `EUR`.
+/// 
+/// Countries and territories that use the euro under a monetary agreement
+/// with the European Union, such as Monaco, or unilaterally, such as
+/// Montenegro, are not included, as they are not members of the euro area.
+/// 
+pub static EUROZONE: LazyLock<CountrySet> = LazyLock::new(|| {
+	CountrySet::from_iter([
+		Country::AT, Country::BE, Country::CY, Country::DE, Country::EE, Country::ES, Country::FI, Country::FR, Country::GR, Country::HR,
+		Country::IE, Country::IT, Country::LT, Country::LU, Country::LV, Country::MT, Country::NL, Country::PT, Country::SI, Country::SK,
+	])
+});
+
+/// The member states of the West African Economic and Monetary Union, which
+/// use the West African CFA franc, `XOF`.
+pub static WAEMU: LazyLock<CountrySet> = LazyLock::new(|| {
+	CountrySet::from_iter([
+		Country::BF, Country::BJ, Country::CI, Country::GW, Country::ML, Country::NE, Country::SN, Country::TG,
+	])
+});
+
+
+
+//		Enums
+
+//		MonetaryZone															
+/// A monetary zone, i.e. a group of countries that share a currency.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum MonetaryZone {
+	/// The Central African Economic and Monetary Community, using the Central
+	/// African CFA franc.
+	Cemac,
+	
+	/// The countries that have officially adopted the US dollar.
+	Dollarised,
+	
+	/// The euro area.
+	Eurozone,
+	
+	/// The West African Economic and Monetary Union, using the West African
+	/// CFA franc.
+	Waemu,
+}
+
+//󰭅		MonetaryZone															
+impl MonetaryZone {
+	//		all																	
+	/// Returns all the monetary zones.
+	#[must_use]
+	pub fn all() -> Vec<Self> {
+		vec![ Self::Cemac, Self::Dollarised, Self::Eurozone, Self::Waemu ]
+	}
+	
+	//		countries															
+	/// Returns the countries in the monetary zone.
+	#[must_use]
+	pub fn countries(&self) -> &'static CountrySet {
+		match *self {
+			Self::Cemac      => &CEMAC,
+			Self::Dollarised => &DOLLARISED,
+			Self::Eurozone   => &EUROZONE,
+			Self::Waemu      => &WAEMU,
+		}
+	}
+	
+	//		currency															
+	/// Returns the currency shared by the countries in the monetary zone.
+	#[must_use]
+	pub const fn currency(&self) -> CurrencyCode {
+		match *self {
+			Self::Cemac      => CurrencyCode::XAF,
+			Self::Dollarised => CurrencyCode::USD,
+			Self::Eurozone   => CurrencyCode::EUR,
+			Self::Waemu      => CurrencyCode::XOF,
+		}
+	}
+}
+
+//󰭅		AsStr																	
+impl AsStr for MonetaryZone {
+	//		as_str																
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Self::Cemac      => "CEMAC",
+			Self::Dollarised => "Dollarised",
+			Self::Eurozone   => "Eurozone",
+			Self::Waemu      => "WAEMU",
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for MonetaryZone {
+	//		fmt																	
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}